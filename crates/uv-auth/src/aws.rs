@@ -0,0 +1,215 @@
+//! Signing of requests to AWS services with AWS Signature Version 4.
+//!
+//! Credentials and the region are resolved once per invocation via the standard AWS credential
+//! chain, i.e., from the environment, the shared config and credentials files (including SSO and
+//! `credential_process` profiles), web identity tokens, and the ECS and EC2 metadata services.
+//! Temporary credentials are refreshed shortly before they expire.
+
+use std::time::{Duration, SystemTime};
+
+use aws_config::{BehaviorVersion, SdkConfig};
+use aws_credential_types::provider::ProvideCredentials;
+use aws_sigv4::http_request::{
+    PayloadChecksumKind, PercentEncodingMode, SignableBody, SignableRequest, SigningSettings,
+    UriPathNormalizationMode,
+};
+use aws_sigv4::sign::v4;
+use aws_smithy_http_client::tls::{self, rustls_provider::CryptoMode};
+use http::HeaderValue;
+use http::header::AUTHORIZATION;
+use reqwest::Request;
+use tokio::sync::{Mutex, OnceCell};
+use tracing::{debug, trace, warn};
+
+/// Refresh credentials this long before they expire, to avoid using credentials that expire
+/// mid-request.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The AWS configuration, as loaded from the environment and the shared config files.
+static CONFIG: OnceCell<SdkConfig> = OnceCell::const_new();
+
+/// The credentials from the credential chain, once requested.
+static CREDENTIALS: Mutex<Option<CachedCredentials>> = Mutex::const_new(None);
+
+/// The result of querying the credential chain.
+#[derive(Debug, Clone)]
+enum CachedCredentials {
+    Found(aws_credential_types::Credentials),
+    Missing,
+}
+
+/// An AWS service whose requests can be signed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AwsService {
+    /// Amazon S3.
+    S3,
+    /// AWS CodeArtifact.
+    CodeArtifact,
+}
+
+impl AwsService {
+    /// The name of the service in the credential scope.
+    fn signing_name(self) -> &'static str {
+        match self {
+            Self::S3 => "s3",
+            Self::CodeArtifact => "codeartifact",
+        }
+    }
+
+    fn settings(self) -> SigningSettings {
+        let mut settings = SigningSettings::default();
+        if self == Self::S3 {
+            // S3 requires `x-amz-content-sha256`, and doesn't normalize or double-encode paths.
+            settings.payload_checksum_kind = PayloadChecksumKind::XAmzSha256;
+            settings.percent_encoding_mode = PercentEncodingMode::Single;
+            settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+        }
+        settings
+    }
+}
+
+/// Load the AWS configuration.
+async fn config() -> &'static SdkConfig {
+    CONFIG
+        .get_or_init(|| async {
+            let http_client = aws_smithy_http_client::Builder::new()
+                .tls_provider(tls::Provider::Rustls(CryptoMode::Ring))
+                .build_https();
+            aws_config::defaults(BehaviorVersion::latest())
+                .http_client(http_client)
+                .load()
+                .await
+        })
+        .await
+}
+
+/// Return the configured AWS region, if any.
+pub async fn aws_region() -> Option<String> {
+    config().await.region().map(ToString::to_string)
+}
+
+/// Return the credentials from the credential chain, refreshing them if they're about to expire.
+async fn credentials() -> Option<aws_credential_types::Credentials> {
+    let mut cached = CREDENTIALS.lock().await;
+    match cached.as_ref() {
+        Some(CachedCredentials::Missing) => return None,
+        Some(CachedCredentials::Found(credentials)) => {
+            if credentials
+                .expiry()
+                .is_none_or(|expiry| expiry > SystemTime::now() + EXPIRY_MARGIN)
+            {
+                return Some(credentials.clone());
+            }
+            debug!("Refreshing expired AWS credentials");
+        }
+        None => {}
+    }
+
+    let Some(provider) = config().await.credentials_provider() else {
+        debug!("No AWS credential provider configured");
+        *cached = Some(CachedCredentials::Missing);
+        return None;
+    };
+    match provider.provide_credentials().await {
+        Ok(credentials) => {
+            debug!("Found AWS credentials");
+            *cached = Some(CachedCredentials::Found(credentials.clone()));
+            Some(credentials)
+        }
+        Err(err) => {
+            debug!("No AWS credentials found: {err}");
+            *cached = Some(CachedCredentials::Missing);
+            None
+        }
+    }
+}
+
+/// Sign a request to the given AWS service and region with the credentials from the credential
+/// chain.
+///
+/// Returns `false` if no credentials are available, in which case the request is left unsigned.
+/// The request body is not included in the signature.
+pub async fn sign_aws_request(request: &mut Request, service: AwsService, region: &str) -> bool {
+    let Some(credentials) = credentials().await else {
+        return false;
+    };
+    trace!("Signing request to {} for {service:?}", request.url());
+    if let Err(err) = sign_with(request, credentials, service, region, SystemTime::now()) {
+        warn!("Failed to sign request to {}: {err}", request.url());
+        return false;
+    }
+    true
+}
+
+/// Sign a request with the given credentials, as of the given time.
+fn sign_with(
+    request: &mut Request,
+    credentials: aws_credential_types::Credentials,
+    service: AwsService,
+    region: &str,
+    time: SystemTime,
+) -> anyhow::Result<()> {
+    let identity = credentials.into();
+    let params = v4::SigningParams::builder()
+        .identity(&identity)
+        .region(region)
+        .name(service.signing_name())
+        .time(time)
+        .settings(service.settings())
+        .build()?
+        .into();
+    let body = match service {
+        AwsService::S3 => SignableBody::UnsignedPayload,
+        AwsService::CodeArtifact => SignableBody::Bytes(&[]),
+    };
+    let signable = SignableRequest::new(
+        request.method().as_str(),
+        request.url().as_str(),
+        std::iter::empty(),
+        body,
+    )?;
+    let (instructions, _) = aws_sigv4::http_request::sign(signable, &params)?.into_parts();
+    let (headers, _) = instructions.into_parts();
+    for header in headers {
+        let mut value = HeaderValue::from_str(header.value())?;
+        value.set_sensitive(header.sensitive() || header.name() == AUTHORIZATION);
+        request.headers_mut().insert(header.name(), value);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, Url};
+
+    use super::*;
+
+    #[test]
+    fn sign_s3() {
+        let mut request = Request::new(
+            Method::GET,
+            Url::parse("https://wheels.s3.us-west-2.amazonaws.com/?list-type=2&prefix=internal%2F")
+                .unwrap(),
+        );
+        let credentials = aws_credential_types::Credentials::new(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            Some("session-token".to_string()),
+            None,
+            "test",
+        );
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_440_938_160);
+        sign_with(&mut request, credentials, AwsService::S3, "us-west-2", time).unwrap();
+
+        let headers = request.headers();
+        assert_eq!(headers["x-amz-date"], "20150830T123600Z");
+        assert_eq!(headers["x-amz-content-sha256"], "UNSIGNED-PAYLOAD");
+        assert!(headers["x-amz-security-token"].is_sensitive());
+        let authorization = headers[AUTHORIZATION].to_str().unwrap();
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-west-2/s3/aws4_request, \
+             SignedHeaders=host;x-amz-content-sha256;x-amz-date;x-amz-security-token, Signature="
+        ));
+        assert!(headers[AUTHORIZATION].is_sensitive());
+    }
+}
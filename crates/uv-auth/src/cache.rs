@@ -13,9 +13,14 @@ use uv_redacted::DisplaySafeUrl;
 
 use crate::Realm;
 use crate::credentials::{Credentials, Username};
+use crate::debug::Origin;
+use crate::index::CredentialSource;
 
 type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
 
+/// The key for a credential fetch: the URL, the username, and the sources to consult.
+pub(crate) type FetchKey = (FetchUrl, Username, Box<[CredentialSource]>);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum FetchUrl {
     /// A full index URL
@@ -36,8 +41,9 @@ impl Display for FetchUrl {
 pub struct CredentialsCache {
     /// A cache per realm and username
     realms: RwLock<FxHashMap<(Realm, Username), Arc<Credentials>>>,
-    /// A cache tracking the result of realm or index URL fetches from external services
-    pub(crate) fetches: FxOnceMap<(FetchUrl, Username), Option<Arc<Credentials>>>,
+    /// A cache tracking the result of realm or index URL fetches from the given credential
+    /// sources, along with the origin of the fetched credentials
+    pub(crate) fetches: FxOnceMap<FetchKey, Option<(Arc<Credentials>, Origin)>>,
     /// A cache per URL, uses a trie for efficient prefix queries.
     urls: RwLock<UrlTrie>,
 }
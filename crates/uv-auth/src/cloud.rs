@@ -0,0 +1,322 @@
+//! Short-lived tokens for cloud package registries, fetched on demand.
+//!
+//! AWS CodeArtifact tokens are requested from the CodeArtifact API, signed with the credentials
+//! from the standard AWS credential chain; Azure Artifacts tokens are fetched via the `az` CLI.
+//!
+//! AWS CodeArtifact and Azure Artifacts issue tokens that commonly expire in under an hour, so
+//! tokens are cached alongside their expiry and refreshed transparently, rather than stored in the
+//! credentials cache for the remainder of the invocation.
+//!
+//! Tokens are only fetched for indexes that opt in via `cloud-auth`, and only once the index has
+//! rejected a request and no other credential source has credentials for it.
+
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+use http::Extensions;
+use jiff::{SignedDuration, Timestamp};
+use reqwest::{Method, Request};
+use reqwest_middleware::Next;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::aws::{AwsService, sign_aws_request};
+use crate::{CloudAuth, Credentials};
+
+/// The Azure DevOps resource ID, for which Azure Artifacts tokens are issued.
+const AZURE_DEVOPS_RESOURCE: &str = "499b84ac-1321-427f-aa17-267ca6975798";
+
+/// Refresh tokens this long before they expire, to avoid using a token that expires mid-request.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The lifetime to assume for tokens without a known expiry.
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// The tokens fetched for each registry, or `None` if fetching a token failed.
+static TOKENS: LazyLock<Mutex<FxHashMap<TokenProvider, Option<Token>>>> =
+    LazyLock::new(Mutex::default);
+
+/// A cloud package registry that issues short-lived tokens.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub(crate) enum TokenProvider {
+    /// An AWS CodeArtifact domain.
+    CodeArtifact {
+        domain: String,
+        owner: String,
+        region: String,
+    },
+    /// An Azure Artifacts feed.
+    AzureArtifacts,
+}
+
+impl TokenProvider {
+    /// Return the [`TokenProvider`] for a URL, if it refers to a registry of the given vendor.
+    pub(crate) fn for_url(url: &Url, cloud_auth: CloudAuth) -> Option<Self> {
+        let host = url.host_str()?;
+        match cloud_auth {
+            CloudAuth::Aws => Self::code_artifact(host),
+            CloudAuth::Azure => Self::azure_artifacts(host),
+        }
+    }
+
+    /// Parse an AWS CodeArtifact host, e.g., `acme-123456789012.d.codeartifact.us-west-2.amazonaws.com`.
+    fn code_artifact(host: &str) -> Option<Self> {
+        let host = host.strip_suffix(".amazonaws.com")?;
+        let (prefix, region) = host.split_once(".d.codeartifact.")?;
+        let (domain, owner) = prefix.rsplit_once('-')?;
+        if domain.is_empty()
+            || owner.len() != 12
+            || !owner.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return None;
+        }
+        Some(Self::CodeArtifact {
+            domain: domain.to_string(),
+            owner: owner.to_string(),
+            region: region.to_string(),
+        })
+    }
+
+    /// Parse an Azure Artifacts host, e.g., `pkgs.dev.azure.com` or `acme.pkgs.visualstudio.com`.
+    fn azure_artifacts(host: &str) -> Option<Self> {
+        if host == "pkgs.dev.azure.com" || host.ends_with(".pkgs.visualstudio.com") {
+            Some(Self::AzureArtifacts)
+        } else {
+            None
+        }
+    }
+
+    /// Return the cached credentials for the registry, if a token was fetched earlier in the
+    /// invocation and hasn't expired.
+    pub(crate) async fn cached_credentials(&self) -> Option<Arc<Credentials>> {
+        let tokens = TOKENS.lock().await;
+        let token = tokens.get(self)?.as_ref()?;
+        (!token.is_expired()).then(|| token.credentials.clone())
+    }
+
+    /// Return credentials for the registry, fetching a new token if the cached token has expired
+    /// (or if `refresh` is set, e.g., because the registry rejected the cached token).
+    ///
+    /// Tokens are requested via the remainder of the middleware chain, `next`.
+    pub(crate) async fn credentials(
+        &self,
+        refresh: bool,
+        next: &Next<'_>,
+    ) -> Option<Arc<Credentials>> {
+        let mut tokens = TOKENS.lock().await;
+        match tokens.get(self) {
+            // A previous attempt failed; don't retry.
+            Some(None) => return None,
+            Some(Some(token)) if !refresh && !token.is_expired() => {
+                return Some(token.credentials.clone());
+            }
+            _ => {}
+        }
+
+        debug!("Fetching a token for {self:?}");
+        let token = self
+            .fetch(next)
+            .await
+            .inspect_err(|err| warn!("Failed to fetch a token for {self:?}: {err}"))
+            .ok();
+        let credentials = token.as_ref().map(|token| token.credentials.clone());
+        tokens.insert(self.clone(), token);
+        credentials
+    }
+
+    /// Fetch a new token from the registry.
+    async fn fetch(&self, next: &Next<'_>) -> Result<Token, std::io::Error> {
+        match self {
+            Self::CodeArtifact {
+                domain,
+                owner,
+                region,
+            } => {
+                // See: https://docs.aws.amazon.com/codeartifact/latest/APIReference/API_GetAuthorizationToken.html
+                let mut url = Url::parse(&format!(
+                    "https://codeartifact.{region}.amazonaws.com/v1/authorization-token"
+                ))
+                .map_err(std::io::Error::other)?;
+                url.query_pairs_mut()
+                    .append_pair("domain", domain)
+                    .append_pair("domain-owner", owner);
+                let mut request = Request::new(Method::POST, url);
+                if !sign_aws_request(&mut request, AwsService::CodeArtifact, region).await {
+                    return Err(std::io::Error::other("no AWS credentials found"));
+                }
+                let response = next
+                    .clone()
+                    .run(request, &mut Extensions::new())
+                    .await
+                    .map_err(std::io::Error::other)?
+                    .error_for_status()
+                    .map_err(std::io::Error::other)?;
+                let response: CodeArtifactToken =
+                    response.json().await.map_err(std::io::Error::other)?;
+                Ok(Token::new(
+                    Credentials::basic(Some("aws".to_string()), Some(response.authorization_token)),
+                    response.expiration.as_ref().and_then(parse_expiry),
+                ))
+            }
+            Self::AzureArtifacts => {
+                let output = run(Command::new("az").args([
+                    "account",
+                    "get-access-token",
+                    "--resource",
+                    AZURE_DEVOPS_RESOURCE,
+                    "--output",
+                    "json",
+                ]))
+                .await?;
+                let response: AzureToken =
+                    serde_json::from_slice(&output).map_err(std::io::Error::other)?;
+                Ok(Token::new(
+                    Credentials::bearer(response.access_token.into_bytes()),
+                    response.expires_on.as_ref().and_then(parse_expiry),
+                ))
+            }
+        }
+    }
+}
+
+/// A token and the time at which it should be refreshed.
+#[derive(Debug)]
+struct Token {
+    credentials: Arc<Credentials>,
+    refresh_at: Timestamp,
+}
+
+impl Token {
+    fn new(credentials: Credentials, expires_at: Option<Timestamp>) -> Self {
+        let now = Timestamp::now();
+        let refresh_at = expires_at
+            .and_then(|expires_at| expires_at.checked_sub(EXPIRY_MARGIN).ok())
+            .unwrap_or_else(|| now + DEFAULT_LIFETIME);
+        Self {
+            credentials: Arc::new(credentials),
+            refresh_at,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        Timestamp::now() >= self.refresh_at
+    }
+}
+
+/// Parse an expiry, given either as a Unix timestamp or an RFC 3339 timestamp.
+fn parse_expiry(value: &serde_json::Value) -> Option<Timestamp> {
+    match value {
+        serde_json::Value::Number(seconds) => {
+            Timestamp::from_duration(SignedDuration::try_from_secs_f64(seconds.as_f64()?).ok()?)
+                .ok()
+        }
+        serde_json::Value::String(value) => value
+            .parse::<Timestamp>()
+            .ok()
+            .or_else(|| Timestamp::from_second(value.parse().ok()?).ok()),
+        _ => None,
+    }
+}
+
+/// Run a command, returning its stdout.
+async fn run(command: &mut Command) -> Result<Vec<u8>, std::io::Error> {
+    let output = command
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .await?;
+    if !output.status.success() {
+        return Err(std::io::Error::other(format!(
+            "command exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+/// The response from the CodeArtifact `GetAuthorizationToken` API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CodeArtifactToken {
+    authorization_token: String,
+    expiration: Option<serde_json::Value>,
+}
+
+/// The response from `az account get-access-token`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureToken {
+    access_token: String,
+    #[serde(rename = "expires_on")]
+    expires_on: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_artifact() {
+        let url = Url::parse(
+            "https://acme-corp-123456789012.d.codeartifact.us-west-2.amazonaws.com/pypi/shared/simple/",
+        )
+        .unwrap();
+        assert_eq!(
+            TokenProvider::for_url(&url, CloudAuth::Aws),
+            Some(TokenProvider::CodeArtifact {
+                domain: "acme-corp".to_string(),
+                owner: "123456789012".to_string(),
+                region: "us-west-2".to_string(),
+            })
+        );
+
+        // The registry must match the configured vendor.
+        assert_eq!(TokenProvider::for_url(&url, CloudAuth::Azure), None);
+
+        let url = Url::parse("https://s3.us-west-2.amazonaws.com/bucket/").unwrap();
+        assert_eq!(TokenProvider::for_url(&url, CloudAuth::Aws), None);
+    }
+
+    #[test]
+    fn azure_artifacts() {
+        let url =
+            Url::parse("https://pkgs.dev.azure.com/acme/_packaging/feed/pypi/simple/").unwrap();
+        assert_eq!(
+            TokenProvider::for_url(&url, CloudAuth::Azure),
+            Some(TokenProvider::AzureArtifacts)
+        );
+
+        let url =
+            Url::parse("https://acme.pkgs.visualstudio.com/_packaging/feed/pypi/simple/").unwrap();
+        assert_eq!(
+            TokenProvider::for_url(&url, CloudAuth::Azure),
+            Some(TokenProvider::AzureArtifacts)
+        );
+    }
+
+    #[test]
+    fn expiry() {
+        let expected = Timestamp::from_second(1_700_000_000).unwrap();
+        assert_eq!(
+            parse_expiry(&serde_json::json!(1_700_000_000)),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_expiry(&serde_json::json!(1_700_000_000.0)),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_expiry(&serde_json::json!("1700000000")),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_expiry(&serde_json::json!("2023-11-14T22:13:20Z")),
+            Some(expected)
+        );
+    }
+}
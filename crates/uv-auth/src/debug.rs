@@ -0,0 +1,72 @@
+//! Reporting of the credentials used for each request, for `--debug-auth`.
+
+use std::fmt::{self, Display, Formatter};
+
+use tracing::info;
+use url::Url;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::credentials::Credentials;
+use crate::index::CredentialSource;
+
+/// The `tracing` target for the credentials used for each request, which is enabled at the `INFO`
+/// level by `--debug-auth`.
+pub const DEBUG_AUTH_TARGET: &str = "uv_auth::debug";
+
+/// Where the credentials for a request came from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Origin {
+    /// The request itself, i.e., credentials embedded in the URL.
+    Request,
+    /// The `HF_TOKEN` environment variable, for requests to Hugging Face.
+    HuggingFace,
+    /// A token minted by a cloud registry's CLI (e.g., `aws` or `az`).
+    CloudToken,
+    /// One of the configurable [`CredentialSource`]s.
+    Source(CredentialSource),
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Request => write!(f, "the request URL"),
+            Self::HuggingFace => write!(f, "`HF_TOKEN`"),
+            Self::CloudToken => write!(f, "the cloud registry CLI"),
+            Self::Source(CredentialSource::Env) => write!(f, "environment variables"),
+            Self::Source(CredentialSource::Cache) => write!(f, "the credentials cache"),
+            Self::Source(CredentialSource::Helper) => write!(f, "the credential helper"),
+            Self::Source(CredentialSource::Netrc) => write!(f, "the netrc file"),
+            Self::Source(CredentialSource::Keyring) => write!(f, "the keyring"),
+        }
+    }
+}
+
+impl From<CredentialSource> for Origin {
+    fn from(source: CredentialSource) -> Self {
+        Self::Source(source)
+    }
+}
+
+/// Report the credentials that a request to the given URL is sent with, if enabled.
+pub(crate) fn report(url: &Url, credentials: Option<(&Credentials, Origin)>) {
+    if !tracing::enabled!(target: DEBUG_AUTH_TARGET, tracing::Level::INFO) {
+        return;
+    }
+    let url = DisplaySafeUrl::ref_cast(url).without_credentials();
+    let description = match credentials {
+        None => "no credentials".to_string(),
+        Some((Credentials::Bearer { .. }, origin)) => format!("token from {origin}"),
+        Some((credentials, origin)) => match (credentials.username(), credentials.password()) {
+            (Some(username), Some(_)) => {
+                format!("username `{username}` and password from {origin}")
+            }
+            (Some(username), None) => {
+                format!("username `{username}` (without a password) from {origin}")
+            }
+            (None, Some(_)) => format!("password from {origin}"),
+            (None, None) => "no credentials".to_string(),
+        },
+    };
+    info!(target: DEBUG_AUTH_TARGET, "Authenticating {url} with {description}");
+}
@@ -0,0 +1,236 @@
+use std::fmt::{self, Display, Formatter};
+use std::process::Stdio;
+use std::time::Duration;
+
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{debug, instrument, warn};
+
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::credentials::Credentials;
+
+/// The default time to wait for a credential helper, e.g., one that prompts for an interactive
+/// login, before giving up on it.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// An external command that provides credentials for an index, following the protocol used by
+/// Docker's credential helpers.
+///
+/// The command is invoked with a `get` argument and the index URL on stdin, and is expected to
+/// write either a JSON object with `Username` and `Secret` keys, or a bare token, to stdout.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize)]
+#[serde(transparent)]
+pub struct CredentialHelper(Vec<String>);
+
+impl CredentialHelper {
+    /// Fetch credentials for the given URL from the helper.
+    ///
+    /// Returns [`None`] if the helper fails, times out, or doesn't return any credentials.
+    pub(crate) async fn fetch(&self, url: &DisplaySafeUrl) -> Option<Credentials> {
+        let timeout = std::env::var(EnvVars::UV_CREDENTIAL_HELPER_TIMEOUT)
+            .ok()
+            .and_then(|value| {
+                value
+                    .parse::<u64>()
+                    .inspect_err(|err| {
+                        warn_user_once!(
+                            "Ignoring invalid value for `{}`: `{value}` ({err})",
+                            EnvVars::UV_CREDENTIAL_HELPER_TIMEOUT
+                        );
+                    })
+                    .ok()
+            })
+            .map_or(DEFAULT_TIMEOUT, Duration::from_secs);
+        self.fetch_with_timeout(url, timeout).await
+    }
+
+    #[instrument(skip_all, fields(url = % url.to_string()))]
+    async fn fetch_with_timeout(
+        &self,
+        url: &DisplaySafeUrl,
+        timeout: Duration,
+    ) -> Option<Credentials> {
+        let (program, args) = self.0.split_first()?;
+
+        debug!("Running credential helper `{self}` for {url}");
+        let mut child = Command::new(program)
+            .args(args)
+            .arg("get")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            // Kill the helper if it times out.
+            .kill_on_drop(true)
+            .spawn()
+            .inspect_err(|err| {
+                warn_user_once!("Failed to run credential helper `{self}`: {err}");
+            })
+            .ok()?;
+
+        // Write the URL to stdin, then close it to signal the end of input.
+        if let Some(mut stdin) = child.stdin.take() {
+            let url = url.without_credentials();
+            stdin
+                .write_all(format!("{url}\n").as_bytes())
+                .await
+                .inspect_err(|err| warn!("Failed to write to credential helper: {err}"))
+                .ok()?;
+        }
+
+        let Ok(output) = tokio::time::timeout(timeout, child.wait_with_output()).await else {
+            warn_user_once!(
+                "Credential helper `{self}` timed out after {}s for {url}",
+                timeout.as_secs()
+            );
+            return None;
+        };
+        let output = output
+            .inspect_err(|err| warn!("Failed to wait for credential helper output: {err}"))
+            .ok()?;
+
+        if !output.status.success() {
+            warn_user_once!(
+                "Credential helper `{self}` failed for {url} ({})",
+                output.status
+            );
+            return None;
+        }
+
+        let output = String::from_utf8(output.stdout)
+            .inspect_err(|err| warn!("Failed to parse response from credential helper: {err}"))
+            .ok()?;
+        Self::parse(&output)
+    }
+
+    /// Parse the [`Credentials`] from the output of a credential helper.
+    fn parse(output: &str) -> Option<Credentials> {
+        let output = output.trim();
+        if output.is_empty() {
+            return None;
+        }
+
+        // Docker-style helpers return a JSON object, using `<token>` as the username for identity
+        // tokens.
+        if output.starts_with('{') {
+            let response = serde_json::from_str::<HelperResponse>(output)
+                .inspect_err(|err| warn!("Failed to parse response from credential helper: {err}"))
+                .ok()?;
+            return match response.username.as_deref() {
+                None | Some("" | "<token>") => {
+                    Some(Credentials::bearer(response.secret.into_bytes()))
+                }
+                Some(username) => Some(Credentials::basic(
+                    Some(username.to_string()),
+                    Some(response.secret),
+                )),
+            };
+        }
+
+        // Otherwise, treat the output as a bearer token.
+        Some(Credentials::bearer(output.as_bytes().to_vec()))
+    }
+}
+
+impl Display for CredentialHelper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.join(" "))
+    }
+}
+
+impl<'de> Deserialize<'de> for CredentialHelper {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let command = Vec::<String>::deserialize(deserializer)?;
+        if command.is_empty() {
+            return Err(serde::de::Error::custom(
+                "`credential-helper` must include a command",
+            ));
+        }
+        Ok(Self(command))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CredentialHelper {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("CredentialHelper")
+    }
+
+    fn json_schema(generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        <Vec<String>>::json_schema(generator)
+    }
+}
+
+/// The response from a Docker-style credential helper.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HelperResponse {
+    username: Option<String>,
+    secret: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_token() {
+        let credentials = CredentialHelper::parse("s3cr3t\n").unwrap();
+        assert_eq!(credentials, Credentials::bearer(b"s3cr3t".to_vec()));
+    }
+
+    #[test]
+    fn parse_docker() {
+        let credentials = CredentialHelper::parse(
+            r#"{"ServerURL": "https://example.com/simple", "Username": "aws", "Secret": "s3cr3t"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            credentials,
+            Credentials::basic(Some("aws".to_string()), Some("s3cr3t".to_string()))
+        );
+
+        let credentials =
+            CredentialHelper::parse(r#"{"Username": "<token>", "Secret": "s3cr3t"}"#).unwrap();
+        assert_eq!(credentials, Credentials::bearer(b"s3cr3t".to_vec()));
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert!(CredentialHelper::parse("\n").is_none());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn fetch_timeout() {
+        let url = DisplaySafeUrl::parse("https://example.com/simple").unwrap();
+
+        let helper = CredentialHelper(vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            "read url && echo s3cr3t".to_string(),
+        ]);
+        assert_eq!(
+            helper
+                .fetch_with_timeout(&url, Duration::from_secs(10))
+                .await,
+            Some(Credentials::bearer(b"s3cr3t".to_vec()))
+        );
+
+        // A helper that hangs, e.g., waiting for an interactive login, is abandoned.
+        let helper = CredentialHelper(vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            "sleep 10".to_string(),
+        ]);
+        assert!(
+            helper
+                .fetch_with_timeout(&url, Duration::from_millis(100))
+                .await
+                .is_none()
+        );
+    }
+}
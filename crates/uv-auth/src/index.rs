@@ -4,6 +4,8 @@ use rustc_hash::FxHashSet;
 use url::Url;
 use uv_redacted::DisplaySafeUrl;
 
+use crate::CredentialHelper;
+
 /// When to use authentication.
 #[derive(
     Copy,
@@ -49,6 +51,72 @@ impl Display for AuthPolicy {
     }
 }
 
+/// A source of credentials for an index.
+#[derive(
+    Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CredentialSource {
+    /// The `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD` environment variables.
+    Env,
+    /// Credentials that were used for an earlier request to the index (or its host) in the same
+    /// invocation.
+    Cache,
+    /// The index's `credential-helper`.
+    Helper,
+    /// The netrc file.
+    Netrc,
+    /// The keyring provider, if enabled.
+    Keyring,
+}
+
+impl CredentialSource {
+    /// The order in which sources are consulted, if not configured for the index.
+    pub const DEFAULT: &'static [Self] = &[
+        Self::Env,
+        Self::Cache,
+        Self::Helper,
+        Self::Netrc,
+        Self::Keyring,
+    ];
+}
+
+impl Display for CredentialSource {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Env => write!(f, "env"),
+            Self::Cache => write!(f, "cache"),
+            Self::Helper => write!(f, "helper"),
+            Self::Netrc => write!(f, "netrc"),
+            Self::Keyring => write!(f, "keyring"),
+        }
+    }
+}
+
+/// A cloud registry that issues short-lived tokens, fetched via the vendor's CLI.
+#[derive(
+    Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CloudAuth {
+    /// An AWS CodeArtifact repository, authenticated with
+    /// `aws codeartifact get-authorization-token`.
+    Aws,
+    /// An Azure Artifacts feed, authenticated with `az account get-access-token`.
+    Azure,
+}
+
+impl Display for CloudAuth {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Aws => write!(f, "aws"),
+            Self::Azure => write!(f, "azure"),
+        }
+    }
+}
+
 // TODO(john): We are not using `uv_distribution_types::Index` directly
 // here because it would cause circular crate dependencies. However, this
 // could potentially make sense for a future refactor.
@@ -59,6 +127,15 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// The command to run to fetch credentials for the index, if any.
+    pub credential_helper: Option<CredentialHelper>,
+    /// The name of the index, as used in environment variables (e.g., `INTERNAL` for
+    /// `UV_INDEX_INTERNAL_USERNAME`), if any.
+    pub env_name: Option<String>,
+    /// The sources to consult for credentials, in order, if configured.
+    pub credential_sources: Option<Vec<CredentialSource>>,
+    /// The cloud registry to fetch tokens from, if the index opts in to cloud authentication.
+    pub cloud_auth: Option<CloudAuth>,
 }
 
 impl Index {
@@ -107,6 +184,31 @@ impl Indexes {
             .unwrap_or(AuthPolicy::Auto)
     }
 
+    /// Get the [`CredentialHelper`] for a URL, if one is configured.
+    pub fn credential_helper_for(&self, url: &Url) -> Option<&CredentialHelper> {
+        self.find_prefix_index(url)
+            .and_then(|index| index.credential_helper.as_ref())
+    }
+
+    /// Get the sources to consult for credentials for a URL, in order.
+    pub fn credential_sources_for(&self, url: &Url) -> &[CredentialSource] {
+        self.find_prefix_index(url)
+            .and_then(|index| index.credential_sources.as_deref())
+            .unwrap_or(CredentialSource::DEFAULT)
+    }
+
+    /// Get the [`CloudAuth`] for a URL, if the index opts in to cloud authentication.
+    pub fn cloud_auth_for(&self, url: &Url) -> Option<CloudAuth> {
+        self.find_prefix_index(url)
+            .and_then(|index| index.cloud_auth)
+    }
+
+    /// Get the name of the index for a URL, as used in environment variables, if any.
+    pub fn env_name_for(&self, url: &Url) -> Option<&str> {
+        self.find_prefix_index(url)
+            .and_then(|index| index.env_name.as_deref())
+    }
+
     fn find_prefix_index(&self, url: &Url) -> Option<&Index> {
         self.0.iter().find(|&index| index.is_prefix_for(url))
     }
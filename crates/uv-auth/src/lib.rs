@@ -2,16 +2,23 @@ use std::sync::{Arc, LazyLock};
 
 use tracing::trace;
 
+pub use aws::{AwsService, aws_region, sign_aws_request};
 use cache::CredentialsCache;
 pub use credentials::Credentials;
-pub use index::{AuthPolicy, Index, Indexes};
+pub use debug::DEBUG_AUTH_TARGET;
+pub use helper::CredentialHelper;
+pub use index::{AuthPolicy, CloudAuth, CredentialSource, Index, Indexes};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
 use realm::Realm;
 use uv_redacted::DisplaySafeUrl;
 
+mod aws;
 mod cache;
+mod cloud;
 mod credentials;
+mod debug;
+mod helper;
 mod index;
 mod keyring;
 mod middleware;
@@ -6,16 +6,23 @@ use netrc::Netrc;
 use reqwest::{Request, Response};
 use reqwest_middleware::{Error, Middleware, Next};
 use tracing::{debug, trace, warn};
+use url::Url;
 
+use crate::cloud::TokenProvider;
+use crate::debug::{self, Origin};
 use crate::providers::HuggingFaceProvider;
 use crate::{
     CREDENTIALS_CACHE, CredentialsCache, KeyringProvider,
     cache::FetchUrl,
     credentials::{Credentials, Username},
-    index::{AuthPolicy, Indexes},
+    index::{AuthPolicy, CredentialSource, Indexes},
     realm::Realm,
 };
 use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user_once;
+
+/// The origin of credentials reused from the [`CredentialsCache`].
+const CACHE: Origin = Origin::Source(CredentialSource::Cache);
 
 /// Strategy for loading netrc files.
 enum NetrcMode {
@@ -151,8 +158,7 @@ impl Middleware for AuthMiddleware {
     /// The discovered credentials must have the requested username to be used.
     ///
     /// - Check the cache (index URL or realm key) for a password
-    /// - Check the netrc for a password
-    /// - Check the keyring for a password
+    /// - Check the configured credential sources (e.g., netrc and the keyring) for a password
     /// - Perform the request
     /// - Add the username and password to the cache if successful
     ///
@@ -168,9 +174,11 @@ impl Middleware for AuthMiddleware {
     /// - Perform the request
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (index URL or realm key) for the username and password
-    ///     - Check the netrc for a username and password
+    ///     - Check the configured credential sources for a username and password
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
+    ///
+    /// The cache is only consulted if it's among the index's credential sources.
     async fn handle(
         &self,
         mut request: Request,
@@ -185,9 +193,12 @@ impl Middleware for AuthMiddleware {
         let url = tracing_url(&request, request_credentials.as_ref());
         let maybe_index_url = self.indexes.index_url_for(request.url());
         let auth_policy = self.indexes.auth_policy_for(request.url());
+        let sources = self.indexes.credential_sources_for(request.url());
+        let use_cache = sources.contains(&CredentialSource::Cache);
         trace!("Handling request for {url} with authentication policy {auth_policy}");
 
-        let credentials: Option<Arc<Credentials>> = if matches!(auth_policy, AuthPolicy::Never) {
+        let found: Option<(Arc<Credentials>, Origin)> = if matches!(auth_policy, AuthPolicy::Never)
+        {
             None
         } else {
             if let Some(request_credentials) = request_credentials {
@@ -200,33 +211,57 @@ impl Middleware for AuthMiddleware {
                         &url,
                         maybe_index_url,
                         auth_policy,
+                        sources,
                     )
                     .await;
             }
 
             // We have no credentials
+            //
+            // Tokens for cloud registries expire mid-run, so they're managed (and refreshed) by
+            // the provider, rather than the credentials cache. If a token was already needed for
+            // the index, reuse it to avoid a failing request.
+            if let Some(provider) = self.token_provider_for(request.url()) {
+                if let Some(credentials) = provider.cached_credentials().await {
+                    trace!("Request for {url} is authenticated with a token from {provider:?}");
+                    return self
+                        .complete_request_with_token(
+                            provider,
+                            credentials,
+                            request,
+                            extensions,
+                            next,
+                        )
+                        .await;
+                }
+            }
+
             trace!("Request for {url} is unauthenticated, checking cache");
 
             // Check the cache for a URL match first. This can save us from
             // making a failing request
-            let credentials = self.cache().get_url(request.url(), &Username::none());
-            if let Some(credentials) = credentials.as_ref() {
+            let found = self.local_credentials(request.url(), sources);
+            if let Some((credentials, origin)) = found.as_ref() {
                 request = credentials.authenticate(request);
 
                 // If it's fully authenticated, finish the request
                 if credentials.password().is_some() {
                     trace!("Request for {url} is fully authenticated");
+                    debug::report(request.url(), Some((&**credentials, *origin)));
+                    // Do not insert already-cached credentials
+                    let credentials = (*origin != CACHE).then(|| credentials.clone());
                     return self
-                        .complete_request(None, request, extensions, next, auth_policy)
+                        .complete_request(credentials, request, extensions, next, auth_policy)
                         .await;
                 }
 
                 // If we just found a username, we'll make the request then look for password elsewhere
                 // if it fails
-                trace!("Found username for {url} in cache, attempting request");
+                trace!("Found username for {url} in {origin}, attempting request");
             }
-            credentials
+            found
         };
+        let credentials = found.as_ref().map(|(credentials, _)| credentials.clone());
         let attempt_has_username = credentials
             .as_ref()
             .is_some_and(|credentials| credentials.username().is_some());
@@ -250,6 +285,12 @@ impl Middleware for AuthMiddleware {
                 ))
             })?;
 
+            debug::report(
+                request.url(),
+                found
+                    .as_ref()
+                    .map(|(credentials, origin)| (&**credentials, *origin)),
+            );
             let response = next.clone().run(request, extensions).await?;
 
             // If we don't fail with authorization related codes or
@@ -276,12 +317,40 @@ impl Middleware for AuthMiddleware {
             (request, None)
         };
         let retry_request_url = DisplaySafeUrl::ref_cast(retry_request.url());
+        let (before_cache, after_cache) = split_at_cache(sources);
+
+        // First, fetch from the sources that take precedence over the cache.
+        if let Some((credentials, origin)) = self
+            .fetch_credentials(
+                credentials.as_deref(),
+                retry_request_url,
+                maybe_index_url,
+                auth_policy,
+                before_cache,
+            )
+            .await
+        {
+            retry_request = credentials.authenticate(retry_request);
+            trace!("Retrying request for {url} with {credentials:?}");
+            debug::report(retry_request.url(), Some((&*credentials, origin)));
+            return self
+                .complete_request(
+                    Some(credentials),
+                    retry_request,
+                    extensions,
+                    next,
+                    auth_policy,
+                )
+                .await;
+        }
 
         let username = credentials
             .as_ref()
             .map(|credentials| credentials.to_username())
             .unwrap_or(Username::none());
-        let credentials = if let Some(index_url) = maybe_index_url {
+        let cached = if !use_cache {
+            None
+        } else if let Some(index_url) = maybe_index_url {
             self.cache().get_url(index_url, &username).or_else(|| {
                 self.cache()
                     .get_realm(Realm::from(&**retry_request_url), username)
@@ -291,32 +360,35 @@ impl Middleware for AuthMiddleware {
             // the realm-level cache.
             self.cache()
                 .get_realm(Realm::from(&**retry_request_url), username)
-        }
-        .or(credentials);
+        };
+        let credentials = cached.or(credentials);
 
         if let Some(credentials) = credentials.as_ref() {
             if credentials.password().is_some() {
                 trace!("Retrying request for {url} with credentials from cache {credentials:?}");
                 retry_request = credentials.authenticate(retry_request);
+                debug::report(retry_request.url(), Some((&**credentials, CACHE)));
                 return self
                     .complete_request(None, retry_request, extensions, next, auth_policy)
                     .await;
             }
         }
 
-        // Then, fetch from external services.
+        // Then, fetch from the remaining sources.
         // Here, we use the username from the cache if present.
-        if let Some(credentials) = self
+        if let Some((credentials, origin)) = self
             .fetch_credentials(
                 credentials.as_deref(),
                 retry_request_url,
                 maybe_index_url,
                 auth_policy,
+                after_cache,
             )
             .await
         {
             retry_request = credentials.authenticate(retry_request);
             trace!("Retrying request for {url} with {credentials:?}");
+            debug::report(retry_request.url(), Some((&*credentials, origin)));
             return self
                 .complete_request(
                     Some(credentials),
@@ -328,10 +400,35 @@ impl Middleware for AuthMiddleware {
                 .await;
         }
 
+        // As a last resort, fetch a token from the cloud registry, if the index opts in and the
+        // request was rejected as unauthorized.
+        if response.as_ref().is_none_or(|response| {
+            matches!(
+                response.status(),
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+            )
+        }) {
+            if let Some(provider) = self.token_provider_for(retry_request.url()) {
+                if let Some(credentials) = provider.credentials(false, &next).await {
+                    trace!("Retrying request for {url} with a token from {provider:?}");
+                    return self
+                        .complete_request_with_token(
+                            provider,
+                            credentials,
+                            retry_request,
+                            extensions,
+                            next,
+                        )
+                        .await;
+                }
+            }
+        }
+
         if let Some(credentials) = credentials.as_ref() {
             if !attempt_has_username {
                 trace!("Retrying request for {url} with username from cache {credentials:?}");
                 retry_request = credentials.authenticate(retry_request);
+                debug::report(retry_request.url(), Some((&**credentials, CACHE)));
                 return self
                     .complete_request(None, retry_request, extensions, next, auth_policy)
                     .await;
@@ -382,6 +479,59 @@ impl AuthMiddleware {
         result
     }
 
+    /// Complete a request using a token from a cloud registry, refreshing the token and retrying
+    /// if the registry rejects it (e.g., because it expired).
+    async fn complete_request_with_token(
+        &self,
+        provider: TokenProvider,
+        credentials: Arc<Credentials>,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        debug::report(request.url(), Some((&*credentials, Origin::CloudToken)));
+        let Some(retry_request) = request.try_clone() else {
+            return next
+                .run(credentials.authenticate(request), extensions)
+                .await;
+        };
+        let response = next
+            .clone()
+            .run(credentials.authenticate(request), extensions)
+            .await?;
+        if !matches!(
+            response.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) {
+            return Ok(response);
+        }
+        let Some(refreshed) = provider.credentials(true, &next).await else {
+            return Ok(response);
+        };
+        debug!(
+            "Request for {} was rejected with {}, retrying with a refreshed token",
+            retry_request.url(),
+            response.status()
+        );
+        debug::report(retry_request.url(), Some((&*refreshed, Origin::CloudToken)));
+        next.run(refreshed.authenticate(retry_request), extensions)
+            .await
+    }
+
+    /// Return the cloud registry to fetch tokens from for a URL, if its index opts in to cloud
+    /// authentication.
+    fn token_provider_for(&self, url: &Url) -> Option<TokenProvider> {
+        let cloud_auth = self.indexes.cloud_auth_for(url)?;
+        let provider = TokenProvider::for_url(url, cloud_auth);
+        if provider.is_none() {
+            warn_user_once!(
+                "Ignoring `cloud-auth = \"{cloud_auth}\"` for `{}`, which is not a supported registry",
+                url.host_str().unwrap_or_default()
+            );
+        }
+        provider
+    }
+
     /// Use known request credentials to complete the request.
     async fn complete_request_with_request_credentials(
         &self,
@@ -392,12 +542,15 @@ impl AuthMiddleware {
         url: &DisplaySafeUrl,
         index_url: Option<&DisplaySafeUrl>,
         auth_policy: AuthPolicy,
+        sources: &[CredentialSource],
     ) -> reqwest_middleware::Result<Response> {
         let credentials = Arc::new(credentials);
+        let use_cache = sources.contains(&CredentialSource::Cache);
 
         // If there's a password, send the request and cache
         if credentials.password().is_some() {
             trace!("Request for {url} already contains username and password");
+            debug::report(request.url(), Some((&*credentials, Origin::Request)));
             return self
                 .complete_request(Some(credentials), request, extensions, next, auth_policy)
                 .await;
@@ -405,10 +558,31 @@ impl AuthMiddleware {
 
         trace!("Request for {url} is missing a password, looking for credentials");
 
+        // First, fetch from the sources that take precedence over the cache.
+        let (before_cache, after_cache) = split_at_cache(sources);
+        if let Some((fetched, origin)) = self
+            .fetch_credentials(
+                Some(&credentials),
+                DisplaySafeUrl::ref_cast(request.url()),
+                index_url,
+                auth_policy,
+                before_cache,
+            )
+            .await
+        {
+            request = fetched.authenticate(request);
+            debug::report(request.url(), Some((&*fetched, origin)));
+            return self
+                .complete_request(Some(fetched), request, extensions, next, auth_policy)
+                .await;
+        }
+
         // There's just a username, try to find a password.
         // If we have an index URL, check the cache for that URL. Otherwise,
         // check for the realm.
-        let maybe_cached_credentials = if let Some(index_url) = index_url {
+        let maybe_cached_credentials = if !use_cache {
+            None
+        } else if let Some(index_url) = index_url {
             self.cache()
                 .get_url(index_url, credentials.as_username().as_ref())
         } else {
@@ -417,6 +591,7 @@ impl AuthMiddleware {
         };
         if let Some(credentials) = maybe_cached_credentials {
             request = credentials.authenticate(request);
+            debug::report(request.url(), Some((&*credentials, CACHE)));
             // Do not insert already-cached credentials
             let credentials = None;
             return self
@@ -424,37 +599,49 @@ impl AuthMiddleware {
                 .await;
         }
 
-        let credentials = if let Some(credentials) = self
-            .cache()
-            .get_url(request.url(), credentials.as_username().as_ref())
+        let credentials = if let Some(credentials) = use_cache
+            .then(|| {
+                self.cache()
+                    .get_url(request.url(), credentials.as_username().as_ref())
+            })
+            .flatten()
         {
             request = credentials.authenticate(request);
+            debug::report(request.url(), Some((&*credentials, CACHE)));
             // Do not insert already-cached credentials
             None
-        } else if let Some(credentials) = self
+        } else if let Some((credentials, origin)) = self
             .fetch_credentials(
                 Some(&credentials),
                 DisplaySafeUrl::ref_cast(request.url()),
                 index_url,
                 auth_policy,
+                after_cache,
             )
             .await
         {
             request = credentials.authenticate(request);
+            debug::report(request.url(), Some((&*credentials, origin)));
             Some(credentials)
         } else if index_url.is_some() {
             // If this is a known index, we fall back to checking for the realm.
-            if let Some(credentials) = self
-                .cache()
-                .get_realm(Realm::from(request.url()), credentials.to_username())
+            if let Some(credentials) = use_cache
+                .then(|| {
+                    self.cache()
+                        .get_realm(Realm::from(request.url()), credentials.to_username())
+                })
+                .flatten()
             {
                 request = credentials.authenticate(request);
+                debug::report(request.url(), Some((&*credentials, CACHE)));
                 Some(credentials)
             } else {
+                debug::report(request.url(), Some((&*credentials, Origin::Request)));
                 Some(credentials)
             }
         } else {
             // If we don't find a password, we'll still attempt the request with the existing credentials
+            debug::report(request.url(), Some((&*credentials, Origin::Request)));
             Some(credentials)
         };
 
@@ -462,16 +649,18 @@ impl AuthMiddleware {
             .await
     }
 
-    /// Fetch credentials for a URL.
+    /// Fetch credentials for a URL, along with their origin.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Consults the given credential sources (e.g., netrc and the keyring) in order. The cache is
+    /// checked by the caller, in its configured position (see [`split_at_cache`]).
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
         url: &DisplaySafeUrl,
         maybe_index_url: Option<&DisplaySafeUrl>,
         auth_policy: AuthPolicy,
-    ) -> Option<Arc<Credentials>> {
+        sources: &[CredentialSource],
+    ) -> Option<(Arc<Credentials>, Origin)> {
         let username = Username::from(
             credentials.map(|credentials| credentials.username().unwrap_or_default().to_string()),
         );
@@ -479,9 +668,17 @@ impl AuthMiddleware {
         // Fetches can be expensive, so we will only run them _once_ per realm or index URL and username combination
         // All other requests for the same realm or index URL will wait until the first one completes
         let key = if let Some(index_url) = maybe_index_url {
-            (FetchUrl::Index(index_url.clone()), username)
+            (
+                FetchUrl::Index(index_url.clone()),
+                username,
+                Box::from(sources),
+            )
         } else {
-            (FetchUrl::Realm(Realm::from(&**url)), username)
+            (
+                FetchUrl::Realm(Realm::from(&**url)),
+                username,
+                Box::from(sources),
+            )
         };
         if !self.cache().fetches.register(key.clone()) {
             let credentials = self
@@ -506,68 +703,188 @@ impl AuthMiddleware {
         // Support for known providers, like Hugging Face.
         if let Some(credentials) = HuggingFaceProvider::credentials_for(url).map(Arc::new) {
             debug!("Found Hugging Face credentials for {url}");
-            self.cache().fetches.done(key, Some(credentials.clone()));
-            return Some(credentials);
+            let fetched = (credentials, Origin::HuggingFace);
+            self.cache().fetches.done(key, Some(fetched.clone()));
+            return Some(fetched);
+        }
+
+        for &source in sources {
+            let fetched = match source {
+                // The cache is consulted by the caller.
+                CredentialSource::Cache => None,
+                CredentialSource::Env => self.env_credentials(credentials, url),
+                CredentialSource::Helper => {
+                    if let Some(helper) = self.indexes.credential_helper_for(url) {
+                        let index_url = maybe_index_url.unwrap_or(url);
+                        helper.fetch(index_url).await.inspect(|_| {
+                            debug!("Found credentials from credential helper for {url}");
+                        })
+                    } else {
+                        None
+                    }
+                }
+                CredentialSource::Netrc => self.netrc_credentials(credentials, url),
+                CredentialSource::Keyring => {
+                    self.keyring_credentials(credentials, url, maybe_index_url, auth_policy)
+                        .await
+                }
+            };
+            if let Some(fetched) = fetched {
+                let fetched = (Arc::new(fetched), Origin::from(source));
+                self.cache().fetches.done(key, Some(fetched.clone()));
+                return Some(fetched);
+            }
         }
 
+        // Register the fetch for this key
+        self.cache().fetches.done(key, None);
+
+        None
+    }
+
+    /// Find credentials for a URL without fetching them, i.e., from the environment or the cache
+    /// (URL key).
+    ///
+    /// The sources are consulted in order, stopping at the first source that requires a fetch,
+    /// since those are only consulted once the server requests authentication.
+    fn local_credentials(
+        &self,
+        url: &Url,
+        sources: &[CredentialSource],
+    ) -> Option<(Arc<Credentials>, Origin)> {
+        for &source in sources {
+            match source {
+                CredentialSource::Env => {
+                    if let Some(credentials) =
+                        self.env_credentials(None, DisplaySafeUrl::ref_cast(url))
+                    {
+                        return Some((Arc::new(credentials), Origin::from(source)));
+                    }
+                }
+                CredentialSource::Cache => {
+                    return self
+                        .cache()
+                        .get_url(url, &Username::none())
+                        .map(|credentials| (credentials, CACHE));
+                }
+                CredentialSource::Helper | CredentialSource::Netrc | CredentialSource::Keyring => {
+                    return None;
+                }
+            }
+        }
+        None
+    }
+
+    /// Read credentials for a URL from the `UV_INDEX_<NAME>_USERNAME` and
+    /// `UV_INDEX_<NAME>_PASSWORD` environment variables, if the URL belongs to a named index.
+    fn env_credentials(
+        &self,
+        credentials: Option<&Credentials>,
+        url: &DisplaySafeUrl,
+    ) -> Option<Credentials> {
+        let name = self.indexes.env_name_for(url)?;
+        debug!("Checking environment for credentials for {url}");
+        let found = Credentials::from_env(name)?;
+
+        // If the request includes a username, the credentials must match it.
+        if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
+            if found.username() != Some(username) {
+                debug!("Ignoring credentials from environment for {url} with a different username");
+                return None;
+            }
+        }
+
+        debug!("Found credentials in environment for {url}");
+        Some(found)
+    }
+
+    /// Read credentials for a URL from the netrc file, if enabled.
+    fn netrc_credentials(
+        &self,
+        credentials: Option<&Credentials>,
+        url: &DisplaySafeUrl,
+    ) -> Option<Credentials> {
         // Netrc support based on: <https://github.com/gribouille/netrc>.
-        let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
-            debug!("Checking netrc for credentials for {url}");
-            Credentials::from_netrc(
-                netrc,
-                url,
-                credentials
-                    .as_ref()
-                    .and_then(|credentials| credentials.username()),
-            )
-        }) {
-            debug!("Found credentials in netrc file for {url}");
-            Some(credentials)
+        let netrc = self.netrc.get()?;
+        debug!("Checking netrc for credentials for {url}");
+        let found = Credentials::from_netrc(
+            netrc,
+            url,
+            credentials
+                .as_ref()
+                .and_then(|credentials| credentials.username()),
+        )?;
+        debug!("Found credentials in netrc file for {url}");
+        Some(found)
+    }
+
+    /// Fetch credentials for a URL from the keyring, if enabled.
+    async fn keyring_credentials(
+        &self,
+        credentials: Option<&Credentials>,
+        url: &DisplaySafeUrl,
+        maybe_index_url: Option<&DisplaySafeUrl>,
+        auth_policy: AuthPolicy,
+    ) -> Option<Credentials> {
+        let keyring = self.keyring.as_ref()?;
 
         // N.B. The keyring provider performs lookups for the exact URL then falls back to the host.
         //      But, in the absence of an index URL, we cache the result per realm. So in that case,
         //      if a keyring implementation returns different credentials for different URLs in the
         //      same realm we will use the wrong credentials.
-        } else if let Some(credentials) = match self.keyring {
-            Some(ref keyring) => {
-                // The subprocess keyring provider is _slow_ so we do not perform fetches for all
-                // URLs; instead, we fetch if there's a username or if the user has requested to
-                // always authenticate.
-                if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
-                    if let Some(index_url) = maybe_index_url {
-                        debug!("Checking keyring for credentials for index URL {}@{}", username, index_url);
-                        keyring.fetch(DisplaySafeUrl::ref_cast(index_url), Some(username)).await
-                    } else {
-                        debug!("Checking keyring for credentials for full URL {}@{}", username, url);
-                        keyring.fetch(url, Some(username)).await
-                    }
-                } else if matches!(auth_policy, AuthPolicy::Always) {
-                    if let Some(index_url) = maybe_index_url {
-                        debug!(
-                            "Checking keyring for credentials for index URL {index_url} without username due to `authenticate = always`"
-                        );
-                        keyring.fetch(DisplaySafeUrl::ref_cast(index_url), None).await
-                    } else {
-                        None
-                    }
-                } else {
-                    debug!("Skipping keyring fetch for {url} without username; use `authenticate = always` to force");
-                    None
-                }
+        //
+        // The subprocess keyring provider is _slow_ so we do not perform fetches for all
+        // URLs; instead, we fetch if there's a username or if the user has requested to
+        // always authenticate.
+        let found = if let Some(username) =
+            credentials.and_then(|credentials| credentials.username())
+        {
+            if let Some(index_url) = maybe_index_url {
+                debug!(
+                    "Checking keyring for credentials for index URL {}@{}",
+                    username, index_url
+                );
+                keyring
+                    .fetch(DisplaySafeUrl::ref_cast(index_url), Some(username))
+                    .await
+            } else {
+                debug!(
+                    "Checking keyring for credentials for full URL {}@{}",
+                    username, url
+                );
+                keyring.fetch(url, Some(username)).await
+            }
+        } else if matches!(auth_policy, AuthPolicy::Always) {
+            if let Some(index_url) = maybe_index_url {
+                debug!(
+                    "Checking keyring for credentials for index URL {index_url} without username due to `authenticate = always`"
+                );
+                keyring
+                    .fetch(DisplaySafeUrl::ref_cast(index_url), None)
+                    .await
+            } else {
+                None
             }
-            None => None,
-        } {
-            debug!("Found credentials in keyring for {url}");
-            Some(credentials)
         } else {
+            debug!(
+                "Skipping keyring fetch for {url} without username; use `authenticate = always` to force"
+            );
             None
-        }
-        .map(Arc::new);
-
-        // Register the fetch for this key
-        self.cache().fetches.done(key, credentials.clone());
+        }?;
+        debug!("Found credentials in keyring for {url}");
+        Some(found)
+    }
+}
 
-        credentials
+/// Split the credential sources into those consulted before the cache, and those consulted after
+/// it. If the cache isn't among the sources, all of them precede it.
+fn split_at_cache(sources: &[CredentialSource]) -> (&[CredentialSource], &[CredentialSource]) {
+    match sources
+        .iter()
+        .position(|source| *source == CredentialSource::Cache)
+    {
+        Some(index) => (&sources[..index], &sources[index + 1..]),
+        None => (sources, &[]),
     }
 }
 
@@ -1052,6 +1369,154 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_credential_sources_order() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r"machine {} login {username} password invalid",
+            base_url.host_str().unwrap()
+        )?;
+        let keyring = || {
+            KeyringProvider::dummy([(
+                format!(
+                    "{}:{}",
+                    base_url.host_str().unwrap(),
+                    base_url.port().unwrap()
+                ),
+                username,
+                password,
+            )])
+        };
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+
+        // By default, netrc is consulted before the keyring.
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                    .with_keyring(Some(keyring())),
+            )
+            .build();
+        assert_eq!(
+            client.get(url.clone()).send().await?.status(),
+            401,
+            "The invalid netrc password should be used by default"
+        );
+
+        // With a configured order, the keyring is consulted first.
+        let indexes = indexes_with_sources(
+            &base_url,
+            vec![CredentialSource::Keyring, CredentialSource::Netrc],
+        );
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                    .with_keyring(Some(keyring()))
+                    .with_indexes(indexes),
+            )
+            .build();
+        assert_eq!(
+            client.get(url.clone()).send().await?.status(),
+            200,
+            "The keyring password should take precedence over netrc"
+        );
+
+        // Sources that aren't configured are never consulted.
+        let indexes = indexes_with_sources(&base_url, vec![CredentialSource::Netrc]);
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_keyring(Some(keyring()))
+                    .with_indexes(indexes),
+            )
+            .build();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "The keyring should not be consulted"
+        );
+
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_credential_sources_cache_position() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r"machine {} login {username} password {password}",
+            base_url.host_str().unwrap()
+        )?;
+        let stale_cache = || {
+            let cache = CredentialsCache::new();
+            cache.insert(
+                &base_url,
+                Arc::new(Credentials::basic(
+                    Some(username.to_string()),
+                    Some("invalid".to_string()),
+                )),
+            );
+            cache
+        };
+
+        // Sources listed before the cache take precedence over cached credentials.
+        let indexes = indexes_with_sources(
+            &base_url,
+            vec![CredentialSource::Netrc, CredentialSource::Cache],
+        );
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(stale_cache())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                    .with_indexes(indexes),
+            )
+            .build();
+        assert_eq!(
+            client.get(base_url.clone()).send().await?.status(),
+            200,
+            "The netrc password should take precedence over the cache"
+        );
+
+        // Sources listed after the cache are only consulted if the cache has no credentials.
+        let indexes = indexes_with_sources(
+            &base_url,
+            vec![CredentialSource::Cache, CredentialSource::Netrc],
+        );
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(stale_cache())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                    .with_indexes(indexes),
+            )
+            .build();
+        assert_eq!(
+            client.get(base_url).send().await?.status(),
+            401,
+            "The cached password should take precedence over netrc"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_keyring_always_authenticate() -> Result<(), Error> {
         let username = "user";
@@ -1758,11 +2223,19 @@ mod tests {
                 url: DisplaySafeUrl::from(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                credential_helper: None,
+                env_name: None,
+                credential_sources: None,
+                cloud_auth: None,
             },
             Index {
                 url: DisplaySafeUrl::from(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                credential_helper: None,
+                env_name: None,
+                credential_sources: None,
+                cloud_auth: None,
             },
         ]);
 
@@ -1866,6 +2339,10 @@ mod tests {
             url: DisplaySafeUrl::from(index_url.clone()),
             root_url: DisplaySafeUrl::from(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            credential_helper: None,
+            env_name: None,
+            credential_sources: None,
+            cloud_auth: None,
         }]);
 
         let client = test_client_builder()
@@ -1925,6 +2402,23 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            credential_helper: None,
+            env_name: None,
+            credential_sources: None,
+            cloud_auth: None,
+        }])
+    }
+
+    fn indexes_with_sources(url: &Url, sources: Vec<CredentialSource>) -> Indexes {
+        let url = DisplaySafeUrl::from(url.clone());
+        Indexes::from_indexes(vec![Index {
+            url: url.clone(),
+            root_url: url,
+            auth_policy: AuthPolicy::Auto,
+            credential_helper: None,
+            env_name: None,
+            credential_sources: Some(sources),
+            cloud_auth: None,
         }])
     }
 
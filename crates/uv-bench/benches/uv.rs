@@ -91,7 +91,9 @@ mod resolver {
     };
     use uv_dispatch::{BuildDispatch, SharedState};
     use uv_distribution::DistributionDatabase;
-    use uv_distribution_types::{DependencyMetadata, IndexLocations, RequiresPython};
+    use uv_distribution_types::{
+        BuildSystemOverrides, DependencyMetadata, IndexLocations, RequiresPython,
+    };
     use uv_install_wheel::LinkMode;
     use uv_pep440::Version;
     use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
@@ -130,7 +132,7 @@ mod resolver {
     );
 
     static TAGS: LazyLock<Tags> = LazyLock::new(|| {
-        Tags::from_env(&PLATFORM, (3, 11), "cpython", (3, 11), false, false).unwrap()
+        Tags::from_env(&PLATFORM, (3, 11), "cpython", (3, 11), false, false, false).unwrap()
     });
 
     pub(crate) async fn resolve(
@@ -162,6 +164,7 @@ mod resolver {
         let options = OptionsBuilder::new().exclude_newer(exclude_newer).build();
         let sources = SourceStrategy::default();
         let dependency_metadata = DependencyMetadata::default();
+        let build_system_overrides = BuildSystemOverrides::default();
         let conflicts = Conflicts::empty();
         let workspace_cache = WorkspaceCache::default();
 
@@ -182,6 +185,7 @@ mod resolver {
             &index_locations,
             &flat_index,
             &dependency_metadata,
+            &build_system_overrides,
             state,
             IndexStrategy::default(),
             &config_settings,
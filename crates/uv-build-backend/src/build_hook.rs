@@ -0,0 +1,128 @@
+//! Build hooks, which compile extension modules (e.g., with a C compiler or Cython) into the wheel.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
+use uv_static::EnvVars;
+
+use crate::{Error, PyProjectToml};
+
+/// Print the wheel tags for the running interpreter, as `<python tag> <abi tag> <platform tag>`.
+const WHEEL_TAGS_SCRIPT: &str = r#"
+import sys, sysconfig
+version = f"{sys.version_info[0]}{sys.version_info[1]}"
+if sys.implementation.name == "cpython":
+    python_tag = f"cp{version}"
+    abi_tag = python_tag + ("t" if sysconfig.get_config_var("Py_GIL_DISABLED") else "")
+    # `Py_DEBUG` isn't available on Windows, where `sys.gettotalrefcount` identifies debug builds.
+    if sysconfig.get_config_var("Py_DEBUG") or hasattr(sys, "gettotalrefcount"):
+        abi_tag += "d"
+elif sys.implementation.name == "pypy":
+    python_tag = f"pp{version}"
+    abi_tag = "_".join(sysconfig.get_config_var("SOABI").split("-")[:2])
+else:
+    raise SystemExit(f"Unsupported Python implementation: {sys.implementation.name}")
+print(python_tag, abi_tag, sysconfig.get_platform().replace("-", "_").replace(".", "_"))
+"#;
+
+/// A script that builds extension modules for the wheel, configured with
+/// `tool.uv.build-backend.build-hook`.
+#[derive(Debug)]
+pub(crate) struct BuildHook {
+    /// The path to the script, relative to the source tree.
+    script: PathBuf,
+    /// The Python interpreter of the build environment.
+    python: PathBuf,
+}
+
+impl BuildHook {
+    /// Return the build hook configured in the `pyproject.toml`, if any.
+    ///
+    /// The hook is run with the given Python interpreter, or, if none is provided, the interpreter
+    /// passed by the `uv_build` Python shims.
+    pub(crate) fn from_pyproject_toml(
+        pyproject_toml: &PyProjectToml,
+        python: Option<&Path>,
+    ) -> Result<Option<Self>, Error> {
+        let Some(script) = pyproject_toml
+            .settings()
+            .and_then(|settings| settings.build_hook.as_ref())
+        else {
+            return Ok(None);
+        };
+
+        // The interpreter is passed by the `uv_build` Python shims, since the build backend itself
+        // doesn't know which environment it's running in.
+        let python = python
+            .map(Path::to_path_buf)
+            .or_else(|| {
+                std::env::var_os(EnvVars::UV_INTERNAL__BUILD_BACKEND_PYTHON)
+                    .filter(|python| !python.is_empty())
+                    .map(PathBuf::from)
+            })
+            .ok_or_else(|| Error::BuildHookPython(script.clone()))?;
+
+        Ok(Some(Self {
+            script: script.clone(),
+            python,
+        }))
+    }
+
+    /// Run the build hook, which writes the built files to the output directory.
+    pub(crate) fn run(&self, source_tree: &Path, output_dir: &Path) -> Result<(), Error> {
+        debug!(
+            "Running build hook `{}` with output directory: `{}`",
+            self.script.user_display(),
+            output_dir.user_display()
+        );
+        let status = Command::new(&self.python)
+            .arg(&self.script)
+            .arg(output_dir)
+            .current_dir(source_tree)
+            // The build frontend reads the filename of the built artifact from stdout.
+            .stdout(Stdio::from(std::io::stderr()))
+            .status()
+            .map_err(|err| Error::BuildHookSpawn(self.script.clone(), err))?;
+        if !status.success() {
+            return Err(Error::BuildHookFailed(self.script.clone(), status));
+        }
+        Ok(())
+    }
+
+    /// Return the wheel tags for the interpreter and platform of the build environment.
+    pub(crate) fn wheel_tags(&self) -> Result<(LanguageTag, AbiTag, PlatformTag), Error> {
+        let output = Command::new(&self.python)
+            .arg("-c")
+            .arg(WHEEL_TAGS_SCRIPT)
+            .output()
+            .map_err(|err| Error::BuildHookTags(err.to_string()))?;
+        if !output.status.success() {
+            return Err(Error::BuildHookTags(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let Some((python_tag, abi_tag, platform_tag)) = stdout.split_whitespace().collect_tuple()
+        else {
+            return Err(Error::BuildHookTags(format!(
+                "Unexpected output: `{}`",
+                stdout.trim()
+            )));
+        };
+        let python_tag = LanguageTag::from_str(python_tag)
+            .map_err(|err| Error::BuildHookTags(err.to_string()))?;
+        let abi_tag =
+            AbiTag::from_str(abi_tag).map_err(|err| Error::BuildHookTags(err.to_string()))?;
+        let platform_tag = PlatformTag::from_str(platform_tag)
+            .map_err(|err| Error::BuildHookTags(err.to_string()))?;
+        debug!("Tagging wheel as `{python_tag}-{abi_tag}-{platform_tag}` for build hook");
+        Ok((python_tag, abi_tag, platform_tag))
+    }
+}
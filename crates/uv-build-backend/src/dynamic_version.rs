@@ -0,0 +1,175 @@
+//! Dynamic project versions, derived from Git tags in the style of `setuptools-scm`.
+
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use regex::Regex;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_pep440::{BumpCommand, LocalSegment, Version};
+
+use crate::Error;
+use crate::settings::{DynamicVersion, VersionSource};
+
+/// By default, strip an optional `v` prefix from the tag.
+const DEFAULT_TAG_PATTERN: &str = "^v?(?P<version>.+)$";
+
+/// Determine the project version from the configured source.
+pub(crate) fn resolve_version(
+    source_tree: &Path,
+    dynamic_version: &DynamicVersion,
+) -> Result<Version, Error> {
+    // Source distributions don't contain the Git repository, but the resolved version in
+    // `PKG-INFO`.
+    let pkg_info = source_tree.join("PKG-INFO");
+    if pkg_info.is_file() {
+        let contents = fs_err::read_to_string(&pkg_info)?;
+        if let Some(version) = contents
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .find_map(|line| line.strip_prefix("Version: "))
+        {
+            debug!(
+                "Using version from `{}`: {version}",
+                pkg_info.user_display()
+            );
+            return Version::from_str(version.trim()).map_err(|err| {
+                Error::InvalidDynamicVersion(version.to_string(), "`PKG-INFO`".to_string(), err)
+            });
+        }
+    }
+
+    let result = match dynamic_version.source {
+        VersionSource::Git => git_version(source_tree, dynamic_version.tag_pattern.as_deref()),
+    };
+    match (result, dynamic_version.fallback_version.as_deref()) {
+        (Ok(version), _) => Ok(version),
+        (Err(Error::GitVersion(err)), Some(fallback_version)) => {
+            debug!("Using fallback version {fallback_version}: {err}");
+            Version::from_str(fallback_version).map_err(|err| {
+                Error::InvalidDynamicVersion(
+                    fallback_version.to_string(),
+                    "`tool.uv.build-backend.version.fallback-version`".to_string(),
+                    err,
+                )
+            })
+        }
+        (Err(err), _) => Err(err),
+    }
+}
+
+/// Determine the version from the most recent Git tag.
+fn git_version(source_tree: &Path, tag_pattern: Option<&str>) -> Result<Version, Error> {
+    let tag_pattern = tag_pattern.unwrap_or(DEFAULT_TAG_PATTERN);
+    let tag_regex = Regex::new(tag_pattern)
+        .map_err(|err| Error::InvalidTagPattern(tag_pattern.to_string(), err))?;
+
+    let output = Command::new("git")
+        .args(["describe", "--tags", "--long", "--dirty", "--abbrev=7"])
+        .current_dir(source_tree)
+        .output()
+        .map_err(|err| Error::GitVersion(format!("Failed to run `git describe`: {err}")))?;
+    if !output.status.success() {
+        return Err(Error::GitVersion(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    let describe = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    debug!("`git describe` returned: `{describe}`");
+
+    let (tag, distance, commit, dirty) = parse_describe(&describe).ok_or_else(|| {
+        Error::GitVersion(format!("Unexpected `git describe` output: `{describe}`"))
+    })?;
+    let tag_version = tag_regex
+        .captures(tag)
+        .and_then(|captures| captures.name("version").or_else(|| captures.get(0)))
+        .ok_or_else(|| {
+            Error::GitVersion(format!(
+                "Tag `{tag}` does not match the tag pattern `{tag_pattern}`"
+            ))
+        })?
+        .as_str();
+    let version = Version::from_str(tag_version).map_err(|err| {
+        Error::InvalidDynamicVersion(tag_version.to_string(), format!("tag `{tag}`"), err)
+    })?;
+
+    Ok(scm_version(version, distance, commit, dirty))
+}
+
+/// Parse the output of `git describe --long --dirty`, e.g., `v1.2.3-4-gabc1234-dirty`, into the
+/// tag, the number of commits since the tag, the abbreviated commit hash, and whether the working
+/// tree is dirty.
+fn parse_describe(describe: &str) -> Option<(&str, u64, &str, bool)> {
+    let (describe, dirty) = match describe.strip_suffix("-dirty") {
+        Some(describe) => (describe, true),
+        None => (describe, false),
+    };
+    // Tags may contain dashes, so split from the right.
+    let (describe, commit) = describe.rsplit_once('-')?;
+    let commit = commit.strip_prefix('g')?;
+    let (tag, distance) = describe.rsplit_once('-')?;
+    let distance = distance.parse().ok()?;
+    Some((tag, distance, commit, dirty))
+}
+
+/// Compute the version for a checkout, given the version of the most recent tag.
+///
+/// If the tag is checked out in a clean working tree, the tag's version is used. Otherwise, the
+/// next version is guessed by incrementing the pre-release or the last release segment, and
+/// marked as a development release with the number of commits since the tag (e.g.,
+/// `1.2.4.dev3+g1a2b3c4` for three commits after `1.2.3`).
+fn scm_version(version: Version, distance: u64, commit: &str, dirty: bool) -> Version {
+    if distance == 0 && !dirty {
+        return version;
+    }
+
+    let mut next = version.clone().with_local_segments(Vec::new());
+    if let Some(pre) = version.pre() {
+        next.bump(BumpCommand::BumpPrerelease { kind: pre.kind });
+    } else {
+        next.bump(BumpCommand::BumpRelease {
+            index: version.release().len() - 1,
+        });
+    }
+
+    let mut local = Vec::new();
+    if distance > 0 {
+        local.push(LocalSegment::String(format!("g{commit}")));
+    }
+    if dirty {
+        local.push(LocalSegment::String("dirty".to_string()));
+    }
+    next.with_dev(Some(distance)).with_local_segments(local)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            parse_describe("v1.2.3-0-gabc1234"),
+            Some(("v1.2.3", 0, "abc1234", false))
+        );
+        assert_eq!(
+            parse_describe("release-1.2.3-4-gabc1234-dirty"),
+            Some(("release-1.2.3", 4, "abc1234", true))
+        );
+        assert_eq!(parse_describe("abc1234"), None);
+    }
+
+    #[test]
+    fn version() {
+        let version = |tag: &str, distance, dirty| {
+            scm_version(Version::from_str(tag).unwrap(), distance, "abc1234", dirty).to_string()
+        };
+        assert_eq!(version("1.2.3", 0, false), "1.2.3");
+        assert_eq!(version("1.2.3", 3, false), "1.2.4.dev3+gabc1234");
+        assert_eq!(version("1.2.3", 0, true), "1.2.4.dev0+dirty");
+        assert_eq!(version("1.2.3", 3, true), "1.2.4.dev3+gabc1234.dirty");
+        assert_eq!(version("2.0rc1", 1, false), "2.0rc2.dev1+gabc1234");
+    }
+}
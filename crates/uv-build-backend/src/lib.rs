@@ -1,16 +1,23 @@
+mod build_hook;
+mod dynamic_version;
 mod metadata;
 mod serde_verbatim;
 mod settings;
 mod source_dist;
 mod wheel;
 
-pub use metadata::{PyProjectToml, check_direct_build};
-pub use settings::{BuildBackendSettings, WheelDataIncludes};
+pub use metadata::{
+    PyProjectToml, check_direct_build, check_direct_list, has_build_hook, project_name_and_version,
+};
+pub use settings::{
+    BuildBackendSettings, DynamicVersion, Symlinks, VersionSource, WheelDataIncludes,
+};
 pub use source_dist::{build_source_dist, list_source_dist};
 pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
 
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
 use std::str::FromStr;
 use thiserror::Error;
 use tracing::debug;
@@ -19,7 +26,10 @@ use walkdir::DirEntry;
 use uv_fs::Simplified;
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
+use uv_pep440::VersionParseError;
 use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
@@ -63,6 +73,8 @@ pub enum Error {
     MissingInitPy(PathBuf),
     #[error("For namespace packages, `__init__.py[i]` is not allowed in parent directory: `{}`", _0.user_display())]
     NotANamespace(PathBuf),
+    #[error("Expected a module directory at: `{}`", _0.user_display())]
+    MissingModuleDirectory(PathBuf),
     /// Either an absolute path or a parent path through `..`.
     #[error("Module root must be inside the project: `{}`", _0.user_display())]
     InvalidModuleRoot(PathBuf),
@@ -70,6 +82,51 @@ pub enum Error {
     InconsistentSteps(&'static str),
     #[error("Failed to write to {}", _0.user_display())]
     TarWrite(PathBuf, #[source] io::Error),
+    #[error("The build hook `{}` can only be run through the `uv_build` Python package, which provides the Python interpreter", _0.user_display())]
+    BuildHookPython(PathBuf),
+    #[error("Failed to run build hook: `{}`", _0.user_display())]
+    BuildHookSpawn(PathBuf, #[source] io::Error),
+    #[error("Build hook `{}` failed ({})", _0.user_display(), _1)]
+    BuildHookFailed(PathBuf, ExitStatus),
+    #[error("Failed to determine the wheel tags for the build hook: {0}")]
+    BuildHookTags(String),
+    #[error("Invalid `tool.uv.build-backend.version.tag-pattern`: `{0}`")]
+    InvalidTagPattern(String, #[source] regex::Error),
+    #[error("Failed to determine the project version from Git: {0}")]
+    GitVersion(String),
+    #[error("Invalid version `{0}` from {1}")]
+    InvalidDynamicVersion(String, String, #[source] VersionParseError),
+    #[error(
+        "Symlink `{}` points outside the project to `{}`, which is not supported with `tool.uv.build-backend.symlinks = \"{}\"`",
+        _0.user_display(),
+        _1.user_display(),
+        _2
+    )]
+    SymlinkOutsideProject(PathBuf, PathBuf, Symlinks),
+}
+
+/// Read the modification time for the archive entries from `SOURCE_DATE_EPOCH`.
+///
+/// Without `SOURCE_DATE_EPOCH`, the archive formats use a fixed timestamp, so the builds are
+/// reproducible either way.
+///
+/// Invalid values are ignored with a warning, as other build tools do.
+///
+/// See <https://reproducible-builds.org/specs/source-date-epoch/>.
+fn source_date_epoch() -> Option<u64> {
+    let value = std::env::var_os(EnvVars::SOURCE_DATE_EPOCH)?;
+    let value = value.to_string_lossy();
+    if value.is_empty() {
+        return None;
+    }
+    let Ok(timestamp) = value.trim().parse() else {
+        warn_user_once!(
+            "Ignoring invalid `SOURCE_DATE_EPOCH`, expected a Unix timestamp: `{value}`"
+        );
+        return None;
+    };
+    debug!("Using `SOURCE_DATE_EPOCH` timestamp: {timestamp}");
+    Some(timestamp)
 }
 
 /// Dispatcher between writing to a directory, writing to a zip, writing to a `.tar.gz` and
@@ -98,9 +155,22 @@ trait DirectoryWriter {
     /// Add a local file.
     fn write_file(&mut self, path: &str, file: &Path) -> Result<(), Error>;
 
+    /// Add a file that was generated during the build, e.g., by the build hook, rather than taken
+    /// from the source tree.
+    fn write_generated_file(&mut self, path: &str, file: &Path) -> Result<(), Error> {
+        self.write_file(path, file)
+    }
+
     /// Create a directory.
     fn write_directory(&mut self, directory: &str) -> Result<(), Error>;
 
+    /// Add a symlink to a file or directory at the relative `target`.
+    ///
+    /// Writers without symlink support include the file the symlink points to instead.
+    fn write_symlink(&mut self, path: &str, link: &Path, _target: &Path) -> Result<(), Error> {
+        self.write_file(path, link)
+    }
+
     /// Write the `RECORD` file and if applicable, the central directory.
     fn close(self, dist_info_dir: &str) -> Result<(), Error>;
 }
@@ -132,6 +202,11 @@ impl DirectoryWriter for ListWriter<'_> {
         Ok(())
     }
 
+    fn write_generated_file(&mut self, path: &str, _file: &Path) -> Result<(), Error> {
+        self.files.push((path.to_string(), None));
+        Ok(())
+    }
+
     fn write_directory(&mut self, _directory: &str) -> Result<(), Error> {
         Ok(())
     }
@@ -141,6 +216,22 @@ impl DirectoryWriter for ListWriter<'_> {
     }
 }
 
+/// Check that a symlink points to a file or directory inside the source tree.
+///
+/// Returns the target of the symlink, relative to the directory containing the symlink.
+fn symlink_target(source_tree: &Path, link: &Path, symlinks: Symlinks) -> Result<PathBuf, Error> {
+    let target = fs_err::canonicalize(link)?;
+    if !target.starts_with(fs_err::canonicalize(source_tree)?) {
+        return Err(Error::SymlinkOutsideProject(
+            link.to_path_buf(),
+            target,
+            symlinks,
+        ));
+    }
+    let parent = fs_err::canonicalize(link.parent().expect("a symlink has a parent"))?;
+    Ok(uv_fs::relative_to(target, parent)?)
+}
+
 /// PEP 517 requires that the metadata directory from the prepare metadata call is identical to the
 /// build wheel call. This method performs a prudence check that `METADATA` and `entry_points.txt`
 /// match.
@@ -217,21 +308,25 @@ fn find_roots(
     debug!("Source root: {}", src_root.user_display());
 
     if namespace {
-        // `namespace = true` disables module structure checks.
+        // `namespace = true` disables the `__init__.py` checks for the module itself, but the module
+        // must exist and its parents must still be namespaces.
         let modules_relative = if let Some(module_name) = module_name {
             match module_name {
-                ModuleName::Name(name) => {
-                    vec![name.split('.').collect::<PathBuf>()]
-                }
+                ModuleName::Name(name) => vec![namespace_module_path(&src_root, name)?],
                 ModuleName::Names(names) => names
                     .iter()
-                    .map(|name| name.split('.').collect::<PathBuf>())
-                    .collect(),
+                    .map(|name| namespace_module_path(&src_root, name))
+                    .collect::<Result<_, _>>()?,
             }
         } else {
-            vec![PathBuf::from(
-                pyproject_toml.name().as_dist_info_name().to_string(),
-            )]
+            let module_relative =
+                PathBuf::from(pyproject_toml.name().as_dist_info_name().to_string());
+            if !src_root.join(&module_relative).is_dir() {
+                return Err(Error::MissingModuleDirectory(
+                    src_root.join(&module_relative),
+                ));
+            }
+            vec![module_relative]
         };
         for module_relative in &modules_relative {
             debug!("Namespace module path: {}", module_relative.user_display());
@@ -291,11 +386,49 @@ fn find_module_path_from_package_name(
     }
 }
 
+/// Determine the relative module path from an explicit module name with `namespace = true`.
+///
+/// The module itself may be an implicit namespace package without an `__init__.py`, but it must
+/// exist and, like for regular modules, its parent directories must be namespaces.
+fn namespace_module_path(src_root: &Path, module_name: &str) -> Result<PathBuf, Error> {
+    // This name can be uppercase.
+    let module_relative = module_name.split('.').collect::<PathBuf>();
+    check_module_name(module_name)?;
+
+    let module_path = src_root.join(&module_relative);
+    if !module_path.is_dir() {
+        return Err(Error::MissingModuleDirectory(module_path));
+    }
+
+    check_namespace_parents(src_root, &module_relative)?;
+
+    Ok(module_relative)
+}
+
 /// Determine the relative module path from an explicit module name.
 fn module_path_from_module_name(src_root: &Path, module_name: &str) -> Result<PathBuf, Error> {
     // This name can be uppercase.
     let module_relative = module_name.split('.').collect::<PathBuf>();
+    let stubs = check_module_name(module_name)?;
+
+    // Check that an `__init__.py[i]` exists for the module.
+    let init_py =
+        src_root
+            .join(&module_relative)
+            .join(if stubs { "__init__.pyi" } else { "__init__.py" });
+    if !init_py.is_file() {
+        return Err(Error::MissingInitPy(init_py));
+    }
+
+    check_namespace_parents(src_root, &module_relative)?;
+
+    Ok(module_relative)
+}
 
+/// Check that all segments of a module name are valid identifiers.
+///
+/// Returns whether the module is a stubs module.
+fn check_module_name(module_name: &str) -> Result<bool, Error> {
     // Check if we have a regular module or a namespace.
     let (root_name, namespace_segments) =
         if let Some((root_name, namespace_segments)) = module_name.split_once('.') {
@@ -326,16 +459,14 @@ fn module_path_from_module_name(src_root: &Path, module_name: &str) -> Result<Pa
             .map_err(|err| Error::InvalidModuleName(module_name.to_string(), err))?;
     }
 
-    // Check that an `__init__.py[i]` exists for the module.
-    let init_py =
-        src_root
-            .join(&module_relative)
-            .join(if stubs { "__init__.pyi" } else { "__init__.py" });
-    if !init_py.is_file() {
-        return Err(Error::MissingInitPy(init_py));
-    }
+    Ok(stubs)
+}
 
-    // For a namespace, check that the directories above the lowest are namespace directories.
+/// For a namespace, check that the directories above the lowest are namespace directories.
+///
+/// Other distributions contribute to the same namespace, so the parents must not contain an
+/// `__init__.py[i]` that would shadow them.
+fn check_namespace_parents(src_root: &Path, module_relative: &Path) -> Result<(), Error> {
     for namespace_dir in module_relative.ancestors().skip(1) {
         if src_root.join(namespace_dir).join("__init__.py").exists()
             || src_root.join(namespace_dir).join("__init__.pyi").exists()
@@ -343,8 +474,7 @@ fn module_path_from_module_name(src_root: &Path, module_name: &str) -> Result<Pa
             return Err(Error::NotANamespace(src_root.join(namespace_dir)));
         }
     }
-
-    Ok(module_relative)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -391,7 +521,7 @@ mod tests {
     fn build(source_root: &Path, dist: &Path) -> Result<BuildResults, Error> {
         // Build a direct wheel, capture all its properties to compare it with the indirect wheel
         // latest and remove it since it has the same filename as the indirect wheel.
-        let (_name, direct_wheel_list_files) = list_wheel(source_root, MOCK_UV_VERSION)?;
+        let (_name, direct_wheel_list_files) = list_wheel(source_root, MOCK_UV_VERSION, None)?;
         let direct_wheel_filename = build_wheel(source_root, dist, None, MOCK_UV_VERSION)?;
         let direct_wheel_path = dist.join(direct_wheel_filename.to_string());
         let direct_wheel_contents = wheel_contents(&direct_wheel_path);
@@ -402,7 +532,7 @@ mod tests {
         let (_name, source_dist_list_files) = list_source_dist(source_root, MOCK_UV_VERSION)?;
         // TODO(konsti): This should run in the unpacked source dist tempdir, but we need to
         // normalize the path.
-        let (_name, wheel_list_files) = list_wheel(source_root, MOCK_UV_VERSION)?;
+        let (_name, wheel_list_files) = list_wheel(source_root, MOCK_UV_VERSION, None)?;
         let source_dist_filename = build_source_dist(source_root, dist, MOCK_UV_VERSION)?;
         let source_dist_path = dist.join(source_dist_filename.to_string());
         let source_dist_contents = sdist_contents(&source_dist_path);
@@ -1070,7 +1200,7 @@ mod tests {
             build_err(src.path()),
             @"For namespace packages, `__init__.py[i]` is not allowed in parent directory: `[TEMP_PATH]/src/simple_namespace`"
         );
-        fs_err::remove_file(bogus_init_py).unwrap();
+        fs_err::remove_file(&bogus_init_py).unwrap();
 
         let dist = TempDir::new().unwrap();
         let build1 = build(src.path(), dist.path()).unwrap();
@@ -1112,6 +1242,25 @@ mod tests {
 
         let build2 = build(src.path(), dist.path()).unwrap();
         assert_eq!(build1, build2);
+
+        // With `namespace = true`, the parent directories must still be namespaces.
+        File::create(&bogus_init_py).unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"For namespace packages, `__init__.py[i]` is not allowed in parent directory: `[TEMP_PATH]/src/simple_namespace`"
+        );
+        fs_err::remove_file(bogus_init_py).unwrap();
+
+        // A typo in the module name is caught even without an `__init__.py` check.
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            pyproject_toml.replace("simple_namespace.part", "simple_namespace.prat"),
+        )
+        .unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"Expected a module directory at: `[TEMP_PATH]/src/simple_namespace/prat`"
+        );
     }
 
     /// A complex namespace package with a multiple root `__init__.py`.
@@ -1360,4 +1509,239 @@ mod tests {
         simple_namespace_part-1.0.0.dist-info/WHEEL
         ");
     }
+
+    /// Data includes can be single files, and Python scripts get a placeholder shebang that's
+    /// reflected in the `RECORD`.
+    #[test]
+    fn data_files_and_scripts() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "1.0.0"
+
+            [tool.uv.build-backend.data]
+            scripts = "bin"
+            data = "etc/foo.toml"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("foo")).unwrap();
+        File::create(src.path().join("src").join("foo").join("__init__.py")).unwrap();
+        fs_err::create_dir_all(src.path().join("bin")).unwrap();
+        fs_err::write(
+            src.path().join("bin").join("foo-tool"),
+            "#!/usr/bin/env python3\nprint('hello')\n",
+        )
+        .unwrap();
+        fs_err::write(
+            src.path().join("bin").join("foo-shell"),
+            "#!/bin/sh\necho hello\n",
+        )
+        .unwrap();
+        fs_err::create_dir_all(src.path().join("etc")).unwrap();
+        fs_err::write(src.path().join("etc").join("foo.toml"), "[foo]\n").unwrap();
+        fs_err::write(src.path().join("etc").join("other.toml"), "[other]\n").unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build.source_dist_contents.join("\n"), @r"
+        foo-1.0.0/
+        foo-1.0.0/PKG-INFO
+        foo-1.0.0/bin
+        foo-1.0.0/bin/foo-shell
+        foo-1.0.0/bin/foo-tool
+        foo-1.0.0/etc
+        foo-1.0.0/etc/foo.toml
+        foo-1.0.0/pyproject.toml
+        foo-1.0.0/src
+        foo-1.0.0/src/foo
+        foo-1.0.0/src/foo/__init__.py
+        ");
+        assert_snapshot!(build.wheel_contents.join("\n"), @r"
+        foo-1.0.0.data/data/
+        foo-1.0.0.data/data/foo.toml
+        foo-1.0.0.data/scripts/
+        foo-1.0.0.data/scripts/foo-shell
+        foo-1.0.0.data/scripts/foo-tool
+        foo-1.0.0.dist-info/
+        foo-1.0.0.dist-info/METADATA
+        foo-1.0.0.dist-info/RECORD
+        foo-1.0.0.dist-info/WHEEL
+        foo/
+        foo/__init__.py
+        ");
+
+        let mut wheel = zip::ZipArchive::new(
+            File::open(dist.path().join(build.wheel_filename.to_string())).unwrap(),
+        )
+        .unwrap();
+        let mut script = String::new();
+        wheel
+            .by_name("foo-1.0.0.data/scripts/foo-tool")
+            .unwrap()
+            .read_to_string(&mut script)
+            .unwrap();
+        assert_snapshot!(script, @r"
+        #!python
+        print('hello')
+        ");
+        let mut record = String::new();
+        wheel
+            .by_name("foo-1.0.0.dist-info/RECORD")
+            .unwrap()
+            .read_to_string(&mut record)
+            .unwrap();
+        let expected = format!(
+            "foo-1.0.0.data/scripts/foo-tool,sha256={:x},24",
+            sha2::Sha256::digest(script.as_bytes())
+        );
+        assert!(record.lines().any(|line| line == expected), "{record}");
+    }
+
+    /// Symlinks are followed by default, and preserved within the project on request.
+    #[test]
+    #[cfg(unix)]
+    fn symlinks() {
+        let src = TempDir::new().unwrap();
+        let shared = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("foo")).unwrap();
+        File::create(src.path().join("src").join("foo").join("__init__.py")).unwrap();
+        File::create(src.path().join("src").join("foo").join("data.json")).unwrap();
+        fs_err::os::unix::fs::symlink(
+            "data.json",
+            src.path().join("src").join("foo").join("alias.json"),
+        )
+        .unwrap();
+
+        // By default, symlinks to files are included as regular files.
+        let dist = TempDir::new().unwrap();
+        let build0 = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build0.source_dist_contents.join("\n"), @r"
+        foo-1.0.0/
+        foo-1.0.0/PKG-INFO
+        foo-1.0.0/pyproject.toml
+        foo-1.0.0/src
+        foo-1.0.0/src/foo
+        foo-1.0.0/src/foo/__init__.py
+        foo-1.0.0/src/foo/alias.json
+        foo-1.0.0/src/foo/data.json
+        ");
+        assert!(
+            build0
+                .wheel_contents
+                .contains(&"foo/alias.json".to_string())
+        );
+
+        // Code shared between projects, outside of the project.
+        File::create(shared.path().join("utils.py")).unwrap();
+        fs_err::os::unix::fs::symlink(
+            shared.path(),
+            src.path().join("src").join("foo").join("shared"),
+        )
+        .unwrap();
+
+        // With `symlinks = "follow"`, symlinked directories are included, too.
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            format!("{pyproject_toml}\n[tool.uv.build-backend]\nsymlinks = \"follow\"\n"),
+        )
+        .unwrap();
+        let dist = TempDir::new().unwrap();
+        let build1 = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build1.source_dist_contents.join("\n"), @r"
+        foo-1.0.0/
+        foo-1.0.0/PKG-INFO
+        foo-1.0.0/pyproject.toml
+        foo-1.0.0/src
+        foo-1.0.0/src/foo
+        foo-1.0.0/src/foo/__init__.py
+        foo-1.0.0/src/foo/alias.json
+        foo-1.0.0/src/foo/data.json
+        foo-1.0.0/src/foo/shared
+        foo-1.0.0/src/foo/shared/utils.py
+        ");
+        assert_snapshot!(build1.wheel_contents.join("\n"), @r"
+        foo-1.0.0.dist-info/
+        foo-1.0.0.dist-info/METADATA
+        foo-1.0.0.dist-info/RECORD
+        foo-1.0.0.dist-info/WHEEL
+        foo/
+        foo/__init__.py
+        foo/alias.json
+        foo/data.json
+        foo/shared/
+        foo/shared/utils.py
+        ");
+
+        // With `symlinks = "contained"`, symlinks must not point outside the project.
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            format!("{pyproject_toml}\n[tool.uv.build-backend]\nsymlinks = \"contained\"\n"),
+        )
+        .unwrap();
+        let dist = TempDir::new().unwrap();
+        let err = build(src.path(), dist.path()).unwrap_err();
+        assert!(
+            matches!(err, Error::SymlinkOutsideProject(.., Symlinks::Contained)),
+            "{}",
+            format_err(&err)
+        );
+
+        // The same applies to `symlinks = "preserve"`.
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            format!("{pyproject_toml}\n[tool.uv.build-backend]\nsymlinks = \"preserve\"\n"),
+        )
+        .unwrap();
+        let dist = TempDir::new().unwrap();
+        let err = build(src.path(), dist.path()).unwrap_err();
+        assert!(
+            matches!(err, Error::SymlinkOutsideProject(.., Symlinks::Preserve)),
+            "{}",
+            format_err(&err)
+        );
+
+        // Symlinks within the project are kept in the source distribution, but not in the wheel.
+        fs_err::remove_file(src.path().join("src").join("foo").join("shared")).unwrap();
+        let build2 = build(src.path(), dist.path()).unwrap();
+        let source_dist = dist.path().join(build2.source_dist_filename.to_string());
+        let mut source_dist = tar::Archive::new(GzDecoder::new(BufReader::new(
+            File::open(&source_dist).unwrap(),
+        )));
+        let links: Vec<_> = source_dist
+            .entries()
+            .unwrap()
+            .map(Result::unwrap)
+            .filter(|entry| entry.header().entry_type() == tar::EntryType::Symlink)
+            .map(|entry| {
+                format!(
+                    "{} -> {}",
+                    entry.path().unwrap().display(),
+                    entry.link_name().unwrap().unwrap().display()
+                )
+            })
+            .collect();
+        assert_eq!(links, ["foo-1.0.0/src/foo/alias.json -> data.json"]);
+        assert!(
+            build2
+                .wheel_contents
+                .contains(&"foo/alias.json".to_string())
+        );
+    }
 }
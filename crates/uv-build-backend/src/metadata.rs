@@ -44,8 +44,16 @@ pub enum ValidationError {
     UnsupportedContentType(String),
     #[error("`project.description` must be a single line")]
     DescriptionNewlines,
-    #[error("Dynamic metadata is not supported")]
+    #[error("Dynamic metadata is not supported, except for `version`")]
     Dynamic,
+    #[error("`project.version` is missing")]
+    MissingVersion,
+    #[error("`project.version` must not be set when it's listed in `project.dynamic`")]
+    StaticAndDynamicVersion,
+    #[error(
+        "A dynamic `project.version` requires a version source in `tool.uv.build-backend.version`, e.g., `version = {{ source = \"git\" }}`"
+    )]
+    MissingVersionSource,
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -64,6 +72,40 @@ pub enum ValidationError {
 
 /// Check if the build backend is matching the currently running uv version.
 pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
+    check_uv_build_backend(source_tree, name, false)
+}
+
+/// Check if the files of the distributions can be listed by the currently running uv version.
+///
+/// Unlike [`check_direct_build`], projects with a build hook are supported, as long as the caller
+/// provides a Python interpreter to run the hook with.
+pub fn check_direct_list(source_tree: &Path, name: impl Display) -> bool {
+    check_uv_build_backend(source_tree, name, true)
+}
+
+/// Whether the project in the source tree configures a build hook, which requires a Python
+/// interpreter to build or list the wheel.
+pub fn has_build_hook(source_tree: &Path) -> bool {
+    fs_err::read_to_string(source_tree.join("pyproject.toml"))
+        .ok()
+        .and_then(|pyproject_toml| toml::from_str::<PyProjectToml>(&pyproject_toml).ok())
+        .is_some_and(|pyproject_toml| pyproject_toml.has_build_hook())
+}
+
+/// Read the name and version of the project in the source tree, if it has a valid
+/// `pyproject.toml`.
+pub fn project_name_and_version(source_tree: &Path) -> Option<(PackageName, Version)> {
+    let pyproject_toml = fs_err::read_to_string(source_tree.join("pyproject.toml")).ok()?;
+    let pyproject_toml = PyProjectToml::parse(&pyproject_toml).ok()?;
+    Some((
+        pyproject_toml.name().clone(),
+        pyproject_toml.version().clone(),
+    ))
+}
+
+/// Check if the build backend is matching the currently running uv version, optionally allowing
+/// a build hook.
+fn check_uv_build_backend(source_tree: &Path, name: impl Display, allow_build_hook: bool) -> bool {
     let pyproject_toml: PyProjectToml =
         match fs_err::read_to_string(source_tree.join("pyproject.toml"))
             .map_err(|err| err.to_string())
@@ -78,6 +120,11 @@ pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
                 return false;
             }
         };
+    // Build hooks run Python code, so they require a Python interpreter.
+    if !allow_build_hook && pyproject_toml.has_build_hook() {
+        debug!("Not using uv build backend direct build of {name}, a build hook is configured");
+        return false;
+    }
     match pyproject_toml
         .check_build_system(uv_version::version())
         .as_slice()
@@ -119,13 +166,52 @@ impl PyProjectToml {
     }
 
     pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+        self.project
+            .version
+            .as_ref()
+            .expect("dynamic version was resolved when reading the source tree")
     }
 
     pub(crate) fn parse(contents: &str) -> Result<Self, Error> {
         Ok(toml::from_str(contents)?)
     }
 
+    /// Read the `pyproject.toml` from the source tree, resolving a dynamic version.
+    pub(crate) fn from_source_tree(source_tree: &Path) -> Result<Self, Error> {
+        let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
+        let mut pyproject_toml = Self::parse(&contents)?;
+        pyproject_toml.resolve_version(source_tree)?;
+        Ok(pyproject_toml)
+    }
+
+    /// Determine the project version if `project.version` is dynamic.
+    fn resolve_version(&mut self, source_tree: &Path) -> Result<(), Error> {
+        let dynamic = self
+            .project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "version");
+        match (&self.project.version, dynamic) {
+            (Some(_), false) => Ok(()),
+            (Some(_), true) => Err(ValidationError::StaticAndDynamicVersion.into()),
+            (None, false) => Err(ValidationError::MissingVersion.into()),
+            (None, true) => {
+                let Some(dynamic_version) = self
+                    .settings()
+                    .and_then(|settings| settings.version.as_ref())
+                else {
+                    return Err(ValidationError::MissingVersionSource.into());
+                };
+                self.project.version = Some(crate::dynamic_version::resolve_version(
+                    source_tree,
+                    dynamic_version,
+                )?);
+                Ok(())
+            }
+        }
+    }
+
     pub(crate) fn readme(&self) -> Option<&Readme> {
         self.project.readme.as_ref()
     }
@@ -161,6 +247,12 @@ impl PyProjectToml {
         self.tool.as_ref()?.uv.as_ref()?.build_backend.as_ref()
     }
 
+    /// Whether a build hook is configured with `tool.uv.build-backend.build-hook`.
+    pub(crate) fn has_build_hook(&self) -> bool {
+        self.settings()
+            .is_some_and(|settings| settings.build_hook.is_some())
+    }
+
     /// Returns user-facing warnings if the `[build-system]` table looks suspicious.
     ///
     /// Example of a valid table:
@@ -191,14 +283,21 @@ impl PyProjectToml {
             )
         };
 
-        let [uv_requirement] = &self.build_system.requires.as_slice() else {
-            warnings.push(expected());
-            return warnings;
+        // Build hooks may require further packages, such as Cython.
+        let uv_requirement = if self.has_build_hook() {
+            self.build_system
+                .requires
+                .iter()
+                .find(|requirement| requirement.name.as_str() == "uv-build")
+        } else if let [uv_requirement] = self.build_system.requires.as_slice() {
+            Some(uv_requirement).filter(|requirement| requirement.name.as_str() == "uv-build")
+        } else {
+            None
         };
-        if uv_requirement.name.as_str() != "uv-build" {
+        let Some(uv_requirement) = uv_requirement else {
             warnings.push(expected());
             return warnings;
-        }
+        };
         let bounded = match &uv_requirement.version_or_url {
             None => false,
             Some(VersionOrUrl::Url(_)) => {
@@ -305,11 +404,13 @@ impl PyProjectToml {
             None => (None, None),
         };
 
+        // A dynamic version is resolved when reading the source tree.
         if self
             .project
             .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
+            .iter()
+            .flatten()
+            .any(|field| field != "version")
         {
             return Err(ValidationError::Dynamic.into());
         }
@@ -521,7 +622,7 @@ impl PyProjectToml {
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.to_string(),
-            version: self.project.version.to_string(),
+            version: self.version().to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -645,7 +746,9 @@ struct Project {
     /// The name of the project.
     name: PackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// Only optional if the version is dynamic.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -694,7 +797,7 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     ///
-    /// Not supported, an error if anything but the default empty list.
+    /// Only `version` is supported, when `tool.uv.build-backend.version` is set.
     dynamic: Option<Vec<String>>,
 }
 
@@ -1202,6 +1305,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_system_build_hook() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            version = "0.1.0"
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0", "cython"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            build-hook = "build.py"
+        "#};
+        let pyproject_toml = PyProjectToml::parse(contents).unwrap();
+        assert_snapshot!(
+            pyproject_toml.check_build_system("0.4.15+test").join("\n"),
+            @""
+        );
+    }
+
     #[test]
     fn build_system_no_requires_uv() {
         let contents = indoc! {r#"
@@ -1379,7 +1503,7 @@ mod tests {
             .unwrap_err();
         assert_snapshot!(format_err(err), @r###"
         Invalid pyproject.toml
-          Caused by: Dynamic metadata is not supported
+          Caused by: Dynamic metadata is not supported, except for `version`
         "###);
     }
 
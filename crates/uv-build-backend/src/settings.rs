@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
 use std::path::PathBuf;
 use uv_macros::OptionsMetadata;
 
@@ -92,6 +93,9 @@ pub struct BuildBackendSettings {
     /// Use this option when the namespace package contains multiple root `__init__.py`, for
     /// namespace packages with a single root `__init__.py` use a dotted `module-name` instead.
     ///
+    /// The module directory must exist, and for a dotted `module-name`, its parent directories
+    /// must not contain an `__init__.py`, since they are shared with other distributions.
+    ///
     /// To compare dotted `module-name` and `namespace = true`, the first example below can be
     /// expressed with `module-name = "cloud.database"`: There is one root `__init__.py` `database`.
     /// In the second example, we have three roots (`cloud.database`, `cloud.database_pro`,
@@ -138,7 +142,8 @@ pub struct BuildBackendSettings {
     /// Data includes for wheels.
     ///
     /// Each entry is a directory, whose contents are copied to the matching directory in the wheel
-    /// in `<name>-<version>.data/(purelib|platlib|headers|scripts|data)`. Upon installation, this
+    /// in `<name>-<version>.data/(purelib|platlib|headers|scripts|data)`, or a single file, which is
+    /// copied into that directory. Upon installation, this
     /// data is moved to its target location, as defined by
     /// <https://docs.python.org/3.12/library/sysconfig.html#installation-paths>. Usually, small
     /// data files are included by placing them in the Python module instead of using data includes.
@@ -147,6 +152,8 @@ pub struct BuildBackendSettings {
     ///   `<venv>\Scripts` on Windows. This directory is added to `PATH` when the virtual
     ///   environment  is activated or when using `uv run`, so this data type can be used to install
     ///   additional binaries. Consider using `project.scripts` instead for Python entrypoints.
+    ///   Python scripts with a shebang such as `#!/usr/bin/env python3` are rewritten to use the
+    ///   interpreter of the environment they are installed into.
     /// - `data`: Installed over the virtualenv environment root.
     ///
     ///     Warning: This may override existing files!
@@ -168,6 +175,69 @@ pub struct BuildBackendSettings {
         example = r#"data = { headers = "include/headers", scripts = "bin" }"#
     )]
     pub data: WheelDataIncludes,
+
+    /// A Python script that builds extension modules for the wheel, relative to the project root.
+    ///
+    /// The script is run in the project root with the Python interpreter of the build environment,
+    /// and receives the directory to write the built files to as its only argument. The files are
+    /// added to the wheel relative to that directory, e.g., an extension module for the `foo`
+    /// package would be written to `foo/_speedups.cpython-312-x86_64-linux-gnu.so`. For editable
+    /// installs, the directory is the module root, such that extension modules are built in place.
+    ///
+    /// When a build hook is configured, the wheel is tagged for the interpreter and platform of the
+    /// build environment (e.g., `cp312-cp312-linux_x86_64`) rather than `py3-none-any`, and the
+    /// script is always included in the source distribution. Any packages the script needs, such as
+    /// Cython, can be added to `build-system.requires`.
+    #[option(
+        default = r#"None"#,
+        value_type = "str",
+        example = r#"build-hook = "build.py""#
+    )]
+    pub build_hook: Option<PathBuf>,
+
+    /// Derive the project version from Git tags, for projects that declare
+    /// `dynamic = ["version"]`.
+    ///
+    /// The version is determined with `git describe`. `tag-pattern` is a regular expression that
+    /// extracts the version from the tag, through a `version` capture group; the default pattern
+    /// strips an optional `v` prefix.
+    ///
+    /// If the latest tag is checked out and the working tree is clean, the tag's version is used
+    /// as-is. Otherwise, the next version is guessed, in the style of `setuptools-scm`: for example,
+    /// three commits after the `v1.2.3` tag, the version is `1.2.4.dev3+g<commit>`, with an
+    /// additional `dirty` local segment if there are uncommitted changes.
+    ///
+    /// Source distributions contain the resolved version, such that building a wheel from a
+    /// source distribution doesn't require Git. If Git or a matching tag isn't available, the
+    /// `fallback-version` is used, if set.
+    #[option(
+        default = r#"None"#,
+        value_type = "dict",
+        example = r#"version = { source = "git", tag-pattern = "^release-(?P<version>.+)$" }"#
+    )]
+    pub version: Option<DynamicVersion>,
+
+    /// How to handle symlinks in the project.
+    ///
+    /// - `copy`: Symlinks to files are included as regular files with the contents of the file
+    ///   they point to. Symlinks to directories are not followed.
+    /// - `follow`: Symlinks, including symlinks to directories, are followed, and the files they
+    ///   point to are included as regular files. This includes symlinks that point outside the
+    ///   project, e.g., to code shared between projects in a monorepo, such that the source
+    ///   distribution is self-contained.
+    /// - `contained`: Like `follow`, but symlinks that point outside the project are an error.
+    /// - `preserve`: Symlinks are kept as symlinks in the source distribution. Since wheels can't
+    ///   contain symlinks, the files they point to are included as regular files in the wheel.
+    ///   Symlinks that point outside the project are an error.
+    ///
+    /// In all modes, includes and excludes match the path of the symlink, not the path of the
+    /// file it points to.
+    #[option(
+        default = r#""copy""#,
+        value_type = "str",
+        example = r#"symlinks = "contained""#
+    )]
+    pub symlinks: Symlinks,
 }
 
 impl Default for BuildBackendSettings {
@@ -181,6 +251,9 @@ impl Default for BuildBackendSettings {
             wheel_exclude: Vec::new(),
             namespace: false,
             data: WheelDataIncludes::default(),
+            build_hook: None,
+            version: None,
+            symlinks: Symlinks::default(),
         }
     }
 }
@@ -225,3 +298,71 @@ impl WheelDataIncludes {
         .filter_map(|(name, value)| Some((name, value?)))
     }
 }
+
+/// The source of a dynamic project version.
+///
+/// See `BuildBackendSettings::version`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DynamicVersion {
+    /// Where to read the version from.
+    pub source: VersionSource,
+    /// A regular expression that extracts the version from a tag through a `version` capture
+    /// group.
+    pub tag_pattern: Option<String>,
+    /// The version to use if the version can't be determined from the source.
+    pub fallback_version: Option<String>,
+}
+
+/// Where to read a dynamic project version from.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VersionSource {
+    /// The most recent Git tag, as reported by `git describe`.
+    Git,
+}
+
+/// How to handle symlinks in the project.
+///
+/// See `BuildBackendSettings::symlinks`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Symlinks {
+    /// Include the files that symlinks point to as regular files, without following symlinks to
+    /// directories.
+    #[default]
+    Copy,
+    /// Follow symlinks, including symlinks to directories, and include the files they point to as
+    /// regular files.
+    Follow,
+    /// Follow symlinks like `follow`, but reject symlinks that point outside the project.
+    Contained,
+    /// Keep symlinks within the project as symlinks in the source distribution.
+    Preserve,
+}
+
+impl Symlinks {
+    /// Whether to descend into symlinked directories when collecting files.
+    pub(crate) fn follow_links(self) -> bool {
+        matches!(self, Self::Follow | Self::Contained)
+    }
+
+    /// Whether symlinks that point outside the project are an error.
+    pub(crate) fn reject_outside_project(self) -> bool {
+        matches!(self, Self::Contained | Self::Preserve)
+    }
+}
+
+impl Display for Symlinks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Copy => f.write_str("copy"),
+            Self::Follow => f.write_str("follow"),
+            Self::Contained => f.write_str("contained"),
+            Self::Preserve => f.write_str("preserve"),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::wheel::build_exclude_matcher;
 use crate::{
-    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, find_roots,
+    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, Symlinks,
+    find_roots, source_date_epoch, symlink_target,
 };
 use flate2::Compression;
 use flate2::write::GzEncoder;
@@ -24,15 +25,14 @@ pub fn build_source_dist(
     source_dist_directory: &Path,
     uv_version: &str,
 ) -> Result<SourceDistFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
         extension: SourceDistExtension::TarGz,
     };
     let source_dist_path = source_dist_directory.join(filename.to_string());
-    let writer = TarGzWriter::new(&source_dist_path)?;
+    let writer = TarGzWriter::new(&source_dist_path, source_date_epoch().unwrap_or_default())?;
     write_source_dist(source_tree, writer, uv_version)?;
     Ok(filename)
 }
@@ -42,8 +42,7 @@ pub fn list_source_dist(
     source_tree: &Path,
     uv_version: &str,
 ) -> Result<(SourceDistFilename, FileList), Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
@@ -66,6 +65,10 @@ fn source_dist_matcher(
     let mut includes: Vec<String> = settings.source_include;
     // pyproject.toml is always included.
     includes.push(globset::escape("pyproject.toml"));
+    // The build hook is required to build the wheel.
+    if let Some(build_hook) = &settings.build_hook {
+        includes.push(globset::escape(&build_hook.portable_display().to_string()));
+    }
 
     // Check that the source tree contains a module.
     let (src_root, modules_relative) = find_roots(
@@ -122,16 +125,18 @@ fn source_dist_matcher(
     }
 
     // Include the data files
-    for (name, directory) in settings.data.iter() {
-        let directory = uv_fs::normalize_path(Path::new(directory));
-        trace!(
-            "Including data ({}) at: `{}`",
-            name,
-            directory.user_display()
-        );
-        let directory = directory.portable_display().to_string();
+    for (name, path) in settings.data.iter() {
+        let path = uv_fs::normalize_path(Path::new(path));
+        trace!("Including data ({}) at: `{}`", name, path.user_display());
+        let escaped = globset::escape(&path.portable_display().to_string());
+        // Data includes are either a directory or a single file.
+        let glob = if source_tree.join(&path).is_file() {
+            escaped
+        } else {
+            format!("{escaped}/**")
+        };
         let glob = PortableGlobParser::Uv
-            .parse(&format!("{}/**", globset::escape(&directory)))
+            .parse(&glob)
             .map_err(|err| Error::PortableGlob {
                 field: format!("tool.uv.build-backend.data.{name}"),
                 source: err,
@@ -176,8 +181,7 @@ fn write_source_dist(
     mut writer: impl DirectoryWriter,
     uv_version: &str,
 ) -> Result<SourceDistFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
@@ -210,11 +214,13 @@ fn write_source_dist(
         metadata_email.as_bytes(),
     )?;
 
+    let symlinks = settings.symlinks;
     let (include_matcher, exclude_matcher) =
         source_dist_matcher(source_tree, &pyproject_toml, settings)?;
 
     let mut files_visited = 0;
     for entry in WalkDir::new(source_tree)
+        .follow_links(symlinks.follow_links())
         .sort_by_file_name()
         .into_iter()
         .filter_entry(|entry| {
@@ -260,6 +266,19 @@ fn write_source_dist(
             .join(relative)
             .portable_display()
             .to_string();
+        if symlinks == Symlinks::Contained && entry.path_is_symlink() {
+            symlink_target(source_tree, entry.path(), symlinks)?;
+        }
+        if symlinks == Symlinks::Preserve && entry.path_is_symlink() {
+            let target = symlink_target(source_tree, entry.path(), symlinks)?;
+            debug!(
+                "Adding symlink to sdist: {} -> {}",
+                relative.user_display(),
+                target.user_display()
+            );
+            writer.write_symlink(&entry_path, entry.path(), &target)?;
+            continue;
+        }
         debug!("Adding to sdist: {}", relative.user_display());
         writer.write_dir_entry(&entry, &entry_path)?;
     }
@@ -273,15 +292,17 @@ fn write_source_dist(
 struct TarGzWriter {
     path: PathBuf,
     tar: tar::Builder<GzEncoder<File>>,
+    /// The modification time of all entries, for reproducibility.
+    mtime: u64,
 }
 
 impl TarGzWriter {
-    fn new(path: impl Into<PathBuf>) -> Result<Self, Error> {
+    fn new(path: impl Into<PathBuf>, mtime: u64) -> Result<Self, Error> {
         let path = path.into();
         let file = File::create(&path)?;
         let enc = GzEncoder::new(file, Compression::default());
         let tar = tar::Builder::new(enc);
-        Ok(Self { path, tar })
+        Ok(Self { path, tar, mtime })
     }
 }
 
@@ -289,6 +310,7 @@ impl DirectoryWriter for TarGzWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         let mut header = Header::new_gnu();
         header.set_size(bytes.len() as u64);
+        header.set_mtime(self.mtime);
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
         header.set_mode(0o644);
@@ -320,6 +342,7 @@ impl DirectoryWriter for TarGzWriter {
             header.set_mode(0o644);
         }
         header.set_size(metadata.len());
+        header.set_mtime(self.mtime);
         let reader = BufReader::new(File::open(file)?);
         self.tar
             .append_data(&mut header, path, reader)
@@ -333,12 +356,25 @@ impl DirectoryWriter for TarGzWriter {
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
         header.set_size(0);
+        header.set_mtime(self.mtime);
         self.tar
             .append_data(&mut header, directory, io::empty())
             .map_err(|err| Error::TarWrite(self.path.clone(), err))?;
         Ok(())
     }
 
+    fn write_symlink(&mut self, path: &str, _link: &Path, target: &Path) -> Result<(), Error> {
+        let mut header = Header::new_gnu();
+        header.set_mode(0o777);
+        header.set_entry_type(EntryType::Symlink);
+        header.set_size(0);
+        header.set_mtime(self.mtime);
+        self.tar
+            .append_link(&mut header, path, target.portable_display().to_string())
+            .map_err(|err| Error::TarWrite(self.path.clone(), err))?;
+        Ok(())
+    }
+
     fn close(mut self, _dist_info_dir: &str) -> Result<(), Error> {
         self.tar
             .finish()
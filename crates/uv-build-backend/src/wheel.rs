@@ -8,7 +8,7 @@ use std::path::{Path, PathBuf};
 use std::{io, mem};
 use tracing::{debug, trace};
 use walkdir::WalkDir;
-use zip::{CompressionMethod, ZipWriter};
+use zip::{CompressionMethod, DateTime, ZipWriter};
 
 use uv_distribution_filename::WheelFilename;
 use uv_fs::Simplified;
@@ -16,9 +16,11 @@ use uv_globfilter::{GlobDirFilter, PortableGlobParser};
 use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
 use uv_warnings::warn_user_once;
 
+use crate::build_hook::BuildHook;
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
-    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, find_roots,
+    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, Symlinks,
+    find_roots, source_date_epoch, symlink_target,
 };
 
 /// Build a wheel from the source tree and place it in the output directory.
@@ -28,32 +30,25 @@ pub fn build_wheel(
     metadata_directory: Option<&Path>,
     uv_version: &str,
 ) -> Result<WheelFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let build_hook = BuildHook::from_pyproject_toml(&pyproject_toml, None)?;
+    let filename = wheel_filename(&pyproject_toml, build_hook.as_ref())?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
-    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let wheel_writer =
+        ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?, source_date_epoch());
 
     write_wheel(
         source_tree,
         &pyproject_toml,
         &filename,
+        build_hook.as_ref(),
         uv_version,
         wheel_writer,
     )?;
@@ -65,27 +60,27 @@ pub fn build_wheel(
 pub fn list_wheel(
     source_tree: &Path,
     uv_version: &str,
+    python: Option<&Path>,
 ) -> Result<(WheelFilename, FileList), Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    // The files built by the build hook can only be listed by running it.
+    let build_hook = BuildHook::from_pyproject_toml(&pyproject_toml, python)?;
+    let filename = wheel_filename(&pyproject_toml, build_hook.as_ref())?;
 
     let mut files = FileList::new();
     let writer = ListWriter::new(&mut files);
-    write_wheel(source_tree, &pyproject_toml, &filename, uv_version, writer)?;
+    write_wheel(
+        source_tree,
+        &pyproject_toml,
+        &filename,
+        build_hook.as_ref(),
+        uv_version,
+        writer,
+    )?;
     Ok((filename, files))
 }
 
@@ -93,6 +88,7 @@ fn write_wheel(
     source_tree: &Path,
     pyproject_toml: &PyProjectToml,
     filename: &WheelFilename,
+    build_hook: Option<&BuildHook>,
     uv_version: &str,
     mut wheel_writer: impl DirectoryWriter,
 ) -> Result<(), Error> {
@@ -146,7 +142,10 @@ fn write_wheel(
             }
         }
 
+        // Wheels can't contain symlinks, so we include the files they point to, and follow
+        // symlinked directories unless the project uses the default `copy` mode.
         for entry in WalkDir::new(src_root.join(module_relative))
+            .follow_links(settings.symlinks != Symlinks::Copy)
             .sort_by_file_name()
             .into_iter()
             .filter_entry(|entry| !exclude_matcher.is_match(entry.path()))
@@ -155,6 +154,9 @@ fn write_wheel(
                 root: source_tree.to_path_buf(),
                 err,
             })?;
+            if settings.symlinks.reject_outside_project() && entry.path_is_symlink() {
+                symlink_target(source_tree, entry.path(), settings.symlinks)?;
+            }
 
             files_visited += 1;
             if files_visited > 10000 {
@@ -186,6 +188,30 @@ fn write_wheel(
     }
     debug!("Visited {files_visited} files for wheel build");
 
+    // Add the files built by the build hook
+    if let Some(build_hook) = build_hook {
+        let build_dir = tempfile::tempdir()?;
+        build_hook.run(source_tree, build_dir.path())?;
+        for entry in WalkDir::new(build_dir.path()).sort_by_file_name() {
+            let entry = entry.map_err(|err| Error::WalkDir {
+                root: build_dir.path().to_path_buf(),
+                err,
+            })?;
+            // Directories are already created for the module files.
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let entry_path = entry
+                .path()
+                .strip_prefix(build_dir.path())
+                .expect("walkdir starts with root")
+                .portable_display()
+                .to_string();
+            debug!("Adding build hook output to wheel: {entry_path}");
+            wheel_writer.write_generated_file(&entry_path, entry.path())?;
+        }
+    }
+
     // Add the license files
     if pyproject_toml.license_files_wheel().next().is_some() {
         debug!("Adding license files");
@@ -205,8 +231,8 @@ fn write_wheel(
     }
 
     // Add the data files
-    for (name, directory) in settings.data.iter() {
-        debug!("Adding {name} data files from: `{directory}`");
+    for (name, path) in settings.data.iter() {
+        debug!("Adding {name} data files from: `{path}`");
         let data_dir = format!(
             "{}-{}.data/{}/",
             pyproject_toml.name().as_dist_info_name(),
@@ -214,12 +240,13 @@ fn write_wheel(
             name
         );
 
-        wheel_subdir_from_globs(
-            &source_tree.join(directory),
+        wheel_data(
+            source_tree,
+            &source_tree.join(path),
+            name,
             &data_dir,
-            &["**".to_string()],
+            settings.symlinks,
             &mut wheel_writer,
-            &format!("tool.uv.build-backend.data.{name}"),
         )?;
     }
 
@@ -243,8 +270,7 @@ pub fn build_editable(
     metadata_directory: Option<&Path>,
     uv_version: &str,
 ) -> Result<WheelFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
@@ -255,20 +281,13 @@ pub fn build_editable(
 
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let build_hook = BuildHook::from_pyproject_toml(&pyproject_toml, None)?;
+    let filename = wheel_filename(&pyproject_toml, build_hook.as_ref())?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
-    let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let mut wheel_writer =
+        ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?, source_date_epoch());
 
     debug!("Adding pth file to {}", wheel_path.user_display());
     // Check that a module root exists in the directory we're linking from the `.pth` file
@@ -280,6 +299,11 @@ pub fn build_editable(
         settings.namespace,
     )?;
 
+    // Build extension modules in place, next to the Python files they're imported with.
+    if let Some(build_hook) = &build_hook {
+        build_hook.run(source_tree, &src_root)?;
+    }
+
     wheel_writer.write_bytes(
         &format!("{}.pth", pyproject_toml.name().as_dist_info_name()),
         src_root.as_os_str().as_encoded_bytes(),
@@ -304,22 +328,13 @@ pub fn metadata(
     metadata_directory: &Path,
     uv_version: &str,
 ) -> Result<String, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::from_source_tree(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let build_hook = BuildHook::from_pyproject_toml(&pyproject_toml, None)?;
+    let filename = wheel_filename(&pyproject_toml, build_hook.as_ref())?;
 
     debug!(
         "Writing metadata files to {}",
@@ -338,6 +353,32 @@ pub fn metadata(
     Ok(dist_info_dir)
 }
 
+/// Return the filename for the wheel, which is platform-specific if it's built with a build hook.
+fn wheel_filename(
+    pyproject_toml: &PyProjectToml,
+    build_hook: Option<&BuildHook>,
+) -> Result<WheelFilename, Error> {
+    let (python_tag, abi_tag, platform_tag) = if let Some(build_hook) = build_hook {
+        build_hook.wheel_tags()?
+    } else {
+        (
+            LanguageTag::Python {
+                major: 3,
+                minor: None,
+            },
+            AbiTag::None,
+            PlatformTag::Any,
+        )
+    };
+    Ok(WheelFilename::new(
+        pyproject_toml.name().clone(),
+        pyproject_toml.version().clone(),
+        python_tag,
+        abi_tag,
+        platform_tag,
+    ))
+}
+
 /// An entry in the `RECORD` file.
 ///
 /// <https://packaging.python.org/en/latest/specifications/recording-installed-packages/#the-record-file>
@@ -525,6 +566,105 @@ fn wheel_subdir_from_globs(
     Ok(())
 }
 
+/// Add a data directory or a single data file to the `.data` subdirectory of the wheel.
+///
+/// Python scripts get a `#!python` shebang, which installers replace with the interpreter of the
+/// target environment.
+fn wheel_data(
+    source_tree: &Path,
+    src: &Path,
+    name: &str,
+    target: &str,
+    symlinks: Symlinks,
+    wheel_writer: &mut impl DirectoryWriter,
+) -> Result<(), Error> {
+    wheel_writer.write_directory(target)?;
+
+    for entry in WalkDir::new(src)
+        .follow_links(symlinks != Symlinks::Copy)
+        .sort_by_file_name()
+    {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: src.to_path_buf(),
+            err,
+        })?;
+        if symlinks.reject_outside_project() && entry.path_is_symlink() {
+            symlink_target(source_tree, entry.path(), symlinks)?;
+        }
+
+        let relative = if entry.path() == src {
+            // The directory itself is already included as `target`.
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            // A single file is added to the data directory by its file name.
+            Path::new(entry.file_name())
+        } else {
+            entry
+                .path()
+                .strip_prefix(src)
+                .expect("walkdir starts with root")
+        };
+
+        let data_path = Path::new(target)
+            .join(relative)
+            .portable_display()
+            .to_string();
+        debug!(
+            "Adding for tool.uv.build-backend.data.{name}: `{}`",
+            relative.user_display()
+        );
+        if name == "scripts" && entry.file_type().is_file() {
+            let contents = fs_err::read(entry.path())?;
+            if let Some(script) = rewrite_python_shebang(&contents) {
+                trace!("Rewriting shebang of Python script: `{data_path}`");
+                wheel_writer.write_bytes(&data_path, &script)?;
+                continue;
+            }
+        }
+        wheel_writer.write_dir_entry(&entry, &data_path)?;
+    }
+    Ok(())
+}
+
+/// If the script has a Python shebang, such as `#!/usr/bin/env python3`, replace it with the
+/// `#!python` (or `#!pythonw`) placeholder from the binary distribution format.
+///
+/// Returns `None` for all other files, including binaries and scripts for other interpreters.
+fn rewrite_python_shebang(contents: &[u8]) -> Option<Vec<u8>> {
+    let rest = contents.strip_prefix(b"#!")?;
+    let line_end = rest
+        .iter()
+        .position(|byte| matches!(byte, b'\r' | b'\n'))
+        .unwrap_or(rest.len());
+    let (first_line, rest) = rest.split_at(line_end);
+    let first_line = std::str::from_utf8(first_line).ok()?;
+
+    let mut words = first_line.split_whitespace();
+    let mut interpreter = words.next()?.rsplit(['/', '\\']).next()?;
+    if interpreter == "env" {
+        interpreter = words.find(|word| !word.starts_with('-'))?;
+    }
+    let interpreter = interpreter.strip_suffix(".exe").unwrap_or(interpreter);
+    let (interpreter, gui) = match interpreter.strip_suffix('w') {
+        Some(interpreter) => (interpreter, true),
+        None => (interpreter, false),
+    };
+    let version = interpreter.strip_prefix("python")?;
+    if !version.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    let mut script = if gui {
+        b"#!pythonw".to_vec()
+    } else {
+        b"#!python".to_vec()
+    };
+    // Keep the original line ending.
+    script.extend_from_slice(rest);
+    Some(script)
+}
+
 /// Add `METADATA` and `entry_points.txt` to the dist-info directory.
 ///
 /// Returns the name of the dist-info directory.
@@ -570,7 +710,15 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
     let mut wheel_info = vec![
         ("Wheel-Version", "1.0".to_string()),
         ("Generator", format!("uv {uv_version}")),
-        ("Root-Is-Purelib", "true".to_string()),
+        // Wheels with extension modules are installed to `platlib`.
+        (
+            "Root-Is-Purelib",
+            filename
+                .platform_tags()
+                .iter()
+                .all(|platform_tag| *platform_tag == PlatformTag::Any)
+                .to_string(),
+        ),
     ];
     for python_tag in filename.python_tags() {
         for abi_tag in filename.abi_tags() {
@@ -589,16 +737,21 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
 struct ZipDirectoryWriter {
     writer: ZipWriter<File>,
     compression: CompressionMethod,
+    /// The modification time of all entries, for reproducibility.
+    last_modified_time: DateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
 
 impl ZipDirectoryWriter {
     /// A wheel writer with deflate compression.
-    fn new_wheel(file: File) -> Self {
+    ///
+    /// Without a `SOURCE_DATE_EPOCH` timestamp, the entries use the earliest zip timestamp.
+    fn new_wheel(file: File, source_date_epoch: Option<u64>) -> Self {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Deflated,
+            last_modified_time: source_date_epoch.map(zip_date_time).unwrap_or_default(),
             record: Vec::new(),
         }
     }
@@ -611,6 +764,7 @@ impl ZipDirectoryWriter {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Stored,
+            last_modified_time: DateTime::default(),
             record: Vec::new(),
         }
     }
@@ -625,7 +779,8 @@ impl ZipDirectoryWriter {
         let permissions = if executable_bit { 775 } else { 664 };
         let options = zip::write::SimpleFileOptions::default()
             .unix_permissions(permissions)
-            .compression_method(self.compression);
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified_time);
         self.writer.start_file(path, options)?;
         Ok(Box::new(&mut self.writer))
     }
@@ -634,7 +789,9 @@ impl ZipDirectoryWriter {
 impl DirectoryWriter for ZipDirectoryWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         trace!("Adding {}", path);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified_time);
         self.writer.start_file(path, options)?;
         self.writer.write_all(bytes)?;
 
@@ -669,7 +826,9 @@ impl DirectoryWriter for ZipDirectoryWriter {
 
     fn write_directory(&mut self, directory: &str) -> Result<(), Error> {
         trace!("Adding directory {}", directory);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified_time);
         Ok(self.writer.add_directory(directory, options)?)
     }
 
@@ -690,6 +849,36 @@ impl DirectoryWriter for ZipDirectoryWriter {
     }
 }
 
+/// Convert a Unix timestamp to a zip timestamp in UTC.
+///
+/// Zip timestamps can only represent the years 1980 to 2107, earlier and later timestamps are
+/// clamped.
+fn zip_date_time(timestamp: u64) -> DateTime {
+    let latest = || {
+        DateTime::from_date_and_time(2107, 12, 31, 23, 59, 58)
+            .expect("The latest zip timestamp is valid")
+    };
+    let Some(date_time) = i64::try_from(timestamp)
+        .ok()
+        .and_then(|timestamp| jiff::Timestamp::from_second(timestamp).ok())
+        .map(|timestamp| timestamp.to_zoned(jiff::tz::TimeZone::UTC).datetime())
+    else {
+        return latest();
+    };
+    if date_time.year() < 1980 {
+        return DateTime::default();
+    }
+    DateTime::from_date_and_time(
+        date_time.year().unsigned_abs(),
+        date_time.month().unsigned_abs(),
+        date_time.day().unsigned_abs(),
+        date_time.hour().unsigned_abs(),
+        date_time.minute().unsigned_abs(),
+        date_time.second().unsigned_abs(),
+    )
+    .unwrap_or_else(|_| latest())
+}
+
 struct FilesystemWriter {
     /// The virtualenv or metadata directory that add file paths are relative to.
     root: PathBuf,
@@ -788,6 +977,53 @@ mod test {
     ");
     }
 
+    #[test]
+    fn test_zip_date_time() {
+        let format = |timestamp| {
+            let date_time = zip_date_time(timestamp);
+            format!(
+                "{}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date_time.year(),
+                date_time.month(),
+                date_time.day(),
+                date_time.hour(),
+                date_time.minute(),
+                date_time.second()
+            )
+        };
+        // Before 1980, clamped.
+        assert_eq!(format(0), "1980-01-01T00:00:00");
+        assert_eq!(format(315_532_800), "1980-01-01T00:00:00");
+        assert_eq!(format(1_709_210_096), "2024-02-29T12:34:56");
+        assert_eq!(format(1_735_689_598), "2024-12-31T23:59:58");
+        // After 2107, clamped.
+        assert_eq!(format(u64::from(u32::MAX) * 2), "2107-12-31T23:59:58");
+    }
+
+    #[test]
+    fn test_rewrite_python_shebang() {
+        let rewrite = |script: &str| {
+            rewrite_python_shebang(script.as_bytes())
+                .map(|script| String::from_utf8(script).unwrap())
+        };
+        assert_eq!(
+            rewrite("#!/usr/bin/env python3\nprint(1)\n").as_deref(),
+            Some("#!python\nprint(1)\n")
+        );
+        assert_eq!(
+            rewrite("#!/usr/bin/python3.12 -u\r\nprint(1)\r\n").as_deref(),
+            Some("#!python\r\nprint(1)\r\n")
+        );
+        assert_eq!(
+            rewrite("#!/usr/bin/env -S pythonw\n").as_deref(),
+            Some("#!pythonw\n")
+        );
+        assert_eq!(rewrite("#!python").as_deref(), Some("#!python"));
+        assert_eq!(rewrite("#!/bin/bash\necho hi\n"), None);
+        assert_eq!(rewrite("#!/usr/bin/env python-config\n"), None);
+        assert_eq!(rewrite("print(1)\n"), None);
+    }
+
     #[test]
     fn test_record() {
         let record = vec![RecordEntry {
@@ -0,0 +1,481 @@
+//! Verify that the platform tags of a built wheel are justified by its shared libraries, in the
+//! style of `auditwheel show`.
+//!
+//! Only `manylinux` tags are audited: each ELF file in the wheel may only link against the
+//! libraries in the manylinux policy (or libraries bundled in the wheel), may only require glibc
+//! symbol versions up to the glibc version of the tag, and may only require the `libstdc++` symbol
+//! versions shipped by the reference distribution of the policy.
+//!
+//! <https://peps.python.org/pep-0600/>
+
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use fs_err as fs;
+use goblin::elf::Elf;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_distribution_filename::WheelFilename;
+use uv_platform_tags::PlatformTag;
+
+/// The libraries that `manylinux1` wheels may link against without bundling them, as defined in
+/// PEP 513.
+const MANYLINUX1_LIBRARIES: &[&str] = &[
+    "libpanelw.so.5",
+    "libncursesw.so.5",
+    "libgcc_s.so.1",
+    "libstdc++.so.6",
+    "libm.so.6",
+    "libdl.so.2",
+    "librt.so.1",
+    "libcrypt.so.1",
+    "libc.so.6",
+    "libnsl.so.1",
+    "libutil.so.1",
+    "libpthread.so.0",
+    "libX11.so.6",
+    "libXext.so.6",
+    "libXrender.so.1",
+    "libICE.so.6",
+    "libSM.so.6",
+    "libGL.so.1",
+    "libgobject-2.0.so.0",
+    "libgthread-2.0.so.0",
+    "libglib-2.0.so.0",
+];
+
+/// The libraries that `manylinux2010` and later wheels may link against without bundling them, as
+/// defined in PEP 571 and PEP 599, and carried forward by PEP 600.
+const ALLOWED_LIBRARIES: &[&str] = &[
+    "libgcc_s.so.1",
+    "libstdc++.so.6",
+    "libm.so.6",
+    "libdl.so.2",
+    "librt.so.1",
+    "libc.so.6",
+    "libnsl.so.1",
+    "libutil.so.1",
+    "libpthread.so.0",
+    "libresolv.so.2",
+    "libX11.so.6",
+    "libXext.so.6",
+    "libXrender.so.1",
+    "libICE.so.6",
+    "libSM.so.6",
+    "libGL.so.1",
+    "libgobject-2.0.so.0",
+    "libgthread-2.0.so.0",
+    "libglib-2.0.so.0",
+];
+
+/// The newest `libstdc++` symbol versions available on the reference distribution of a manylinux
+/// policy, as in the `auditwheel` policies.
+struct CxxPolicy {
+    /// The glibc version of the policy.
+    glibc: (u16, u16),
+    /// The newest `GLIBCXX_*` symbol version.
+    glibcxx: [u16; 3],
+    /// The newest `CXXABI_*` symbol version.
+    cxxabi: [u16; 3],
+}
+
+const CXX_POLICIES: &[CxxPolicy] = &[
+    // `manylinux1`: CentOS 5, GCC 4.1
+    CxxPolicy {
+        glibc: (2, 5),
+        glibcxx: [3, 4, 8],
+        cxxabi: [1, 3, 1],
+    },
+    // `manylinux2010`: CentOS 6, GCC 4.4
+    CxxPolicy {
+        glibc: (2, 12),
+        glibcxx: [3, 4, 13],
+        cxxabi: [1, 3, 3],
+    },
+    // `manylinux2014`: CentOS 7, GCC 4.8
+    CxxPolicy {
+        glibc: (2, 17),
+        glibcxx: [3, 4, 19],
+        cxxabi: [1, 3, 7],
+    },
+    // `manylinux_2_24`: Debian 9, GCC 6
+    CxxPolicy {
+        glibc: (2, 24),
+        glibcxx: [3, 4, 22],
+        cxxabi: [1, 3, 10],
+    },
+    // `manylinux_2_28`: AlmaLinux 8, GCC 8
+    CxxPolicy {
+        glibc: (2, 28),
+        glibcxx: [3, 4, 25],
+        cxxabi: [1, 3, 11],
+    },
+    // `manylinux_2_31`: Ubuntu 20.04, GCC 10
+    CxxPolicy {
+        glibc: (2, 31),
+        glibcxx: [3, 4, 28],
+        cxxabi: [1, 3, 12],
+    },
+    // `manylinux_2_34`: AlmaLinux 9, GCC 11
+    CxxPolicy {
+        glibc: (2, 34),
+        glibcxx: [3, 4, 29],
+        cxxabi: [1, 3, 13],
+    },
+    // `manylinux_2_35`: Ubuntu 22.04, GCC 12
+    CxxPolicy {
+        glibc: (2, 35),
+        glibcxx: [3, 4, 30],
+        cxxabi: [1, 3, 13],
+    },
+];
+
+/// The policy constraints that apply to a manylinux platform tag.
+struct Policy<'a> {
+    tag: &'a PlatformTag,
+    /// The maximum glibc version.
+    glibc: (u16, u16),
+    /// The libraries that may be linked against without bundling them.
+    libraries: &'static [&'static str],
+    /// The `libstdc++` symbol versions, or `None` for glibc versions newer than the known
+    /// policies.
+    cxx: Option<&'static CxxPolicy>,
+}
+
+impl<'a> Policy<'a> {
+    /// Return the policy for a manylinux platform tag, or `None` for any other platform tag.
+    fn from_tag(tag: &'a PlatformTag) -> Option<Self> {
+        let glibc = glibc_version(tag)?;
+        let libraries = if glibc < (2, 12) {
+            MANYLINUX1_LIBRARIES
+        } else {
+            ALLOWED_LIBRARIES
+        };
+        let cxx = if CXX_POLICIES
+            .last()
+            .is_some_and(|newest| glibc > newest.glibc)
+        {
+            None
+        } else {
+            CXX_POLICIES
+                .iter()
+                .rev()
+                .find(|policy| policy.glibc <= glibc)
+        };
+        Some(Self {
+            tag,
+            glibc,
+            libraries,
+            cxx,
+        })
+    }
+
+    /// Whether the policy allows the given symbol version.
+    fn allows_symbol_version(&self, symbol_version: &str) -> bool {
+        if let Some(required) = parse_glibc_symbol_version(symbol_version) {
+            return required <= self.glibc;
+        }
+        let Some(cxx) = self.cxx else {
+            return true;
+        };
+        if let Some(required) = parse_symbol_version(symbol_version, "GLIBCXX_") {
+            return required <= cxx.glibcxx;
+        }
+        if let Some(required) = parse_symbol_version(symbol_version, "CXXABI_") {
+            return required <= cxx.cxxabi;
+        }
+        true
+    }
+}
+
+/// A reason why a wheel doesn't conform to its platform tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AuditViolation {
+    /// A shared library links against a library that is neither allowed nor bundled.
+    DisallowedLibrary {
+        file: String,
+        library: String,
+        tag: PlatformTag,
+    },
+    /// A shared library requires a glibc or `libstdc++` symbol version that's newer than the tag
+    /// allows.
+    SymbolVersion {
+        file: String,
+        symbol_version: String,
+        tag: PlatformTag,
+    },
+}
+
+impl std::fmt::Display for AuditViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DisallowedLibrary { file, library, tag } => write!(
+                f,
+                "`{file}` links against `{library}`, which is not allowed for `{tag}`"
+            ),
+            Self::SymbolVersion {
+                file,
+                symbol_version,
+                tag,
+            } => write!(
+                f,
+                "`{file}` requires `{symbol_version}`, which is newer than allowed for `{tag}`"
+            ),
+        }
+    }
+}
+
+/// Return the maximum glibc version allowed by a manylinux platform tag, or `None` for any other
+/// platform tag.
+fn glibc_version(tag: &PlatformTag) -> Option<(u16, u16)> {
+    match tag {
+        PlatformTag::Manylinux { major, minor, .. } => Some((*major, *minor)),
+        PlatformTag::Manylinux1 { .. } => Some((2, 5)),
+        PlatformTag::Manylinux2010 { .. } => Some((2, 12)),
+        PlatformTag::Manylinux2014 { .. } => Some((2, 17)),
+        _ => None,
+    }
+}
+
+/// Parse a glibc symbol version, e.g., `GLIBC_2.17`, into its major and minor version.
+fn parse_glibc_symbol_version(symbol_version: &str) -> Option<(u16, u16)> {
+    let version = symbol_version.strip_prefix("GLIBC_")?;
+    let mut parts = version.split('.');
+    let major = u16::from_str(parts.next()?).ok()?;
+    let minor = parts
+        .next()
+        .map_or(Some(0), |minor| u16::from_str(minor).ok())?;
+    Some((major, minor))
+}
+
+/// Parse a symbol version with the given prefix, e.g., `GLIBCXX_3.4.21`, into its version
+/// components.
+fn parse_symbol_version(symbol_version: &str, prefix: &str) -> Option<[u16; 3]> {
+    let version = symbol_version.strip_prefix(prefix)?;
+    let mut components = [0; 3];
+    for (index, part) in version.split('.').enumerate() {
+        *components.get_mut(index)? = u16::from_str(part).ok()?;
+    }
+    Some(components)
+}
+
+/// Whether a library is the dynamic linker, which is always available.
+fn is_dynamic_linker(library: &str) -> bool {
+    library.starts_with("ld-linux") || library.starts_with("ld64.so")
+}
+
+/// Audit the shared libraries of a built wheel against its manylinux platform tags.
+///
+/// Returns an empty list for wheels without a manylinux tag.
+pub(crate) fn audit_wheel(path: &Path) -> Result<Vec<AuditViolation>, std::io::Error> {
+    let Some(filename) = path
+        .file_name()
+        .and_then(|filename| filename.to_str())
+        .and_then(|filename| WheelFilename::from_str(filename).ok())
+    else {
+        return Ok(Vec::new());
+    };
+    let policies: Vec<Policy> = filename
+        .platform_tags()
+        .iter()
+        .filter_map(Policy::from_tag)
+        .collect();
+    if policies.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut archive = zip::ZipArchive::new(fs::File::open(path)?).map_err(std::io::Error::other)?;
+
+    // Libraries vendored into the wheel, e.g., by `auditwheel repair`, may be linked against.
+    let bundled: FxHashSet<String> = archive
+        .file_names()
+        .filter_map(|name| name.rsplit('/').next())
+        .map(ToString::to_string)
+        .collect();
+
+    let mut violations = Vec::new();
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).map_err(std::io::Error::other)?;
+        if file.is_dir() {
+            continue;
+        }
+        let name = file.name().to_string();
+
+        // Only read the contents of ELF files.
+        let mut magic = [0u8; 4];
+        if file.read_exact(&mut magic).is_err() || magic != *b"\x7fELF" {
+            continue;
+        }
+        let mut contents = magic.to_vec();
+        file.read_to_end(&mut contents)?;
+
+        let elf = match Elf::parse(&contents) {
+            Ok(elf) => elf,
+            Err(err) => {
+                debug!("Skipping audit of `{name}`, which is not a valid ELF file: {err}");
+                continue;
+            }
+        };
+
+        for library in &elf.libraries {
+            if is_dynamic_linker(library) || bundled.contains(*library) {
+                continue;
+            }
+            for policy in &policies {
+                if !policy.libraries.contains(library) {
+                    violations.push(AuditViolation::DisallowedLibrary {
+                        file: name.clone(),
+                        library: (*library).to_string(),
+                        tag: policy.tag.clone(),
+                    });
+                }
+            }
+        }
+
+        let Some(verneed) = &elf.verneed else {
+            continue;
+        };
+        let mut symbol_versions = FxHashSet::default();
+        for need in verneed {
+            for aux in &need {
+                let Some(symbol_version) = elf.dynstrtab.get_at(aux.vna_name) else {
+                    continue;
+                };
+                if !symbol_versions.insert(symbol_version) {
+                    continue;
+                }
+                for policy in &policies {
+                    if !policy.allows_symbol_version(symbol_version) {
+                        violations.push(AuditViolation::SymbolVersion {
+                            file: name.clone(),
+                            symbol_version: symbol_version.to_string(),
+                            tag: policy.tag.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uv_platform_tags::Arch;
+
+    #[test]
+    fn glibc_symbol_version() {
+        assert_eq!(parse_glibc_symbol_version("GLIBC_2.17"), Some((2, 17)));
+        assert_eq!(parse_glibc_symbol_version("GLIBC_2.2.5"), Some((2, 2)));
+        assert_eq!(parse_glibc_symbol_version("GLIBC_2"), Some((2, 0)));
+        assert_eq!(parse_glibc_symbol_version("GLIBC_PRIVATE"), None);
+        assert_eq!(parse_glibc_symbol_version("GLIBCXX_3.4.21"), None);
+    }
+
+    #[test]
+    fn symbol_version() {
+        assert_eq!(
+            parse_symbol_version("GLIBCXX_3.4.21", "GLIBCXX_"),
+            Some([3, 4, 21])
+        );
+        assert_eq!(
+            parse_symbol_version("CXXABI_1.3", "CXXABI_"),
+            Some([1, 3, 0])
+        );
+        assert_eq!(parse_symbol_version("CXXABI_TM_1", "CXXABI_"), None);
+        assert_eq!(parse_symbol_version("GLIBC_2.17", "GLIBCXX_"), None);
+    }
+
+    #[test]
+    fn policy_libraries() {
+        let manylinux1 = PlatformTag::Manylinux1 { arch: Arch::X86_64 };
+        let policy = Policy::from_tag(&manylinux1).unwrap();
+        assert!(policy.libraries.contains(&"libcrypt.so.1"));
+        assert!(!policy.libraries.contains(&"libresolv.so.2"));
+
+        let manylinux2014 = PlatformTag::Manylinux2014 { arch: Arch::X86_64 };
+        let policy = Policy::from_tag(&manylinux2014).unwrap();
+        assert!(!policy.libraries.contains(&"libcrypt.so.1"));
+        assert!(policy.libraries.contains(&"libresolv.so.2"));
+        assert!(!policy.allows_symbol_version("GLIBCXX_3.4.21"));
+        assert!(policy.allows_symbol_version("GLIBCXX_3.4.19"));
+
+        // Newer glibc versions than the known policies only check glibc symbol versions.
+        let manylinux_2_40 = PlatformTag::Manylinux {
+            major: 2,
+            minor: 40,
+            arch: Arch::X86_64,
+        };
+        let policy = Policy::from_tag(&manylinux_2_40).unwrap();
+        assert!(policy.allows_symbol_version("GLIBCXX_3.4.33"));
+        assert!(!policy.allows_symbol_version("GLIBC_2.41"));
+    }
+
+    /// Write a wheel that contains the `libaudit.so` fixture.
+    fn fixture_wheel(dir: &Path, filename: &str) -> std::path::PathBuf {
+        let library = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-data/audit/libaudit.so");
+        let path = dir.join(filename);
+        let mut writer = zip::ZipWriter::new(fs::File::create(&path).unwrap());
+        writer
+            .start_file("foo/libaudit.so", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        std::io::Write::write_all(&mut writer, &fs::read(library).unwrap()).unwrap();
+        writer.finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn audit_manylinux2014_wheel() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = fixture_wheel(
+            temp_dir.path(),
+            "foo-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl",
+        );
+        let mut violations: Vec<String> = audit_wheel(&path)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        violations.sort();
+        insta::assert_debug_snapshot!(violations, @r#"
+        [
+            "`foo/libaudit.so` links against `libz.so.1`, which is not allowed for `manylinux_2_17_x86_64`",
+            "`foo/libaudit.so` requires `CXXABI_1.3.9`, which is newer than allowed for `manylinux_2_17_x86_64`",
+            "`foo/libaudit.so` requires `GLIBCXX_3.4.21`, which is newer than allowed for `manylinux_2_17_x86_64`",
+        ]
+        "#);
+    }
+
+    #[test]
+    fn audit_manylinux_2_28_wheel() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = fixture_wheel(
+            temp_dir.path(),
+            "foo-1.0.0-cp312-cp312-manylinux_2_28_x86_64.whl",
+        );
+        let violations: Vec<String> = audit_wheel(&path)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        insta::assert_debug_snapshot!(violations, @r#"
+        [
+            "`foo/libaudit.so` links against `libz.so.1`, which is not allowed for `manylinux_2_28_x86_64`",
+        ]
+        "#);
+    }
+
+    #[test]
+    fn audit_pure_python_wheel() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("foo-1.0.0-py3-none-any.whl");
+        fs::write(&path, b"").unwrap();
+        // Wheels without a manylinux tag aren't opened.
+        assert_eq!(audit_wheel(&path).unwrap(), Vec::new());
+    }
+}
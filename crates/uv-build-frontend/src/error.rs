@@ -74,6 +74,8 @@ pub enum Error {
     RequirementsInstall(&'static str, #[source] AnyErrorBuild),
     #[error("Failed to create temporary virtualenv")]
     Virtualenv(#[from] uv_virtualenv::Error),
+    #[error("Failed to read cached build environment")]
+    CachedEnvironment(#[source] uv_python::Error),
     // Build backend errors
     #[error("Failed to run `{0}`")]
     CommandFailed(PathBuf, #[source] io::Error),
@@ -103,6 +105,7 @@ impl IsBuildBackendError for Error {
             | Self::RequirementsResolve(_, _)
             | Self::RequirementsInstall(_, _)
             | Self::Virtualenv(_)
+            | Self::CachedEnvironment(_)
             | Self::NoSourceDistBuild(_)
             | Self::NoSourceDistBuilds
             | Self::CyclicBuildDependency(_) => false,
@@ -2,8 +2,10 @@
 //!
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
+mod audit;
 mod error;
 
+use std::cell::Cell;
 use std::ffi::OsString;
 use std::fmt::Formatter;
 use std::fmt::Write;
@@ -18,29 +20,32 @@ use std::{env, iter};
 use fs_err as fs;
 use indoc::formatdoc;
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use serde::de::{self, IntoDeserializer, SeqAccess, Visitor, value};
 use serde::{Deserialize, Deserializer};
 use tempfile::TempDir;
 use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
-use tokio::sync::{Mutex, Semaphore};
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
 use tracing::{Instrument, debug, info_span, instrument, warn};
 
-use uv_cache_key::cache_digest;
-use uv_configuration::Preview;
+use uv_cache::{CacheBucket, rm_rf};
+use uv_cache_info::Timestamp;
+use uv_cache_key::{cache_digest, hash_digest};
 use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
+use uv_configuration::{Preview, PreviewFeatures};
 use uv_distribution::BuildRequires;
-use uv_distribution_types::{IndexLocations, Requirement, Resolution};
+use uv_distribution_types::{IndexLocations, Name, Requirement, Resolution};
 use uv_fs::LockedFile;
 use uv_fs::{PythonExt, Simplified};
 use uv_pep440::Version;
 use uv_pep508::PackageName;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{Interpreter, PythonEnvironment};
+use uv_python::{Interpreter, PythonEnvironment, canonicalize_executable};
 use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBuildTrait};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::WorkspaceCache;
 
 pub use crate::error::{Error, MissingHeaderCause};
@@ -211,10 +216,72 @@ impl Pep517Backend {
 }
 
 /// Uses an [`Rc`] internally, clone freely.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct SourceBuildContext {
     /// An in-memory resolution of the default backend's requirements for PEP 517 builds.
     default_resolution: Rc<Mutex<Option<Resolution>>>,
+    /// The build backend invocations shared by all builds, to bound the number of concurrent
+    /// builds and to attribute their interleaved output.
+    jobs: Rc<BuildJobs>,
+}
+
+impl SourceBuildContext {
+    /// Create a [`SourceBuildContext`] that runs at most `concurrent_builds` build backend
+    /// invocations at a time.
+    pub fn new(concurrent_builds: usize) -> Self {
+        Self {
+            default_resolution: Rc::default(),
+            jobs: Rc::new(BuildJobs::new(concurrent_builds)),
+        }
+    }
+}
+
+/// The build backend invocations across all builds.
+#[derive(Debug)]
+struct BuildJobs {
+    /// Limits the number of build backend invocations that run concurrently.
+    permits: Semaphore,
+    /// The number of build backend invocations that are currently running.
+    running: Cell<usize>,
+}
+
+impl BuildJobs {
+    fn new(concurrent_builds: usize) -> Self {
+        Self {
+            permits: Semaphore::new(concurrent_builds),
+            running: Cell::new(0),
+        }
+    }
+
+    /// Wait for a free job slot, and hold it until the returned guard is dropped.
+    async fn acquire(&self) -> BuildJob<'_> {
+        let permit = self.permits.acquire().await.unwrap();
+        self.running.set(self.running.get() + 1);
+        BuildJob {
+            jobs: self,
+            _permit: permit,
+        }
+    }
+}
+
+/// A running build backend invocation.
+struct BuildJob<'a> {
+    jobs: &'a BuildJobs,
+    _permit: SemaphorePermit<'a>,
+}
+
+impl BuildJob<'_> {
+    /// Whether other build backend invocations are running concurrently with this one, such
+    /// that their output may be interleaved.
+    fn is_concurrent(&self) -> bool {
+        self.jobs.running.get() > 1
+    }
+}
+
+impl Drop for BuildJob<'_> {
+    fn drop(&mut self) {
+        self.jobs.running.set(self.jobs.running.get() - 1);
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -259,6 +326,8 @@ pub struct SourceBuild {
     environment_variables: FxHashMap<OsString, OsString>,
     /// Runner for Python scripts.
     runner: PythonRunner,
+    /// Whether to audit the shared libraries of built wheels against their platform tags.
+    audit_wheels: bool,
 }
 
 impl SourceBuild {
@@ -285,10 +354,11 @@ impl SourceBuild {
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
         level: BuildOutput,
-        concurrent_builds: usize,
+        log_dir: Option<&Path>,
         preview: Preview,
     ) -> Result<Self, Error> {
         let temp_dir = build_context.cache().venv_dir()?;
+        let jobs = source_build_context.jobs.clone();
 
         let source_tree = if let Some(subdir) = subdirectory {
             source.join(subdir)
@@ -299,7 +369,7 @@ impl SourceBuild {
         let default_backend: Pep517Backend = DEFAULT_BACKEND.clone();
 
         // Check if we have a PEP 517 build backend.
-        let (pep517_backend, project) = Self::extract_pep517_backend(
+        let (mut pep517_backend, project) = Self::extract_pep517_backend(
             &source_tree,
             install_path,
             fallback_package_name,
@@ -322,45 +392,71 @@ impl SourceBuild {
             .or(fallback_package_version)
             .cloned();
 
-        // Create a virtual environment, or install into the shared environment if requested.
-        let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
-            venv.clone()
-        } else {
-            uv_virtualenv::create_venv(
-                temp_dir.path(),
-                interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                uv_virtualenv::OnExisting::Remove,
-                false,
-                false,
-                false,
-                preview,
-            )?
-        };
-
-        // Set up the build environment. If build isolation is disabled, we assume the build
-        // environment is already setup.
-        if build_isolation.is_isolated(package_name.as_ref()) {
-            debug!("Resolving build requirements");
-
-            let resolved_requirements = Self::get_resolved_requirements(
-                build_context,
-                source_build_context,
-                &default_backend,
-                &pep517_backend,
-                build_stack,
-            )
-            .await?;
-
+        // Apply any user-defined override of the `[build-system]` table.
+        if let Some(build_system_override) = package_name.as_ref().and_then(|package_name| {
             build_context
-                .install(&resolved_requirements, &venv, build_stack)
-                .await
-                .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err.into()))?;
-        } else {
-            debug!("Proceeding without build isolation");
+                .build_system_overrides()
+                .get(package_name, package_version.as_ref())
+        }) {
+            if let Some(requires) = &build_system_override.requires {
+                debug!(
+                    "Overriding build requirements with: {}",
+                    requires.iter().join(", ")
+                );
+                pep517_backend.requirements =
+                    requires.iter().cloned().map(Requirement::from).collect();
+            }
+            if let Some(build_backend) = &build_system_override.build_backend {
+                debug!("Overriding build backend with: `{build_backend}`");
+                pep517_backend.backend.clone_from(build_backend);
+                pep517_backend.backend_path = None;
+            }
         }
 
+        // Install into the shared environment if requested, or set up an isolated build
+        // environment. If build isolation is disabled, we assume the build environment is already
+        // setup.
+        let (mut venv, shared_environment) =
+            if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
+                debug!("Proceeding without build isolation");
+                (venv.clone(), None)
+            } else if build_isolation.is_isolated(package_name.as_ref()) {
+                debug!("Resolving build requirements");
+
+                let resolved_requirements = Self::get_resolved_requirements(
+                    build_context,
+                    source_build_context,
+                    &default_backend,
+                    &pep517_backend,
+                    build_stack,
+                )
+                .await?;
+
+                build_environment(
+                    build_context,
+                    interpreter,
+                    &resolved_requirements,
+                    build_stack,
+                    temp_dir.path(),
+                    preview,
+                )
+                .await?
+            } else {
+                debug!("Proceeding without build isolation");
+                let venv = uv_virtualenv::create_venv(
+                    temp_dir.path(),
+                    interpreter.clone(),
+                    uv_virtualenv::Prompt::None,
+                    false,
+                    uv_virtualenv::OnExisting::Remove,
+                    false,
+                    false,
+                    false,
+                    preview,
+                )?;
+                (venv, None)
+            };
+
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
         let user_path = environment_variables.remove(&OsString::from(EnvVars::PATH));
@@ -384,25 +480,29 @@ impl SourceBuild {
             os_path
         };
 
-        // Prepend the venv bin directory to the modified path
-        let modified_path = if let Some(path) = modified_path {
-            let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(&path));
-            env::join_paths(venv_path).map_err(Error::BuildScriptPath)?
-        } else {
-            OsString::from(venv.scripts())
-        };
-
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(concurrent_builds, level);
+        let log_file = if let Some(log_dir) = log_dir {
+            let log_file = log_dir.join(format!(
+                "{}-{build_kind}.log",
+                log_identifier(package_name.as_ref(), package_version.as_ref(), version_id)
+            ));
+            start_log(&log_file, &source_tree, build_kind)?;
+            Some(log_file)
+        } else {
+            None
+        };
+        let mut runner = PythonRunner::new(jobs, level, log_file, shared_environment);
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            if let Some((extra_venv, extra_shared_environment)) = create_pep517_build_environment(
                 &runner,
                 &source_tree,
                 install_path,
                 &venv,
+                runner.shared_environment.is_some(),
+                interpreter,
                 &pep517_backend,
                 build_context,
                 package_name.as_ref(),
@@ -416,12 +516,20 @@ impl SourceBuild {
                 level,
                 &config_settings,
                 &environment_variables,
-                &modified_path,
+                &venv_path(&venv, modified_path.as_ref())?,
                 &temp_dir,
+                preview,
             )
-            .await?;
+            .await?
+            {
+                venv = extra_venv;
+                runner.shared_environment = extra_shared_environment;
+            }
         }
 
+        // Prepend the venv bin directory to the modified path
+        let modified_path = venv_path(&venv, modified_path.as_ref())?;
+
         Ok(Self {
             temp_dir,
             source_tree,
@@ -438,9 +546,25 @@ impl SourceBuild {
             environment_variables,
             modified_path,
             runner,
+            audit_wheels: preview.is_enabled(PreviewFeatures::AUDIT_WHEELS),
         })
     }
 
+    /// Return the file that the build backend output is written to, if any.
+    pub fn log_file(&self) -> Option<&Path> {
+        self.runner.log_file.as_deref()
+    }
+
+    /// Return the root of the environment that the build backend runs in.
+    pub fn environment(&self) -> &Path {
+        self.venv.root()
+    }
+
+    /// Return the Python interpreter of the environment that the build backend runs in.
+    pub fn python_executable(&self) -> &Path {
+        self.venv.python_executable()
+    }
+
     /// Acquire a lock on the source tree, if necessary.
     async fn acquire_lock(&self) -> Result<Option<LockedFile>, Error> {
         // Depending on the command, setuptools puts `*.egg-info`, `build/`, and `dist/` in the
@@ -713,6 +837,7 @@ impl SourceBuild {
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                self.version_id.as_deref(),
             )
             .instrument(span)
             .await?;
@@ -750,9 +875,31 @@ impl SourceBuild {
         // The build scripts run with the extracted root as cwd, so they need the absolute path.
         let wheel_dir = std::path::absolute(wheel_dir)?;
         let filename = self.pep517_build(&wheel_dir).await?;
+        if self.audit_wheels && self.build_kind == BuildKind::Wheel {
+            Self::audit_wheel(&wheel_dir.join(&filename));
+        }
         Ok(filename)
     }
 
+    /// Warn if the platform tags of a built wheel aren't justified by its shared libraries.
+    fn audit_wheel(wheel: &Path) {
+        let Some(filename) = wheel.file_name().and_then(|filename| filename.to_str()) else {
+            return;
+        };
+        match audit::audit_wheel(wheel) {
+            Ok(violations) => {
+                for violation in violations {
+                    warn_user!(
+                        "The built wheel `{filename}` does not conform to its platform tag: {violation}"
+                    );
+                }
+            }
+            Err(err) => {
+                warn!("Failed to audit `{}`: {err}", wheel.user_display());
+            }
+        }
+    }
+
     /// Perform a PEP 517 build for a wheel or source distribution (sdist).
     async fn pep517_build(&self, output_dir: &Path) -> Result<String, Error> {
         // Lock the source tree, if necessary.
@@ -835,6 +982,7 @@ impl SourceBuild {
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                self.version_id.as_deref(),
             )
             .instrument(span)
             .await?;
@@ -886,12 +1034,328 @@ fn escape_path_for_python(path: &Path) -> String {
         .replace('"', "\\\"")
 }
 
+/// Return a filesystem-safe identifier for the build log of a package, e.g., `foo-1.2.3`.
+fn log_identifier(
+    package_name: Option<&PackageName>,
+    package_version: Option<&Version>,
+    version_id: Option<&str>,
+) -> String {
+    let identifier = match (package_name, package_version) {
+        (Some(name), Some(version)) => format!("{name}-{version}"),
+        (Some(name), None) => name.to_string(),
+        (None, _) => version_id.unwrap_or("unknown").to_string(),
+    };
+    identifier.replace(
+        |c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_')),
+        "_",
+    )
+}
+
+/// Start a new section in the build log, creating the log directory and file if necessary.
+///
+/// Logs are appended to, such that repeated builds of the same package (e.g., across runs, or in
+/// separate build environments) don't overwrite each other's output.
+fn start_log(log_file: &Path, source_tree: &Path, build_kind: BuildKind) -> io::Result<()> {
+    use std::io::Write as _;
+
+    if let Some(parent) = log_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_file)?;
+    writeln!(
+        file,
+        "=== {} build of {} at {} ===",
+        build_kind,
+        source_tree.user_display(),
+        jiff::Timestamp::now()
+    )?;
+    Ok(())
+}
+
+/// Prepend the scripts directory of the environment to the `PATH`.
+#[allow(clippy::result_large_err)]
+fn venv_path(venv: &PythonEnvironment, path: Option<&OsString>) -> Result<OsString, Error> {
+    if let Some(path) = path {
+        let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(path));
+        env::join_paths(venv_path).map_err(Error::BuildScriptPath)
+    } else {
+        Ok(OsString::from(venv.scripts()))
+    }
+}
+
+/// Create an environment with the resolved build requirements installed.
+///
+/// Builds don't modify their environment, so environments that only contain registry
+/// distributions are content-addressed by the interpreter and the resolution in the cache, and
+/// reused across builds. Other environments, e.g., with build requirements from local paths, are
+/// created at `location`.
+///
+/// Returns the environment and, if it's shared through the cache, its [`SharedEnvironment`].
+async fn build_environment(
+    build_context: &impl BuildContext,
+    interpreter: &Interpreter,
+    resolution: &Resolution,
+    build_stack: &BuildStack,
+    location: &Path,
+    preview: Preview,
+) -> Result<(PythonEnvironment, Option<SharedEnvironment>), Error> {
+    let cache = build_context.cache();
+
+    if resolution
+        .distributions()
+        .any(|dist| dist.index().is_none())
+    {
+        let venv = uv_virtualenv::create_venv(
+            location,
+            interpreter.clone(),
+            uv_virtualenv::Prompt::None,
+            false,
+            uv_virtualenv::OnExisting::Remove,
+            false,
+            false,
+            false,
+            preview,
+        )?;
+        build_context
+            .install(resolution, &venv, build_stack)
+            .await
+            .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err.into()))?;
+        return Ok((venv, None));
+    }
+
+    let resolution_hash = {
+        let mut distributions = resolution.distributions().collect::<Vec<_>>();
+        distributions.sort_unstable_by_key(|dist| dist.name());
+        hash_digest(&distributions)
+    };
+    // Include the interpreter's modification time, such that environments aren't reused after
+    // the interpreter is replaced in place (e.g., by a patch upgrade of a system Python).
+    let interpreter_hash = {
+        let executable = canonicalize_executable(interpreter.sys_executable())?;
+        let modified = Timestamp::from_path(&executable)?;
+        hash_digest(&(
+            executable,
+            interpreter.python_full_version().to_string(),
+            modified,
+        ))
+    };
+    let cache_entry = cache.entry(
+        CacheBucket::Environments,
+        Path::new("build").join(interpreter_hash),
+        resolution_hash,
+    );
+
+    // Hold a shared lock on the environment for as long as it's in use, such that it isn't
+    // removed by a concurrent build that finds it modified.
+    fs::create_dir_all(cache_entry.dir())?;
+    let lock = LockedFile::acquire_shared_blocking(
+        SharedEnvironment::lock_path(cache_entry.path()),
+        cache_entry.path().user_display(),
+    )?;
+
+    if cache.refresh().is_none() {
+        if let Ok(root) = cache.resolve_link(cache_entry.path()) {
+            if let Ok(venv) = PythonEnvironment::from_root(root, cache) {
+                if let Some(fingerprint) = SharedEnvironment::cached_fingerprint(&venv) {
+                    debug!(
+                        "Using cached build environment: {}",
+                        venv.root().user_display()
+                    );
+                    let shared = SharedEnvironment::new(
+                        cache_entry.into_path_buf(),
+                        &venv,
+                        fingerprint,
+                        lock,
+                    );
+                    return Ok((venv, Some(shared)));
+                }
+                debug!(
+                    "Cached build environment was modified, recreating: {}",
+                    venv.root().user_display()
+                );
+            }
+        }
+    }
+
+    // Create the environment in the cache, then relocate it to its content-addressed location.
+    let temp_dir = cache.venv_dir()?;
+    let venv = uv_virtualenv::create_venv(
+        temp_dir.path(),
+        interpreter.clone(),
+        uv_virtualenv::Prompt::None,
+        false,
+        uv_virtualenv::OnExisting::Remove,
+        true,
+        false,
+        false,
+        preview,
+    )?;
+    build_context
+        .install(resolution, &venv, build_stack)
+        .await
+        .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err.into()))?;
+
+    // Record the installed distributions, to detect build backends that modify the environment.
+    let fingerprint = installed_fingerprint(&venv)?;
+    fs::write(
+        venv.root().join(SharedEnvironment::FINGERPRINT),
+        &fingerprint,
+    )?;
+
+    let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
+    let venv = PythonEnvironment::from_root(cache.archive(&id), cache)
+        .map_err(Error::CachedEnvironment)?;
+    debug!("Cached build environment: {}", venv.root().user_display());
+    let shared = SharedEnvironment::new(cache_entry.into_path_buf(), &venv, fingerprint, lock);
+    Ok((venv, Some(shared)))
+}
+
+/// A build environment that is shared across builds through the cache.
+///
+/// Build backends may modify their environment, e.g., by installing packages at build time, which
+/// would leak into other builds. The installed distributions are recorded when the environment is
+/// created, and re-validated when it's reused and after each build backend invocation. A modified
+/// environment is unlinked from the cache, such that subsequent builds create a fresh one, and
+/// removed once no build holds a lock on it anymore.
+#[derive(Debug)]
+struct SharedEnvironment {
+    /// The cache entry that links to the environment.
+    link: PathBuf,
+    /// The environment.
+    venv: PythonEnvironment,
+    /// The fingerprint of the distributions installed when the environment was created.
+    fingerprint: String,
+    /// The shared lock held while the environment is in use.
+    lock: Option<LockedFile>,
+    /// Whether the environment was modified and unlinked from the cache.
+    unlinked: Cell<bool>,
+}
+
+impl SharedEnvironment {
+    /// The file in the environment root that records its fingerprint.
+    const FINGERPRINT: &'static str = ".uv-build-fingerprint";
+
+    fn new(link: PathBuf, venv: &PythonEnvironment, fingerprint: String, lock: LockedFile) -> Self {
+        Self {
+            link,
+            venv: venv.clone(),
+            fingerprint,
+            lock: Some(lock),
+            unlinked: Cell::new(false),
+        }
+    }
+
+    /// Returns the path to the lock file for the environment linked at the given path.
+    fn lock_path(link: &Path) -> PathBuf {
+        let mut path = link.as_os_str().to_owned();
+        path.push(".lock");
+        PathBuf::from(path)
+    }
+
+    /// Read the fingerprint of a cached environment, if the environment is unmodified.
+    fn cached_fingerprint(venv: &PythonEnvironment) -> Option<String> {
+        let fingerprint = fs::read_to_string(venv.root().join(Self::FINGERPRINT)).ok()?;
+        installed_fingerprint(venv)
+            .is_ok_and(|installed| installed == fingerprint)
+            .then_some(fingerprint)
+    }
+
+    /// Whether the environment still contains the distributions it was created with.
+    fn is_unmodified(&self) -> bool {
+        installed_fingerprint(&self.venv).is_ok_and(|fingerprint| fingerprint == self.fingerprint)
+    }
+
+    /// Unlink the environment from the cache if it was modified.
+    fn revalidate(&self) {
+        if self.unlinked.get() || self.is_unmodified() {
+            return;
+        }
+        debug!(
+            "Build backend modified the cached build environment, removing it from the cache: {}",
+            self.venv.root().user_display()
+        );
+        match fs::remove_file(&self.link) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => warn!(
+                "Failed to remove modified build environment from the cache: {}: {err}",
+                self.link.user_display()
+            ),
+        }
+        self.unlinked.set(true);
+    }
+}
+
+impl Drop for SharedEnvironment {
+    /// Remove an unlinked environment, unless a concurrent build is still using it.
+    fn drop(&mut self) {
+        if !self.unlinked.get() {
+            return;
+        }
+        drop(self.lock.take());
+        let lock_path = Self::lock_path(&self.link);
+        match LockedFile::try_acquire(&lock_path, self.link.user_display()) {
+            Ok(Some(_lock)) => {
+                if let Err(err) = rm_rf(self.venv.root()) {
+                    warn!(
+                        "Failed to remove modified build environment: {}: {err}",
+                        self.venv.root().user_display()
+                    );
+                }
+            }
+            Ok(None) => debug!(
+                "Modified build environment is in use, skipping removal: {}",
+                self.venv.root().user_display()
+            ),
+            Err(err) => debug!(
+                "Failed to lock modified build environment: {}: {err}",
+                self.venv.root().user_display()
+            ),
+        }
+    }
+}
+
+/// Compute a digest of the distributions and top-level modules installed in an environment.
+///
+/// Byte-compiled files are ignored, since Python writes them as a side effect of imports.
+fn installed_fingerprint(venv: &PythonEnvironment) -> io::Result<String> {
+    let mut installed = Vec::new();
+    for site_packages in venv.site_packages() {
+        for entry in fs::read_dir(&*site_packages)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if name == "__pycache__" {
+                continue;
+            }
+            let record = if name.ends_with(".dist-info") {
+                fs::read(path.join("RECORD")).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            installed.push((name.to_string(), record));
+        }
+    }
+    installed.sort_unstable();
+    Ok(hash_digest(&installed))
+}
+
 /// Not a method because we call it before the builder is completely initialized
+///
+/// If the build backend requires additional packages and the environment is shared through the
+/// cache, returns a new environment with the additional packages, instead of modifying the shared
+/// environment.
 async fn create_pep517_build_environment(
     runner: &PythonRunner,
     source_tree: &Path,
     install_path: &Path,
     venv: &PythonEnvironment,
+    cached: bool,
+    interpreter: &Interpreter,
     pep517_backend: &Pep517Backend,
     build_context: &impl BuildContext,
     package_name: Option<&PackageName>,
@@ -907,7 +1371,8 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
-) -> Result<(), Error> {
+    preview: Preview,
+) -> Result<Option<(PythonEnvironment, Option<SharedEnvironment>)>, Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -949,6 +1414,7 @@ async fn create_pep517_build_environment(
             source_tree,
             environment_variables,
             modified_path,
+            version_id,
         )
         .instrument(span)
         .await?;
@@ -1031,6 +1497,20 @@ async fn create_pep517_build_environment(
                 Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
 
+        if cached {
+            // The hook output has been read, so the temporary directory can hold the environment.
+            let environment = build_environment(
+                build_context,
+                interpreter,
+                &resolution,
+                build_stack,
+                temp_dir.path(),
+                preview,
+            )
+            .await?;
+            return Ok(Some(environment));
+        }
+
         build_context
             .install(&resolution, venv, build_stack)
             .await
@@ -1039,15 +1519,19 @@ async fn create_pep517_build_environment(
             })?;
     }
 
-    Ok(())
+    Ok(None)
 }
 
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
 #[derive(Debug)]
 struct PythonRunner {
-    control: Semaphore,
+    jobs: Rc<BuildJobs>,
+    /// The cached build environment the scripts run in, if any, to re-validate after each script.
+    shared_environment: Option<SharedEnvironment>,
     level: BuildOutput,
+    /// A file to append the output of each script to, in addition to the output level.
+    log_file: Option<PathBuf>,
 }
 
 #[derive(Debug)]
@@ -1058,11 +1542,19 @@ struct PythonRunnerOutput {
 }
 
 impl PythonRunner {
-    /// Create a `PythonRunner` with the provided concurrency limit and output level.
-    fn new(concurrency: usize, level: BuildOutput) -> Self {
+    /// Create a `PythonRunner` with the provided job slots, output level, log file, and cached
+    /// build environment.
+    fn new(
+        jobs: Rc<BuildJobs>,
+        level: BuildOutput,
+        log_file: Option<PathBuf>,
+        shared_environment: Option<SharedEnvironment>,
+    ) -> Self {
         Self {
-            control: Semaphore::new(concurrency),
+            jobs,
+            shared_environment,
             level,
+            log_file,
         }
     }
 
@@ -1079,11 +1571,14 @@ impl PythonRunner {
         source_tree: &Path,
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
+        version_id: Option<&str>,
     ) -> Result<PythonRunnerOutput, Error> {
         /// Read lines from a reader and store them in a buffer.
         async fn read_from(
             mut reader: tokio::io::Split<tokio::io::BufReader<impl tokio::io::AsyncRead + Unpin>>,
             mut printer: Printer,
+            job: &BuildJob<'_>,
+            version_id: Option<&str>,
             buffer: &mut Vec<String>,
         ) -> io::Result<()> {
             loop {
@@ -1091,7 +1586,8 @@ impl PythonRunner {
                     Some(line_buf) => {
                         let line_buf = line_buf.strip_suffix(b"\r").unwrap_or(&line_buf);
                         let line = String::from_utf8_lossy(line_buf).into();
-                        let _ = write!(printer, "{line}");
+                        let prefix = line_prefix(printer, version_id, job.is_concurrent());
+                        let _ = write!(printer, "{prefix}{line}");
                         buffer.push(line);
                     }
                     None => return Ok(()),
@@ -1099,7 +1595,7 @@ impl PythonRunner {
             }
         }
 
-        let _permit = self.control.acquire().await.unwrap();
+        let job = self.jobs.acquire().await;
 
         let mut child = Command::new(venv.python_executable())
             .args(["-c", script])
@@ -1125,8 +1621,8 @@ impl PythonRunner {
         // Asynchronously read from the in-memory pipes.
         let printer = Printer::from(self.level);
         let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
+            read_from(stdout_reader, printer, &job, version_id, &mut stdout_buf),
+            read_from(stderr_reader, printer, &job, version_id, &mut stderr_buf),
         );
         match result {
             (Ok(()), Ok(())) => {}
@@ -1144,11 +1640,52 @@ impl PythonRunner {
             .await
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
 
-        Ok(PythonRunnerOutput {
+        if let Some(shared_environment) = &self.shared_environment {
+            shared_environment.revalidate();
+        }
+
+        let output = PythonRunnerOutput {
             stdout: stdout_buf,
             stderr: stderr_buf,
             status,
-        })
+        };
+        if let Some(log_file) = &self.log_file {
+            output.append_to(log_file)?;
+        }
+        Ok(output)
+    }
+}
+
+impl PythonRunnerOutput {
+    /// Append the output to a log file, in the same format as build backend errors.
+    fn append_to(&self, log_file: &Path) -> io::Result<()> {
+        use std::io::Write as _;
+
+        let mut file = fs::OpenOptions::new().append(true).open(log_file)?;
+        writeln!(file, "[{}]", self.status)?;
+        if !self.stdout.is_empty() {
+            writeln!(file, "\n[stdout]\n{}", self.stdout.join("\n"))?;
+        }
+        if !self.stderr.is_empty() {
+            writeln!(file, "\n[stderr]\n{}", self.stderr.join("\n"))?;
+        }
+        writeln!(file)?;
+        Ok(())
+    }
+}
+
+/// The prefix that attributes a line of build backend output to its build.
+///
+/// Up to `concurrent-builds` builds run in parallel, so their output may be interleaved. Logs
+/// always name the build, while output on `stderr` only does so when other builds are running at
+/// the same time, to keep the output of a single build unchanged.
+fn line_prefix(printer: Printer, version_id: Option<&str>, concurrent: bool) -> String {
+    match (printer, version_id) {
+        (Printer::Debug, Some(version_id)) => format!("[{version_id}] "),
+        (Printer::Stderr, Some(version_id)) if concurrent => {
+            format!("{} ", format!("[{version_id}]").dimmed())
+        }
+        _ => String::new(),
     }
 }
 
@@ -1186,3 +1723,39 @@ impl Write for Printer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn jobs_are_shared_across_builds() {
+        let context = SourceBuildContext::new(2);
+        let first = context.jobs.acquire().await;
+        assert!(!first.is_concurrent());
+
+        // A clone of the context, as passed to each build, shares the job slots.
+        let second_context = context.clone();
+        let second = second_context.jobs.acquire().await;
+        assert!(first.is_concurrent());
+        assert!(second.is_concurrent());
+        assert!(context.jobs.permits.try_acquire().is_err());
+
+        drop(second);
+        assert!(!first.is_concurrent());
+        assert!(context.jobs.permits.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn prefix() {
+        assert_eq!(
+            line_prefix(Printer::Debug, Some("foo-1.0.0"), false),
+            "[foo-1.0.0] "
+        );
+        // On `stderr`, the prefix is only shown while other builds are running.
+        assert_eq!(line_prefix(Printer::Stderr, Some("foo-1.0.0"), false), "");
+        assert!(line_prefix(Printer::Stderr, Some("foo-1.0.0"), true).contains("[foo-1.0.0]"));
+        assert_eq!(line_prefix(Printer::Quiet, Some("foo-1.0.0"), true), "");
+        assert_eq!(line_prefix(Printer::Debug, None, true), "");
+    }
+}
@@ -0,0 +1,13 @@
+// Fixture for the wheel audit tests, built on x86_64 Linux (glibc 2.35, GCC 11) with:
+//
+//   g++ -shared -fPIC -O2 -s -o libaudit.so audit.cpp -lz
+//
+// The library links against `libz.so.1`, which isn't in any manylinux policy, and requires
+// `GLIBCXX_3.4.21` and `CXXABI_1.3.9`, which are newer than `manylinux2014` allows.
+#include <string>
+#include <zlib.h>
+
+extern "C" unsigned long checksum(const char *data) {
+    std::string value(data);
+    return crc32(0, reinterpret_cast<const unsigned char *>(value.data()), value.size());
+}
@@ -2,7 +2,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use uv_static::EnvVars;
 
-use crate::Cache;
+use crate::{ArchiveCompression, Cache};
 use clap::Parser;
 use tracing::{debug, warn};
 
@@ -29,6 +29,15 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// The compression to apply to unpacked wheels and source distributions in the cache.
+    ///
+    /// Compressed archives reduce the cache's disk footprint, but are always copied (and
+    /// decompressed) into the environment, regardless of the link mode.
+    ///
+    /// Defaults to `none`.
+    #[arg(global = true, long, value_enum, env = EnvVars::UV_CACHE_COMPRESSION)]
+    pub cache_compression: Option<ArchiveCompression>,
 }
 
 impl Cache {
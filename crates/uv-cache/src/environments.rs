@@ -0,0 +1,392 @@
+//! The lifecycle of the ephemeral environments in the cache, e.g., for `uv run --with` and `uvx`.
+//!
+//! Ephemeral environments are content-addressed links in the environments bucket, pointing into
+//! the archive bucket. Each time an environment is reused, the modification time of its link is
+//! updated, such that environments can be removed once they haven't been used for a while, or
+//! evicted in least-recently-used order once they exceed a size or count budget.
+//!
+//! While an environment is in use, uv holds a shared lock on it (`<resolution>.lock`, next to the
+//! link). Pruning only removes an environment if it can acquire the lock exclusively, such that
+//! environments in use by a concurrent invocation are skipped.
+//!
+//! Requests (e.g., `uvx ruff`) can additionally be mapped to the environment they last resolved
+//! to, such that the environment can be reused without resolving again.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use tracing::{debug, warn};
+
+use uv_fs::LockedFile;
+use uv_static::EnvVars;
+
+use crate::{Cache, CacheBucket, CacheEntry, Removal, rm_rf};
+
+/// The directory in the environments bucket that maps requests to environments.
+const REQUESTS: &str = "requests";
+
+/// The limits on the ephemeral environments in the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvironmentLimits {
+    /// Remove environments that haven't been used for longer than this duration.
+    pub max_age: Option<Duration>,
+    /// Remove the least recently used environments until their total size, in bytes, is below
+    /// this limit.
+    pub max_size: Option<u64>,
+    /// Remove the least recently used environments until at most this many remain.
+    pub max_count: Option<usize>,
+}
+
+impl Default for EnvironmentLimits {
+    fn default() -> Self {
+        Self::from_settings(None, None, None)
+    }
+}
+
+impl EnvironmentLimits {
+    const DEFAULT_MAX_AGE_DAYS: u64 = 30;
+
+    /// Resolve the [`EnvironmentLimits`] from the given maximum age (in days), maximum size (in
+    /// MiB), and maximum number of environments, where `0` disables the respective limit.
+    ///
+    /// The `UV_CACHE_ENVIRONMENT_MAX_AGE`, `UV_CACHE_ENVIRONMENT_MAX_SIZE`, and
+    /// `UV_CACHE_ENVIRONMENT_MAX_COUNT` environment variables take precedence over the given
+    /// settings.
+    pub fn from_settings(
+        max_age: Option<u64>,
+        max_size: Option<u64>,
+        max_count: Option<usize>,
+    ) -> Self {
+        let max_age = parse_env(EnvVars::UV_CACHE_ENVIRONMENT_MAX_AGE)
+            .or(max_age)
+            .unwrap_or(Self::DEFAULT_MAX_AGE_DAYS);
+        let max_size = parse_env(EnvVars::UV_CACHE_ENVIRONMENT_MAX_SIZE).or(max_size);
+        let max_count = parse_env(EnvVars::UV_CACHE_ENVIRONMENT_MAX_COUNT).or(max_count);
+        Self {
+            max_age: (max_age > 0).then(|| Duration::from_secs(max_age * 24 * 60 * 60)),
+            max_size: max_size
+                .filter(|mebibytes| *mebibytes > 0)
+                .map(|mebibytes| mebibytes * 1024 * 1024),
+            max_count: max_count.filter(|count| *count > 0),
+        }
+    }
+}
+
+/// Parse a non-negative integer from an environment variable, warning if it's invalid.
+fn parse_env<T: FromStr>(name: &'static str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    if let Ok(value) = value.parse() {
+        Some(value)
+    } else {
+        warn!("Ignoring invalid value for `{name}`: `{value}` (expected a non-negative integer)");
+        None
+    }
+}
+
+/// Returns the path to the lock file for the ephemeral environment linked at the given path.
+fn lock_path(link: &Path) -> PathBuf {
+    let mut path = link.as_os_str().to_owned();
+    path.push(".lock");
+    PathBuf::from(path)
+}
+
+/// An ephemeral environment in the cache.
+#[derive(Debug)]
+struct EphemeralEnvironment {
+    /// The link in the environments bucket.
+    link: PathBuf,
+    /// The last time the environment was used.
+    last_used: SystemTime,
+}
+
+impl Cache {
+    /// Return the entry that records the ephemeral environment last resolved for the request with
+    /// the given digest.
+    pub fn environment_request(&self, digest: &str) -> CacheEntry {
+        self.entry(CacheBucket::Environments, REQUESTS, digest)
+    }
+
+    /// Mark the ephemeral environment linked at the given path as in use.
+    ///
+    /// Updates the last use of the environment, and holds a shared lock on it until the [`Cache`]
+    /// is dropped, such that it isn't pruned while in use (e.g., by a concurrent `uvx`).
+    ///
+    /// Returns `false` if the environment was removed before the lock was acquired.
+    pub fn use_environment(&self, link: impl AsRef<Path>) -> bool {
+        let link = link.as_ref();
+        match LockedFile::acquire_shared_blocking(lock_path(link), link.display()) {
+            Ok(lock) => {
+                // The environment may have been pruned while we were waiting for the lock.
+                if self.resolve_link(link).is_err() {
+                    return false;
+                }
+                self.environment_locks.lock().unwrap().push(lock);
+            }
+            Err(err) => {
+                debug!("Failed to lock environment at `{}`: {err}", link.display());
+            }
+        }
+
+        let now = filetime::FileTime::now();
+        if let Err(err) = filetime::set_symlink_file_times(link, now, now) {
+            debug!(
+                "Failed to update last use of environment at `{}`: {err}",
+                link.display()
+            );
+        }
+        true
+    }
+
+    /// Remove the ephemeral environments that exceed the given [`EnvironmentLimits`].
+    ///
+    /// Environments that are in use (i.e., locked by another invocation) are skipped.
+    pub fn prune_environments(&self, limits: EnvironmentLimits) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        let mut environments = self.ephemeral_environments()?;
+
+        // Remove any environments that haven't been used recently.
+        if let Some(max_age) = limits.max_age {
+            let now = SystemTime::now();
+            environments.retain(|environment| {
+                let age = now
+                    .duration_since(environment.last_used)
+                    .unwrap_or_default();
+                if age < max_age {
+                    return true;
+                }
+                debug!(
+                    "Removing unused cache environment: {}",
+                    environment.link.display()
+                );
+                match self.try_remove_environment(&environment.link) {
+                    Ok(Some(removal)) => {
+                        summary += removal;
+                        false
+                    }
+                    Ok(None) => true,
+                    Err(err) => {
+                        warn!(
+                            "Failed to remove cache environment at `{}`: {err}",
+                            environment.link.display()
+                        );
+                        false
+                    }
+                }
+            });
+        }
+
+        // Evict the least recently used environments until the remaining ones fit in the budget.
+        if limits.max_size.is_some() || limits.max_count.is_some() {
+            let mut environments = environments
+                .into_iter()
+                .map(|environment| {
+                    let size = if limits.max_size.is_some() {
+                        self.resolve_link(&environment.link)
+                            .map(|root| directory_size(&root))
+                            .unwrap_or(0)
+                    } else {
+                        0
+                    };
+                    (environment, size)
+                })
+                .collect::<Vec<_>>();
+            environments.sort_by_key(|(environment, _)| environment.last_used);
+
+            // Always retain the most recently used environment, e.g., one that was just created.
+            let mut total_size = environments.iter().map(|(_, size)| size).sum::<u64>();
+            let mut total_count = environments.len();
+            environments.pop();
+            for (environment, size) in environments {
+                let over_size = limits
+                    .max_size
+                    .is_some_and(|max_size| total_size > max_size);
+                let over_count = limits
+                    .max_count
+                    .is_some_and(|max_count| total_count > max_count);
+                if !over_size && !over_count {
+                    break;
+                }
+                debug!(
+                    "Evicting least recently used cache environment: {}",
+                    environment.link.display()
+                );
+                if let Some(removal) = self.try_remove_environment(&environment.link)? {
+                    summary += removal;
+                    total_size -= size;
+                    total_count -= 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Find the ephemeral environments in the cache.
+    ///
+    /// Ephemeral environments are stored as `environments-v2/<interpreter>/<resolution>` links,
+    /// alongside the (non-ephemeral) script environments, which are stored as virtual environments
+    /// directly, the build environments, which are stored under `build`, and the request records,
+    /// which are stored under `requests`.
+    fn ephemeral_environments(&self) -> io::Result<Vec<EphemeralEnvironment>> {
+        let mut environments = Vec::new();
+        let entries = match fs_err::read_dir(self.bucket(CacheBucket::Environments)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(environments),
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_name() == "build"
+                || entry.file_name() == REQUESTS
+                || !entry.file_type()?.is_dir()
+                || path.join("pyvenv.cfg").exists()
+            {
+                continue;
+            }
+            for link in fs_err::read_dir(&path)? {
+                let link = link?;
+                let metadata = fs_err::symlink_metadata(link.path())?;
+                let is_link = if cfg!(windows) {
+                    metadata.is_file()
+                } else {
+                    metadata.is_symlink()
+                };
+                if !is_link
+                    || link
+                        .path()
+                        .extension()
+                        .is_some_and(|extension| extension == "lock")
+                {
+                    continue;
+                }
+                environments.push(EphemeralEnvironment {
+                    link: link.path(),
+                    last_used: metadata.modified()?,
+                });
+            }
+        }
+        Ok(environments)
+    }
+
+    /// Remove an ephemeral environment, along with its entry in the archive bucket, unless it's
+    /// in use.
+    ///
+    /// Returns `None` if the environment is in use.
+    fn try_remove_environment(&self, link: &Path) -> io::Result<Option<Removal>> {
+        let Some(_lock) = LockedFile::try_acquire(lock_path(link), link.display())? else {
+            debug!("Skipping cache environment in use: {}", link.display());
+            return Ok(None);
+        };
+        let mut summary = Removal::default();
+        if let Ok(root) = self.resolve_link(link) {
+            summary += rm_rf(root)?;
+        }
+        summary += rm_rf(link)?;
+        Ok(Some(summary))
+    }
+}
+
+/// Returns the total size of the files in a directory, in bytes.
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(std::fs::Metadata::is_file)
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ArchiveId;
+
+    /// Create an ephemeral environment that was last used `days` days ago.
+    fn environment(cache: &Cache, resolution: &str, days: u64) -> (CacheEntry, PathBuf) {
+        let temp_env = tempfile::tempdir_in(cache.root()).unwrap();
+        fs_err::write(temp_env.path().join("pyvenv.cfg"), "home = /usr/bin").unwrap();
+        let id = ArchiveId::new();
+        let archive = cache.archive(&id);
+        fs_err::create_dir_all(archive.parent().unwrap()).unwrap();
+        fs_err::rename(temp_env.keep(), &archive).unwrap();
+
+        let link = cache.entry(CacheBucket::Environments, "interpreter", resolution);
+        fs_err::create_dir_all(link.dir()).unwrap();
+        cache.create_link(&id, link.path()).unwrap();
+        let last_used = filetime::FileTime::from_system_time(
+            SystemTime::now() - Duration::from_secs(days * 24 * 60 * 60),
+        );
+        filetime::set_symlink_file_times(link.path(), last_used, last_used).unwrap();
+        (link, archive)
+    }
+
+    #[test]
+    fn prune_environments() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(temp_dir.path());
+
+        let (old, old_archive) = environment(&cache, "old", 4);
+        let (recent, recent_archive) = environment(&cache, "recent", 2);
+
+        // A script environment, which is never removed.
+        let script = cache.shard(CacheBucket::Environments, "script-0123456789abcdef");
+        fs_err::create_dir_all(&script).unwrap();
+        fs_err::write(script.join("pyvenv.cfg"), "home = /usr/bin").unwrap();
+
+        // Only the old environment exceeds the maximum age.
+        let limits = EnvironmentLimits {
+            max_age: Some(Duration::from_secs(3 * 24 * 60 * 60)),
+            max_size: None,
+            max_count: None,
+        };
+        cache.prune_environments(limits).unwrap();
+        assert!(!old.path().exists());
+        assert!(!old_archive.exists());
+        assert!(recent.path().exists());
+
+        // The most recently used environment is retained, even if it exceeds the size budget.
+        let (newest, _) = environment(&cache, "newest", 0);
+        let limits = EnvironmentLimits {
+            max_age: None,
+            max_size: Some(1),
+            max_count: None,
+        };
+        cache.prune_environments(limits).unwrap();
+        assert!(!recent.path().exists());
+        assert!(!recent_archive.exists());
+        assert!(newest.path().exists());
+        assert!(script.join("pyvenv.cfg").exists());
+    }
+
+    #[test]
+    fn prune_environments_in_use() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(temp_dir.path());
+
+        let (oldest, _) = environment(&cache, "oldest", 3);
+        let (older, _) = environment(&cache, "older", 2);
+        let (newest, _) = environment(&cache, "newest", 1);
+
+        // The oldest environment is in use elsewhere, so it's skipped.
+        let _lock =
+            LockedFile::acquire_shared_blocking(lock_path(oldest.path()), "oldest").unwrap();
+        let limits = EnvironmentLimits {
+            max_age: None,
+            max_size: None,
+            max_count: Some(1),
+        };
+        cache.prune_environments(limits).unwrap();
+        assert!(oldest.path().exists());
+        assert!(!older.path().exists());
+        assert!(newest.path().exists());
+
+        // An environment that was pruned can't be used.
+        assert!(!cache.use_environment(older.path()));
+        assert!(cache.use_environment(newest.path()));
+    }
+}
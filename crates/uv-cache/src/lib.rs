@@ -4,7 +4,7 @@ use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use rustc_hash::FxHashMap;
 use tracing::debug;
@@ -18,8 +18,10 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::environments::EnvironmentLimits;
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
+pub use crate::stats::{CacheStats, CacheSummary};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -27,7 +29,9 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod environments;
 mod removal;
+mod stats;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -35,6 +39,31 @@ mod wheel;
 /// Must be kept in-sync with the version in [`CacheBucket::to_str`].
 pub const ARCHIVE_VERSION: u8 = 0;
 
+/// The compression to apply to unpacked archives (e.g., wheels and source distributions) in the
+/// cache.
+///
+/// Compressed archives trade CPU time (to decompress files when installing) for a reduction in the
+/// cache's disk footprint. Archives are always decompressed by copying, regardless of the link
+/// mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ArchiveCompression {
+    /// Store unpacked archives uncompressed, such that they can be linked into environments.
+    #[default]
+    None,
+    /// Store the files in unpacked archives zstd-compressed.
+    Zstd,
+}
+
+impl ArchiveCompression {
+    /// Returns `true` if unpacked archives should be compressed.
+    pub fn is_enabled(self) -> bool {
+        matches!(self, Self::Zstd)
+    }
+}
+
 /// A [`CacheEntry`] which may or may not exist yet.
 #[derive(Debug, Clone)]
 pub struct CacheEntry(PathBuf);
@@ -146,6 +175,14 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The compression to apply to unpacked archives written to the cache.
+    compression: ArchiveCompression,
+    /// The limits on the ephemeral environments in the cache.
+    environment_limits: EnvironmentLimits,
+    /// The locks on the ephemeral environments in use, held until the cache is dropped.
+    environment_locks: Arc<Mutex<Vec<LockedFile>>>,
+    /// Statistics on the cache's effectiveness, shared across clones.
+    stats: Arc<CacheStats>,
 }
 
 impl Cache {
@@ -155,6 +192,10 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            compression: ArchiveCompression::None,
+            environment_limits: EnvironmentLimits::default(),
+            environment_locks: Arc::default(),
+            stats: Arc::default(),
         }
     }
 
@@ -165,6 +206,10 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            compression: ArchiveCompression::None,
+            environment_limits: EnvironmentLimits::default(),
+            environment_locks: Arc::default(),
+            stats: Arc::default(),
         })
     }
 
@@ -174,6 +219,39 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`ArchiveCompression`] for unpacked archives written to the cache.
+    #[must_use]
+    pub fn with_compression(self, compression: ArchiveCompression) -> Self {
+        Self {
+            compression,
+            ..self
+        }
+    }
+
+    /// Return the [`ArchiveCompression`] for unpacked archives written to the cache.
+    pub fn compression(&self) -> ArchiveCompression {
+        self.compression
+    }
+
+    /// Set the [`EnvironmentLimits`] for the ephemeral environments in the cache.
+    #[must_use]
+    pub fn with_environment_limits(self, environment_limits: EnvironmentLimits) -> Self {
+        Self {
+            environment_limits,
+            ..self
+        }
+    }
+
+    /// Return the [`EnvironmentLimits`] for the ephemeral environments in the cache.
+    pub fn environment_limits(&self) -> EnvironmentLimits {
+        self.environment_limits
+    }
+
+    /// Return the [`CacheStats`] for the current invocation.
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -985,6 +1063,34 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// The files that a registry accepted during `uv publish`, to skip them when publishing is
+    /// retried.
+    ///
+    /// Cache structure: `publish-v0/<digest(publish_url)>.json`
+    Publish,
+    /// Remote scripts run with an expected hash, which are immutable and thus never refreshed.
+    ///
+    /// Cache structure: `scripts-v0/<algorithm>/<digest>.py`
+    Scripts,
+    /// Remote requirements files (e.g., `-r https://...` or `-c https://...`), alongside their
+    /// cache policy.
+    ///
+    /// Cache structure: `requirements-v0/remote/<digest(url)>.msgpack`
+    Requirements,
+    /// The state of `uv run --container`, i.e., the environment, cache, and Python installations
+    /// used inside the container, which are mounted into the container.
+    ///
+    /// Cache structure: `containers-v0/<digest(project, image)>/`
+    Containers,
+    /// Distribution archives (wheels and source distributions) that are stored as-is, rather than
+    /// unpacked or built, as for `uv pip download`, alongside their cache policy.
+    ///
+    /// Cache structure:
+    ///
+    /// * `downloads-v0/pypi/<package_name>/<filename>`
+    /// * `downloads-v0/index/<digest(index-url)>/<package_name>/<filename>`
+    /// * `downloads-v0/url/<digest(url)>/<package_name>/<filename>`
+    Downloads,
 }
 
 impl CacheBucket {
@@ -995,10 +1101,10 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v9",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v5",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v16",
+            Self::Simple => "simple-v18",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
             Self::Wheels => "wheels-v5",
@@ -1007,6 +1113,11 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v2",
+            Self::Publish => "publish-v0",
+            Self::Scripts => "scripts-v0",
+            Self::Requirements => "requirements-v0",
+            Self::Containers => "containers-v0",
+            Self::Downloads => "downloads-v0",
         }
     }
 
@@ -1027,7 +1138,7 @@ impl CacheBucket {
 
         let mut summary = Removal::default();
         match self {
-            Self::Wheels => {
+            Self::Wheels | Self::Downloads => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(name.to_string()))?;
@@ -1108,7 +1219,15 @@ impl CacheBucket {
                 let root = cache.bucket(self);
                 summary += rm_rf(root)?;
             }
-            Self::Git | Self::Interpreter | Self::Archive | Self::Builds | Self::Environments => {
+            Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Publish
+            | Self::Scripts
+            | Self::Requirements
+            | Self::Containers => {
                 // Nothing to do.
             }
         }
@@ -1127,6 +1246,11 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Publish,
+            Self::Scripts,
+            Self::Requirements,
+            Self::Containers,
+            Self::Downloads,
         ]
         .iter()
         .copied()
@@ -0,0 +1,154 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+
+/// Counters tracking the effectiveness of the cache over the course of a single invocation.
+///
+/// The counters are shared across all clones of a [`crate::Cache`].
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    /// The number of wheels installed directly from the cache, without consulting the network.
+    wheels_cached: AtomicU64,
+    /// The number of wheels requested from the distribution database.
+    wheels_requested: AtomicU64,
+    /// The number of wheels downloaded from a remote source.
+    wheels_downloaded: AtomicU64,
+    /// The number of source distributions whose built wheel was installed directly from the cache.
+    builds_cached: AtomicU64,
+    /// The number of source distributions requested from the distribution database.
+    builds_requested: AtomicU64,
+    /// The number of source distributions built.
+    builds: AtomicU64,
+    /// The total (known) size of the wheels installed directly from the cache.
+    cached_bytes: AtomicU64,
+    /// The total (known) size of the wheels requested from the distribution database.
+    requested_bytes: AtomicU64,
+    /// The total (known) size of the requested wheels that were downloaded.
+    requested_bytes_downloaded: AtomicU64,
+    /// The total size of the downloaded wheels.
+    downloaded_bytes: AtomicU64,
+}
+
+impl CacheStats {
+    /// Record a wheel that was installed directly from the cache.
+    pub fn record_cached_wheel(&self, size: Option<u64>) {
+        self.wheels_cached.fetch_add(1, Ordering::Relaxed);
+        self.cached_bytes
+            .fetch_add(size.unwrap_or_default(), Ordering::Relaxed);
+    }
+
+    /// Record a source distribution whose built wheel was installed directly from the cache.
+    pub fn record_cached_build(&self) {
+        self.builds_cached.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a request for a wheel, which may be served from the cache or downloaded.
+    pub fn record_wheel_request(&self, size: Option<u64>) {
+        self.wheels_requested.fetch_add(1, Ordering::Relaxed);
+        self.requested_bytes
+            .fetch_add(size.unwrap_or_default(), Ordering::Relaxed);
+    }
+
+    /// Record a wheel download, given the size reported by the index (if any) and the size of the
+    /// downloaded file (if known).
+    pub fn record_wheel_download(&self, expected: Option<u64>, actual: Option<u64>) {
+        self.wheels_downloaded.fetch_add(1, Ordering::Relaxed);
+        self.requested_bytes_downloaded
+            .fetch_add(expected.unwrap_or_default(), Ordering::Relaxed);
+        self.downloaded_bytes
+            .fetch_add(actual.or(expected).unwrap_or_default(), Ordering::Relaxed);
+    }
+
+    /// Record a request for a source distribution, which may be served from the cache or built.
+    pub fn record_build_request(&self) {
+        self.builds_requested.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a source distribution build.
+    pub fn record_build(&self) {
+        self.builds.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Summarize the cache statistics.
+    pub fn summary(&self) -> CacheSummary {
+        let wheels_requested = self.wheels_requested.load(Ordering::Relaxed);
+        let wheels_downloaded = self.wheels_downloaded.load(Ordering::Relaxed);
+        let builds_requested = self.builds_requested.load(Ordering::Relaxed);
+        let builds = self.builds.load(Ordering::Relaxed);
+        let requested_bytes = self.requested_bytes.load(Ordering::Relaxed);
+        let requested_bytes_downloaded = self.requested_bytes_downloaded.load(Ordering::Relaxed);
+        CacheSummary {
+            wheels_from_cache: self.wheels_cached.load(Ordering::Relaxed)
+                + wheels_requested.saturating_sub(wheels_downloaded),
+            wheels_downloaded,
+            builds,
+            builds_avoided: self.builds_cached.load(Ordering::Relaxed)
+                + builds_requested.saturating_sub(builds),
+            bytes_downloaded: self.downloaded_bytes.load(Ordering::Relaxed),
+            bytes_saved: self.cached_bytes.load(Ordering::Relaxed)
+                + requested_bytes.saturating_sub(requested_bytes_downloaded),
+        }
+    }
+}
+
+/// A summary of the cache's effectiveness over the course of a single invocation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CacheSummary {
+    /// The number of wheels served from the cache.
+    pub wheels_from_cache: u64,
+    /// The number of wheels downloaded from a remote source.
+    pub wheels_downloaded: u64,
+    /// The number of source distributions built.
+    pub builds: u64,
+    /// The number of source distribution builds avoided by way of the cache.
+    pub builds_avoided: u64,
+    /// The total size of the downloaded wheels, in bytes.
+    pub bytes_downloaded: u64,
+    /// The (estimated) number of bytes that would've been downloaded, if not for the cache.
+    ///
+    /// Only includes wheels for which the index reports a size.
+    pub bytes_saved: u64,
+}
+
+impl CacheSummary {
+    /// Returns `true` if no distributions were served from the cache or fetched.
+    pub fn is_empty(&self) -> bool {
+        self.wheels_from_cache == 0
+            && self.wheels_downloaded == 0
+            && self.builds == 0
+            && self.builds_avoided == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary() {
+        let stats = CacheStats::default();
+        stats.record_cached_wheel(Some(100));
+        stats.record_cached_build();
+        stats.record_wheel_request(Some(200));
+        stats.record_wheel_request(Some(300));
+        stats.record_wheel_download(Some(300), Some(300));
+        stats.record_wheel_request(None);
+        stats.record_wheel_download(None, Some(50));
+        stats.record_build_request();
+        stats.record_build_request();
+        stats.record_build();
+
+        assert_eq!(
+            stats.summary(),
+            CacheSummary {
+                wheels_from_cache: 2,
+                wheels_downloaded: 2,
+                builds: 1,
+                builds_avoided: 2,
+                bytes_downloaded: 350,
+                bytes_saved: 300,
+            }
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::ffi::OsString;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -11,13 +12,14 @@ use clap::{Args, Parser, Subcommand};
 use uv_cache::CacheArgs;
 use uv_configuration::{
     ConfigSettingEntry, ConfigSettingPackageEntry, ExportFormat, IndexStrategy,
-    KeyringProviderType, PackageNameSpecifier, PreviewFeatures, ProjectBuildBackend, TargetTriple,
-    TrustedHost, TrustedPublishing, VersionControlSystem,
+    KeyringProviderType, PackageNameSpecifier, PreviewFeatures, ProjectBuildBackend, RateLimit,
+    TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
 };
 use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
+use uv_pep508::uv_pep440::Version;
 use uv_pep508::{MarkerTree, Requirement};
-use uv_pypi_types::VerbatimParsedUrl;
+use uv_pypi_types::{HashDigest, VerbatimParsedUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
@@ -47,6 +49,38 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonUpgradePolicy {
+    /// Upgrade to the latest patch release within each minor version, e.g., 3.12.8 to 3.12.11.
+    ///
+    /// Virtual environments created from the minor version are transparently moved to the new
+    /// patch release.
+    #[default]
+    Patch,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ToolListFormat {
+    /// Display the list of tools in a human-readable format.
+    #[default]
+    Text,
+    /// Display the list of tools in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum TreeFormat {
+    /// Display the workspace graph in a human-readable format.
+    #[default]
+    Text,
+    /// Display the workspace graph in the Graphviz DOT format.
+    Dot,
+    /// Display the workspace graph as a Mermaid flowchart.
+    Mermaid,
+    /// Display the workspace graph in a machine-readable JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -56,6 +90,24 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CheckFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum BuildFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the built distributions in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -273,6 +325,13 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// Limit the aggregate download bandwidth.
+    ///
+    /// Accepts a rate in bytes per second, with an optional `K`, `M`, or `G` suffix. For example,
+    /// `--limit-rate 10M` limits the combined throughput of all downloads to 10 MiB/s.
+    #[arg(global = true, long, env = EnvVars::UV_LIMIT_RATE)]
+    pub limit_rate: Option<RateLimit>,
+
     /// Whether to enable all experimental preview features.
     ///
     /// Preview features may change without warning.
@@ -289,7 +348,8 @@ pub struct GlobalArgs {
     /// Use comma-separated values or pass multiple times to enable multiple features.
     ///
     /// The following features are available: `python-install-default`, `python-upgrade`,
-    /// `json-output`, `pylock`, `add-bounds`.
+    /// `json-output`, `pylock`, `add-bounds`, `audit-wheels`, `tool-layers`,
+    /// `member-requires-python`.
     #[arg(
         global = true,
         long = "preview-features",
@@ -316,6 +376,39 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// Record all HTTP requests to the given file, for debugging network issues.
+    ///
+    /// Each request is recorded with its URL, headers (omitting credentials), status, timing, and
+    /// cache disposition. If the path has a `.har` extension, the trace is written as an HTTP
+    /// Archive; otherwise, it's written as JSON lines.
+    #[arg(global = true, long, env = EnvVars::UV_TRACE_HTTP, value_name = "PATH")]
+    pub trace_http: Option<PathBuf>,
+
+    /// Report the credentials used for each request, and where they came from.
+    ///
+    /// For each request, uv reports whether credentials were sent and which source (e.g., the
+    /// request URL, the keyring, or a netrc file) supplied them. Passwords and tokens are never
+    /// shown.
+    #[arg(global = true, long, env = EnvVars::UV_DEBUG_AUTH, value_parser = clap::builder::BoolishValueParser::new())]
+    pub debug_auth: bool,
+
+    /// Refuse to combine versions of a package across indexes that don't declare a relationship
+    /// to each other.
+    ///
+    /// By default, uv warns if a package is found on multiple indexes that publish PEP 708
+    /// metadata without linking them. With this flag, uv fails unless one index tracks the other,
+    /// or both list each other as alternate locations.
+    #[arg(global = true, long, env = EnvVars::UV_STRICT_INDEX_TRACKING, value_parser = clap::builder::BoolishValueParser::new(), overrides_with("no_strict_index_tracking"))]
+    pub strict_index_tracking: bool,
+
+    #[arg(
+        global = true,
+        long,
+        overrides_with("strict_index_tracking"),
+        hide = true
+    )]
+    pub no_strict_index_tracking: bool,
+
     /// Hide all progress outputs.
     ///
     /// For example, spinners or progress bars.
@@ -500,6 +593,14 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Yank or un-yank a release on an index.
+    ///
+    /// A yanked release is ignored by resolvers unless it is requested with an exact version
+    /// specifier, such as `==1.0.0`, but remains installable for projects that pinned it.
+    ///
+    /// The index must be configured with a `publish-url` and support yanking through its upload
+    /// API. PyPI doesn't, releases on PyPI need to be yanked through its web interface.
+    Yank(YankArgs),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -524,6 +625,12 @@ pub enum Commands {
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
+    /// Print the completion candidates for a project-specific value.
+    ///
+    /// Invoked by the shell completion scripts to complete values that depend on the current
+    /// project, e.g., the command of `uv run`.
+    #[command(hide = true)]
+    Complete(CompleteArgs),
     /// Display documentation for a command.
     // To avoid showing the global options when displaying help for the help command, we are
     // responsible for maintaining the options using the `after_help`.
@@ -622,8 +729,22 @@ pub struct VersionArgs {
     pub refresh: RefreshArgs,
 
     /// Update the version of a specific package in the workspace.
+    ///
+    /// With `--workspace`, this can be provided multiple times to select the members to read or
+    /// update.
     #[arg(long, conflicts_with = "isolated")]
-    pub package: Option<PackageName>,
+    pub package: Vec<PackageName>,
+
+    /// Read or update the version of every member in the workspace.
+    ///
+    /// When updating, requirements on the updated members that pin their previous versions (e.g.,
+    /// `child==0.1.0` or `child>=0.1.0`) are updated to match across the workspace, and the
+    /// workspace is re-locked once.
+    ///
+    /// Members without a static `project.version` are skipped. To only include specific members,
+    /// use `--package`.
+    #[arg(long)]
+    pub workspace: bool,
 
     /// The Python interpreter to use for resolving and syncing.
     ///
@@ -738,6 +859,16 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Populate the cache with the distributions required by the project's lockfile.
+    ///
+    /// Downloads every wheel and builds every source distribution that `uv sync` would need for the
+    /// selected Python version and platform, without creating or modifying a virtual environment.
+    /// This is useful for baking a warm cache into container images or CI base images.
+    ///
+    /// Requires an existing `uv.lock`; the lockfile is read as-is and is never updated.
+    ///
+    /// Local distributions, such as workspace members and path dependencies, are skipped.
+    Warm(Box<CacheWarmArgs>),
     /// Show the cache directory.
     ///
     ///
@@ -778,6 +909,122 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Only prune the ephemeral environments created by `uv run --with` and `uvx`.
+    ///
+    /// Removes the environments that haven't been used within `cache-environment-max-age` days
+    /// (30, by default), then the least recently used environments until the remaining ones fit
+    /// within `cache-environment-max-size` and `cache-environment-max-count`, if set. Environments
+    /// in use by another uv invocation are skipped. The rest of the cache is left untouched.
+    ///
+    /// uv applies the same limits automatically whenever it creates an ephemeral environment.
+    #[arg(long, conflicts_with = "ci")]
+    pub ephemeral: bool,
+}
+
+#[derive(Args)]
+pub struct CacheWarmArgs {
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Disable the development dependency group.
+    ///
+    /// This option is an alias of `--no-group dev`.
+    #[arg(long)]
+    pub no_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long)]
+    pub no_default_groups: bool,
+
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long)]
+    pub all_groups: bool,
+
+    /// Warm the cache for all packages in the workspace.
+    #[arg(long, conflicts_with = "package")]
+    pub all_packages: bool,
+
+    /// Warm the cache for a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with = "all_packages")]
+    pub package: Option<PackageName>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to warm the cache for.
+    ///
+    /// The interpreter determines the wheel tags and markers that are used to select
+    /// distributions, and is used to build any source distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The platform for which the cache should be warmed.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Can also be provided via `--platform`, e.g., `uv cache warm --platform linux`.
+    ///
+    /// WARNING: When specified, uv will select wheels that are compatible with the _target_
+    /// platform. Any distributions that are built from source will be built for the _current_
+    /// platform, and may be incompatible with the _target_ platform.
+    #[arg(long, alias = "platform")]
+    pub python_platform: Option<TargetTriple>,
 }
 
 #[derive(Args)]
@@ -815,6 +1062,27 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Install(PipInstallArgs),
+    /// Download packages into a directory, without installing them.
+    ///
+    /// The requirements are resolved for the target Python version and platform, and the
+    /// resolved wheels and source distributions are written to the destination directory, which
+    /// can later be used as a `--find-links` source for offline installation.
+    #[command(
+        after_help = "Use `uv help pip download` for more details.",
+        after_long_help = ""
+    )]
+    Download(PipDownloadArgs),
+    /// Build wheels for packages into a directory.
+    ///
+    /// The requirements are resolved for the current Python interpreter. Any pre-built wheels
+    /// selected by the resolver are downloaded as-is, while source distributions (including Git
+    /// and local directory sources) are built into wheels using uv's build frontend, reusing
+    /// previously built wheels from the cache.
+    #[command(
+        after_help = "Use `uv help pip wheel` for more details.",
+        after_long_help = ""
+    )]
+    Wheel(PipWheelArgs),
     /// Uninstall packages from an environment.
     #[command(
         after_help = "Use `uv help pip uninstall` for more details.",
@@ -895,13 +1163,24 @@ pub enum ProjectCommand {
     /// Some project state is not created until needed, e.g., the project virtual environment
     /// (`.venv`) and lockfile (`uv.lock`) are lazily created during the first sync.
     Init(InitArgs),
+    /// Migrate a project from another tool to uv.
+    ///
+    /// Converts the `[tool.poetry]` table of a Poetry project, the `Pipfile` of a Pipenv project,
+    /// or the `setup.cfg` or `setup.py` metadata of a setuptools project into standard `[project]`
+    /// metadata, with development dependencies as `[dependency-groups]`, and with any package
+    /// indexes and non-registry dependencies as `[tool.uv]` configuration.
+    ///
+    /// The converted metadata is written to the project's `pyproject.toml` file, which is created if
+    /// necessary. The project is then locked; to skip creating the lockfile, use `--no-lock`.
+    Migrate(MigrateArgs),
     /// Add dependencies to the project.
     ///
     /// Dependencies are added to the project's `pyproject.toml` file.
     ///
     /// If a given dependency exists already, it will be updated to the new version specifier unless
     /// it includes markers that differ from the existing specifier in which case another entry for
-    /// the dependency will be added.
+    /// the dependency will be added. If the existing entry is unconditional, and the new requirement
+    /// only adds markers, the existing entry will be restricted to the new markers instead.
     ///
     /// The lockfile and project environment will be updated to reflect the added dependencies. To
     /// skip updating the lockfile, use `--frozen`. To skip updating the environment, use
@@ -1150,6 +1429,84 @@ fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     }
 }
 
+/// A target platform, as provided to `uv pip download`, which accepts pip-style platform tags in
+/// addition to target triples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipPlatform {
+    /// The target triple for the platform.
+    pub triple: TargetTriple,
+    /// The macOS deployment target from a `macosx_*` platform tag (e.g., `(11, 0)` for
+    /// `macosx_11_0_arm64`), if any.
+    pub macos_deployment_target: Option<(u16, u16)>,
+}
+
+/// Parse a target triple, additionally accepting pip-style platform tags (e.g.,
+/// `manylinux2014_x86_64`, `win_amd64`, or `macosx_11_0_arm64`).
+fn parse_pip_platform(input: &str) -> Result<PipPlatform, String> {
+    if let Ok(triple) = <TargetTriple as clap::ValueEnum>::from_str(input, false) {
+        return Ok(PipPlatform {
+            triple,
+            macos_deployment_target: None,
+        });
+    }
+
+    let mut macos_deployment_target = None;
+    let triple = match input {
+        "win_amd64" => Some("x86_64-pc-windows-msvc".to_string()),
+        "win32" => Some("i686-pc-windows-msvc".to_string()),
+        _ => {
+            if let Some(rest) = input.strip_prefix("macosx_") {
+                let (version, triple) = if let Some(version) = rest.strip_suffix("_arm64") {
+                    (version, "aarch64-apple-darwin")
+                } else if let Some(version) = rest.strip_suffix("_x86_64") {
+                    (version, "x86_64-apple-darwin")
+                } else {
+                    return Err(format!("Unsupported platform: `{input}`"));
+                };
+                let (major, minor) = version
+                    .split_once('_')
+                    .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+                    .ok_or_else(|| format!("Invalid macOS version in platform: `{input}`"))?;
+                macos_deployment_target = Some((major, minor));
+                Some(triple.to_string())
+            } else if let Some((platform, arch)) = ["x86_64", "aarch64"]
+                .iter()
+                .find_map(|arch| Some((input.strip_suffix(arch)?.strip_suffix('_')?, *arch)))
+            {
+                if platform == "linux" {
+                    Some(format!("{arch}-unknown-linux-gnu"))
+                } else if platform.starts_with("musllinux_") {
+                    Some(format!("{arch}-unknown-linux-musl"))
+                } else if platform.starts_with("manylinux") {
+                    Some(format!("{arch}-{platform}"))
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        }
+    };
+
+    let triple = triple
+        .and_then(|triple| <TargetTriple as clap::ValueEnum>::from_str(&triple, false).ok())
+        .ok_or_else(|| format!("Unsupported platform: `{input}`"))?;
+    Ok(PipPlatform {
+        triple,
+        macos_deployment_target,
+    })
+}
+
+/// Parse a `KEY=VALUE` string into an environment variable name and value.
+fn parse_env_var(input: &str) -> Result<(String, String), String> {
+    match input.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!(
+            "Invalid environment variable: `{input}` (expected `KEY=VALUE`)"
+        )),
+    }
+}
+
 // Parse a string, mapping the empty string to `None`.
 #[allow(clippy::unnecessary_wraps)]
 fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
@@ -1578,10 +1935,10 @@ pub struct PipSyncArgs {
     ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
-    /// - Git dependencies are not supported.
+    /// - Git dependencies must either include a hash, which is validated against the built wheel,
+    ///   or be pinned to a full commit SHA.
     /// - Editable installations are not supported.
-    /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
-    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    /// - Local directories must include a hash, which is validated against the built wheel.
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -1888,10 +2245,10 @@ pub struct PipInstallArgs {
     ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
-    /// - Git dependencies are not supported.
+    /// - Git dependencies must either include a hash, which is validated against the built wheel,
+    ///   or be pinned to a full commit SHA.
     /// - Editable installations are not supported.
-    /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
-    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    /// - Local directories must include a hash, which is validated against the built wheel.
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -2082,6 +2439,16 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write a JSON report of the distributions selected for installation to the given file.
+    ///
+    /// The report uses the same format as `pip install --report`, including the resolved version,
+    /// download URL, and hashes of each distribution, along with whether it was installed from the
+    /// cache or built from source. Use `-` to write the report to stdout.
+    ///
+    /// May be combined with `--dry-run` to generate a report without modifying the environment.
+    #[arg(long)]
+    pub report: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2103,52 +2470,438 @@ pub struct PipInstallArgs {
 
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
-pub struct PipUninstallArgs {
-    /// Uninstall all listed packages.
+pub struct PipDownloadArgs {
+    /// Download all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
     #[arg(group = "sources")]
     pub package: Vec<String>,
 
-    /// Uninstall all packages listed in the given requirements files.
+    /// Download all packages listed in the given `requirements.txt` files.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
     #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
     pub requirements: Vec<PathBuf>,
 
-    /// The Python interpreter from which packages should be uninstalled.
+    /// Constrain versions using the given requirements files.
     ///
-    /// By default, uninstallation requires a virtual environment. A path to an alternative Python
-    /// can be provided, but it is only recommended in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's downloaded. However, including a package in a constraints file will
+    /// _not_ trigger the download of that package.
     ///
-    /// See `uv help python` for details on Python discovery and supported request formats.
-    #[arg(
-        long,
-        short,
-        env = EnvVars::UV_PYTHON,
-        verbatim_doc_comment,
-        help_heading = "Python options",
-        value_parser = parse_maybe_string,
-    )]
-    pub python: Option<Maybe<String>>,
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long, short, alias = "constraint", env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
 
-    /// Attempt to use `keyring` for authentication for remote requirements files.
+    /// Override versions using the given requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be downloaded, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(long, alias = "override", env = EnvVars::UV_OVERRIDE, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    #[arg(long, short, alias = "build-constraint", env = EnvVars::UV_BUILD_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub build_constraints: Vec<Maybe<PathBuf>>,
+
+    /// The directory into which the distributions should be downloaded.
     ///
-    /// Defaults to `disabled`.
-    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
-    pub keyring_provider: Option<KeyringProviderType>,
+    /// Defaults to the current working directory.
+    #[arg(long, short = 'd', alias = "destination-directory")]
+    pub dest: Option<PathBuf>,
 
-    /// Use the system Python to uninstall packages.
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Ignore package dependencies, instead only downloading those packages explicitly listed
+    /// on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
+
+    /// Require a matching hash for each requirement.
     ///
-    /// By default, uv uninstalls from the virtual environment in the current working directory or
-    /// any parent directory. The `--system` option instructs uv to instead use the first Python
-    /// found in the system `PATH`.
+    /// By default, uv will verify any available hashes in the requirements file, along with any
+    /// hashes provided by the index, but will not require that all requirements have an associated
+    /// hash.
     ///
-    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
     #[arg(
         long,
-        env = EnvVars::UV_SYSTEM_PYTHON,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter to use when building source distributions.
+    ///
+    /// uv will only build source distributions that lack static metadata. If the interpreter
+    /// differs from the target `--python-version`, the interpreter is only used for builds, and
+    /// the resolution will still target the requested version.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Use the system Python when building source distributions.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Don't build source distributions.
+    ///
+    /// When enabled, resolving will not run arbitrary Python code, and only wheels will be
+    /// downloaded.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't download pre-built wheels.
+    ///
+    /// The source distributions of the given packages will be downloaded instead. The resolver will
+    /// still use pre-built wheels to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only download pre-built wheels; don't download source distributions.
+    ///
+    /// Multiple packages may be provided. Disable source distributions for all packages with
+    /// `:all:`. Clear previously specified packages with `:none:`.
+    ///
+    /// When downloading for a `--python-platform` or `--python-version` other than that of the
+    /// current interpreter, `--only-binary :all:` is recommended, as any source distributions
+    /// that lack static metadata will be built for the _current_ platform.
+    #[arg(long, conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// The Python version for which distributions should be downloaded (e.g., `3.7` or `3.7.9`).
+    ///
+    /// If a patch version is omitted, the minimum patch version is assumed. For example, `3.7` is
+    /// mapped to `3.7.0`.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which distributions should be downloaded.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// For compatibility with pip, a platform tag (e.g., `manylinux2014_x86_64`, `win_amd64`, or
+    /// `macosx_11_0_arm64`) is also accepted, and can be provided via `--platform`. The macOS
+    /// version in a `macosx_*` tag is used as the deployment target, taking precedence over
+    /// `MACOSX_DEPLOYMENT_TARGET`.
+    ///
+    /// Defaults to the platform of the discovered Python interpreter.
+    #[arg(long, alias = "platform", value_parser = parse_pip_platform)]
+    pub python_platform: Option<PipPlatform>,
+
+    /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
+    ///
+    /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
+    /// and will instead use the defined backend.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, value_enum, env = EnvVars::UV_TORCH_BACKEND)]
+    pub torch_backend: Option<TorchMode>,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipWheelArgs {
+    /// Build wheels for all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
+    #[arg(group = "sources")]
+    pub package: Vec<String>,
+
+    /// Build wheels for all packages listed in the given `requirements.txt` files.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's built. However, including a package in a constraints file will _not_
+    /// trigger a build of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long, short, alias = "constraint", env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be built, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(long, alias = "override", env = EnvVars::UV_OVERRIDE, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    #[arg(long, short, alias = "build-constraint", env = EnvVars::UV_BUILD_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub build_constraints: Vec<Maybe<PathBuf>>,
+
+    /// The directory into which the wheels should be written.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(long, short = 'w')]
+    pub wheel_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Ignore package dependencies, instead only building wheels for those packages explicitly
+    /// listed on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, along with any
+    /// hashes provided by the index, but will not require that all requirements have an associated
+    /// hash.
+    ///
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL. For source distributions, the hash is validated against the
+    /// source distribution, rather than the built wheel.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter for which wheels should be built.
+    ///
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Use the system Python to build wheels.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Don't build source distributions.
+    ///
+    /// When enabled, only pre-built wheels will be written, and operations that require building
+    /// distributions will exit with an error.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't use pre-built wheels.
+    ///
+    /// The given packages will be built from source. The resolver will still use pre-built wheels
+    /// to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only use pre-built wheels; don't build source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
+    ///
+    /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
+    /// and will instead use the defined backend.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, value_enum, env = EnvVars::UV_TORCH_BACKEND)]
+    pub torch_backend: Option<TorchMode>,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipUninstallArgs {
+    /// Uninstall all listed packages.
+    #[arg(group = "sources")]
+    pub package: Vec<String>,
+
+    /// Uninstall all packages listed in the given requirements files.
+    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
+
+    /// The Python interpreter from which packages should be uninstalled.
+    ///
+    /// By default, uninstallation requires a virtual environment. A path to an alternative Python
+    /// can be provided, but it is only recommended in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Attempt to use `keyring` for authentication for remote requirements files.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    /// Use the system Python to uninstall packages.
+    ///
+    /// By default, uv uninstalls from the virtual environment in the current working directory or
+    /// any parent directory. The `--system` option instructs uv to instead use the first Python
+    /// found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
         value_parser = clap::builder::BoolishValueParser::new(),
         overrides_with("no_system")
     )]
@@ -2182,6 +2935,16 @@ pub struct PipUninstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Also uninstall any dependencies that would no longer be required by an installed package.
+    ///
+    /// Dependencies of the uninstalled packages are removed (transitively) if no other package in
+    /// the environment requires them, including via optional dependencies. `pip` and `setuptools`
+    /// are never removed in this way.
+    ///
+    /// Combine with `--dry-run` to preview the packages that would be removed.
+    #[arg(long)]
+    pub cascade: bool,
+
     /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
@@ -2321,6 +3084,26 @@ pub struct PipListArgs {
 
 #[derive(Args)]
 pub struct PipCheckArgs {
+    /// Select the output format.
+    ///
+    /// With `json`, the results are written to stdout as a JSON object.
+    #[arg(long, value_enum, default_value_t = CheckFormat::default())]
+    pub output_format: CheckFormat,
+
+    /// For each missing or incompatible dependency, use the resolver to suggest a version that
+    /// satisfies the requirements of all installed packages.
+    ///
+    /// Suggestions are computed against the configured package indexes, and thus require network
+    /// access.
+    #[arg(long, overrides_with("no_suggest"))]
+    pub suggest: bool,
+
+    #[arg(long, overrides_with("suggest"), hide = true)]
+    pub no_suggest: bool,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+
     /// The Python interpreter for which packages should be checked.
     ///
     /// By default, uv checks packages in a virtual environment but will check packages in a system
@@ -2517,6 +3300,13 @@ pub struct BuildArgs {
     #[arg(long, hide = true)]
     pub list: bool,
 
+    /// Select the output format.
+    ///
+    /// With `json`, the paths, filenames, tags, sizes, and SHA-256 digests of the built
+    /// distributions are written to stdout.
+    #[arg(long, value_enum, default_value_t = BuildFormat::default(), conflicts_with = "list")]
+    pub output_format: BuildFormat,
+
     #[arg(long, overrides_with("no_build_logs"), hide = true)]
     pub build_logs: bool,
 
@@ -2524,6 +3314,17 @@ pub struct BuildArgs {
     #[arg(long, overrides_with("build_logs"))]
     pub no_build_logs: bool,
 
+    /// Write the output of the build backend for each build to a file in the given directory.
+    ///
+    /// Each PEP 517 build appends its output to `<name>-<version>-<kind>.log`, including the builds
+    /// of build dependencies. An `index.json` summarizes each build with its outcome, duration,
+    /// interpreter, build environment, and log file.
+    ///
+    /// Other commands that build packages, such as `uv sync` and `uv pip install`, write build logs
+    /// when `UV_BUILD_LOG_DIR` is set.
+    #[arg(long, value_parser = parse_file_path, env = EnvVars::UV_BUILD_LOG_DIR)]
+    pub log_dir: Option<PathBuf>,
+
     /// Always build through PEP 517, don't use the fast path for the uv build backend.
     ///
     /// By default, uv won't create a PEP 517 build environment for packages using the uv build
@@ -2532,6 +3333,14 @@ pub struct BuildArgs {
     #[arg(long, conflicts_with = "list")]
     pub force_pep517: bool,
 
+    /// Build distributions that are byte-identical across rebuilds.
+    ///
+    /// Sets `SOURCE_DATE_EPOCH` to `315532800` (1980-01-01) for the build backend, unless it is
+    /// already set. `SOURCE_DATE_EPOCH` determines the timestamps in the built archives for most
+    /// build backends, including the uv build backend, setuptools, and hatchling.
+    #[arg(long)]
+    pub reproducible: bool,
+
     /// Constrain build dependencies using the given requirements files when building distributions.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -2551,10 +3360,10 @@ pub struct BuildArgs {
     ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
-    /// - Git dependencies are not supported.
+    /// - Git dependencies must either include a hash, which is validated against the built wheel,
+    ///   or be pinned to a full commit SHA.
     /// - Editable installations are not supported.
-    /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
-    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    /// - Local directories must include a hash, which is validated against the built wheel.
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -2599,6 +3408,21 @@ pub struct BuildArgs {
     )]
     pub python: Option<Maybe<String>>,
 
+    /// The platform for which wheels should be built.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-manylinux_2_28` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// The target platform is passed to the build backend through the `_PYTHON_HOST_PLATFORM`
+    /// environment variable, which determines the platform tag of the wheel for build backends
+    /// that use `sysconfig.get_platform()`, such as setuptools and the uv build backend. Any
+    /// extension modules are compiled by the build backend's toolchain, which needs to support the
+    /// target platform. uv exits with an error if the built wheel is not compatible with the target
+    /// platform.
+    #[arg(long, help_heading = "Python options")]
+    pub python_platform: Option<TargetTriple>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -2929,34 +3753,101 @@ pub struct InitArgs {
 
     /// Fill in the `authors` field in the `pyproject.toml`.
     ///
-    /// By default, uv will attempt to infer the author information from some sources (e.g., Git)
-    /// (`auto`). Use `--author-from git` to only infer from Git configuration. Use `--author-from
-    /// none` to avoid inferring the author information.
+    /// By default, uv will attempt to infer the author information from some sources (e.g., Git)
+    /// (`auto`). Use `--author-from git` to only infer from Git configuration. Use `--author-from
+    /// none` to avoid inferring the author information.
+    #[arg(long, value_enum)]
+    pub author_from: Option<AuthorFrom>,
+
+    /// Do not create a `.python-version` file for the project.
+    ///
+    /// By default, uv will create a `.python-version` file containing the minor version of the
+    /// discovered Python interpreter, which will cause subsequent uv commands to use that version.
+    #[arg(long)]
+    pub no_pin_python: bool,
+
+    /// Create a `.python-version` file for the project.
+    ///
+    /// This is the default.
+    #[arg(long, hide = true)]
+    pub pin_python: bool,
+
+    /// Avoid discovering a workspace and create a standalone project.
+    ///
+    /// By default, uv searches for workspaces in the current directory or any parent directory.
+    #[arg(long, alias = "no-project")]
+    pub no_workspace: bool,
+
+    /// The Python interpreter to use to determine the minimum supported Python version.
+    ///
+    /// See `uv help python` to view supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+/// The tool to migrate a project from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum MigrateFrom {
+    /// Migrate from the `[tool.poetry]` table in a `pyproject.toml`.
+    Poetry,
+    /// Migrate from a `Pipfile`.
+    Pipenv,
+    /// Migrate from the metadata in a `setup.cfg` or `setup.py`.
+    Setuptools,
+}
+
+#[derive(Args)]
+pub struct MigrateArgs {
+    /// The path to the project to migrate.
+    ///
+    /// Defaults to the current working directory.
+    pub path: Option<PathBuf>,
+
+    /// The tool to migrate from.
+    ///
+    /// By default, uv will detect the tool based on the files in the project directory, preferring
+    /// Poetry, then Pipenv, then setuptools.
     #[arg(long, value_enum)]
-    pub author_from: Option<AuthorFrom>,
+    pub from: Option<MigrateFrom>,
 
-    /// Do not create a `.python-version` file for the project.
-    ///
-    /// By default, uv will create a `.python-version` file containing the minor version of the
-    /// discovered Python interpreter, which will cause subsequent uv commands to use that version.
+    /// Avoid locking the project after migration.
     #[arg(long)]
-    pub no_pin_python: bool,
+    pub no_lock: bool,
 
-    /// Create a `.python-version` file for the project.
+    /// Perform a dry run, without writing any files.
     ///
-    /// This is the default.
-    #[arg(long, hide = true)]
-    pub pin_python: bool,
+    /// In dry-run mode, uv will print the migrated `pyproject.toml` to stdout.
+    #[arg(long)]
+    pub dry_run: bool,
 
-    /// Avoid discovering a workspace and create a standalone project.
+    /// Run the project's `setup.py` to read the arguments passed to `setup()`.
     ///
-    /// By default, uv searches for workspaces in the current directory or any parent directory.
-    #[arg(long, alias = "no-project")]
-    pub no_workspace: bool,
+    /// Reading the metadata from a `setup.py` requires executing it, which can run arbitrary code.
+    /// By default, the `setup.py` is not executed, and only the metadata in the `setup.cfg` is
+    /// migrated.
+    #[arg(long)]
+    pub run_setup_py: bool,
 
-    /// The Python interpreter to use to determine the minimum supported Python version.
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use when locking the project, or when reading the metadata from a
+    /// `setup.py` file.
     ///
-    /// See `uv help python` to view supported request formats.
+    /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
         long,
         short,
@@ -3083,6 +3974,34 @@ pub struct RunArgs {
     #[arg(long, overrides_with("inexact"))]
     pub exact: bool,
 
+    /// Re-run the command whenever files in the project change.
+    ///
+    /// If the command is still running when a change is detected, it's terminated first. Each run
+    /// syncs the environment, so changes to the `pyproject.toml` or `uv.lock` are picked up.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// The files to watch with `--watch`, as glob patterns relative to the project directory.
+    ///
+    /// Defaults to `**/*.py`. The `pyproject.toml` and `uv.lock` files are always watched. Hidden
+    /// directories (like `.venv`) and `__pycache__` directories are ignored.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "watch")]
+    pub watch_glob: Vec<String>,
+
+    /// Run the command inside a container created from the given OCI image.
+    ///
+    /// The project is mounted into the container at the same path, and the locked environment is
+    /// synced inside the container, in a cache directory that's reused across runs. The container
+    /// runs as the current user, and uv's environment variables and netrc file are forwarded.
+    /// Requires `docker` or `podman` (see `UV_CONTAINER_ENGINE`).
+    ///
+    /// On Linux, the running uv executable is used in the container if the image is for the same
+    /// platform; otherwise, the image must provide `uv`.
+    #[arg(long, value_name = "IMAGE")]
+    pub container: Option<String>,
+
     /// Load environment variables from a `.env` file.
     ///
     /// Can be provided multiple times, with subsequent files overriding values defined in previous
@@ -3094,6 +4013,20 @@ pub struct RunArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_NO_ENV_FILE)]
     pub no_env_file: bool,
 
+    /// Set an environment variable for the command, as `KEY=VALUE`.
+    ///
+    /// Takes precedence over the current environment and any `.env` files. Can be provided
+    /// multiple times.
+    #[arg(long = "env", value_name = "KEY=VALUE", value_parser = parse_env_var)]
+    pub env: Vec<(String, String)>,
+
+    /// Unset an environment variable for the command.
+    ///
+    /// Takes precedence over the current environment and any `.env` files. Can be provided
+    /// multiple times.
+    #[arg(long, value_name = "KEY")]
+    pub unset: Vec<String>,
+
     /// The command to run.
     ///
     /// If the path to a Python script (i.e., ending in `.py`), it will be
@@ -3188,6 +4121,24 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Run the command as a GUI application.
+    ///
+    /// Python scripts and `python` itself are run with `pythonw.exe`. Entry points defined in
+    /// `[project.gui-scripts]` are always run as GUI applications. Only affects Windows.
+    ///
+    /// uv only detaches from the console when the command runs with `pythonw.exe`, such that no
+    /// console window lingers while the application is running. If the interpreter doesn't provide
+    /// `pythonw.exe`, the command runs with `python.exe` and keeps the console.
+    #[arg(long, conflicts_with = "module")]
+    pub gui: bool,
+
+    /// The expected hash of a remote script (e.g., `sha256:...`).
+    ///
+    /// The script is only executed if its contents match the hash. Remote scripts with a hash are
+    /// cached, so they're only downloaded once.
+    #[arg(long, conflicts_with = "module")]
+    pub hash: Option<HashDigest>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3206,6 +4157,16 @@ pub struct RunArgs {
     #[arg(long, conflicts_with = "package")]
     pub all_packages: bool,
 
+    /// Run the command in every workspace member concurrently.
+    ///
+    /// Requires `--all-packages`. The command is run once per member, from the member's directory
+    /// and with the member as the current package (as with `--package`). The output of each command
+    /// is prefixed with the member's name, and uv exits with an error if any of the commands fail.
+    ///
+    /// The number of commands running at once is limited by `UV_CONCURRENT_BUILDS`.
+    #[arg(long, requires = "all_packages")]
+    pub parallel: bool,
+
     /// Run the command in a specific package in the workspace.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
@@ -3529,6 +4490,37 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// After syncing, snapshot the environment into a Python zipapp at the given path.
+    ///
+    /// The zipapp contains the packages in the environment, precompiled to bytecode, and runs an
+    /// entry point of the project. It can be executed directly (or with `python <PATH>`), without
+    /// the overhead of checking that the environment is up-to-date.
+    ///
+    /// The bytecode is specific to the version of the environment's Python interpreter. Compiled
+    /// extension modules can't be imported from a zipapp, and editable installs can't be included,
+    /// so the project must be installed with `--no-editable`.
+    #[arg(long, conflicts_with_all = ["script", "dry_run", "check"])]
+    pub zipapp: Option<PathBuf>,
+
+    /// The name of the entry point to run in the zipapp, from `[project.scripts]`.
+    ///
+    /// Defaults to the project's entry point, if it defines exactly one.
+    #[arg(long, requires = "zipapp")]
+    pub zipapp_entry_point: Option<String>,
+
+    /// The interpreter to run the zipapp with, written to its shebang line.
+    ///
+    /// Defaults to `/usr/bin/env python3`.
+    #[arg(long, requires = "zipapp")]
+    pub python_shebang: Option<String>,
+
+    /// Include compiled extension modules in the zipapp without warning.
+    ///
+    /// Extension modules can't be imported from a zipapp, so uv warns if the environment contains
+    /// any. Packages with a pure-Python fallback for their extension modules still work.
+    #[arg(long, requires = "zipapp")]
+    pub allow_extension_modules: bool,
 }
 
 #[derive(Args)]
@@ -3613,6 +4605,10 @@ pub struct AddArgs {
     pub constraints: Vec<Maybe<PathBuf>>,
 
     /// Apply this marker to all added packages.
+    ///
+    /// The marker is combined with any markers in the requirement itself, e.g.,
+    /// `--marker "sys_platform == 'win32'"`. If a package is already an unconditional dependency,
+    /// its existing entry is restricted to the marker, unless a different version is requested.
     #[arg(long, short, value_parser = MarkerTree::from_str)]
     pub marker: Option<MarkerTree>,
 
@@ -3778,9 +4774,9 @@ pub struct AddArgs {
 
     /// Add the dependency as a workspace member.
     ///
-    /// By default, uv will add path dependencies that are within the workspace directory
-    /// as workspace members. When used with a path dependency, the package will be added
-    /// to the workspace's `members` list in the root `pyproject.toml` file.
+    /// By default, uv will add path dependencies that are within the workspace directory, and not
+    /// excluded from the workspace, as workspace members. When used with a path dependency, the
+    /// package will be added to the workspace's `members` list in the root `pyproject.toml` file.
     #[arg(long, overrides_with = "no_workspace")]
     pub workspace: bool,
 
@@ -3827,6 +4823,21 @@ pub struct RemoveArgs {
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
 
+    /// Report the packages that are no longer required after removing the dependencies.
+    ///
+    /// Lists every package that becomes unreachable from the project's requirements, and is thus
+    /// removed from the lockfile (and uninstalled from the project environment when syncing).
+    #[arg(long, conflicts_with = "frozen")]
+    pub prune_unused: bool,
+
+    /// Perform a dry run, without writing the `pyproject.toml`, the lockfile, or syncing the
+    /// environment.
+    ///
+    /// Reports the packages that would no longer be required after removing the dependencies, as
+    /// with `--prune-unused`.
+    #[arg(long, conflicts_with_all = ["frozen", "locked"])]
+    pub dry_run: bool,
+
     /// Prefer the active virtual environment over the project's virtual environment.
     ///
     /// If the project virtual environment is active or no virtual environment is active, this has
@@ -3898,6 +4909,17 @@ pub struct TreeArgs {
     #[arg(long)]
     pub universal: bool,
 
+    /// Show the graph of the workspace members and their dependencies on one another.
+    ///
+    /// Only the workspace members are shown, along with the dependencies between them, including
+    /// those declared in optional dependencies and dependency groups. The lockfile is not used.
+    #[arg(long, conflicts_with_all = ["script", "universal", "locked", "frozen"])]
+    pub workspace: bool,
+
+    /// The format in which to display the workspace graph.
+    #[arg(long, value_enum, default_value_t = TreeFormat::default(), requires = "workspace")]
+    pub format: TreeFormat,
+
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
@@ -4220,6 +5242,30 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with_all = ["all_packages", "package", "no_emit_project", "no_emit_workspace"])]
     pub script: Option<PathBuf>,
 
+    /// The Python version to export the dependencies for.
+    ///
+    /// For example, pass `--python-version 3.10` to export only the dependencies that would be
+    /// installed on Python 3.10, omitting environment markers from the output.
+    ///
+    /// Defaults to the version of the discovered Python interpreter, or, if no interpreter is
+    /// discovered (as with `--frozen`), the minimum version in the project's `requires-python`.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform to export the dependencies for.
+    ///
+    /// For example, pass `--python-platform aarch64-unknown-linux-gnu` to export only the
+    /// dependencies that would be installed on ARM Linux, omitting environment markers from the
+    /// output. The lockfile is universal, so no interpreter for the target platform is required.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// By default, the export is universal, and includes the dependencies for all platforms.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+
     /// The Python interpreter to use during resolution.
     ///
     /// A Python interpreter is required for building source distributions to determine package
@@ -4292,6 +5338,30 @@ pub enum ToolCommand {
     ///
     /// If the tool was previously installed, the existing tool will generally be replaced.
     Install(ToolInstallArgs),
+    /// Install and uninstall tools to match the tools manifest.
+    ///
+    /// The manifest lists the tools to install, along with any version constraints, extras, and
+    /// additional packages. By default, it's read from `tools.toml` in the uv user configuration
+    /// directory (e.g., `~/.config/uv/tools.toml`).
+    ///
+    /// Tools are installed as with `uv tool install`, such that tools that already satisfy the
+    /// manifest are left as-is. Installed tools that aren't listed in the manifest are uninstalled,
+    /// unless `--inexact` is provided.
+    Sync(ToolSyncArgs),
+    /// Export the installed tools to a tools manifest.
+    ///
+    /// The manifest lists each tool with its installed version (or its source, for tools installed
+    /// from a URL or Git repository), along with any extras, additional packages, and Python
+    /// version, such that the tools can be re-created elsewhere with `uv tool import` or
+    /// `uv tool sync`.
+    ///
+    /// By default, the manifest is written to stdout.
+    Export(ToolExportArgs),
+    /// Install the tools listed in a tools manifest, e.g., as written by `uv tool export`.
+    ///
+    /// Tools are installed as with `uv tool install`, such that tools that already satisfy the
+    /// manifest are left as-is. Installed tools that aren't listed in the manifest are retained.
+    Import(ToolImportArgs),
     /// Upgrade installed tools.
     ///
     /// If a tool was installed with version constraints, they will be respected on upgrade — to
@@ -4408,6 +5478,20 @@ pub struct ToolRunArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_NO_ENV_FILE)]
     pub no_env_file: bool,
 
+    /// Set an environment variable for the command, as `KEY=VALUE`.
+    ///
+    /// Takes precedence over the current environment and any `.env` files. Can be provided
+    /// multiple times.
+    #[arg(long = "env", value_name = "KEY=VALUE", value_parser = parse_env_var)]
+    pub env: Vec<(String, String)>,
+
+    /// Unset an environment variable for the command.
+    ///
+    /// Takes precedence over the current environment and any `.env` files. Can be provided
+    /// multiple times.
+    #[arg(long, value_name = "KEY")]
+    pub unset: Vec<String>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -4524,6 +5608,14 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Install the shell completions and man pages provided by the tool.
+    ///
+    /// Any files the tool's package installs into `share/man`, `share/bash-completion/completions`,
+    /// `share/zsh/site-functions`, or `share/fish/vendor_completions.d` are copied into the user's
+    /// data directory (e.g., `~/.local/share`), and removed when the tool is uninstalled.
+    #[arg(long)]
+    pub data_files: bool,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
@@ -4538,6 +5630,42 @@ pub struct ToolInstallArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct ToolSyncArgs {
+    /// The path to the tools manifest.
+    ///
+    /// Defaults to `tools.toml` in the uv user configuration directory.
+    #[arg(long, env = EnvVars::UV_TOOL_MANIFEST, value_parser = parse_file_path)]
+    pub manifest: Option<PathBuf>,
+
+    /// Do not uninstall tools that aren't listed in the manifest.
+    #[arg(long)]
+    pub inexact: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use to build the tool environments, unless the manifest specifies
+    /// one for the tool.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct ToolListArgs {
     /// Whether to display the path to each tool environment and installed executable.
@@ -4556,6 +5684,27 @@ pub struct ToolListArgs {
     #[arg(long)]
     pub show_extras: bool,
 
+    /// List outdated tools.
+    ///
+    /// The latest version of each tool, as available on the indexes it was installed from, will be
+    /// shown alongside the installed version. Up-to-date tools will be omitted from the output.
+    #[arg(long, overrides_with("no_outdated"))]
+    pub outdated: bool,
+
+    #[arg(long, overrides_with("outdated"), hide = true)]
+    pub no_outdated: bool,
+
+    /// Include the outdated dependencies of each tool.
+    ///
+    /// By default, only the tools themselves are checked. With `--include-dependencies`, the
+    /// packages installed in each tool environment are checked too.
+    #[arg(long, requires = "outdated")]
+    pub include_dependencies: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ToolListFormat::default())]
+    pub output_format: ToolListFormat,
+
     // Hide unused global Python options.
     #[arg(long, hide = true)]
     pub python_preference: Option<PythonPreference>,
@@ -4564,6 +5713,43 @@ pub struct ToolListArgs {
     pub no_python_downloads: bool,
 }
 
+#[derive(Args)]
+pub struct ToolExportArgs {
+    /// Write the tools manifest to the given file.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ToolImportArgs {
+    /// The path to the tools manifest.
+    #[arg(value_parser = parse_file_path)]
+    pub manifest: PathBuf,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use to build the tool environments, unless the manifest specifies
+    /// one for the tool.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct ToolDirArgs {
     /// Show the directory into which `uv tool` will install executables.
@@ -4600,9 +5786,17 @@ pub struct ToolUpgradeArgs {
     pub name: Vec<String>,
 
     /// Upgrade all tools.
+    ///
+    /// Once all tools are upgraded, any tools that were held back from a newer version by their
+    /// version constraints are reported.
     #[arg(long, conflicts_with("name"))]
     pub all: bool,
 
+    /// Perform a dry run, i.e., resolve the upgrades and report the changes, but don't modify any
+    /// tool environments.
+    #[arg(long)]
+    pub dry_run: bool,
+
     /// Upgrade a tool, and specify it to use the given Python interpreter to build its environment.
     /// Use with `--all` to apply to all tools.
     ///
@@ -5092,6 +6286,16 @@ pub struct PythonInstallArgs {
     #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
     pub python_downloads_json_url: Option<String>,
 
+    /// Refuse to install Python distributions that can't be verified with an attestation from
+    /// python-build-standalone.
+    ///
+    /// The GitHub artifact attestations of each archive are verified against the Sigstore trusted
+    /// root. Attestations are not verified for downloads from a mirror or in offline mode.
+    ///
+    /// This can also be set with `UV_PYTHON_INSTALL_REQUIRE_ATTESTATIONS=1`.
+    #[arg(long)]
+    pub require_attestations: bool,
+
     /// Reinstall the requested Python version, if it's already installed.
     ///
     /// By default, uv will exit successfully if the version is already
@@ -5164,6 +6368,18 @@ pub struct PythonUpgradeArgs {
     /// Note that currently, only local paths are supported.
     #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
     pub python_downloads_json_url: Option<String>,
+
+    /// The policy to use when selecting the version to upgrade to.
+    ///
+    /// Upgrades across minor versions are not supported, as they can change the resolution of
+    /// dependencies in existing environments.
+    #[arg(long, value_enum, default_value = "patch")]
+    pub policy: PythonUpgradePolicy,
+
+    /// Perform a dry run, i.e., report the patch versions that each installation would be upgraded
+    /// to, without downloading or installing anything.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Args)]
@@ -5181,6 +6397,13 @@ pub struct PythonUninstallArgs {
     /// Uninstall all managed Python versions.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Uninstall the Python versions even if known virtual environments depend on them.
+    ///
+    /// By default, uv refuses to remove a Python installation that is used by a tool environment,
+    /// the active virtual environment, or the `.venv` in the current directory.
+    #[arg(long)]
+    pub force: bool,
 }
 
 #[derive(Args)]
@@ -5229,6 +6452,13 @@ pub struct PythonFindArgs {
     /// Show the Python version that would be used instead of the path to the interpreter.
     #[arg(long)]
     pub show_version: bool,
+
+    /// The format in which to display the Python interpreter.
+    ///
+    /// With `json`, the interpreter's path, version, implementation, and the source it was
+    /// discovered from are displayed as a JSON object.
+    #[arg(long, value_enum, default_value_t = PythonListFormat::default(), conflicts_with = "show_version")]
+    pub output_format: PythonListFormat,
 }
 
 #[derive(Args)]
@@ -5279,6 +6509,22 @@ pub struct PythonPinArgs {
     pub rm: bool,
 }
 
+#[derive(Args)]
+pub struct CompleteArgs {
+    /// The kind of value to complete.
+    pub target: CompletionTarget,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CompletionTarget {
+    /// The command of `uv run`, i.e., the entry points and tasks of the project.
+    Run,
+    /// The extras of the project.
+    Extra,
+    /// The dependency groups of the project.
+    Group,
+}
+
 #[derive(Args)]
 pub struct GenerateShellCompletionArgs {
     /// The shell to generate the completion script for
@@ -6121,6 +7367,14 @@ pub struct PublishArgs {
     /// uv publish --index pypi
     /// uv publish --publish-url https://upload.pypi.org/legacy/ --check-url https://pypi.org/simple
     /// ```
+    ///
+    /// May be provided multiple times to publish the files to multiple indexes, e.g., an internal
+    /// mirror and PyPI. The files are uploaded to each index in order, and each index is checked
+    /// for existing files separately. Credentials for an index can be provided with the
+    /// `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD` environment variables, which take
+    /// precedence over `--username`, `--password`, and `--token`. To avoid sending the credentials
+    /// of one index to another, `--username`, `--password`, and `--token` can't be used when
+    /// publishing to multiple indexes.
     #[arg(
         long,
         verbatim_doc_comment,
@@ -6128,7 +7382,7 @@ pub struct PublishArgs {
         conflicts_with = "publish_url",
         conflicts_with = "check_url"
     )]
-    pub index: Option<String>,
+    pub index: Vec<String>,
 
     /// The username for the upload.
     #[arg(short, long, env = EnvVars::UV_PUBLISH_USERNAME)]
@@ -6151,11 +7405,12 @@ pub struct PublishArgs {
     )]
     pub token: Option<String>,
 
-    /// Configure using trusted publishing through GitHub Actions.
+    /// Configure using trusted publishing through GitHub Actions or GitLab CI.
     ///
-    /// By default, uv checks for trusted publishing when running in GitHub Actions, but ignores it
-    /// if it isn't configured or the workflow doesn't have enough permissions (e.g., a pull request
-    /// from a fork).
+    /// By default, uv checks for trusted publishing when running in GitHub Actions or GitLab CI, or
+    /// when an OIDC token is provided through `UV_PUBLISH_OIDC_TOKEN`, but ignores it if it isn't
+    /// configured or the workflow doesn't have enough permissions (e.g., a pull request from a
+    /// fork).
     #[arg(long)]
     pub trusted_publishing: Option<TrustedPublishing>,
 
@@ -6193,10 +7448,158 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
 
+    /// The maximum number of files to upload concurrently.
+    ///
+    /// By default, files are uploaded one at a time. If an upload fails, the uploads that are
+    /// already in progress still finish, and the files the index accepted are skipped when
+    /// retrying.
+    #[arg(long, env = EnvVars::UV_CONCURRENT_UPLOADS)]
+    pub concurrent_uploads: Option<NonZeroUsize>,
+
+    /// Generate and upload publish attestations (PEP 740) for each file.
+    ///
+    /// The attestations are signed with Sigstore, using the OIDC identity of the CI provider, and
+    /// uploaded alongside the files. Requires GitHub Actions with the `id-token: write`
+    /// permission, or GitLab CI with an `id_tokens` entry named `SIGSTORE_ID_TOKEN` with the
+    /// `sigstore` audience.
+    #[arg(long)]
+    pub attest: bool,
+
+    /// Sign each file with Sigstore, writing the signature bundle next to the file.
+    ///
+    /// The bundle is written to `<file>.sigstore.json`, e.g., `dist/foo-1.0.0.tar.gz.sigstore.json`,
+    /// for registries and consumers that verify signatures, and the signature is recorded in the
+    /// Sigstore transparency log. The bundle is also sent with the upload, in the `sigstore_bundle`
+    /// field, for registries that store signatures.
+    ///
+    /// If a file already has a bundle that is a valid signature of it, e.g., from a previous
+    /// attempt, the bundle is reused instead of signing the file again.
+    ///
+    /// Without `--sign-key`, signing is keyless and uses the OIDC identity of the CI provider, which
+    /// requires GitHub Actions with the `id-token: write` permission, or GitLab CI with an
+    /// `id_tokens` entry named `SIGSTORE_ID_TOKEN` with the `sigstore` audience.
+    #[arg(long)]
+    pub sign: bool,
+
+    /// Sign each file with the given private key instead of a keyless CI identity.
+    ///
+    /// The key must be an unencrypted PKCS#8 ECDSA P-256 private key in PEM format, e.g., as
+    /// generated with `openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256`.
+    ///
+    /// Implies `--sign`.
+    #[arg(long, value_parser = parse_file_path)]
+    pub sign_key: Option<PathBuf>,
+
+    /// The URL of the Sigstore certificate authority (Fulcio) for `--attest` and `--sign`.
+    ///
+    /// Defaults to the public-good Sigstore instance, <https://fulcio.sigstore.dev>.
+    #[arg(long, env = EnvVars::UV_PUBLISH_FULCIO_URL)]
+    pub fulcio_url: Option<DisplaySafeUrl>,
+
+    /// The URL of the Sigstore transparency log (Rekor) for `--attest` and `--sign`.
+    ///
+    /// Defaults to the public-good Sigstore instance, <https://rekor.sigstore.dev>.
+    #[arg(long, env = EnvVars::UV_PUBLISH_REKOR_URL)]
+    pub rekor_url: Option<DisplaySafeUrl>,
+
+    /// The Sigstore trusted root to verify the signing certificate and the transparency log
+    /// entries against, in the `trusted_root.json` format.
+    ///
+    /// Required when using a Sigstore instance other than the public-good instance with
+    /// `--fulcio-url` and `--rekor-url`. Defaults to the trusted root of the public-good instance,
+    /// which is updated from its TUF repository.
+    #[arg(long, env = EnvVars::UV_PUBLISH_SIGSTORE_TRUSTED_ROOT, value_parser = parse_file_path)]
+    pub sigstore_trusted_root: Option<PathBuf>,
+
+    /// Validate the files without uploading them.
+    ///
+    /// Checks that the metadata is complete, that the long description renders, and that the name
+    /// and version in the metadata match the filename. Long descriptions in reStructuredText, the
+    /// default without a content type, are checked for the problems that make PyPI reject them,
+    /// such as unknown directives or roles and malformed markup. When
+    /// `--check-url` or `--index` is used, the files are also compared against the files in the
+    /// index, to detect files that already exist.
+    ///
+    /// Exits with a non-zero status if any file would fail to upload. No credentials are required.
+    #[arg(long, conflicts_with_all = ["attest", "sign", "sign_key"])]
+    pub check: bool,
+
+    /// Build and publish all packages in the workspace.
+    ///
+    /// The workspace members are built and published one at a time, in the order of their
+    /// dependencies on each other. Before publishing a member, uv waits until the new versions of
+    /// the members it depends on are available on the index, which requires `--index` or
+    /// `--check-url`.
+    ///
+    /// Workspace members that aren't packages, e.g., because they don't declare a build system,
+    /// are skipped.
+    #[arg(long, conflicts_with_all = ["files", "check"])]
+    pub workspace: bool,
+
     #[arg(long, hide = true)]
     pub skip_existing: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct YankArgs {
+    /// The name of the package to yank.
+    pub package: PackageName,
+
+    /// The version of the release to yank.
+    pub version: Version,
+
+    /// The reason for yanking the release, shown to users who install it.
+    #[arg(long, conflicts_with = "undo")]
+    pub reason: Option<String>,
+
+    /// Un-yank a previously yanked release.
+    #[arg(long)]
+    pub undo: bool,
+
+    /// The name of the index in the configuration to yank the release on.
+    ///
+    /// The index must have a `publish-url` setting, see `uv publish --index`, and support yanking
+    /// through its upload API.
+    #[arg(long, env = EnvVars::UV_PUBLISH_INDEX, required = true)]
+    pub index: String,
+
+    /// The username for the index.
+    #[arg(short, long, env = EnvVars::UV_PUBLISH_USERNAME)]
+    pub username: Option<String>,
+
+    /// The password for the index.
+    #[arg(short, long, env = EnvVars::UV_PUBLISH_PASSWORD)]
+    pub password: Option<String>,
+
+    /// The token for the index.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username` and the token as
+    /// `--password` password.
+    #[arg(
+        short,
+        long,
+        env = EnvVars::UV_PUBLISH_TOKEN,
+        conflicts_with = "username",
+        conflicts_with = "password"
+    )]
+    pub token: Option<String>,
+
+    /// Configure using trusted publishing through GitHub Actions or GitLab CI.
+    ///
+    /// See `uv publish --trusted-publishing`.
+    #[arg(long)]
+    pub trusted_publishing: Option<TrustedPublishing>,
+
+    /// Attempt to use `keyring` for authentication.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
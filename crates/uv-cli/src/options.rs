@@ -338,6 +338,7 @@ pub fn resolver_options(
         },
         fork_strategy,
         dependency_metadata: None,
+        build_system_overrides: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         config_settings_package: config_settings_package.map(|config_settings| {
@@ -451,6 +452,7 @@ pub fn resolver_installer_options(
         },
         fork_strategy,
         dependency_metadata: None,
+        build_system_overrides: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         config_settings_package: config_settings_package.map(|config_settings| {
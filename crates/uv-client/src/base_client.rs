@@ -1,12 +1,12 @@
 use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Write;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 use std::{env, io, iter};
 
-use anyhow::Context;
 use anyhow::anyhow;
 use http::{
     HeaderMap, HeaderName, HeaderValue, Method, StatusCode,
@@ -19,9 +19,8 @@ use itertools::Itertools;
 use reqwest::{Client, ClientBuilder, IntoUrl, Proxy, Request, Response, multipart};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
-use reqwest_retry::{
-    DefaultRetryableStrategy, RetryTransientMiddleware, Retryable, RetryableStrategy,
-};
+use reqwest_retry::{DefaultRetryableStrategy, Retryable, RetryableStrategy};
+use tokio::sync::OwnedSemaphorePermit;
 use tracing::{debug, trace};
 use url::ParseError;
 use url::Url;
@@ -38,9 +37,16 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::Connectivity;
+use crate::concurrency::IndexConcurrency;
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{InvalidTlsMiddleware, OfflineMiddleware};
+use crate::oci::OciAuthMiddleware;
+use crate::proxy::ProxyConfig;
+use crate::retry::{IndexRetries, RetryBackoff, RetryMiddleware, RetrySettings, is_prefix};
+use crate::retry_after::RetryAfterMiddleware;
+use crate::s3::{S3Location, S3SigningMiddleware};
+use crate::tls::{IndexTls, LoadedIndexTls, read_identity};
+use crate::trace::TraceMiddleware;
 
 pub const DEFAULT_RETRIES: u32 = 3;
 /// Maximum number of redirects to follow before giving up.
@@ -69,14 +75,23 @@ pub struct BaseClientBuilder<'a> {
     native_tls: bool,
     built_in_root_certs: bool,
     retries: u32,
+    retry_backoff: RetryBackoff,
+    retry_status_codes: Vec<StatusCode>,
+    index_retries: IndexRetries,
+    index_concurrency: IndexConcurrency,
+    index_tls: Vec<(DisplaySafeUrl, IndexTls)>,
+    oci_indexes: Vec<DisplaySafeUrl>,
+    s3_locations: Vec<S3Location>,
     pub connectivity: Connectivity,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     indexes: Indexes,
+    strict_index_tracking: bool,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
+    proxy_config: Option<ProxyConfig>,
     redirect_policy: RedirectPolicy,
     /// Whether credentials should be propagated during cross-origin redirects.
     ///
@@ -131,13 +146,22 @@ impl BaseClientBuilder<'_> {
             built_in_root_certs: false,
             connectivity: Connectivity::Online,
             retries: DEFAULT_RETRIES,
+            retry_backoff: RetryBackoff::default(),
+            retry_status_codes: vec![],
+            index_retries: IndexRetries::default(),
+            index_concurrency: IndexConcurrency::default(),
+            index_tls: vec![],
+            oci_indexes: vec![],
+            s3_locations: vec![],
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
             indexes: Indexes::new(),
+            strict_index_tracking: false,
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
             proxies: vec![],
+            proxy_config: None,
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
         }
@@ -169,23 +193,105 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
-    /// Read the retry count from [`EnvVars::UV_HTTP_RETRIES`] if set, otherwise, make no change.
+    /// Read the retry policy from the environment, i.e., the retry count from
+    /// [`EnvVars::UV_HTTP_RETRIES`], the backoff from [`EnvVars::UV_HTTP_RETRY_BASE_DELAY`],
+    /// [`EnvVars::UV_HTTP_RETRY_MAX_DELAY`], and [`EnvVars::UV_HTTP_RETRY_JITTER`], and any
+    /// additional retryable status codes from [`EnvVars::UV_HTTP_RETRY_STATUS_CODES`].
     ///
-    /// Errors when [`EnvVars::UV_HTTP_RETRIES`] is not a valid u32.
+    /// Errors when any setting is invalid. Prefer [`BaseClientBuilder::retry_settings`] with
+    /// settings that were resolved along with the persistent configuration.
     pub fn retries_from_env(self) -> anyhow::Result<Self> {
-        // TODO(zanieb): We should probably parse this in another layer, but there's not a natural
-        // fit for it right now
-        if let Some(value) = env::var_os(EnvVars::UV_HTTP_RETRIES) {
-            Ok(self.retries(
-                value
-                    .to_string_lossy()
-                    .as_ref()
-                    .parse::<u32>()
-                    .context("Failed to parse `UV_HTTP_RETRIES`")?,
-            ))
+        Ok(self.retry_settings(&RetrySettings::from_env()?))
+    }
+
+    /// Set the retry policy for transient failures. Settings that are unset are left unchanged.
+    #[must_use]
+    pub fn retry_settings(self, retry_settings: &RetrySettings) -> Self {
+        let builder = if let Some(retries) = retry_settings.retries {
+            self.retries(retries)
         } else {
-            Ok(self)
-        }
+            self
+        };
+        builder
+            .retry_backoff(retry_settings.backoff)
+            .retry_status_codes(retry_settings.status_codes.clone())
+    }
+
+    /// Set the backoff between retries of transient failures.
+    #[must_use]
+    pub fn retry_backoff(mut self, retry_backoff: RetryBackoff) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Set additional HTTP status codes to treat as transient failures.
+    #[must_use]
+    pub fn retry_status_codes(mut self, retry_status_codes: Vec<StatusCode>) -> Self {
+        self.retry_status_codes = retry_status_codes;
+        self
+    }
+
+    /// The retry policy for the configured retry count and backoff, for callers that implement
+    /// their own retry loop (e.g., for requests that can't be cloned).
+    pub fn retry_policy(&self) -> ExponentialBackoff {
+        self.retry_backoff.policy(self.retries)
+    }
+
+    /// The strategy for classifying failures as transient, including any additional retryable
+    /// status codes.
+    pub fn retryable_strategy(&self) -> UvRetryableStrategy {
+        UvRetryableStrategy::new(self.retry_status_codes.clone())
+    }
+
+    /// Set per-index overrides for the retry count, as pairs of index root URLs and retry counts.
+    #[must_use]
+    pub fn index_retries(
+        mut self,
+        index_retries: impl IntoIterator<Item = (DisplaySafeUrl, u32)>,
+    ) -> Self {
+        self.index_retries = IndexRetries::new(index_retries);
+        self
+    }
+
+    /// Set per-index limits on the number of concurrent requests, as pairs of index root URLs and
+    /// request limits.
+    #[must_use]
+    pub fn index_concurrency(
+        mut self,
+        index_concurrency: impl IntoIterator<Item = (DisplaySafeUrl, NonZeroUsize)>,
+    ) -> Self {
+        self.index_concurrency = IndexConcurrency::new(index_concurrency);
+        self
+    }
+
+    /// Set per-index TLS settings (i.e., client certificates for mutual TLS and custom CA
+    /// bundles), as pairs of index root URLs and settings.
+    #[must_use]
+    pub fn index_tls(
+        mut self,
+        index_tls: impl IntoIterator<Item = (DisplaySafeUrl, IndexTls)>,
+    ) -> Self {
+        self.index_tls = index_tls.into_iter().collect();
+        self
+    }
+
+    /// Set the root URLs of indexes backed by an OCI registry, which may require token
+    /// authentication.
+    #[must_use]
+    pub fn oci_indexes(mut self, oci_indexes: impl IntoIterator<Item = DisplaySafeUrl>) -> Self {
+        self.oci_indexes = oci_indexes.into_iter().collect();
+        self
+    }
+
+    /// Set the `s3://` URLs of indexes stored in S3 buckets, such that requests to the buckets are
+    /// signed with AWS credentials.
+    #[must_use]
+    pub fn s3_indexes<'url>(mut self, s3_indexes: impl IntoIterator<Item = &'url Url>) -> Self {
+        self.s3_locations = s3_indexes
+            .into_iter()
+            .filter_map(S3Location::from_url)
+            .collect();
+        self
     }
 
     #[must_use]
@@ -194,6 +300,14 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Refuse to combine versions of a package across indexes, unless the indexes declare that
+    /// they track each other (or that they're alternate locations for the project), per PEP 708.
+    #[must_use]
+    pub fn strict_index_tracking(mut self, strict_index_tracking: bool) -> Self {
+        self.strict_index_tracking = strict_index_tracking;
+        self
+    }
+
     #[must_use]
     pub fn built_in_root_certs(mut self, built_in_root_certs: bool) -> Self {
         self.built_in_root_certs = built_in_root_certs;
@@ -242,6 +356,14 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the proxy to use for requests, as configured via uv's settings, which takes precedence
+    /// over the proxy environment variables.
+    #[must_use]
+    pub fn proxy_config(mut self, proxy_config: Option<ProxyConfig>) -> Self {
+        self.proxy_config = proxy_config;
+        self
+    }
+
     #[must_use]
     pub fn redirect(mut self, policy: RedirectPolicy) -> Self {
         self.redirect_policy = policy;
@@ -264,13 +386,8 @@ impl<'a> BaseClientBuilder<'a> {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
-    /// Create a [`RetryPolicy`] for the client.
-    fn retry_policy(&self) -> ExponentialBackoff {
-        let mut builder = ExponentialBackoff::builder();
-        if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
-            builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
-        }
-        builder.build_with_max_retries(self.retries)
+    pub fn is_strict_index_tracking(&self) -> bool {
+        self.strict_index_tracking
     }
 
     pub fn build(&self) -> BaseClient {
@@ -321,6 +438,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_file_exists,
             Security::Secure,
             self.redirect_policy,
+            None,
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -330,8 +448,71 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_file_exists,
             Security::Insecure,
             self.redirect_policy,
+            None,
         );
 
+        // Create a dedicated client for each index with custom TLS settings.
+        let index_clients = self
+            .index_tls
+            .iter()
+            .map(|(root, tls)| {
+                let security = if self
+                    .allow_insecure_host
+                    .iter()
+                    .any(|allow_insecure_host| allow_insecure_host.matches(root))
+                {
+                    Security::Insecure
+                } else {
+                    Security::Secure
+                };
+                let tls = match tls.load() {
+                    Ok(tls) => tls,
+                    Err(err) => {
+                        // Reject requests to the index, rather than silently connecting without
+                        // the configured certificates.
+                        let raw_client = self.create_client(
+                            &user_agent_string,
+                            timeout,
+                            ssl_cert_file_exists,
+                            security,
+                            self.redirect_policy,
+                            None,
+                        );
+                        let client = reqwest_middleware::ClientBuilder::new(raw_client.clone())
+                            .with(InvalidTlsMiddleware::new(root.clone(), err))
+                            .build();
+                        return IndexClient {
+                            root: root.clone(),
+                            client: RedirectClientWithMiddleware {
+                                client,
+                                redirect_policy: self.redirect_policy,
+                                cross_origin_credentials_policy: self
+                                    .cross_origin_credential_policy,
+                            },
+                            raw_client,
+                        };
+                    }
+                };
+                let raw_client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    security,
+                    self.redirect_policy,
+                    Some(&tls),
+                );
+                IndexClient {
+                    root: root.clone(),
+                    client: RedirectClientWithMiddleware {
+                        client: self.apply_middleware(raw_client.clone()),
+                        redirect_policy: self.redirect_policy,
+                        cross_origin_credentials_policy: self.cross_origin_credential_policy,
+                    },
+                    raw_client,
+                }
+            })
+            .collect();
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
             client: self.apply_middleware(raw_client.clone()),
@@ -348,10 +529,14 @@ impl<'a> BaseClientBuilder<'a> {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
+            retry_backoff: self.retry_backoff,
+            index_retries: self.index_retries.clone(),
+            index_concurrency: self.index_concurrency.clone(),
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients,
             timeout,
         }
     }
@@ -369,15 +554,32 @@ impl<'a> BaseClientBuilder<'a> {
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
+        let index_clients = existing
+            .index_clients
+            .iter()
+            .map(|index_client| IndexClient {
+                root: index_client.root.clone(),
+                client: RedirectClientWithMiddleware {
+                    client: self.apply_middleware(index_client.raw_client.clone()),
+                    redirect_policy: self.redirect_policy,
+                    cross_origin_credentials_policy: self.cross_origin_credential_policy,
+                },
+                raw_client: index_client.raw_client.clone(),
+            })
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
+            retry_backoff: self.retry_backoff,
+            index_retries: self.index_retries.clone(),
+            index_concurrency: self.index_concurrency.clone(),
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients,
             timeout: existing.timeout,
         }
     }
@@ -389,6 +591,7 @@ impl<'a> BaseClientBuilder<'a> {
         ssl_cert_file_exists: bool,
         security: Security,
         redirect_policy: RedirectPolicy,
+        index_tls: Option<&LoadedIndexTls>,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -405,14 +608,24 @@ impl<'a> BaseClientBuilder<'a> {
             Security::Insecure => client_builder.danger_accept_invalid_certs(true),
         };
 
-        let client_builder = if self.native_tls || ssl_cert_file_exists {
+        let mut client_builder = if self.native_tls || ssl_cert_file_exists {
             client_builder.tls_built_in_native_certs(true)
         } else {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+        // Trust the index's CA bundle, if any, in addition to the default trust roots.
+        if let Some(tls) = index_tls {
+            for certificate in &tls.ca_certificates {
+                client_builder = client_builder.add_root_certificate(certificate.clone());
+            }
+        }
+
+        // Configure mTLS, preferring the index's client certificate, if any.
+        let client_builder = if let Some(identity) = index_tls.and_then(|tls| tls.identity.clone())
+        {
+            client_builder.identity(identity)
+        } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
                 Ok(identity) => client_builder.identity(identity),
                 Err(err) => {
@@ -426,9 +639,20 @@ impl<'a> BaseClientBuilder<'a> {
 
         // apply proxies
         let mut client_builder = client_builder;
+        // When a proxy auto-config file is in use, it's authoritative: a `DIRECT` result must
+        // connect directly, rather than falling back to the proxy environment variables.
+        if let Some(ProxyConfig::AutoConfig(_)) = &self.proxy_config {
+            client_builder = client_builder.no_proxy();
+        }
         for p in &self.proxies {
             client_builder = client_builder.proxy(p.clone());
         }
+        if let Some(proxy_config) = &self.proxy_config {
+            match proxy_config.proxy() {
+                Ok(proxy) => client_builder = client_builder.proxy(proxy),
+                Err(err) => warn_user_once!("Ignoring invalid proxy `{proxy_config}`: {err}"),
+            }
+        }
         let client_builder = client_builder;
 
         client_builder
@@ -442,15 +666,21 @@ impl<'a> BaseClientBuilder<'a> {
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
-                if self.retries > 0 {
+                if self.retries > 0 || !self.index_retries.is_empty() {
                     // Initialize the retry strategy.
-                    let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
-                        UvRetryableStrategy,
+                    let retry_strategy = RetryMiddleware::new(
+                        self.retries,
+                        self.retry_backoff,
+                        &UvRetryableStrategy::new(self.retry_status_codes.clone()),
+                        &self.index_retries,
                     );
                     client = client.with(retry_strategy);
                 }
 
+                // Pause and reschedule requests to hosts that ask us to back off. This runs
+                // within the retry middleware, such that waiting doesn't consume retries.
+                client = client.with(RetryAfterMiddleware);
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
@@ -472,6 +702,18 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Exchange credentials for bearer tokens on OCI registries. This runs after the
+                // authentication middleware, so that it can forward any credentials it attached.
+                if !self.oci_indexes.is_empty() {
+                    client = client.with(OciAuthMiddleware::new(self.oci_indexes.clone()));
+                }
+
+                // Sign requests to S3 buckets. This must run last, as the signature covers the
+                // final request.
+                if !self.s3_locations.is_empty() {
+                    client = client.with(S3SigningMiddleware::new(self.s3_locations.clone()));
+                }
+
                 // When supplied add the extra middleware
                 if let Some(extra_middleware) = &self.extra_middleware {
                     for middleware in &extra_middleware.0 {
@@ -479,6 +721,16 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Select a proxy for each request via the proxy auto-config file, if any.
+                if let Some(ProxyConfig::AutoConfig(pac)) = &self.proxy_config {
+                    client = client.with(pac.middleware());
+                }
+
+                // Record each request that's sent over the network, if tracing is enabled.
+                if TraceMiddleware::is_enabled() {
+                    client = client.with(TraceMiddleware);
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -499,6 +751,8 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for indexes with custom TLS settings.
+    index_clients: Vec<IndexClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -507,6 +761,24 @@ pub struct BaseClient {
     allow_insecure_host: Vec<TrustedHost>,
     /// The number of retries to attempt on transient errors.
     retries: u32,
+    /// The backoff between retries of transient errors.
+    retry_backoff: RetryBackoff,
+    /// Per-index overrides for the number of retries.
+    index_retries: IndexRetries,
+    /// Per-index limits on the number of concurrent requests.
+    index_concurrency: IndexConcurrency,
+}
+
+/// A dedicated HTTP client for requests to an index (e.g., with a client certificate or a custom
+/// CA bundle).
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The root URL of the index.
+    root: DisplaySafeUrl,
+    /// The HTTP client, with middleware.
+    client: RedirectClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -520,6 +792,13 @@ enum Security {
 impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
+        if let Some(index_client) = self
+            .index_clients
+            .iter()
+            .find(|index_client| is_prefix(&index_client.root, url))
+        {
+            return &index_client.client;
+        }
         if self.disable_ssl(url) {
             &self.dangerous_client
         } else {
@@ -552,7 +831,23 @@ impl BaseClient {
 
     /// The [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+        self.retry_backoff.policy(self.retries)
+    }
+
+    /// The [`RetryPolicy`] for requests to the given URL, respecting any per-index overrides.
+    pub fn retry_policy_for(&self, url: &Url) -> ExponentialBackoff {
+        self.retry_backoff.policy(self.retries_for(url))
+    }
+
+    /// The number of retries for requests to the given URL, respecting any per-index overrides.
+    pub(crate) fn retries_for(&self, url: &Url) -> u32 {
+        self.index_retries.get(url).unwrap_or(self.retries)
+    }
+
+    /// Wait for capacity to make a request to the given URL, respecting any per-index limits on
+    /// the number of concurrent requests.
+    pub(crate) async fn index_permit(&self, url: &Url) -> Option<OwnedSemaphorePermit> {
+        self.index_concurrency.acquire(url).await
     }
 }
 
@@ -877,7 +1172,20 @@ impl<'a> RequestBuilder<'a> {
 }
 
 /// Extends [`DefaultRetryableStrategy`], to log transient request failures and additional retry cases.
-pub struct UvRetryableStrategy;
+#[derive(Debug, Default, Clone)]
+pub struct UvRetryableStrategy {
+    /// Additional status codes to treat as transient failures.
+    status_codes: Arc<[StatusCode]>,
+}
+
+impl UvRetryableStrategy {
+    /// Create a strategy that additionally retries responses with the given status codes.
+    pub fn new(status_codes: impl Into<Arc<[StatusCode]>>) -> Self {
+        Self {
+            status_codes: status_codes.into(),
+        }
+    }
+}
 
 impl RetryableStrategy for UvRetryableStrategy {
     fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
@@ -890,6 +1198,13 @@ impl RetryableStrategy for UvRetryableStrategy {
             {
                 Some(Retryable::Transient)
             }
+            None | Some(Retryable::Fatal)
+                if res
+                    .as_ref()
+                    .is_ok_and(|response| self.status_codes.contains(&response.status())) =>
+            {
+                Some(Retryable::Transient)
+            }
             default => default,
         };
 
@@ -988,7 +1303,7 @@ mod tests {
     use anyhow::Result;
 
     use reqwest::{Client, Method};
-    use wiremock::matchers::method;
+    use wiremock::matchers::{method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::base_client::request_into_redirect;
@@ -1181,4 +1496,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_proxy_config() -> Result<()> {
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("proxied"))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let client = BaseClientBuilder::default()
+            .retries(0)
+            .proxy_config(Some(ProxyConfig::Url(DisplaySafeUrl::parse(&proxy.uri())?)))
+            .build();
+
+        // The host doesn't exist, so the request can only succeed via the proxy.
+        let url = DisplaySafeUrl::parse("http://pypi.invalid/simple/")?;
+        let response = client
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .send()
+            .await?;
+        assert_eq!(response.text().await?, "proxied");
+
+        Ok(())
+    }
 }
@@ -15,6 +15,8 @@ use uv_redacted::DisplaySafeUrl;
 
 use crate::BaseClient;
 use crate::base_client::is_extended_transient_error;
+use crate::concurrency::hold_until_read;
+use crate::trace::record_cache_hit;
 use crate::{
     Error, ErrorKind,
     httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
@@ -207,6 +209,19 @@ pub enum CacheControl<'a> {
     Override(&'a str),
 }
 
+impl<'a> CacheControl<'a> {
+    /// Override the cache control header of the response with a user-provided value (e.g., from
+    /// the index's `cache-control` setting), unless the cache entry must be revalidated (e.g.,
+    /// due to `--refresh`) or may be served stale.
+    #[must_use]
+    pub fn with_override(self, header: Option<&'a str>) -> Self {
+        match (self, header) {
+            (Self::None, Some(header)) => Self::Override(header),
+            (cache_control, _) => cache_control,
+        }
+    }
+}
+
 impl From<Freshness> for CacheControl<'_> {
     fn from(value: Freshness) -> Self {
         match value {
@@ -491,6 +506,14 @@ impl CachedClient {
         cache_control: CacheControl<'_>,
         cached: DataWithCachePolicy,
     ) -> Result<CachedResponse, Error> {
+        // If the user set a custom `Cache-Control` header, apply it to the cached response too, such
+        // that changes to the setting take effect for existing cache entries.
+        let cached = if let CacheControl::Override(header) = cache_control {
+            cached.with_cache_control(header)?
+        } else {
+            cached
+        };
+
         // Apply the cache control header, if necessary.
         match cache_control {
             CacheControl::None | CacheControl::AllowStale | CacheControl::Override(..) => {}
@@ -504,6 +527,7 @@ impl CachedClient {
         Ok(match cached.cache_policy.before_request(&mut req) {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
+                record_cache_hit(req.method(), req.url());
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
@@ -519,6 +543,7 @@ impl CachedClient {
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
+                    record_cache_hit(req.method(), req.url());
                     CachedResponse::FreshCache(cached)
                 }
             },
@@ -546,6 +571,7 @@ impl CachedClient {
     ) -> Result<CachedResponse, Error> {
         let url = DisplaySafeUrl::from(req.url().clone());
         debug!("Sending revalidation request for: {url}");
+        let permit = self.0.index_permit(req.url()).await;
         let mut response = self
             .0
             .execute(req)
@@ -555,6 +581,11 @@ impl CachedClient {
             .error_for_status()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
 
+        // Hold the index's concurrency permit (if any) until the response body has been read.
+        if let Some(permit) = permit {
+            response = hold_until_read(response, permit);
+        }
+
         // If the user set a custom `Cache-Control` header, override it.
         if let CacheControl::Override(header) = cache_control {
             response.headers_mut().insert(
@@ -597,12 +628,18 @@ impl CachedClient {
         let url = DisplaySafeUrl::from(req.url().clone());
         trace!("Sending fresh {} request for {}", req.method(), url);
         let cache_policy_builder = CachePolicyBuilder::new(&req);
+        let permit = self.0.index_permit(req.url()).await;
         let mut response = self
             .0
             .execute(req)
             .await
             .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?;
 
+        // Hold the index's concurrency permit (if any) until the response body has been read.
+        if let Some(permit) = permit {
+            response = hold_until_read(response, permit);
+        }
+
         // If the user set a custom `Cache-Control` header, override it.
         if let CacheControl::Override(header) = cache_control {
             response.headers_mut().insert(
@@ -673,7 +710,7 @@ impl CachedClient {
     ) -> Result<Payload::Target, CachedClientError<CallBackError>> {
         let mut past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_policy = self.uncached().retry_policy_for(req.url());
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
@@ -731,7 +768,7 @@ impl CachedClient {
     ) -> Result<Payload, CachedClientError<CallBackError>> {
         let mut past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_policy = self.uncached().retry_policy_for(req.url());
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
@@ -916,6 +953,22 @@ impl DataWithCachePolicy {
         })
     }
 
+    /// Replaces the `Cache-Control` header of the cached response with the
+    /// given value.
+    ///
+    /// # Errors
+    ///
+    /// If the updated cache policy can't be archived, then this returns an
+    /// error.
+    fn with_cache_control(self, header: &str) -> Result<Self, Error> {
+        let cache_policy =
+            OwnedArchive::deserialize(&self.cache_policy).with_response_cache_control(header);
+        Ok(Self {
+            data: self.data,
+            cache_policy: OwnedArchive::from_unarchived(&cache_policy)?,
+        })
+    }
+
     /// Serializes the given cache policy and arbitrary data blob to an in
     /// memory byte buffer.
     ///
@@ -1028,3 +1081,154 @@ impl DataWithCachePolicy {
         Ok(len_usize)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::time::Duration;
+
+    use reqwest::{Method, Request, Response};
+    use url::Url;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use uv_cache::{Cache, CacheBucket};
+    use uv_redacted::DisplaySafeUrl;
+
+    use super::{CacheControl, CachedClient, DataWithCachePolicy};
+    use crate::httpcache::{BeforeRequest, CachePolicyBuilder};
+    use crate::{BaseClientBuilder, Error, ErrorKind};
+
+    fn request() -> Request {
+        Request::new(
+            Method::GET,
+            Url::parse("https://pypi.org/simple/anyio/").unwrap(),
+        )
+    }
+
+    /// Cache a response with the given `Cache-Control` header.
+    fn cached(cache_control: &str) -> DataWithCachePolicy {
+        let response = Response::from(
+            http::Response::builder()
+                .header(http::header::CACHE_CONTROL, cache_control)
+                .body("data")
+                .unwrap(),
+        );
+        let cache_policy = CachePolicyBuilder::new(&request()).build(&response);
+        let bytes = DataWithCachePolicy::serialize(&cache_policy, b"data").unwrap();
+        DataWithCachePolicy::from_reader(bytes.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn with_override() {
+        assert!(matches!(
+            CacheControl::None.with_override(Some("max-age=600")),
+            CacheControl::Override("max-age=600")
+        ));
+        assert!(matches!(
+            CacheControl::None.with_override(None),
+            CacheControl::None
+        ));
+
+        // `--refresh` and stale reads take precedence over the setting.
+        assert!(matches!(
+            CacheControl::MustRevalidate.with_override(Some("max-age=600")),
+            CacheControl::MustRevalidate
+        ));
+        assert!(matches!(
+            CacheControl::AllowStale.with_override(Some("max-age=600")),
+            CacheControl::AllowStale
+        ));
+    }
+
+    #[test]
+    fn with_cache_control() {
+        // A response that must be revalidated...
+        let cached = cached("no-cache");
+        assert!(matches!(
+            cached.cache_policy.before_request(&mut request()),
+            BeforeRequest::Stale(_)
+        ));
+
+        // ...is fresh once the header is overridden, without losing the data.
+        let cached = cached.with_cache_control("max-age=600").unwrap();
+        assert_eq!(cached.data.as_slice(), b"data");
+        assert!(matches!(
+            cached.cache_policy.before_request(&mut request()),
+            BeforeRequest::Fresh
+        ));
+
+        // And vice versa.
+        let cached = cached.with_cache_control("no-cache").unwrap();
+        assert!(matches!(
+            cached.cache_policy.before_request(&mut request()),
+            BeforeRequest::Stale(_)
+        ));
+    }
+
+    /// The per-index concurrency limit applies to network requests, not to cache hits.
+    #[tokio::test]
+    async fn index_concurrency_cache_hit() -> anyhow::Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(http::header::CACHE_CONTROL, "max-age=600")
+                    .set_body_string("data"),
+            )
+            .mount(&server)
+            .await;
+
+        let root = DisplaySafeUrl::parse(&server.uri())?;
+        let client = CachedClient::new(
+            BaseClientBuilder::default()
+                .index_concurrency([(root.clone(), NonZeroUsize::MIN)])
+                .build(),
+        );
+        let cache = Cache::temp()?;
+        let cache_entry = cache.entry(CacheBucket::Simple, "test", "data.msgpack");
+        let url = Url::parse(&format!("{}/simple/", server.uri()))?;
+        let get = |cache_control| {
+            let req = client
+                .uncached()
+                .for_host(&root)
+                .get(url.clone())
+                .build()
+                .unwrap();
+            let (client, cache_entry, root) = (&client, &cache_entry, &root);
+            tokio::time::timeout(Duration::from_secs(5), async move {
+                client
+                    .get_serde(req, cache_entry, cache_control, async |response| {
+                        response
+                            .text()
+                            .await
+                            .map_err(|err| Error::from(ErrorKind::from_reqwest(root.clone(), err)))
+                    })
+                    .await
+                    .map_err(Error::from)
+            })
+        };
+
+        // Populate the cache; the permit is released once the body has been read.
+        assert_eq!(get(CacheControl::None).await??, "data");
+
+        // While the only permit is held elsewhere, a fresh cache entry is still served...
+        let permit = client.uncached().index_permit(&url).await;
+        assert!(permit.is_some());
+        assert_eq!(get(CacheControl::None).await??, "data");
+
+        // ...but a request that needs the network waits for capacity.
+        assert!(
+            tokio::time::timeout(
+                Duration::from_millis(100),
+                get(CacheControl::MustRevalidate)
+            )
+            .await
+            .is_err()
+        );
+        drop(permit);
+        assert_eq!(get(CacheControl::MustRevalidate).await??, "data");
+
+        Ok(())
+    }
+}
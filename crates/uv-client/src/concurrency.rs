@@ -0,0 +1,147 @@
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use reqwest::{Response, ResponseBuilderExt, Url};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::trace;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::retry::is_prefix;
+
+/// Per-index limits on the number of concurrent requests.
+///
+/// The limits are shared across all clones, such that every client built from the same
+/// [`crate::BaseClientBuilder`] draws from the same pool of permits.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct IndexConcurrency(Arc<[(DisplaySafeUrl, Arc<Semaphore>)]>);
+
+impl IndexConcurrency {
+    /// Create a set of limits from pairs of index root URLs and request limits.
+    pub(crate) fn new(indexes: impl IntoIterator<Item = (DisplaySafeUrl, NonZeroUsize)>) -> Self {
+        Self(
+            indexes
+                .into_iter()
+                .map(|(root, limit)| (root, Arc::new(Semaphore::new(limit.get()))))
+                .collect(),
+        )
+    }
+
+    /// Wait for capacity on the index that hosts the given URL, if it has a limit.
+    ///
+    /// The returned permit should be held until the response body has been read, e.g., with
+    /// [`hold_until_read`].
+    pub(crate) async fn acquire(&self, url: &Url) -> Option<OwnedSemaphorePermit> {
+        let (root, semaphore) = self.0.iter().find(|(root, _)| is_prefix(root, url))?;
+        if semaphore.available_permits() == 0 {
+            trace!("Waiting for a concurrent request slot on: {root}");
+        }
+        semaphore.clone().acquire_owned().await.ok()
+    }
+}
+
+/// Attach a concurrency permit to the body of a response, such that it's released once the body
+/// has been read (or the response is dropped), rather than when the headers are received.
+pub(crate) fn hold_until_read(response: Response, permit: OwnedSemaphorePermit) -> Response {
+    let url = response.url().clone();
+    let (mut parts, body) = http::Response::from(response).into_parts();
+    let body = PermitBody {
+        inner: body,
+        permit: Some(permit),
+    };
+
+    // The URL is stored outside the extensions, so it's lost in the round-trip through `http`.
+    let (url_parts, ()) = http::Response::builder()
+        .url(url)
+        .body(())
+        .expect("response with only a URL must be valid")
+        .into_parts();
+    parts.extensions.extend(url_parts.extensions);
+
+    Response::from(http::Response::from_parts(parts, reqwest::Body::wrap(body)))
+}
+
+/// A response body that holds a concurrency permit until the end of the stream.
+struct PermitBody {
+    inner: reqwest::Body,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Body for PermitBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+        if matches!(poll, Poll::Ready(None | Some(Err(_)))) {
+            self.permit.take();
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn hold_until_read() {
+        let concurrency = IndexConcurrency::new([(
+            DisplaySafeUrl::parse("https://mirror.example.com/pypi/").unwrap(),
+            NonZeroUsize::new(1).unwrap(),
+        )]);
+        let url = Url::parse("https://mirror.example.com/pypi/simple/flask/").unwrap();
+        let response = Response::from(
+            http::Response::builder()
+                .url(url.clone())
+                .body("data")
+                .unwrap(),
+        );
+
+        let permit = concurrency.acquire(&url).await.unwrap();
+        let response = super::hold_until_read(response, permit);
+        assert_eq!(concurrency.0[0].1.available_permits(), 0);
+
+        // The response is otherwise unchanged.
+        assert_eq!(response.url(), &url);
+        assert_eq!(response.content_length(), Some(4));
+
+        // The permit is released once the body has been read.
+        assert_eq!(response.bytes().await.unwrap().as_ref(), b"data");
+        assert_eq!(concurrency.0[0].1.available_permits(), 1);
+    }
+
+    #[tokio::test]
+    async fn acquire() {
+        let concurrency = IndexConcurrency::new([(
+            DisplaySafeUrl::parse("https://mirror.example.com/pypi/").unwrap(),
+            NonZeroUsize::new(1).unwrap(),
+        )]);
+        let url = Url::parse("https://mirror.example.com/pypi/simple/flask/").unwrap();
+
+        let permit = concurrency.acquire(&url).await;
+        assert!(permit.is_some());
+        assert_eq!(concurrency.0[0].1.available_permits(), 0);
+        drop(permit);
+        assert_eq!(concurrency.0[0].1.available_permits(), 1);
+
+        // Requests to other hosts are unlimited.
+        let url = Url::parse("https://pypi.org/simple/flask/").unwrap();
+        assert!(concurrency.acquire(&url).await.is_none());
+    }
+}
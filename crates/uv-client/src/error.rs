@@ -38,11 +38,31 @@ impl Error {
         ErrorKind::BadHtml { source: err, url }.into()
     }
 
+    /// Create a new error from an XML parsing error.
+    pub(crate) fn from_xml_err(err: roxmltree::Error, url: DisplaySafeUrl) -> Self {
+        ErrorKind::BadXml { source: err, url }.into()
+    }
+
     /// Returns `true` if this error corresponds to an offline error.
     pub(crate) fn is_offline(&self) -> bool {
         matches!(&*self.kind, ErrorKind::Offline(_))
     }
 
+    /// Returns `true` if this error indicates that the server is unavailable (i.e., a connection
+    /// failure, a timeout, or a server error), rather than an issue with the request itself.
+    pub fn is_unavailable(&self) -> bool {
+        let mut kind = &*self.kind;
+        while let ErrorKind::RequestWithRetries { source, .. } = kind {
+            kind = source;
+        }
+        match kind {
+            ErrorKind::WrappedReqwestError(_, err) => {
+                err.status().is_none_or(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+
     /// Returns `true` if this error corresponds to an I/O "not found" error.
     pub(crate) fn is_file_not_exists(&self) -> bool {
         let ErrorKind::Io(err) = &*self.kind else {
@@ -128,6 +148,18 @@ impl Error {
         false
     }
 
+    /// Returns `true` if the error indicates that the data returned by a series of range requests
+    /// didn't form a valid wheel (e.g., due to a proxy that ignores the requested ranges), such
+    /// that the wheel should be downloaded in full instead.
+    pub fn is_http_range_requests_corrupt(&self) -> bool {
+        // Errors reading from the server (e.g., a dropped connection) are surfaced as-is, as are
+        // wheels that are valid archives but lack valid metadata.
+        matches!(
+            &*self.kind,
+            ErrorKind::Zip(_, err) if !matches!(err, ZipError::UpstreamReadError(_))
+        )
+    }
+
     /// Returns `true` if the error is due to the server not supporting HTTP streaming. Most
     /// commonly, this is due to serving ZIP files with features that are incompatible with
     /// streaming, like data descriptors.
@@ -178,6 +210,12 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the local index")]
     FileNotFound(String),
 
+    /// The package was found on multiple indexes that don't track each other, per PEP 708.
+    #[error(
+        "Package `{0}` was found at `{1}` and `{2}`, but neither index declares that it tracks the other"
+    )]
+    UntrackedIndexes(PackageName, DisplaySafeUrl, DisplaySafeUrl),
+
     /// The metadata file could not be parsed.
     #[error("Couldn't parse metadata of {0} from {1}")]
     MetadataParseError(
@@ -213,6 +251,12 @@ pub enum ErrorKind {
         url: DisplaySafeUrl,
     },
 
+    #[error("Received some unexpected XML from {}", url)]
+    BadXml {
+        source: roxmltree::Error,
+        url: DisplaySafeUrl,
+    },
+
     #[error("Failed to read zip with range requests: `{0}`")]
     AsyncHttpRangeReader(DisplaySafeUrl, #[source] AsyncHttpRangeReaderError),
 
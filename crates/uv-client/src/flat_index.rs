@@ -15,6 +15,7 @@ use uv_small_str::SmallString;
 
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleHtml;
+use crate::s3::{self, S3Location};
 use crate::{CachedClient, Connectivity, Error, ErrorKind, OwnedArchive};
 
 #[derive(Debug, thiserror::Error)]
@@ -149,6 +150,10 @@ impl<'a> FlatIndexClient<'a> {
                 Self::read_from_directory(&path, index)
                     .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))
             }
+            IndexUrl::Url(url) if url.scheme() == "s3" => self
+                .read_from_s3(url, index)
+                .await
+                .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err)),
             IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
                 .read_from_url(url, index)
                 .await
@@ -195,7 +200,7 @@ impl<'a> FlatIndexClient<'a> {
                     .text()
                     .await
                     .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                let SimpleHtml { base, files } = SimpleHtml::parse(&text, &url)
+                let SimpleHtml { base, files, .. } = SimpleHtml::parse(&text, &url)
                     .map_err(|err| Error::from_html_err(err, url.clone()))?;
 
                 // Convert to a reference-counted string.
@@ -253,6 +258,37 @@ impl<'a> FlatIndexClient<'a> {
         }
     }
 
+    /// Read a flat remote index from an `s3://bucket/prefix` URL, listing the objects directly
+    /// beneath the prefix.
+    async fn read_from_s3(
+        &self,
+        url: &DisplaySafeUrl,
+        flat_index: &IndexUrl,
+    ) -> Result<FlatIndexEntries, Error> {
+        if self.connectivity.is_offline() {
+            return Ok(FlatIndexEntries::offline());
+        }
+        let location =
+            S3Location::from_url(url).ok_or_else(|| ErrorKind::CannotBeABase(url.clone()))?;
+        let region = s3::region().await;
+        let client = self
+            .client
+            .uncached()
+            .for_host(&location.bucket_url(&region));
+        let entries = s3::list_files(client, &location, &region)
+            .await?
+            .into_iter()
+            .filter_map(|file| {
+                Some(FlatIndexEntry {
+                    filename: DistFilename::try_from_normalized_filename(&file.filename)?,
+                    file,
+                    index: flat_index.clone(),
+                })
+            })
+            .collect();
+        Ok(FlatIndexEntries::from_entries(entries))
+    }
+
     /// Read a flat remote index from a `--find-links` directory.
     fn read_from_directory(
         path: &Path,
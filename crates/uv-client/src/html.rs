@@ -9,6 +9,7 @@ use uv_pep440::VersionSpecifiers;
 use uv_pypi_types::{BaseUrl, CoreMetadata, File, Hashes, Yanked};
 use uv_pypi_types::{HashError, LenientVersionSpecifiers};
 use uv_redacted::DisplaySafeUrl;
+use uv_small_str::SmallString;
 
 /// A parsed structure from PyPI "HTML" index format for a single package.
 #[derive(Debug, Clone)]
@@ -17,6 +18,11 @@ pub(crate) struct SimpleHtml {
     pub(crate) base: BaseUrl,
     /// The list of [`File`]s available for download sorted by filename.
     pub(crate) files: Vec<File>,
+    /// The URLs of the projects that this project tracks, from the `pypi:tracks` meta tags.
+    pub(crate) tracks: Vec<SmallString>,
+    /// The URLs of the repositories that extend this project's namespace, from the
+    /// `pypi:alternate-locations` meta tags.
+    pub(crate) alternate_locations: Vec<SmallString>,
 }
 
 impl SimpleHtml {
@@ -63,7 +69,49 @@ impl SimpleHtml {
         // probably be the thing that does the sorting.)
         files.sort_unstable_by(|f1, f2| f1.filename.cmp(&f2.filename));
 
-        Ok(Self { base, files })
+        // Parse the PEP 708 `<meta>` tags, which declare the project's relationship to other
+        // repositories.
+        let mut tracks = Vec::new();
+        let mut alternate_locations = Vec::new();
+        for meta in dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|tag| tag.name().as_bytes() == b"meta")
+        {
+            let Some((name, content)) = Self::parse_meta(meta)? else {
+                continue;
+            };
+            match name.as_str() {
+                "pypi:tracks" => tracks.push(content),
+                "pypi:alternate-locations" => alternate_locations.push(content),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            base,
+            files,
+            tracks,
+            alternate_locations,
+        })
+    }
+
+    /// Parse the `name` and `content` from a `<meta>` tag.
+    fn parse_meta(meta: &HTMLTag) -> Result<Option<(String, SmallString)>, Error> {
+        let Some(Some(name)) = meta.attributes().get("name") else {
+            return Ok(None);
+        };
+        let Some(Some(content)) = meta.attributes().get("content") else {
+            return Ok(None);
+        };
+        let name = std::str::from_utf8(name.as_bytes())?;
+        let content = std::str::from_utf8(content.as_bytes())?;
+        let content = html_escape::decode_html_entities(content);
+        Ok(Some((
+            name.to_ascii_lowercase(),
+            SmallString::from(content.as_ref()),
+        )))
     }
 
     /// Parse the `href` from a `<base>` tag.
@@ -315,6 +363,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -372,6 +422,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -432,6 +484,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -489,6 +543,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -546,6 +602,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -601,6 +659,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -656,6 +716,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -694,6 +756,8 @@ mod tests {
                 },
             ),
             files: [],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -732,6 +796,8 @@ mod tests {
                 },
             ),
             files: [],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -787,6 +853,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -842,6 +910,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -898,6 +968,8 @@ mod tests {
                         yanked: None,
                     },
                 ],
+                tracks: [],
+                alternate_locations: [],
             },
         )
         "#);
@@ -955,6 +1027,8 @@ mod tests {
                         yanked: None,
                     },
                 ],
+                tracks: [],
+                alternate_locations: [],
             },
         )
         "#);
@@ -1045,6 +1119,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -1160,6 +1236,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -1227,6 +1305,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
@@ -1371,7 +1451,41 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "#);
     }
+
+    #[test]
+    fn parse_project_locations() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<head>
+<meta name="pypi:repository-version" content="1.2">
+<meta name="pypi:tracks" content="https://pypi.org/simple/holygrail/">
+<meta name="pypi:alternate-locations" content="https://pypi.org/simple/holygrail/">
+<meta name="pypi:alternate-locations" content="https://example.com/simple/holygrail/">
+</head>
+<body>
+<h1>Links for holygrail</h1>
+<a href="/holygrail-1.0-py3-none-any.whl">holygrail-1.0-py3-none-any.whl</a><br/>
+</body>
+</html>
+    "#;
+        let base = Url::parse("https://example.com/simple/holygrail/").unwrap();
+        let result = SimpleHtml::parse(text, &base).unwrap();
+        assert_eq!(
+            result.tracks,
+            [SmallString::from("https://pypi.org/simple/holygrail/")]
+        );
+        assert_eq!(
+            result.alternate_locations,
+            [
+                SmallString::from("https://pypi.org/simple/holygrail/"),
+                SmallString::from("https://example.com/simple/holygrail/"),
+            ]
+        );
+    }
 }
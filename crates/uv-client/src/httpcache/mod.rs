@@ -273,6 +273,15 @@ impl CachePolicy {
         // There's no way (other than OOM) for serializing this type to fail.
         OwnedArchive::from_unarchived(self).expect("all possible values can be archived")
     }
+
+    /// Replace the directives from the `Cache-Control` header of the stored
+    /// response, e.g., to apply a user-provided override to a response that
+    /// was cached before the override was set.
+    #[must_use]
+    pub fn with_response_cache_control(mut self, header: &str) -> Self {
+        self.response.headers.cc = std::iter::once(header).collect();
+        self
+    }
 }
 
 impl ArchivedCachePolicy {
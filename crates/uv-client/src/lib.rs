@@ -6,21 +6,41 @@ pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithC
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use pac::{ProxyAutoConfig, ProxyAutoConfigSource};
+pub use proxy::ProxyConfig;
+pub use rate_limit::{RateLimited, set_download_rate_limit};
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleMetadata,
     SimpleMetadatum, VersionFiles,
 };
+pub use resumable::ResumableStream;
+pub use retry::{RetryBackoff, RetrySettings};
+pub use retry_after::{RetryAfterSummary, retry_after_summary};
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use tls::{ClientCertificate, IndexTls};
+pub use trace::{flush_http_trace, set_http_trace};
 
 mod base_client;
 mod cached_client;
+mod concurrency;
 mod error;
 mod flat_index;
 mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod mirrors;
+mod oci;
+mod pac;
+mod proxy;
+mod rate_limit;
 mod registry_client;
 mod remote_metadata;
+mod resumable;
+mod retry;
+mod retry_after;
 mod rkyvutil;
+mod s3;
 mod tls;
+mod trace;
+mod tracking;
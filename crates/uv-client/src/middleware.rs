@@ -1,10 +1,13 @@
 use http::Extensions;
 use std::fmt::Debug;
+use std::sync::Arc;
 use uv_redacted::DisplaySafeUrl;
 
 use reqwest::{Request, Response};
 use reqwest_middleware::{Middleware, Next};
 
+use crate::tls::CertificateError;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -49,3 +52,46 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// An error indicating that the TLS settings for an index could not be loaded.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid TLS settings for `{root}`")]
+pub(crate) struct InvalidTlsError {
+    root: DisplaySafeUrl,
+    #[source]
+    err: Arc<CertificateError>,
+}
+
+/// A middleware that rejects all requests to an index whose TLS settings (e.g., a client
+/// certificate) could not be loaded.
+pub(crate) struct InvalidTlsMiddleware {
+    root: DisplaySafeUrl,
+    err: Arc<CertificateError>,
+}
+
+impl InvalidTlsMiddleware {
+    pub(crate) fn new(root: DisplaySafeUrl, err: CertificateError) -> Self {
+        Self {
+            root,
+            err: Arc::new(err),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for InvalidTlsMiddleware {
+    async fn handle(
+        &self,
+        _req: Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        Err(reqwest_middleware::Error::Middleware(
+            InvalidTlsError {
+                root: self.root.clone(),
+                err: self.err.clone(),
+            }
+            .into(),
+        ))
+    }
+}
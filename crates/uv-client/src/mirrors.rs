@@ -0,0 +1,214 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use reqwest::Url;
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_distribution_types::{IndexUrl, MirrorSelection};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::BaseClient;
+
+/// The maximum time to wait for a mirror to respond to a health check.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The order in which to query each index and its mirrors, shared across requests such that a
+/// mirror that fails over is preferred for the remainder of the invocation.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct IndexMirrors(Arc<Mutex<FxHashMap<IndexUrl, Vec<IndexUrl>>>>);
+
+impl IndexMirrors {
+    /// Return the index and its mirrors, in the order in which they should be queried.
+    pub(crate) async fn candidates(
+        &self,
+        client: &BaseClient,
+        index: &IndexUrl,
+        mirrors: &[IndexUrl],
+        selection: MirrorSelection,
+    ) -> Vec<IndexUrl> {
+        if let Some(candidates) = self.0.lock().unwrap().get(index) {
+            return candidates.clone();
+        }
+
+        let candidates = std::iter::once(index).chain(mirrors).cloned();
+        let candidates = match selection {
+            MirrorSelection::Ordered => candidates.collect(),
+            MirrorSelection::Latency => Self::by_latency(client, candidates).await,
+        };
+
+        self.0
+            .lock()
+            .unwrap()
+            .entry(index.clone())
+            .or_insert(candidates)
+            .clone()
+    }
+
+    /// Mark a mirror of the given index as unavailable, such that it's queried last.
+    pub(crate) fn demote(&self, index: &IndexUrl, mirror: &IndexUrl) {
+        let mut state = self.0.lock().unwrap();
+        let Some(candidates) = state.get_mut(index) else {
+            return;
+        };
+        if let Some(position) = candidates.iter().position(|candidate| candidate == mirror) {
+            let mirror = candidates.remove(position);
+            candidates.push(mirror);
+        }
+    }
+
+    /// Sort the candidates by the latency of a health check, with unreachable candidates last.
+    async fn by_latency(
+        client: &BaseClient,
+        candidates: impl Iterator<Item = IndexUrl>,
+    ) -> Vec<IndexUrl> {
+        let mut latencies = futures::stream::iter(candidates)
+            .map(async |candidate| {
+                let latency = Self::health_check(client, &candidate).await;
+                if let Some(latency) = latency {
+                    debug!(
+                        "Health check for {candidate} took {}ms",
+                        latency.as_millis()
+                    );
+                } else {
+                    debug!("Health check for {candidate} failed");
+                }
+                (candidate, latency.unwrap_or(Duration::MAX))
+            })
+            .buffered(8)
+            .collect::<Vec<_>>()
+            .await;
+        latencies.sort_by_key(|(_, latency)| *latency);
+        latencies
+            .into_iter()
+            .map(|(candidate, _)| candidate)
+            .collect()
+    }
+
+    /// Return the time taken for the index to respond to a `HEAD` request, or `None` if it's
+    /// unreachable or returns a server error.
+    async fn health_check(client: &BaseClient, index: &IndexUrl) -> Option<Duration> {
+        if matches!(index, IndexUrl::Path(_)) {
+            return Some(Duration::ZERO);
+        }
+        let url = index.url();
+        let start = Instant::now();
+        let response = tokio::time::timeout(
+            HEALTH_CHECK_TIMEOUT,
+            client.for_host(url).head(Url::from(url.clone())).send(),
+        )
+        .await
+        .ok()?
+        .ok()?;
+        if response.status().is_server_error() {
+            return None;
+        }
+        Some(start.elapsed())
+    }
+}
+
+/// Map a URL hosted on one index to the equivalent URL on another (e.g., a mirror of the index).
+///
+/// Files are assumed to be served at the same path relative to the root of each index, i.e., the
+/// index URL without its final path segment (like `/simple`). Returns `None` if the URL isn't
+/// hosted under the root of the source index (e.g., a file served from a separate CDN).
+pub(crate) fn mirror_url(
+    url: &DisplaySafeUrl,
+    source: &IndexUrl,
+    target: &IndexUrl,
+) -> Option<DisplaySafeUrl> {
+    let source_root = index_root(source.url());
+    if url.scheme() != source_root.scheme()
+        || url.host_str() != source_root.host_str()
+        || url.port_or_known_default() != source_root.port_or_known_default()
+    {
+        return None;
+    }
+    let relative = url.path().strip_prefix(source_root.path())?;
+    let mut mirrored = index_root(target.url());
+    let path = format!("{}{relative}", mirrored.path());
+    mirrored.set_path(&path);
+    mirrored.set_query(url.query());
+    mirrored.set_fragment(url.fragment());
+    Some(mirrored)
+}
+
+/// Returns `true` if the URL is hosted under the root of the index.
+pub(crate) fn is_hosted_on(url: &DisplaySafeUrl, index: &IndexUrl) -> bool {
+    mirror_url(url, index, index).is_some()
+}
+
+/// Return the root of an index, i.e., its URL without the final path segment, with a trailing
+/// slash.
+fn index_root(url: &DisplaySafeUrl) -> DisplaySafeUrl {
+    let mut root = url.clone();
+    let path = url.path().trim_end_matches('/');
+    let parent = path.rfind('/').map_or("/", |index| &path[..=index]);
+    root.set_path(parent);
+    root.set_query(None);
+    root.set_fragment(None);
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn demote() {
+        let index = IndexUrl::from_str("https://primary.example.com/simple").unwrap();
+        let mirror = IndexUrl::from_str("https://secondary.example.com/simple").unwrap();
+        let mirrors = IndexMirrors::default();
+        mirrors
+            .0
+            .lock()
+            .unwrap()
+            .insert(index.clone(), vec![index.clone(), mirror.clone()]);
+
+        mirrors.demote(&index, &index);
+        assert_eq!(
+            mirrors.0.lock().unwrap().get(&index),
+            Some(&vec![mirror, index.clone()])
+        );
+    }
+
+    #[test]
+    fn mirror_urls() {
+        let index = IndexUrl::from_str("https://primary.example.com/simple").unwrap();
+        let mirror = IndexUrl::from_str("https://secondary.example.com/pypi/simple/").unwrap();
+
+        // Files under the root of the index are mapped onto the mirror.
+        let url = DisplaySafeUrl::parse(
+            "https://primary.example.com/packages/ab/cd/foo-1.0-py3-none-any.whl#sha256=abc",
+        )
+        .unwrap();
+        assert_eq!(
+            mirror_url(&url, &index, &mirror).map(|url| url.to_string()),
+            Some(
+                "https://secondary.example.com/pypi/packages/ab/cd/foo-1.0-py3-none-any.whl#sha256=abc"
+                    .to_string()
+            )
+        );
+
+        // And back again.
+        let url = DisplaySafeUrl::parse(
+            "https://secondary.example.com/pypi/packages/ab/cd/foo-1.0.tar.gz",
+        )
+        .unwrap();
+        assert_eq!(
+            mirror_url(&url, &mirror, &index).map(|url| url.to_string()),
+            Some("https://primary.example.com/packages/ab/cd/foo-1.0.tar.gz".to_string())
+        );
+
+        // Files hosted elsewhere are left alone.
+        let url =
+            DisplaySafeUrl::parse("https://files.example.com/packages/foo-1.0.tar.gz").unwrap();
+        assert_eq!(mirror_url(&url, &index, &mirror), None);
+        let url =
+            DisplaySafeUrl::parse("https://secondary.example.com/other/foo-1.0.tar.gz").unwrap();
+        assert_eq!(mirror_url(&url, &mirror, &index), None);
+    }
+}
@@ -0,0 +1,462 @@
+//! Support for package indexes backed by an OCI registry.
+//!
+//! Distributions are stored as OCI artifacts (e.g., as pushed by `oras push`): each package is a
+//! repository beneath the index URL (e.g., `https://ghcr.io/acme/python` hosts `flask` in the
+//! `acme/python/flask` repository), and every layer of every tagged manifest is a distribution,
+//! named by its `org.opencontainers.image.title` annotation.
+
+use std::sync::Mutex;
+
+use futures::{StreamExt, TryStreamExt};
+use http::header::{AUTHORIZATION, LINK, WWW_AUTHENTICATE};
+use http::{Extensions, HeaderMap, HeaderValue, StatusCode};
+use reqwest::{Request, Response, Url};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+use tracing::{debug, trace};
+
+use uv_normalize::PackageName;
+use uv_pypi_types::{File, Hashes};
+use uv_redacted::DisplaySafeUrl;
+use uv_small_str::SmallString;
+
+use crate::{Error, ErrorKind, RedirectClientWithMiddleware};
+
+/// The annotation that holds the filename of a layer.
+const TITLE_ANNOTATION: &str = "org.opencontainers.image.title";
+
+/// The media types to accept for manifests.
+const MANIFEST_MEDIA_TYPES: &str = "application/vnd.oci.image.manifest.v1+json, application/vnd.docker.distribution.manifest.v2+json";
+
+/// The number of manifests to fetch concurrently.
+const MANIFEST_CONCURRENCY: usize = 8;
+
+/// Return the URL of the repository for a package on an OCI index, with a trailing slash (e.g.,
+/// `https://ghcr.io/v2/acme/python/flask/` for `flask` on `https://ghcr.io/acme/python`).
+pub(crate) fn repository_url(index: &DisplaySafeUrl, package_name: &PackageName) -> DisplaySafeUrl {
+    let namespace = index.path().trim_matches('/');
+    let mut url = index.clone();
+    if namespace.is_empty() {
+        url.set_path(&format!("/v2/{package_name}/"));
+    } else {
+        url.set_path(&format!("/v2/{namespace}/{package_name}/"));
+    }
+    url.set_query(None);
+    url.set_fragment(None);
+    url
+}
+
+/// A page of tags, as returned by the `tags/list` endpoint.
+#[derive(Debug, Deserialize)]
+struct TagList {
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+}
+
+/// An image manifest.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    layers: Vec<Descriptor>,
+}
+
+/// A content descriptor for a single layer of a manifest.
+#[derive(Debug, Deserialize)]
+struct Descriptor {
+    digest: String,
+    size: Option<u64>,
+    #[serde(default)]
+    annotations: FxHashMap<String, String>,
+}
+
+impl Descriptor {
+    /// Convert the layer to a [`File`], if it's a named distribution.
+    fn into_file(self, repository: &DisplaySafeUrl) -> Option<File> {
+        let filename = self.annotations.get(TITLE_ANNOTATION)?;
+        let (algorithm, digest) = self.digest.split_once(':')?;
+        let digest = SmallString::from(digest);
+        let hashes = match algorithm {
+            "sha256" => Hashes {
+                sha256: Some(digest),
+                ..Hashes::default()
+            },
+            "sha512" => Hashes {
+                sha512: Some(digest),
+                ..Hashes::default()
+            },
+            _ => Hashes::default(),
+        };
+        let mut url = repository.join(&format!("blobs/{}", self.digest)).ok()?;
+        url.set_username("").ok();
+        url.set_password(None).ok();
+        Some(File {
+            core_metadata: None,
+            filename: SmallString::from(filename.as_str()),
+            hashes,
+            requires_python: None,
+            size: self.size,
+            upload_time: None,
+            url: SmallString::from(url.as_str()),
+            yanked: None,
+        })
+    }
+}
+
+/// List the distributions in a repository, given the response to its `tags/list` endpoint.
+pub(crate) async fn list_files(
+    client: &RedirectClientWithMiddleware,
+    repository: &DisplaySafeUrl,
+    mut response: Response,
+) -> Result<Vec<File>, Error> {
+    // Collect the tags, following any pagination links.
+    let mut tags = Vec::new();
+    loop {
+        let url = DisplaySafeUrl::from(response.url().clone());
+        let next = next_link(response.headers()).and_then(|link| url.join(&link).ok());
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let page: TagList =
+            serde_json::from_slice(&bytes).map_err(|err| Error::from_json_err(err, url.clone()))?;
+        tags.extend(page.tags.unwrap_or_default());
+
+        let Some(next) = next else {
+            break;
+        };
+        response = client
+            .get(Url::from(next.clone()))
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest_middleware(next.clone(), err))?
+            .error_for_status()
+            .map_err(|err| ErrorKind::from_reqwest(next.clone(), err))?;
+    }
+    trace!("Found {} tag(s) in {repository}", tags.len());
+
+    // Fetch the manifest for each tag.
+    let manifests = futures::stream::iter(tags)
+        .map(async |tag| {
+            let mut url = repository.clone();
+            if let Ok(mut segments) = url.path_segments_mut() {
+                segments.pop_if_empty().push("manifests").push(&tag);
+            }
+            let response = client
+                .get(Url::from(url.clone()))
+                .header("Accept", MANIFEST_MEDIA_TYPES)
+                .send()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
+                .error_for_status()
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            let manifest: Manifest = serde_json::from_slice(&bytes)
+                .map_err(|err| Error::from_json_err(err, url.clone()))?;
+            Ok::<_, Error>(manifest)
+        })
+        .buffered(MANIFEST_CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // The same artifact may be referenced by multiple tags (e.g., `latest`).
+    let mut seen = FxHashSet::default();
+    Ok(manifests
+        .into_iter()
+        .flat_map(|manifest| manifest.layers)
+        .filter_map(|layer| layer.into_file(repository))
+        .filter(|file| seen.insert(file.filename.clone()))
+        .collect())
+}
+
+/// Return the target of the `rel="next"` link in a paginated response, if any.
+fn next_link(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let (target, params) = link.split_once(';')?;
+            params
+                .split(';')
+                .any(|param| {
+                    param
+                        .trim()
+                        .strip_prefix("rel=")
+                        .is_some_and(|rel| rel.trim_matches('"') == "next")
+                })
+                .then(|| {
+                    target
+                        .trim()
+                        .trim_start_matches('<')
+                        .trim_end_matches('>')
+                        .to_string()
+                })
+        })
+}
+
+/// A `Bearer` challenge from a `WWW-Authenticate` header, per the Docker token authentication
+/// specification.
+#[derive(Debug, PartialEq, Eq)]
+struct BearerChallenge {
+    realm: String,
+    service: Option<String>,
+    scope: Option<String>,
+}
+
+impl BearerChallenge {
+    fn parse(header: &str) -> Option<Self> {
+        let (scheme, params) = header.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+
+        let mut realm = None;
+        let mut service = None;
+        let mut scope = None;
+        let mut rest = params.trim();
+        while !rest.is_empty() {
+            let (key, value) = rest.split_once('=')?;
+            let value = value.trim_start();
+            let (value, remainder) = if let Some(value) = value.strip_prefix('"') {
+                // Quoted values may contain commas (e.g., multiple scopes).
+                let end = value.find('"')?;
+                (&value[..end], &value[end + 1..])
+            } else {
+                value.split_once(',').unwrap_or((value, ""))
+            };
+            match key.trim().to_ascii_lowercase().as_str() {
+                "realm" => realm = Some(value.to_string()),
+                "service" => service = Some(value.to_string()),
+                "scope" => scope = Some(value.to_string()),
+                _ => {}
+            }
+            rest = remainder.trim_start_matches([',', ' ']);
+        }
+
+        Some(Self {
+            realm: realm?,
+            service,
+            scope,
+        })
+    }
+}
+
+/// A token response from a registry's authorization service.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// A middleware that exchanges credentials for bearer tokens when an OCI registry requests
+/// token authentication.
+///
+/// Any `Basic` credentials attached to the request (e.g., by the authentication middleware) are
+/// forwarded to the registry's authorization service.
+pub(crate) struct OciAuthMiddleware {
+    /// The root URLs of the OCI indexes.
+    roots: Vec<DisplaySafeUrl>,
+    /// The bearer tokens, keyed by repository URL.
+    tokens: Mutex<FxHashMap<String, HeaderValue>>,
+}
+
+impl OciAuthMiddleware {
+    pub(crate) fn new(roots: Vec<DisplaySafeUrl>) -> Self {
+        Self {
+            roots,
+            tokens: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Return the repository URL for a request to a registry API endpoint on an OCI index, which
+    /// serves as the key for its bearer token.
+    fn repository_for(&self, url: &Url) -> Option<String> {
+        if !self.roots.iter().any(|root| {
+            root.scheme() == url.scheme()
+                && root.host_str() == url.host_str()
+                && root.port_or_known_default() == url.port_or_known_default()
+        }) {
+            return None;
+        }
+        let path = url.path().strip_prefix("/v2/")?;
+        let end = ["/tags/", "/manifests/", "/blobs/"]
+            .into_iter()
+            .filter_map(|segment| path.rfind(segment))
+            .max()?;
+        Some(format!(
+            "{}/v2/{}",
+            url.origin().ascii_serialization(),
+            &path[..end]
+        ))
+    }
+
+    /// Request a bearer token for the given challenge.
+    async fn token(
+        challenge: &BearerChallenge,
+        credentials: Option<&HeaderValue>,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Option<HeaderValue> {
+        let mut url = Url::parse(&challenge.realm).ok()?;
+        {
+            let mut query = url.query_pairs_mut();
+            if let Some(service) = &challenge.service {
+                query.append_pair("service", service);
+            }
+            if let Some(scope) = &challenge.scope {
+                query.append_pair("scope", scope);
+            }
+        }
+        let mut request = Request::new(http::Method::GET, url);
+        if let Some(credentials) = credentials {
+            request
+                .headers_mut()
+                .insert(AUTHORIZATION, credentials.clone());
+        }
+
+        let response = match next.run(request, extensions).await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                debug!(
+                    "Failed to obtain registry token from {}: {}",
+                    challenge.realm,
+                    response.status()
+                );
+                return None;
+            }
+            Err(err) => {
+                debug!(
+                    "Failed to obtain registry token from {}: {err}",
+                    challenge.realm
+                );
+                return None;
+            }
+        };
+        let bytes = response.bytes().await.ok()?;
+        let TokenResponse {
+            token,
+            access_token,
+        } = serde_json::from_slice(&bytes).ok()?;
+        let token = token.or(access_token)?;
+        let mut value = HeaderValue::try_from(format!("Bearer {token}")).ok()?;
+        value.set_sensitive(true);
+        Some(value)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for OciAuthMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(repository) = self.repository_for(req.url()) else {
+            return next.run(req, extensions).await;
+        };
+
+        // Reuse an existing token for the repository, if possible.
+        let credentials = req.headers().get(AUTHORIZATION).cloned();
+        let cached = self.tokens.lock().unwrap().get(&repository).cloned();
+        if let Some(token) = cached {
+            req.headers_mut().insert(AUTHORIZATION, token);
+        }
+
+        let Some(retry) = req.try_clone() else {
+            return next.run(req, extensions).await;
+        };
+        let response = next.clone().run(req, extensions).await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        // If the registry requested token authentication, exchange the credentials for a token.
+        let Some(challenge) = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(BearerChallenge::parse)
+        else {
+            return Ok(response);
+        };
+        let credentials = credentials.filter(|value| {
+            value
+                .as_bytes()
+                .get(..6)
+                .is_some_and(|scheme| scheme.eq_ignore_ascii_case(b"basic "))
+        });
+        trace!("Requesting registry token for {repository}");
+        let Some(token) =
+            Self::token(&challenge, credentials.as_ref(), extensions, next.clone()).await
+        else {
+            return Ok(response);
+        };
+        self.tokens
+            .lock()
+            .unwrap()
+            .insert(repository, token.clone());
+
+        let mut retry = retry;
+        retry.headers_mut().insert(AUTHORIZATION, token);
+        next.run(retry, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn repository() {
+        let index = DisplaySafeUrl::parse("https://ghcr.io/acme/python/").unwrap();
+        let package_name = PackageName::from_str("flask").unwrap();
+        assert_eq!(
+            repository_url(&index, &package_name).as_str(),
+            "https://ghcr.io/v2/acme/python/flask/"
+        );
+
+        let index = DisplaySafeUrl::parse("https://registry.example.com").unwrap();
+        assert_eq!(
+            repository_url(&index, &package_name).as_str(),
+            "https://registry.example.com/v2/flask/"
+        );
+    }
+
+    #[test]
+    fn bearer_challenge() {
+        assert_eq!(
+            BearerChallenge::parse(
+                r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:acme/python/flask:pull""#
+            ),
+            Some(BearerChallenge {
+                realm: "https://ghcr.io/token".to_string(),
+                service: Some("ghcr.io".to_string()),
+                scope: Some("repository:acme/python/flask:pull".to_string()),
+            })
+        );
+        assert_eq!(BearerChallenge::parse(r#"Basic realm="registry""#), None);
+    }
+
+    #[test]
+    fn pagination() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(r#"</v2/acme/flask/tags/list?last=3.0.0&n=100>; rel="next""#),
+        );
+        assert_eq!(
+            next_link(&headers).as_deref(),
+            Some("/v2/acme/flask/tags/list?last=3.0.0&n=100")
+        );
+        assert_eq!(next_link(&HeaderMap::new()), None);
+    }
+}
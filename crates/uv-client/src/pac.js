@@ -0,0 +1,92 @@
+// The predefined functions available to proxy auto-config (PAC) files, aside from `dnsResolve` and
+// `myIpAddress`, which are provided natively.
+//
+// See: https://developer.mozilla.org/en-US/docs/Web/HTTP/Proxy_servers_and_tunneling/Proxy_Auto-Configuration_PAC_file
+
+function isPlainHostName(host) {
+  return host.indexOf(".") < 0;
+}
+
+function dnsDomainIs(host, domain) {
+  return (
+    host.length >= domain.length &&
+    host.substring(host.length - domain.length) === domain
+  );
+}
+
+function localHostOrDomainIs(host, hostdom) {
+  return host === hostdom || hostdom.lastIndexOf(host + ".", 0) === 0;
+}
+
+function isResolvable(host) {
+  return dnsResolve(host) !== null;
+}
+
+function convertAddr(ip) {
+  var parts = ip.split(".");
+  return (
+    ((parseInt(parts[0], 10) & 0xff) << 24) |
+    ((parseInt(parts[1], 10) & 0xff) << 16) |
+    ((parseInt(parts[2], 10) & 0xff) << 8) |
+    (parseInt(parts[3], 10) & 0xff)
+  );
+}
+
+function isInNet(host, pattern, mask) {
+  var ip = /^\d+\.\d+\.\d+\.\d+$/.test(host) ? host : dnsResolve(host);
+  if (ip === null) {
+    return false;
+  }
+  return (convertAddr(ip) & convertAddr(mask)) === (convertAddr(pattern) & convertAddr(mask));
+}
+
+function dnsDomainLevels(host) {
+  return host.split(".").length - 1;
+}
+
+function shExpMatch(str, shexp) {
+  var pattern = shexp
+    .replace(/[.+^${}()|[\]\\]/g, "\\$&")
+    .replace(/\*/g, ".*")
+    .replace(/\?/g, ".");
+  return new RegExp("^" + pattern + "$").test(str);
+}
+
+var WEEKDAYS = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+function weekdayRange(start, end, gmt) {
+  if (end === "GMT") {
+    gmt = end;
+    end = undefined;
+  }
+  var now = new Date();
+  var day = gmt === "GMT" ? now.getUTCDay() : now.getDay();
+  var first = WEEKDAYS.indexOf(start);
+  var last = end === undefined ? first : WEEKDAYS.indexOf(end);
+  if (first < 0 || last < 0) {
+    return false;
+  }
+  return first <= last ? day >= first && day <= last : day >= first || day <= last;
+}
+
+function timeRange() {
+  var args = Array.prototype.slice.call(arguments);
+  var gmt = args[args.length - 1] === "GMT";
+  if (gmt) {
+    args.pop();
+  }
+  var now = new Date();
+  var hour = gmt ? now.getUTCHours() : now.getHours();
+  if (args.length === 1) {
+    return hour === args[0];
+  }
+  if (args.length === 2) {
+    return args[0] <= hour && hour < args[1];
+  }
+  return false;
+}
+
+function dateRange() {
+  // Date-based proxy selection is rarely used and ambiguous to implement; treat it as unmatched.
+  return false;
+}
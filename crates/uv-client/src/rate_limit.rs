@@ -0,0 +1,139 @@
+//! A process-wide limit on the aggregate download bandwidth.
+
+use std::pin::Pin;
+use std::sync::{Mutex, OnceLock};
+use std::task::{Context, Poll, ready};
+use std::time::{Duration, Instant};
+
+use futures::Stream;
+use tokio::time::Sleep;
+use tracing::debug;
+
+use uv_configuration::RateLimit;
+
+/// The limiter shared by all downloads in the process, if a limit is set.
+static LIMITER: OnceLock<RateLimiter> = OnceLock::new();
+
+/// Limit the aggregate bandwidth of all downloads wrapped with [`RateLimited`].
+///
+/// The limit can only be set once per process; subsequent calls are ignored.
+pub fn set_download_rate_limit(limit: RateLimit) {
+    if LIMITER.set(RateLimiter::new(limit)).is_ok() {
+        debug!("Limiting download bandwidth to {limit}/s");
+    }
+}
+
+/// A limiter that schedules bytes against a shared budget.
+///
+/// Each chunk is assigned the earliest slot at which the limiter has capacity for it, such that
+/// the aggregate throughput across all concurrent downloads never exceeds the limit.
+#[derive(Debug)]
+struct RateLimiter {
+    bytes_per_second: f64,
+    /// The instant at which the limiter next has capacity.
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(limit: RateLimit) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let bytes_per_second = limit.bytes_per_second() as f64;
+        Self {
+            bytes_per_second,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Reserve capacity for the given number of bytes, returning the delay before they may be
+    /// consumed.
+    fn reserve(&self, bytes: usize) -> Duration {
+        #[allow(clippy::cast_precision_loss)]
+        let cost = Duration::from_secs_f64(bytes as f64 / self.bytes_per_second);
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap();
+        let start = (*next).max(now);
+        *next = start + cost;
+        start - now
+    }
+}
+
+/// A stream of downloaded chunks, throttled by the process-wide download rate limit.
+///
+/// If no limit is set, the stream is passed through as-is.
+pub struct RateLimited<S: Stream> {
+    inner: S,
+    limiter: Option<&'static RateLimiter>,
+    /// The pending delay, and the chunk to yield once it elapses.
+    delay: Option<(Pin<Box<Sleep>>, S::Item)>,
+}
+
+impl<S: Stream> RateLimited<S> {
+    /// Throttle the given stream by the process-wide download rate limit.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            limiter: LIMITER.get(),
+            delay: None,
+        }
+    }
+}
+
+impl<S, T, E> Stream for RateLimited<S>
+where
+    S: Stream<Item = Result<T, E>> + Unpin,
+    T: AsRef<[u8]> + Unpin,
+    E: Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some((sleep, _)) = self.delay.as_mut() {
+            ready!(sleep.as_mut().poll(cx));
+            let (_, item) = self.delay.take().expect("delay is pending");
+            return Poll::Ready(Some(item));
+        }
+
+        let item = ready!(Pin::new(&mut self.inner).poll_next(cx));
+        let Some(limiter) = self.limiter else {
+            return Poll::Ready(item);
+        };
+        let Some(Ok(chunk)) = &item else {
+            return Poll::Ready(item);
+        };
+
+        let delay = limiter.reserve(chunk.as_ref().len());
+        if delay.is_zero() {
+            return Poll::Ready(item);
+        }
+
+        let mut sleep = Box::pin(tokio::time::sleep(delay));
+        if sleep.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(item);
+        }
+        self.delay = Some((sleep, item.expect("item is present")));
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use super::*;
+
+    #[test]
+    fn reserve() {
+        let limiter = RateLimiter::new(RateLimit::from_bytes_per_second(
+            NonZeroU64::new(1000).unwrap(),
+        ));
+
+        // The first chunk is admitted immediately.
+        assert_eq!(limiter.reserve(500), Duration::ZERO);
+
+        // Subsequent chunks are scheduled after the capacity consumed by prior chunks.
+        let delay = limiter.reserve(500);
+        assert!(delay > Duration::from_millis(400) && delay <= Duration::from_millis(500));
+        let delay = limiter.reserve(1000);
+        assert!(delay > Duration::from_millis(900) && delay <= Duration::from_millis(1000));
+    }
+}
@@ -21,8 +21,8 @@ use uv_configuration::KeyringProviderType;
 use uv_configuration::{IndexStrategy, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, File, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
-    IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, Name,
+    BuiltDist, File, Index, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
+    IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, MirrorSelection, Name,
 };
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
@@ -33,13 +33,21 @@ use uv_pypi_types::{ResolutionMetadata, SimpleJson};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
+use uv_warnings::warn_user_once;
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware, RedirectPolicy};
 use crate::cached_client::CacheControl;
 use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleHtml;
-use crate::remote_metadata::wheel_metadata_from_remote_zip;
+use crate::mirrors::{IndexMirrors, is_hosted_on, mirror_url};
+use crate::oci;
+use crate::proxy::ProxyConfig;
+use crate::rate_limit::RateLimited;
+use crate::remote_metadata::{LazyWheelSettings, wheel_metadata_from_remote_zip};
+use crate::retry::RetrySettings;
 use crate::rkyvutil::OwnedArchive;
+use crate::tls::{ClientCertificate, IndexTls};
+use crate::tracking::{self, ProjectLocations};
 use crate::{
     BaseClient, CachedClient, Error, ErrorKind, FlatIndexClient, FlatIndexEntries,
     RedirectClientWithMiddleware,
@@ -73,7 +81,56 @@ impl<'a> RegistryClientBuilder<'a> {
         self.index_urls = index_locations.index_urls();
         self.base_client_builder = self
             .base_client_builder
-            .indexes(Indexes::from(index_locations));
+            .indexes(Indexes::from(index_locations))
+            .index_retries(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(|index| Some((index_root(index), index.retries?))),
+            )
+            .index_concurrency(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(|index| Some((index_root(index), index.concurrent_requests?))),
+            )
+            .index_tls(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(|index| {
+                        if index.client_cert.is_none() && index.ca_cert.is_none() {
+                            return None;
+                        }
+                        // Apply the settings to any URL on the index's host, since the index may
+                        // serve files from outside of its root.
+                        let mut origin = index_root(index);
+                        origin.set_path("/");
+                        let tls = IndexTls {
+                            client_certificate: index.client_cert.clone().map(|cert| {
+                                ClientCertificate {
+                                    cert,
+                                    key: index.client_key.clone(),
+                                }
+                            }),
+                            ca_cert: index.ca_cert.clone(),
+                        };
+                        Some((origin, tls))
+                    }),
+            )
+            .oci_indexes(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter(|index| index.format == IndexFormat::Oci)
+                    .map(index_root),
+            )
+            .s3_indexes(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .map(|index| &**index.url.url()),
+            );
         self
     }
 
@@ -120,12 +177,26 @@ impl<'a> RegistryClientBuilder<'a> {
         Ok(self)
     }
 
+    #[must_use]
+    pub fn retry_settings(mut self, retry_settings: &RetrySettings) -> Self {
+        self.base_client_builder = self.base_client_builder.retry_settings(retry_settings);
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.base_client_builder = self.base_client_builder.native_tls(native_tls);
         self
     }
 
+    #[must_use]
+    pub fn strict_index_tracking(mut self, strict_index_tracking: bool) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .strict_index_tracking(strict_index_tracking);
+        self
+    }
+
     #[must_use]
     pub fn built_in_root_certs(mut self, built_in_root_certs: bool) -> Self {
         self.base_client_builder = self
@@ -164,6 +235,12 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn proxy_config(mut self, proxy_config: Option<ProxyConfig>) -> Self {
+        self.base_client_builder = self.base_client_builder.proxy_config(proxy_config);
+        self
+    }
+
     /// Allows credentials to be propagated on cross-origin redirects.
     ///
     /// WARNING: This should only be available for tests. In production code, propagating credentials
@@ -177,6 +254,8 @@ impl<'a> RegistryClientBuilder<'a> {
     }
 
     pub fn build(self) -> RegistryClient {
+        let strict_index_tracking = self.base_client_builder.is_strict_index_tracking();
+
         // Build a base client
         let builder = self
             .base_client_builder
@@ -199,6 +278,9 @@ impl<'a> RegistryClientBuilder<'a> {
             client,
             timeout,
             flat_indexes: Arc::default(),
+            mirrors: IndexMirrors::default(),
+            lazy_wheel: LazyWheelSettings::from_env(),
+            strict_index_tracking,
         }
     }
 
@@ -222,6 +304,9 @@ impl<'a> RegistryClientBuilder<'a> {
             client,
             timeout,
             flat_indexes: Arc::default(),
+            mirrors: IndexMirrors::default(),
+            lazy_wheel: LazyWheelSettings::from_env(),
+            strict_index_tracking: self.base_client_builder.is_strict_index_tracking(),
         }
     }
 }
@@ -240,6 +325,31 @@ impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
     }
 }
 
+/// Return the root URL of an index (e.g., `https://example.com/pypi` for
+/// `https://example.com/pypi/simple`), without credentials, for matching requests to the index.
+fn index_root(index: &Index) -> DisplaySafeUrl {
+    let mut root = index
+        .url()
+        .root()
+        .unwrap_or_else(|| index.url().url().clone());
+    root.set_username("").ok();
+    root.set_password(None).ok();
+    root
+}
+
+/// Return the URL of the project page for a package on a PEP 503-compatible index.
+fn project_url(index: &IndexUrl, package_name: &PackageName) -> Result<DisplaySafeUrl, Error> {
+    let mut url = index.url().clone();
+    url.path_segments_mut()
+        .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
+        .pop_if_empty()
+        .push(package_name.as_ref())
+        // The URL *must* end in a trailing slash for proper relative path behavior
+        // ref https://github.com/servo/rust-url/issues/333
+        .push("");
+    Ok(url)
+}
+
 /// A client for fetching packages from a `PyPI`-compatible index.
 #[derive(Debug, Clone)]
 pub struct RegistryClient {
@@ -259,6 +369,12 @@ pub struct RegistryClient {
     timeout: Duration,
     /// The flat index entries for each `--find-links`-style index URL.
     flat_indexes: Arc<Mutex<FlatIndexCache>>,
+    /// The order in which to query each index and its mirrors.
+    mirrors: IndexMirrors,
+    /// The settings controlling when wheel metadata is read via range requests.
+    lazy_wheel: LazyWheelSettings,
+    /// Whether to refuse to combine a package across indexes that don't track each other.
+    strict_index_tracking: bool,
 }
 
 /// The format of the package metadata returned by querying an index.
@@ -357,13 +473,14 @@ impl RegistryClient {
                 for index in indexes {
                     let _permit = download_concurrency.acquire().await;
                     match index.format {
-                        IndexFormat::Simple => {
+                        IndexFormat::Simple | IndexFormat::Oci => {
                             let status_code_strategy =
                                 self.index_urls.status_code_strategy_for(index.url);
                             match self
                                 .simple_single_index(
                                     package_name,
                                     index.url,
+                                    index.format,
                                     capabilities,
                                     &status_code_strategy,
                                 )
@@ -402,7 +519,7 @@ impl RegistryClient {
                     .map(async |index| {
                         let _permit = download_concurrency.acquire().await;
                         match index.format {
-                            IndexFormat::Simple => {
+                            IndexFormat::Simple | IndexFormat::Oci => {
                                 // For unsafe matches, ignore authentication failures.
                                 let status_code_strategy =
                                     IndexStatusCodeStrategy::ignore_authentication_error_codes();
@@ -410,6 +527,7 @@ impl RegistryClient {
                                     .simple_single_index(
                                         package_name,
                                         index.url,
+                                        index.format,
                                         capabilities,
                                         &status_code_strategy,
                                     )
@@ -435,6 +553,24 @@ impl RegistryClient {
                     })
                     .try_collect::<Vec<_>>()
                     .await?;
+
+                // Verify that the indexes on which the package was found may be combined, per
+                // PEP 708.
+                let projects = results
+                    .iter()
+                    .filter_map(|(index, metadata)| match metadata {
+                        MetadataFormat::Simple(metadata) => Some(
+                            project_url(index, package_name)
+                                .map(|url| ProjectLocations::from_metadata(url, metadata)),
+                        ),
+                        MetadataFormat::Flat(_) => None,
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                tracking::check_index_tracking(
+                    package_name,
+                    &projects,
+                    self.strict_index_tracking,
+                )?;
             }
         }
 
@@ -492,44 +628,28 @@ impl RegistryClient {
 
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
-    /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
-    /// out in the same format.
+    /// The index can either be a PEP 503-compatible remote repository, a local directory laid
+    /// out in the same format, or an OCI registry.
     async fn simple_single_index(
         &self,
         package_name: &PackageName,
         index: &IndexUrl,
+        format: IndexFormat,
         capabilities: &IndexCapabilities,
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
-        // Format the URL for PyPI.
-        let mut url = index.url().clone();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
-
-        trace!("Fetching metadata for {package_name} from {url}");
-
         let cache_entry = self.cache.entry(
             CacheBucket::Simple,
             WheelCache::Index(index).root(),
             format!("{package_name}.rkyv"),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => {
-                if let Some(header) = self.index_urls.simple_api_cache_control_for(index) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.cache
-                            .freshness(&cache_entry, Some(package_name), None)
-                            .map_err(ErrorKind::Io)?,
-                    )
-                }
-            }
+            Connectivity::Online => CacheControl::from(
+                self.cache
+                    .freshness(&cache_entry, Some(package_name), None)
+                    .map_err(ErrorKind::Io)?,
+            )
+            .with_override(self.index_urls.simple_api_cache_control_for(index)),
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -540,10 +660,19 @@ impl RegistryClient {
             lock_entry.lock().await.map_err(ErrorKind::CacheWrite)?
         };
 
-        let result = if matches!(index, IndexUrl::Path(_)) {
-            self.fetch_local_index(package_name, &url).await
+        let result = if let Some((mirrors, selection)) = self.index_urls.mirrors_for(index) {
+            self.fetch_with_failover(
+                package_name,
+                index,
+                format,
+                mirrors,
+                selection,
+                &cache_entry,
+                cache_control,
+            )
+            .await
         } else {
-            self.fetch_remote_index(package_name, &url, &cache_entry, cache_control)
+            self.fetch_index(package_name, index, format, &cache_entry, cache_control)
                 .await
         };
 
@@ -579,6 +708,126 @@ impl RegistryClient {
         }
     }
 
+    /// Fetch the [`SimpleMetadata`] for a given package from the index or one of its mirrors,
+    /// failing over to the next mirror if an index is unavailable.
+    ///
+    /// The metadata is cached under the index, rather than the mirror that served it.
+    async fn fetch_with_failover(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+        format: IndexFormat,
+        mirrors: &[IndexUrl],
+        selection: MirrorSelection,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let candidates = self
+            .mirrors
+            .candidates(self.client.uncached(), index, mirrors, selection)
+            .await;
+        let mut candidates = candidates.iter().peekable();
+        while let Some(candidate) = candidates.next() {
+            let result = self
+                .fetch_index(package_name, candidate, format, cache_entry, cache_control)
+                .await;
+            match result {
+                Err(err) if err.is_unavailable() => {
+                    self.mirrors.demote(index, candidate);
+                    let Some(next) = candidates.peek() else {
+                        return Err(err);
+                    };
+                    debug!("Failed to fetch metadata for {package_name} from {candidate}: {err}");
+                    warn_user_once!("Index `{candidate}` is unavailable; falling back to `{next}`");
+                }
+                result => return result,
+            }
+        }
+        unreachable!("an index has at least one candidate URL")
+    }
+
+    /// Fetch a file (e.g., a wheel or source distribution) hosted on the given index, failing over
+    /// to the index's mirrors if the index is unavailable.
+    ///
+    /// The file is fetched via `fetch`, which is called with the URL of the file on each candidate
+    /// in turn, until it returns a result for which `is_unavailable` is `false`. If the index has
+    /// no mirrors, or the file isn't hosted under the index (e.g., it's served from a separate
+    /// CDN), `fetch` is called once, with the original URL.
+    pub async fn with_artifact_failover<T, E: std::fmt::Display>(
+        &self,
+        index: Option<&IndexUrl>,
+        url: &DisplaySafeUrl,
+        is_unavailable: impl Fn(&E) -> bool,
+        mut fetch: impl AsyncFnMut(DisplaySafeUrl) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let Some((index, (mirrors, selection))) =
+            index.and_then(|index| Some((index, self.index_urls.mirrors_for(index)?)))
+        else {
+            return fetch(url.clone()).await;
+        };
+
+        // Determine which of the index and its mirrors is hosting the file. If the metadata was
+        // served by a mirror, the file may point to the mirror rather than the index.
+        let Some(source) = std::iter::once(index)
+            .chain(mirrors)
+            .find(|candidate| is_hosted_on(url, candidate))
+        else {
+            return fetch(url.clone()).await;
+        };
+
+        let candidates = self
+            .mirrors
+            .candidates(self.client.uncached(), index, mirrors, selection)
+            .await;
+        let mut candidates = candidates
+            .iter()
+            .filter_map(|candidate| Some((candidate, mirror_url(url, source, candidate)?)))
+            .peekable();
+        while let Some((candidate, candidate_url)) = candidates.next() {
+            let result = fetch(candidate_url).await;
+            match result {
+                Err(err) if is_unavailable(&err) => {
+                    self.mirrors.demote(index, candidate);
+                    let Some((next, _)) = candidates.peek() else {
+                        return Err(err);
+                    };
+                    debug!("Failed to fetch {url} from {candidate}: {err}");
+                    warn_user_once!("Index `{candidate}` is unavailable; falling back to `{next}`");
+                }
+                result => return result,
+            }
+        }
+        unreachable!("an index has at least one candidate URL")
+    }
+
+    /// Fetch the [`SimpleMetadata`] for a given package from a single index URL.
+    async fn fetch_index(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+        format: IndexFormat,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        if format == IndexFormat::Oci && !matches!(index, IndexUrl::Path(_)) {
+            trace!("Fetching metadata for {package_name} from OCI registry {index}");
+            return self
+                .fetch_oci_index(package_name, index.url(), cache_entry, cache_control)
+                .await;
+        }
+
+        let url = project_url(index, package_name)?;
+
+        trace!("Fetching metadata for {package_name} from {url}");
+
+        if matches!(index, IndexUrl::Path(_)) {
+            self.fetch_local_index(package_name, &url).await
+        } else {
+            self.fetch_remote_index(package_name, &url, cache_entry, cache_control)
+                .await
+        }
+    }
+
     /// Fetch the [`SimpleMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
     async fn fetch_remote_index(
         &self,
@@ -625,6 +874,7 @@ impl RegistryClient {
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
                         SimpleMetadata::from_files(data.files, package_name, &url)
+                            .with_locations(data.tracks, data.alternate_locations)
                     }
                     MediaType::Html => {
                         let text = response
@@ -651,6 +901,51 @@ impl RegistryClient {
         Ok(simple)
     }
 
+    /// Fetch the [`SimpleMetadata`] from an OCI registry, in which the package is stored as a
+    /// repository of artifacts beneath the index URL.
+    async fn fetch_oci_index(
+        &self,
+        package_name: &PackageName,
+        index: &DisplaySafeUrl,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let repository = oci::repository_url(index, package_name);
+        let mut url = repository.clone();
+        url.path_segments_mut()
+            .map_err(|()| ErrorKind::CannotBeABase(repository.clone()))?
+            .pop_if_empty()
+            .push("tags")
+            .push("list");
+        let tags_request = self
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", "application/json")
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let parse_tags_response = |response: Response| {
+            async {
+                let files =
+                    oci::list_files(self.uncached_client(&repository), &repository, response)
+                        .await?;
+                let metadata = SimpleMetadata::from_files(files, package_name, &repository);
+                OwnedArchive::from_unarchived(&metadata)
+            }
+            .boxed_local()
+            .instrument(info_span!("parse_oci_index", package = %package_name))
+        };
+        let simple = self
+            .cached_client()
+            .get_cacheable_with_retry(
+                tags_request,
+                cache_entry,
+                cache_control,
+                parse_tags_response,
+            )
+            .await?;
+        Ok(simple)
+    }
+
     /// Fetch the [`SimpleMetadata`] from a local file, using a PEP 503-compatible directory
     /// structure.
     async fn fetch_local_index(
@@ -741,6 +1036,7 @@ impl RegistryClient {
                     &wheel.filename,
                     &wheel.url,
                     None,
+                    None,
                     WheelCache::Url(&wheel.url),
                     capabilities,
                 )
@@ -797,17 +1093,12 @@ impl RegistryClient {
                 format!("{}.msgpack", filename.cache_key()),
             );
             let cache_control = match self.connectivity {
-                Connectivity::Online => {
-                    if let Some(header) = self.index_urls.artifact_cache_control_for(index) {
-                        CacheControl::Override(header)
-                    } else {
-                        CacheControl::from(
-                            self.cache
-                                .freshness(&cache_entry, Some(&filename.name), None)
-                                .map_err(ErrorKind::Io)?,
-                        )
-                    }
-                }
+                Connectivity::Online => CacheControl::from(
+                    self.cache
+                        .freshness(&cache_entry, Some(&filename.name), None)
+                        .map_err(ErrorKind::Io)?,
+                )
+                .with_override(self.index_urls.artifact_cache_control_for(index)),
                 Connectivity::Offline => CacheControl::AllowStale,
             };
 
@@ -850,6 +1141,7 @@ impl RegistryClient {
             self.wheel_metadata_no_pep658(
                 &filename,
                 url,
+                file.size,
                 Some(index),
                 WheelCache::Index(index),
                 capabilities,
@@ -863,6 +1155,7 @@ impl RegistryClient {
         &self,
         filename: &'data WheelFilename,
         url: &'data DisplaySafeUrl,
+        size: Option<u64>,
         index: Option<&'data IndexUrl>,
         cache_shard: WheelCache<'data>,
         capabilities: &'data IndexCapabilities,
@@ -873,25 +1166,14 @@ impl RegistryClient {
             format!("{}.msgpack", filename.cache_key()),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => {
-                if let Some(index) = index {
-                    if let Some(header) = self.index_urls.artifact_cache_control_for(index) {
-                        CacheControl::Override(header)
-                    } else {
-                        CacheControl::from(
-                            self.cache
-                                .freshness(&cache_entry, Some(&filename.name), None)
-                                .map_err(ErrorKind::Io)?,
-                        )
-                    }
-                } else {
-                    CacheControl::from(
-                        self.cache
-                            .freshness(&cache_entry, Some(&filename.name), None)
-                            .map_err(ErrorKind::Io)?,
-                    )
-                }
-            }
+            Connectivity::Online => CacheControl::from(
+                self.cache
+                    .freshness(&cache_entry, Some(&filename.name), None)
+                    .map_err(ErrorKind::Io)?,
+            )
+            .with_override(
+                index.and_then(|index| self.index_urls.artifact_cache_control_for(index)),
+            ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -902,8 +1184,12 @@ impl RegistryClient {
             lock_entry.lock().await.map_err(ErrorKind::CacheWrite)?
         };
 
-        // Attempt to fetch via a range request.
-        if index.is_none_or(|index| capabilities.supports_range_requests(index)) {
+        // Attempt to fetch via a range request, unless range requests are disabled for the index
+        // or the wheel is small enough to download in full.
+        if index.is_none_or(|index| {
+            capabilities.supports_range_requests(index) && self.index_urls.range_requests_for(index)
+        }) && self.lazy_wheel.use_range_requests(size)
+        {
             let req = self
                 .uncached_client(url)
                 .head(Url::from(url.clone()))
@@ -933,7 +1219,13 @@ impl RegistryClient {
                     .await
                     .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
                     trace!("Getting metadata for {filename} by range request");
-                    let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
+                    let text = wheel_metadata_from_remote_zip(
+                        filename,
+                        url,
+                        &mut reader,
+                        self.lazy_wheel.prefetch(),
+                    )
+                    .await?;
                     ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
                         Error::from(ErrorKind::MetadataParseError(
                             filename.clone(),
@@ -965,6 +1257,18 @@ impl RegistryClient {
                         // for the METADATA file.
                         warn!("Range requests not supported for {filename}; streaming wheel");
 
+                        // Mark the index as not supporting range requests.
+                        if let Some(index) = index {
+                            capabilities.set_no_range_requests(index.clone());
+                        }
+                    } else if err.is_http_range_requests_corrupt() {
+                        // The ranges returned by the server didn't form a valid wheel, e.g., due to
+                        // a proxy that mishandles range requests. Fall back to streaming the file.
+                        warn_user_once!(
+                            "Failed to read metadata for {filename} via range requests; downloading the full wheel instead. If the index is behind a proxy that mishandles range requests, consider setting `range-requests = false` for the index."
+                        );
+                        debug!("Range request failure for {filename}: {err}");
+
                         // Mark the index as not supporting range requests.
                         if let Some(index) = index {
                             capabilities.set_no_range_requests(index.clone());
@@ -993,8 +1297,7 @@ impl RegistryClient {
         // Stream the file, searching for the METADATA.
         let read_metadata_stream = |response: Response| {
             async {
-                let reader = response
-                    .bytes_stream()
+                let reader = RateLimited::new(response.bytes_stream())
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -1113,7 +1416,13 @@ pub struct VersionSourceDist {
 
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
-pub struct SimpleMetadata(Vec<SimpleMetadatum>);
+pub struct SimpleMetadata {
+    versions: Vec<SimpleMetadatum>,
+    /// The URLs of the projects on other repositories that this project tracks, per PEP 708.
+    tracks: Vec<SmallString>,
+    /// The URLs of the repositories that extend this project's namespace, per PEP 708.
+    alternate_locations: Vec<SmallString>,
+}
 
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
@@ -1124,7 +1433,7 @@ pub struct SimpleMetadatum {
 
 impl SimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SimpleMetadatum> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     fn from_files(files: Vec<uv_pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
@@ -1163,11 +1472,25 @@ impl SimpleMetadata {
                 }
             }
         }
-        Self(
-            map.into_iter()
+        Self {
+            versions: map
+                .into_iter()
                 .map(|(version, files)| SimpleMetadatum { version, files })
                 .collect(),
-        )
+            tracks: Vec::new(),
+            alternate_locations: Vec::new(),
+        }
+    }
+
+    /// Attach the PEP 708 tracking and alternate locations metadata for the project.
+    fn with_locations(
+        mut self,
+        tracks: Vec<SmallString>,
+        alternate_locations: Vec<SmallString>,
+    ) -> Self {
+        self.tracks = tracks;
+        self.alternate_locations = alternate_locations;
+        self
     }
 
     /// Read the [`SimpleMetadata`] from an HTML index.
@@ -1176,14 +1499,17 @@ impl SimpleMetadata {
         package_name: &PackageName,
         url: &DisplaySafeUrl,
     ) -> Result<Self, Error> {
-        let SimpleHtml { base, files } =
-            SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
-
-        Ok(SimpleMetadata::from_files(
+        let SimpleHtml {
+            base,
             files,
-            package_name,
-            base.as_url(),
-        ))
+            tracks,
+            alternate_locations,
+        } = SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
+
+        Ok(
+            SimpleMetadata::from_files(files, package_name, base.as_url())
+                .with_locations(tracks, alternate_locations),
+        )
     }
 }
 
@@ -1192,17 +1518,29 @@ impl IntoIterator for SimpleMetadata {
     type IntoIter = std::vec::IntoIter<SimpleMetadatum>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.versions.into_iter()
     }
 }
 
 impl ArchivedSimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleMetadatum>> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     pub fn datum(&self, i: usize) -> Option<&rkyv::Archived<SimpleMetadatum>> {
-        self.0.get(i)
+        self.versions.get(i)
+    }
+
+    /// The URLs of the projects on other repositories that this project tracks.
+    pub fn tracks(&self) -> impl Iterator<Item = &str> {
+        self.tracks.iter().map(rkyv::string::ArchivedString::as_str)
+    }
+
+    /// The URLs of the repositories that extend this project's namespace.
+    pub fn alternate_locations(&self) -> impl Iterator<Item = &str> {
+        self.alternate_locations
+            .iter()
+            .map(rkyv::string::ArchivedString::as_str)
     }
 }
 
@@ -1515,7 +1853,7 @@ mod tests {
         // Note the lack of a trailing `/` here is important for coverage of url-join behavior
         let base = DisplaySafeUrl::parse("https://account.d.codeartifact.us-west-2.amazonaws.com/pypi/shared-packages-pypi/simple/flask")
             .unwrap();
-        let SimpleHtml { base, files } = SimpleHtml::parse(text, &base).unwrap();
+        let SimpleHtml { base, files, .. } = SimpleHtml::parse(text, &base).unwrap();
         let base = SmallString::from(base.as_str());
 
         // Test parsing of the file urls
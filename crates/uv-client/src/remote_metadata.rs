@@ -5,6 +5,67 @@ use tokio_util::compat::TokioAsyncReadCompatExt;
 use url::Url;
 use uv_distribution_filename::WheelFilename;
 use uv_metadata::find_archive_dist_info;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+/// Settings controlling when wheel metadata is read via HTTP range requests, rather than by
+/// downloading the entire wheel.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct LazyWheelSettings {
+    /// Wheels smaller than this size (in bytes) are downloaded in full.
+    min_size: u64,
+    /// The number of bytes to fetch from the end of the wheel in the initial range request, as a
+    /// best guess for the size of the central directory.
+    prefetch: u64,
+}
+
+impl Default for LazyWheelSettings {
+    fn default() -> Self {
+        Self {
+            min_size: 0,
+            prefetch: 16384,
+        }
+    }
+}
+
+impl LazyWheelSettings {
+    /// Read the settings from `UV_LAZY_WHEEL_MIN_SIZE` and `UV_LAZY_WHEEL_PREFETCH`, ignoring
+    /// invalid values.
+    pub(crate) fn from_env() -> Self {
+        let mut settings = Self::default();
+        if let Some(min_size) = parse_env(EnvVars::UV_LAZY_WHEEL_MIN_SIZE) {
+            settings.min_size = min_size;
+        }
+        if let Some(prefetch) = parse_env(EnvVars::UV_LAZY_WHEEL_PREFETCH) {
+            settings.prefetch = prefetch;
+        }
+        settings
+    }
+
+    /// Returns `true` if the metadata for a wheel of the given size (if known) should be read via
+    /// range requests.
+    pub(crate) fn use_range_requests(&self, size: Option<u64>) -> bool {
+        size.is_none_or(|size| size >= self.min_size)
+    }
+
+    /// The number of bytes to fetch from the end of the wheel in the initial range request.
+    pub(crate) fn prefetch(&self) -> u64 {
+        self.prefetch
+    }
+}
+
+/// Parse a size in bytes from the given environment variable, warning if it's invalid.
+fn parse_env(name: &str) -> Option<u64> {
+    let value = std::env::var(name).ok()?;
+    if let Ok(value) = value.parse::<u64>() {
+        Some(value)
+    } else {
+        warn_user_once!(
+            "Ignoring invalid value from environment for `{name}`. Expected a number of bytes, got \"{value}\"."
+        );
+        None
+    }
+}
 
 /// Read the `.dist-info/METADATA` file from a async remote zip reader, so we avoid downloading the
 /// entire wheel just for the one file.
@@ -51,13 +112,12 @@ pub(crate) async fn wheel_metadata_from_remote_zip(
     filename: &WheelFilename,
     debug_name: &Url,
     reader: &mut AsyncHttpRangeReader,
+    central_directory_size: u64,
 ) -> Result<String, Error> {
-    // Make sure we have the back part of the stream.
-    // Best guess for the central directory size inside the zip
-    const CENTRAL_DIRECTORY_SIZE: u64 = 16384;
-    // Because the zip index is at the back
+    // Make sure we have the back part of the stream, using the best guess for the central
+    // directory size inside the zip, because the zip index is at the back
     reader
-        .prefetch(reader.len().saturating_sub(CENTRAL_DIRECTORY_SIZE)..reader.len())
+        .prefetch(reader.len().saturating_sub(central_directory_size)..reader.len())
         .await;
 
     // Construct a zip reader to uses the stream.
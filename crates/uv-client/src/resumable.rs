@@ -0,0 +1,281 @@
+//! Resumption of interrupted downloads via HTTP range requests.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use http::header::{
+    ACCEPT_ENCODING, ACCEPT_RANGES, CONTENT_ENCODING, CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED,
+    RANGE,
+};
+use http::{HeaderValue, StatusCode};
+use reqwest::{Response, Url};
+use tracing::{debug, warn};
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::{BaseClient, RedirectClientWithMiddleware};
+
+/// The body of a download, which transparently resumes from the last received byte if the
+/// connection is interrupted.
+///
+/// A download is only resumed if the server advertises support for range requests and provides a
+/// validator (an `ETag` or `Last-Modified` header), which is sent as `If-Range` to ensure that
+/// the remainder is drawn from the same file. Callers are still expected to validate the
+/// downloaded file against its expected hash, if known.
+pub struct ResumableStream(BoxStream<'static, reqwest::Result<Bytes>>);
+
+impl ResumableStream {
+    /// Stream the body of the given response, resuming via the client if interrupted.
+    pub fn new(client: &BaseClient, response: Response) -> Self {
+        let Some(resume) = Resume::from_response(client, &response) else {
+            return Self(response.bytes_stream().boxed());
+        };
+        Self::from_state(State {
+            body: Some(response.bytes_stream().boxed()),
+            resume,
+        })
+    }
+
+    /// Stream the remainder of the file in the given response, starting at `offset`, e.g., to
+    /// complete a download that was interrupted in a previous run.
+    ///
+    /// The caller is responsible for ensuring that the bytes before `offset` were drawn from a
+    /// response with the same [`ResumableStream::validator`]. Returns `None` if the download can't
+    /// be resumed, in which case the given response should be used instead.
+    pub async fn resume(client: &BaseClient, response: &Response, offset: u64) -> Option<Self> {
+        let mut resume = Resume::from_response(client, response)?;
+        resume.offset = offset;
+        match resume.request().await {
+            Ok(body) => Some(Self::from_state(State {
+                body: Some(body),
+                resume,
+            })),
+            Err(reason) => {
+                debug!("Failed to resume download of {}: {reason}", resume.url);
+                None
+            }
+        }
+    }
+
+    /// Return the validator (an `ETag` or `Last-Modified` header) that identifies the file in the
+    /// given response, if the download can be resumed with a range request.
+    pub fn validator(response: &Response) -> Option<HeaderValue> {
+        if response.status() != StatusCode::OK {
+            return None;
+        }
+        let headers = response.headers();
+
+        // The server must support byte ranges.
+        if !headers
+            .get(ACCEPT_RANGES)
+            .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"bytes"))
+        {
+            return None;
+        }
+
+        // Offsets refer to the encoded representation, so only resume unencoded bodies.
+        if headers
+            .get(CONTENT_ENCODING)
+            .is_some_and(|value| !value.as_bytes().eq_ignore_ascii_case(b"identity"))
+        {
+            return None;
+        }
+
+        // Prefer a strong `ETag`, since weak validators can't be used with `If-Range`.
+        headers
+            .get(ETAG)
+            .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+            .or_else(|| headers.get(LAST_MODIFIED))
+            .cloned()
+    }
+
+    fn from_state(state: State) -> Self {
+        Self(
+            futures::stream::unfold(state, |mut state| async move {
+                let item = state.next().await?;
+                Some((item, state))
+            })
+            .boxed(),
+        )
+    }
+}
+
+impl Stream for ResumableStream {
+    type Item = reqwest::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_next_unpin(cx)
+    }
+}
+
+/// The information necessary to resume an interrupted download.
+struct Resume {
+    client: RedirectClientWithMiddleware,
+    url: DisplaySafeUrl,
+    /// The value to send in the `If-Range` header.
+    validator: HeaderValue,
+    /// The total size of the file, if known.
+    total: Option<u64>,
+    /// The number of bytes received so far.
+    offset: u64,
+    /// The number of remaining attempts to resume the download.
+    attempts: u32,
+}
+
+impl Resume {
+    /// Determine whether the download in the given response can be resumed.
+    fn from_response(client: &BaseClient, response: &Response) -> Option<Self> {
+        let validator = ResumableStream::validator(response)?;
+
+        let attempts = client.retries_for(response.url());
+        if attempts == 0 {
+            return None;
+        }
+
+        let url = DisplaySafeUrl::from(response.url().clone());
+        Some(Self {
+            client: client.for_host(&url).clone(),
+            url,
+            validator,
+            total: response.content_length(),
+            offset: 0,
+            attempts,
+        })
+    }
+
+    /// Request the remainder of the file, starting from the current offset.
+    async fn request(&self) -> Result<BoxStream<'static, reqwest::Result<Bytes>>, String> {
+        let response = self
+            .client
+            .get(Url::from(self.url.clone()))
+            .header(RANGE, format!("bytes={}-", self.offset))
+            .header(IF_RANGE, self.validator.clone())
+            .header(ACCEPT_ENCODING, HeaderValue::from_static("identity"))
+            .send()
+            .await
+            .map_err(|err| err.to_string())?;
+
+        // If the file changed, the server responds with the full file (`200 OK`), which can't be
+        // spliced onto the bytes received so far.
+        if response.status() != StatusCode::PARTIAL_CONTENT {
+            return Err(format!(
+                "server responded with `{}` to a range request",
+                response.status()
+            ));
+        }
+
+        let content_range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(ContentRange::parse)
+            .ok_or_else(|| "missing or invalid `Content-Range` header".to_string())?;
+        if content_range.start != self.offset {
+            return Err(format!(
+                "server resumed at byte {}, expected {}",
+                content_range.start, self.offset
+            ));
+        }
+        if self
+            .total
+            .zip(content_range.total)
+            .is_some_and(|(expected, total)| expected != total)
+        {
+            return Err("file size changed since the download started".to_string());
+        }
+
+        Ok(response.bytes_stream().boxed())
+    }
+}
+
+struct State {
+    /// The current response body, or `None` if the stream is exhausted or has failed.
+    body: Option<BoxStream<'static, reqwest::Result<Bytes>>>,
+    resume: Resume,
+}
+
+impl State {
+    async fn next(&mut self) -> Option<reqwest::Result<Bytes>> {
+        loop {
+            let body = self.body.as_mut()?;
+            match body.next().await {
+                Some(Ok(chunk)) => {
+                    self.resume.offset += chunk.len() as u64;
+                    return Some(Ok(chunk));
+                }
+                Some(Err(err)) => {
+                    if self.resume.offset == 0 || self.resume.attempts == 0 {
+                        self.body = None;
+                        return Some(Err(err));
+                    }
+                    self.resume.attempts -= 1;
+                    debug!(
+                        "Download of {} interrupted after {} bytes ({err}); resuming",
+                        self.resume.url, self.resume.offset
+                    );
+                    match self.resume.request().await {
+                        Ok(body) => self.body = Some(body),
+                        Err(reason) => {
+                            warn!("Failed to resume download of {}: {reason}", self.resume.url);
+                            self.body = None;
+                            return Some(Err(err));
+                        }
+                    }
+                }
+                None => {
+                    self.body = None;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// A parsed `Content-Range` header, e.g., `bytes 100-199/200`.
+#[derive(Debug, PartialEq, Eq)]
+struct ContentRange {
+    start: u64,
+    total: Option<u64>,
+}
+
+impl ContentRange {
+    fn parse(value: &str) -> Option<Self> {
+        let range = value.trim().strip_prefix("bytes ")?;
+        let (range, total) = range.split_once('/')?;
+        let (start, _end) = range.split_once('-')?;
+        let start = start.trim().parse().ok()?;
+        let total = match total.trim() {
+            "*" => None,
+            total => Some(total.parse().ok()?),
+        };
+        Some(Self { start, total })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_range() {
+        assert_eq!(
+            ContentRange::parse("bytes 100-199/200"),
+            Some(ContentRange {
+                start: 100,
+                total: Some(200)
+            })
+        );
+        assert_eq!(
+            ContentRange::parse("bytes 100-199/*"),
+            Some(ContentRange {
+                start: 100,
+                total: None
+            })
+        );
+        assert_eq!(ContentRange::parse("bytes */200"), None);
+        assert_eq!(ContentRange::parse("100-199/200"), None);
+    }
+}
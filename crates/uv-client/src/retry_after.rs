@@ -0,0 +1,234 @@
+//! Handling of rate-limited responses (`429 Too Many Requests` and `503 Service Unavailable`)
+//! that include a `Retry-After` header.
+//!
+//! Rather than spending the retry budget on requests that the server has asked us to delay, all
+//! requests to the host are paused until the server's deadline, then rescheduled.
+
+use std::fmt::{self, Display, Formatter};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use http::{Extensions, HeaderMap, StatusCode};
+use itertools::Itertools;
+use reqwest::{Request, Response, Url};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
+use tracing::{debug, trace};
+
+/// The longest `Retry-After` delay to honor. Responses asking for a longer delay are returned
+/// as-is (and may be retried as a transient failure).
+const MAX_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// The maximum number of times to reschedule a single request.
+const MAX_ATTEMPTS: u32 = 5;
+
+/// The rate-limiting state of each host, shared by all clients in the process.
+static HOSTS: LazyLock<Mutex<FxHashMap<String, HostState>>> = LazyLock::new(Mutex::default);
+
+#[derive(Debug)]
+struct HostState {
+    /// The instant until which requests to the host are paused.
+    paused_until: Instant,
+    /// The total (wall-clock) time for which requests to the host were paused.
+    paused: Duration,
+    /// The number of rate-limited responses from the host.
+    responses: usize,
+}
+
+/// A middleware that pauses requests to a host that responded with a `Retry-After` header, and
+/// reschedules the rate-limited request once the delay has elapsed.
+///
+/// Requests to the same host are coalesced behind a single pause, such that concurrent requests
+/// don't each run into the rate limit (and burn a retry) in turn.
+pub(crate) struct RetryAfterMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(host) = host_key(req.url()) else {
+            return next.run(req, extensions).await;
+        };
+
+        let mut attempts = 0;
+        loop {
+            wait(&host).await;
+
+            // Avoid uncloneable errors with a streaming body during publish.
+            let retry = if attempts < MAX_ATTEMPTS {
+                req.try_clone()
+            } else {
+                None
+            };
+            let response = next.clone().run(req, extensions).await?;
+            let Some(retry) = retry else {
+                return Ok(response);
+            };
+            if !matches!(
+                response.status(),
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            ) {
+                return Ok(response);
+            }
+            let Some(delay) = retry_after(response.headers()) else {
+                return Ok(response);
+            };
+            if delay > MAX_DELAY {
+                debug!(
+                    "Request to {} was rate-limited ({}), but `Retry-After` exceeds the maximum delay of {}s",
+                    response.url(),
+                    response.status(),
+                    MAX_DELAY.as_secs()
+                );
+                return Ok(response);
+            }
+
+            debug!(
+                "Request to {} was rate-limited ({}), pausing requests to {host} for {:.1}s",
+                response.url(),
+                response.status(),
+                delay.as_secs_f64()
+            );
+            pause(&host, delay);
+            attempts += 1;
+            req = retry;
+        }
+    }
+}
+
+/// Return the key for the host of a URL, including the port.
+fn host_key(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    match url.port_or_known_default() {
+        Some(port) => Some(format!("{host}:{port}")),
+        None => Some(host.to_string()),
+    }
+}
+
+/// Wait until requests to the host are no longer paused.
+async fn wait(host: &str) {
+    loop {
+        let paused_until = HOSTS
+            .lock()
+            .unwrap()
+            .get(host)
+            .map(|state| state.paused_until);
+        match paused_until {
+            Some(paused_until) if paused_until > Instant::now() => {
+                trace!("Waiting for rate limit on {host} to elapse");
+                tokio::time::sleep_until(paused_until.into()).await;
+            }
+            // The pause may have been extended while we were waiting, so check again.
+            _ => return,
+        }
+    }
+}
+
+/// Pause requests to the host for the given delay.
+fn pause(host: &str, delay: Duration) {
+    let now = Instant::now();
+    let until = now + delay;
+    let mut hosts = HOSTS.lock().unwrap();
+    let state = hosts.entry(host.to_string()).or_insert(HostState {
+        paused_until: now,
+        paused: Duration::ZERO,
+        responses: 0,
+    });
+    state.responses += 1;
+    if until > state.paused_until {
+        // Only count the time by which the pause is extended.
+        state.paused += until - state.paused_until.max(now);
+        state.paused_until = until;
+    }
+}
+
+/// Parse the `Retry-After` header, as either a number of seconds or an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::DateTimeParser::new()
+        .parse_timestamp(value)
+        .ok()?;
+    // A date in the past means the request can be retried immediately.
+    let delay = date.duration_since(jiff::Timestamp::now());
+    Some(Duration::try_from(delay).unwrap_or(Duration::ZERO))
+}
+
+/// The time spent waiting on rate-limited hosts, per the `Retry-After` header.
+#[derive(Debug)]
+pub struct RetryAfterSummary(Vec<(String, Duration, usize)>);
+
+impl Display for RetryAfterSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let total = self
+            .0
+            .iter()
+            .map(|(_, paused, _)| *paused)
+            .sum::<Duration>();
+        write!(
+            f,
+            "Waited {:.1}s for rate-limited requests to {} (as requested via `Retry-After`)",
+            total.as_secs_f64(),
+            self.0
+                .iter()
+                .map(|(host, paused, responses)| {
+                    let s = if *responses == 1 { "" } else { "s" };
+                    format!(
+                        "`{host}` ({:.1}s, {responses} response{s})",
+                        paused.as_secs_f64()
+                    )
+                })
+                .join(", ")
+        )
+    }
+}
+
+/// Return a summary of the time spent waiting on rate-limited hosts, if any.
+pub fn retry_after_summary() -> Option<RetryAfterSummary> {
+    let hosts = HOSTS.lock().unwrap();
+    if hosts.is_empty() {
+        return None;
+    }
+    Some(RetryAfterSummary(
+        hosts
+            .iter()
+            .map(|(host, state)| (host.clone(), state.paused, state.responses))
+            .sorted()
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn parse_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(http::header::RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+
+        headers.insert(
+            http::header::RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(http::header::RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+    }
+}
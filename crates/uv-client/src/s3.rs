@@ -0,0 +1,307 @@
+//! Support for `--find-links`-style indexes stored in an S3 bucket (e.g., `s3://bucket/prefix`).
+//!
+//! Objects are listed via the S3 `ListObjectsV2` API and downloaded over HTTPS, with requests
+//! signed using the credentials from the standard AWS credential chain, if any.
+
+use http::Extensions;
+use percent_encoding::percent_decode_str;
+use reqwest::{Request, Response, Url};
+use reqwest_middleware::{Middleware, Next};
+use tracing::trace;
+
+use uv_auth::{AwsService, aws_region, sign_aws_request};
+use uv_distribution_types::{File, FileLocation, UrlString};
+use uv_pypi_types::HashDigests;
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+
+use crate::retry::is_prefix;
+use crate::{Error, ErrorKind, RedirectClientWithMiddleware};
+
+/// The region to assume if none is configured.
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// A location in an S3 bucket, as given by an `s3://bucket/prefix` URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct S3Location {
+    bucket: String,
+    prefix: String,
+}
+
+impl S3Location {
+    /// Parse an `s3://bucket/prefix` URL.
+    pub(crate) fn from_url(url: &Url) -> Option<Self> {
+        if url.scheme() != "s3" {
+            return None;
+        }
+        let bucket = url.host_str()?.to_string();
+        let prefix = percent_decode_str(url.path().trim_start_matches('/'))
+            .decode_utf8()
+            .ok()?;
+        // Treat the prefix as a directory.
+        let prefix = if prefix.is_empty() || prefix.ends_with('/') {
+            prefix.into_owned()
+        } else {
+            format!("{prefix}/")
+        };
+        Some(Self { bucket, prefix })
+    }
+
+    /// Return the HTTPS URL of the bucket in the given region, with a trailing slash.
+    ///
+    /// Uses the endpoint from `AWS_ENDPOINT_URL_S3` or `AWS_ENDPOINT_URL` (with path-style
+    /// addressing), if set, or the regional AWS endpoint otherwise.
+    pub(crate) fn bucket_url(&self, region: &str) -> DisplaySafeUrl {
+        let endpoint = std::env::var(EnvVars::AWS_ENDPOINT_URL_S3)
+            .or_else(|_| std::env::var(EnvVars::AWS_ENDPOINT_URL))
+            .ok()
+            .and_then(|endpoint| DisplaySafeUrl::parse(&endpoint).ok());
+        let mut url = if let Some(endpoint) = endpoint {
+            endpoint
+        } else if self.bucket.contains('.') {
+            // Dotted bucket names don't match the wildcard certificate for virtual-hosted URLs.
+            DisplaySafeUrl::parse(&format!("https://s3.{region}.amazonaws.com"))
+                .expect("valid S3 endpoint")
+        } else {
+            return DisplaySafeUrl::parse(&format!(
+                "https://{}.s3.{region}.amazonaws.com/",
+                self.bucket
+            ))
+            .expect("valid S3 endpoint");
+        };
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty().push(&self.bucket).push("");
+        }
+        url
+    }
+
+    /// Return the URL of an object in the bucket.
+    fn object_url(&self, region: &str, key: &str) -> DisplaySafeUrl {
+        let mut url = self.bucket_url(region);
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty().extend(key.split('/'));
+        }
+        url
+    }
+}
+
+/// Return the AWS region of the buckets, as configured via the standard AWS configuration.
+pub(crate) async fn region() -> String {
+    aws_region()
+        .await
+        .unwrap_or_else(|| DEFAULT_REGION.to_string())
+}
+
+/// List the distributions stored directly beneath the given location.
+pub(crate) async fn list_files(
+    client: &RedirectClientWithMiddleware,
+    location: &S3Location,
+    region: &str,
+) -> Result<Vec<File>, Error> {
+    let mut files = Vec::new();
+    let mut continuation_token: Option<String> = None;
+    loop {
+        let mut url = location.bucket_url(region);
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("list-type", "2")
+                .append_pair("delimiter", "/")
+                .append_pair("prefix", &location.prefix);
+            if let Some(token) = &continuation_token {
+                query.append_pair("continuation-token", token);
+            }
+        }
+        trace!("Listing objects in {url}");
+
+        let text = client
+            .get(Url::from(url.clone()))
+            .send()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
+            .error_for_status()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?
+            .text()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let result = ListBucketResult::parse(&text).map_err(|err| Error::from_xml_err(err, url))?;
+
+        for object in result.contents {
+            let Some(filename) = object.key.strip_prefix(location.prefix.as_str()) else {
+                continue;
+            };
+            if filename.is_empty() || filename.contains('/') {
+                continue;
+            }
+            let file = File {
+                dist_info_metadata: false,
+                filename: filename.into(),
+                hashes: HashDigests::empty(),
+                requires_python: None,
+                size: object.size,
+                upload_time_utc_ms: None,
+                url: FileLocation::AbsoluteUrl(UrlString::from(
+                    location.object_url(region, &object.key),
+                )),
+                yanked: None,
+            };
+            files.push(file);
+        }
+
+        if !result.is_truncated {
+            break;
+        }
+        let Some(token) = result.next_continuation_token else {
+            break;
+        };
+        continuation_token = Some(token);
+    }
+    Ok(files)
+}
+
+/// A page of the response to a `ListObjectsV2` request.
+///
+/// See: <https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListObjectsV2.html>
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ListBucketResult {
+    contents: Vec<Object>,
+    is_truncated: bool,
+    next_continuation_token: Option<String>,
+}
+
+/// An object in a `ListObjectsV2` response.
+#[derive(Debug, PartialEq, Eq)]
+struct Object {
+    key: String,
+    size: Option<u64>,
+}
+
+impl ListBucketResult {
+    fn parse(xml: &str) -> Result<Self, roxmltree::Error> {
+        let document = roxmltree::Document::parse(xml)?;
+        let mut result = Self::default();
+        for node in document
+            .root_element()
+            .children()
+            .filter(roxmltree::Node::is_element)
+        {
+            match node.tag_name().name() {
+                "Contents" => {
+                    let Some(key) = child_text(node, "Key") else {
+                        continue;
+                    };
+                    result.contents.push(Object {
+                        key: key.to_string(),
+                        size: child_text(node, "Size").and_then(|size| size.parse().ok()),
+                    });
+                }
+                "IsTruncated" => result.is_truncated = node.text() == Some("true"),
+                "NextContinuationToken" => {
+                    result.next_continuation_token = node.text().map(ToString::to_string);
+                }
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Return the text of the first child element with the given name.
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name(name))
+        .and_then(|child| child.text())
+}
+
+/// A middleware that signs requests to S3 buckets with AWS Signature Version 4.
+pub(crate) struct S3SigningMiddleware {
+    /// The locations of `s3://` indexes.
+    locations: Vec<S3Location>,
+}
+
+impl S3SigningMiddleware {
+    pub(crate) fn new(locations: Vec<S3Location>) -> Self {
+        Self { locations }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for S3SigningMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let region = region().await;
+        if self
+            .locations
+            .iter()
+            .any(|location| is_prefix(&location.bucket_url(&region), req.url()))
+        {
+            sign_aws_request(&mut req, AwsService::S3, &region).await;
+        }
+        next.run(req, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let url = Url::parse("s3://wheels/internal/simple").unwrap();
+        assert_eq!(
+            S3Location::from_url(&url),
+            Some(S3Location {
+                bucket: "wheels".to_string(),
+                prefix: "internal/simple/".to_string(),
+            })
+        );
+        let url = Url::parse("s3://wheels").unwrap();
+        assert_eq!(
+            S3Location::from_url(&url),
+            Some(S3Location {
+                bucket: "wheels".to_string(),
+                prefix: String::new(),
+            })
+        );
+        let url = Url::parse("https://wheels.s3.amazonaws.com").unwrap();
+        assert_eq!(S3Location::from_url(&url), None);
+    }
+
+    #[test]
+    fn list_bucket_result() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>wheels</Name>
+            <Prefix>internal/</Prefix>
+            <IsTruncated>true</IsTruncated>
+            <NextContinuationToken>1ueGcxLPRx1Tr/XYExHnhbYLgveDs2J/wm36Hy4vbOwM=</NextContinuationToken>
+            <Contents><Key>internal/a-1.0-py3-none-any.whl</Key><Size>10</Size></Contents>
+            <Contents><Key>internal/b&amp;c-1.0.tar.gz</Key><Size>20</Size></Contents>
+            <CommonPrefixes><Prefix>internal/nested/</Prefix></CommonPrefixes>
+        </ListBucketResult>"#;
+        assert_eq!(
+            ListBucketResult::parse(xml).unwrap(),
+            ListBucketResult {
+                contents: vec![
+                    Object {
+                        key: "internal/a-1.0-py3-none-any.whl".to_string(),
+                        size: Some(10),
+                    },
+                    Object {
+                        key: "internal/b&c-1.0.tar.gz".to_string(),
+                        size: Some(20),
+                    },
+                ],
+                is_truncated: true,
+                next_continuation_token: Some(
+                    "1ueGcxLPRx1Tr/XYExHnhbYLgveDs2J/wm36Hy4vbOwM=".to_string()
+                ),
+            }
+        );
+        assert!(ListBucketResult::parse("<ListBucketResult>").is_err());
+    }
+}
@@ -0,0 +1,389 @@
+//! A process-wide trace of HTTP requests, written as JSON lines or as a HAR file.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use http::{Extensions, HeaderMap};
+use jiff::Timestamp;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use serde::Serialize;
+use tracing::debug;
+use url::Url;
+
+use uv_redacted::DisplaySafeUrl;
+
+/// The trace shared by all clients in the process, if tracing is enabled.
+static TRACE: OnceLock<HttpTrace> = OnceLock::new();
+
+/// Headers whose values are omitted from the trace, as they may contain secrets.
+const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "cookie",
+    "proxy-authorization",
+    "set-cookie",
+    "x-amz-security-token",
+];
+
+/// Record all HTTP requests to the given file.
+///
+/// If the path has a `.har` extension, the trace is written as an HTTP Archive (HAR) when
+/// [`flush_http_trace`] is called; otherwise, each request is appended to the file as a JSON
+/// line as soon as it completes.
+pub fn set_http_trace(path: &Path) -> Result<(), std::io::Error> {
+    let format = if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("har"))
+    {
+        TraceFormat::Har(Mutex::new(Vec::new()))
+    } else {
+        TraceFormat::JsonLines(Mutex::new(fs_err::File::create(path)?))
+    };
+    if TRACE
+        .set(HttpTrace {
+            path: path.to_path_buf(),
+            format,
+        })
+        .is_ok()
+    {
+        debug!("Writing HTTP trace to: {}", path.display());
+    }
+    Ok(())
+}
+
+/// Write out the HTTP trace, if it's buffered in memory.
+pub fn flush_http_trace() -> Result<(), std::io::Error> {
+    let Some(trace) = TRACE.get() else {
+        return Ok(());
+    };
+    match &trace.format {
+        TraceFormat::JsonLines(file) => file.lock().unwrap().flush(),
+        TraceFormat::Har(entries) => {
+            let entries = entries.lock().unwrap();
+            let har = Har {
+                log: HarLog {
+                    version: "1.2",
+                    creator: HarCreator {
+                        name: "uv",
+                        version: uv_version::version(),
+                    },
+                    entries: entries.iter().map(HarEntry::from).collect(),
+                },
+            };
+            let file = fs_err::File::create(&trace.path)?;
+            serde_json::to_writer_pretty(std::io::BufWriter::new(file), &har)
+                .map_err(std::io::Error::from)
+        }
+    }
+}
+
+/// Record a request that was served from the cache, without contacting the server.
+pub(crate) fn record_cache_hit(method: &http::Method, url: &Url) {
+    let Some(trace) = TRACE.get() else {
+        return;
+    };
+    trace.record(TraceEntry {
+        started: Timestamp::now(),
+        method: method.to_string(),
+        url: DisplaySafeUrl::from(url.clone()).to_string(),
+        request_headers: Vec::new(),
+        status: None,
+        response_headers: Vec::new(),
+        time_ms: 0.0,
+        cache: CacheDisposition::Hit,
+        error: None,
+    });
+}
+
+#[derive(Debug)]
+struct HttpTrace {
+    path: PathBuf,
+    format: TraceFormat,
+}
+
+#[derive(Debug)]
+enum TraceFormat {
+    JsonLines(Mutex<fs_err::File>),
+    Har(Mutex<Vec<TraceEntry>>),
+}
+
+impl HttpTrace {
+    fn record(&self, entry: TraceEntry) {
+        match &self.format {
+            TraceFormat::JsonLines(file) => {
+                let Ok(mut line) = serde_json::to_vec(&entry) else {
+                    return;
+                };
+                line.push(b'\n');
+                if let Err(err) = file.lock().unwrap().write_all(&line) {
+                    debug!("Failed to write to HTTP trace: {err}");
+                }
+            }
+            TraceFormat::Har(entries) => entries.lock().unwrap().push(entry),
+        }
+    }
+}
+
+/// How the cache was involved in a request.
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum CacheDisposition {
+    /// The response was served from the cache, without contacting the server.
+    Hit,
+    /// The cached response was revalidated with the server, which reported it as unchanged.
+    Revalidated,
+    /// The cached response was revalidated with the server, which returned a new response.
+    Modified,
+    /// The request was sent without a cached response.
+    Miss,
+}
+
+/// A single traced request.
+#[derive(Debug, Serialize)]
+struct TraceEntry {
+    started: Timestamp,
+    method: String,
+    url: String,
+    request_headers: Vec<TraceHeader>,
+    status: Option<u16>,
+    response_headers: Vec<TraceHeader>,
+    time_ms: f64,
+    cache: CacheDisposition,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct TraceHeader {
+    name: String,
+    value: String,
+}
+
+/// Convert the headers to a list of [`TraceHeader`], omitting the values of sensitive headers.
+fn trace_headers(headers: &HeaderMap) -> Vec<TraceHeader> {
+    headers
+        .iter()
+        .map(|(name, value)| TraceHeader {
+            name: name.to_string(),
+            value: if REDACTED_HEADERS.contains(&name.as_str()) {
+                "****".to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            },
+        })
+        .collect()
+}
+
+/// A middleware that records each request sent over the network to the HTTP trace.
+///
+/// This should be the innermost middleware, such that each attempt (e.g., retries and
+/// authenticated requests) is recorded with the headers that were actually sent.
+pub(crate) struct TraceMiddleware;
+
+impl TraceMiddleware {
+    /// Returns `true` if HTTP tracing is enabled.
+    pub(crate) fn is_enabled() -> bool {
+        TRACE.get().is_some()
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for TraceMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(trace) = TRACE.get() else {
+            return next.run(req, extensions).await;
+        };
+
+        let started = Timestamp::now();
+        let start = Instant::now();
+        let method = req.method().to_string();
+        let url = DisplaySafeUrl::from(req.url().clone()).to_string();
+        let request_headers = trace_headers(req.headers());
+        let conditional = req.headers().contains_key(http::header::IF_NONE_MATCH)
+            || req.headers().contains_key(http::header::IF_MODIFIED_SINCE);
+
+        let result = next.run(req, extensions).await;
+
+        let (status, response_headers, error) = match &result {
+            Ok(response) => (
+                Some(response.status().as_u16()),
+                trace_headers(response.headers()),
+                None,
+            ),
+            Err(err) => (None, Vec::new(), Some(err.to_string())),
+        };
+        let cache = match status {
+            Some(304) => CacheDisposition::Revalidated,
+            _ if conditional => CacheDisposition::Modified,
+            _ => CacheDisposition::Miss,
+        };
+        trace.record(TraceEntry {
+            started,
+            method,
+            url,
+            request_headers,
+            status,
+            response_headers,
+            time_ms: start.elapsed().as_secs_f64() * 1000.0,
+            cache,
+            error,
+        });
+
+        result
+    }
+}
+
+/// An HTTP Archive, per <http://www.softwareishard.com/blog/har-12-spec/>.
+#[derive(Serialize)]
+struct Har<'a> {
+    log: HarLog<'a>,
+}
+
+#[derive(Serialize)]
+struct HarLog<'a> {
+    version: &'static str,
+    creator: HarCreator,
+    entries: Vec<HarEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct HarCreator {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarEntry<'a> {
+    started_date_time: Timestamp,
+    time: f64,
+    request: HarRequest<'a>,
+    response: HarResponse<'a>,
+    cache: HarCache,
+    timings: HarTimings,
+    #[serde(rename = "_cacheDisposition")]
+    cache_disposition: CacheDisposition,
+    #[serde(rename = "_error", skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest<'a> {
+    method: &'a str,
+    url: &'a str,
+    http_version: &'static str,
+    cookies: [(); 0],
+    headers: &'a [TraceHeader],
+    query_string: [(); 0],
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarResponse<'a> {
+    status: u16,
+    status_text: &'static str,
+    http_version: &'static str,
+    cookies: [(); 0],
+    headers: &'a [TraceHeader],
+    content: HarContent,
+    #[serde(rename = "redirectURL")]
+    redirect_url: &'static str,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarContent {
+    size: i64,
+    mime_type: &'static str,
+}
+
+/// HAR requires an (empty) object here, so this can't be a unit struct.
+#[derive(Serialize)]
+#[allow(clippy::empty_structs_with_brackets)]
+struct HarCache {}
+
+#[derive(Serialize)]
+struct HarTimings {
+    send: f64,
+    wait: f64,
+    receive: f64,
+}
+
+impl<'a> From<&'a TraceEntry> for HarEntry<'a> {
+    fn from(entry: &'a TraceEntry) -> Self {
+        let status = entry.status.unwrap_or(0);
+        Self {
+            started_date_time: entry.started,
+            time: entry.time_ms,
+            request: HarRequest {
+                method: &entry.method,
+                url: &entry.url,
+                http_version: "HTTP/1.1",
+                cookies: [],
+                headers: &entry.request_headers,
+                query_string: [],
+                headers_size: -1,
+                body_size: -1,
+            },
+            response: HarResponse {
+                status,
+                status_text: http::StatusCode::from_u16(status)
+                    .ok()
+                    .and_then(|status| status.canonical_reason())
+                    .unwrap_or(""),
+                http_version: "HTTP/1.1",
+                cookies: [],
+                headers: &entry.response_headers,
+                content: HarContent {
+                    size: -1,
+                    mime_type: "",
+                },
+                redirect_url: "",
+                headers_size: -1,
+                body_size: -1,
+            },
+            cache: HarCache {},
+            timings: HarTimings {
+                send: 0.0,
+                wait: entry.time_ms,
+                receive: 0.0,
+            },
+            cache_disposition: entry.cache,
+            error: entry.error.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn redact_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("Basic c2VjcmV0"));
+        headers.insert("accept", HeaderValue::from_static("text/html"));
+        let headers = trace_headers(&headers);
+        let headers = headers
+            .iter()
+            .map(|header| (header.name.as_str(), header.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            headers,
+            [("authorization", "****"), ("accept", "text/html")]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Support for the repository tracking and alternate locations metadata defined in PEP 708.
+//!
+//! When a package is found on multiple indexes, PEP 708 allows a repository to declare that its
+//! project "tracks" the project of the same name on another repository, or that the project's
+//! namespace is extended across several repositories. Combining versions from indexes that don't
+//! declare such a relationship is the root of dependency confusion attacks.
+//!
+//! See: <https://peps.python.org/pep-0708/>
+
+use tracing::debug;
+
+use uv_normalize::PackageName;
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user_once;
+
+use crate::registry_client::ArchivedSimpleMetadata;
+use crate::{Error, ErrorKind};
+
+/// The PEP 708 metadata for a project on a single index.
+#[derive(Debug)]
+pub(crate) struct ProjectLocations<'a> {
+    /// The URL of the project page on the index.
+    url: DisplaySafeUrl,
+    /// The project pages that the project tracks.
+    tracks: Vec<&'a str>,
+    /// The repositories that extend the project's namespace.
+    alternate_locations: Vec<&'a str>,
+}
+
+impl<'a> ProjectLocations<'a> {
+    /// Read the [`ProjectLocations`] for the project at the given URL from its metadata.
+    pub(crate) fn from_metadata(
+        mut url: DisplaySafeUrl,
+        metadata: &'a ArchivedSimpleMetadata,
+    ) -> Self {
+        url.remove_credentials();
+        Self {
+            url,
+            tracks: metadata.tracks().collect(),
+            alternate_locations: metadata.alternate_locations().collect(),
+        }
+    }
+
+    /// Returns `true` if the index declares any PEP 708 metadata for the project.
+    fn is_declared(&self) -> bool {
+        !self.tracks.is_empty() || !self.alternate_locations.is_empty()
+    }
+
+    /// Returns `true` if the project may be combined with the project on another index.
+    ///
+    /// Either project may track the other; otherwise, both must list the other as an alternate
+    /// location.
+    fn is_linked(&self, other: &Self) -> bool {
+        contains(&self.tracks, &other.url)
+            || contains(&other.tracks, &self.url)
+            || (contains(&self.alternate_locations, &other.url)
+                && contains(&other.alternate_locations, &self.url))
+    }
+}
+
+/// Returns `true` if any of the given URLs refers to the project page at `url`.
+fn contains(urls: &[&str], url: &DisplaySafeUrl) -> bool {
+    let url = url.as_str().trim_end_matches('/');
+    urls.iter()
+        .any(|candidate| candidate.trim_end_matches('/') == url)
+}
+
+/// Verify that a package found on multiple indexes may be combined across them.
+///
+/// If the indexes publish PEP 708 metadata that doesn't link them, a warning is emitted; in
+/// strict mode, any pair of indexes that aren't linked is an error.
+pub(crate) fn check_index_tracking(
+    package_name: &PackageName,
+    projects: &[ProjectLocations],
+    strict: bool,
+) -> Result<(), Error> {
+    for (position, first) in projects.iter().enumerate() {
+        for second in &projects[position + 1..] {
+            if first.is_linked(second) {
+                continue;
+            }
+            if strict {
+                return Err(ErrorKind::UntrackedIndexes(
+                    package_name.clone(),
+                    first.url.clone(),
+                    second.url.clone(),
+                )
+                .into());
+            }
+            if first.is_declared() || second.is_declared() {
+                warn_user_once!(
+                    "`{package_name}` was found at `{}` and `{}`, but neither declares that it tracks the other; combining versions across these indexes may be unsafe",
+                    first.url,
+                    second.url
+                );
+            } else {
+                debug!(
+                    "Combining `{package_name}` from `{}` and `{}`, which don't declare a tracking relationship",
+                    first.url, second.url
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project<'a>(
+        url: &str,
+        tracks: &[&'a str],
+        alternate_locations: &[&'a str],
+    ) -> ProjectLocations<'a> {
+        ProjectLocations {
+            url: DisplaySafeUrl::parse(url).unwrap(),
+            tracks: tracks.to_vec(),
+            alternate_locations: alternate_locations.to_vec(),
+        }
+    }
+
+    #[test]
+    fn tracks() {
+        let pypi = project("https://pypi.org/simple/holygrail/", &[], &[]);
+        let mirror = project(
+            "https://example.com/simple/holygrail/",
+            &["https://pypi.org/simple/holygrail"],
+            &[],
+        );
+        assert!(mirror.is_linked(&pypi));
+        assert!(pypi.is_linked(&mirror));
+    }
+
+    #[test]
+    fn alternate_locations() {
+        let locations = [
+            "https://pypi.org/simple/holygrail/",
+            "https://example.com/simple/holygrail/",
+        ];
+        let pypi = project("https://pypi.org/simple/holygrail/", &[], &locations);
+        let extension = project("https://example.com/simple/holygrail/", &[], &locations);
+        assert!(pypi.is_linked(&extension));
+
+        // Both repositories must agree on the alternate locations.
+        let pypi = project("https://pypi.org/simple/holygrail/", &[], &[]);
+        assert!(!pypi.is_linked(&extension));
+    }
+
+    #[test]
+    fn untracked() {
+        let pypi = project("https://pypi.org/simple/holygrail/", &[], &[]);
+        let internal = project("https://internal.example.com/simple/holygrail/", &[], &[]);
+        assert!(!pypi.is_linked(&internal));
+        assert!(!internal.is_declared());
+    }
+}
@@ -15,7 +15,10 @@ pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
 pub use project_build_backend::*;
+pub use rate_limit::*;
+pub use refresh_interval::*;
 pub use required_version::*;
+pub use retry::*;
 pub use sources::*;
 pub use target_triple::*;
 pub use threading::*;
@@ -40,7 +43,10 @@ mod overrides;
 mod package_options;
 mod preview;
 mod project_build_backend;
+mod rate_limit;
+mod refresh_interval;
 mod required_version;
+mod retry;
 mod sources;
 mod target_triple;
 mod threading;
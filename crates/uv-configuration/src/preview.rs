@@ -14,6 +14,9 @@ bitflags::bitflags! {
         const JSON_OUTPUT = 1 << 2;
         const PYLOCK = 1 << 3;
         const ADD_BOUNDS = 1 << 4;
+        const AUDIT_WHEELS = 1 << 5;
+        const TOOL_LAYERS = 1 << 6;
+        const MEMBER_REQUIRES_PYTHON = 1 << 7;
     }
 }
 
@@ -28,6 +31,9 @@ impl PreviewFeatures {
             Self::JSON_OUTPUT => "json-output",
             Self::PYLOCK => "pylock",
             Self::ADD_BOUNDS => "add-bounds",
+            Self::AUDIT_WHEELS => "audit-wheels",
+            Self::TOOL_LAYERS => "tool-layers",
+            Self::MEMBER_REQUIRES_PYTHON => "member-requires-python",
             _ => panic!("`flag_as_str` can only be used for exactly one feature flag"),
         }
     }
@@ -70,6 +76,9 @@ impl FromStr for PreviewFeatures {
                 "json-output" => Self::JSON_OUTPUT,
                 "pylock" => Self::PYLOCK,
                 "add-bounds" => Self::ADD_BOUNDS,
+                "audit-wheels" => Self::AUDIT_WHEELS,
+                "tool-layers" => Self::TOOL_LAYERS,
+                "member-requires-python" => Self::MEMBER_REQUIRES_PYTHON,
                 _ => {
                     warn_user_once!("Unknown preview feature: `{part}`");
                     continue;
@@ -232,6 +241,12 @@ mod tests {
         assert_eq!(PreviewFeatures::JSON_OUTPUT.flag_as_str(), "json-output");
         assert_eq!(PreviewFeatures::PYLOCK.flag_as_str(), "pylock");
         assert_eq!(PreviewFeatures::ADD_BOUNDS.flag_as_str(), "add-bounds");
+        assert_eq!(PreviewFeatures::AUDIT_WHEELS.flag_as_str(), "audit-wheels");
+        assert_eq!(PreviewFeatures::TOOL_LAYERS.flag_as_str(), "tool-layers");
+        assert_eq!(
+            PreviewFeatures::MEMBER_REQUIRES_PYTHON.flag_as_str(),
+            "member-requires-python"
+        );
     }
 
     #[test]
@@ -0,0 +1,145 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+/// A limit on the download bandwidth, in bytes per second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RateLimit(NonZeroU64);
+
+impl RateLimit {
+    /// Create a [`RateLimit`] from a number of bytes per second.
+    pub fn from_bytes_per_second(bytes: NonZeroU64) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the limit in bytes per second.
+    pub fn bytes_per_second(self) -> u64 {
+        self.0.get()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RateLimitError {
+    #[error("invalid rate limit: `{0}` (expected bytes per second, e.g., `512K` or `10M`)")]
+    Invalid(String),
+    #[error("rate limit must be greater than zero: `{0}`")]
+    Zero(String),
+}
+
+impl FromStr for RateLimit {
+    type Err = RateLimitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        // Detect the (binary) unit suffix, e.g., `10M` or `10MiB`.
+        let digits = trimmed.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '/');
+        let suffix = &trimmed[digits.len()..];
+        let multiplier: u64 = match suffix.to_ascii_lowercase().as_str() {
+            "" | "b" | "b/s" => 1,
+            "k" | "kb" | "kib" | "k/s" | "kb/s" | "kib/s" => 1024,
+            "m" | "mb" | "mib" | "m/s" | "mb/s" | "mib/s" => 1024 * 1024,
+            "g" | "gb" | "gib" | "g/s" | "gb/s" | "gib/s" => 1024 * 1024 * 1024,
+            _ => return Err(RateLimitError::Invalid(s.to_string())),
+        };
+
+        let value = digits
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| RateLimitError::Invalid(s.to_string()))?
+            .checked_mul(multiplier)
+            .ok_or_else(|| RateLimitError::Invalid(s.to_string()))?;
+
+        NonZeroU64::new(value)
+            .map(Self)
+            .ok_or_else(|| RateLimitError::Zero(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let bytes = self.0.get();
+        for (suffix, unit) in [("G", 1024 * 1024 * 1024), ("M", 1024 * 1024), ("K", 1024)] {
+            if bytes % unit == 0 {
+                return write!(f, "{}{suffix}", bytes / unit);
+            }
+        }
+        write!(f, "{bytes}")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RateLimit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .string(|string| RateLimit::from_str(string).map_err(serde::de::Error::custom))
+            .u64(|bytes| {
+                NonZeroU64::new(bytes).map(RateLimit).ok_or_else(|| {
+                    serde::de::Error::custom(RateLimitError::Zero(bytes.to_string()))
+                })
+            })
+            .deserialize(deserializer)
+    }
+}
+
+impl serde::Serialize for RateLimit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RateLimit {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("RateLimit")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": ["string", "integer"],
+            "description": "A rate in bytes per second, with an optional `K`, `M`, or `G` suffix (e.g., `10M`)."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!("512".parse::<RateLimit>().unwrap().bytes_per_second(), 512);
+        assert_eq!(
+            "10K".parse::<RateLimit>().unwrap().bytes_per_second(),
+            10 * 1024
+        );
+        assert_eq!(
+            "10M".parse::<RateLimit>().unwrap().bytes_per_second(),
+            10 * 1024 * 1024
+        );
+        assert_eq!(
+            "2MiB/s".parse::<RateLimit>().unwrap().bytes_per_second(),
+            2 * 1024 * 1024
+        );
+        assert_eq!(
+            "1g".parse::<RateLimit>().unwrap().bytes_per_second(),
+            1024 * 1024 * 1024
+        );
+        assert!("0".parse::<RateLimit>().is_err());
+        assert!("fast".parse::<RateLimit>().is_err());
+        assert!("10X".parse::<RateLimit>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("10M".parse::<RateLimit>().unwrap().to_string(), "10M");
+        assert_eq!("1536".parse::<RateLimit>().unwrap().to_string(), "1536");
+        assert_eq!("2048K".parse::<RateLimit>().unwrap().to_string(), "2M");
+    }
+}
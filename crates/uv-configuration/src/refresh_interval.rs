@@ -0,0 +1,150 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The interval after which a previous resolution should be refreshed, e.g., `7d` or `12h`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RefreshInterval(Duration);
+
+impl RefreshInterval {
+    /// Create a [`RefreshInterval`] from a [`Duration`].
+    pub fn from_duration(duration: Duration) -> Self {
+        Self(duration)
+    }
+
+    /// Return the interval as a [`Duration`].
+    pub fn duration(self) -> Duration {
+        self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "invalid refresh interval: `{0}` (expected a number with a unit, e.g., `30m`, `12h`, or `7d`)"
+)]
+pub struct RefreshIntervalError(String);
+
+impl FromStr for RefreshInterval {
+    type Err = RefreshIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        // Detect the unit suffix, e.g., `7d` or `12h`.
+        let digits = trimmed.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let suffix = &trimmed[digits.len()..];
+        let multiplier: u64 = match suffix.to_ascii_lowercase().as_str() {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            // A unit isn't required for zero, which disables the interval.
+            "" if digits.trim() == "0" => 1,
+            _ => return Err(RefreshIntervalError(s.to_string())),
+        };
+
+        let seconds = digits
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| RefreshIntervalError(s.to_string()))?
+            .checked_mul(multiplier)
+            .ok_or_else(|| RefreshIntervalError(s.to_string()))?;
+
+        Ok(Self(Duration::from_secs(seconds)))
+    }
+}
+
+impl std::fmt::Display for RefreshInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let seconds = self.0.as_secs();
+        if seconds == 0 {
+            return write!(f, "0");
+        }
+        for (suffix, unit) in [
+            ("w", 7 * 24 * 60 * 60),
+            ("d", 24 * 60 * 60),
+            ("h", 60 * 60),
+            ("m", 60),
+        ] {
+            if seconds % unit == 0 {
+                return write!(f, "{}{suffix}", seconds / unit);
+            }
+        }
+        write!(f, "{seconds}s")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RefreshInterval {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        RefreshInterval::from_str(&string).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for RefreshInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RefreshInterval {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("RefreshInterval")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A duration with an `s`, `m`, `h`, `d`, or `w` suffix (e.g., `7d`)."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "90s".parse::<RefreshInterval>().unwrap().duration(),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            "30m".parse::<RefreshInterval>().unwrap().duration(),
+            Duration::from_secs(30 * 60)
+        );
+        assert_eq!(
+            "12h".parse::<RefreshInterval>().unwrap().duration(),
+            Duration::from_secs(12 * 60 * 60)
+        );
+        assert_eq!(
+            "7D".parse::<RefreshInterval>().unwrap().duration(),
+            Duration::from_secs(7 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            "0".parse::<RefreshInterval>().unwrap().duration(),
+            Duration::ZERO
+        );
+        assert!("7".parse::<RefreshInterval>().is_err());
+        assert!("soon".parse::<RefreshInterval>().is_err());
+        assert!("7y".parse::<RefreshInterval>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("7d".parse::<RefreshInterval>().unwrap().to_string(), "1w");
+        assert_eq!("36h".parse::<RefreshInterval>().unwrap().to_string(), "36h");
+        assert_eq!("90s".parse::<RefreshInterval>().unwrap().to_string(), "90s");
+        assert_eq!("0".parse::<RefreshInterval>().unwrap().to_string(), "0");
+    }
+}
@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+/// The random jitter to apply to the delay between retries of a failed HTTP request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RetryJitter {
+    /// Use the computed delay as-is.
+    None,
+    /// Pick a random delay between zero and the computed delay.
+    Full,
+    /// Pick a random delay between the base delay and the computed delay.
+    Bounded,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown jitter `{0}` (expected `none`, `full`, or `bounded`)")]
+pub struct RetryJitterError(String);
+
+impl FromStr for RetryJitter {
+    type Err = RetryJitterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "full" => Ok(Self::Full),
+            "bounded" => Ok(Self::Bounded),
+            _ => Err(RetryJitterError(s.to_string())),
+        }
+    }
+}
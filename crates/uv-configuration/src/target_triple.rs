@@ -464,6 +464,16 @@ impl TargetTriple {
         }
     }
 
+    /// Return the [`Platform`] for the target, using the given macOS deployment target for macOS
+    /// targets, rather than reading `MACOSX_DEPLOYMENT_TARGET`.
+    pub fn platform_with_macos_deployment_target(self, major: u16, minor: u16) -> Platform {
+        let platform = self.platform();
+        match platform.os() {
+            Os::Macos { .. } => Platform::new(Os::Macos { major, minor }, platform.arch()),
+            _ => platform,
+        }
+    }
+
     /// Return the `platform_machine` value for the target.
     pub fn platform_machine(self) -> &'static str {
         match self {
@@ -756,6 +766,34 @@ impl TargetTriple {
         }
     }
 
+    /// Return the platform in the format of Python's `sysconfig.get_platform()`, e.g.,
+    /// `manylinux_2_28-x86_64` or `macosx-13.0-arm64`.
+    ///
+    /// Build backends derive the platform tag of a wheel from this value, which can be overridden
+    /// through the `_PYTHON_HOST_PLATFORM` environment variable when cross-compiling.
+    pub fn sysconfig_platform(self) -> String {
+        let platform = self.platform();
+        let arch = platform.arch();
+        match platform.os() {
+            Os::Manylinux { major, minor } => format!("manylinux_{major}_{minor}-{arch}"),
+            Os::Musllinux { major, minor } => format!("musllinux_{major}_{minor}-{arch}"),
+            Os::Macos { major, minor } => {
+                let arch = match arch {
+                    Arch::Aarch64 => "arm64",
+                    _ => "x86_64",
+                };
+                format!("macosx-{major}.{minor}-{arch}")
+            }
+            Os::Windows => match arch {
+                Arch::X86 => "win32".to_string(),
+                Arch::Aarch64 => "win-arm64".to_string(),
+                _ => "win-amd64".to_string(),
+            },
+            Os::Pyodide { major, minor } => format!("pyodide_{major}_{minor}-{arch}"),
+            os => format!("{os}-{arch}"),
+        }
+    }
+
     /// Return a [`MarkerEnvironment`] compatible with the given [`TargetTriple`], based on
     /// a base [`MarkerEnvironment`].
     ///
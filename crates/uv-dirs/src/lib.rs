@@ -37,6 +37,30 @@ pub fn user_executable_directory(override_variable: Option<&'static str>) -> Opt
         })
 }
 
+/// Returns an appropriate user-level directory for storing data files, e.g., man pages and shell
+/// completions.
+///
+/// This follows, in order:
+///
+/// - `$OVERRIDE_VARIABLE` (if provided)
+/// - `$XDG_DATA_HOME`
+/// - `$HOME/.local/share`
+///
+/// On all platforms.
+///
+/// Returns `None` if a directory cannot be found, i.e., if `$HOME` cannot be resolved. Does not
+/// check if the directory exists.
+pub fn user_data_directory(override_variable: Option<&'static str>) -> Option<PathBuf> {
+    override_variable
+        .and_then(std::env::var_os)
+        .and_then(parse_path)
+        .or_else(|| std::env::var_os(EnvVars::XDG_DATA_HOME).and_then(parse_path))
+        .or_else(|| {
+            let home_dir = etcetera::home_dir().ok();
+            home_dir.map(|path| path.join(".local").join("share"))
+        })
+}
+
 /// Returns an appropriate user-level directory for storing the cache.
 ///
 /// Corresponds to `$XDG_CACHE_HOME/uv` on Unix.
@@ -2,8 +2,9 @@
 //! [installer][`uv_installer`] and [build][`uv_build`] through [`BuildDispatch`]
 //! implementing [`BuildContext`].
 
+use std::env;
 use std::ffi::{OsStr, OsString};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use futures::FutureExt;
@@ -12,7 +13,7 @@ use rustc_hash::FxHashMap;
 use thiserror::Error;
 use tracing::{debug, instrument, trace};
 
-use uv_build_backend::check_direct_build;
+use uv_build_backend::{check_direct_build, project_name_and_version};
 use uv_build_frontend::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
@@ -24,8 +25,9 @@ use uv_configuration::{BuildOutput, Concurrency};
 use uv_distribution::DistributionDatabase;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    CachedDist, DependencyMetadata, Identifier, IndexCapabilities, IndexLocations,
-    IsBuildBackendError, Name, Requirement, Resolution, SourceDist, VersionOrUrlRef,
+    BuildSystemOverrides, CachedDist, DependencyMetadata, Identifier, IndexCapabilities,
+    IndexLocations, IsBuildBackendError, Name, Requirement, Resolution, SourceDist,
+    VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
@@ -35,6 +37,7 @@ use uv_resolver::{
     ExcludeNewer, FlatIndex, Flexibility, InMemoryIndex, Manifest, OptionsBuilder,
     PythonRequirement, Resolver, ResolverEnvironment,
 };
+use uv_static::EnvVars;
 use uv_types::{
     AnyErrorBuild, BuildArena, BuildContext, BuildIsolation, BuildStack, EmptyInstalledPackages,
     HashStrategy, InFlight,
@@ -87,6 +90,7 @@ pub struct BuildDispatch<'a> {
     flat_index: &'a FlatIndex,
     shared_state: SharedState,
     dependency_metadata: &'a DependencyMetadata,
+    build_system_overrides: &'a BuildSystemOverrides,
     build_isolation: BuildIsolation<'a>,
     link_mode: uv_install_wheel::LinkMode,
     build_options: &'a BuildOptions,
@@ -96,6 +100,7 @@ pub struct BuildDispatch<'a> {
     exclude_newer: Option<ExcludeNewer>,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    build_log_dir: Option<PathBuf>,
     sources: SourceStrategy,
     workspace_cache: WorkspaceCache,
     concurrency: Concurrency,
@@ -111,6 +116,7 @@ impl<'a> BuildDispatch<'a> {
         index_locations: &'a IndexLocations,
         flat_index: &'a FlatIndex,
         dependency_metadata: &'a DependencyMetadata,
+        build_system_overrides: &'a BuildSystemOverrides,
         shared_state: SharedState,
         index_strategy: IndexStrategy,
         config_settings: &'a ConfigSettings,
@@ -134,6 +140,7 @@ impl<'a> BuildDispatch<'a> {
             flat_index,
             shared_state,
             dependency_metadata,
+            build_system_overrides,
             index_strategy,
             config_settings,
             config_settings_package,
@@ -142,8 +149,9 @@ impl<'a> BuildDispatch<'a> {
             build_options,
             hasher,
             exclude_newer,
-            source_build_context: SourceBuildContext::default(),
+            source_build_context: SourceBuildContext::new(concurrency.builds),
             build_extra_env_vars: FxHashMap::default(),
+            build_log_dir: env::var_os(EnvVars::UV_BUILD_LOG_DIR).map(PathBuf::from),
             sources,
             workspace_cache,
             concurrency,
@@ -165,6 +173,15 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the directory to write the build backend output of each source build to.
+    ///
+    /// Defaults to `UV_BUILD_LOG_DIR`, if set. The directory is created if it doesn't exist.
+    #[must_use]
+    pub fn with_build_log_dir(mut self, build_log_dir: Option<PathBuf>) -> Self {
+        self.build_log_dir = build_log_dir;
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -195,6 +212,10 @@ impl BuildContext for BuildDispatch<'_> {
         self.dependency_metadata
     }
 
+    fn build_system_overrides(&self) -> &BuildSystemOverrides {
+        self.build_system_overrides
+    }
+
     fn build_options(&self) -> &BuildOptions {
         self.build_options
     }
@@ -456,7 +477,7 @@ impl BuildContext for BuildDispatch<'_> {
             build_kind,
             self.build_extra_env_vars.clone(),
             build_output,
-            self.concurrency.builds,
+            self.build_log_dir.as_deref(),
             self.preview,
         )
         .boxed_local()
@@ -486,6 +507,18 @@ impl BuildContext for BuildDispatch<'_> {
             return Ok(None);
         }
 
+        // The build system override is applied when setting up the PEP 517 build.
+        if let Some((name, version)) = project_name_and_version(&source_tree) {
+            if self
+                .build_system_overrides
+                .get(&name, Some(&version))
+                .is_some()
+            {
+                debug!("Not using direct build for {identifier}, the build system is overridden");
+                return Ok(None);
+            }
+        }
+
         debug!("Performing direct build for {identifier}");
 
         let output_dir = output_dir.to_path_buf();
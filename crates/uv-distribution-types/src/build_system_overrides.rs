@@ -0,0 +1,172 @@
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use uv_cache_key::{CacheKey, CacheKeyHasher};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::Requirement;
+use uv_pypi_types::VerbatimParsedUrl;
+
+/// Pre-defined [`BuildSystemOverride`] entries, indexed by [`PackageName`].
+#[derive(Debug, Clone, Default)]
+pub struct BuildSystemOverrides(FxHashMap<PackageName, Vec<BuildSystemOverride>>);
+
+impl BuildSystemOverrides {
+    /// Index a set of [`BuildSystemOverride`] entries by [`PackageName`].
+    pub fn from_entries(entries: impl IntoIterator<Item = BuildSystemOverride>) -> Self {
+        let mut map = Self::default();
+        for entry in entries {
+            map.0.entry(entry.name.clone()).or_default().push(entry);
+        }
+        map
+    }
+
+    /// Retrieve the [`BuildSystemOverride`] for a package.
+    ///
+    /// An entry for the exact version takes precedence over an entry without a version.
+    pub fn get(
+        &self,
+        package: &PackageName,
+        version: Option<&Version>,
+    ) -> Option<&BuildSystemOverride> {
+        let entries = self.0.get(package)?;
+
+        if let Some(version) = version {
+            if let Some(entry) = entries
+                .iter()
+                .find(|entry| entry.version.as_ref() == Some(version))
+            {
+                debug!("Found build system override for `{package}=={version}`");
+                return Some(entry);
+            }
+        }
+
+        let entry = entries.iter().find(|entry| entry.version.is_none())?;
+        debug!("Found global build system override for `{package}`");
+        Some(entry)
+    }
+
+    /// Retrieve the [`BuildSystemOverride`] entries that may apply to a package, to scope the
+    /// cache of the wheels built from it.
+    ///
+    /// If the version isn't known ahead of the build (e.g., for a path or Git source), any entry for
+    /// the package may apply.
+    pub fn candidates(
+        &self,
+        package: &PackageName,
+        version: Option<&Version>,
+    ) -> Vec<&BuildSystemOverride> {
+        let Some(entries) = self.0.get(package) else {
+            return Vec::new();
+        };
+        if let Some(version) = version {
+            entries
+                .iter()
+                .find(|entry| entry.version.as_ref() == Some(version))
+                .or_else(|| entries.iter().find(|entry| entry.version.is_none()))
+                .into_iter()
+                .collect()
+        } else {
+            entries.iter().collect()
+        }
+    }
+}
+
+/// A replacement for the `[build-system]` table of a package, used when building it from source.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct BuildSystemOverride {
+    pub name: PackageName,
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<String>",
+            description = "PEP 440-style package version, e.g., `1.2.3`"
+        )
+    )]
+    pub version: Option<Version>,
+    /// The requirements of the build environment, replacing `build-system.requires`.
+    pub requires: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    /// The build backend, replacing `build-system.build-backend`.
+    pub build_backend: Option<String>,
+}
+
+impl CacheKey for BuildSystemOverride {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        self.name.as_str().cache_key(state);
+        self.version
+            .as_ref()
+            .map(ToString::to_string)
+            .cache_key(state);
+        self.requires
+            .as_ref()
+            .map(|requires| requires.iter().map(ToString::to_string).collect::<Vec<_>>())
+            .cache_key(state);
+        self.build_backend.cache_key(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_cache_key::cache_digest;
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::{BuildSystemOverride, BuildSystemOverrides};
+
+    fn entry(version: Option<&str>, build_backend: &str) -> BuildSystemOverride {
+        BuildSystemOverride {
+            name: PackageName::from_str("foo").unwrap(),
+            version: version.map(|version| Version::from_str(version).unwrap()),
+            requires: None,
+            build_backend: Some(build_backend.to_string()),
+        }
+    }
+
+    #[test]
+    fn candidates() {
+        let overrides = BuildSystemOverrides::from_entries([
+            entry(None, "hatchling.build"),
+            entry(Some("1.0.0"), "setuptools.build_meta"),
+        ]);
+        let foo = PackageName::from_str("foo").unwrap();
+        let bar = PackageName::from_str("bar").unwrap();
+
+        // An exact version match takes precedence over the global entry.
+        let version = Version::from_str("1.0.0").unwrap();
+        assert_eq!(
+            overrides.candidates(&foo, Some(&version)),
+            vec![&entry(Some("1.0.0"), "setuptools.build_meta")]
+        );
+
+        // Otherwise, the global entry applies.
+        let version = Version::from_str("2.0.0").unwrap();
+        assert_eq!(
+            overrides.candidates(&foo, Some(&version)),
+            vec![&entry(None, "hatchling.build")]
+        );
+
+        // Without a version, any entry may apply.
+        assert_eq!(overrides.candidates(&foo, None).len(), 2);
+        assert!(overrides.candidates(&bar, None).is_empty());
+    }
+
+    #[test]
+    fn cache_key() {
+        assert_eq!(
+            cache_digest(&entry(None, "hatchling.build")),
+            cache_digest(&entry(None, "hatchling.build"))
+        );
+        assert_ne!(
+            cache_digest(&entry(None, "hatchling.build")),
+            cache_digest(&entry(None, "setuptools.build_meta"))
+        );
+        assert_ne!(
+            cache_digest(&entry(None, "hatchling.build")),
+            cache_digest(&entry(Some("1.0.0"), "hatchling.build"))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HashPolicy<'a> {
@@ -99,3 +99,9 @@ pub trait Hashed {
         }
     }
 }
+
+impl Hashed for HashDigests {
+    fn hashes(&self) -> &[HashDigest] {
+        self.as_slice()
+    }
+}
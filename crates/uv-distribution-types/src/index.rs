@@ -1,10 +1,11 @@
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use uv_auth::{AuthPolicy, Credentials};
+use uv_auth::{AuthPolicy, CloudAuth, CredentialHelper, CredentialSource, Credentials};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
@@ -80,6 +81,10 @@ pub struct Index {
     /// Indexes can either be PEP 503-compliant (i.e., a PyPI-style registry implementing the Simple
     /// API) or structured as a flat list of distributions (e.g., `--find-links`). In both cases,
     /// indexes can point to either local or remote resources.
+    ///
+    /// Indexes can also be backed by an OCI registry (`format = "oci"`), in which case the URL
+    /// points to the namespace containing a repository for each package (e.g.,
+    /// `https://ghcr.io/acme/python`).
     #[serde(default)]
     pub format: IndexFormat,
     /// The URL of the upload endpoint.
@@ -129,6 +134,159 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub cache_control: Option<IndexCacheControl>,
+    /// The number of times to retry requests to this index that fail with a transient error.
+    ///
+    /// Overrides the global retry count (`http-retries` or `UV_HTTP_RETRIES`) for requests to the
+    /// index, e.g., to retry more aggressively against an unreliable mirror.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// retries = 10
+    /// ```
+    #[serde(default)]
+    pub retries: Option<u32>,
+    /// The maximum number of concurrent requests to this index.
+    ///
+    /// Requests to the index (including downloads) wait for an earlier request to complete once
+    /// the limit is reached, e.g., to avoid being throttled by a private registry. The limit
+    /// applies in addition to the global `concurrent-downloads` limit.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// concurrent-requests = 4
+    /// ```
+    #[serde(default)]
+    pub concurrent_requests: Option<NonZeroUsize>,
+    /// The path to a client certificate to present to the index, for mutual TLS.
+    ///
+    /// The certificate may be PEM-encoded, in which case the file may also contain the
+    /// certificate's private key; otherwise, the key must be provided via `client-key`.
+    /// Alternatively, the certificate may be a PKCS#12 bundle (`.p12` or `.pfx`) containing both
+    /// the certificate and the key, decrypted with the password in `UV_CLIENT_CERT_PASSWORD`.
+    ///
+    /// For requests to the index, the certificate takes precedence over `SSL_CLIENT_CERT`. If the
+    /// certificate or key can't be read, requests to the index fail. Relative paths are resolved
+    /// relative to the configuration file.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// client-cert = "certs/client.pem"
+    /// client-key = "certs/client.key"
+    /// ```
+    #[serde(default)]
+    pub client_cert: Option<PathBuf>,
+    /// The path to the PEM-encoded private key for the `client-cert`, if stored separately.
+    #[serde(default)]
+    pub client_key: Option<PathBuf>,
+    /// Mirrors of the index, to fail over to if the index is unavailable.
+    ///
+    /// Mirrors are expected to serve the same packages as the index. If a request to the index
+    /// fails with a connection error or a server error (after any retries), the request is retried
+    /// against the next mirror, which is then preferred for the remainder of the invocation.
+    ///
+    /// Distributions hosted under the root of the index (i.e., the index URL without its final
+    /// path segment) are downloaded from the same path on the mirror.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://primary.example.com/simple"
+    /// mirrors = ["https://secondary.example.com/simple"]
+    /// ```
+    #[serde(default)]
+    pub mirrors: Vec<IndexUrl>,
+    /// How to choose between the index and its `mirrors`.
+    ///
+    /// By default, the index is tried first, followed by each mirror in order. With `latency`, uv
+    /// checks the health of the index and each mirror on first use, and prefers the fastest to
+    /// respond.
+    #[serde(default)]
+    pub mirror_selection: MirrorSelection,
+    /// A command to run to fetch credentials for the index, e.g., to mint a short-lived token.
+    ///
+    /// The command is invoked with a `get` argument and the index URL on stdin, following the
+    /// protocol used by Docker credential helpers. It should write either a bare token (sent as a
+    /// bearer token) or a JSON object with `Username` and `Secret` keys to stdout. By default,
+    /// credentials from the helper take precedence over those from netrc and the keyring.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// credential-helper = ["my-credential-helper", "--profile", "dev"]
+    /// ```
+    #[serde(default)]
+    pub credential_helper: Option<CredentialHelper>,
+    /// The path to a PEM-encoded bundle of CA certificates to trust for the index.
+    ///
+    /// The certificates are trusted in addition to the default trust roots (or the system's trust
+    /// store, with `native-tls`), and only for requests to the index's host, e.g., for an internal
+    /// mirror signed by a private CA. Relative paths are resolved relative to the configuration
+    /// file.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// ca-cert = "certs/internal-ca.pem"
+    /// ```
+    #[serde(default)]
+    pub ca_cert: Option<PathBuf>,
+    /// Whether to read wheel metadata from the index via HTTP range requests.
+    ///
+    /// When an index doesn't serve wheel metadata separately (per PEP 658), uv reads the metadata
+    /// by requesting only the relevant parts of each wheel. Set to `false` to download wheels in
+    /// full instead, e.g., for indexes behind a proxy that mishandles range requests. Defaults to
+    /// `true`.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// range-requests = false
+    /// ```
+    #[serde(default)]
+    pub range_requests: Option<bool>,
+    /// The sources to consult for credentials for the index, in order.
+    ///
+    /// Supported sources are `env` (the `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD`
+    /// environment variables), `helper` (the `credential-helper`), `netrc`, `keyring`, and `cache`
+    /// (credentials used for earlier requests to the index in the same invocation). Sources listed
+    /// before `cache` take precedence over cached credentials, while those listed after it are only
+    /// consulted if the cache has no credentials for the index. Sources that are omitted are never
+    /// consulted. Credentials embedded in the index URL are always used.
+    ///
+    /// Defaults to `["env", "cache", "helper", "netrc", "keyring"]`.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// credential-sources = ["keyring", "netrc"]
+    /// ```
+    #[serde(default)]
+    pub credential_sources: Option<Vec<CredentialSource>>,
+    /// Fetch short-lived tokens for the index from a cloud registry, via the vendor's CLI.
+    ///
+    /// Set to `aws` for an AWS CodeArtifact repository (using `aws codeartifact
+    /// get-authorization-token`), or `azure` for an Azure Artifacts feed (using `az account
+    /// get-access-token`). A token is only fetched if the index rejects a request as unauthorized
+    /// and no other credential source provides credentials for it.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://acme-123456789012.d.codeartifact.us-west-2.amazonaws.com/pypi/shared/simple/"
+    /// cloud-auth = "aws"
+    /// ```
+    #[serde(default)]
+    pub cloud_auth: Option<CloudAuth>,
 }
 
 #[derive(
@@ -152,6 +310,33 @@ pub enum IndexFormat {
     Simple,
     /// A `--find-links`-style index containing a flat list of wheels and source distributions.
     Flat,
+    /// An OCI registry, in which each package is a repository of artifacts (e.g., as pushed by
+    /// ORAS), with one layer per distribution.
+    Oci,
+}
+
+/// How to choose between an index and its mirrors.
+#[derive(
+    Default,
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum MirrorSelection {
+    /// Try the index first, followed by each mirror in the order in which they're defined.
+    #[default]
+    Ordered,
+    /// Prefer whichever of the index and its mirrors responds fastest to an initial health check.
+    Latency,
 }
 
 impl Index {
@@ -168,6 +353,17 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            retries: None,
+            concurrent_requests: None,
+            client_cert: None,
+            client_key: None,
+            mirrors: Vec::new(),
+            mirror_selection: MirrorSelection::default(),
+            credential_helper: None,
+            ca_cert: None,
+            range_requests: None,
+            credential_sources: None,
+            cloud_auth: None,
         }
     }
 
@@ -184,6 +380,17 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            retries: None,
+            concurrent_requests: None,
+            client_cert: None,
+            client_key: None,
+            mirrors: Vec::new(),
+            mirror_selection: MirrorSelection::default(),
+            credential_helper: None,
+            ca_cert: None,
+            range_requests: None,
+            credential_sources: None,
+            cloud_auth: None,
         }
     }
 
@@ -200,6 +407,17 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            retries: None,
+            concurrent_requests: None,
+            client_cert: None,
+            client_key: None,
+            mirrors: Vec::new(),
+            mirror_selection: MirrorSelection::default(),
+            credential_helper: None,
+            ca_cert: None,
+            range_requests: None,
+            credential_sources: None,
+            cloud_auth: None,
         }
     }
 
@@ -234,9 +452,15 @@ impl Index {
     }
 
     /// Retrieve the credentials for the index, either from the environment, or from the URL itself.
+    ///
+    /// The environment is skipped if the index's `credential-sources` omit it.
     pub fn credentials(&self) -> Option<Credentials> {
         // If the index is named, and credentials are provided via the environment, prefer those.
-        if let Some(name) = self.name.as_ref() {
+        if let Some(name) = self.name.as_ref().filter(|_| {
+            self.credential_sources
+                .as_ref()
+                .is_none_or(|sources| sources.contains(&CredentialSource::Env))
+        }) {
             if let Some(credentials) = Credentials::from_env(name.to_env_var()) {
                 return Some(credentials);
             }
@@ -253,6 +477,16 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        for mirror in &mut self.mirrors {
+            if let IndexUrl::Path(url) = mirror {
+                if let Some(given) = url.given() {
+                    *mirror = IndexUrl::parse(given, Some(root_dir))?;
+                }
+            }
+        }
+        self.client_cert = self.client_cert.map(|path| root_dir.join(path));
+        self.client_key = self.client_key.map(|path| root_dir.join(path));
+        self.ca_cert = self.ca_cert.map(|path| root_dir.join(path));
         Ok(self)
     }
 
@@ -279,6 +513,17 @@ impl From<IndexUrl> for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            retries: None,
+            concurrent_requests: None,
+            client_cert: None,
+            client_key: None,
+            mirrors: Vec::new(),
+            mirror_selection: MirrorSelection::default(),
+            credential_helper: None,
+            ca_cert: None,
+            range_requests: None,
+            credential_sources: None,
+            cloud_auth: None,
         }
     }
 }
@@ -303,6 +548,17 @@ impl FromStr for Index {
                     authenticate: AuthPolicy::default(),
                     ignore_error_codes: None,
                     cache_control: None,
+                    retries: None,
+                    concurrent_requests: None,
+                    client_cert: None,
+                    client_key: None,
+                    mirrors: Vec::new(),
+                    mirror_selection: MirrorSelection::default(),
+                    credential_helper: None,
+                    ca_cert: None,
+                    range_requests: None,
+                    credential_sources: None,
+                    cloud_auth: None,
                 });
             }
         }
@@ -320,6 +576,17 @@ impl FromStr for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            retries: None,
+            concurrent_requests: None,
+            client_cert: None,
+            client_key: None,
+            mirrors: Vec::new(),
+            mirror_selection: MirrorSelection::default(),
+            credential_helper: None,
+            ca_cert: None,
+            range_requests: None,
+            credential_sources: None,
+            cloud_auth: None,
         })
     }
 }
@@ -14,7 +14,7 @@ use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
-use crate::{Index, IndexStatusCodeStrategy, Verbatim};
+use crate::{Index, IndexName, IndexStatusCodeStrategy, MirrorSelection, Verbatim};
 
 static PYPI_URL: LazyLock<DisplaySafeUrl> =
     LazyLock::new(|| DisplaySafeUrl::parse("https://pypi.org/simple").unwrap());
@@ -39,7 +39,12 @@ impl IndexUrl {
     /// If no root directory is provided, relative paths are resolved against the current working
     /// directory.
     pub fn parse(path: &str, root_dir: Option<&Path>) -> Result<Self, IndexUrlError> {
-        let url = VerbatimUrl::from_url_or_path(path, root_dir)?;
+        // Ex) `s3://bucket/prefix`, which isn't otherwise recognized as a URL scheme.
+        let url = if path.starts_with("s3://") {
+            VerbatimUrl::parse_url(path)?.with_given(path)
+        } else {
+            VerbatimUrl::from_url_or_path(path, root_dir)?
+        };
         Ok(Self::from(url))
     }
 
@@ -500,6 +505,10 @@ impl From<&IndexLocations> for uv_auth::Indexes {
                 url,
                 root_url,
                 auth_policy: index.authenticate,
+                credential_helper: index.credential_helper.clone(),
+                env_name: index.name.as_ref().map(IndexName::to_env_var),
+                credential_sources: index.credential_sources.clone(),
+                cloud_auth: index.cloud_auth,
             }
         }))
     }
@@ -638,6 +647,30 @@ impl<'a> IndexUrls {
         }
         None
     }
+
+    /// Return the mirrors for an [`IndexUrl`] and the strategy for selecting among them, if
+    /// configured.
+    pub fn mirrors_for(&self, url: &IndexUrl) -> Option<(&[IndexUrl], MirrorSelection)> {
+        for index in &self.indexes {
+            if index.url() == url {
+                if index.mirrors.is_empty() {
+                    return None;
+                }
+                return Some((&index.mirrors, index.mirror_selection));
+            }
+        }
+        None
+    }
+
+    /// Returns `false` if range requests are disabled for an [`IndexUrl`].
+    pub fn range_requests_for(&self, url: &IndexUrl) -> bool {
+        for index in &self.indexes {
+            if index.url() == url {
+                return index.range_requests.unwrap_or(true);
+            }
+        }
+        true
+    }
 }
 
 bitflags::bitflags! {
@@ -774,6 +807,17 @@ mod tests {
                     api: Some(SmallString::from("max-age=300")),
                     files: Some(SmallString::from("max-age=1800")),
                 }),
+                retries: None,
+                concurrent_requests: None,
+                client_cert: None,
+                client_key: None,
+                mirrors: Vec::new(),
+                mirror_selection: crate::MirrorSelection::default(),
+                credential_helper: None,
+                ca_cert: None,
+                range_requests: None,
+                credential_sources: None,
+                cloud_auth: None,
                 explicit: false,
                 default: false,
                 origin: None,
@@ -786,6 +830,17 @@ mod tests {
                 name: Some(IndexName::from_str("index2").unwrap()),
                 url: IndexUrl::from_str("https://index2.example.com/simple").unwrap(),
                 cache_control: None,
+                retries: None,
+                concurrent_requests: None,
+                client_cert: None,
+                client_key: None,
+                mirrors: Vec::new(),
+                mirror_selection: crate::MirrorSelection::default(),
+                credential_helper: None,
+                ca_cert: None,
+                range_requests: None,
+                credential_sources: None,
+                cloud_auth: None,
                 explicit: false,
                 default: false,
                 origin: None,
@@ -54,6 +54,7 @@ use uv_redacted::DisplaySafeUrl;
 
 pub use crate::annotation::*;
 pub use crate::any::*;
+pub use crate::build_system_overrides::*;
 pub use crate::buildable::*;
 pub use crate::cached::*;
 pub use crate::dependency_metadata::*;
@@ -82,6 +83,7 @@ pub use crate::traits::*;
 
 mod annotation;
 mod any;
+mod build_system_overrides;
 mod buildable;
 mod cached;
 mod dependency_metadata;
@@ -83,6 +83,29 @@ impl RequiresPython {
         Some(Self { specifiers, range })
     }
 
+    /// Returns a [`RequiresPython`] that spans all of the given version specifiers, i.e., from the
+    /// lowest lower bound to the highest upper bound.
+    ///
+    /// For example, given `>=3.8` and `>=3.10,<3.13`, this would return `>=3.8`.
+    pub fn union<'a>(specifiers: impl Iterator<Item = &'a VersionSpecifiers>) -> Option<Self> {
+        // Convert to PubGrub range and perform a union.
+        let range = specifiers
+            .map(|specs| release_specifiers_to_ranges(specs.clone()))
+            .reduce(|acc, r| acc.union(&r))?;
+
+        // Fill in any gaps between the ranges, such that the result is contiguous.
+        let (lower, upper) = range.bounding_range()?;
+        let range = Ranges::from_range_bounds((lower.cloned(), upper.cloned()));
+
+        // Convert back to PEP 440 specifiers.
+        let specifiers = VersionSpecifiers::from_release_only_bounds(range.iter());
+
+        // Extract the bounds.
+        let range = RequiresPythonRange::from_range(&range);
+
+        Some(Self { specifiers, range })
+    }
+
     /// Split the [`RequiresPython`] at the given version.
     ///
     /// For example, if the current requirement is `>=3.10`, and the split point is `3.11`, then
@@ -312,6 +335,7 @@ impl RequiresPython {
                 Some(AbiTag::CPython {
                     gil_disabled: false,
                     python_version: (major, minor),
+                    debug: false,
                 })
             }
             Bound::Unbounded => None,
@@ -774,6 +798,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn union() {
+        // The union of `>=3.8` and `>=3.10,<3.13` is `>=3.8`.
+        let specifiers = [
+            VersionSpecifiers::from_str(">=3.8").unwrap(),
+            VersionSpecifiers::from_str(">=3.10, <3.13").unwrap(),
+        ];
+        assert_eq!(
+            RequiresPython::union(specifiers.iter()).unwrap(),
+            RequiresPython::from_specifiers(&VersionSpecifiers::from_str(">=3.8").unwrap())
+        );
+
+        // Gaps between disjoint specifiers are filled, e.g., `>=3.8,<3.10` and `>=3.12` span
+        // `>=3.8`.
+        let specifiers = [
+            VersionSpecifiers::from_str(">=3.8, <3.10").unwrap(),
+            VersionSpecifiers::from_str(">=3.12").unwrap(),
+        ];
+        assert_eq!(
+            RequiresPython::union(specifiers.iter()).unwrap(),
+            RequiresPython::from_specifiers(&VersionSpecifiers::from_str(">=3.8").unwrap())
+        );
+
+        // The union of no specifiers is `None`.
+        assert!(RequiresPython::union(std::iter::empty()).is_none());
+    }
+
     #[test]
     fn split_version() {
         // Splitting `>=3.10` on `>3.12` should result in `>=3.10, <=3.12` and `>3.12`.
@@ -1,39 +1,47 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
 use tempfile::TempDir;
-use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
+use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWriteExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
-    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RateLimited,
+    RegistryClient, ResumableStream,
 };
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuildableSource, BuiltDist, Dist, HashPolicy, Hashed, IndexUrl, InstalledDist, Name, SourceDist,
 };
-use uv_extract::hash::Hasher;
+use uv_extract::hash::{Hasher, SizeMismatch};
 use uv_fs::write_atomic;
+use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
 use uv_pypi_types::{HashDigest, HashDigests};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
+use uv_warnings::warn_user_once;
 
 use crate::archive::Archive;
 use crate::metadata::{ArchiveMetadata, Metadata};
+use crate::partial::PartialDownload;
 use crate::source::SourceDistributionBuilder;
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
+/// The size (in bytes) above which wheels are downloaded to disk rather than streamed, such that
+/// interrupted downloads can be resumed by a later attempt.
+const RESUMABLE_DOWNLOAD_SIZE: u64 = 100 * 1024 * 1024;
+
 /// A cached high-level interface to convert distributions (a requirement resolved to a location)
 /// to a wheel or wheel metadata.
 ///
@@ -180,6 +188,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 let wheel = wheels.best_wheel();
                 let url = wheel.file.url.to_url()?;
 
+                if url.scheme() != "file" {
+                    self.build_context
+                        .cache()
+                        .stats()
+                        .record_wheel_request(wheel.file.size);
+                }
+
                 // Create a cache entry for the wheel.
                 let wheel_entry = self.build_context.cache().entry(
                     CacheBucket::Wheels,
@@ -197,72 +212,27 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
-                // Download and unzip.
-                match self
-                    .stream_wheel(
-                        url.clone(),
-                        dist.index(),
-                        &wheel.filename,
-                        wheel.file.size,
-                        &wheel_entry,
-                        dist,
-                        hashes,
+                // Download and unzip, failing over to the index's mirrors if necessary.
+                self.client
+                    .unmanaged
+                    .with_artifact_failover(
+                        Some(&wheel.index),
+                        &url,
+                        Error::is_unavailable,
+                        async |url| {
+                            self.download_registry_wheel(url, dist, &wheel_entry, hashes)
+                                .await
+                        },
                     )
                     .await
-                {
-                    Ok(archive) => Ok(LocalWheel {
-                        dist: Dist::Built(dist.clone()),
-                        archive: self
-                            .build_context
-                            .cache()
-                            .archive(&archive.id)
-                            .into_boxed_path(),
-                        hashes: archive.hashes,
-                        filename: wheel.filename.clone(),
-                        cache: CacheInfo::default(),
-                    }),
-                    Err(Error::Extract(name, err)) => {
-                        if err.is_http_streaming_unsupported() {
-                            warn!(
-                                "Streaming unsupported for {dist}; downloading wheel to disk ({err})"
-                            );
-                        } else if err.is_http_streaming_failed() {
-                            warn!("Streaming failed for {dist}; downloading wheel to disk ({err})");
-                        } else {
-                            return Err(Error::Extract(name, err));
-                        }
-
-                        // If the request failed because streaming is unsupported, download the
-                        // wheel directly.
-                        let archive = self
-                            .download_wheel(
-                                url,
-                                dist.index(),
-                                &wheel.filename,
-                                wheel.file.size,
-                                &wheel_entry,
-                                dist,
-                                hashes,
-                            )
-                            .await?;
-
-                        Ok(LocalWheel {
-                            dist: Dist::Built(dist.clone()),
-                            archive: self
-                                .build_context
-                                .cache()
-                                .archive(&archive.id)
-                                .into_boxed_path(),
-                            hashes: archive.hashes,
-                            filename: wheel.filename.clone(),
-                            cache: CacheInfo::default(),
-                        })
-                    }
-                    Err(err) => Err(err),
-                }
             }
 
             BuiltDist::DirectUrl(wheel) => {
+                self.build_context
+                    .cache()
+                    .stats()
+                    .record_wheel_request(None);
+
                 // Create a cache entry for the wheel.
                 let wheel_entry = self.build_context.cache().entry(
                     CacheBucket::Wheels,
@@ -358,6 +328,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<LocalWheel, Error> {
+        self.build_context.cache().stats().record_build_request();
+
         let built_wheel = self
             .builder
             .download_and_build(&BuildableSource::Dist(dist), tags, hashes, &self.client)
@@ -407,6 +379,316 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         })
     }
 
+    /// Build a wheel for a source distribution, returning the filename of the wheel and the path
+    /// to the built `.whl` file in the cache.
+    ///
+    /// Unlike [`DistributionDatabase::get_or_build_wheel`], the wheel is not unzipped, as the
+    /// caller needs the archive itself (e.g., to copy it into a wheelhouse).
+    #[instrument(skip_all, fields(%dist))]
+    pub async fn build_wheel_archive(
+        &self,
+        dist: &SourceDist,
+        tags: &Tags,
+        hashes: HashPolicy<'_>,
+    ) -> Result<(WheelFilename, PathBuf), Error> {
+        self.build_context.cache().stats().record_build_request();
+
+        let built_wheel = self
+            .builder
+            .download_and_build(&BuildableSource::Dist(dist), tags, hashes, &self.client)
+            .boxed_local()
+            .await?;
+
+        Ok((built_wheel.filename, built_wheel.path.into_path_buf()))
+    }
+
+    /// Fetch a wheel or source distribution archive as-is, without unpacking or building it,
+    /// returning the path to the archive and its hash digests.
+    ///
+    /// Remote archives are downloaded into the cache, such that they're only fetched once; local
+    /// (`file://`) archives are read in place.
+    #[instrument(skip_all, fields(%filename))]
+    pub async fn download_archive(
+        &self,
+        url: DisplaySafeUrl,
+        index: Option<&IndexUrl>,
+        name: &PackageName,
+        filename: &str,
+        hashes: HashPolicy<'_>,
+    ) -> Result<(PathBuf, HashDigests), Error> {
+        let (path, digests) = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| Error::NonFileUrl(url.clone()))?;
+            let digests = hash_archive(&path, hashes).await?;
+            (path, digests)
+        } else {
+            // Create a cache entry for the archive.
+            let cache_entry = self.build_context.cache().entry(
+                CacheBucket::Downloads,
+                match index {
+                    Some(index) => WheelCache::Index(index),
+                    None => WheelCache::Url(&url),
+                }
+                .wheel_dir(name.as_ref()),
+                filename,
+            );
+
+            // Download the archive, failing over to the index's mirrors if necessary.
+            let digests = self
+                .client
+                .unmanaged
+                .with_artifact_failover(index, &url, Error::is_unavailable, async |url| {
+                    self.fetch_archive(url, index, name, filename, &cache_entry, hashes)
+                        .await
+                })
+                .await?;
+            (cache_entry.into_path_buf(), digests)
+        };
+
+        if hashes.is_validate() && !digests.satisfies(hashes) {
+            return Err(Error::hash_mismatch(
+                filename.to_string(),
+                hashes.digests(),
+                digests.as_slice(),
+            ));
+        }
+
+        Ok((path, digests))
+    }
+
+    /// Download an archive into the given cache entry, returning its hash digests.
+    async fn fetch_archive(
+        &self,
+        url: DisplaySafeUrl,
+        index: Option<&IndexUrl>,
+        name: &PackageName,
+        filename: &str,
+        cache_entry: &CacheEntry,
+        hashes: HashPolicy<'_>,
+    ) -> Result<HashDigests, Error> {
+        // Acquire an advisory lock, to guard against concurrent writes.
+        let _lock = cache_entry
+            .with_file(format!("{filename}.lock"))
+            .lock()
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        // Create an entry for the HTTP cache.
+        let http_entry = cache_entry.with_file(format!("{filename}.http"));
+
+        let download = |response: reqwest::Response| {
+            async {
+                // Resume the download if the connection is interrupted.
+                let stream = ResumableStream::new(
+                    self.client.unmanaged.cached_client().uncached(),
+                    response,
+                );
+                let reader = RateLimited::new(stream)
+                    .map_err(|err| self.handle_response_errors(err))
+                    .into_async_read();
+
+                // Download the archive to a temporary file, hashing it as it's downloaded.
+                let temp_file = tempfile::NamedTempFile::new_in(self.build_context.cache().root())
+                    .map_err(Error::CacheWrite)?;
+                let mut writer = tokio::io::BufWriter::new(
+                    fs_err::tokio::File::create(temp_file.path())
+                        .await
+                        .map_err(Error::CacheWrite)?,
+                );
+                let algorithms = hashes.algorithms();
+                let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+                tokio::io::copy(&mut hasher, &mut writer)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                writer.flush().await.map_err(Error::CacheWrite)?;
+
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+                if hashes.is_validate() && !digests.satisfies(hashes) {
+                    return Err(Error::hash_mismatch(
+                        filename.to_string(),
+                        hashes.digests(),
+                        digests.as_slice(),
+                    ));
+                }
+
+                // Persist the archive to the cache.
+                fs_err::tokio::create_dir_all(cache_entry.dir())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                uv_fs::persist_with_retry(temp_file, cache_entry.path())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                Ok(digests)
+            }
+            .instrument(info_span!("download", archive = %filename))
+        };
+
+        // Determine the cache control policy for the URL.
+        let cache_control = match self.client.unmanaged.connectivity() {
+            Connectivity::Online => CacheControl::from(
+                self.build_context
+                    .cache()
+                    .freshness(&http_entry, Some(name), None)
+                    .map_err(Error::CacheRead)?,
+            )
+            .with_override(index.and_then(|index| {
+                self.build_context
+                    .locations()
+                    .artifact_cache_control_for(index)
+            })),
+            Connectivity::Offline => CacheControl::AllowStale,
+        };
+
+        // Fetch the archive from the cache, or download it if necessary.
+        let req = self.request(url.clone())?;
+        let digests = self
+            .client
+            .managed(|client| {
+                client.cached_client().get_serde_with_retry(
+                    req,
+                    &http_entry,
+                    cache_control,
+                    download,
+                )
+            })
+            .await
+            .map_err(|err| match err {
+                CachedClientError::Callback { err, .. } => err,
+                CachedClientError::Client { err, .. } => Error::Client(err),
+            })?;
+
+        // If the archive is missing the required hashes, or has since been removed, force a refresh.
+        if digests.has_digests(hashes) && cache_entry.path().is_file() {
+            return Ok(digests);
+        }
+
+        self.client
+            .managed(async |client| {
+                client
+                    .cached_client()
+                    .skip_cache_with_retry(self.request(url)?, &http_entry, cache_control, download)
+                    .await
+                    .map_err(|err| match err {
+                        CachedClientError::Callback { err, .. } => err,
+                        CachedClientError::Client { err, .. } => Error::Client(err),
+                    })
+            })
+            .await
+    }
+
+    /// Download a wheel from a registry, streaming it into the cache if possible.
+    async fn download_registry_wheel(
+        &self,
+        url: DisplaySafeUrl,
+        dist: &BuiltDist,
+        wheel_entry: &CacheEntry,
+        hashes: HashPolicy<'_>,
+    ) -> Result<LocalWheel, Error> {
+        let BuiltDist::Registry(wheels) = dist else {
+            unreachable!("expected a registry distribution");
+        };
+        let wheel = wheels.best_wheel();
+
+        // Download large wheels to disk rather than streaming them, such that the download can be
+        // resumed by a later attempt if it's interrupted.
+        if wheel
+            .file
+            .size
+            .is_some_and(|size| size >= RESUMABLE_DOWNLOAD_SIZE)
+            || PartialDownload::exists(wheel_entry, &wheel.filename)
+        {
+            let archive = self
+                .download_wheel(
+                    url,
+                    dist.index(),
+                    &wheel.filename,
+                    wheel.file.size,
+                    wheel_entry,
+                    dist,
+                    hashes,
+                )
+                .await?;
+            return Ok(LocalWheel {
+                dist: Dist::Built(dist.clone()),
+                archive: self
+                    .build_context
+                    .cache()
+                    .archive(&archive.id)
+                    .into_boxed_path(),
+                hashes: archive.hashes,
+                filename: wheel.filename.clone(),
+                cache: CacheInfo::default(),
+            });
+        }
+
+        match self
+            .stream_wheel(
+                url.clone(),
+                dist.index(),
+                &wheel.filename,
+                wheel.file.size,
+                wheel_entry,
+                dist,
+                hashes,
+            )
+            .await
+        {
+            Ok(archive) => Ok(LocalWheel {
+                dist: Dist::Built(dist.clone()),
+                archive: self
+                    .build_context
+                    .cache()
+                    .archive(&archive.id)
+                    .into_boxed_path(),
+                hashes: archive.hashes,
+                filename: wheel.filename.clone(),
+                cache: CacheInfo::default(),
+            }),
+            Err(Error::Extract(name, err)) => {
+                if err.is_http_streaming_unsupported() {
+                    warn!("Streaming unsupported for {dist}; downloading wheel to disk ({err})");
+                } else if err.is_http_streaming_failed() {
+                    warn!("Streaming failed for {dist}; downloading wheel to disk ({err})");
+                } else {
+                    return Err(Error::Extract(name, err));
+                }
+
+                // If the request failed because streaming is unsupported, download the
+                // wheel directly.
+                let archive = self
+                    .download_wheel(
+                        url,
+                        dist.index(),
+                        &wheel.filename,
+                        wheel.file.size,
+                        wheel_entry,
+                        dist,
+                        hashes,
+                    )
+                    .await?;
+
+                Ok(LocalWheel {
+                    dist: Dist::Built(dist.clone()),
+                    archive: self
+                        .build_context
+                        .cache()
+                        .archive(&archive.id)
+                        .into_boxed_path(),
+                    hashes: archive.hashes,
+                    filename: wheel.filename.clone(),
+                    cache: CacheInfo::default(),
+                })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Fetch the wheel metadata from the index, or from the cache if possible.
     ///
     /// While hashes will be generated in some cases, hash-checking is _not_ enforced and should
@@ -557,63 +839,97 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                let expected = size;
                 let size = size.or_else(|| content_length(&response));
 
+                // If the server reports a different size than the index, abort before downloading
+                // anything. If the hashes are being validated, they're authoritative, and the size
+                // is merely advisory.
+                if !hashes.is_validate() {
+                    if let Some(mismatch) = content_length_mismatch(expected, &response) {
+                        return Err(Error::SizeMismatch(dist.to_string(), mismatch));
+                    }
+                }
+
                 let progress = self
                     .reporter
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                // Resume the download if the connection is interrupted; the hashes are validated
+                // as the wheel is unzipped, as usual.
+                let stream = ResumableStream::new(
+                    self.client.unmanaged.cached_client().uncached(),
+                    response,
+                );
+                let reader = RateLimited::new(stream)
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
                 // Create a hasher for each hash algorithm.
                 let algorithms = hashes.algorithms();
                 let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers)
+                    .with_expected_size(expected.filter(|_| !hashes.is_validate()));
 
                 // Download and unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
 
-                match progress {
+                let result = match progress {
                     Some((reporter, progress)) => {
                         let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
-                        uv_extract::stream::unzip(&mut reader, temp_dir.path())
-                            .await
-                            .map_err(|err| Error::Extract(filename.to_string(), err))?;
-                    }
-                    None => {
-                        uv_extract::stream::unzip(&mut hasher, temp_dir.path())
-                            .await
-                            .map_err(|err| Error::Extract(filename.to_string(), err))?;
+                        uv_extract::stream::unzip(&mut reader, temp_dir.path()).await
                     }
+                    None => uv_extract::stream::unzip(&mut hasher, temp_dir.path()).await,
+                };
+                if let Some(mismatch) = hasher.size_mismatch() {
+                    return Err(Error::SizeMismatch(dist.to_string(), mismatch));
                 }
+                result.map_err(|err| Error::Extract(filename.to_string(), err))?;
 
                 // If necessary, exhaust the reader to compute the hash.
                 if !hashes.is_none() {
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
+                    let result = hasher.finish().await;
+                    if let Some(mismatch) = hasher.size_mismatch() {
+                        return Err(Error::SizeMismatch(dist.to_string(), mismatch));
+                    }
+                    result.map_err(Error::HashExhaustion)?;
+                }
+
+                // Validate the hashes before persisting the wheel to the cache.
+                let bytes_read = hasher.bytes_read();
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+                if hashes.is_validate() && !digests.satisfies(hashes) {
+                    return Err(Error::hash_mismatch(
+                        dist.to_string(),
+                        hashes.digests(),
+                        digests.as_slice(),
+                    ));
+                }
+                if hashes.is_validate() {
+                    warn_size_mismatch(dist, expected, bytes_read);
                 }
 
                 // Persist the temporary directory to the directory store.
                 let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
+                    .persist_wheel(temp_dir.keep(), wheel_entry.path())
                     .await
                     .map_err(Error::CacheRead)?;
 
+                self.build_context
+                    .cache()
+                    .stats()
+                    .record_wheel_download(expected, size);
+
                 if let Some((reporter, progress)) = progress {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                    filename.clone(),
-                ))
+                Ok(Archive::new(id, digests, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -623,22 +939,17 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         // Determine the cache control policy for the URL.
         let cache_control = match self.client.unmanaged.connectivity() {
-            Connectivity::Online => {
-                if let Some(header) = index.and_then(|index| {
-                    self.build_context
-                        .locations()
-                        .artifact_cache_control_for(index)
-                }) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
-                            .map_err(Error::CacheRead)?,
-                    )
-                }
-            }
+            Connectivity::Online => CacheControl::from(
+                self.build_context
+                    .cache()
+                    .freshness(&http_entry, Some(&filename.name), None)
+                    .map_err(Error::CacheRead)?,
+            )
+            .with_override(index.and_then(|index| {
+                self.build_context
+                    .locations()
+                    .artifact_cache_control_for(index)
+            })),
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -711,93 +1022,166 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                let expected = size;
                 let size = size.or_else(|| content_length(&response));
 
+                // If the server reports a different size than the index, abort before downloading
+                // anything. If the hashes are being validated, they're authoritative, and the size
+                // is merely advisory.
+                if !hashes.is_validate() {
+                    if let Some(mismatch) = content_length_mismatch(expected, &response) {
+                        return Err(Error::SizeMismatch(dist.to_string(), mismatch));
+                    }
+                }
+
                 let progress = self
                     .reporter
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                // Persist the download in the cache as it's received, such that it can be resumed
+                // by a later attempt if it's interrupted. If the wheel is being downloaded
+                // concurrently, download it to a temporary file instead.
+                let partial =
+                    PartialDownload::acquire(wheel_entry, filename).map_err(Error::CacheWrite)?;
+                let client = self.client.unmanaged.cached_client().uncached();
+                let validator = ResumableStream::validator(&response);
+
+                // Create a hasher for each hash algorithm, hashing the wheel as it's downloaded
+                // such that a mismatch is detected before unzipping.
+                let algorithms = hashes.algorithms();
+                let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+
+                // Resume a partial download left by a previous attempt, if it was drawn from the
+                // same file, hashing the bytes received so far.
+                let mut resumed = None;
+                if let Some((partial, validator)) = partial.as_ref().zip(validator.as_ref()) {
+                    if let Some(offset) = partial.offset(validator) {
+                        if let Some(stream) =
+                            ResumableStream::resume(client, &response, offset).await
+                        {
+                            debug!("Resuming download of {dist} at byte {offset}");
+                            let mut prefix = uv_extract::hash::HashReader::new(
+                                partial.read().await.map_err(Error::CacheWrite)?,
+                                &mut hashers,
+                            );
+                            tokio::io::copy(&mut prefix, &mut tokio::io::sink())
+                                .await
+                                .map_err(Error::CacheWrite)?;
+                            resumed = Some((offset, stream));
+                        }
+                    }
+                }
+
+                let (offset, stream, file) = match (resumed, &partial) {
+                    (Some((offset, stream)), Some(partial)) => (
+                        offset,
+                        stream,
+                        partial.append().await.map_err(Error::CacheWrite)?,
+                    ),
+                    (_, Some(partial)) => (
+                        0,
+                        ResumableStream::new(client, response),
+                        partial
+                            .create(validator.as_ref())
+                            .await
+                            .map_err(Error::CacheWrite)?,
+                    ),
+                    (_, None) => (
+                        0,
+                        ResumableStream::new(client, response),
+                        tokio::fs::File::from_std(
+                            tempfile::tempfile_in(self.build_context.cache().root())
+                                .map_err(Error::CacheWrite)?,
+                        ),
+                    ),
+                };
+                let reader = RateLimited::new(stream)
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
+                let mut writer = tokio::io::BufWriter::new(file);
+                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers)
+                    .with_offset(offset)
+                    .with_expected_size(expected.filter(|_| !hashes.is_validate()));
 
-                // Download the wheel to a temporary file.
-                let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut writer = tokio::io::BufWriter::new(tokio::fs::File::from_std(temp_file));
-
-                match progress {
+                let result = match progress {
                     Some((reporter, progress)) => {
+                        reporter.on_download_progress(progress, offset);
                         // Wrap the reader in a progress reporter. This will report 100% progress
-                        // after the download is complete, even if we still have to unzip and hash
-                        // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
-
-                        tokio::io::copy(&mut reader, &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
+                        // after the download is complete, even if we still have to unzip part of
+                        // the file.
+                        let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
+                        tokio::io::copy(&mut reader, &mut writer).await
                     }
-                    None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
+                    None => tokio::io::copy(&mut hasher, &mut writer).await,
+                };
+                if let Some(mismatch) = hasher.size_mismatch() {
+                    return Err(Error::SizeMismatch(dist.to_string(), mismatch));
+                }
+                if let Err(err) = result {
+                    // Retain the bytes received so far, such that a later attempt can resume the
+                    // download.
+                    if let Some(partial) = &partial {
+                        if writer.flush().await.is_ok() {
+                            partial.keep();
+                        }
                     }
+                    return Err(Error::CacheWrite(err));
+                }
+                writer.flush().await.map_err(Error::CacheWrite)?;
+
+                // Validate the hashes before unzipping the wheel.
+                let bytes_read = hasher.bytes_read();
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+                if hashes.is_validate() && !digests.satisfies(hashes) {
+                    return Err(Error::hash_mismatch(
+                        dist.to_string(),
+                        hashes.digests(),
+                        digests.as_slice(),
+                    ));
+                }
+                if hashes.is_validate() {
+                    warn_size_mismatch(dist, expected, bytes_read);
                 }
 
-                // Unzip the wheel to a temporary directory.
+                // Unzip the wheel to a temporary directory, parallelizing the unzip operation.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
                 let mut file = writer.into_inner();
                 file.seek(io::SeekFrom::Start(0))
                     .await
                     .map_err(Error::CacheWrite)?;
-
-                // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
-                    let file = file.into_std().await;
-                    tokio::task::spawn_blocking({
-                        let target = temp_dir.path().to_owned();
-                        move || -> Result<(), uv_extract::Error> {
-                            // Unzip the wheel into a temporary directory.
-                            uv_extract::unzip(file, &target)?;
-                            Ok(())
-                        }
-                    })
-                    .await?
-                    .map_err(|err| Error::Extract(filename.to_string(), err))?;
-
-                    HashDigests::empty()
-                } else {
-                    // Create a hasher for each hash algorithm.
-                    let algorithms = hashes.algorithms();
-                    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
-                    uv_extract::stream::unzip(&mut hasher, temp_dir.path())
-                        .await
-                        .map_err(|err| Error::Extract(filename.to_string(), err))?;
-
-                    // If necessary, exhaust the reader to compute the hash.
-                    hasher.finish().await.map_err(Error::HashExhaustion)?;
-
-                    hashers.into_iter().map(HashDigest::from).collect()
-                };
+                let file = file.into_std().await;
+                tokio::task::spawn_blocking({
+                    let target = temp_dir.path().to_owned();
+                    move || -> Result<(), uv_extract::Error> {
+                        // Unzip the wheel into a temporary directory.
+                        uv_extract::unzip(file, &target)?;
+                        Ok(())
+                    }
+                })
+                .await?
+                .map_err(|err| Error::Extract(filename.to_string(), err))?;
 
                 // Persist the temporary directory to the directory store.
                 let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
+                    .persist_wheel(temp_dir.keep(), wheel_entry.path())
                     .await
                     .map_err(Error::CacheRead)?;
 
+                self.build_context
+                    .cache()
+                    .stats()
+                    .record_wheel_download(expected, size);
+
                 if let Some((reporter, progress)) = progress {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(id, hashes, filename.clone()))
+                Ok(Archive::new(id, digests, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -807,22 +1191,17 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         // Determine the cache control policy for the URL.
         let cache_control = match self.client.unmanaged.connectivity() {
-            Connectivity::Online => {
-                if let Some(header) = index.and_then(|index| {
-                    self.build_context
-                        .locations()
-                        .artifact_cache_control_for(index)
-                }) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
-                            .map_err(Error::CacheRead)?,
-                    )
-                }
-            }
+            Connectivity::Online => CacheControl::from(
+                self.build_context
+                    .cache()
+                    .freshness(&http_entry, Some(&filename.name), None)
+                    .map_err(Error::CacheRead)?,
+            )
+            .with_override(index.and_then(|index| {
+                self.build_context
+                    .locations()
+                    .artifact_cache_control_for(index)
+            })),
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -964,9 +1343,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
             // Persist the temporary directory to the directory store.
             let id = self
-                .build_context
-                .cache()
-                .persist(temp_dir.keep(), wheel_entry.path())
+                .persist_wheel(temp_dir.keep(), wheel_entry.path())
                 .await
                 .map_err(Error::CacheWrite)?;
 
@@ -1012,15 +1389,26 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         // Persist the temporary directory to the directory store.
         let id = self
-            .build_context
-            .cache()
-            .persist(temp_dir.keep(), target)
+            .persist_wheel(temp_dir.keep(), target)
             .await
             .map_err(Error::CacheWrite)?;
 
         Ok(id)
     }
 
+    /// Persist an unpacked wheel to the directory store, compressing it if requested.
+    async fn persist_wheel(&self, temp_dir: PathBuf, target: &Path) -> io::Result<ArchiveId> {
+        let cache = self.build_context.cache();
+        if cache.compression().is_enabled() {
+            tokio::task::spawn_blocking({
+                let temp_dir = temp_dir.clone();
+                move || uv_install_wheel::compress_wheel(temp_dir)
+            })
+            .await??;
+        }
+        cache.persist(temp_dir, target).await
+    }
+
     /// Returns a GET [`reqwest::Request`] for the given URL.
     fn request(&self, url: DisplaySafeUrl) -> Result<reqwest::Request, reqwest::Error> {
         self.client
@@ -1085,6 +1473,50 @@ impl<'a> ManagedClient<'a> {
     }
 }
 
+/// Returns the [`SizeMismatch`] between the expected size of a download and the `Content-Length`
+/// reported by the server, if any.
+fn content_length_mismatch(
+    expected: Option<u64>,
+    response: &reqwest::Response,
+) -> Option<SizeMismatch> {
+    let expected = expected?;
+    let actual = content_length(response)?;
+    match actual.cmp(&expected) {
+        std::cmp::Ordering::Less => Some(SizeMismatch::TooShort { expected, actual }),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Greater => Some(SizeMismatch::TooLong { expected }),
+    }
+}
+
+/// Warn if a download whose hashes were validated doesn't match the size reported by the index.
+///
+/// The hashes are authoritative, so a stale or incorrect size shouldn't fail the download.
+fn warn_size_mismatch(dist: &BuiltDist, expected: Option<u64>, actual: u64) {
+    let Some(expected) = expected else {
+        return;
+    };
+    if expected != actual {
+        warn_user_once!(
+            "The index reported a size of {expected} bytes for `{dist}`, but {actual} bytes were downloaded; the hashes matched, so the download was accepted"
+        );
+    }
+}
+
+/// Compute the hash digests of a local archive, as required by the [`HashPolicy`].
+async fn hash_archive(path: &Path, hashes: HashPolicy<'_>) -> Result<HashDigests, Error> {
+    if hashes.is_none() {
+        return Ok(HashDigests::empty());
+    }
+    let file = fs_err::tokio::File::open(path)
+        .await
+        .map_err(Error::CacheRead)?;
+    let algorithms = hashes.algorithms();
+    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+    let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
+    hasher.finish().await.map_err(Error::HashExhaustion)?;
+    Ok(hashers.into_iter().map(HashDigest::from).collect())
+}
+
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
 fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
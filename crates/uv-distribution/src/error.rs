@@ -129,6 +129,9 @@ pub enum Error {
         actual: String,
     },
 
+    #[error("Size mismatch for `{0}`: {1}")]
+    SizeMismatch(String, uv_extract::hash::SizeMismatch),
+
     #[error(
         "Hash-checking is enabled, but no hashes were provided or computed for: `{distribution}`"
     )]
@@ -149,12 +152,6 @@ pub enum Error {
         distribution: String,
         actual: String,
     },
-
-    #[error("Hash-checking is not supported for local directories: `{0}`")]
-    HashesNotSupportedSourceTree(String),
-
-    #[error("Hash-checking is not supported for Git repositories: `{0}`")]
-    HashesNotSupportedGit(String),
 }
 
 impl From<reqwest::Error> for Error {
@@ -237,4 +234,10 @@ impl Error {
             }
         }
     }
+
+    /// Returns `true` if the error indicates that the server hosting the distribution is
+    /// unavailable (e.g., a connection failure or a server error).
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Client(err) if err.is_unavailable())
+    }
 }
@@ -15,5 +15,6 @@ mod download;
 mod error;
 mod index;
 mod metadata;
+mod partial;
 mod reporter;
 mod source;
@@ -80,8 +80,11 @@ impl SourcedDependencyGroups {
         let project = VirtualProject::discover_defaulted(project_dir, &discovery, cache).await?;
 
         // Collect the dependency groups.
-        let dependency_groups =
-            FlatDependencyGroups::from_pyproject_toml(project.root(), project.pyproject_toml())?;
+        let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
+            project.root(),
+            project.pyproject_toml(),
+            project.workspace(),
+        )?;
 
         // If sources/indexes are disabled we can just stop here
         let SourceStrategy::Enabled = source_strategy else {
@@ -110,6 +110,7 @@ impl RequiresDist {
         let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
             project_workspace.current_project().root(),
             project_workspace.current_project().pyproject_toml(),
+            project_workspace.workspace(),
         )?;
 
         // Now that we've resolved the dependency groups, we can validate that each source references
@@ -0,0 +1,186 @@
+//! Persistence of interrupted wheel downloads, such that a later run can resume them.
+
+use std::cell::Cell;
+use std::io;
+use std::path::PathBuf;
+
+use reqwest::header::HeaderValue;
+use tracing::debug;
+
+use uv_cache::CacheEntry;
+use uv_distribution_filename::WheelFilename;
+use uv_fs::{LockedFile, Simplified};
+
+/// A wheel download that's persisted in the cache as it's received.
+///
+/// The received bytes are stored next to the wheel's cache entry (as `{stem}.partial`), along with
+/// the validator (an `ETag` or `Last-Modified` header) of the response they were drawn from. If the
+/// download is interrupted, a later attempt can request the remainder of the same file with an
+/// HTTP range request, rather than starting over.
+///
+/// The partial download is removed when dropped, unless it was [`PartialDownload::keep`]-ed.
+#[derive(Debug)]
+pub(crate) struct PartialDownload {
+    /// The file that holds the received bytes.
+    path: PathBuf,
+    /// The file that holds the validator of the response the bytes were drawn from.
+    validator: PathBuf,
+    /// Whether to retain the partial download once dropped.
+    keep: Cell<bool>,
+    /// An exclusive lock on the partial download, to guard against concurrent downloads.
+    _lock: LockedFile,
+}
+
+impl PartialDownload {
+    /// Acquire the partial download for the given wheel.
+    ///
+    /// Returns `None` if the wheel is being downloaded concurrently.
+    pub(crate) fn acquire(
+        wheel_entry: &CacheEntry,
+        filename: &WheelFilename,
+    ) -> io::Result<Option<Self>> {
+        fs_err::create_dir_all(wheel_entry.dir())?;
+        let path = Self::path(wheel_entry, filename);
+        let Some(lock) = LockedFile::try_acquire(
+            wheel_entry
+                .dir()
+                .join(format!("{}.partial.lock", filename.stem())),
+            path.user_display(),
+        )?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            validator: wheel_entry
+                .dir()
+                .join(format!("{}.partial.validator", filename.stem())),
+            path,
+            keep: Cell::new(false),
+            _lock: lock,
+        }))
+    }
+
+    /// Whether a previous attempt left a partial download of the given wheel.
+    pub(crate) fn exists(wheel_entry: &CacheEntry, filename: &WheelFilename) -> bool {
+        Self::path(wheel_entry, filename).is_file()
+    }
+
+    fn path(wheel_entry: &CacheEntry, filename: &WheelFilename) -> PathBuf {
+        wheel_entry
+            .dir()
+            .join(format!("{}.partial", filename.stem()))
+    }
+
+    /// Return the number of bytes received by a previous attempt, if they were drawn from a
+    /// response with the given validator.
+    pub(crate) fn offset(&self, validator: &HeaderValue) -> Option<u64> {
+        let previous = fs_err::read(&self.validator).ok()?;
+        if previous != validator.as_bytes() {
+            debug!(
+                "Discarding partial download with outdated validator: {}",
+                self.path.user_display()
+            );
+            return None;
+        }
+        let offset = fs_err::metadata(&self.path).ok()?.len();
+        (offset > 0).then_some(offset)
+    }
+
+    /// Open the partial download to append the remainder of the file.
+    pub(crate) async fn append(&self) -> io::Result<tokio::fs::File> {
+        tokio::fs::OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(&self.path)
+            .await
+    }
+
+    /// Open the previously received bytes for reading.
+    pub(crate) async fn read(&self) -> io::Result<tokio::fs::File> {
+        tokio::fs::File::open(&self.path).await
+    }
+
+    /// Start a new download from a response with the given validator, discarding any previously
+    /// received bytes.
+    ///
+    /// If the response has no validator, the download can't be resumed, so the bytes are only
+    /// stored until the download completes.
+    pub(crate) async fn create(
+        &self,
+        validator: Option<&HeaderValue>,
+    ) -> io::Result<tokio::fs::File> {
+        match tokio::fs::remove_file(&self.validator).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.path)
+            .await?;
+        if let Some(validator) = validator {
+            tokio::fs::write(&self.validator, validator.as_bytes()).await?;
+        }
+        Ok(file)
+    }
+
+    /// Retain the partial download, such that a later attempt can resume it.
+    pub(crate) fn keep(&self) {
+        self.keep.set(true);
+    }
+}
+
+impl Drop for PartialDownload {
+    fn drop(&mut self) {
+        if self.keep.get() {
+            return;
+        }
+        for path in [&self.validator, &self.path] {
+            match fs_err::remove_file(path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => debug!("Failed to remove partial download: {err}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use tokio::io::AsyncWriteExt;
+
+    use uv_cache::{Cache, CacheBucket};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn resume_with_matching_validator() {
+        let cache = Cache::temp().unwrap();
+        let entry = cache.entry(CacheBucket::Wheels, "index", "foo");
+        let filename = WheelFilename::from_str("foo-1.0.0-py3-none-any.whl").unwrap();
+        let validator = HeaderValue::from_static("\"etag\"");
+
+        // An interrupted download is retained.
+        let partial = PartialDownload::acquire(&entry, &filename).unwrap().unwrap();
+        let mut file = partial.create(Some(&validator)).await.unwrap();
+        file.write_all(b"partial").await.unwrap();
+        file.flush().await.unwrap();
+        partial.keep();
+        drop(partial);
+        assert!(PartialDownload::exists(&entry, &filename));
+
+        // It can only be resumed from a response with the same validator.
+        let partial = PartialDownload::acquire(&entry, &filename).unwrap().unwrap();
+        assert_eq!(partial.offset(&validator), Some(7));
+        assert_eq!(partial.offset(&HeaderValue::from_static("\"other\"")), None);
+
+        // Once the download completes, it's removed.
+        drop(partial);
+        assert!(!PartialDownload::exists(&entry, &filename));
+    }
+}
@@ -27,13 +27,13 @@ use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
 use uv_client::{
-    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RateLimited, RegistryClient,
 };
 use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
-    BuildableSource, DirectorySourceUrl, GitSourceUrl, HashPolicy, Hashed, IndexUrl, PathSourceUrl,
-    SourceDist, SourceUrl,
+    BuildSystemOverride, BuildableSource, DirectorySourceUrl, GitSourceUrl, HashPolicy, Hashed,
+    IndexUrl, PathSourceUrl, SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic};
@@ -325,12 +325,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
             }
             BuildableSource::Dist(SourceDist::Git(dist)) => {
-                self.git_metadata(source, &GitSourceUrl::from(dist), hashes, client)
+                self.git_metadata(source, &GitSourceUrl::from(dist), client)
                     .boxed_local()
                     .await?
             }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
-                self.source_tree_metadata(source, &DirectorySourceUrl::from(dist), hashes)
+                self.source_tree_metadata(source, &DirectorySourceUrl::from(dist))
                     .boxed_local()
                     .await?
             }
@@ -364,12 +364,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
             }
             BuildableSource::Url(SourceUrl::Git(resource)) => {
-                self.git_metadata(source, resource, hashes, client)
+                self.git_metadata(source, resource, client)
                     .boxed_local()
                     .await?
             }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
-                self.source_tree_metadata(source, resource, hashes)
+                self.source_tree_metadata(source, resource)
                     .boxed_local()
                     .await?
             }
@@ -404,6 +404,21 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
     }
 
+    /// Determine the [`BuildSystemOverride`] entries that may apply to the given source.
+    fn build_system_overrides_for(
+        &self,
+        source: &BuildableSource<'_>,
+    ) -> Vec<&BuildSystemOverride> {
+        source
+            .name()
+            .map(|name| {
+                self.build_context
+                    .build_system_overrides()
+                    .candidates(name, source.version())
+            })
+            .unwrap_or_default()
+    }
+
     /// Build a source distribution from a remote URL.
     async fn url<'data>(
         &self,
@@ -417,7 +432,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -438,12 +453,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_dist_entry = cache_shard.entry(SOURCE);
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -504,7 +524,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
@@ -534,7 +555,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -614,12 +635,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // Otherwise, we either need to build the metadata.
@@ -644,7 +670,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 metadata
             };
 
-            // Store the metadata.
+            // Store the metadata, unless the lock was lost in the interim.
+            lock.check().map_err(Error::CacheWrite)?;
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
@@ -690,7 +717,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             metadata
         };
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
@@ -716,22 +744,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Determine the cache control policy for the request.
         let cache_control = match client.unmanaged.connectivity() {
-            Connectivity::Online => {
-                if let Some(header) = index.and_then(|index| {
-                    self.build_context
-                        .locations()
-                        .artifact_cache_control_for(index)
-                }) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&cache_entry, source.name(), source.source_tree())
-                            .map_err(Error::CacheRead)?,
-                    )
-                }
-            }
+            Connectivity::Online => CacheControl::from(
+                self.build_context
+                    .cache()
+                    .freshness(&cache_entry, source.name(), source.source_tree())
+                    .map_err(Error::CacheRead)?,
+            )
+            .with_override(index.and_then(|index| {
+                self.build_context
+                    .locations()
+                    .artifact_cache_control_for(index)
+            })),
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -754,21 +777,32 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .boxed_local()
             .instrument(info_span!("download", source_dist = %source))
         };
-        let req = Self::request(DisplaySafeUrl::from(url.clone()), client.unmanaged)?;
+        // Download the source distribution, failing over to the index's mirrors if necessary.
         let revision = client
-            .managed(|client| {
-                client.cached_client().get_serde_with_retry(
-                    req,
-                    &cache_entry,
-                    cache_control,
-                    download,
-                )
-            })
-            .await
-            .map_err(|err| match err {
-                CachedClientError::Callback { err, .. } => err,
-                CachedClientError::Client { err, .. } => Error::Client(err),
-            })?;
+            .unmanaged
+            .with_artifact_failover(
+                index,
+                &DisplaySafeUrl::from(url.clone()),
+                Error::is_unavailable,
+                async |url| {
+                    let req = Self::request(url, client.unmanaged)?;
+                    client
+                        .managed(|client| {
+                            client.cached_client().get_serde_with_retry(
+                                req,
+                                &cache_entry,
+                                cache_control,
+                                download,
+                            )
+                        })
+                        .await
+                        .map_err(|err| match err {
+                            CachedClientError::Callback { err, .. } => err,
+                            CachedClientError::Client { err, .. } => Error::Client(err),
+                        })
+                },
+            )
+            .await?;
 
         // If the archive is missing the required hashes, force a refresh.
         if revision.has_digests(hashes) {
@@ -803,7 +837,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -827,12 +861,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_entry = cache_shard.entry(SOURCE);
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -873,7 +912,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
@@ -899,7 +939,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
@@ -975,7 +1015,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 metadata
             };
 
-            // Store the metadata.
+            // Store the metadata, unless the lock was lost in the interim.
+            lock.check().map_err(Error::CacheWrite)?;
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
@@ -989,12 +1030,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             });
         }
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // Otherwise, we need to build a wheel.
@@ -1029,7 +1075,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             metadata
         };
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
@@ -1102,11 +1149,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        // Before running the build, check that the hashes match.
-        if hashes.is_validate() {
-            return Err(Error::HashesNotSupportedSourceTree(source.to_string()));
-        }
-
         let cache_shard = self.build_context.cache().shard(
             CacheBucket::SourceDistributions,
             if resource.editable.unwrap_or(false) {
@@ -1117,7 +1159,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -1131,12 +1173,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // freshness, since entries have to be fresher than the revision itself.
         let cache_shard = cache_shard.shard(revision.id());
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -1145,7 +1192,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
         {
-            return Ok(built_wheel);
+            return hash_built_wheel(built_wheel, hashes).await;
         }
 
         // Otherwise, we need to build a wheel.
@@ -1170,19 +1217,23 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
-        Ok(BuiltWheelMetadata {
+        let built_wheel = BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename).into_boxed_path(),
             target: cache_shard.join(filename.stem()).into_boxed_path(),
             filename,
             hashes: revision.into_hashes(),
             cache_info,
-        })
+        };
+
+        // If required, hash the built wheel, since the source tree itself can't be hashed.
+        hash_built_wheel(built_wheel, hashes).await
     }
 
     /// Build the source distribution's metadata from a local source tree (i.e., a directory),
@@ -1190,17 +1241,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ///
     /// If the build backend supports `prepare_metadata_for_build_wheel`, this method will avoid
     /// building the wheel.
+    ///
+    /// Source trees can't be hashed, so any required hashes are instead validated against the
+    /// built wheel (see: [`SourceDistributionBuilder::source_tree`]).
     async fn source_tree_metadata(
         &self,
         source: &BuildableSource<'_>,
         resource: &DirectorySourceUrl<'_>,
-        hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        // Before running the build, check that the hashes match.
-        if hashes.is_validate() {
-            return Err(Error::HashesNotSupportedSourceTree(source.to_string()));
-        }
-
         // If the metadata is static, return it.
         let dynamic = match StaticMetadata::read(source, &resource.install_path, None).await? {
             StaticMetadata::Some(metadata) => {
@@ -1230,7 +1278,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
@@ -1288,7 +1336,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .boxed_local()
             .await?
         {
-            // Store the metadata.
+            // Store the metadata, unless the lock was lost in the interim.
+            lock.check().map_err(Error::CacheWrite)?;
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
@@ -1319,12 +1368,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // Otherwise, we need to build a wheel.
@@ -1349,7 +1403,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
@@ -1480,11 +1535,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        // Before running the build, check that the hashes match.
-        if hashes.is_validate() {
-            return Err(Error::HashesNotSupportedGit(source.to_string()));
-        }
-
         // Fetch the Git repository.
         let fetch = self
             .build_context
@@ -1522,14 +1572,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -1538,7 +1593,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
         {
-            return Ok(built_wheel);
+            return hash_built_wheel(built_wheel, hashes).await;
         }
 
         let task = self
@@ -1562,36 +1617,37 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
 
-        Ok(BuiltWheelMetadata {
+        let built_wheel = BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename).into_boxed_path(),
             target: cache_shard.join(filename.stem()).into_boxed_path(),
             filename,
             hashes: HashDigests::empty(),
             cache_info: CacheInfo::default(),
-        })
+        };
+
+        // If required, hash the built wheel, since the repository itself can't be hashed.
+        hash_built_wheel(built_wheel, hashes).await
     }
 
     /// Build the source distribution's metadata from a Git repository.
     ///
     /// If the build backend supports `prepare_metadata_for_build_wheel`, this method will avoid
     /// building the wheel.
+    ///
+    /// Git repositories can't be hashed, so any required hashes are instead validated against the
+    /// built wheel (see: [`SourceDistributionBuilder::git`]).
     async fn git_metadata(
         &self,
         source: &BuildableSource<'_>,
         resource: &GitSourceUrl<'_>,
-        hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        // Before running the build, check that the hashes match.
-        if hashes.is_validate() {
-            return Err(Error::HashesNotSupportedGit(source.to_string()));
-        }
-
         // If the reference appears to be a commit, and we've already checked it out, avoid taking
         // the GitHub fast path.
         let cache_shard = resource
@@ -1712,7 +1768,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         let path = if let Some(subdirectory) = resource.subdirectory {
             Cow::Owned(fetch.path().join(subdirectory))
@@ -1806,7 +1862,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 metadata
             };
 
-            // Store the metadata.
+            // Store the metadata, unless the lock was lost in the interim.
+            lock.check().map_err(Error::CacheWrite)?;
             fs::create_dir_all(metadata_entry.dir())
                 .await
                 .map_err(Error::CacheWrite)?;
@@ -1827,12 +1884,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or build system overrides, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
+        let build_system_overrides = self.build_system_overrides_for(source);
+        let cache_shard = if build_system_overrides.is_empty() {
+            if config_settings.is_empty() {
+                cache_shard
+            } else {
+                cache_shard.shard(cache_digest(&config_settings))
+            }
         } else {
-            cache_shard.shard(cache_digest(&config_settings))
+            cache_shard.shard(cache_digest(&(&config_settings, &build_system_overrides)))
         };
 
         // Otherwise, we need to build a wheel.
@@ -1867,7 +1929,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             metadata
         };
 
-        // Store the metadata.
+        // Store the metadata, unless the lock was lost in the interim.
+        lock.check().map_err(Error::CacheWrite)?;
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
@@ -2099,22 +2162,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Determine the cache control policy for the request.
         let cache_control = match client.unmanaged.connectivity() {
-            Connectivity::Online => {
-                if let Some(header) = index.and_then(|index| {
-                    self.build_context
-                        .locations()
-                        .artifact_cache_control_for(index)
-                }) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&cache_entry, source.name(), source.source_tree())
-                            .map_err(Error::CacheRead)?,
-                    )
-                }
-            }
+            Connectivity::Online => CacheControl::from(
+                self.build_context
+                    .cache()
+                    .freshness(&cache_entry, source.name(), source.source_tree())
+                    .map_err(Error::CacheRead)?,
+            )
+            .with_override(index.and_then(|index| {
+                self.build_context
+                    .locations()
+                    .artifact_cache_control_for(index)
+            })),
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -2178,8 +2236,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .bucket(CacheBucket::SourceDistributions),
         )
         .map_err(Error::CacheWrite)?;
-        let reader = response
-            .bytes_stream()
+        let reader = RateLimited::new(response.bytes_stream())
             .map_err(std::io::Error::other)
             .into_async_read();
 
@@ -2217,6 +2274,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         };
 
+        // If requested, compress the extracted files.
+        self.compress_source_tree(&extracted).await?;
+
         // Persist it to the cache.
         fs_err::tokio::create_dir_all(target.parent().expect("Cache entry to have parent"))
             .await
@@ -2285,6 +2345,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         };
 
+        // If requested, compress the extracted files.
+        self.compress_source_tree(&extracted).await?;
+
         // Persist it to the cache.
         fs_err::tokio::create_dir_all(target.parent().expect("Cache entry to have parent"))
             .await
@@ -2301,6 +2364,45 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(hashes)
     }
 
+    /// Compress an unpacked source distribution in-place, if compression is enabled for the cache.
+    async fn compress_source_tree(&self, source_root: &Path) -> Result<(), Error> {
+        if !self.build_context.cache().compression().is_enabled() {
+            return Ok(());
+        }
+        let source_root = source_root.to_path_buf();
+        tokio::task::spawn_blocking(move || uv_install_wheel::compress_source_tree(source_root))
+            .await?
+            .map_err(Error::CacheWrite)
+    }
+
+    /// If the source distribution at the given path is compressed, decompress it into a temporary
+    /// directory from which it can be built.
+    async fn decompress_source_tree(
+        &self,
+        source_root: &Path,
+    ) -> Result<Option<tempfile::TempDir>, Error> {
+        if !uv_install_wheel::is_compressed(source_root) {
+            return Ok(None);
+        }
+        debug!(
+            "Decompressing source distribution: {}",
+            source_root.display()
+        );
+        let temp_dir = self
+            .build_context
+            .cache()
+            .build_dir()
+            .map_err(Error::CacheWrite)?;
+        tokio::task::spawn_blocking({
+            let source_root = source_root.to_path_buf();
+            let target = temp_dir.path().to_path_buf();
+            move || uv_install_wheel::decompress_source_tree(source_root, target)
+        })
+        .await?
+        .map_err(Error::CacheRead)?;
+        Ok(Some(temp_dir))
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -2328,6 +2430,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        self.build_context.cache().stats().record_build();
+
+        // If the source distribution is stored compressed, build from a decompressed copy.
+        let decompressed = self.decompress_source_tree(source_root).await?;
+        let source_root = decompressed
+            .as_ref()
+            .map_or(source_root, tempfile::TempDir::path);
+
         // Build into a temporary directory, to prevent partial builds.
         let temp_dir = self
             .build_context
@@ -2481,6 +2591,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // If the source distribution is stored compressed, prepare from a decompressed copy.
+        let decompressed = self.decompress_source_tree(source_root).await?;
+        let source_root = decompressed
+            .as_ref()
+            .map_or(source_root, tempfile::TempDir::path);
+
         // Identify the base Python interpreter to use in the cache key.
         let base_python = if cfg!(unix) {
             self.build_context
@@ -2521,18 +2637,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // Build the metadata.
         let dist_info = builder.metadata().await.map_err(Error::Build)?;
 
-        // Store the build context.
-        self.build_context.build_arena().insert(
-            BuildKey {
-                base_python: base_python.into_boxed_path(),
-                source_root: source_root.to_path_buf().into_boxed_path(),
-                subdirectory: subdirectory
-                    .map(|subdirectory| subdirectory.to_path_buf().into_boxed_path()),
-                source_strategy,
-                build_kind,
-            },
-            builder,
-        );
+        // Store the build context, unless it refers to a decompressed copy of the source
+        // distribution, which is removed on return.
+        let _builder = if decompressed.is_none() {
+            self.build_context.build_arena().insert(
+                BuildKey {
+                    base_python: base_python.into_boxed_path(),
+                    source_root: source_root.to_path_buf().into_boxed_path(),
+                    subdirectory: subdirectory
+                        .map(|subdirectory| subdirectory.to_path_buf().into_boxed_path()),
+                    source_strategy,
+                    build_kind,
+                },
+                builder,
+            );
+            None
+        } else {
+            Some(builder)
+        };
 
         // Return the `.dist-info` directory, if it exists.
         let Some(dist_info) = dist_info else {
@@ -2780,6 +2902,36 @@ fn has_sources(content: &str) -> Result<bool, toml::de::Error> {
     Ok(false)
 }
 
+/// Compute the hashes of a wheel built from a Git repository or local source tree, if required by
+/// the hash policy.
+///
+/// Unlike archives, Git repositories and source trees can't be hashed directly, so the built wheel
+/// is hashed instead, to be validated against the expected hashes downstream.
+async fn hash_built_wheel(
+    built_wheel: BuiltWheelMetadata,
+    hashes: HashPolicy<'_>,
+) -> Result<BuiltWheelMetadata, Error> {
+    if !hashes.is_validate() {
+        return Ok(built_wheel);
+    }
+
+    let reader = fs_err::tokio::File::open(&*built_wheel.path)
+        .await
+        .map_err(Error::CacheRead)?;
+
+    // Create a hasher for each hash algorithm.
+    let mut hashers = hashes
+        .algorithms()
+        .into_iter()
+        .map(Hasher::from)
+        .collect::<Vec<_>>();
+    let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
+    hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+    let hashes = hashers.into_iter().map(HashDigest::from).collect();
+    Ok(built_wheel.with_hashes(hashes))
+}
+
 /// Validate that the source distribution matches the built metadata.
 fn validate_metadata(
     source: &BuildableSource<'_>,
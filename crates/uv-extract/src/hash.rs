@@ -66,9 +66,39 @@ impl From<Hasher> for HashDigest {
     }
 }
 
+/// A mismatch between the expected size of a stream and the number of bytes it returned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SizeMismatch {
+    /// The stream ended before the expected number of bytes was read.
+    TooShort { expected: u64, actual: u64 },
+    /// The stream returned more than the expected number of bytes.
+    TooLong { expected: u64 },
+}
+
+impl std::fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort { expected, actual } => {
+                write!(f, "expected {expected} bytes, but received {actual}")
+            }
+            Self::TooLong { expected } => {
+                write!(f, "expected {expected} bytes, but received more")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SizeMismatch {}
+
 pub struct HashReader<'a, R> {
     reader: R,
     hashers: &'a mut [Hasher],
+    /// The expected size of the stream, if known.
+    expected_size: Option<u64>,
+    /// The number of bytes read so far.
+    bytes_read: u64,
+    /// The size mismatch that caused the stream to be aborted, if any.
+    size_mismatch: Option<SizeMismatch>,
 }
 
 impl<'a, R> HashReader<'a, R>
@@ -76,7 +106,39 @@ where
     R: tokio::io::AsyncRead + Unpin,
 {
     pub fn new(reader: R, hashers: &'a mut [Hasher]) -> Self {
-        HashReader { reader, hashers }
+        HashReader {
+            reader,
+            hashers,
+            expected_size: None,
+            bytes_read: 0,
+            size_mismatch: None,
+        }
+    }
+
+    /// Abort the stream with an error as soon as it's certain that it doesn't match the expected
+    /// size, rather than reading it to the end.
+    #[must_use]
+    pub fn with_expected_size(mut self, expected_size: Option<u64>) -> Self {
+        self.expected_size = expected_size;
+        self
+    }
+
+    /// Account for `offset` bytes that were read (and hashed) before the stream, e.g., the
+    /// previously downloaded prefix of a resumed download.
+    #[must_use]
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.bytes_read = offset;
+        self
+    }
+
+    /// Return the size mismatch that caused the stream to be aborted, if any.
+    pub fn size_mismatch(&self) -> Option<SizeMismatch> {
+        self.size_mismatch
+    }
+
+    /// Return the number of bytes read so far.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
     }
 
     /// Exhaust the underlying reader.
@@ -85,6 +147,30 @@ where
 
         Ok(())
     }
+
+    /// Record that `len` bytes were read (or that the stream ended, if `len` is zero), returning
+    /// an error if the stream no longer matches the expected size.
+    fn check_size(&mut self, len: usize, eof: bool) -> Result<(), std::io::Error> {
+        self.bytes_read += len as u64;
+        let Some(expected) = self.expected_size else {
+            return Ok(());
+        };
+        let mismatch = if self.bytes_read > expected {
+            SizeMismatch::TooLong { expected }
+        } else if eof && self.bytes_read < expected {
+            SizeMismatch::TooShort {
+                expected,
+                actual: self.bytes_read,
+            }
+        } else {
+            return Ok(());
+        };
+        self.size_mismatch = Some(mismatch);
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            mismatch,
+        ))
+    }
 }
 
 impl<R> tokio::io::AsyncRead for HashReader<'_, R>
@@ -96,15 +182,55 @@ where
         cx: &mut Context<'_>,
         buf: &mut ReadBuf<'_>,
     ) -> Poll<std::io::Result<()>> {
+        let start = buf.filled().len();
+        let remaining = buf.remaining();
         let reader = Pin::new(&mut self.reader);
         match reader.poll_read(cx, buf) {
             Poll::Ready(Ok(())) => {
+                let filled = &buf.filled()[start..];
                 for hasher in self.hashers.iter_mut() {
-                    hasher.update(buf.filled());
+                    hasher.update(filled);
                 }
-                Poll::Ready(Ok(()))
+                let len = filled.len();
+                Poll::Ready(self.check_size(len, len == 0 && remaining > 0))
             }
             other => other,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn size_mismatch() {
+        let data = b"hello world";
+
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader =
+            HashReader::new(&data[..], &mut hashers).with_expected_size(Some(data.len() as u64));
+        reader.finish().await.unwrap();
+        assert_eq!(reader.size_mismatch(), None);
+        assert_eq!(reader.bytes_read(), data.len() as u64);
+
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader = HashReader::new(&data[..], &mut hashers).with_expected_size(Some(4));
+        assert!(reader.finish().await.is_err());
+        assert_eq!(
+            reader.size_mismatch(),
+            Some(SizeMismatch::TooLong { expected: 4 })
+        );
+
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader = HashReader::new(&data[..], &mut hashers).with_expected_size(Some(64));
+        assert!(reader.finish().await.is_err());
+        assert_eq!(
+            reader.size_mismatch(),
+            Some(SizeMismatch::TooShort {
+                expected: 64,
+                actual: 11,
+            })
+        );
+    }
+}
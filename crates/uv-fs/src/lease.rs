@@ -0,0 +1,739 @@
+//! A lease-based lock for filesystems on which `flock`-style advisory locks are unreliable.
+//!
+//! Network filesystems (like NFS and SMB) often implement advisory locks poorly, if at all: locks
+//! may be silently ignored, emulated locally on each client, or held indefinitely after a client
+//! disconnects. Instead, a lease is represented by a sidecar file that's created atomically (via
+//! `O_EXCL`, which is honored by NFS v3 and later, and by SMB). The holder periodically refreshes the lease's
+//! modification time; waiters consider the lease stale if its modification time hasn't changed
+//! for [`LEASE_TIMEOUT`], as measured by the waiter's own clock (to avoid relying on synchronized
+//! clocks across hosts).
+//!
+//! Refreshing, breaking, and releasing a lease all happen under a separate, atomically created
+//! token file, such that a stale lease is only removed if it's still unchanged, and a holder whose
+//! lease was broken notices on its next refresh rather than refreshing the new holder's lease.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime};
+
+use tracing::{debug, error, info, trace, warn};
+
+use crate::Simplified;
+
+/// The interval at which the lease holder refreshes the lease.
+const LEASE_REFRESH: Duration = Duration::from_secs(5);
+
+/// The duration after which an unrefreshed lease is considered abandoned.
+///
+/// NFS clients cache file attributes (including the modification time) for up to `acregmax`
+/// seconds (60 by default), so a waiter may not observe a refresh until well after it happened.
+/// The timeout must comfortably exceed that window, plus the refresh interval.
+const LEASE_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// The initial delay between attempts to acquire a held lease.
+const MIN_BACKOFF: Duration = Duration::from_millis(50);
+
+/// The maximum delay between attempts to acquire a held lease.
+const MAX_BACKOFF: Duration = Duration::from_secs(1);
+
+/// A held lease, released when dropped.
+///
+/// A lease is either exclusive, in which case it's held at the lease path itself, or shared, in
+/// which case it's held at a unique sibling path (see [`shared_path`]). Shared leases are created
+/// while holding the exclusive lease, and exclusive holders wait for all shared leases to be
+/// released.
+#[derive(Debug)]
+pub(crate) struct Lease {
+    path: PathBuf,
+    owner: LeaseOwner,
+    stop: Arc<AtomicBool>,
+    lost: Arc<AtomicBool>,
+    heartbeat: Option<JoinHandle<()>>,
+}
+
+impl Lease {
+    /// Acquire the exclusive lease at the given path, blocking until it's available.
+    pub(crate) fn acquire_blocking(path: &Path, resource: &str) -> io::Result<Self> {
+        let lease = Self::acquire_exclusive(path, resource)?;
+        lease.wait_for_readers(resource)?;
+        Ok(lease)
+    }
+
+    /// Acquire a shared lease at the given path, blocking until any exclusive lease is released.
+    pub(crate) fn acquire_shared_blocking(path: &Path, resource: &str) -> io::Result<Self> {
+        // Hold the exclusive lease while registering the shared lease, such that an exclusive
+        // holder can't miss it.
+        let _exclusive = Self::acquire_exclusive(path, resource)?;
+        let owner = LeaseOwner::current();
+        let shared = shared_path(path, owner.nonce);
+        let Some(lease) = Self::try_create(&shared, &owner)? else {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("Shared lease already exists at `{}`", shared.user_display()),
+            ));
+        };
+        debug!("Acquired shared lease for `{resource}`");
+        Ok(lease)
+    }
+
+    /// Attempt to acquire the exclusive lease at the given path, returning `None` if it (or a
+    /// shared lease) is held elsewhere.
+    ///
+    /// Leases held by processes that are known to have exited are broken; otherwise, a held lease
+    /// is assumed to be live, since staleness can only be determined by waiting.
+    pub(crate) fn try_acquire(path: &Path, resource: &str) -> io::Result<Option<Self>> {
+        let owner = LeaseOwner::current();
+        let lease = if let Some(lease) = Self::try_create(path, &owner)? {
+            lease
+        } else {
+            if !break_dead_lease(path, &owner)? {
+                debug!("Lease for `{resource}` is held elsewhere");
+                return Ok(None);
+            }
+            let Some(lease) = Self::try_create(path, &owner)? else {
+                debug!("Lease for `{resource}` is held elsewhere");
+                return Ok(None);
+            };
+            lease
+        };
+
+        for reader in shared_leases(path)? {
+            if !break_dead_lease(&reader, &owner)? {
+                debug!("Shared lease for `{resource}` is held elsewhere");
+                return Ok(None);
+            }
+        }
+
+        debug!("Acquired lease for `{resource}`");
+        Ok(Some(lease))
+    }
+
+    /// Acquire the lease at the given path, blocking until it's available, without waiting for
+    /// shared leases to be released.
+    fn acquire_exclusive(path: &Path, resource: &str) -> io::Result<Self> {
+        trace!(
+            "Checking lease for `{resource}` at `{}`",
+            path.user_display()
+        );
+        let owner = LeaseOwner::current();
+
+        // Fast path: the lease is uncontended.
+        if let Some(lease) = Self::try_create(path, &owner)? {
+            debug!("Acquired lease for `{resource}`");
+            return Ok(lease);
+        }
+
+        info!(
+            "Waiting to acquire lease for `{resource}` at `{}`",
+            path.user_display()
+        );
+
+        let mut backoff = MIN_BACKOFF;
+        let mut observed = Observation::default();
+        loop {
+            std::thread::sleep(backoff);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+
+            if let Some(lease) = Self::try_create(path, &owner)? {
+                debug!("Acquired lease for `{resource}`");
+                return Ok(lease);
+            }
+
+            if break_stale_lease(path, &owner, &mut observed)? {
+                warn!(
+                    "Removed stale lease for `{resource}` at `{}`",
+                    path.user_display()
+                );
+                observed = Observation::default();
+                backoff = MIN_BACKOFF;
+            }
+        }
+    }
+
+    /// Wait for all shared leases on the lease path to be released, breaking any that are stale.
+    fn wait_for_readers(&self, resource: &str) -> io::Result<()> {
+        let mut observed = HashMap::<PathBuf, Observation>::new();
+        let mut backoff = MIN_BACKOFF;
+        let mut waiting = false;
+        loop {
+            let readers = shared_leases(&self.path)?;
+            observed.retain(|reader, _| readers.contains(reader));
+
+            let mut live = false;
+            for reader in readers {
+                let observation = observed.entry(reader.clone()).or_default();
+                if break_stale_lease(&reader, &self.owner, observation)? {
+                    warn!(
+                        "Removed stale shared lease for `{resource}` at `{}`",
+                        reader.user_display()
+                    );
+                    observed.remove(&reader);
+                } else {
+                    live = true;
+                }
+            }
+            if !live {
+                return Ok(());
+            }
+
+            if !waiting {
+                info!("Waiting for shared leases on `{resource}` to be released");
+                waiting = true;
+            }
+            std::thread::sleep(backoff);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    /// Attempt to atomically create the lease file, returning `None` if it's already held.
+    fn try_create(path: &Path, owner: &LeaseOwner) -> io::Result<Option<Self>> {
+        let mut file = match fs_err::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+        {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if let Err(err) = file
+            .write_all(owner.to_string().as_bytes())
+            .and_then(|()| file.sync_all())
+        {
+            let _ = fs_err::remove_file(path);
+            return Err(err);
+        }
+
+        // Spawn a thread to keep the lease alive for as long as it's held. If the lease is broken
+        // by another process in the interim (e.g., because this process stalled for longer than
+        // the timeout), the heartbeat stops rather than refreshing a lease it no longer holds.
+        let stop = Arc::new(AtomicBool::new(false));
+        let lost = Arc::new(AtomicBool::new(false));
+        let heartbeat = std::thread::Builder::new()
+            .name("uv-lease".to_string())
+            .spawn({
+                let stop = stop.clone();
+                let lost = lost.clone();
+                let path = path.to_path_buf();
+                let owner = owner.clone();
+                move || {
+                    loop {
+                        std::thread::park_timeout(LEASE_REFRESH);
+                        if stop.load(Ordering::Acquire) {
+                            break;
+                        }
+                        match refresh_lease(&path, &owner, &file) {
+                            Ok(true) => {}
+                            Ok(false) => {
+                                error!("{}", LeaseLost(&path));
+                                lost.store(true, Ordering::Release);
+                                break;
+                            }
+                            Err(err) => {
+                                warn!(
+                                    "Failed to refresh lease at `{}`: {err}",
+                                    path.user_display()
+                                );
+                            }
+                        }
+                    }
+                }
+            })?;
+
+        Ok(Some(Self {
+            path: path.to_path_buf(),
+            owner: owner.clone(),
+            stop,
+            lost,
+            heartbeat: Some(heartbeat),
+        }))
+    }
+}
+
+impl Lease {
+    /// Returns an error if the lease was broken by another process while held.
+    pub(crate) fn check(&self) -> io::Result<()> {
+        if self.lost.load(Ordering::Acquire) {
+            return Err(io::Error::other(LeaseLost(&self.path).to_string()));
+        }
+        Ok(())
+    }
+
+    /// Release the lease, returning an error if it was lost while held.
+    pub(crate) fn release(&mut self) -> io::Result<()> {
+        let Some(heartbeat) = self.heartbeat.take() else {
+            return Ok(());
+        };
+        self.stop.store(true, Ordering::Release);
+        heartbeat.thread().unpark();
+        let _ = heartbeat.join();
+        self.check()?;
+
+        // Remove the lease under the break token, and only if it's still ours: if the lease was
+        // broken, it may now be held by another process.
+        let _token = BreakToken::acquire(&self.path)?;
+        match fs_err::read_to_string(&self.path) {
+            Ok(contents) if LeaseOwner::parse(&contents).as_ref() == Some(&self.owner) => {}
+            Ok(_) => return Err(io::Error::other(LeaseLost(&self.path).to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(io::Error::other(LeaseLost(&self.path).to_string()));
+            }
+            Err(err) => return Err(err),
+        }
+        fs_err::remove_file(&self.path)?;
+        debug!("Released lease at `{}`", self.path.display());
+        Ok(())
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        if let Err(err) = self.release() {
+            warn!("Failed to release lease: {err}");
+        }
+    }
+}
+
+/// The error reported when a lease was broken by another process while held.
+struct LeaseLost<'a>(&'a Path);
+
+impl std::fmt::Display for LeaseLost<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Lease at `{}` was broken by another process while held; concurrent access is no longer excluded",
+            self.0.user_display()
+        )
+    }
+}
+
+/// The modification time of a lease, as last observed by the current process.
+///
+/// Staleness is measured against the observer's own clock, since clocks may differ across hosts.
+#[derive(Debug, Default)]
+struct Observation(Option<(SystemTime, Instant)>);
+
+impl Observation {
+    /// Record the modification time, returning `true` if it hasn't changed for [`LEASE_TIMEOUT`].
+    fn expired(&mut self, modified: SystemTime) -> bool {
+        match self.0 {
+            Some((previous, since)) if previous == modified => since.elapsed() >= LEASE_TIMEOUT,
+            _ => {
+                self.0 = Some((modified, Instant::now()));
+                false
+            }
+        }
+    }
+}
+
+/// The right to modify a lease, held while a lease is refreshed, broken, or released.
+///
+/// The token is a sidecar file next to the lease, created atomically. Since every modification of
+/// a lease happens under the token, a lease is only ever broken if it's unchanged since it was
+/// observed to be stale, and a holder can't refresh a lease that has since been broken.
+#[derive(Debug)]
+struct BreakToken {
+    path: PathBuf,
+}
+
+impl BreakToken {
+    /// Acquire the token for the lease at the given path, blocking until it's available.
+    ///
+    /// The token is only ever held briefly. A token left behind by a process that crashed is
+    /// removed once it has gone unmodified for [`LEASE_TIMEOUT`].
+    fn acquire(lease: &Path) -> io::Result<Self> {
+        let mut path = lease.as_os_str().to_owned();
+        path.push(".break");
+        let path = PathBuf::from(path);
+
+        let mut backoff = MIN_BACKOFF;
+        let mut observed = Observation::default();
+        loop {
+            match fs_err::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Self { path }),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(err) => return Err(err),
+            }
+
+            match fs_err::metadata(&path).and_then(|metadata| metadata.modified()) {
+                Ok(modified) => {
+                    if observed.expired(modified) {
+                        warn!(
+                            "Removing abandoned lease token at `{}`",
+                            path.user_display()
+                        );
+                        match fs_err::remove_file(&path) {
+                            Ok(()) => {}
+                            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                            Err(err) => return Err(err),
+                        }
+                        observed = Observation::default();
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    observed = Observation::default();
+                    continue;
+                }
+                Err(err) => return Err(err),
+            }
+
+            std::thread::sleep(backoff);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+}
+
+impl Drop for BreakToken {
+    fn drop(&mut self) {
+        if let Err(err) = fs_err::remove_file(&self.path) {
+            warn!("Failed to release lease token: {err}");
+        }
+    }
+}
+
+/// Read the modification time and contents of a lease file.
+fn read_lease(path: &Path) -> io::Result<(SystemTime, String)> {
+    let modified = fs_err::metadata(path)?.modified()?;
+    let contents = fs_err::read_to_string(path)?;
+    Ok((modified, contents))
+}
+
+/// Refresh a held lease, returning `false` if it's no longer held by the given owner.
+fn refresh_lease(path: &Path, owner: &LeaseOwner, file: &fs_err::File) -> io::Result<bool> {
+    let _token = BreakToken::acquire(path)?;
+    match fs_err::read_to_string(path) {
+        Ok(contents) if LeaseOwner::parse(&contents).as_ref() == Some(owner) => {}
+        Ok(_) => return Ok(false),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err),
+    }
+    file.file().set_modified(SystemTime::now())?;
+    Ok(true)
+}
+
+/// Break the lease at the given path if it's stale, returning `true` if it's no longer held.
+///
+/// A lease is stale if its holder is known to have exited, or if it hasn't been refreshed since
+/// it was first observed (for at least [`LEASE_TIMEOUT`]).
+fn break_stale_lease(
+    path: &Path,
+    current: &LeaseOwner,
+    observed: &mut Observation,
+) -> io::Result<bool> {
+    let (modified, contents) = match read_lease(path) {
+        Ok(lease) => lease,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(true),
+        Err(err) => return Err(err),
+    };
+    let dead = LeaseOwner::parse(&contents).is_some_and(|holder| holder.is_dead(current));
+    let expired = observed.expired(modified);
+    if dead || expired {
+        return break_lease(path, &contents, modified);
+    }
+    Ok(false)
+}
+
+/// Break the lease at the given path if its holder is known to have exited, returning `true` if
+/// it's no longer held.
+fn break_dead_lease(path: &Path, current: &LeaseOwner) -> io::Result<bool> {
+    let (modified, contents) = match read_lease(path) {
+        Ok(lease) => lease,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(true),
+        Err(err) => return Err(err),
+    };
+    if LeaseOwner::parse(&contents).is_some_and(|holder| holder.is_dead(current)) {
+        return break_lease(path, &contents, modified);
+    }
+    Ok(false)
+}
+
+/// Remove a stale lease, returning `true` if it's no longer held.
+///
+/// The lease is only removed if, under the [`BreakToken`], it's unchanged since it was observed
+/// to be stale: if the holder refreshed it, or another process acquired it in the interim, it's
+/// left in place.
+fn break_lease(path: &Path, expected: &str, expected_modified: SystemTime) -> io::Result<bool> {
+    let _token = BreakToken::acquire(path)?;
+    match read_lease(path) {
+        Ok((modified, contents)) if modified == expected_modified && contents == expected => {}
+        Ok(_) => return Ok(false),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(true),
+        Err(err) => return Err(err),
+    }
+    match fs_err::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(true),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the path of a shared lease on the lease at the given path.
+fn shared_path(path: &Path, nonce: u64) -> PathBuf {
+    let mut shared = path.as_os_str().to_owned();
+    shared.push(format!(".shared.{nonce}"));
+    PathBuf::from(shared)
+}
+
+/// Returns the paths of the shared leases on the lease at the given path.
+fn shared_leases(path: &Path) -> io::Result<Vec<PathBuf>> {
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        return Ok(Vec::new());
+    };
+    let prefix = format!("{}.shared.", name.to_string_lossy());
+    let mut readers = Vec::new();
+    for entry in fs_err::read_dir(parent)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with(&prefix) && !name.ends_with(".break") {
+            readers.push(entry.path());
+        }
+    }
+    Ok(readers)
+}
+
+/// The process that holds a lease.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LeaseOwner {
+    host: String,
+    pid: u32,
+    nonce: u64,
+}
+
+impl LeaseOwner {
+    /// The owner information for the current process.
+    fn current() -> Self {
+        Self {
+            host: hostname(),
+            pid: std::process::id(),
+            nonce: Self::nonce(),
+        }
+    }
+
+    /// Parse the owner from the contents of a lease file.
+    fn parse(contents: &str) -> Option<Self> {
+        let mut parts = contents.trim().rsplitn(3, ' ');
+        let nonce = parts.next()?.parse().ok()?;
+        let pid = parts.next()?.parse().ok()?;
+        let host = parts.next()?.to_string();
+        Some(Self { host, pid, nonce })
+    }
+
+    /// Returns `true` if the owner is known to have exited, i.e., it's a process on the same host
+    /// as `current` that no longer exists.
+    fn is_dead(&self, current: &Self) -> bool {
+        if self.host.is_empty() || self.host != current.host {
+            return false;
+        }
+        if self.pid == current.pid {
+            // A lease held by this process is released on drop; treat it as live.
+            return false;
+        }
+        !process_exists(self.pid)
+    }
+
+    /// A value that's unique to the current attempt, to disambiguate leases written by the same
+    /// process.
+    fn nonce() -> u64 {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos();
+        (u64::from(std::process::id()) << 32) | u64::from(nanos)
+    }
+}
+
+impl std::fmt::Display for LeaseOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.host, self.pid, self.nonce)
+    }
+}
+
+/// Return the hostname of the current machine, or an empty string if it can't be determined.
+fn hostname() -> String {
+    #[cfg(target_os = "linux")]
+    if let Ok(hostname) = fs_err::read_to_string("/proc/sys/kernel/hostname") {
+        return hostname.trim().replace(' ', "_");
+    }
+    #[cfg(windows)]
+    if let Some(hostname) = std::env::var_os("COMPUTERNAME") {
+        return hostname.to_string_lossy().replace(' ', "_");
+    }
+    String::new()
+}
+
+/// Returns `true` if a process with the given PID exists on the current host.
+///
+/// On platforms where this can't be determined, the process is assumed to exist, and stale leases
+/// are only broken after the timeout.
+fn process_exists(pid: u32) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        Path::new("/proc").join(pid.to_string()).exists()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owner_roundtrip() {
+        let owner = LeaseOwner {
+            host: "build-agent-1".to_string(),
+            pid: 1234,
+            nonce: 42,
+        };
+        assert_eq!(LeaseOwner::parse(&owner.to_string()), Some(owner));
+        assert_eq!(LeaseOwner::parse("garbage"), None);
+    }
+
+    #[test]
+    fn acquire_release() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        let lease = Lease::acquire_blocking(&path, "test").unwrap();
+        assert!(path.exists());
+        assert!(
+            Lease::try_create(&path, &LeaseOwner::current())
+                .unwrap()
+                .is_none()
+        );
+        drop(lease);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn break_stale_lease() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        let stale = "other-host 1 1";
+        fs_err::write(&path, stale).unwrap();
+        let (modified, _) = read_lease(&path).unwrap();
+        assert!(!break_lease(&path, "other-host 2 2", modified).unwrap());
+        assert!(path.exists());
+        assert!(break_lease(&path, stale, modified).unwrap());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn break_refreshed_lease() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        let owner = LeaseOwner::current();
+        fs_err::write(&path, owner.to_string()).unwrap();
+        let file = fs_err::File::open(&path).unwrap();
+        file.file()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1))
+            .unwrap();
+        let (modified, contents) = read_lease(&path).unwrap();
+
+        // The holder refreshed the lease after it was observed to be stale.
+        assert!(refresh_lease(&path, &owner, &file).unwrap());
+        assert!(!break_lease(&path, &contents, modified).unwrap());
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn heartbeat_races_break() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        for _ in 0..50 {
+            let owner = LeaseOwner::current();
+            fs_err::write(&path, owner.to_string()).unwrap();
+            let file = fs_err::File::open(&path).unwrap();
+            file.file()
+                .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1))
+                .unwrap();
+            let (modified, contents) = read_lease(&path).unwrap();
+
+            let barrier = std::sync::Barrier::new(2);
+            let (broken, refreshed) = std::thread::scope(|scope| {
+                let breaker = scope.spawn(|| {
+                    barrier.wait();
+                    break_lease(&path, &contents, modified).unwrap()
+                });
+                let heartbeat = scope.spawn(|| {
+                    barrier.wait();
+                    refresh_lease(&path, &owner, &file).unwrap()
+                });
+                (breaker.join().unwrap(), heartbeat.join().unwrap())
+            });
+
+            // Either the lease was refreshed and survives, or it was broken and the holder
+            // notices; never both.
+            assert_ne!(broken, refreshed);
+            assert_eq!(path.exists(), refreshed);
+            let _ = fs_err::remove_file(&path);
+        }
+    }
+
+    #[test]
+    fn shared_leases_exclude_writers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        let first = Lease::acquire_shared_blocking(&path, "test").unwrap();
+        let second = Lease::acquire_shared_blocking(&path, "test").unwrap();
+        assert!(!path.exists());
+        assert_eq!(shared_leases(&path).unwrap().len(), 2);
+        assert!(Lease::try_acquire(&path, "test").unwrap().is_none());
+
+        drop(first);
+        drop(second);
+        assert!(shared_leases(&path).unwrap().is_empty());
+        assert!(Lease::try_acquire(&path, "test").unwrap().is_some());
+    }
+
+    #[test]
+    fn drop_after_takeover() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        let lease = Lease::acquire_blocking(&path, "test").unwrap();
+
+        // Simulate another process breaking the lease and acquiring it.
+        let other = "other-host 1 1";
+        fs_err::remove_file(&path).unwrap();
+        fs_err::write(&path, other).unwrap();
+
+        // Releasing the original lease must not remove the new holder's lease.
+        drop(lease);
+        assert_eq!(fs_err::read_to_string(&path).unwrap(), other);
+    }
+
+    #[test]
+    fn release_after_takeover() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock.lease");
+
+        let mut lease = Lease::acquire_blocking(&path, "test").unwrap();
+        lease.check().unwrap();
+
+        // Simulate another process breaking the lease and acquiring it.
+        let other = "other-host 1 1";
+        fs_err::remove_file(&path).unwrap();
+        fs_err::write(&path, other).unwrap();
+
+        // The lost lease is reported to the holder.
+        assert!(lease.release().is_err());
+        assert_eq!(fs_err::read_to_string(&path).unwrap(), other);
+    }
+}
@@ -6,9 +6,12 @@ use fs2::FileExt;
 use tempfile::NamedTempFile;
 use tracing::{debug, error, info, trace, warn};
 
+use uv_static::EnvVars;
+
 pub use crate::path::*;
 
 pub mod cachedir;
+mod lease;
 mod path;
 pub mod which;
 
@@ -633,10 +636,156 @@ pub fn is_virtualenv_base(path: impl AsRef<Path>) -> bool {
     path.as_ref().join("pyvenv.cfg").is_file()
 }
 
+/// The protocol used to implement cross-process file locks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// Use a lease-based lock on network filesystems, and an OS-level advisory lock otherwise.
+    #[default]
+    Auto,
+    /// Always use an OS-level advisory lock (i.e., `flock` on Unix, `LockFileEx` on Windows).
+    Os,
+    /// Always use a lease-based lock, which is safe on network filesystems like NFS and SMB.
+    Lease,
+}
+
+impl LockMode {
+    /// Read the lock mode from the `UV_LOCK_MODE` environment variable.
+    fn from_env() -> Self {
+        match std::env::var(EnvVars::UV_LOCK_MODE).as_deref() {
+            Ok("os") => Self::Os,
+            Ok("lease") => Self::Lease,
+            Ok("auto") | Err(_) => Self::Auto,
+            Ok(value) => {
+                warn!(
+                    "Ignoring invalid value for `{}`: `{value}` (expected `auto`, `os`, or `lease`)",
+                    EnvVars::UV_LOCK_MODE
+                );
+                Self::Auto
+            }
+        }
+    }
+
+    /// Returns `true` if a lease-based lock should be used for the given lock file.
+    fn use_lease(self, path: &Path) -> bool {
+        match self {
+            Self::Os => false,
+            Self::Lease => true,
+            Self::Auto => path.parent().is_some_and(is_network_filesystem),
+        }
+    }
+}
+
+/// Returns `true` if the directory is known to reside on a network filesystem, on which
+/// OS-level advisory locks are unreliable.
+///
+/// On platforms on which the filesystem type can't be determined, this returns `false`; set
+/// `UV_LOCK_MODE=lease` to use lease-based locks on network filesystems there.
+fn is_network_filesystem(dir: &Path) -> bool {
+    match network_filesystem(dir) {
+        Ok(network) => network,
+        Err(err) => {
+            debug!(
+                "Failed to determine filesystem type for `{}`: {err}",
+                dir.user_display()
+            );
+            false
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn network_filesystem(dir: &Path) -> std::io::Result<bool> {
+    const NFS_SUPER_MAGIC: u32 = 0x6969;
+    const SMB_SUPER_MAGIC: u32 = 0x517B;
+    const CIFS_SUPER_MAGIC: u32 = 0xFF53_4D42;
+    const SMB2_SUPER_MAGIC: u32 = 0xFE53_4D42;
+
+    let stat = rustix::fs::statfs(dir)?;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok(matches!(
+        stat.f_type as u32,
+        NFS_SUPER_MAGIC | SMB_SUPER_MAGIC | CIFS_SUPER_MAGIC | SMB2_SUPER_MAGIC
+    ))
+}
+
+#[cfg(any(
+    target_vendor = "apple",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd"
+))]
+fn network_filesystem(dir: &Path) -> std::io::Result<bool> {
+    let stat = rustix::fs::statfs(dir)?;
+    // `c_char` is signed on some targets and unsigned on others.
+    #[allow(clippy::cast_sign_loss, clippy::unnecessary_cast)]
+    let name = stat
+        .f_fstypename
+        .iter()
+        .take_while(|&&byte| byte != 0)
+        .map(|&byte| byte as u8)
+        .collect::<Vec<u8>>();
+    Ok(matches!(
+        name.as_slice(),
+        b"nfs" | b"smbfs" | b"cifs" | b"afpfs" | b"webdav"
+    ))
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)] // We need to do an FFI call through the windows-* crates.
+fn network_filesystem(dir: &Path) -> std::io::Result<bool> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::Win32::Storage::FileSystem::{GetDriveTypeW, GetVolumePathNameW};
+    use windows_core::PCWSTR;
+
+    /// See: <https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getdrivetypew>
+    const DRIVE_REMOTE: u32 = 4;
+
+    let name = std::path::absolute(dir)?
+        .as_os_str()
+        .encode_wide()
+        .chain(Some(0))
+        .collect::<Vec<u16>>();
+    // The volume path (e.g., `Z:\` or `\\server\share\`) is never longer than the path itself.
+    let mut volume = vec![0u16; name.len() + 1];
+    // SAFETY: winapi calls; both buffers are null-terminated.
+    let drive_type = unsafe {
+        GetVolumePathNameW(PCWSTR(name.as_ptr()), &mut volume)?;
+        GetDriveTypeW(PCWSTR(volume.as_ptr()))
+    };
+    Ok(drive_type == DRIVE_REMOTE)
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_vendor = "apple",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd",
+    windows
+)))]
+fn network_filesystem(_dir: &Path) -> std::io::Result<bool> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!(
+            "filesystem detection is unsupported on this platform; set `{}=lease` to use lease-based locks",
+            EnvVars::UV_LOCK_MODE
+        ),
+    ))
+}
+
 /// A file lock that is automatically released when dropped.
 #[derive(Debug)]
 #[must_use]
-pub struct LockedFile(fs_err::File);
+pub struct LockedFile(LockedFileInner);
+
+#[derive(Debug)]
+enum LockedFileInner {
+    /// An OS-level advisory lock on the file.
+    Os(fs_err::File),
+    /// A lease on a sidecar file, for filesystems on which advisory locks are unreliable.
+    Lease(lease::Lease),
+}
 
 impl LockedFile {
     /// Inner implementation for [`LockedFile::acquire_blocking`] and [`LockedFile::acquire`].
@@ -648,7 +797,7 @@ impl LockedFile {
         match file.file().try_lock_exclusive() {
             Ok(()) => {
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self(LockedFileInner::Os(file)))
             }
             Err(err) => {
                 // Log error code and enum kind to help debugging more exotic failures.
@@ -669,7 +818,7 @@ impl LockedFile {
                 })?;
 
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self(LockedFileInner::Os(file)))
             }
         }
     }
@@ -681,22 +830,122 @@ impl LockedFile {
         path: impl AsRef<Path>,
         resource: impl Display,
     ) -> Result<Self, std::io::Error> {
-        let file = Self::create(path)?;
         let resource = resource.to_string();
+        if LockMode::from_env().use_lease(path.as_ref()) {
+            return Self::lease_blocking(path.as_ref(), &resource);
+        }
+        let file = Self::create(path)?;
         Self::lock_file_blocking(file, &resource)
     }
 
+    /// Acquire a shared lock for a resource using a file at the provided path, blocking until
+    /// any exclusive lock is released.
+    ///
+    /// On network filesystems (per [`LockMode`]), a shared lease is held on a sidecar file
+    /// instead.
+    pub fn acquire_shared_blocking(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Self, std::io::Error> {
+        let resource = resource.to_string();
+        if LockMode::from_env().use_lease(path.as_ref()) {
+            let lease =
+                lease::Lease::acquire_shared_blocking(&Self::lease_path(path.as_ref()), &resource)?;
+            return Ok(Self(LockedFileInner::Lease(lease)));
+        }
+        let file = Self::create(path)?;
+        trace!(
+            "Checking shared lock for `{resource}` at `{}`",
+            file.path().user_display()
+        );
+        FileExt::lock_shared(file.file()).map_err(|err| {
+            std::io::Error::other(format!(
+                "Could not acquire shared lock for `{resource}` at `{}`: {}",
+                file.path().user_display(),
+                err
+            ))
+        })?;
+        debug!("Acquired shared lock for `{resource}`");
+        Ok(Self(LockedFileInner::Os(file)))
+    }
+
+    /// Attempt to acquire an exclusive lock for a resource using a file at the provided path,
+    /// returning `None` if the lock (or a shared lock) is held elsewhere.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let resource = resource.to_string();
+        if LockMode::from_env().use_lease(path.as_ref()) {
+            let lease = lease::Lease::try_acquire(&Self::lease_path(path.as_ref()), &resource)?;
+            return Ok(lease.map(|lease| Self(LockedFileInner::Lease(lease))));
+        }
+        let file = Self::create(path)?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(LockedFileInner::Os(file))))
+            }
+            Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                debug!("Lock for `{resource}` is held elsewhere");
+                Ok(None)
+            }
+            Err(err) => Err(std::io::Error::other(format!(
+                "Could not acquire lock for `{resource}` at `{}`: {}",
+                file.path().user_display(),
+                err
+            ))),
+        }
+    }
+
     /// Acquire a cross-process lock for a resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire(
         path: impl AsRef<Path>,
         resource: impl Display,
     ) -> Result<Self, std::io::Error> {
-        let file = Self::create(path)?;
         let resource = resource.to_string();
+        if LockMode::from_env().use_lease(path.as_ref()) {
+            let path = path.as_ref().to_path_buf();
+            return tokio::task::spawn_blocking(move || Self::lease_blocking(&path, &resource))
+                .await?;
+        }
+        let file = Self::create(path)?;
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
 
+    /// Returns an error if the lock is no longer held, i.e., if it's a lease that was broken by
+    /// another process (e.g., because this process stalled for longer than the lease timeout).
+    ///
+    /// Callers should check the lock before committing the results of the work it guards.
+    pub fn check(&self) -> Result<(), std::io::Error> {
+        match &self.0 {
+            LockedFileInner::Os(_) => Ok(()),
+            LockedFileInner::Lease(lease) => lease.check(),
+        }
+    }
+
+    /// Release the lock, returning an error if it was lost while held.
+    pub fn release(mut self) -> Result<(), std::io::Error> {
+        match &mut self.0 {
+            LockedFileInner::Os(_) => Ok(()),
+            LockedFileInner::Lease(lease) => lease.release(),
+        }
+    }
+
+    /// Acquire a lease-based lock on a sidecar file next to the provided path.
+    fn lease_blocking(path: &Path, resource: &str) -> Result<Self, std::io::Error> {
+        let lease = lease::Lease::acquire_blocking(&Self::lease_path(path), resource)?;
+        Ok(Self(LockedFileInner::Lease(lease)))
+    }
+
+    /// Returns the path of the lease sidecar file for the provided lock file.
+    fn lease_path(path: &Path) -> PathBuf {
+        let mut lease = path.as_os_str().to_owned();
+        lease.push(".lease");
+        PathBuf::from(lease)
+    }
+
     #[cfg(unix)]
     fn create(path: impl AsRef<Path>) -> Result<fs_err::File, std::io::Error> {
         use std::os::unix::fs::PermissionsExt;
@@ -752,14 +1001,18 @@ impl LockedFile {
 
 impl Drop for LockedFile {
     fn drop(&mut self) {
-        if let Err(err) = fs2::FileExt::unlock(self.0.file()) {
+        // Leases are released when dropped.
+        let LockedFileInner::Os(file) = &self.0 else {
+            return;
+        };
+        if let Err(err) = fs2::FileExt::unlock(file.file()) {
             error!(
                 "Failed to unlock {}; program may be stuck: {}",
-                self.0.path().display(),
+                file.path().display(),
                 err
             );
         } else {
-            debug!("Released lock at `{}`", self.0.path().display());
+            debug!("Released lock at `{}`", file.path().display());
         }
     }
 }
@@ -55,6 +55,19 @@ impl GitReference {
         }
     }
 
+    /// Returns `true` if the reference can move to a different commit over time, i.e., if it
+    /// isn't a tag or a commit.
+    ///
+    /// References that are ambiguously a branch or tag are assumed to be mutable.
+    pub fn is_mutable(&self) -> bool {
+        match self {
+            Self::Branch(_) | Self::BranchOrTag(_) | Self::NamedRef(_) | Self::DefaultBranch => {
+                true
+            }
+            Self::Tag(_) | Self::BranchOrTagOrCommit(_) => false,
+        }
+    }
+
     /// Returns the kind of this reference.
     pub fn kind_str(&self) -> &str {
         match self {
@@ -78,3 +91,27 @@ impl Display for GitReference {
 fn looks_like_commit_hash(rev: &str) -> bool {
     rev.len() >= 7 && rev.chars().all(|ch| ch.is_ascii_hexdigit())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GitReference;
+
+    #[test]
+    fn mutable() {
+        // Branches move to new commits.
+        assert!(GitReference::Branch("main".to_string()).is_mutable());
+        assert!(GitReference::DefaultBranch.is_mutable());
+        assert!(GitReference::NamedRef("refs/pull/493/head".to_string()).is_mutable());
+        assert!(GitReference::from_rev("main".to_string()).is_mutable());
+
+        // References that may be a branch are assumed to move.
+        assert!(GitReference::from_rev("v1.0.0".to_string()).is_mutable());
+
+        // Tags and commits are pinned.
+        assert!(!GitReference::Tag("v1.0.0".to_string()).is_mutable());
+        assert!(
+            !GitReference::from_rev("4a23745badf5bf5ef7928f1e346e9986bd696d82".to_string())
+                .is_mutable()
+        );
+    }
+}
@@ -0,0 +1,348 @@
+//! Compressed storage for unpacked wheels and source distributions in the cache.
+//!
+//! When enabled, the files in an unpacked wheel are compressed in-place with zstd, and a marker
+//! file is written to the root of the wheel. The `.dist-info` directory is left uncompressed, as
+//! its contents are read directly from the cache (e.g., to parse the `METADATA` and `RECORD`
+//! files). Files are decompressed when they're linked into the target environment.
+//!
+//! Unpacked source distributions are compressed in the same way, except for the `PKG-INFO` and
+//! `pyproject.toml` files, which are read directly from the cache to extract static metadata.
+//! Source distributions are decompressed into a temporary directory prior to a build.
+//!
+//! Symlinks are stored as-is: they're neither compressed nor decompressed, but recreated, with
+//! the same target, when the files are decompressed.
+
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use fs_err as fs;
+use tempfile::NamedTempFile;
+use tracing::trace;
+use walkdir::WalkDir;
+
+use crate::Error;
+use crate::linker::Locks;
+
+/// The marker file indicating that the files in an unpacked wheel are zstd-compressed.
+const ZSTD_MARKER: &str = ".uv-zstd";
+
+/// The zstd compression level, which favors decompression speed over compression ratio.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Returns `true` if the unpacked wheel or source distribution at the given path is compressed.
+pub fn is_compressed(root: impl AsRef<Path>) -> bool {
+    root.as_ref().join(ZSTD_MARKER).is_file()
+}
+
+/// Compress the files in an unpacked wheel in-place.
+pub fn compress_wheel(wheel: impl AsRef<Path>) -> io::Result<()> {
+    compress_tree(wheel.as_ref(), is_uncompressed)
+}
+
+/// Compress the files in an unpacked source distribution in-place.
+pub fn compress_source_tree(source: impl AsRef<Path>) -> io::Result<()> {
+    compress_tree(source.as_ref(), is_static_metadata)
+}
+
+/// Decompress an unpacked source distribution into the given (empty) directory.
+pub fn decompress_source_tree(
+    source: impl AsRef<Path>,
+    target: impl AsRef<Path>,
+) -> io::Result<()> {
+    let source = source.as_ref();
+    for entry in WalkDir::new(source) {
+        let entry = entry.map_err(io::Error::from)?;
+        let path = entry.path();
+        let relative = path.strip_prefix(source).expect("walkdir starts with root");
+        if relative == Path::new(ZSTD_MARKER) {
+            continue;
+        }
+        let out_path = target.as_ref().join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&out_path)?;
+            continue;
+        }
+        if entry.file_type().is_symlink() {
+            copy_symlink(path, &out_path)?;
+            continue;
+        }
+
+        let mut reader = fs::File::open(path)?;
+        let mut writer = fs::File::create(&out_path)?;
+        if is_static_metadata(relative) {
+            io::copy(&mut reader, &mut writer)?;
+        } else {
+            zstd::stream::copy_decode(&mut reader, &mut writer)?;
+        }
+        writer.set_permissions(reader.metadata()?.permissions())?;
+    }
+    Ok(())
+}
+
+/// Compress the files under `root` in-place, skipping those for which `skip` returns `true`.
+fn compress_tree(root: &Path, skip: fn(&Path) -> bool) -> io::Result<()> {
+    let mut count = 0usize;
+
+    for entry in WalkDir::new(root) {
+        let entry = entry.map_err(io::Error::from)?;
+        // Skip directories and symlinks, which are stored as-is.
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let path = entry.path();
+        let relative = path.strip_prefix(root).expect("walkdir starts with root");
+        if skip(relative) {
+            continue;
+        }
+
+        // Compress to a temporary file, preserving permissions (e.g., for executables).
+        let mut reader = fs::File::open(path)?;
+        let parent = path.parent().expect("walkdir entries have a parent");
+        let mut writer = NamedTempFile::new_in(parent)?;
+        zstd::stream::copy_encode(&mut reader, &mut writer, ZSTD_LEVEL)?;
+        writer
+            .as_file()
+            .set_permissions(reader.metadata()?.permissions())?;
+        writer.persist(path).map_err(|err| err.error)?;
+
+        count += 1;
+    }
+
+    fs::write(root.join(ZSTD_MARKER), b"")?;
+    trace!("Compressed {count} files in: {}", root.display());
+
+    Ok(())
+}
+
+/// Extract a compressed wheel by decompressing all of its files into site packages.
+pub(crate) fn decompress_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+    locks: &Locks,
+) -> Result<usize, Error> {
+    let mut count = 0usize;
+
+    // Walk over the directory.
+    for entry in WalkDir::new(&wheel) {
+        let entry = entry?;
+        let path = entry.path();
+
+        let relative = path.strip_prefix(&wheel).expect("walkdir starts with root");
+        if relative == Path::new(ZSTD_MARKER) {
+            continue;
+        }
+        let out_path = site_packages.as_ref().join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&out_path)?;
+            continue;
+        }
+
+        if entry.file_type().is_symlink() {
+            copy_symlink(path, &out_path)?;
+        } else if is_uncompressed(relative) {
+            synchronized_write(path, &out_path, locks, |reader, writer| {
+                io::copy(reader, writer).map(|_| ())
+            })?;
+        } else {
+            synchronized_write(path, &out_path, locks, |reader, writer| {
+                zstd::stream::copy_decode(reader, writer)
+            })?;
+        }
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Returns `true` if the file at the given path (relative to the wheel root) is stored
+/// uncompressed.
+fn is_uncompressed(relative: &Path) -> bool {
+    relative.components().next().is_some_and(|component| {
+        Path::new(component.as_os_str())
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("dist-info"))
+    })
+}
+
+/// Returns `true` if the file at the given path (relative to the source distribution root) is read
+/// to extract static metadata, and is thus stored uncompressed.
+fn is_static_metadata(relative: &Path) -> bool {
+    relative
+        .file_name()
+        .is_some_and(|name| name == "PKG-INFO" || name == "pyproject.toml")
+}
+
+/// Recreate the symlink at `from` at `to`, with the same target.
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    fs::os::unix::fs::symlink(fs::read_link(from)?, to)
+}
+
+/// Recreate the symlink at `from` at `to`, with the same target.
+#[cfg(windows)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    let target = fs::read_link(from)?;
+    if from.is_dir() {
+        fs::os::windows::fs::symlink_dir(target, to)
+    } else {
+        fs::os::windows::fs::symlink_file(target, to)
+    }
+}
+
+/// Write the (transformed) contents of `from` to `to`, ensuring that the parent directory is
+/// locked, and preserving the permissions of `from`.
+fn synchronized_write(
+    from: &Path,
+    to: &Path,
+    locks: &Locks,
+    write: impl FnOnce(&mut fs::File, &mut fs::File) -> io::Result<()>,
+) -> io::Result<()> {
+    // Ensure we have a lock for the directory.
+    let dir_lock = {
+        let mut locks_guard = locks.0.lock().unwrap();
+        locks_guard
+            .entry(to.parent().unwrap().to_path_buf())
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone()
+    };
+
+    // Acquire a lock on the directory.
+    let _dir_guard = dir_lock.lock().unwrap();
+
+    let mut reader = fs::File::open(from)?;
+    let mut writer = fs::File::create(to)?;
+    write(&mut reader, &mut writer)?;
+    writer.set_permissions(reader.metadata()?.permissions())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let wheel = tempfile::tempdir().unwrap();
+        fs::create_dir_all(wheel.path().join("foo")).unwrap();
+        fs::create_dir_all(wheel.path().join("foo-1.0.dist-info")).unwrap();
+        fs::write(wheel.path().join("foo/__init__.py"), "print('hello')\n").unwrap();
+        fs::write(
+            wheel.path().join("foo-1.0.dist-info/METADATA"),
+            "Name: foo\n",
+        )
+        .unwrap();
+
+        compress_wheel(wheel.path()).unwrap();
+        assert!(is_compressed(wheel.path()));
+        assert_ne!(
+            fs::read(wheel.path().join("foo/__init__.py")).unwrap(),
+            b"print('hello')\n"
+        );
+        assert_eq!(
+            fs::read_to_string(wheel.path().join("foo-1.0.dist-info/METADATA")).unwrap(),
+            "Name: foo\n"
+        );
+
+        let site_packages = tempfile::tempdir().unwrap();
+        let count =
+            decompress_wheel_files(site_packages.path(), wheel.path(), &Locks::default()).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            fs::read_to_string(site_packages.path().join("foo/__init__.py")).unwrap(),
+            "print('hello')\n"
+        );
+        assert!(!site_packages.path().join(ZSTD_MARKER).exists());
+    }
+
+    #[test]
+    fn roundtrip_source_tree() {
+        let source = tempfile::tempdir().unwrap();
+        fs::create_dir_all(source.path().join("src/foo")).unwrap();
+        fs::write(
+            source.path().join("src/foo/__init__.py"),
+            "print('hello')\n",
+        )
+        .unwrap();
+        fs::write(source.path().join("PKG-INFO"), "Name: foo\n").unwrap();
+        fs::write(source.path().join("pyproject.toml"), "[project]\n").unwrap();
+
+        compress_source_tree(source.path()).unwrap();
+        assert!(is_compressed(source.path()));
+        assert_ne!(
+            fs::read(source.path().join("src/foo/__init__.py")).unwrap(),
+            b"print('hello')\n"
+        );
+        assert_eq!(
+            fs::read_to_string(source.path().join("PKG-INFO")).unwrap(),
+            "Name: foo\n"
+        );
+
+        let target = tempfile::tempdir().unwrap();
+        decompress_source_tree(source.path(), target.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(target.path().join("src/foo/__init__.py")).unwrap(),
+            "print('hello')\n"
+        );
+        assert_eq!(
+            fs::read_to_string(target.path().join("pyproject.toml")).unwrap(),
+            "[project]\n"
+        );
+        assert!(!target.path().join(ZSTD_MARKER).exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn roundtrip_source_tree_symlinks() {
+        let source = tempfile::tempdir().unwrap();
+        fs::create_dir_all(source.path().join("src/foo")).unwrap();
+        fs::write(
+            source.path().join("src/foo/__init__.py"),
+            "print('hello')\n",
+        )
+        .unwrap();
+        fs::write(source.path().join("PKG-INFO"), "Name: foo\n").unwrap();
+        fs::os::unix::fs::symlink("src/foo/__init__.py", source.path().join("link.py")).unwrap();
+        fs::os::unix::fs::symlink("src/foo", source.path().join("foo")).unwrap();
+
+        // The symlinks are left as-is, and the files they point to are only compressed once.
+        compress_source_tree(source.path()).unwrap();
+        assert_eq!(
+            fs::read_link(source.path().join("link.py")).unwrap(),
+            Path::new("src/foo/__init__.py")
+        );
+        assert_eq!(
+            fs::read_link(source.path().join("foo")).unwrap(),
+            Path::new("src/foo")
+        );
+        assert_eq!(
+            zstd::decode_all(fs::File::open(source.path().join("src/foo/__init__.py")).unwrap())
+                .unwrap(),
+            b"print('hello')\n"
+        );
+
+        // The symlinks are recreated, rather than decompressed.
+        let target = tempfile::tempdir().unwrap();
+        decompress_source_tree(source.path(), target.path()).unwrap();
+        assert_eq!(
+            fs::read_link(target.path().join("link.py")).unwrap(),
+            Path::new("src/foo/__init__.py")
+        );
+        assert_eq!(
+            fs::read_to_string(target.path().join("link.py")).unwrap(),
+            "print('hello')\n"
+        );
+        assert_eq!(
+            fs::read_link(target.path().join("foo")).unwrap(),
+            Path::new("src/foo")
+        );
+        assert_eq!(
+            fs::read_to_string(target.path().join("foo/__init__.py")).unwrap(),
+            "print('hello')\n"
+        );
+    }
+}
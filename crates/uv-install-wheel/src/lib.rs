@@ -10,11 +10,15 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
+pub use compression::{
+    compress_source_tree, compress_wheel, decompress_source_tree, is_compressed,
+};
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
 pub use wheel::{LibKind, parse_wheel_file, read_record_file};
 
+mod compression;
 mod install;
 mod linker;
 mod record;
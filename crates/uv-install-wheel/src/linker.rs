@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{Error, compression};
 use fs_err as fs;
 use fs_err::DirEntry;
 use reflink_copy as reflink;
@@ -13,7 +13,7 @@ use uv_warnings::warn_user_once;
 use walkdir::WalkDir;
 
 #[derive(Debug, Default)]
-pub struct Locks(Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>);
+pub struct Locks(pub(crate) Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
@@ -54,6 +54,10 @@ impl LinkMode {
         wheel: impl AsRef<Path>,
         locks: &Locks,
     ) -> Result<usize, Error> {
+        // Compressed wheels must be decompressed into site packages, regardless of the link mode.
+        if compression::is_compressed(&wheel) {
+            return compression::decompress_wheel_files(site_packages, wheel, locks);
+        }
         match self {
             Self::Clone => clone_wheel_files(site_packages, wheel, locks),
             Self::Copy => copy_wheel_files(site_packages, wheel, locks),
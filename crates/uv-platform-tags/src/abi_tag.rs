@@ -24,10 +24,12 @@ pub enum AbiTag {
     None,
     /// Ex) `abi3`
     Abi3,
-    /// Ex) `cp39m`, `cp310t`
+    /// Ex) `cp39m`, `cp310t`, `cp312d`
     CPython {
         gil_disabled: bool,
         python_version: (u8, u8),
+        /// Whether the ABI is that of a debug build (i.e., with `Py_DEBUG`).
+        debug: bool,
     },
     /// Ex) `pypy39_pp73`
     PyPy {
@@ -80,15 +82,17 @@ impl std::fmt::Display for AbiTag {
             Self::CPython {
                 gil_disabled,
                 python_version: (major, minor),
+                debug,
             } => {
+                let debug = if *debug { "d" } else { "" };
                 if *minor <= 7 {
-                    write!(f, "cp{major}{minor}m")
+                    write!(f, "cp{major}{minor}{debug}m")
                 } else if *gil_disabled {
                     // https://peps.python.org/pep-0703/#build-configuration-changes
                     // Python 3.13+ only, but it makes more sense to just rely on the sysconfig var.
-                    write!(f, "cp{major}{minor}t")
+                    write!(f, "cp{major}{minor}t{debug}")
                 } else {
-                    write!(f, "cp{major}{minor}")
+                    write!(f, "cp{major}{minor}{debug}")
                 }
             }
             Self::PyPy {
@@ -198,14 +202,17 @@ impl FromStr for AbiTag {
         } else if s == "abi3" {
             Ok(Self::Abi3)
         } else if let Some(cp) = s.strip_prefix("cp") {
-            // Ex) `cp39m`, `cp310t`
+            // Ex) `cp39m`, `cp310t`, `cp313td`
             let version_end = cp.find(|c: char| !c.is_ascii_digit()).unwrap_or(cp.len());
             let version_str = &cp[..version_end];
             let (major, minor) = parse_python_version(version_str, "CPython", s)?;
-            let gil_disabled = cp.ends_with('t');
+            let flags = &cp[version_end..];
+            let gil_disabled = flags.contains('t');
+            let debug = flags.contains('d');
             Ok(Self::CPython {
                 gil_disabled,
                 python_version: (major, minor),
+                debug,
             })
         } else if let Some(rest) = s.strip_prefix("pypy") {
             if let Some(rest) = rest.strip_prefix('_') {
@@ -354,6 +361,7 @@ mod tests {
         let tag = AbiTag::CPython {
             gil_disabled: false,
             python_version: (3, 9),
+            debug: false,
         };
         assert_eq!(AbiTag::from_str("cp39"), Ok(tag));
         assert_eq!(tag.to_string(), "cp39");
@@ -361,6 +369,7 @@ mod tests {
         let tag = AbiTag::CPython {
             gil_disabled: false,
             python_version: (3, 7),
+            debug: false,
         };
         assert_eq!(AbiTag::from_str("cp37m"), Ok(tag));
         assert_eq!(tag.to_string(), "cp37m");
@@ -368,10 +377,35 @@ mod tests {
         let tag = AbiTag::CPython {
             gil_disabled: true,
             python_version: (3, 13),
+            debug: false,
         };
         assert_eq!(AbiTag::from_str("cp313t"), Ok(tag));
         assert_eq!(tag.to_string(), "cp313t");
 
+        let tag = AbiTag::CPython {
+            gil_disabled: false,
+            python_version: (3, 12),
+            debug: true,
+        };
+        assert_eq!(AbiTag::from_str("cp312d"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp312d");
+
+        let tag = AbiTag::CPython {
+            gil_disabled: true,
+            python_version: (3, 13),
+            debug: true,
+        };
+        assert_eq!(AbiTag::from_str("cp313td"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp313td");
+
+        let tag = AbiTag::CPython {
+            gil_disabled: false,
+            python_version: (3, 7),
+            debug: true,
+        };
+        assert_eq!(AbiTag::from_str("cp37dm"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp37dm");
+
         assert_eq!(
             AbiTag::from_str("cpXY"),
             Err(ParseAbiTagError::MissingMajorVersion {
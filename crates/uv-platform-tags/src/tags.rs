@@ -116,8 +116,10 @@ impl Tags {
         implementation_version: (u8, u8),
         manylinux_compatible: bool,
         gil_disabled: bool,
+        debug_enabled: bool,
     ) -> Result<Self, TagsError> {
-        let implementation = Implementation::parse(implementation_name, gil_disabled)?;
+        let implementation =
+            Implementation::parse(implementation_name, gil_disabled, debug_enabled)?;
 
         // Determine the compatible tags for the current platform.
         let platform_tags = {
@@ -131,15 +133,17 @@ impl Tags {
         let mut tags = Vec::with_capacity(5 * platform_tags.len());
 
         // 1. This exact c api version
-        for platform_tag in &platform_tags {
-            tags.push((
-                implementation.language_tag(python_version),
-                implementation.abi_tag(python_version, implementation_version),
-                platform_tag.clone(),
-            ));
+        for abi_tag in implementation.abi_tags(python_version, implementation_version) {
+            for platform_tag in &platform_tags {
+                tags.push((
+                    implementation.language_tag(python_version),
+                    abi_tag,
+                    platform_tag.clone(),
+                ));
+            }
         }
         // 2. abi3 and no abi (e.g. executable binary)
-        if let Implementation::CPython { gil_disabled } = implementation {
+        if let Implementation::CPython { gil_disabled, .. } = implementation {
             // For some reason 3.2 is the minimum python for the cp abi
             for minor in (2..=python_version.1).rev() {
                 // No abi3 for free-threading python
@@ -361,7 +365,10 @@ impl std::fmt::Display for Tags {
 
 #[derive(Debug, Clone, Copy)]
 enum Implementation {
-    CPython { gil_disabled: bool },
+    CPython {
+        gil_disabled: bool,
+        debug_enabled: bool,
+    },
     PyPy,
     GraalPy,
     Pyston,
@@ -383,37 +390,55 @@ impl Implementation {
         }
     }
 
-    fn abi_tag(self, python_version: (u8, u8), implementation_version: (u8, u8)) -> AbiTag {
+    /// Returns the ABI tags for the current implementation and Python version, in order of
+    /// preference (e.g., `cp39`, or `cp312d` followed by `cp312` for a debug build).
+    fn abi_tags(self, python_version: (u8, u8), implementation_version: (u8, u8)) -> Vec<AbiTag> {
         match self {
             // Ex) `cp39`
-            Self::CPython { gil_disabled } => AbiTag::CPython {
+            Self::CPython {
                 gil_disabled,
-                python_version,
-            },
+                debug_enabled,
+            } => {
+                let abi_tag = |debug| AbiTag::CPython {
+                    gil_disabled,
+                    python_version,
+                    debug,
+                };
+                // Since Python 3.8, debug builds can also load extension modules built for
+                // release builds. <https://docs.python.org/3/using/configure.html#debug-build>
+                if debug_enabled && python_version >= (3, 8) {
+                    vec![abi_tag(true), abi_tag(false)]
+                } else {
+                    vec![abi_tag(debug_enabled)]
+                }
+            }
             // Ex) `pypy39_pp73`
-            Self::PyPy => AbiTag::PyPy {
+            Self::PyPy => vec![AbiTag::PyPy {
                 python_version: Some(python_version),
                 implementation_version,
-            },
+            }],
             // Ex) `graalpy240_310_native
-            Self::GraalPy => AbiTag::GraalPy {
+            Self::GraalPy => vec![AbiTag::GraalPy {
                 python_version,
                 implementation_version,
-            },
+            }],
             // Ex) `pyston_23_x86_64_linux`
-            Self::Pyston => AbiTag::Pyston {
+            Self::Pyston => vec![AbiTag::Pyston {
                 implementation_version,
-            },
+            }],
         }
     }
 
-    fn parse(name: &str, gil_disabled: bool) -> Result<Self, TagsError> {
+    fn parse(name: &str, gil_disabled: bool, debug_enabled: bool) -> Result<Self, TagsError> {
         if gil_disabled && name != "cpython" {
             return Err(TagsError::GilIsACPythonProblem(name.to_string()));
         }
         match name {
             // Known and supported implementations.
-            "cpython" => Ok(Self::CPython { gil_disabled }),
+            "cpython" => Ok(Self::CPython {
+                gil_disabled,
+                debug_enabled,
+            }),
             "pypy" => Ok(Self::PyPy),
             "graalpy" => Ok(Self::GraalPy),
             "pyston" => Ok(Self::Pyston),
@@ -1147,6 +1172,7 @@ mod tests {
             (3, 9),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_snapshot!(
@@ -1210,6 +1236,7 @@ mod tests {
             (3, 9),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_snapshot!(
@@ -1834,6 +1861,7 @@ mod tests {
             (3, 9),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_snapshot!(
@@ -2295,4 +2323,68 @@ mod tests {
     "###
         );
     }
+
+    /// Debug builds prefer debug ABI wheels, but can also load extension modules built for release
+    /// builds.
+    #[test]
+    fn test_system_tags_debug() {
+        let tags = Tags::from_env(
+            &Platform::new(Os::Windows, Arch::X86_64),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_snapshot!(
+            tags,
+            @r###"
+    cp312-cp312d-win_amd64
+    cp312-cp312-win_amd64
+    cp312-abi3-win_amd64
+    cp312-none-win_amd64
+    cp311-abi3-win_amd64
+    cp310-abi3-win_amd64
+    cp39-abi3-win_amd64
+    cp38-abi3-win_amd64
+    cp37-abi3-win_amd64
+    cp36-abi3-win_amd64
+    cp35-abi3-win_amd64
+    cp34-abi3-win_amd64
+    cp33-abi3-win_amd64
+    cp32-abi3-win_amd64
+    py312-none-win_amd64
+    py3-none-win_amd64
+    py311-none-win_amd64
+    py310-none-win_amd64
+    py39-none-win_amd64
+    py38-none-win_amd64
+    py37-none-win_amd64
+    py36-none-win_amd64
+    py35-none-win_amd64
+    py34-none-win_amd64
+    py33-none-win_amd64
+    py32-none-win_amd64
+    py31-none-win_amd64
+    py30-none-win_amd64
+    cp312-none-any
+    py312-none-any
+    py3-none-any
+    py311-none-any
+    py310-none-any
+    py39-none-any
+    py38-none-any
+    py37-none-any
+    py36-none-any
+    py35-none-any
+    py34-none-any
+    py33-none-any
+    py32-none-any
+    py31-none-any
+    py30-none-any
+    "###
+        );
+    }
 }
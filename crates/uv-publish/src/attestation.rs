@@ -0,0 +1,291 @@
+//! Publish attestations ([PEP 740](https://peps.python.org/pep-0740/)), signed with Sigstore.
+//!
+//! An attestation binds the SHA-256 of a distribution to the CI identity that published it: we
+//! generate an ephemeral key pair, obtain a short-lived signing certificate for the key from
+//! Fulcio using the CI provider's OIDC token, sign an in-toto statement for the distribution, and
+//! record the signature in the Rekor transparency log.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use reqwest_middleware::ClientWithMiddleware;
+use ring::rand::SystemRandom;
+use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair};
+use serde::Serialize;
+use serde_json::json;
+use thiserror::Error;
+use tracing::debug;
+
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_pypi_types::HashAlgorithm;
+use uv_sigstore::{
+    IN_TOTO_PAYLOAD_TYPE, SigstoreError, SigstoreInstance, pem_to_der, pre_authentication_encoding,
+    signing_certificate, transparency_log_entry,
+};
+use uv_static::EnvVars;
+
+use crate::hash_file;
+use crate::trusted_publishing::{TrustedPublishingError, TrustedPublishingProvider};
+
+/// The OIDC audience of Sigstore.
+const SIGSTORE_AUDIENCE: &str = "sigstore";
+
+/// The predicate type of PyPI publish attestations.
+const PUBLISH_PREDICATE_TYPE: &str = "https://docs.pypi.org/attestations/publish/v1";
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error(
+        "Attestations require an OIDC identity from GitHub Actions or GitLab CI, but {0} is not supported"
+    )]
+    UnsupportedProvider(TrustedPublishingProvider),
+    #[error(
+        "Attestations require an OIDC identity from GitHub Actions or GitLab CI, but neither was detected"
+    )]
+    MissingProvider,
+    #[error("Failed to obtain an OIDC token for Sigstore")]
+    OidcToken(#[source] Box<TrustedPublishingError>),
+    #[error(
+        "Environment variable {0} not set, is the `id_tokens` entry with `aud: sigstore` missing from the GitLab CI job?"
+    )]
+    MissingGitLabIdToken(&'static str),
+    #[error("Failed to generate a signing key")]
+    Key,
+    #[error("Failed to read signing key: `{}`", _0.user_display())]
+    ReadSigningKey(PathBuf, #[source] std::io::Error),
+    #[error(
+        "Signing key is not an unencrypted PKCS#8 ECDSA P-256 private key in PEM format: `{}`",
+        _0.user_display()
+    )]
+    InvalidSigningKey(PathBuf),
+    #[error("Failed to sign the attestation")]
+    Signature,
+    #[error(transparent)]
+    Sigstore(#[from] SigstoreError),
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// A PEP 740 attestation, in the format of the `attestations` field of the upload API.
+#[derive(Debug, Clone, Serialize)]
+pub struct Attestation {
+    version: u8,
+    verification_material: VerificationMaterial,
+    envelope: Envelope,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct VerificationMaterial {
+    /// The base64-encoded DER of the signing certificate.
+    certificate: String,
+    /// The transparency log entries, in the JSON encoding of the Sigstore protobuf specs.
+    transparency_entries: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Envelope {
+    /// The base64-encoded in-toto statement.
+    statement: String,
+    /// The base64-encoded signature over the DSSE pre-authentication encoding of the statement.
+    signature: String,
+}
+
+/// An in-toto statement for a single distribution.
+///
+/// The fields are declared in lexicographic order, such that the serialization is the canonical
+/// JSON that PyPI expects.
+#[derive(Serialize)]
+struct Statement<'a> {
+    #[serde(rename = "_type")]
+    type_: &'static str,
+    predicate: Option<()>,
+    #[serde(rename = "predicateType")]
+    predicate_type: &'static str,
+    subject: [Subject<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct Subject<'a> {
+    digest: Digest<'a>,
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+struct Digest<'a> {
+    sha256: &'a str,
+}
+
+/// Signs publish attestations with an ephemeral key and a Sigstore certificate for the CI
+/// identity.
+pub struct Attester {
+    key_pair: EcdsaKeyPair,
+    rng: SystemRandom,
+    /// The PEM-encoded signing certificate.
+    certificate: String,
+    instance: SigstoreInstance,
+}
+
+impl Attester {
+    /// Generate a signing key and obtain a certificate for it from Fulcio.
+    pub async fn new(
+        client: &ClientWithMiddleware,
+        instance: &SigstoreInstance,
+    ) -> Result<Self, AttestationError> {
+        let (key_pair, rng, certificate) = ephemeral_identity(client, instance).await?;
+        Ok(Self {
+            key_pair,
+            rng,
+            certificate,
+            instance: instance.clone(),
+        })
+    }
+
+    /// Create the publish attestation for a distribution.
+    pub async fn attest(
+        &self,
+        file: &Path,
+        filename: &DistFilename,
+        client: &ClientWithMiddleware,
+    ) -> Result<Attestation, AttestationError> {
+        let hash = hash_file(file, Hasher::from(HashAlgorithm::Sha256)).await?;
+        let name = filename.to_string();
+        let statement = serde_json::to_vec(&Statement {
+            type_: "https://in-toto.io/Statement/v1",
+            predicate: None,
+            predicate_type: PUBLISH_PREDICATE_TYPE,
+            subject: [Subject {
+                digest: Digest {
+                    sha256: &hash.digest,
+                },
+                name: &name,
+            }],
+        })?;
+
+        let signature = self
+            .key_pair
+            .sign(&self.rng, &pre_authentication_encoding(&statement))
+            .map_err(|_| AttestationError::Signature)?;
+
+        let transparency_entry = self
+            .log_entry(&statement, signature.as_ref(), client)
+            .await?;
+        debug!("Recorded attestation for {filename} in the transparency log");
+
+        Ok(Attestation {
+            version: 1,
+            verification_material: VerificationMaterial {
+                certificate: BASE64_STANDARD.encode(pem_to_der(&self.certificate)?),
+                transparency_entries: vec![transparency_entry],
+            },
+            envelope: Envelope {
+                statement: BASE64_STANDARD.encode(&statement),
+                signature: BASE64_STANDARD.encode(signature.as_ref()),
+            },
+        })
+    }
+
+    /// Record the signed statement in Rekor, returning the transparency log entry.
+    async fn log_entry(
+        &self,
+        statement: &[u8],
+        signature: &[u8],
+        client: &ClientWithMiddleware,
+    ) -> Result<serde_json::Value, AttestationError> {
+        let envelope = json!({
+            "payload": BASE64_STANDARD.encode(statement),
+            "payloadType": IN_TOTO_PAYLOAD_TYPE,
+            "signatures": [{ "sig": BASE64_STANDARD.encode(signature) }],
+        });
+        let request = json!({
+            "apiVersion": "0.0.1",
+            "kind": "dsse",
+            "spec": {
+                "proposedContent": {
+                    "envelope": envelope.to_string(),
+                    "verifiers": [BASE64_STANDARD.encode(&self.certificate)],
+                },
+            },
+        });
+        Ok(transparency_log_entry(&request, "dsse", signature, &self.instance, client).await?)
+    }
+}
+
+/// Generate an ephemeral signing key and obtain a certificate for the CI identity from Fulcio.
+///
+/// Returns the key pair, the random number generator for signing and the PEM-encoded certificate.
+///
+/// The certificate is verified to chain to the trusted root and to be issued for the key.
+pub(crate) async fn ephemeral_identity(
+    client: &ClientWithMiddleware,
+    instance: &SigstoreInstance,
+) -> Result<(EcdsaKeyPair, SystemRandom, String), AttestationError> {
+    let provider =
+        TrustedPublishingProvider::from_env().ok_or(AttestationError::MissingProvider)?;
+    let oidc_token = sigstore_oidc_token(provider, client).await?;
+
+    let rng = SystemRandom::new();
+    let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng)
+        .map_err(|_| AttestationError::Key)?;
+    let key_pair = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+        .map_err(|_| AttestationError::Key)?;
+
+    let certificate = signing_certificate(&key_pair, &rng, &oidc_token, instance, client).await?;
+    debug!("Received signing certificate for the {provider} identity");
+    Ok((key_pair, rng, certificate))
+}
+
+/// Obtain an OIDC token with the Sigstore audience from the CI provider.
+async fn sigstore_oidc_token(
+    provider: TrustedPublishingProvider,
+    client: &ClientWithMiddleware,
+) -> Result<String, AttestationError> {
+    match provider {
+        TrustedPublishingProvider::GitHubActions => {
+            let request_token =
+                env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN).map_err(|_| {
+                    AttestationError::OidcToken(Box::new(TrustedPublishingError::MissingEnvVar(
+                        EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN,
+                    )))
+                })?;
+            crate::trusted_publishing::get_oidc_token(SIGSTORE_AUDIENCE, &request_token, client)
+                .await
+                .map_err(|err| AttestationError::OidcToken(Box::new(err)))
+        }
+        TrustedPublishingProvider::GitLabCi => env::var(EnvVars::SIGSTORE_ID_TOKEN)
+            .ok()
+            .filter(|token| !token.is_empty())
+            .ok_or(AttestationError::MissingGitLabIdToken(
+                EnvVars::SIGSTORE_ID_TOKEN,
+            )),
+        TrustedPublishingProvider::Generic => Err(AttestationError::UnsupportedProvider(provider)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement() {
+        let statement = serde_json::to_string(&Statement {
+            type_: "https://in-toto.io/Statement/v1",
+            predicate: None,
+            predicate_type: PUBLISH_PREDICATE_TYPE,
+            subject: [Subject {
+                digest: Digest { sha256: "abc" },
+                name: "foo-1.0.0-py3-none-any.whl",
+            }],
+        })
+        .unwrap();
+        assert_eq!(
+            statement,
+            r#"{"_type":"https://in-toto.io/Statement/v1","predicate":null,"predicateType":"https://docs.pypi.org/attestations/publish/v1","subject":[{"digest":{"sha256":"abc"},"name":"foo-1.0.0-py3-none-any.whl"}]}"#
+        );
+    }
+}
@@ -1,10 +1,17 @@
+mod attestation;
+mod rst;
+mod signature;
+mod state;
 mod trusted_publishing;
+mod validate;
+mod yank;
 
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
-use std::{env, fmt, io};
+use std::{fmt, io};
 
+pub use attestation::{Attestation, AttestationError, Attester};
 use fs_err::tokio::File;
 use futures::TryStreamExt;
 use glob::{GlobError, PatternError, glob};
@@ -16,19 +23,24 @@ use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{RetryPolicy, Retryable, RetryableStrategy};
 use rustc_hash::FxHashSet;
 use serde::Deserialize;
+pub use signature::{Signer, read_bundle};
+pub use state::UploadState;
 use thiserror::Error;
 use tokio::io::{AsyncReadExt, BufReader};
 use tokio::sync::Semaphore;
 use tokio_util::io::ReaderStream;
 use tracing::{Level, debug, enabled, trace, warn};
+pub use trusted_publishing::TrustedPublishingProvider;
 use trusted_publishing::TrustedPublishingToken;
 use url::Url;
+pub use validate::{ValidationIssue, validate_metadata};
+pub use yank::{YankAction, yank};
 
 use uv_auth::Credentials;
 use uv_cache::{Cache, Refresh};
 use uv_client::{
-    BaseClient, DEFAULT_RETRIES, MetadataFormat, OwnedArchive, RegistryClientBuilder,
-    RequestBuilder, UvRetryableStrategy,
+    BaseClient, MetadataFormat, OwnedArchive, RegistryClientBuilder, RequestBuilder,
+    UvRetryableStrategy,
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
@@ -38,7 +50,6 @@ use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::DisplaySafeUrl;
-use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 
 use crate::trusted_publishing::TrustedPublishingError;
@@ -60,8 +71,23 @@ pub enum PublishError {
     PublishPrepare(PathBuf, #[source] Box<PublishPrepareError>),
     #[error("Failed to publish `{}` to {}", _0.user_display(), _1)]
     PublishSend(PathBuf, DisplaySafeUrl, #[source] PublishSendError),
+    #[error("Failed to {0} `{1}` on {2}")]
+    Yank(
+        YankAction,
+        String,
+        DisplaySafeUrl,
+        #[source] PublishSendError,
+    ),
+    #[error(
+        "{0} doesn't support yanking releases through its upload API, use the index's web interface instead"
+    )]
+    YankUnsupported(DisplaySafeUrl),
     #[error("Failed to obtain token for trusted publishing")]
     TrustedPublishing(#[from] TrustedPublishingError),
+    #[error("Failed to generate attestation for `{}`", _0.user_display())]
+    Attestation(PathBuf, #[source] AttestationError),
+    #[error("Failed to sign `{}`", _0.user_display())]
+    Signature(PathBuf, #[source] AttestationError),
     #[error("{0} are not allowed when using trusted publishing")]
     MixedCredentials(String),
     #[error("Failed to query check URL")]
@@ -97,6 +123,8 @@ pub enum PublishPrepareError {
     MultiplePkgInfo(String),
     #[error("Failed to read: `{0}`")]
     Read(String, #[source] io::Error),
+    #[error("Failed to serialize attestation")]
+    SerializeAttestation(#[from] serde_json::Error),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -320,17 +348,22 @@ pub async fn check_trusted_publishing(
             {
                 return Ok(TrustedPublishResult::Skipped);
             }
-            // If we aren't in GitHub Actions, we can't use trusted publishing.
-            if env::var(EnvVars::GITHUB_ACTIONS) != Ok("true".to_string()) {
+            // If we aren't in a supported CI provider and don't have an OIDC token, we can't use
+            // trusted publishing.
+            let Some(provider) = TrustedPublishingProvider::from_env() else {
                 return Ok(TrustedPublishResult::Skipped);
-            }
+            };
             // We could check for credentials from the keyring or netrc the auth middleware first, but
-            // given that we are in GitHub Actions we check for trusted publishing first.
+            // given that we are in CI we check for trusted publishing first.
             debug!(
-                "Running on GitHub Actions without explicit credentials, checking for trusted publishing"
+                "Running on {provider} without explicit credentials, checking for trusted publishing"
             );
-            match trusted_publishing::get_token(registry, client.for_host(registry).raw_client())
-                .await
+            match trusted_publishing::get_token(
+                registry,
+                client.for_host(registry).raw_client(),
+                provider,
+            )
+            .await
             {
                 Ok(token) => Ok(TrustedPublishResult::Configured(token)),
                 Err(err) => {
@@ -343,8 +376,6 @@ pub async fn check_trusted_publishing(
             }
         }
         TrustedPublishing::Always => {
-            debug!("Using trusted publishing for GitHub Actions");
-
             let mut conflicts = Vec::new();
             if username.is_some() {
                 conflicts.push("a username");
@@ -359,45 +390,76 @@ pub async fn check_trusted_publishing(
                 return Err(PublishError::MixedCredentials(conflicts.join(" and ")));
             }
 
-            if env::var(EnvVars::GITHUB_ACTIONS) != Ok("true".to_string()) {
+            let provider = TrustedPublishingProvider::from_env().unwrap_or_else(|| {
                 warn_user_once!(
-                    "Trusted publishing was requested, but you're not in GitHub Actions."
+                    "Trusted publishing was requested, but you're not in GitHub Actions or GitLab CI."
                 );
-            }
-
-            let token =
-                trusted_publishing::get_token(registry, client.for_host(registry).raw_client())
-                    .await?;
+                TrustedPublishingProvider::GitHubActions
+            });
+            debug!("Using trusted publishing for {provider}");
+
+            let token = trusted_publishing::get_token(
+                registry,
+                client.for_host(registry).raw_client(),
+                provider,
+            )
+            .await?;
             Ok(TrustedPublishResult::Configured(token))
         }
         TrustedPublishing::Never => Ok(TrustedPublishResult::Skipped),
     }
 }
 
+/// Read the metadata of a file and hash it for uploading.
+///
+/// The [`FormMetadata`] can be reused to upload the same file to multiple registries.
+pub async fn prepare_upload(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<FormMetadata, PublishError> {
+    FormMetadata::read_from_file(file, filename)
+        .await
+        .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))
+}
+
+/// Whether the registry accepts publish attestations (PEP 740) with uploads.
+///
+/// Registries don't advertise support for attestations, and registries that don't support them
+/// may reject the upload, so we only attach them for PyPI and TestPyPI.
+pub fn supports_attestations(publish_url: &DisplaySafeUrl) -> bool {
+    is_pypi(publish_url)
+}
+
+/// Whether the publish URL is PyPI or TestPyPI.
+fn is_pypi(publish_url: &DisplaySafeUrl) -> bool {
+    matches!(
+        publish_url.host_str(),
+        Some("upload.pypi.org" | "test.pypi.org")
+    )
+}
+
 /// Upload a file to a registry.
 ///
 /// Returns `true` if the file was newly uploaded and `false` if it already existed.
 ///
-/// Implements a custom retry flow since the request isn't cloneable.
+/// Implements a custom retry flow since the request isn't cloneable, using the given retry policy
+/// and strategy in lieu of the client's (which must be configured with zero retries).
 pub async fn upload(
     file: &Path,
     raw_filename: &str,
     filename: &DistFilename,
+    form_metadata: &FormMetadata,
     registry: &DisplaySafeUrl,
     client: &BaseClient,
+    retry_policy: &ExponentialBackoff,
+    retryable_strategy: &UvRetryableStrategy,
     credentials: &Credentials,
     check_url_client: Option<&CheckUrlClient<'_>>,
     download_concurrency: &Semaphore,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
-    let form_metadata = FormMetadata::read_from_file(file, filename)
-        .await
-        .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
-
     let mut n_past_retries = 0;
     let start_time = SystemTime::now();
-    // N.B. We cannot use the client policy here because it is set to zero retries
-    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(DEFAULT_RETRIES);
     loop {
         let (request, idx) = build_request(
             file,
@@ -406,18 +468,21 @@ pub async fn upload(
             registry,
             client,
             credentials,
-            &form_metadata,
+            form_metadata,
             reporter.clone(),
         )
         .await
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
         let result = request.send().await;
-        if UvRetryableStrategy.handle(&result) == Some(Retryable::Transient) {
+        // Clear the progress bar once the request is done, whether or not we retry.
+        reporter.on_upload_complete(idx);
+        if retryable_strategy.handle(&result) == Some(Retryable::Transient) {
             let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
             if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
-                warn_user!("Transient failure while handling response for {registry}; retrying...");
-                reporter.on_upload_complete(idx);
+                warn_user!(
+                    "Transient failure while uploading {filename} to {registry}; retrying..."
+                );
                 let duration = execute_after
                     .duration_since(SystemTime::now())
                     .unwrap_or_else(|_| Duration::default());
@@ -644,8 +709,9 @@ async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, Pu
     Ok(Metadata23::parse(&contents)?)
 }
 
+/// The non-file fields of the multipart upload request.
 #[derive(Debug, Clone)]
-struct FormMetadata(Vec<(&'static str, String)>);
+pub struct FormMetadata(Vec<(&'static str, String)>);
 
 impl FormMetadata {
     /// Collect the non-file fields for the multipart request from the package METADATA.
@@ -752,6 +818,37 @@ impl FormMetadata {
         Ok(Self(form_metadata))
     }
 
+    /// The hex-encoded SHA-256 digest of the file.
+    pub fn sha256_digest(&self) -> &str {
+        self.0
+            .iter()
+            .find(|(key, _)| *key == "sha256_digest")
+            .map(|(_, value)| value.as_str())
+            .expect("The SHA-256 digest is always set")
+    }
+
+    /// Add the publish attestation (PEP 740) for the file.
+    ///
+    /// The attestations are a JSON array in the `attestations` form field.
+    pub fn with_attestation(&self, attestation: &Attestation) -> Result<Self, PublishPrepareError> {
+        let mut form_metadata = self.clone();
+        form_metadata
+            .0
+            .push(("attestations", serde_json::to_string(&[attestation])?));
+        Ok(form_metadata)
+    }
+
+    /// Attach a Sigstore bundle to the upload, for registries that store signatures.
+    ///
+    /// Registries without support for signatures ignore the field.
+    pub fn with_signature(&self, bundle: &serde_json::Value) -> Result<Self, PublishPrepareError> {
+        let mut form_metadata = self.clone();
+        form_metadata
+            .0
+            .push(("sigstore_bundle", serde_json::to_string(bundle)?));
+        Ok(form_metadata)
+    }
+
     /// Returns an iterator over the metadata fields.
     fn iter(&self) -> std::slice::Iter<'_, (&'static str, String)> {
         self.0.iter()
@@ -797,6 +894,18 @@ async fn build_request<'a>(
     let part = Part::stream_with_length(file_reader, file_size).file_name(raw_filename.to_string());
     form = form.part("content", part);
 
+    let request = post_request(registry, client, credentials, form);
+
+    Ok((request, idx))
+}
+
+/// Build an authenticated POST request with a multipart form to the registry.
+fn post_request<'a>(
+    registry: &DisplaySafeUrl,
+    client: &'a BaseClient,
+    credentials: &Credentials,
+    form: reqwest::multipart::Form,
+) -> RequestBuilder<'a> {
     // If we have a username but no password, attach the username to the URL so the authentication
     // middleware can find the matching password.
     let url = if let Some(username) = credentials
@@ -835,7 +944,7 @@ async fn build_request<'a>(
         }
     }
 
-    Ok((request, idx))
+    request
 }
 
 /// Log response information and map response to an error variant if not successful.
@@ -0,0 +1,669 @@
+//! Check reStructuredText long descriptions for the problems that make PyPI reject them.
+//!
+//! PyPI renders reStructuredText with docutils and rejects the upload if rendering reports a
+//! warning or an error. We don't implement a complete reStructuredText parser, but detect the
+//! common problems, using the messages of docutils: title underlines that are too short, unknown
+//! directives and roles (e.g., from Sphinx), disabled directives, inline markup without an
+//! end-string, and references to undefined targets.
+//!
+//! Reference: <https://docutils.sourceforge.io/docs/ref/rst/restructuredtext.html>
+
+use rustc_hash::FxHashSet;
+
+/// The directives of docutils.
+const DIRECTIVES: &[&str] = &[
+    "admonition",
+    "attention",
+    "caution",
+    "class",
+    "code",
+    "code-block",
+    "compound",
+    "container",
+    "contents",
+    "csv-table",
+    "danger",
+    "date",
+    "default-role",
+    "epigraph",
+    "error",
+    "figure",
+    "footer",
+    "header",
+    "highlights",
+    "hint",
+    "image",
+    "important",
+    "include",
+    "line-block",
+    "list-table",
+    "math",
+    "meta",
+    "note",
+    "parsed-literal",
+    "pull-quote",
+    "raw",
+    "replace",
+    "role",
+    "rubric",
+    "section-numbering",
+    "sectnum",
+    "sidebar",
+    "sourcecode",
+    "table",
+    "target-notes",
+    "tip",
+    "title",
+    "topic",
+    "unicode",
+    "warning",
+];
+
+/// The directives that PyPI disables, since they access files or inject HTML.
+const DISABLED_DIRECTIVES: &[&str] = &["include", "raw"];
+
+/// The directives whose content is not reStructuredText.
+const LITERAL_DIRECTIVES: &[&str] = &["code", "code-block", "csv-table", "math", "sourcecode"];
+
+/// The interpreted text roles of docutils.
+const ROLES: &[&str] = &[
+    "ab",
+    "abbreviation",
+    "ac",
+    "acronym",
+    "code",
+    "emphasis",
+    "literal",
+    "math",
+    "pep",
+    "pep-reference",
+    "rfc",
+    "rfc-reference",
+    "strong",
+    "sub",
+    "subscript",
+    "sup",
+    "superscript",
+    "t",
+    "title",
+    "title-reference",
+];
+
+/// The characters that may precede an inline markup start-string.
+const START_PREFIXES: &str = "-:/'\"<([{";
+
+/// The characters that may follow an inline markup end-string.
+const END_SUFFIXES: &str = "-.,:;!?\\/'\")]}>";
+
+/// A problem that makes docutils fail to render the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RstProblem {
+    /// The line of the problem, starting at 1.
+    pub(crate) line: usize,
+    /// The docutils message.
+    pub(crate) message: String,
+}
+
+/// Check a reStructuredText document for problems that make docutils fail to render it.
+pub(crate) fn check(text: &str) -> Vec<RstProblem> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut problems = Vec::new();
+
+    let mut targets = titles(&lines, &mut problems);
+    let mut roles: FxHashSet<String> = ROLES.iter().map(ToString::to_string).collect();
+    for line in &lines {
+        let line = line.trim_start();
+        if let Some(name) = line.strip_prefix(".. _").and_then(target_name) {
+            targets.insert(normalize(name));
+        } else if let Some(role) = line.strip_prefix(".. role::") {
+            // A custom role, e.g., `.. role:: raw-html(raw)`.
+            let role = role.trim();
+            let name = role.split_once('(').map_or(role, |(name, _)| name);
+            roles.insert(name.trim().to_ascii_lowercase());
+        }
+    }
+
+    let mut skip = Skip::None;
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut paragraph_line = 0;
+    for (index, line) in lines.iter().enumerate() {
+        let number = index + 1;
+        let indent = indentation(line);
+        let trimmed = line.trim();
+
+        match skip {
+            Skip::Indented(block_indent) if trimmed.is_empty() || indent > block_indent => {
+                continue;
+            }
+            Skip::UntilBlank if !trimmed.is_empty() => continue,
+            _ => skip = Skip::None,
+        }
+
+        // An expanded literal block marker.
+        if trimmed == "::" {
+            check_inline(&paragraph, paragraph_line, &targets, &roles, &mut problems);
+            paragraph.clear();
+            skip = Skip::Indented(indent);
+            continue;
+        }
+
+        if trimmed.is_empty() || is_adornment(line) {
+            check_inline(&paragraph, paragraph_line, &targets, &roles, &mut problems);
+            paragraph.clear();
+            continue;
+        }
+
+        if trimmed == ".." || trimmed.starts_with(".. ") || trimmed.starts_with("__ ") {
+            check_inline(&paragraph, paragraph_line, &targets, &roles, &mut problems);
+            paragraph.clear();
+            skip = Skip::Indented(indent);
+            if let Some(name) = directive_name(trimmed) {
+                let name = name.to_ascii_lowercase();
+                if !DIRECTIVES.contains(&name.as_str()) {
+                    problems.push(RstProblem {
+                        line: number,
+                        message: format!("Unknown directive type \"{name}\"."),
+                    });
+                } else if DISABLED_DIRECTIVES.contains(&name.as_str()) {
+                    problems.push(RstProblem {
+                        line: number,
+                        message: format!("\"{name}\" directive disabled."),
+                    });
+                } else if !LITERAL_DIRECTIVES.contains(&name.as_str()) {
+                    // Skip the arguments and options, but check the content.
+                    skip = Skip::UntilBlank;
+                }
+            }
+            continue;
+        }
+
+        // Doctest blocks are literal.
+        if paragraph.is_empty() && trimmed.starts_with(">>>") {
+            skip = Skip::UntilBlank;
+            continue;
+        }
+
+        if paragraph.is_empty() {
+            paragraph_line = number;
+        }
+        paragraph.push(trimmed);
+
+        // The indented block after a paragraph that ends with `::` is a literal block.
+        if trimmed.ends_with("::") {
+            check_inline(&paragraph, paragraph_line, &targets, &roles, &mut problems);
+            paragraph.clear();
+            skip = Skip::Indented(indent);
+        }
+    }
+    check_inline(&paragraph, paragraph_line, &targets, &roles, &mut problems);
+
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
+/// The lines to skip, because they're not reStructuredText or we don't check them.
+#[derive(Debug, Clone, Copy)]
+enum Skip {
+    None,
+    /// Skip the blank lines and the lines that are indented further than the given indentation,
+    /// e.g., a literal block.
+    Indented(usize),
+    /// Skip the lines until the next blank line, e.g., the options of a directive.
+    UntilBlank,
+}
+
+/// Check the section titles, returning their names, which are implicit hyperlink targets.
+fn titles(lines: &[&str], problems: &mut Vec<RstProblem>) -> FxHashSet<String> {
+    let mut targets = FxHashSet::default();
+    for (index, underline) in lines.iter().enumerate().skip(1) {
+        let title = lines[index - 1];
+        if !is_adornment(underline) || title.trim().is_empty() || is_adornment(title) {
+            continue;
+        }
+        let underline = underline.trim_end();
+        let overline = index
+            .checked_sub(2)
+            .map(|index| lines[index])
+            .filter(|line| is_adornment(line))
+            .map(str::trim_end);
+        let text = title.trim();
+        let width = text.chars().count();
+
+        if let Some(overline) = overline {
+            if overline != underline {
+                problems.push(RstProblem {
+                    line: index + 1,
+                    message: "Title overline & underline mismatch.".to_string(),
+                });
+            } else if overline.len() < title.trim_end().chars().count() {
+                problems.push(RstProblem {
+                    line: index - 1,
+                    message: "Title overline too short.".to_string(),
+                });
+            }
+        } else {
+            // A title is the first line of a paragraph and isn't indented.
+            let starts_paragraph = index < 2 || lines[index - 2].trim().is_empty();
+            if !starts_paragraph || indentation(title) > 0 {
+                continue;
+            }
+            if underline.len() < width {
+                // Short underlines are treated as ordinary text.
+                if underline.len() < 4 {
+                    continue;
+                }
+                problems.push(RstProblem {
+                    line: index + 1,
+                    message: "Title underline too short.".to_string(),
+                });
+            }
+        }
+        targets.insert(normalize(text));
+    }
+    targets
+}
+
+/// Whether the line is a section title adornment or a transition, e.g., `=====`.
+fn is_adornment(line: &str) -> bool {
+    let line = line.trim_end();
+    let mut chars = line.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_punctuation() && chars.all(|char| char == first))
+}
+
+/// The number of leading whitespace characters.
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// The name of the directive in an explicit markup block, e.g., `code-block` in
+/// `.. code-block:: python` or `image` in `.. |logo| image:: logo.png`.
+fn directive_name(line: &str) -> Option<&str> {
+    let mut rest = line.strip_prefix("..")?.trim_start();
+    // A substitution definition.
+    if let Some(substitution) = rest.strip_prefix('|') {
+        rest = substitution.split_once("| ")?.1.trim_start();
+    }
+    let (name, _) = rest.split_once("::")?;
+    let valid = !name.is_empty()
+        && name.chars().next().is_some_and(char::is_alphanumeric)
+        && name
+            .chars()
+            .all(|char| char.is_alphanumeric() || "-_.:+".contains(char));
+    valid.then_some(name)
+}
+
+/// The name of an explicit hyperlink target, e.g., `foo` in `.. _foo: https://example.com`.
+fn target_name(target: &str) -> Option<&str> {
+    if let Some(quoted) = target.strip_prefix('`') {
+        return quoted.split_once("`:").map(|(name, _)| name);
+    }
+    let end = target
+        .find(": ")
+        .or_else(|| target.strip_suffix(':').map(str::len))?;
+    // Anonymous targets (`.. __: https://example.com`) can't be referenced by name.
+    Some(&target[..end]).filter(|name| *name != "_")
+}
+
+/// Normalize a reference name: case-insensitive, with whitespace collapsed.
+fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Check the inline markup of a paragraph.
+fn check_inline(
+    paragraph: &[&str],
+    line: usize,
+    targets: &FxHashSet<String>,
+    roles: &FxHashSet<String>,
+    problems: &mut Vec<RstProblem>,
+) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let chars: Vec<char> = paragraph.join("\n").chars().collect();
+    let mut report = |message: String| problems.push(RstProblem { line, message });
+
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if !is_start(&chars, i) {
+            i += 1;
+            continue;
+        }
+
+        // Standalone hyperlinks, which may contain markup characters.
+        if let Some(end) = uri_end(&chars, i) {
+            i = end;
+            continue;
+        }
+
+        if chars[i..].starts_with(&['`', '`']) && opens(&chars, i, 2) {
+            if let Some(end) = find_end(&chars, i + 2, &['`', '`'], true) {
+                i = end + 2;
+            } else {
+                report("Inline literal start-string without end-string.".to_string());
+                i += 2;
+            }
+        } else if chars[i..].starts_with(&['*', '*']) && opens(&chars, i, 2) {
+            if let Some(end) = find_end(&chars, i + 2, &['*', '*'], false) {
+                i = end + 2;
+            } else {
+                report("Inline strong start-string without end-string.".to_string());
+                i += 2;
+            }
+        } else if chars[i] == '*' && opens(&chars, i, 1) {
+            if let Some(end) = find_end(&chars, i + 1, &['*'], false) {
+                i = end + 1;
+            } else {
+                report("Inline emphasis start-string without end-string.".to_string());
+                i += 1;
+            }
+        } else if chars[i] == '`' && opens(&chars, i, 1) {
+            i = check_interpreted(&chars, i, targets, roles, &mut report);
+        } else if chars[i] == ':' {
+            // An interpreted text role before the text, e.g., ``:pep:`8` ``.
+            let name_end = simple_name_end(&chars, i + 1);
+            if name_end > i + 1
+                && chars.get(name_end) == Some(&':')
+                && chars.get(name_end + 1) == Some(&'`')
+            {
+                let name: String = chars[i + 1..name_end].iter().collect();
+                check_role(&name, roles, &mut report);
+                i = check_interpreted(&chars, name_end + 1, targets, roles, &mut report);
+            } else {
+                i += 1;
+            }
+        } else if chars[i].is_alphanumeric() {
+            // A simple reference, e.g., `foo_`, but not an anonymous reference, e.g., `foo__`.
+            let end = simple_name_end(&chars, i);
+            if chars.get(end) == Some(&'_')
+                && chars.get(end + 1) != Some(&'_')
+                && is_end_suffix(&chars, end + 1)
+            {
+                let name: String = chars[i..end].iter().collect();
+                check_target(&name, targets, &mut report);
+                i = end + 1;
+            } else {
+                i = end.max(i + 1);
+            }
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Check interpreted text or a phrase reference starting at the backquote at `start`, returning
+/// the position after it.
+fn check_interpreted(
+    chars: &[char],
+    start: usize,
+    targets: &FxHashSet<String>,
+    roles: &FxHashSet<String>,
+    report: &mut impl FnMut(String),
+) -> usize {
+    let Some(end) = (start + 1..chars.len()).find(|&end| {
+        chars[end] == '`'
+            && end > start + 1
+            && !chars[end - 1].is_whitespace()
+            && chars[end - 1] != '\\'
+            && is_end_suffix(chars, interpreted_suffix_end(chars, end + 1))
+    }) else {
+        report(
+            "Inline interpreted text or phrase reference start-string without end-string."
+                .to_string(),
+        );
+        return start + 1;
+    };
+
+    let after = end + 1;
+    if chars.get(after) == Some(&'_') && chars.get(after + 1) != Some(&'_') {
+        // A phrase reference, unless it embeds the URI, e.g., `` `uv <https://docs.astral.sh>`_ ``.
+        let text: String = chars[start + 1..end].iter().collect();
+        if !(text.ends_with('>') && text.contains('<')) {
+            check_target(&text, targets, report);
+        }
+    } else if chars.get(after) == Some(&':') {
+        // An interpreted text role after the text, e.g., `` `8`:pep: ``.
+        let name_end = simple_name_end(chars, after + 1);
+        if name_end > after + 1 && chars.get(name_end) == Some(&':') {
+            let name: String = chars[after + 1..name_end].iter().collect();
+            check_role(&name, roles, report);
+        }
+    }
+    interpreted_suffix_end(chars, after)
+}
+
+/// Skip the reference or role suffix of interpreted text, e.g., `_`, `__` or `:pep:`.
+fn interpreted_suffix_end(chars: &[char], after: usize) -> usize {
+    if chars[after.min(chars.len())..].starts_with(&['_', '_']) {
+        return after + 2;
+    }
+    match chars.get(after) {
+        Some('_') => after + 1,
+        Some(':') => {
+            let name_end = simple_name_end(chars, after + 1);
+            if name_end > after + 1 && chars.get(name_end) == Some(&':') {
+                name_end + 1
+            } else {
+                after
+            }
+        }
+        _ => after,
+    }
+}
+
+fn check_role(name: &str, roles: &FxHashSet<String>, report: &mut impl FnMut(String)) {
+    let name = name.to_ascii_lowercase();
+    if !roles.contains(&name) {
+        report(format!("Unknown interpreted text role \"{name}\"."));
+    }
+}
+
+fn check_target(name: &str, targets: &FxHashSet<String>, report: &mut impl FnMut(String)) {
+    let name = normalize(name);
+    if !targets.contains(&name) {
+        report(format!("Unknown target name: \"{name}\"."));
+    }
+}
+
+/// Whether inline markup can start at the position, based on the preceding character.
+fn is_start(chars: &[char], i: usize) -> bool {
+    i == 0 || chars[i - 1].is_whitespace() || START_PREFIXES.contains(chars[i - 1])
+}
+
+/// Whether the start-string of length `len` at `i` is followed by text, and not enclosed by
+/// matching quotes or brackets, e.g., `"*"`.
+fn opens(chars: &[char], i: usize, len: usize) -> bool {
+    let Some(&next) = chars.get(i + len) else {
+        return false;
+    };
+    if next.is_whitespace() {
+        return false;
+    }
+    let enclosed = i > 0
+        && matches!(
+            (chars[i - 1], next),
+            ('\'', '\'') | ('"', '"') | ('<', '>') | ('(', ')') | ('[', ']') | ('{', '}')
+        );
+    !enclosed
+}
+
+/// Whether an inline markup end-string can end before the position.
+fn is_end_suffix(chars: &[char], i: usize) -> bool {
+    chars
+        .get(i)
+        .is_none_or(|&char| char.is_whitespace() || END_SUFFIXES.contains(char))
+}
+
+/// Find the end-string of inline markup, starting the search at `from`.
+fn find_end(chars: &[char], from: usize, delimiter: &[char], literal: bool) -> Option<usize> {
+    (from + 1..=chars.len().saturating_sub(delimiter.len())).find(|&end| {
+        chars[end..].starts_with(delimiter)
+            && !chars[end - 1].is_whitespace()
+            && (literal || chars[end - 1] != '\\')
+            && is_end_suffix(chars, end + delimiter.len())
+    })
+}
+
+/// The end of a simple reference name, which consists of alphanumerics separated by single
+/// hyphens, underscores, periods, colons or plus signs.
+fn simple_name_end(chars: &[char], start: usize) -> usize {
+    let mut end = start;
+    while end < chars.len() {
+        let separator = end > start
+            && "-_.:+".contains(chars[end])
+            && chars
+                .get(end + 1)
+                .is_some_and(|char| char.is_alphanumeric());
+        if !(chars[end].is_alphanumeric() || separator) {
+            break;
+        }
+        end += 1;
+    }
+    end
+}
+
+/// The end of a standalone hyperlink starting at `i`, e.g., `https://example.com/foo_`.
+fn uri_end(chars: &[char], i: usize) -> Option<usize> {
+    let scheme_end = (i..chars.len())
+        .take_while(|&end| chars[end].is_ascii_alphanumeric() || "+.-".contains(chars[end]))
+        .last()?
+        + 1;
+    let scheme: String = chars[i..scheme_end].iter().collect();
+    let rest = &chars[scheme_end..];
+    if !(rest.starts_with(&[':', '/', '/']) || (scheme == "mailto" && rest.starts_with(&[':']))) {
+        return None;
+    }
+    Some(
+        (scheme_end..chars.len())
+            .find(|&end| chars[end].is_whitespace() || chars[end] == '>' || chars[end] == '`')
+            .unwrap_or(chars.len()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(text: &str) -> Vec<String> {
+        check(text)
+            .into_iter()
+            .map(|problem| format!("line {}: {}", problem.line, problem.message))
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        let text = r"
+=====
+ Foo
+=====
+
+Foo is a *package* with **bold** claims, ``literal`` text and `links <https://example.com>`_.
+
+Usage
+-----
+
+See the usage_, `the usage`_ and :pep:`8`, or https://example.com/foo_ and
+``f(*args)``.
+
+.. _the usage: https://example.com/usage
+.. _usage: https://example.com/usage
+
+.. code-block:: python
+
+    print(*args, `foo`_)
+
+.. note::
+
+   This is a :strong:`note`.
+
+Example::
+
+    :func:`foo` **kwargs
+
+::
+
+    *args
+
+.. image:: https://example.com/logo_.png
+   :alt: logo_
+
+>>> print(*args)
+
+.. role:: custom
+
+This is :custom:`custom`.
+
+2 * 3 = 6, x_y is fine, as is \*escaped, and ``*`` is literal.
+";
+        assert_eq!(messages(text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn title_underline_too_short() {
+        assert_eq!(
+            messages("A long title\n=====\n\nText\n"),
+            ["line 2: Title underline too short."]
+        );
+        // Very short underlines are treated as ordinary text.
+        assert_eq!(messages("A long title\n===\n"), Vec::<String>::new());
+        assert_eq!(
+            messages("=====\nTitle\n=======\n"),
+            ["line 3: Title overline & underline mismatch."]
+        );
+    }
+
+    #[test]
+    fn unknown_directive_and_role() {
+        let text = "
+.. toctree::
+   :maxdepth: 2
+
+.. raw:: html
+
+    <b>Foo</b>
+
+See :ref:`usage` and :py:func:`foo`.
+";
+        assert_eq!(
+            messages(text),
+            [
+                "line 2: Unknown directive type \"toctree\".",
+                "line 5: \"raw\" directive disabled.",
+                "line 9: Unknown interpreted text role \"ref\".",
+                "line 9: Unknown interpreted text role \"py:func\".",
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_inline_markup() {
+        assert_eq!(
+            messages("Use ``foo\n\nUse **bar\n\nUse *args and **kwargs\n\nUse `baz\n"),
+            [
+                "line 1: Inline literal start-string without end-string.",
+                "line 3: Inline strong start-string without end-string.",
+                "line 5: Inline emphasis start-string without end-string.",
+                "line 5: Inline strong start-string without end-string.",
+                "line 7: Inline interpreted text or phrase reference start-string without end-string.",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_target() {
+        assert_eq!(
+            messages("See the `Usage Guide`_ and foo_.\n\n.. _usage guide: https://example.com\n"),
+            ["line 1: Unknown target name: \"foo\"."]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Track the files that a registry has accepted, such that publishing can be resumed.
+//!
+//! Not all registries support checking for existing files, and uploading an existing file fails on
+//! most of them, so when publishing fails in the middle, e.g., due to a network drop, retrying the
+//! same command would fail for the files that were already uploaded.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_redacted::DisplaySafeUrl;
+
+/// The files that a registry has accepted, by filename, with their SHA-256 digest.
+#[derive(Debug)]
+pub struct UploadState {
+    path: PathBuf,
+    accepted: Mutex<FxHashMap<String, String>>,
+}
+
+impl UploadState {
+    /// Read the state for a registry from the cache.
+    ///
+    /// A missing or unreadable state is treated as empty.
+    pub fn read(cache: &Cache, registry: &DisplaySafeUrl) -> Self {
+        let path = cache.bucket(CacheBucket::Publish).join(format!(
+            "{}.json",
+            cache_digest(&Url::from(registry.clone()))
+        ));
+        let accepted = match fs_err::read(&path) {
+            Ok(contents) => serde_json::from_slice(&contents).unwrap_or_else(|err| {
+                debug!("Ignoring invalid upload state at {}: {err}", path.display());
+                FxHashMap::default()
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => FxHashMap::default(),
+            Err(err) => {
+                debug!(
+                    "Ignoring unreadable upload state at {}: {err}",
+                    path.display()
+                );
+                FxHashMap::default()
+            }
+        };
+        Self {
+            path,
+            accepted: Mutex::new(accepted),
+        }
+    }
+
+    /// Whether the registry accepted a file with the same name and contents in a previous run.
+    pub fn is_accepted(&self, filename: &str, sha256: &str) -> bool {
+        self.accepted
+            .lock()
+            .unwrap()
+            .get(filename)
+            .is_some_and(|digest| digest == sha256)
+    }
+
+    /// Record that the registry accepted a file.
+    pub fn accept(&self, filename: &str, sha256: &str) -> Result<(), io::Error> {
+        let mut accepted = self.accepted.lock().unwrap();
+        accepted.insert(filename.to_string(), sha256.to_string());
+        let contents = serde_json::to_vec(&*accepted)?;
+        fs_err::create_dir_all(self.path.parent().expect("State file has a parent"))?;
+        uv_fs::write_atomic_sync(&self.path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let cache = Cache::temp().unwrap();
+        let registry = DisplaySafeUrl::parse("https://upload.pypi.org/legacy/").unwrap();
+
+        let state = UploadState::read(&cache, &registry);
+        assert!(!state.is_accepted("foo-1.0.0-py3-none-any.whl", "abc"));
+        state.accept("foo-1.0.0-py3-none-any.whl", "abc").unwrap();
+
+        let state = UploadState::read(&cache, &registry);
+        assert!(state.is_accepted("foo-1.0.0-py3-none-any.whl", "abc"));
+        // A rebuilt file with different contents isn't skipped.
+        assert!(!state.is_accepted("foo-1.0.0-py3-none-any.whl", "def"));
+
+        // The state is tracked per registry.
+        let registry = DisplaySafeUrl::parse("https://test.pypi.org/legacy/").unwrap();
+        let state = UploadState::read(&cache, &registry);
+        assert!(!state.is_accepted("foo-1.0.0-py3-none-any.whl", "abc"));
+    }
+}
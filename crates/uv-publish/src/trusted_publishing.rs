@@ -1,4 +1,4 @@
-//! Trusted publishing (via OIDC) with GitHub actions.
+//! Trusted publishing (via OIDC) with GitHub Actions, GitLab CI, or a user-provided OIDC token.
 
 use base64::Engine;
 use base64::prelude::BASE64_URL_SAFE_NO_PAD;
@@ -19,6 +19,12 @@ use uv_static::EnvVars;
 pub enum TrustedPublishingError {
     #[error("Environment variable {0} not set, is the `id-token: write` permission missing?")]
     MissingEnvVar(&'static str),
+    #[error(
+        "Environment variable {0} not set, is the `id_tokens` entry with `aud: {1}` missing from the GitLab CI job?"
+    )]
+    MissingGitLabIdToken(String, String),
+    #[error("Environment variable {0} is not valid UTF-8: `{1:?}`")]
+    InvalidGitLabIdToken(String, OsString),
     #[error("Environment variable {0} is not valid UTF-8: `{1:?}`")]
     InvalidEnvVar(&'static str, OsString),
     #[error(transparent)]
@@ -47,6 +53,44 @@ impl TrustedPublishingError {
     }
 }
 
+/// The source of the OIDC token that is exchanged for an upload token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustedPublishingProvider {
+    /// Request the OIDC token from the GitHub Actions token endpoint.
+    GitHubActions,
+    /// Read the OIDC token from the ID token that GitLab CI provides for the job.
+    GitLabCi,
+    /// Read the OIDC token from `UV_PUBLISH_OIDC_TOKEN`, e.g., for other CI providers.
+    Generic,
+}
+
+impl TrustedPublishingProvider {
+    /// Detect the provider of the OIDC token from the environment.
+    ///
+    /// An explicitly provided OIDC token takes precedence over the CI provider.
+    pub fn from_env() -> Option<Self> {
+        if env::var_os(EnvVars::UV_PUBLISH_OIDC_TOKEN).is_some_and(|token| !token.is_empty()) {
+            Some(Self::Generic)
+        } else if env::var(EnvVars::GITHUB_ACTIONS) == Ok("true".to_string()) {
+            Some(Self::GitHubActions)
+        } else if env::var(EnvVars::GITLAB_CI) == Ok("true".to_string()) {
+            Some(Self::GitLabCi)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for TrustedPublishingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHubActions => write!(f, "GitHub Actions"),
+            Self::GitLabCi => write!(f, "GitLab CI"),
+            Self::Generic => write!(f, "`{}`", EnvVars::UV_PUBLISH_OIDC_TOKEN),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(transparent)]
 pub struct TrustedPublishingToken(String);
@@ -83,37 +127,68 @@ struct PublishToken {
 
 /// The payload of the OIDC token.
 #[derive(Deserialize, Debug)]
+#[serde(untagged)]
 #[allow(dead_code)]
-pub struct OidcTokenClaims {
-    sub: String,
-    repository: String,
-    repository_owner: String,
-    repository_owner_id: String,
-    job_workflow_ref: String,
-    r#ref: String,
+pub enum OidcTokenClaims {
+    GitHubActions {
+        sub: String,
+        repository: String,
+        repository_owner: String,
+        repository_owner_id: String,
+        job_workflow_ref: String,
+        r#ref: String,
+    },
+    GitLabCi {
+        sub: String,
+        project_path: String,
+        ci_config_ref_uri: String,
+        environment: Option<String>,
+    },
+    Other {
+        iss: String,
+        sub: String,
+    },
 }
 
 /// Returns the short-lived token to use for uploading.
 pub(crate) async fn get_token(
     registry: &DisplaySafeUrl,
     client: &ClientWithMiddleware,
+    provider: TrustedPublishingProvider,
 ) -> Result<TrustedPublishingToken, TrustedPublishingError> {
-    // If this fails, we can skip the audience request.
-    let oidc_token_request_token =
-        env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN).map_err(|err| {
-            TrustedPublishingError::from_var_err(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN, err)
-        })?;
+    let oidc_token = match provider {
+        TrustedPublishingProvider::GitHubActions => {
+            // If this fails, we can skip the audience request.
+            let oidc_token_request_token = env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN)
+                .map_err(|err| {
+                    TrustedPublishingError::from_var_err(
+                        EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN,
+                        err,
+                    )
+                })?;
 
-    // Request 1: Get the audience
-    let audience = get_audience(registry, client).await?;
+            // Request 1: Get the audience
+            let audience = get_audience(registry, client).await?;
 
-    // Request 2: Get the OIDC token from GitHub.
-    let oidc_token = get_oidc_token(&audience, &oidc_token_request_token, client).await?;
+            // Request 2: Get the OIDC token from GitHub.
+            get_oidc_token(&audience, &oidc_token_request_token, client).await?
+        }
+        TrustedPublishingProvider::GitLabCi => {
+            // Request 1: Get the audience, which determines the name of the ID token variable.
+            let audience = get_audience(registry, client).await?;
+            get_gitlab_id_token(&audience)?
+        }
+        TrustedPublishingProvider::Generic => {
+            env::var(EnvVars::UV_PUBLISH_OIDC_TOKEN).map_err(|err| {
+                TrustedPublishingError::from_var_err(EnvVars::UV_PUBLISH_OIDC_TOKEN, err)
+            })?
+        }
+    };
 
     // Request 3: Get the publishing token from PyPI.
     let publish_token = get_publish_token(registry, &oidc_token, client).await?;
 
-    debug!("Received token, using trusted publishing");
+    debug!("Received token from {provider}, using trusted publishing");
 
     // Tell GitHub Actions to mask the token in any console logs.
     #[allow(clippy::print_stdout)]
@@ -148,7 +223,7 @@ async fn get_audience(
     Ok(audience.audience)
 }
 
-async fn get_oidc_token(
+pub(crate) async fn get_oidc_token(
     audience: &str,
     oidc_token_request_token: &str,
     client: &ClientWithMiddleware,
@@ -177,6 +252,29 @@ async fn get_oidc_token(
     Ok(oidc_token.value)
 }
 
+/// Read the OIDC token that GitLab CI provides through the job's `id_tokens`.
+///
+/// Following `pypa/gh-action-pypi-publish` and the `id` package, the variable is named after the
+/// audience, e.g., `PYPI_ID_TOKEN` for the `pypi` audience.
+#[allow(clippy::result_large_err)]
+fn get_gitlab_id_token(audience: &str) -> Result<String, TrustedPublishingError> {
+    let var = format!(
+        "{}_ID_TOKEN",
+        audience.to_ascii_uppercase().replace(['-', '.'], "_")
+    );
+    debug!("Reading the trusted publishing OIDC token from `{var}`");
+    match env::var(&var) {
+        Ok(token) if !token.is_empty() => Ok(token),
+        Ok(_) | Err(VarError::NotPresent) => Err(TrustedPublishingError::MissingGitLabIdToken(
+            var,
+            audience.to_string(),
+        )),
+        Err(VarError::NotUnicode(os_string)) => {
+            Err(TrustedPublishingError::InvalidGitLabIdToken(var, os_string))
+        }
+    }
+}
+
 /// Parse the JSON Web Token that the OIDC token is.
 ///
 /// See: <https://github.com/pypa/gh-action-pypi-publish/blob/db8f07d3871a0a180efa06b95d467625c19d5d5f/oidc-exchange.py#L165-L184>
@@ -243,3 +341,43 @@ async fn get_publish_token(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode the claims as the payload of an (unsigned) JSON Web Token.
+    fn token(claims: &str) -> String {
+        format!("e30.{}.signature", BASE64_URL_SAFE_NO_PAD.encode(claims))
+    }
+
+    #[test]
+    fn decode_claims() {
+        let github = token(
+            r#"{"sub": "repo:astral-sh/uv:ref:refs/heads/main", "repository": "astral-sh/uv", "repository_owner": "astral-sh", "repository_owner_id": "1", "job_workflow_ref": "astral-sh/uv/.github/workflows/release.yml@refs/heads/main", "ref": "refs/heads/main"}"#,
+        );
+        assert!(matches!(
+            decode_oidc_token(&github),
+            Some(OidcTokenClaims::GitHubActions { .. })
+        ));
+
+        let gitlab = token(
+            r#"{"sub": "project_path:group/project:ref_type:branch:ref:main", "project_path": "group/project", "ci_config_ref_uri": "gitlab.com/group/project//.gitlab-ci.yml@refs/heads/main"}"#,
+        );
+        assert!(matches!(
+            decode_oidc_token(&gitlab),
+            Some(OidcTokenClaims::GitLabCi {
+                environment: None,
+                ..
+            })
+        ));
+
+        let other = token(r#"{"iss": "https://example.com", "sub": "project"}"#);
+        assert!(matches!(
+            decode_oidc_token(&other),
+            Some(OidcTokenClaims::Other { .. })
+        ));
+
+        assert!(decode_oidc_token("not-a-token").is_none());
+    }
+}
@@ -0,0 +1,303 @@
+//! Validate distributions before uploading them, in the style of `twine check`.
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use uv_distribution_filename::DistFilename;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::Metadata23;
+
+use crate::{PublishPrepareError, metadata, rst};
+
+/// The metadata versions accepted by PyPI.
+const METADATA_VERSIONS: &[&str] = &["1.0", "1.1", "1.2", "2.0", "2.1", "2.2", "2.3", "2.4"];
+
+/// The description content types that PyPI can render.
+const CONTENT_TYPES: &[&str] = &["text/plain", "text/x-rst", "text/markdown"];
+
+/// The Markdown variants that PyPI can render.
+const MARKDOWN_VARIANTS: &[&str] = &["gfm", "commonmark"];
+
+/// PyPI rejects summaries that are longer than this.
+const MAX_SUMMARY_LENGTH: usize = 512;
+
+/// A problem with a distribution that was found before uploading it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// The metadata version is not accepted by the index.
+    UnsupportedMetadataVersion(String),
+    /// The name in the metadata doesn't match the name in the filename.
+    NameMismatch {
+        filename: PackageName,
+        metadata: String,
+    },
+    /// The version in the metadata doesn't match the version in the filename.
+    VersionMismatch { filename: Version, metadata: String },
+    /// The summary spans multiple lines or is too long.
+    InvalidSummary,
+    /// The description content type can't be rendered by the index.
+    InvalidContentType(String),
+    /// The long description fails to render as reStructuredText.
+    InvalidDescription { line: usize, message: String },
+    /// A file with the same name was passed more than once.
+    DuplicateFilename,
+    /// The index has a file with the same name, but different contents.
+    ExistsWithDifferentContents,
+    /// The summary is missing.
+    MissingSummary,
+    /// The long description is missing.
+    MissingDescription,
+    /// The long description has no content type, so the index renders it as reStructuredText.
+    MissingContentType,
+    /// The index already has an identical file, so the upload would be skipped.
+    AlreadyExists,
+}
+
+impl ValidationIssue {
+    /// Whether the issue would cause the upload to fail, or is only a warning.
+    pub fn is_error(&self) -> bool {
+        match self {
+            Self::UnsupportedMetadataVersion(_)
+            | Self::NameMismatch { .. }
+            | Self::VersionMismatch { .. }
+            | Self::InvalidSummary
+            | Self::InvalidContentType(_)
+            | Self::InvalidDescription { .. }
+            | Self::DuplicateFilename
+            | Self::ExistsWithDifferentContents => true,
+            Self::MissingSummary
+            | Self::MissingDescription
+            | Self::MissingContentType
+            | Self::AlreadyExists => false,
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedMetadataVersion(version) => {
+                write!(f, "Unsupported metadata version: `{version}`")
+            }
+            Self::NameMismatch { filename, metadata } => write!(
+                f,
+                "The name in the metadata (`{metadata}`) doesn't match the name in the filename (`{filename}`)"
+            ),
+            Self::VersionMismatch { filename, metadata } => write!(
+                f,
+                "The version in the metadata (`{metadata}`) doesn't match the version in the filename (`{filename}`)"
+            ),
+            Self::InvalidSummary => write!(
+                f,
+                "The summary must be a single line of at most {MAX_SUMMARY_LENGTH} characters"
+            ),
+            Self::InvalidContentType(content_type) => write!(
+                f,
+                "The description content type can't be rendered: `{content_type}`"
+            ),
+            Self::InvalidDescription { line, message } => write!(
+                f,
+                "The long description failed to render as reStructuredText, line {line}: {message}"
+            ),
+            Self::DuplicateFilename => write!(f, "A file with the same name is published twice"),
+            Self::ExistsWithDifferentContents => write!(
+                f,
+                "A file with the same name but different contents exists in the index"
+            ),
+            Self::MissingSummary => write!(f, "The summary is missing"),
+            Self::MissingDescription => write!(f, "The long description is missing"),
+            Self::MissingContentType => write!(
+                f,
+                "The description content type is missing, the long description will be rendered as reStructuredText"
+            ),
+            Self::AlreadyExists => write!(f, "The file already exists in the index"),
+        }
+    }
+}
+
+/// Validate the metadata of a distribution.
+///
+/// This checks that the metadata is complete, that the long description can be rendered and that
+/// the metadata matches the filename.
+pub async fn validate_metadata(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<Vec<ValidationIssue>, PublishPrepareError> {
+    let metadata = metadata(file, filename).await?;
+    Ok(metadata_issues(&metadata, filename))
+}
+
+fn metadata_issues(metadata: &Metadata23, filename: &DistFilename) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    if !METADATA_VERSIONS.contains(&metadata.metadata_version.as_str()) {
+        issues.push(ValidationIssue::UnsupportedMetadataVersion(
+            metadata.metadata_version.clone(),
+        ));
+    }
+
+    if PackageName::from_str(&metadata.name).ok().as_ref() != Some(filename.name()) {
+        issues.push(ValidationIssue::NameMismatch {
+            filename: filename.name().clone(),
+            metadata: metadata.name.clone(),
+        });
+    }
+    if Version::from_str(&metadata.version).ok().as_ref() != Some(filename.version()) {
+        issues.push(ValidationIssue::VersionMismatch {
+            filename: filename.version().clone(),
+            metadata: metadata.version.clone(),
+        });
+    }
+
+    // Older versions of setuptools write `UNKNOWN` for missing fields.
+    let summary = metadata
+        .summary
+        .as_deref()
+        .filter(|summary| !summary.trim().is_empty() && *summary != "UNKNOWN");
+    match summary {
+        Some(summary) => {
+            if summary.contains('\n') || summary.chars().count() > MAX_SUMMARY_LENGTH {
+                issues.push(ValidationIssue::InvalidSummary);
+            }
+        }
+        None => issues.push(ValidationIssue::MissingSummary),
+    }
+
+    let description = metadata
+        .description
+        .as_deref()
+        .filter(|description| !description.trim().is_empty() && *description != "UNKNOWN");
+    match (description, metadata.description_content_type.as_deref()) {
+        (_, Some(content_type)) if !is_renderable(content_type) => {
+            issues.push(ValidationIssue::InvalidContentType(
+                content_type.to_string(),
+            ));
+        }
+        (None, _) => issues.push(ValidationIssue::MissingDescription),
+        (Some(_), None) => issues.push(ValidationIssue::MissingContentType),
+        (Some(_), Some(_)) => {}
+    }
+
+    // The index renders long descriptions without a content type as reStructuredText, and rejects
+    // them if rendering fails.
+    if let Some(description) = description {
+        let is_rst = metadata
+            .description_content_type
+            .as_deref()
+            .is_none_or(|content_type| mime_type(content_type) == "text/x-rst");
+        if is_rst {
+            issues.extend(rst::check(description).into_iter().map(|problem| {
+                ValidationIssue::InvalidDescription {
+                    line: problem.line,
+                    message: problem.message,
+                }
+            }));
+        }
+    }
+
+    issues
+}
+
+/// The MIME type of a content type, e.g., `text/markdown` for `text/markdown; charset=UTF-8`.
+fn mime_type(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Whether the index can render a long description with the given content type, e.g.,
+/// `text/markdown; charset=UTF-8; variant=GFM`.
+fn is_renderable(content_type: &str) -> bool {
+    let mime_type = mime_type(content_type);
+    let parts = content_type.split(';').map(str::trim).skip(1);
+    if !CONTENT_TYPES.contains(&mime_type.as_str()) {
+        return false;
+    }
+    for parameter in parts.filter(|parameter| !parameter.is_empty()) {
+        let Some((key, value)) = parameter.split_once('=') else {
+            return false;
+        };
+        let value = value.trim().trim_matches('"').to_ascii_lowercase();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "charset" => {
+                if value != "utf-8" {
+                    return false;
+                }
+            }
+            "variant" => {
+                if mime_type != "text/markdown" || !MARKDOWN_VARIANTS.contains(&value.as_str()) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renderable_content_types() {
+        assert!(is_renderable("text/markdown"));
+        assert!(is_renderable("text/markdown; charset=UTF-8; variant=GFM"));
+        assert!(is_renderable("text/x-rst; charset=utf-8"));
+        assert!(is_renderable("TEXT/PLAIN"));
+        assert!(!is_renderable("text/html"));
+        assert!(!is_renderable("text/markdown; variant=unknown"));
+        assert!(!is_renderable("text/x-rst; variant=GFM"));
+        assert!(!is_renderable("text/plain; charset=latin-1"));
+    }
+
+    #[test]
+    fn issues() {
+        let filename =
+            DistFilename::try_from_normalized_filename("foo-1.0.0-py3-none-any.whl").unwrap();
+        let metadata = Metadata23 {
+            metadata_version: "2.4".to_string(),
+            name: "Foo".to_string(),
+            version: "1.0".to_string(),
+            summary: Some("A package".to_string()),
+            description: Some("# Foo".to_string()),
+            description_content_type: Some("text/markdown".to_string()),
+            ..Metadata23::default()
+        };
+        assert_eq!(metadata_issues(&metadata, &filename), Vec::new());
+
+        let metadata = Metadata23 {
+            metadata_version: "3.0".to_string(),
+            name: "bar".to_string(),
+            version: "1.0.1".to_string(),
+            summary: Some("UNKNOWN".to_string()),
+            description: Some("Foo\n===\n\nSee :ref:`usage`.".to_string()),
+            ..Metadata23::default()
+        };
+        assert_eq!(
+            metadata_issues(&metadata, &filename),
+            vec![
+                ValidationIssue::UnsupportedMetadataVersion("3.0".to_string()),
+                ValidationIssue::NameMismatch {
+                    filename: PackageName::from_str("foo").unwrap(),
+                    metadata: "bar".to_string(),
+                },
+                ValidationIssue::VersionMismatch {
+                    filename: Version::from_str("1.0.0").unwrap(),
+                    metadata: "1.0.1".to_string(),
+                },
+                ValidationIssue::MissingSummary,
+                ValidationIssue::MissingContentType,
+                ValidationIssue::InvalidDescription {
+                    line: 4,
+                    message: "Unknown interpreted text role \"ref\".".to_string(),
+                },
+            ]
+        );
+    }
+}
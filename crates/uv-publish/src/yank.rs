@@ -0,0 +1,184 @@
+//! Yank and un-yank releases (PEP 592) through the upload API of a registry.
+//!
+//! The upload API has no standardized yank action, and PyPI only supports yanking through its web
+//! interface. We send a multipart `POST` request to the publish URL of an index that the user
+//! configured, in the same form format as uploads, with the fields `:action` (`yank` or `unyank`),
+//! `protocol_version` (`1`), `name`, `version` and, optionally, `yanked_reason`. This format is
+//! documented in the publishing guide, so that registries can implement it. Registries that don't
+//! support it reject the request, which we report as unsupported.
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use reqwest::StatusCode;
+use reqwest_retry::policies::ExponentialBackoff;
+use reqwest_retry::{RetryPolicy, Retryable, RetryableStrategy};
+use tracing::debug;
+
+use uv_auth::Credentials;
+use uv_client::{BaseClient, DEFAULT_RETRIES, UvRetryableStrategy};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user;
+
+use crate::{PublishError, PublishSendError, handle_response, is_pypi, post_request};
+
+/// Whether to yank or un-yank a release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum YankAction {
+    /// Mark the release as yanked, optionally with a reason shown to users.
+    Yank { reason: Option<String> },
+    /// Revert a previous yank.
+    Unyank,
+}
+
+impl YankAction {
+    /// The form fields for the action.
+    fn form_fields(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Yank { reason } => {
+                let mut fields = vec![(":action", "yank".to_string())];
+                if let Some(reason) = reason {
+                    fields.push(("yanked_reason", reason.clone()));
+                }
+                fields
+            }
+            Self::Unyank => vec![(":action", "unyank".to_string())],
+        }
+    }
+}
+
+impl fmt::Display for YankAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Yank { .. } => write!(f, "yank"),
+            Self::Unyank => write!(f, "un-yank"),
+        }
+    }
+}
+
+/// Yank or un-yank all files of a release on a registry.
+///
+/// Yanking is idempotent, so unlike uploads, the request is retried on transient failures without
+/// checking the index.
+pub async fn yank(
+    name: &PackageName,
+    version: &Version,
+    action: &YankAction,
+    registry: &DisplaySafeUrl,
+    client: &BaseClient,
+    credentials: &Credentials,
+) -> Result<(), PublishError> {
+    if is_pypi(registry) {
+        return Err(PublishError::YankUnsupported(registry.clone()));
+    }
+
+    let mut n_past_retries = 0;
+    let start_time = SystemTime::now();
+    // N.B. We cannot use the client policy here because multipart requests aren't cloneable.
+    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(DEFAULT_RETRIES);
+    loop {
+        let mut form = reqwest::multipart::Form::new()
+            .text("protocol_version", "1")
+            .text("name", name.to_string())
+            .text("version", version.to_string());
+        for (key, value) in action.form_fields() {
+            form = form.text(key, value);
+        }
+
+        debug!("Sending {action} request for {name}=={version} to {registry}");
+        let result = post_request(registry, client, credentials, form)
+            .send()
+            .await;
+        if UvRetryableStrategy::default().handle(&result) == Some(Retryable::Transient) {
+            let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
+            if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
+                warn_user!("Transient failure while handling response for {registry}; retrying...");
+                let duration = execute_after
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_else(|_| Duration::default());
+                tokio::time::sleep(duration).await;
+                n_past_retries += 1;
+                continue;
+            }
+        }
+
+        let error = |err| {
+            PublishError::Yank(
+                action.clone(),
+                format!("{name}=={version}"),
+                registry.clone(),
+                err,
+            )
+        };
+        let response = result.map_err(|err| error(PublishSendError::ReqwestMiddleware(err)))?;
+        return match handle_response(registry, response).await {
+            Ok(()) => Ok(()),
+            Err(err) if is_unsupported(&err) => {
+                debug!("Yanking is not supported by {registry}: {err}");
+                Err(PublishError::YankUnsupported(registry.clone()))
+            }
+            Err(err) => Err(error(err)),
+        };
+    }
+}
+
+/// Whether the registry rejected the request because it doesn't support the action, as opposed
+/// to, e.g., missing permissions or an unknown release.
+fn is_unsupported(err: &PublishSendError) -> bool {
+    match err {
+        PublishSendError::MethodNotAllowed(_) | PublishSendError::MethodNotAllowedNoBody => true,
+        PublishSendError::Status(status, body) => {
+            *status == StatusCode::NOT_IMPLEMENTED
+                || (*status == StatusCode::BAD_REQUEST && body.to_lowercase().contains("action"))
+        }
+        PublishSendError::StatusNoBody(status, _) => *status == StatusCode::NOT_IMPLEMENTED,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_fields() {
+        let action = YankAction::Yank {
+            reason: Some("Broken on Windows".to_string()),
+        };
+        assert_eq!(
+            action.form_fields(),
+            vec![
+                (":action", "yank".to_string()),
+                ("yanked_reason", "Broken on Windows".to_string())
+            ]
+        );
+        assert_eq!(
+            YankAction::Unyank.form_fields(),
+            vec![(":action", "unyank".to_string())]
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(is_unsupported(&PublishSendError::MethodNotAllowedNoBody));
+        assert!(is_unsupported(&PublishSendError::Status(
+            StatusCode::NOT_IMPLEMENTED,
+            String::new()
+        )));
+        assert!(is_unsupported(&PublishSendError::Status(
+            StatusCode::BAD_REQUEST,
+            "Unknown :action yank".to_string()
+        )));
+        // Other errors are reported as is.
+        assert!(!is_unsupported(&PublishSendError::Status(
+            StatusCode::BAD_REQUEST,
+            "Release not found".to_string()
+        )));
+        assert!(!is_unsupported(&PublishSendError::PermissionDenied(
+            StatusCode::FORBIDDEN,
+            "Invalid token".to_string()
+        )));
+    }
+}
@@ -141,7 +141,13 @@ impl Conflicts {
         for (group, specifiers) in groups {
             if let Some(includer) = group_node_idxs.get(group) {
                 for specifier in specifiers {
-                    if let DependencyGroupSpecifier::IncludeGroup { include_group } = specifier {
+                    // Only track includes between groups in this package, as opposed to groups
+                    // included from other workspace members.
+                    if let DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        package: None,
+                    } = specifier
+                    {
                         if let Some(included) = group_node_idxs.get(include_group) {
                             graph.add_edge(*included, *includer, ());
                         }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, PackageName};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DependencyGroups(BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>);
@@ -90,6 +90,10 @@ pub enum DependencyGroupSpecifier {
     IncludeGroup {
         /// The name of the group to include.
         include_group: GroupName,
+        /// The workspace member that defines the group, if it's defined in another project.
+        ///
+        /// This is a uv extension to PEP 735, e.g., `{ include-group = "lint", package = "tools" }`.
+        package: Option<PackageName>,
     },
     /// A Dependency Object Specifier.
     Object(BTreeMap<String, String>),
@@ -136,7 +140,16 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
                     .transpose()
                     .map_err(serde::de::Error::custom)?
                 {
-                    Ok(DependencyGroupSpecifier::IncludeGroup { include_group })
+                    let package = map_data
+                        .get("package")
+                        .map(String::as_str)
+                        .map(PackageName::from_str)
+                        .transpose()
+                        .map_err(serde::de::Error::custom)?;
+                    Ok(DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        package,
+                    })
                 } else {
                     Ok(DependencyGroupSpecifier::Object(map_data))
                 }
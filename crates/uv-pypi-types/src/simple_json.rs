@@ -15,6 +15,12 @@ pub struct SimpleJson {
     /// The list of [`File`]s available for download sorted by filename.
     #[serde(deserialize_with = "sorted_simple_json_files")]
     pub files: Vec<File>,
+    /// The URLs of the projects on other repositories that this project tracks, per PEP 708.
+    #[serde(default)]
+    pub tracks: Vec<SmallString>,
+    /// The URLs of the repositories that extend this project's namespace, per PEP 708.
+    #[serde(default, rename = "alternate-locations")]
+    pub alternate_locations: Vec<SmallString>,
 }
 
 /// Deserializes a sequence of "simple" files from `PyPI` and ensures that they
@@ -156,6 +156,9 @@ class PythonDownload:
     sha256: str | None = None
     build_options: list[str] = field(default_factory=list)
     variant: Variant | None = None
+    # The version of the implementation, if it differs from the Python version (e.g., GraalPy's
+    # release version)
+    implementation_version: str | None = None
 
     def key(self) -> str:
         if self.variant:
@@ -623,6 +626,7 @@ class GraalPyFinder(Finder):
                     filename=asset["name"],
                     url=url,
                     sha256=sha256,
+                    implementation_version=graalpy_version,
                 )
                 # Only keep the latest GraalPy version of each arch/platform
                 if (python_version, arch, platform) not in results:
@@ -726,6 +730,8 @@ def render(downloads: list[PythonDownload]) -> None:
             "sha256": download.sha256,
             "variant": download.variant if download.variant else None,
         }
+        if download.implementation_version:
+            results[key]["implementation_version"] = download.implementation_version
 
     VERSIONS_FILE.parent.mkdir(parents=True, exist_ok=True)
     # Make newlines consistent across platforms
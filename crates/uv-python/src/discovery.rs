@@ -29,6 +29,7 @@ use crate::interpreter::{StatusCodeError, UnexpectedResponseError};
 use crate::managed::{ManagedPythonInstallations, PythonMinorVersionLink};
 #[cfg(windows)]
 use crate::microsoft_store::find_microsoft_store_pythons;
+use crate::version_manager::VersionManager;
 use crate::virtualenv::Error as VirtualEnvError;
 use crate::virtualenv::{
     CondaEnvironmentKind, conda_environment_from_env, virtualenv_from_env,
@@ -170,9 +171,15 @@ pub(crate) struct DiscoveryPreferences {
 pub enum PythonVariant {
     #[default]
     Default,
+    Debug,
     Freethreaded,
+    FreethreadedDebug,
 }
 
+/// The first GraalPy release version. Version requests with a major version of at least this are
+/// treated as requests for a GraalPy release, rather than for a Python version.
+const GRAALPY_FIRST_RELEASE: u8 = 20;
+
 /// A Python discovery version request.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum VersionRequest {
@@ -220,6 +227,8 @@ pub enum PythonSource {
     SearchPath,
     /// The first executable found in the search path i.e. `PATH`
     SearchPathFirst,
+    /// A version manager shim, or a conda environment, found in the search path, e.g., from pyenv
+    VersionManager(VersionManager),
     /// An executable was found in the Windows registry via PEP 514
     Registry,
     /// An executable was found in the known Microsoft Store locations
@@ -338,19 +347,28 @@ fn python_executables_from_installed<'a>(
     preview: Preview,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     let from_managed_installations = iter::once_with(move || {
-        ManagedPythonInstallations::from_settings(None)
+        ManagedPythonInstallations::discoverable()
             .map_err(Error::from)
             .and_then(|installed_installations| {
-                debug!(
-                    "Searching for managed installations at `{}`",
-                    installed_installations.root().user_display()
-                );
-                let installations = installed_installations.find_matching_current_platform()?;
+                let mut installations = Vec::new();
+                for installed_installations in installed_installations {
+                    debug!(
+                        "Searching for managed installations at `{}`",
+                        installed_installations.root().user_display()
+                    );
+                    installations.extend(installed_installations.find_matching_current_platform()?);
+                }
                 // Check that the Python version and platform satisfy the request to avoid unnecessary interpreter queries later
                 Ok(installations
                     .into_iter()
                     .filter(move |installation| {
-                        if !version.matches_version(&installation.version()) {
+                        // The release version of GraalPy installations is only known once
+                        // the interpreter is queried.
+                        let graalpy_release = implementation
+                            == Some(&ImplementationName::GraalPy)
+                            && version.is_graalpy_release();
+                        if !graalpy_release && !version.matches_version(&installation.version())
+                        {
                             debug!("Skipping managed installation `{installation}`: does not satisfy `{version}`");
                             return false;
                         }
@@ -391,11 +409,24 @@ fn python_executables_from_installed<'a>(
     let from_search_path = iter::once_with(move || {
         python_executables_from_search_path(version, implementation)
             .enumerate()
-            .map(|(i, path)| {
-                if i == 0 {
-                    Ok((PythonSource::SearchPathFirst, path))
+            .flat_map(|(i, path)| {
+                if let Some(manager) = VersionManager::from_executable(&path) {
+                    // Query the shim first, which resolves to the version selected by the version
+                    // manager, then the versions installed by the manager.
+                    let installed = if manager.is_shim() {
+                        manager.installed_executables(&path)
+                    } else {
+                        Vec::new()
+                    };
+                    Either::Left(
+                        iter::once(path)
+                            .chain(installed)
+                            .map(move |path| Ok((PythonSource::VersionManager(manager), path))),
+                    )
+                } else if i == 0 {
+                    Either::Right(iter::once(Ok((PythonSource::SearchPathFirst, path))))
                 } else {
-                    Ok((PythonSource::SearchPath, path))
+                    Either::Right(iter::once(Ok((PythonSource::SearchPath, path))))
                 }
             })
     })
@@ -729,7 +760,7 @@ fn python_interpreters<'a>(
     })
     .filter_ok(move |(source, interpreter)| {
         let request = version.clone().into_request_for_source(*source);
-        if request.matches_interpreter(interpreter) {
+        if request.matches_implementation_interpreter(implementation, interpreter) {
             true
         } else {
             debug!(
@@ -889,6 +920,7 @@ pub fn satisfies_python_preference(
         | PythonSource::DiscoveredEnvironment
         | PythonSource::SearchPath
         | PythonSource::SearchPathFirst
+        | PythonSource::VersionManager(_)
         | PythonSource::Registry
         | PythonSource::MicrosoftStore
         | PythonSource::BaseCondaPrefix => false,
@@ -929,6 +961,7 @@ pub fn satisfies_python_preference(
                 | PythonSource::DiscoveredEnvironment
                 | PythonSource::SearchPath
                 | PythonSource::SearchPathFirst
+                | PythonSource::VersionManager(_)
                 | PythonSource::Registry
                 | PythonSource::BaseCondaPrefix => !interpreter.is_managed(),
                 // Managed interpreters should never be found in the store
@@ -1285,7 +1318,9 @@ pub(crate) fn find_python_installation(
         let has_default_executable_name = installation.interpreter.has_default_executable_name()
             && matches!(
                 installation.source,
-                PythonSource::SearchPath | PythonSource::SearchPathFirst
+                PythonSource::SearchPath
+                    | PythonSource::SearchPathFirst
+                    | PythonSource::VersionManager(_)
             );
 
         // If it's a pre-release and pre-releases aren't allowed, skip it — but store it for later
@@ -1573,18 +1608,54 @@ impl PythonVariant {
         match self {
             PythonVariant::Default => !interpreter.gil_disabled(),
             PythonVariant::Freethreaded => interpreter.gil_disabled(),
+            // Debug builds are only required when requested explicitly, e.g., with `+debug`.
+            PythonVariant::Debug => !interpreter.gil_disabled() && interpreter.debug_enabled(),
+            PythonVariant::FreethreadedDebug => {
+                interpreter.gil_disabled() && interpreter.debug_enabled()
+            }
         }
     }
 
-    /// Return the lib or executable suffix for the variant, e.g., `t` for `python3.13t`.
+    /// Return the executable suffix for the variant, e.g., `t` for `python3.13t` or `d` for
+    /// `python3.13d`.
     ///
     /// Returns an empty string for the default Python variant.
     pub fn suffix(self) -> &'static str {
         match self {
             Self::Default => "",
+            Self::Debug => "d",
+            Self::Freethreaded => "t",
+            Self::FreethreadedDebug => "td",
+        }
+    }
+
+    /// Return the suffix used to request the variant, e.g., `t` for `3.13t` or `+debug` for
+    /// `3.13+debug`.
+    fn request_suffix(self) -> &'static str {
+        match self {
+            Self::Default => "",
+            Self::Debug => "+debug",
             Self::Freethreaded => "t",
+            Self::FreethreadedDebug => "+freethreaded+debug",
         }
     }
+
+    /// Return the suffix of the `lib` directory for the variant, e.g., `t` for `lib/python3.13t`.
+    ///
+    /// Unlike the executable, the `lib` directory of debug builds has no suffix.
+    pub fn lib_suffix(self) -> &'static str {
+        if self.is_freethreaded() { "t" } else { "" }
+    }
+
+    /// Whether this is a free-threaded variant, i.e., with the GIL disabled.
+    pub fn is_freethreaded(self) -> bool {
+        matches!(self, Self::Freethreaded | Self::FreethreadedDebug)
+    }
+
+    /// Whether this is a debug variant, i.e., built with `Py_DEBUG`.
+    pub fn is_debug(self) -> bool {
+        matches!(self, Self::Debug | Self::FreethreadedDebug)
+    }
 }
 impl PythonRequest {
     /// Create a request from a string.
@@ -1738,6 +1809,15 @@ impl PythonRequest {
                     ImplementationName::from_str(implementation).unwrap(),
                 )));
             }
+            if let Some(version_request) =
+                Self::try_split_graalpy_release(implementation, lowercase_value)
+            {
+                // e.g. `graalpy24` or `graalpy@24`
+                return Ok(Some(Self::ImplementationVersion(
+                    ImplementationName::GraalPy,
+                    version_request,
+                )));
+            }
             if let Some(version_request) =
                 Self::try_split_prefix_and_version(implementation, lowercase_value)?
             {
@@ -1791,6 +1871,18 @@ impl PythonRequest {
         Ok(rest.parse().ok())
     }
 
+    /// Take a value like `"graalpy24"` or `"graalpy@24"` and, if the prefix is a name for GraalPy,
+    /// try to parse a single-segment GraalPy release version.
+    ///
+    /// See [`VersionRequest::parse_graalpy_release`].
+    fn try_split_graalpy_release(prefix: &str, lowercase_value: &str) -> Option<VersionRequest> {
+        if ImplementationName::from_str(prefix).ok()? != ImplementationName::GraalPy {
+            return None;
+        }
+        let rest = lowercase_value.strip_prefix(prefix)?;
+        VersionRequest::parse_graalpy_release(rest.strip_prefix('@').unwrap_or(rest))
+    }
+
     /// Check if this request includes a specific patch version.
     pub fn includes_patch(&self) -> bool {
         match self {
@@ -1894,7 +1986,7 @@ impl PythonRequest {
                 .implementation_name()
                 .eq_ignore_ascii_case(implementation.into()),
             PythonRequest::ImplementationVersion(implementation, version) => {
-                version.matches_interpreter(interpreter)
+                version.matches_implementation_interpreter(Some(implementation), interpreter)
                     && interpreter
                         .implementation_name()
                         .eq_ignore_ascii_case(implementation.into())
@@ -1964,6 +2056,7 @@ impl PythonSource {
             Self::Managed | Self::Registry | Self::MicrosoftStore => false,
             Self::SearchPath
             | Self::SearchPathFirst
+            | Self::VersionManager(_)
             | Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
@@ -1982,6 +2075,7 @@ impl PythonSource {
             // TODO(zanieb): We may want to allow this at some point, but when adding this variant
             // we want compatibility with existing behavior
             | Self::SearchPathFirst
+            | Self::VersionManager(_)
             | Self::MicrosoftStore => false,
             Self::CondaPrefix
             | Self::BaseCondaPrefix
@@ -2012,7 +2106,11 @@ impl PythonSource {
             | Self::BaseCondaPrefix
             | Self::ParentInterpreter
             | Self::SearchPathFirst => true,
-            Self::Managed | Self::SearchPath | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed
+            | Self::SearchPath
+            | Self::VersionManager(_)
+            | Self::Registry
+            | Self::MicrosoftStore => false,
         }
     }
 
@@ -2026,6 +2124,7 @@ impl PythonSource {
             | Self::Managed
             | Self::SearchPath
             | Self::SearchPathFirst
+            | Self::VersionManager(_)
             | Self::Registry
             | Self::MicrosoftStore => true,
             Self::ActiveEnvironment | Self::DiscoveredEnvironment => false,
@@ -2328,10 +2427,13 @@ impl VersionRequest {
             }
         }
 
-        // Include free-threaded variants
-        if self.is_freethreaded() {
+        // Include free-threaded and debug variants
+        if let Some(variant) = self
+            .variant()
+            .filter(|variant| *variant != PythonVariant::Default)
+        {
             for i in 0..names.len() {
-                let name = names[i].with_variant(PythonVariant::Freethreaded);
+                let name = names[i].with_variant(variant);
                 names.push(name);
             }
         }
@@ -2342,6 +2444,26 @@ impl VersionRequest {
         names
     }
 
+    /// Parse a request for a GraalPy release version with a single segment, e.g., `24`.
+    ///
+    /// GraalPy can be requested by its release version rather than the Python version it
+    /// implements. Release versions with multiple segments (e.g., `24.2`) parse as usual, but a
+    /// single segment would otherwise be read as a wheel tag (e.g., `2.4`). Release versions from 30
+    /// onwards are ambiguous with Python 3 wheel tags (e.g., `310`), so are left as-is.
+    pub(crate) fn parse_graalpy_release(s: &str) -> Option<Self> {
+        let major = s.parse::<u8>().ok()?;
+        (GRAALPY_FIRST_RELEASE..30)
+            .contains(&major)
+            .then_some(Self::Major(major, PythonVariant::Default))
+    }
+
+    /// Whether this would be a request for a GraalPy release version (e.g., `24.2`), rather than
+    /// a Python version, if it were scoped to GraalPy.
+    pub(crate) fn is_graalpy_release(&self) -> bool {
+        self.major()
+            .is_some_and(|major| major >= GRAALPY_FIRST_RELEASE)
+    }
+
     /// Return the major version segment of the request, if any.
     pub(crate) fn major(&self) -> Option<u8> {
         match self {
@@ -2443,6 +2565,7 @@ impl VersionRequest {
                 | PythonSource::ActiveEnvironment => Self::Any,
                 PythonSource::SearchPath
                 | PythonSource::SearchPathFirst
+                | PythonSource::VersionManager(_)
                 | PythonSource::Registry
                 | PythonSource::MicrosoftStore
                 | PythonSource::Managed => Self::Default,
@@ -2451,6 +2574,28 @@ impl VersionRequest {
         }
     }
 
+    /// Check if a interpreter matches the request, for a request scoped to the given
+    /// implementation.
+    ///
+    /// GraalPy can be requested by its release version (e.g., `graalpy@24.2`) rather than the
+    /// Python version it implements; the release version is matched against the interpreter's
+    /// `implementation_version` marker.
+    pub(crate) fn matches_implementation_interpreter(
+        &self,
+        implementation: Option<&ImplementationName>,
+        interpreter: &Interpreter,
+    ) -> bool {
+        if implementation == Some(&ImplementationName::GraalPy) && self.is_graalpy_release() {
+            return interpreter
+                .implementation_name()
+                .eq_ignore_ascii_case(ImplementationName::GraalPy.into())
+                && self.matches_version(&PythonVersion::from(
+                    interpreter.markers().implementation_version().clone(),
+                ));
+        }
+        self.matches_interpreter(interpreter)
+    }
+
     /// Check if a interpreter matches the request.
     pub(crate) fn matches_interpreter(&self, interpreter: &Interpreter) -> bool {
         match self {
@@ -2639,7 +2784,7 @@ impl VersionRequest {
             | Self::MajorMinor(_, _, variant)
             | Self::MajorMinorPatch(_, _, _, variant)
             | Self::MajorMinorPrerelease(_, _, _, variant)
-            | Self::Range(_, variant) => variant == &PythonVariant::Freethreaded,
+            | Self::Range(_, variant) => variant.is_freethreaded(),
         }
     }
 
@@ -2690,12 +2835,19 @@ impl FromStr for VersionRequest {
             return Err(Error::InvalidVersionRequest(s.to_string()));
         }
 
-        // Check if the version request is for a free-threaded Python version
-        let (s, variant) = s
-            .strip_suffix('t')
-            .map_or((s, PythonVariant::Default), |s| {
-                (s, PythonVariant::Freethreaded)
-            });
+        // Check if the version request is for a free-threaded Python version, e.g., `3.13t`.
+        // Debug builds must be requested explicitly, e.g., `3.13+debug` or
+        // `3.13+freethreaded+debug`.
+        let (s, variant) = if let Some(s) = s.strip_suffix("+freethreaded+debug") {
+            (s, PythonVariant::FreethreadedDebug)
+        } else if s.contains('+') {
+            // The variant is in the local version, e.g., `3.13+freethreaded`, see below
+            (s, PythonVariant::Default)
+        } else if let Some(s) = s.strip_suffix('t') {
+            (s, PythonVariant::Freethreaded)
+        } else {
+            (s, PythonVariant::Default)
+        };
 
         if variant == PythonVariant::Freethreaded && s.ends_with('t') {
             // More than one trailing "t" is not allowed
@@ -2731,6 +2883,7 @@ impl FromStr for VersionRequest {
 
             match local.as_str() {
                 "freethreaded" => PythonVariant::Freethreaded,
+                "debug" => PythonVariant::Debug,
                 _ => return Err(Error::InvalidVersionRequest(s.to_string())),
             }
         };
@@ -2785,6 +2938,8 @@ impl FromStr for PythonVariant {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "t" | "freethreaded" => Ok(Self::Freethreaded),
+            "debug" => Ok(Self::Debug),
+            "freethreaded+debug" => Ok(Self::FreethreadedDebug),
             "" => Ok(Self::Default),
             _ => Err(()),
         }
@@ -2795,7 +2950,9 @@ impl fmt::Display for PythonVariant {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Default => f.write_str("default"),
+            Self::Debug => f.write_str("debug"),
             Self::Freethreaded => f.write_str("freethreaded"),
+            Self::FreethreadedDebug => f.write_str("freethreaded+debug"),
         }
     }
 }
@@ -2825,23 +2982,15 @@ impl fmt::Display for VersionRequest {
         match self {
             Self::Any => f.write_str("any"),
             Self::Default => f.write_str("default"),
-            Self::Major(major, PythonVariant::Default) => write!(f, "{major}"),
-            Self::Major(major, PythonVariant::Freethreaded) => write!(f, "{major}t"),
-            Self::MajorMinor(major, minor, PythonVariant::Default) => write!(f, "{major}.{minor}"),
-            Self::MajorMinor(major, minor, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}t")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}.{patch}")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}.{patch}t")
+            Self::Major(major, variant) => write!(f, "{major}{}", variant.request_suffix()),
+            Self::MajorMinor(major, minor, variant) => {
+                write!(f, "{major}.{minor}{}", variant.request_suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}{prerelease}")
+            Self::MajorMinorPatch(major, minor, patch, variant) => {
+                write!(f, "{major}.{minor}.{patch}{}", variant.request_suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}{prerelease}t")
+            Self::MajorMinorPrerelease(major, minor, prerelease, variant) => {
+                write!(f, "{major}.{minor}{prerelease}{}", variant.request_suffix())
             }
             Self::Range(specifiers, _) => write!(f, "{specifiers}"),
         }
@@ -2877,6 +3026,10 @@ impl fmt::Display for PythonSource {
             Self::DiscoveredEnvironment => f.write_str("virtual environment"),
             Self::SearchPath => f.write_str("search path"),
             Self::SearchPathFirst => f.write_str("first executable in the search path"),
+            Self::VersionManager(manager) if manager.is_shim() => {
+                write!(f, "{manager} shims in the search path")
+            }
+            Self::VersionManager(manager) => write!(f, "{manager} environment in the search path"),
             Self::Registry => f.write_str("registry"),
             Self::MicrosoftStore => f.write_str("Microsoft Store"),
             Self::Managed => f.write_str("managed installations"),
@@ -3282,6 +3435,31 @@ mod tests {
                 VersionRequest::from_str("3.10").unwrap(),
             )
         );
+        assert_eq!(
+            PythonRequest::parse("graalpy@24"),
+            PythonRequest::ImplementationVersion(
+                ImplementationName::GraalPy,
+                VersionRequest::Major(24, PythonVariant::Default),
+            )
+        );
+        assert_eq!(
+            PythonRequest::parse("graalpy24.2"),
+            PythonRequest::ImplementationVersion(
+                ImplementationName::GraalPy,
+                VersionRequest::from_str("24.2").unwrap(),
+            )
+        );
+        assert_eq!(
+            PythonRequest::parse("graalpy-24"),
+            PythonRequest::Key(PythonDownloadRequest::new(
+                Some(VersionRequest::Major(24, PythonVariant::Default)),
+                Some(ImplementationName::GraalPy),
+                None,
+                None,
+                None,
+                None
+            ))
+        );
 
         let tempdir = TempDir::new().unwrap();
         assert_eq!(
@@ -3577,6 +3755,37 @@ mod tests {
             VersionRequest::from_str("3.13tt"),
             Err(Error::InvalidVersionRequest(_))
         ));
+        assert_eq!(
+            VersionRequest::from_str("3.13+debug").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+debug").unwrap().to_string(),
+            "3.13+debug"
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.14.0+freethreaded+debug").unwrap(),
+            VersionRequest::MajorMinorPatch(3, 14, 0, PythonVariant::FreethreadedDebug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+freethreaded").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Freethreaded)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.14.0+freethreaded+debug")
+                .unwrap()
+                .to_string(),
+            "3.14.0+freethreaded+debug"
+        );
+        // Debug builds are only selected by an explicit `+debug` request.
+        assert!(matches!(
+            VersionRequest::from_str("3.13d"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert!(matches!(
+            VersionRequest::from_str("314td"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
     }
 
     #[test]
@@ -21,13 +21,15 @@ use tokio_util::either::Either;
 use tracing::{debug, instrument};
 use url::Url;
 
-use uv_client::{BaseClient, WrappedReqwestError, is_extended_transient_error};
+use uv_client::{BaseClient, RateLimited, WrappedReqwestError, is_extended_transient_error};
 use uv_distribution_filename::{ExtensionError, SourceDistExtension};
 use uv_extract::hash::Hasher;
 use uv_fs::{Simplified, rename_with_retry};
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_redacted::DisplaySafeUrl;
+use uv_sigstore::{SigstoreConfig, SigstoreError, SigstoreInstance, tuf_url, verify_attestation};
 use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 use crate::PythonVariant;
 use crate::implementation::{
@@ -71,6 +73,14 @@ pub enum Error {
         expected: String,
         actual: String,
     },
+    #[error(
+        "Refusing to install {0}: no attestation from python-build-standalone is available to verify the download"
+    )]
+    MissingAttestation(String),
+    #[error("Failed to verify the attestation of {0}")]
+    Attestation(String, #[source] Box<SigstoreError>),
+    #[error("Failed to read the attestations from {0}")]
+    InvalidAttestations(DisplaySafeUrl, #[source] reqwest::Error),
     #[error("Invalid download URL")]
     InvalidUrl(#[from] url::ParseError),
     #[error("Invalid download URL: {0}")]
@@ -143,6 +153,7 @@ pub struct ManagedPythonDownload {
     key: PythonInstallationKey,
     url: &'static str,
     sha256: Option<&'static str>,
+    implementation_version: Option<PythonVersion>,
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
@@ -425,6 +436,21 @@ impl PythonDownloadRequest {
 
     /// Whether this request is satisfied by a Python download.
     pub fn satisfied_by_download(&self, download: &ManagedPythonDownload) -> bool {
+        // GraalPy release versions aren't part of the installation key, so match them against the
+        // implementation version of the download instead.
+        if let Some(version) = self.version.as_ref().filter(|version| {
+            self.implementation == Some(ImplementationName::GraalPy) && version.is_graalpy_release()
+        }) {
+            let Some(release) = download.implementation_version() else {
+                return false;
+            };
+            return version.matches_version(release)
+                && Self {
+                    version: None,
+                    ..self.clone()
+                }
+                .satisfied_by_key(download.key());
+        }
         self.satisfied_by_key(download.key())
     }
 
@@ -445,7 +471,9 @@ impl PythonDownloadRequest {
     pub fn satisfied_by_interpreter(&self, interpreter: &Interpreter) -> bool {
         let executable = interpreter.sys_executable().display();
         if let Some(version) = self.version() {
-            if !version.matches_interpreter(interpreter) {
+            if !version
+                .matches_implementation_interpreter(self.implementation.as_ref(), interpreter)
+            {
                 let interpreter_version = interpreter.python_version();
                 debug!(
                     "Skipping interpreter at `{executable}`: version `{interpreter_version}` does not match request `{version}`"
@@ -579,10 +607,14 @@ impl FromStr for PythonDownloadRequest {
             match position {
                 1 => implementation = Some(ImplementationName::from_str(part)?),
                 2 => {
-                    version = Some(
-                        VersionRequest::from_str(part)
+                    let graalpy_release = (implementation == Some(ImplementationName::GraalPy))
+                        .then(|| VersionRequest::parse_graalpy_release(part))
+                        .flatten();
+                    version = Some(match graalpy_release {
+                        Some(version) => version,
+                        None => VersionRequest::from_str(part)
                             .map_err(|_| Error::InvalidPythonVersion(part.to_string()))?,
-                    );
+                    });
                 }
                 3 => os = Some(Os::from_str(part)?),
                 4 => arch = Some(ArchRequest::Explicit(Arch::from_str(part)?)),
@@ -611,6 +643,7 @@ struct JsonPythonDownload {
     url: String,
     sha256: Option<String>,
     variant: Option<String>,
+    implementation_version: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -619,6 +652,26 @@ struct JsonArch {
     variant: Option<String>,
 }
 
+/// The GitHub artifact attestations of python-build-standalone, by the digest of the artifact.
+const PYTHON_BUILD_STANDALONE_ATTESTATIONS_URL: &str =
+    "https://api.github.com/repos/astral-sh/python-build-standalone/attestations";
+
+/// The repository of the workflows that sign the python-build-standalone attestations.
+const PYTHON_BUILD_STANDALONE_REPOSITORY: &str =
+    "https://github.com/astral-sh/python-build-standalone";
+
+/// The response of the GitHub API for the attestations of an artifact.
+#[derive(Debug, Deserialize)]
+struct Attestations {
+    attestations: Vec<GitHubAttestation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAttestation {
+    /// The Sigstore bundle of the attestation.
+    bundle: serde_json::Value,
+}
+
 #[derive(Debug, Clone)]
 pub enum DownloadResult {
     AlreadyAvailable(PathBuf),
@@ -698,6 +751,12 @@ impl ManagedPythonDownload {
         self.url
     }
 
+    /// Return the version of the implementation, if it differs from the Python version (e.g., the
+    /// GraalPy release version, `24.2.2`).
+    pub fn implementation_version(&self) -> Option<&PythonVersion> {
+        self.implementation_version.as_ref()
+    }
+
     pub fn key(&self) -> &PythonInstallationKey {
         &self.key
     }
@@ -710,6 +769,14 @@ impl ManagedPythonDownload {
         self.sha256
     }
 
+    /// Return the expected [`HashDigest`] of the distribution archive, if known.
+    pub fn hash(&self) -> Option<HashDigest> {
+        self.sha256.map(|sha256| HashDigest {
+            algorithm: HashAlgorithm::Sha256,
+            digest: sha256.into(),
+        })
+    }
+
     /// Download and extract a Python distribution, retrying on failure.
     #[instrument(skip(client, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
     pub async fn fetch_with_retry(
@@ -720,6 +787,7 @@ impl ManagedPythonDownload {
         reinstall: bool,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        require_attestations: bool,
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
         let mut total_attempts = 0;
@@ -735,6 +803,7 @@ impl ManagedPythonDownload {
                     reinstall,
                     python_install_mirror,
                     pypy_install_mirror,
+                    require_attestations,
                     reporter,
                 )
                 .await;
@@ -787,6 +856,7 @@ impl ManagedPythonDownload {
         reinstall: bool,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        require_attestations: bool,
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
         let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
@@ -797,6 +867,22 @@ impl ManagedPythonDownload {
             return Ok(DownloadResult::AlreadyAvailable(path));
         }
 
+        if require_attestations || require_attestations_from_env() {
+            // Attestations are published by python-build-standalone, and are fetched from the
+            // GitHub API, which isn't available offline and isn't served by mirrors.
+            if python_install_mirror.is_some() {
+                warn_user_once!(
+                    "Skipping attestation verification for Python downloads from `python-install-mirror`"
+                );
+            } else if client.connectivity().is_offline() {
+                warn_user_once!(
+                    "Skipping attestation verification for Python downloads in offline mode"
+                );
+            } else {
+                self.verify_attestation(client).await?;
+            }
+        }
+
         // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
         // decodes to.
         let filename = url
@@ -931,6 +1017,9 @@ impl ManagedPythonDownload {
             }
         }
 
+        #[cfg(unix)]
+        share_permissions(installation_dir, &extracted)?;
+
         // Remove the target if it already exists.
         if path.is_dir() {
             debug!("Removing existing directory: {}", path.user_display());
@@ -949,6 +1038,64 @@ impl ManagedPythonDownload {
         Ok(DownloadResult::Fetched(path))
     }
 
+    /// Verify that python-build-standalone attests to the distribution archive, with the GitHub
+    /// artifact attestations for its SHA-256 digest.
+    ///
+    /// The archive itself is checked against the digest when it's extracted.
+    async fn verify_attestation(&self, client: &BaseClient) -> Result<(), Error> {
+        let (LenientImplementationName::Known(ImplementationName::CPython), Some(sha256)) =
+            (&self.key.implementation, self.sha256)
+        else {
+            return Err(Error::MissingAttestation(self.key.to_string()));
+        };
+
+        let url = DisplaySafeUrl::parse(&format!(
+            "{PYTHON_BUILD_STANDALONE_ATTESTATIONS_URL}/sha256:{sha256}"
+        ))?;
+        debug!("Fetching attestations for {} from {url}", self.key);
+        let response = client
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", "application/vnd.github+json")
+            .send()
+            .await
+            .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(Error::MissingAttestation(self.key.to_string()));
+        }
+        let attestations: Attestations = response
+            .error_for_status()
+            .map_err(|err| Error::from_reqwest(url.clone(), err, None))?
+            .json()
+            .await
+            .map_err(|err| Error::InvalidAttestations(url.clone(), err))?;
+
+        let instance = SigstoreInstance::resolve(
+            &SigstoreConfig::default(),
+            client.for_host(&tuf_url()).raw_client(),
+        )
+        .await
+        .map_err(|err| Error::Attestation(self.key.to_string(), Box::new(err)))?;
+        let mut result = Err(Error::MissingAttestation(self.key.to_string()));
+        for attestation in attestations.attestations {
+            match verify_attestation(
+                &attestation.bundle,
+                sha256,
+                PYTHON_BUILD_STANDALONE_REPOSITORY,
+                &instance,
+            ) {
+                Ok(()) => {
+                    debug!("Verified the attestation of {}", self.key);
+                    return Ok(());
+                }
+                Err(err) => {
+                    result = Err(Error::Attestation(self.key.to_string(), Box::new(err)));
+                }
+            }
+        }
+        result
+    }
+
     /// Download the managed Python archive into the cache directory.
     async fn download_archive(
         &self,
@@ -1030,13 +1177,13 @@ impl ManagedPythonDownload {
         hasher.finish().await.map_err(Error::HashExhaustion)?;
 
         // Check the hash
-        if let Some(expected) = self.sha256 {
-            let actual = HashDigest::from(hashers.pop().unwrap()).digest;
-            if !actual.eq_ignore_ascii_case(expected) {
+        if let Some(expected) = self.hash() {
+            let actual = HashDigest::from(hashers.pop().unwrap());
+            if !actual.digest.eq_ignore_ascii_case(&expected.digest) {
                 return Err(Error::HashMismatch {
                     installation: self.key.to_string(),
-                    expected: expected.to_string(),
-                    actual: actual.to_string(),
+                    expected: expected.digest.to_string(),
+                    actual: actual.digest.to_string(),
                 });
             }
         }
@@ -1063,9 +1210,7 @@ impl ManagedPythonDownload {
                     ) else {
                         return Err(Error::Mirror(EnvVars::UV_PYTHON_INSTALL_MIRROR, self.url));
                     };
-                    return Ok(Url::parse(
-                        format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
-                    )?);
+                    return mirror_url(mirror, suffix);
                 }
             }
 
@@ -1075,9 +1220,7 @@ impl ManagedPythonDownload {
                     else {
                         return Err(Error::Mirror(EnvVars::UV_PYPY_INSTALL_MIRROR, self.url));
                     };
-                    return Ok(Url::parse(
-                        format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
-                    )?);
+                    return mirror_url(mirror, suffix);
                 }
             }
 
@@ -1088,6 +1231,93 @@ impl ManagedPythonDownload {
     }
 }
 
+/// Extend the permissions of an extracted distribution, such that it's accessible to the same users
+/// as the installation directory (e.g., a shared installation directory like `/opt/python`).
+///
+/// Archives are extracted with the default permissions of the current user, which may be more
+/// restrictive, e.g., with a `umask` of `077`.
+#[cfg(unix)]
+fn share_permissions(installation_dir: &Path, extracted: &Path) -> io::Result<()> {
+    use std::fs::Permissions;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn visit(path: &Path, shared: u32) -> io::Result<()> {
+        let metadata = fs_err::symlink_metadata(path)?;
+        if metadata.file_type().is_symlink() {
+            return Ok(());
+        }
+        let mode = metadata.permissions().mode();
+        // Only grant the execute bits for directories and executables.
+        let extra = if metadata.is_dir() || mode & 0o100 != 0 {
+            shared
+        } else {
+            shared & 0o044
+        };
+        if mode & extra != extra {
+            fs_err::set_permissions(path, Permissions::from_mode(mode | extra))?;
+        }
+        if metadata.is_dir() {
+            for entry in fs_err::read_dir(path)? {
+                visit(&entry?.path(), shared)?;
+            }
+        }
+        Ok(())
+    }
+
+    // The read and execute bits for the group and others.
+    let shared = fs_err::metadata(installation_dir)?.permissions().mode() & 0o055;
+    if shared == 0 {
+        return Ok(());
+    }
+    visit(extracted, shared)
+}
+
+/// Whether distributions must be verified with an attestation from python-build-standalone, per
+/// `UV_PYTHON_INSTALL_REQUIRE_ATTESTATIONS`.
+fn require_attestations_from_env() -> bool {
+    env::var(EnvVars::UV_PYTHON_INSTALL_REQUIRE_ATTESTATIONS).is_ok_and(|value| {
+        matches!(
+            value.to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "y" | "on"
+        )
+    })
+}
+
+/// Join the path of a download, relative to its upstream source, onto a mirror.
+///
+/// The mirror can be a URL or an absolute path to a local directory. Local mirrors may either
+/// follow the upstream layout (e.g., `<mirror>/20240713/<archive>`) or contain the archives
+/// directly (i.e., `<mirror>/<archive>`).
+fn mirror_url(mirror: &str, suffix: &str) -> Result<Url, Error> {
+    let mirror = if Path::new(mirror).is_absolute() {
+        Url::from_directory_path(mirror)
+            .map_err(|()| Error::InvalidFileUrl(mirror.to_string()))?
+            .to_string()
+    } else {
+        mirror.to_string()
+    };
+    let mirror = mirror.trim_end_matches('/');
+
+    let url = Url::parse(&format!("{mirror}/{suffix}"))?;
+    if url.scheme() != "file" {
+        return Ok(url);
+    }
+
+    // Fall back to a flat layout if the archive doesn't exist at the upstream location.
+    let Some((_, filename)) = suffix.rsplit_once('/') else {
+        return Ok(url);
+    };
+    let exists = |url: &Url| url.to_file_path().is_ok_and(|path| path.is_file());
+    if !exists(&url) {
+        let flat = Url::parse(&format!("{mirror}/{filename}"))?;
+        if exists(&flat) {
+            debug!("Using archive at the root of the mirror: {flat}");
+            return Ok(flat);
+        }
+    }
+    Ok(url)
+}
+
 fn parse_json_downloads(
     json_downloads: HashMap<String, JsonPythonDownload>,
 ) -> Vec<ManagedPythonDownload> {
@@ -1176,6 +1406,19 @@ fn parse_json_downloads(
                 }
             };
 
+            let implementation_version = match entry
+                .implementation_version
+                .as_deref()
+                .map(PythonVersion::from_str)
+                .transpose()
+            {
+                Ok(implementation_version) => implementation_version,
+                Err(e) => {
+                    debug!("Skipping entry {key}: Invalid implementation version - {e}");
+                    return None;
+                }
+            };
+
             let url = Box::leak(entry.url.into_boxed_str()) as &'static str;
             let sha256 = entry
                 .sha256
@@ -1192,6 +1435,7 @@ fn parse_json_downloads(
                 ),
                 url,
                 sha256,
+                implementation_version,
             })
         })
         .sorted_by(|a, b| Ord::cmp(&b.key, &a.key))
@@ -1339,11 +1583,119 @@ async fn read_url(
             .map_err(|err| Error::from_reqwest(url, err, retry_count))?;
 
         let size = response.content_length();
-        let stream = response
-            .bytes_stream()
+        let stream = RateLimited::new(response.bytes_stream())
             .map_err(io::Error::other)
             .into_async_read();
 
         Ok((Either::Right(stream.compat()), size))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::mirror_url;
+
+    const SUFFIX: &str =
+        "20240713/cpython-3.12.4%2B20240713-x86_64-unknown-linux-gnu-install_only.tar.gz";
+    const FILENAME: &str = "cpython-3.12.4+20240713-x86_64-unknown-linux-gnu-install_only.tar.gz";
+
+    #[test]
+    fn mirror_url_remote() {
+        let url = mirror_url("https://mirror.example.com/python/", SUFFIX).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://mirror.example.com/python/20240713/cpython-3.12.4%2B20240713-x86_64-unknown-linux-gnu-install_only.tar.gz"
+        );
+    }
+
+    #[test]
+    fn mirror_url_absolute_path() {
+        let mirror = tempfile::tempdir().unwrap();
+        let release = mirror.path().join("20240713");
+        fs_err::create_dir_all(&release).unwrap();
+        fs_err::write(release.join(FILENAME), "").unwrap();
+
+        // An absolute path is converted to a `file://` URL, and the `%2B` in the upstream
+        // filename is decoded to the `+` of the archive on disk.
+        let url = mirror_url(mirror.path().to_str().unwrap(), SUFFIX).unwrap();
+        assert_eq!(url.scheme(), "file");
+        assert_eq!(url.to_file_path().unwrap(), release.join(FILENAME));
+    }
+
+    #[test]
+    fn mirror_url_file_url() {
+        let mirror = tempfile::tempdir().unwrap();
+        let release = mirror.path().join("20240713");
+        fs_err::create_dir_all(&release).unwrap();
+        fs_err::write(release.join(FILENAME), "").unwrap();
+
+        // A `file://` mirror with the upstream release layout.
+        let file_mirror = Url::from_directory_path(mirror.path()).unwrap().to_string();
+        let url = mirror_url(&file_mirror, SUFFIX).unwrap();
+        assert_eq!(url.to_file_path().unwrap(), release.join(FILENAME));
+    }
+
+    #[test]
+    fn mirror_url_flat_layout() {
+        let mirror = tempfile::tempdir().unwrap();
+        fs_err::write(mirror.path().join(FILENAME), "").unwrap();
+
+        // The archive is at the root of the mirror, rather than in a release directory.
+        let url = mirror_url(mirror.path().to_str().unwrap(), SUFFIX).unwrap();
+        assert_eq!(url.to_file_path().unwrap(), mirror.path().join(FILENAME));
+
+        // If the archive exists in neither layout, the upstream layout is reported.
+        fs_err::remove_file(mirror.path().join(FILENAME)).unwrap();
+        let url = mirror_url(mirror.path().to_str().unwrap(), SUFFIX).unwrap();
+        assert_eq!(
+            url.to_file_path().unwrap(),
+            mirror.path().join("20240713").join(FILENAME)
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn share_permissions() {
+        use std::fs::Permissions;
+        use std::os::unix::fs::PermissionsExt;
+
+        fn mode(path: &std::path::Path) -> u32 {
+            fs_err::metadata(path).unwrap().permissions().mode() & 0o777
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let installation_dir = temp_dir.path().join("python");
+        let extracted = installation_dir.join("cpython-3.12.0-linux-x86_64-gnu");
+        let bin = extracted.join("bin");
+        fs_err::create_dir_all(&bin).unwrap();
+        fs_err::write(bin.join("python3.12"), "").unwrap();
+        fs_err::write(extracted.join("LICENSE"), "").unwrap();
+
+        // Emulate extraction with a `umask` of `077`.
+        for (path, mode) in [
+            (bin.join("python3.12"), 0o700),
+            (extracted.join("LICENSE"), 0o600),
+            (bin.clone(), 0o700),
+            (extracted.clone(), 0o700),
+        ] {
+            fs_err::set_permissions(path, Permissions::from_mode(mode)).unwrap();
+        }
+
+        // A private installation directory is left as-is.
+        fs_err::set_permissions(&installation_dir, Permissions::from_mode(0o700)).unwrap();
+        super::share_permissions(&installation_dir, &extracted).unwrap();
+        assert_eq!(mode(&bin.join("python3.12")), 0o700);
+        assert_eq!(mode(&extracted.join("LICENSE")), 0o600);
+
+        // A shared installation directory grants read access to files, and execute access to
+        // directories and executables.
+        fs_err::set_permissions(&installation_dir, Permissions::from_mode(0o755)).unwrap();
+        super::share_permissions(&installation_dir, &extracted).unwrap();
+        assert_eq!(mode(&extracted), 0o755);
+        assert_eq!(mode(&bin), 0o755);
+        assert_eq!(mode(&bin.join("python3.12")), 0o755);
+        assert_eq!(mode(&extracted.join("LICENSE")), 0o644);
+    }
+}
@@ -58,6 +58,7 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
     real_executable: PathBuf,
 }
 
@@ -82,6 +83,7 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            debug_enabled: info.debug_enabled,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -167,7 +169,7 @@ impl Interpreter {
             base_executable,
             self.python_major(),
             self.python_minor(),
-            self.variant().suffix(),
+            self.variant().lib_suffix(),
         ) {
             Ok(path) => path,
             Err(err) => {
@@ -211,10 +213,11 @@ impl Interpreter {
     }
 
     pub fn variant(&self) -> PythonVariant {
-        if self.gil_disabled() {
-            PythonVariant::Freethreaded
-        } else {
-            PythonVariant::default()
+        match (self.gil_disabled(), self.debug_enabled()) {
+            (true, true) => PythonVariant::FreethreadedDebug,
+            (true, false) => PythonVariant::Freethreaded,
+            (false, true) => PythonVariant::Debug,
+            (false, false) => PythonVariant::Default,
         }
     }
 
@@ -243,6 +246,7 @@ impl Interpreter {
                 self.implementation_tuple(),
                 self.manylinux_compatible,
                 self.gil_disabled,
+                self.debug_enabled,
             )?;
             self.tags.set(tags).expect("tags should not be set");
         }
@@ -288,11 +292,13 @@ impl Interpreter {
             });
         }
 
-        let Ok(installations) = ManagedPythonInstallations::from_settings(None) else {
+        let Ok(installations) = ManagedPythonInstallations::discoverable() else {
             return false;
         };
 
-        self.sys_base_prefix.starts_with(installations.root())
+        installations
+            .iter()
+            .any(|installations| self.sys_base_prefix.starts_with(installations.root()))
     }
 
     /// Returns `Some` if the environment is externally managed, optionally including an error
@@ -497,6 +503,16 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return whether this is a debug build of Python, as specified by the sysconfig var
+    /// `Py_DEBUG`.
+    ///
+    /// Debug builds prefer wheels built for the debug ABI (e.g., `cp312d`), but since Python 3.8,
+    /// they can also load extension modules built for release builds.
+    /// <https://docs.python.org/3/using/configure.html#debug-build>
+    pub fn debug_enabled(&self) -> bool {
+        self.debug_enabled
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -874,6 +890,7 @@ struct InterpreterInfo {
     standalone: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
 }
 
 impl InterpreterInfo {
@@ -1263,7 +1280,8 @@ mod tests {
                 "scripts": "bin"
             },
             "pointer_size": "64",
-            "gil_disabled": true
+            "gil_disabled": true,
+            "debug_enabled": false
         }
     "##};
 
@@ -27,6 +27,7 @@ pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
     PYTHON_VERSION_FILENAME, PYTHON_VERSIONS_FILENAME, PythonVersionFile,
 };
+pub use crate::version_manager::VersionManager;
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
 mod cpuinfo;
@@ -48,6 +49,7 @@ mod python_version;
 mod sysconfig;
 mod target;
 mod version_files;
+mod version_manager;
 mod virtualenv;
 #[cfg(windows)]
 pub mod windows_registry;
@@ -141,7 +143,7 @@ mod tests {
     use uv_cache::Cache;
 
     use crate::{
-        PythonNotFound, PythonRequest, PythonSource, PythonVersion,
+        PythonNotFound, PythonRequest, PythonSource, PythonVersion, VersionManager,
         implementation::ImplementationName, installation::PythonInstallation,
         managed::ManagedPythonInstallations, virtualenv::virtualenv_python_executable,
     };
@@ -240,6 +242,26 @@ mod tests {
             implementation: ImplementationName,
             system: bool,
             free_threaded: bool,
+        ) -> Result<()> {
+            Self::create_mock_interpreter_with_debug(
+                path,
+                version,
+                implementation,
+                system,
+                free_threaded,
+                false,
+            )
+        }
+
+        /// Create a fake Python interpreter executable, as in [`TestContext::create_mock_interpreter`],
+        /// which may be a debug build.
+        fn create_mock_interpreter_with_debug(
+            path: &Path,
+            version: &PythonVersion,
+            implementation: ImplementationName,
+            system: bool,
+            free_threaded: bool,
+            debug: bool,
         ) -> Result<()> {
             let json = indoc! {r##"
                 {
@@ -291,7 +313,8 @@ mod tests {
                         "scripts": "bin"
                     },
                     "pointer_size": "64",
-                    "gil_disabled": {FREE_THREADED}
+                    "gil_disabled": {FREE_THREADED},
+                    "debug_enabled": {DEBUG}
                 }
             "##};
 
@@ -309,6 +332,7 @@ mod tests {
                 .replace("{FULL_VERSION}", &version.to_string())
                 .replace("{VERSION}", &version.without_patch().to_string())
                 .replace("{FREE_THREADED}", &free_threaded.to_string())
+                .replace("{DEBUG}", &debug.to_string())
                 .replace("{IMPLEMENTATION}", (&implementation).into());
 
             fs_err::create_dir_all(path.parent().unwrap())?;
@@ -2606,4 +2630,205 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn find_python_version_debug() -> Result<()> {
+        let mut context = TestContext::new()?;
+
+        TestContext::create_mock_interpreter(
+            &context.tempdir.join("python"),
+            &PythonVersion::from_str("3.13.1").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+        TestContext::create_mock_interpreter_with_debug(
+            &context.tempdir.join("python3.13d"),
+            &PythonVersion::from_str("3.13.0").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+            true,
+        )?;
+        context.add_to_search_path(context.tempdir.to_path_buf());
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.13+debug"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+                Preview::default(),
+            )
+        })??;
+
+        assert_eq!(
+            &python.interpreter().python_full_version().to_string(),
+            "3.13.0",
+            "We should find the correct interpreter for the request"
+        );
+        assert!(
+            python.interpreter().debug_enabled(),
+            "We should find a debug build"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_version_debug_not_requested() -> Result<()> {
+        let mut context = TestContext::new()?;
+
+        // A debug build is still compatible with a request that doesn't mention `+debug`.
+        TestContext::create_mock_interpreter_with_debug(
+            &context.tempdir.join("python"),
+            &PythonVersion::from_str("3.13.0").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+            true,
+        )?;
+        context.add_to_search_path(context.tempdir.to_path_buf());
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.13"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+                Preview::default(),
+            )
+        })??;
+        assert!(python.interpreter().debug_enabled());
+
+        // But a release build never satisfies a `+debug` request.
+        let mut context = TestContext::new()?;
+        TestContext::create_mock_interpreter(
+            &context.tempdir.join("python"),
+            &PythonVersion::from_str("3.13.0").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+        context.add_to_search_path(context.tempdir.to_path_buf());
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.13+debug"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+                Preview::default(),
+            )
+        });
+        assert!(
+            matches!(result, Ok(Err(PythonNotFound { .. }))),
+            "A release build should not satisfy a debug request; got {result:?}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_pyenv_shim() -> Result<()> {
+        let mut context = TestContext::new()?;
+
+        // The shim resolves to the version selected by pyenv.
+        let pyenv = context.tempdir.child("pyenv");
+        let shims = context.new_search_path_directory("pyenv/shims")?;
+        TestContext::create_mock_interpreter(
+            &shims.join("python3"),
+            &PythonVersion::from_str("3.12.4").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+        for version in ["3.11.9", "3.12.4"] {
+            let bin = pyenv.child("versions").child(version).child("bin");
+            bin.create_dir_all()?;
+            TestContext::create_mock_interpreter(
+                &bin.join("python3"),
+                &PythonVersion::from_str(version).unwrap(),
+                ImplementationName::CPython,
+                true,
+                false,
+            )?;
+        }
+
+        let find = |request: &str| {
+            context.run_with_vars(&[(EnvVars::PYENV_ROOT, Some(pyenv.as_os_str()))], || {
+                find_python_installation(
+                    &PythonRequest::parse(request),
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    &context.cache,
+                    Preview::default(),
+                )
+            })
+        };
+
+        // The selected version is found via the shim.
+        let python = find("3.12")??;
+        assert_eq!(
+            python.source(),
+            &PythonSource::VersionManager(VersionManager::Pyenv)
+        );
+        assert_eq!(python.interpreter().sys_executable(), shims.join("python3"));
+
+        // Other versions are found in the pyenv installation, rather than treating the shim as a
+        // broken interpreter.
+        let python = find("3.11")??;
+        assert_eq!(
+            python.source(),
+            &PythonSource::VersionManager(VersionManager::Pyenv)
+        );
+        assert_eq!(
+            python.interpreter().sys_executable(),
+            pyenv
+                .join("versions")
+                .join("3.11.9")
+                .join("bin")
+                .join("python3")
+        );
+
+        // Versions that aren't installed are not found.
+        assert!(matches!(find("3.10")?, Err(PythonNotFound { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_conda_environment_in_path() -> Result<()> {
+        let mut context = TestContext::new()?;
+
+        let bin = context.new_search_path_directory("conda/envs/foo/bin")?;
+        context
+            .tempdir
+            .child("conda/envs/foo/conda-meta")
+            .create_dir_all()?;
+        TestContext::create_mock_interpreter(
+            &bin.join("python3"),
+            &PythonVersion::from_str("3.12.4").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+
+        // An environment that's on the `PATH`, but not activated, is attributed to conda.
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::Default,
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+                Preview::default(),
+            )
+        })??;
+        assert_eq!(
+            python.source(),
+            &PythonSource::VersionManager(VersionManager::Conda)
+        );
+        assert_eq!(python.interpreter().sys_executable(), bin.join("python3"));
+
+        Ok(())
+    }
 }
@@ -147,6 +147,29 @@ impl ManagedPythonInstallations {
         }
     }
 
+    /// The shared directory of Python installations specified with the
+    /// `UV_PYTHON_SYSTEM_INSTALL_DIR` environment variable, if any.
+    ///
+    /// The directory is searched during discovery, but uv only installs into it when it's
+    /// requested explicitly, e.g., with `--install-dir`.
+    pub fn system() -> Option<Self> {
+        std::env::var_os(EnvVars::UV_PYTHON_SYSTEM_INSTALL_DIR)
+            .filter(|s| !s.is_empty())
+            .map(Self::from_path)
+    }
+
+    /// The directories to search for managed Python installations: the directory from
+    /// [`ManagedPythonInstallations::from_settings`], followed by the [`ManagedPythonInstallations::system`]
+    /// directory, if any.
+    pub fn discoverable() -> Result<Vec<Self>, Error> {
+        let installations = Self::from_settings(None)?;
+        let system = Self::system().filter(|system| {
+            system.root != installations.root
+                && !is_same_file(&system.root, &installations.root).unwrap_or(false)
+        });
+        Ok(std::iter::once(installations).chain(system).collect())
+    }
+
     /// Create a temporary Python installation directory.
     pub fn temp() -> Result<Self, Error> {
         Ok(Self::from_path(
@@ -264,16 +287,14 @@ impl ManagedPythonInstallations {
         let arch = Arch::from_env();
         let libc = Libc::from_env()?;
 
-        let iter = ManagedPythonInstallations::from_settings(None)?
-            .find_all()?
-            .filter(move |installation| {
-                installation.key.os == os
-                    && (arch.supports(installation.key.arch)
+        let iter = self.find_all()?.filter(move |installation| {
+            installation.key.os == os
+                && (arch.supports(installation.key.arch)
                         // TODO(zanieb): Allow inequal variants, as `Arch::supports` does not
                         // implement this yet. See https://github.com/astral-sh/uv/pull/9788
                         || arch.family == installation.key.arch.family)
-                    && installation.key.libc == libc
-            });
+                && installation.key.libc == libc
+        });
 
         Ok(iter)
     }
@@ -548,7 +569,7 @@ impl ManagedPythonInstallation {
         let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
             self.python_dir().join("Lib")
         } else {
-            let lib_suffix = self.key.variant.suffix();
+            let lib_suffix = self.key.variant.lib_suffix();
             let python = if matches!(
                 self.key.implementation,
                 LenientImplementationName::Known(ImplementationName::PyPy)
@@ -574,7 +595,7 @@ impl ManagedPythonInstallation {
                     self.path(),
                     self.key.major,
                     self.key.minor,
-                    self.key.variant.suffix(),
+                    self.key.variant.lib_suffix(),
                 )?;
             }
         }
@@ -684,9 +705,9 @@ impl PythonMinorVersionLink {
     /// version symlink directory (or junction on Windows) for the patch version
     /// directory.
     ///
-    /// The implementation is expected to be CPython and, on Unix, the base Python is
-    /// expected to be in `<home>/bin/` on Unix. If either condition isn't true,
-    /// return [`None`].
+    /// The implementation is expected to be a known implementation and the base Python is
+    /// expected to be in `<home>/bin/` on Unix (and for GraalPy on Windows). If either
+    /// condition isn't true, return [`None`].
     ///
     /// # Examples
     ///
@@ -705,11 +726,7 @@ impl PythonMinorVersionLink {
         preview: Preview,
     ) -> Option<Self> {
         let implementation = key.implementation();
-        if !matches!(
-            implementation,
-            LenientImplementationName::Known(ImplementationName::CPython)
-        ) {
-            // We don't currently support transparent upgrades for PyPy or GraalPy.
+        if !matches!(implementation, LenientImplementationName::Known(_)) {
             return None;
         }
         let executable_name = executable
@@ -721,7 +738,11 @@ impl PythonMinorVersionLink {
             .expect("Executable should have parent directory");
 
         // The home directory of the Python installation
-        let target_directory = if cfg!(unix) {
+        let target_directory = if cfg!(unix)
+            || matches!(
+                implementation,
+                LenientImplementationName::Known(ImplementationName::GraalPy)
+            ) {
             if parent
                 .components()
                 .next_back()
@@ -915,3 +936,99 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
     uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
         .ok_or(Error::NoExecutableDirectory)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_configuration::{Preview, PreviewFeatures};
+
+    use super::PythonMinorVersionLink;
+    use crate::installation::PythonInstallationKey;
+
+    fn minor_version_link(key: &str, executable: &Path) -> Option<PythonMinorVersionLink> {
+        let key = PythonInstallationKey::from_str(key).unwrap();
+        PythonMinorVersionLink::from_executable(
+            executable,
+            &key,
+            Preview::new(PreviewFeatures::PYTHON_UPGRADE),
+        )
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn minor_version_link_pypy() {
+        let link = minor_version_link(
+            "pypy-3.10.14-linux-x86_64-gnu",
+            Path::new("/uv/python/pypy-3.10.14-linux-x86_64-gnu/bin/pypy3.10"),
+        )
+        .unwrap();
+        assert_eq!(
+            link.target_directory,
+            Path::new("/uv/python/pypy-3.10.14-linux-x86_64-gnu")
+        );
+        assert_eq!(
+            link.symlink_directory,
+            Path::new("/uv/python/pypy-3.10-linux-x86_64-gnu")
+        );
+        assert_eq!(
+            link.symlink_executable,
+            Path::new("/uv/python/pypy-3.10-linux-x86_64-gnu/bin/pypy3.10")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn minor_version_link_graalpy() {
+        let link = minor_version_link(
+            "graalpy-3.11.0-linux-x86_64-gnu",
+            Path::new("/uv/python/graalpy-3.11.0-linux-x86_64-gnu/bin/graalpy"),
+        )
+        .unwrap();
+        assert_eq!(
+            link.target_directory,
+            Path::new("/uv/python/graalpy-3.11.0-linux-x86_64-gnu")
+        );
+        assert_eq!(
+            link.symlink_directory,
+            Path::new("/uv/python/graalpy-3.11-linux-x86_64-gnu")
+        );
+        assert_eq!(
+            link.symlink_executable,
+            Path::new("/uv/python/graalpy-3.11-linux-x86_64-gnu/bin/graalpy")
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn minor_version_link_graalpy() {
+        // GraalPy places its executables in `bin`, even on Windows.
+        let link = minor_version_link(
+            "graalpy-3.11.0-windows-x86_64-none",
+            Path::new(r"C:\uv\python\graalpy-3.11.0-windows-x86_64-none\bin\graalpy.exe"),
+        )
+        .unwrap();
+        assert_eq!(
+            link.target_directory,
+            Path::new(r"C:\uv\python\graalpy-3.11.0-windows-x86_64-none")
+        );
+        assert_eq!(
+            link.symlink_executable,
+            Path::new(r"C:\uv\python\graalpy-3.11-windows-x86_64-none\bin\graalpy.exe")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn minor_version_link_outside_bin() {
+        // Without a `bin` directory, the installation home can't be determined.
+        assert!(
+            minor_version_link(
+                "pypy-3.10.14-linux-x86_64-gnu",
+                Path::new("/uv/python/pypy-3.10.14-linux-x86_64-gnu/pypy3.10"),
+            )
+            .is_none()
+        );
+    }
+}
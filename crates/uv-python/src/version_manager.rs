@@ -0,0 +1,195 @@
+//! Detection of Python executables provided by version managers, like pyenv, and by conda.
+//!
+//! Version managers place a directory of shims on the `PATH`, which dispatch to the interpreter
+//! selected by the manager (e.g., via `PYENV_VERSION` or a `.python-version` file). Querying a shim
+//! reports the interpreter it resolves to. A shim for a version that isn't selected fails to
+//! resolve, so the interpreters installed by the manager are searched directly as well.
+//!
+//! Conda environments that are on the `PATH`, but not activated, are recognized by their
+//! `conda-meta` directory.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use same_file::is_same_file;
+use tracing::trace;
+
+use uv_pep440::Version;
+use uv_static::EnvVars;
+
+/// A Python version manager that places shims, or environments, on the `PATH`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum VersionManager {
+    /// [pyenv](https://github.com/pyenv/pyenv) (or [pyenv-win](https://github.com/pyenv-win/pyenv-win)).
+    Pyenv,
+    /// [asdf](https://asdf-vm.com/).
+    Asdf,
+    /// [mise](https://mise.jdx.dev/), formerly rtx.
+    Mise,
+    /// [conda](https://docs.conda.io/) (or a compatible tool, like mamba or pixi).
+    Conda,
+}
+
+impl VersionManager {
+    /// Return the version manager that provides the given executable, if any.
+    pub(crate) fn from_executable(executable: &Path) -> Option<Self> {
+        Self::from_shim(executable)
+            .or_else(|| conda_prefix(executable).is_some().then_some(Self::Conda))
+    }
+
+    /// Return the version manager that owns the shim directory containing the given executable,
+    /// if any.
+    fn from_shim(executable: &Path) -> Option<Self> {
+        let dir = executable.parent()?;
+        if dir.file_name()? != "shims" {
+            return None;
+        }
+        [Self::Pyenv, Self::Asdf, Self::Mise]
+            .into_iter()
+            .find(|manager| {
+                manager.data_dirs().into_iter().any(|data_dir| {
+                    let shims = data_dir.join("shims");
+                    dir == shims || is_same_file(dir, &shims).unwrap_or(false)
+                })
+            })
+    }
+
+    /// Whether the version manager provides shims, rather than the interpreters themselves.
+    pub fn is_shim(self) -> bool {
+        match self {
+            Self::Pyenv | Self::Asdf | Self::Mise => true,
+            Self::Conda => false,
+        }
+    }
+
+    /// Return the executables with the same name as the given shim in each of the Python versions
+    /// installed by the version manager, newest first.
+    ///
+    /// For example, the `python3.11` pyenv shim resolves to `~/.pyenv/versions/3.11.9/bin/python3.11`,
+    /// even if Python 3.11 isn't the version selected by pyenv.
+    pub(crate) fn installed_executables(self, shim: &Path) -> Vec<PathBuf> {
+        let Some(name) = shim.file_name() else {
+            return Vec::new();
+        };
+        self.versions_dirs()
+            .into_iter()
+            .filter_map(|dir| fs_err::read_dir(dir).ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+            .map(|entry| entry.path())
+            .sorted_by_cached_key(|path| {
+                std::cmp::Reverse(
+                    path.file_name()
+                        .and_then(OsStr::to_str)
+                        .and_then(|name| Version::from_str(name).ok()),
+                )
+            })
+            .filter_map(|dir| {
+                let executable = executable_in(&dir, name)?;
+                trace!(
+                    "Found {self} installation for shim `{}` at `{}`",
+                    shim.display(),
+                    executable.display()
+                );
+                Some(executable)
+            })
+            .collect()
+    }
+
+    /// The directories that contain a subdirectory for each Python version installed by the
+    /// version manager.
+    fn versions_dirs(self) -> Vec<PathBuf> {
+        match self {
+            Self::Pyenv => self
+                .data_dirs()
+                .into_iter()
+                .map(|data_dir| data_dir.join("versions"))
+                .collect(),
+            Self::Asdf | Self::Mise => self
+                .data_dirs()
+                .into_iter()
+                .map(|data_dir| data_dir.join("installs").join("python"))
+                .collect(),
+            Self::Conda => Vec::new(),
+        }
+    }
+
+    /// The directories that may contain the version manager's `shims` directory.
+    ///
+    /// As with the version managers themselves, an explicitly configured data directory replaces
+    /// the default location, rather than adding to it.
+    fn data_dirs(self) -> Vec<PathBuf> {
+        let home = env::var_os(EnvVars::HOME)
+            .or_else(|| env::var_os(EnvVars::USERPROFILE))
+            .filter(|home| !home.is_empty())
+            .map(PathBuf::from);
+        let override_dir = |var| {
+            env::var_os(var)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+        };
+        match self {
+            Self::Pyenv => match override_dir(EnvVars::PYENV_ROOT) {
+                Some(root) => vec![root],
+                None => home
+                    .iter()
+                    .flat_map(|home| [home.join(".pyenv"), home.join(".pyenv").join("pyenv-win")])
+                    .collect(),
+            },
+            Self::Asdf => override_dir(EnvVars::ASDF_DATA_DIR)
+                .or_else(|| home.map(|home| home.join(".asdf")))
+                .into_iter()
+                .collect(),
+            Self::Mise => match override_dir(EnvVars::MISE_DATA_DIR) {
+                Some(data_dir) => vec![data_dir],
+                None => override_dir(EnvVars::XDG_DATA_HOME)
+                    .or_else(|| home.map(|home| home.join(".local").join("share")))
+                    .iter()
+                    .flat_map(|data_home| [data_home.join("mise"), data_home.join("rtx")])
+                    .collect(),
+            },
+            Self::Conda => Vec::new(),
+        }
+    }
+}
+
+/// Return the executable with the given name in a Python installation directory, if it exists.
+fn executable_in(dir: &Path, name: &OsStr) -> Option<PathBuf> {
+    // pyenv-win installations don't have a `bin` directory.
+    [dir.join("bin").join(name), dir.join(name)]
+        .into_iter()
+        .find(|executable| executable.is_file())
+}
+
+/// Return the prefix of the conda environment containing the given executable, if any.
+///
+/// Conda environments place the interpreter at `bin/python` on Unix and `python.exe` on Windows,
+/// alongside a `conda-meta` directory.
+fn conda_prefix(executable: &Path) -> Option<&Path> {
+    let dir = executable.parent()?;
+    let prefix = if cfg!(windows) {
+        dir
+    } else {
+        if dir.file_name()? != "bin" {
+            return None;
+        }
+        dir.parent()?
+    };
+    prefix.join("conda-meta").is_dir().then_some(prefix)
+}
+
+impl Display for VersionManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Asdf => f.write_str("asdf"),
+            Self::Mise => f.write_str("mise"),
+            Self::Conda => f.write_str("conda"),
+        }
+    }
+}
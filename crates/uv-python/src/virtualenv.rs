@@ -45,6 +45,8 @@ pub struct PyVenvConfiguration {
     pub(crate) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(crate) version: Option<PythonVersion>,
+    /// The directory containing the base Python executable, i.e., the `home` key.
+    pub(crate) home: Option<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -198,6 +200,7 @@ impl PyVenvConfiguration {
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut home = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -224,6 +227,9 @@ impl PyVenvConfiguration {
                 "include-system-site-packages" => {
                     include_system_site_packages = value.trim().to_lowercase() == "true";
                 }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 "version" | "version_info" => {
                     version = Some(
                         PythonVersion::from_str(value.trim())
@@ -241,9 +247,15 @@ impl PyVenvConfiguration {
             seed,
             include_system_site_packages,
             version,
+            home,
         })
     }
 
+    /// Returns the directory containing the base Python executable, if known.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
     /// Returns true if the virtual environment was created with the `virtualenv` package.
     pub fn is_virtualenv(&self) -> bool {
         self.virtualenv
@@ -6,9 +6,9 @@ use crate::{COMPANY_DISPLAY_NAME, COMPANY_KEY, PythonInstallationKey, PythonVers
 use anyhow::anyhow;
 use std::cmp::Ordering;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use target_lexicon::PointerWidth;
+use target_lexicon::{Architecture, PointerWidth};
 use thiserror::Error;
 use tracing::debug;
 use uv_warnings::{warn_user, warn_user_once};
@@ -168,9 +168,10 @@ fn write_registry_entry(
     // Ex) CPython3.13.1
     let tag = company.create(registry_python_tag(installation.key()))?;
     let display_name = format!(
-        "{} {} ({}-bit)",
+        "{} {}{} ({}-bit)",
         installation.key().implementation().pretty(),
         installation.key().version(),
+        installation.key().variant().suffix(),
         pointer_width
     );
     tag.set_string("DisplayName", &display_name)?;
@@ -199,13 +200,68 @@ fn write_registry_entry(
         "WindowedExecutablePath",
         &Value::from(&HSTRING::from(installation.executable(true).as_os_str())),
     )?;
+
+    // Remove the entry written by previous versions of uv, if any.
+    remove_legacy_registry_entry(&company, installation.key(), installation.path())?;
     Ok(())
 }
 
+/// Return the PEP 514 tag for an installation, e.g., `CPython3.13.1` or `CPython3.13.1t-arm64`.
+///
+/// As with the tags used by the python.org installers (e.g., `3.13-32`), the variant and
+/// non-x86-64 architectures are included so that installations of the same version don't collide.
 fn registry_python_tag(key: &PythonInstallationKey) -> String {
+    let arch = match key.arch().family() {
+        Architecture::X86_32(_) => "-32",
+        Architecture::Aarch64(_) => "-arm64",
+        _ => "",
+    };
+    format!(
+        "{}{}{}{arch}",
+        key.implementation().pretty(),
+        key.version(),
+        key.variant().suffix()
+    )
+}
+
+/// Return the PEP 514 tag used by previous versions of uv, which omitted the variant and
+/// architecture, e.g., `CPython3.13.1`.
+fn legacy_registry_python_tag(key: &PythonInstallationKey) -> String {
     format!("{}{}", key.implementation().pretty(), key.version())
 }
 
+/// Remove the entry for an installation registered under its legacy tag, if any.
+///
+/// The legacy tag may now belong to another installation of the same version (e.g., the default
+/// x86-64 build), so the entry is only removed if it points to the given installation.
+fn remove_legacy_registry_entry(
+    company: &Key,
+    key: &PythonInstallationKey,
+    path: &Path,
+) -> windows_registry::Result<()> {
+    let legacy_tag = legacy_registry_python_tag(key);
+    if legacy_tag == registry_python_tag(key) {
+        return Ok(());
+    }
+    let install_path = match company
+        .open(format!("{legacy_tag}\\InstallPath"))
+        .and_then(|install_path| install_path.get_value(""))
+        .and_then(String::try_from)
+    {
+        Ok(install_path) => install_path,
+        Err(err) if err.code() == ERROR_NOT_FOUND => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    if Path::new(&install_path) != path {
+        return Ok(());
+    }
+    debug!("Removing legacy registry key for {key}: {legacy_tag}");
+    match company.remove_tree(&legacy_tag) {
+        Err(err) if err.code() != ERROR_NOT_FOUND => Err(err),
+        _ => Ok(()),
+    }
+}
+
 /// Remove requested Python entries from the Windows Registry (PEP 514).
 pub fn remove_registry_entry<'a>(
     installations: impl IntoIterator<Item = &'a ManagedPythonInstallation>,
@@ -225,7 +281,29 @@ pub fn remove_registry_entry<'a>(
         return;
     }
 
+    let company = match CURRENT_USER.open(&astral_key) {
+        Ok(company) => Some(company),
+        Err(err) if err.code() == ERROR_NOT_FOUND => None,
+        Err(err) => {
+            warn_user!("Failed to open HKCU:\\{astral_key}: {err}");
+            None
+        }
+    };
+
     for installation in installations {
+        if let Some(company) = &company {
+            if let Err(err) =
+                remove_legacy_registry_entry(company, installation.key(), installation.path())
+            {
+                errors.push((
+                    installation.key().clone(),
+                    anyhow!(
+                        "Failed to clear legacy registry entries under HKCU:\\{astral_key}: {err}"
+                    ),
+                ));
+            }
+        }
+
         let python_tag = registry_python_tag(installation.key());
         let python_entry = format!("{astral_key}\\{python_tag}");
         debug!("Removing registry key HKCU:\\{}", python_entry);
@@ -292,3 +370,61 @@ pub fn remove_orphan_registry_entries(installations: &[ManagedPythonInstallation
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use windows_registry::{CURRENT_USER, HSTRING, Value};
+
+    use super::{legacy_registry_python_tag, registry_python_tag, remove_legacy_registry_entry};
+    use crate::PythonInstallationKey;
+
+    #[test]
+    fn tags() {
+        let key = PythonInstallationKey::from_str("cpython-3.13.1-windows-x86_64-none").unwrap();
+        assert_eq!(registry_python_tag(&key), "CPython3.13.1");
+        assert_eq!(legacy_registry_python_tag(&key), "CPython3.13.1");
+
+        let key =
+            PythonInstallationKey::from_str("cpython-3.13.1+freethreaded-windows-aarch64-none")
+                .unwrap();
+        assert_eq!(registry_python_tag(&key), "CPython3.13.1t-arm64");
+        assert_eq!(legacy_registry_python_tag(&key), "CPython3.13.1");
+    }
+
+    #[test]
+    fn remove_legacy_entry() {
+        let company_key = format!("Software\\uv-test\\registry-{}", std::process::id());
+        let company = CURRENT_USER.create(&company_key).unwrap();
+
+        let write_legacy_entry = |path: &Path| {
+            let install_path = company.create("CPython3.13.1\\InstallPath").unwrap();
+            install_path
+                .set_value("", &Value::from(&HSTRING::from(path.as_os_str())))
+                .unwrap();
+        };
+        let key =
+            PythonInstallationKey::from_str("cpython-3.13.1+freethreaded-windows-x86_64-none")
+                .unwrap();
+        let path = Path::new(r"C:\uv\python\cpython-3.13.1+freethreaded-windows-x86_64-none");
+
+        // A legacy entry that belongs to another installation is retained.
+        write_legacy_entry(Path::new(
+            r"C:\uv\python\cpython-3.13.1-windows-x86_64-none",
+        ));
+        remove_legacy_registry_entry(&company, &key, path).unwrap();
+        assert!(company.open("CPython3.13.1").is_ok());
+
+        // A legacy entry for the installation itself is removed.
+        write_legacy_entry(path);
+        remove_legacy_registry_entry(&company, &key, path).unwrap();
+        assert!(company.open("CPython3.13.1").is_err());
+
+        // Removing a missing entry is not an error.
+        remove_legacy_registry_entry(&company, &key, path).unwrap();
+
+        CURRENT_USER.remove_tree(&company_key).unwrap();
+    }
+}
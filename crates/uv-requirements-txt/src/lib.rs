@@ -44,9 +44,14 @@ use tracing::instrument;
 use unscanny::{Pattern, Scanner};
 use url::Url;
 
+use uv_cache::Cache;
 #[cfg(feature = "http")]
-use uv_client::BaseClient;
+use uv_cache::CacheBucket;
+#[cfg(feature = "http")]
+use uv_cache_key::cache_digest;
 use uv_client::BaseClientBuilder;
+#[cfg(feature = "http")]
+use uv_client::{CacheControl, CachedClient, CachedClientError, Connectivity};
 use uv_configuration::{NoBinary, NoBuild, PackageNameSpecifier};
 use uv_distribution_types::{
     Requirement, UnresolvedRequirement, UnresolvedRequirementSpecification,
@@ -160,7 +165,10 @@ pub struct RequirementsTxt {
 }
 
 impl RequirementsTxt {
-    /// See module level documentation
+    /// See module level documentation.
+    ///
+    /// Remote requirements files are cached in the given [`Cache`], if any, such that they can be
+    /// revalidated (or, when offline, reused) on subsequent invocations.
     #[instrument(
         skip_all,
         fields(requirements_txt = requirements_txt.as_ref().as_os_str().to_str())
@@ -169,6 +177,7 @@ impl RequirementsTxt {
         requirements_txt: impl AsRef<Path>,
         working_dir: impl AsRef<Path>,
         client_builder: &BaseClientBuilder<'_>,
+        cache: Option<&Cache>,
     ) -> Result<Self, RequirementsTxtFileError> {
         let requirements_txt = requirements_txt.as_ref();
         let working_dir = working_dir.as_ref();
@@ -188,8 +197,15 @@ impl RequirementsTxt {
 
                 #[cfg(feature = "http")]
                 {
-                    // Avoid constructing a client if network is disabled already
-                    if client_builder.is_offline() {
+                    // Avoid constructing a client if network is disabled already, unless we have a
+                    // cached copy of the file.
+                    if client_builder.is_offline()
+                        && !cache.is_some_and(|cache| {
+                            remote_cache_entry(cache, requirements_txt)
+                                .path()
+                                .is_file()
+                        })
+                    {
                         return Err(RequirementsTxtFileError {
                             file: requirements_txt.to_path_buf(),
                             error: RequirementsTxtParserError::Io(io::Error::new(
@@ -199,8 +215,7 @@ impl RequirementsTxt {
                         });
                     }
 
-                    let client = client_builder.build();
-                    read_url_to_string(&requirements_txt, client).await
+                    read_url_to_string(&requirements_txt, client_builder, cache).await
                 }
             } else {
                 // Ex) `file:///home/ferris/project/requirements.txt`
@@ -219,6 +234,7 @@ impl RequirementsTxt {
             working_dir,
             requirements_dir,
             client_builder,
+            cache,
             requirements_txt,
         )
         .await
@@ -241,6 +257,7 @@ impl RequirementsTxt {
         working_dir: &Path,
         requirements_dir: &Path,
         client_builder: &BaseClientBuilder<'_>,
+        cache: Option<&Cache>,
         requirements_txt: &Path,
     ) -> Result<Self, RequirementsTxtParserError> {
         let mut s = Scanner::new(content);
@@ -277,7 +294,7 @@ impl RequirementsTxt {
                             requirements_dir.join(filename.as_ref())
                         };
                     let sub_requirements =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
+                        Box::pin(Self::parse(&sub_file, working_dir, client_builder, cache))
                             .await
                             .map_err(|err| RequirementsTxtParserError::Subfile {
                                 source: Box::new(err),
@@ -332,7 +349,7 @@ impl RequirementsTxt {
                             requirements_dir.join(filename.as_ref())
                         };
                     let sub_constraints =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
+                        Box::pin(Self::parse(&sub_file, working_dir, client_builder, cache))
                             .await
                             .map_err(|err| RequirementsTxtParserError::Subfile {
                                 source: Box::new(err),
@@ -937,11 +954,24 @@ fn parse_value<'a, T>(
     Ok(value)
 }
 
+/// Return the cache entry for a remote requirements file.
+#[cfg(feature = "http")]
+fn remote_cache_entry(cache: &Cache, path: &Path) -> uv_cache::CacheEntry {
+    cache.entry(
+        CacheBucket::Requirements,
+        "remote",
+        format!("{}.msgpack", cache_digest(&path)),
+    )
+}
+
 /// Fetch the contents of a URL and return them as a string.
+///
+/// If a [`Cache`] is provided, the response is cached according to its HTTP caching headers.
 #[cfg(feature = "http")]
 async fn read_url_to_string(
     path: impl AsRef<Path>,
-    client: BaseClient,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Option<&Cache>,
 ) -> Result<String, RequirementsTxtParserError> {
     // pip would URL-encode the non-UTF-8 bytes of the string; we just don't support them.
     let path_utf8 =
@@ -953,19 +983,55 @@ async fn read_url_to_string(
 
     let url = DisplaySafeUrl::from_str(path_utf8)
         .map_err(|err| RequirementsTxtParserError::InvalidUrl(path_utf8.to_string(), err))?;
-    let response = client
+    let client = client_builder.build();
+
+    let Some(cache) = cache else {
+        let response = client
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .send()
+            .await
+            .map_err(|err| RequirementsTxtParserError::from_reqwest_middleware(url.clone(), err))?;
+        let text = response
+            .error_for_status()
+            .map_err(|err| RequirementsTxtParserError::from_reqwest(url.clone(), err))?
+            .text()
+            .await
+            .map_err(|err| RequirementsTxtParserError::from_reqwest(url.clone(), err))?;
+        return Ok(text);
+    };
+
+    let cache_entry = remote_cache_entry(cache, path.as_ref());
+    let cache_control = match client_builder.connectivity {
+        Connectivity::Online => CacheControl::from(
+            cache
+                .freshness(&cache_entry, None, None)
+                .map_err(RequirementsTxtParserError::Io)?,
+        ),
+        Connectivity::Offline => CacheControl::AllowStale,
+    };
+
+    let request = client
         .for_host(&url)
         .get(Url::from(url.clone()))
-        .send()
-        .await
-        .map_err(|err| RequirementsTxtParserError::from_reqwest_middleware(url.clone(), err))?;
-    let text = response
-        .error_for_status()
-        .map_err(|err| RequirementsTxtParserError::from_reqwest(url.clone(), err))?
-        .text()
-        .await
+        .build()
         .map_err(|err| RequirementsTxtParserError::from_reqwest(url.clone(), err))?;
-    Ok(text)
+    CachedClient::new(client)
+        .get_serde_with_retry(
+            request,
+            &cache_entry,
+            cache_control,
+            async |response: reqwest::Response| response.text().await,
+        )
+        .await
+        .map_err(|err| match err {
+            CachedClientError::Client { err, .. } => {
+                RequirementsTxtParserError::Client(url.clone(), Box::new(err))
+            }
+            CachedClientError::Callback { err, .. } => {
+                RequirementsTxtParserError::from_reqwest(url.clone(), err)
+            }
+        })
 }
 
 /// Error parsing requirements.txt, wrapper with filename
@@ -1051,6 +1117,8 @@ pub enum RequirementsTxtParserError {
     #[cfg(feature = "http")]
     Reqwest(DisplaySafeUrl, reqwest_middleware::Error),
     #[cfg(feature = "http")]
+    Client(DisplaySafeUrl, Box<uv_client::Error>),
+    #[cfg(feature = "http")]
     InvalidUrl(String, url::ParseError),
 }
 
@@ -1118,7 +1186,7 @@ impl Display for RequirementsTxtParserError {
                 )
             }
             #[cfg(feature = "http")]
-            Self::Reqwest(url, _err) => {
+            Self::Reqwest(url, _) | Self::Client(url, _) => {
                 write!(f, "Error while accessing remote requirements file: `{url}`")
             }
             #[cfg(feature = "http")]
@@ -1151,6 +1219,9 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::NonUnicodeUrl { .. } => None,
             #[cfg(feature = "http")]
             Self::Reqwest(_, err) => err.source(),
+            // Skip the client's own "failed to fetch" context, which repeats the URL.
+            #[cfg(feature = "http")]
+            Self::Client(_, err) => err.source().and_then(std::error::Error::source),
             #[cfg(feature = "http")]
             Self::InvalidUrl(_, err) => err.source(),
         }
@@ -1278,7 +1349,8 @@ impl Display for RequirementsTxtFileError {
                 )
             }
             #[cfg(feature = "http")]
-            RequirementsTxtParserError::Reqwest(url, _err) => {
+            RequirementsTxtParserError::Reqwest(url, _)
+            | RequirementsTxtParserError::Client(url, _) => {
                 write!(f, "Error while accessing remote requirements file: `{url}`")
             }
             #[cfg(feature = "http")]
@@ -1407,6 +1479,7 @@ mod test {
             requirements_txt.clone(),
             &working_dir,
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -1455,10 +1528,14 @@ mod test {
         let requirements_txt = temp_dir.path().join(path);
         fs::write(&requirements_txt, contents).unwrap();
 
-        let actual =
-            RequirementsTxt::parse(&requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap();
+        let actual = RequirementsTxt::parse(
+            &requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let snapshot = format!("line-endings-{}", path.to_string_lossy());
 
@@ -1477,10 +1554,14 @@ mod test {
         let working_dir = workspace_test_data_dir().join("requirements-txt");
         let requirements_txt = working_dir.join(path);
 
-        let actual =
-            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap();
+        let actual = RequirementsTxt::parse(
+            requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let snapshot = format!("parse-unix-{}", path.to_string_lossy());
 
@@ -1499,10 +1580,14 @@ mod test {
         let working_dir = workspace_test_data_dir().join("requirements-txt");
         let requirements_txt = working_dir.join(path);
 
-        let actual =
-            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap_err();
+        let actual = RequirementsTxt::parse(
+            requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap_err();
 
         let snapshot = format!("parse-unix-{}", path.to_string_lossy());
 
@@ -1521,10 +1606,14 @@ mod test {
         let working_dir = workspace_test_data_dir().join("requirements-txt");
         let requirements_txt = working_dir.join(path);
 
-        let actual =
-            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
-                .await
-                .unwrap();
+        let actual = RequirementsTxt::parse(
+            requirements_txt,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let snapshot = format!("parse-windows-{}", path.to_string_lossy());
 
@@ -1548,6 +1637,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1593,6 +1683,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1626,6 +1717,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1659,6 +1751,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1692,6 +1785,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1723,6 +1817,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1756,6 +1851,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1787,6 +1883,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1819,6 +1916,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1852,6 +1950,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -1895,6 +1994,7 @@ mod test {
             parent_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -1959,6 +2059,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -2034,6 +2135,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -2136,6 +2238,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
@@ -2184,6 +2287,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -2399,6 +2503,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap();
@@ -2741,6 +2846,7 @@ mod test {
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
+            None,
         )
         .await
         .unwrap_err();
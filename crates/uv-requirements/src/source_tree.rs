@@ -159,16 +159,13 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
 
         // Determine the hash policy. Since we don't have a package name, we perform a
         // manual match.
+        //
+        // Source trees can't be hashed, so in `--require-hashes` mode, any hashes are instead
+        // validated against the wheel built from the source tree at install time.
         let hashes = match self.hasher {
-            HashStrategy::None => HashPolicy::None,
+            HashStrategy::None | HashStrategy::Require(_) => HashPolicy::None,
             HashStrategy::Generate(mode) => HashPolicy::Generate(*mode),
             HashStrategy::Verify(_) => HashPolicy::Generate(HashGeneration::All),
-            HashStrategy::Require(_) => {
-                return Err(anyhow::anyhow!(
-                    "Hash-checking is not supported for local directories: {}",
-                    path.user_display()
-                ));
-            }
         };
 
         // Fetch the metadata for the distribution.
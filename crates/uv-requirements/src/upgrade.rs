@@ -42,6 +42,7 @@ pub async fn read_requirements_txt(
         output_file,
         &*CWD,
         &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+        None,
     )
     .await?;
 
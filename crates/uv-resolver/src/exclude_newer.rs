@@ -5,7 +5,7 @@ use std::str::FromStr;
 use jiff::{Timestamp, ToSpan, tz::TimeZone};
 
 /// A timestamp that excludes files newer than it.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
 pub struct ExcludeNewer(Timestamp);
 
 impl ExcludeNewer {
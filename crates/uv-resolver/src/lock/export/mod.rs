@@ -12,7 +12,7 @@ use uv_configuration::{
     DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName};
-use uv_pep508::MarkerTree;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::{Reachable, marker_reachability};
@@ -42,6 +42,9 @@ struct ExportableRequirements<'lock>(Vec<ExportableRequirement<'lock>>);
 
 impl<'lock> ExportableRequirements<'lock> {
     /// Generate the set of exportable [`ExportableRequirement`] entries from the given lockfile.
+    ///
+    /// If a [`MarkerEnvironment`] is provided, only the requirements that apply to that environment
+    /// are included, and their markers are dropped.
     fn from_lock(
         target: &impl Installable<'lock>,
         prune: &[PackageName],
@@ -49,6 +52,7 @@ impl<'lock> ExportableRequirements<'lock> {
         dev: &DependencyGroupsWithDefaults,
         annotate: bool,
         install_options: &'lock InstallOptions,
+        markers: Option<&MarkerEnvironment>,
     ) -> Self {
         let size_guess = target.lock().packages.len();
         let mut graph = Graph::<Node<'lock>, Edge<'lock>>::with_capacity(size_guess, size_guess);
@@ -328,6 +332,16 @@ impl<'lock> ExportableRequirements<'lock> {
                 },
             })
             .filter(|requirement| !requirement.marker.is_false())
+            .filter_map(|mut requirement| {
+                // Resolve the marker against the target environment, if any.
+                if let Some(markers) = markers {
+                    if !requirement.marker.evaluate(markers, &[]) {
+                        return None;
+                    }
+                    requirement.marker = MarkerTree::TRUE;
+                }
+                Some(requirement)
+            })
             .collect::<Vec<_>>();
 
         Self(nodes)
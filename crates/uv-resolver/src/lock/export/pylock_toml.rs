@@ -622,6 +622,7 @@ impl<'lock> PylockToml {
         annotate: bool,
         editable: EditableMode,
         install_options: &'lock InstallOptions,
+        markers: Option<&MarkerEnvironment>,
     ) -> Result<Self, PylockTomlErrorKind> {
         // Extract the packages from the lock file.
         let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
@@ -631,6 +632,7 @@ impl<'lock> PylockToml {
             dev,
             annotate,
             install_options,
+            markers,
         );
 
         // Sort the nodes.
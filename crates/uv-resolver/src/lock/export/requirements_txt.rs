@@ -12,6 +12,7 @@ use uv_distribution_filename::{DistExtension, SourceDistExtension};
 use uv_fs::Simplified;
 use uv_git_types::GitReference;
 use uv_normalize::PackageName;
+use uv_pep508::MarkerEnvironment;
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
 use uv_redacted::DisplaySafeUrl;
 
@@ -37,6 +38,7 @@ impl<'lock> RequirementsTxtExport<'lock> {
         editable: EditableMode,
         hashes: bool,
         install_options: &'lock InstallOptions,
+        markers: Option<&MarkerEnvironment>,
     ) -> Result<Self, LockError> {
         // Extract the packages from the lock file.
         let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
@@ -46,6 +48,7 @@ impl<'lock> RequirementsTxtExport<'lock> {
             dev,
             annotate,
             install_options,
+            markers,
         );
 
         // Sort the nodes, such that unnamed URLs (editables) appear at the top.
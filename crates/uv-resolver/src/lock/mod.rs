@@ -667,6 +667,23 @@ impl Lock {
         &self.manifest.members
     }
 
+    /// Returns the workspace members in this lock whose source tree is missing from the given
+    /// workspace root, e.g., as in a sparse checkout.
+    pub fn missing_members(&self, root: &Path) -> BTreeSet<PackageName> {
+        self.packages
+            .iter()
+            .filter(|package| self.manifest.members.contains(&package.id.name))
+            .filter(|package| {
+                package
+                    .id
+                    .source
+                    .as_source_tree()
+                    .is_some_and(|path| !root.join(path).join("pyproject.toml").is_file())
+            })
+            .map(|package| package.id.name.clone())
+            .collect()
+    }
+
     /// Returns the dependency groups that were used to generate this lock.
     pub fn requirements(&self) -> &BTreeSet<Requirement> {
         &self.manifest.requirements
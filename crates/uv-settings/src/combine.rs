@@ -3,9 +3,10 @@ use std::path::PathBuf;
 
 use url::Url;
 
+use uv_cache::ArchiveCompression;
 use uv_configuration::{
     ConfigSettings, ExportFormat, IndexStrategy, KeyringProviderType, PackageConfigSettings,
-    RequiredVersion, TargetTriple, TrustedPublishing,
+    RateLimit, RefreshInterval, RequiredVersion, RetryJitter, TargetTriple, TrustedPublishing,
 };
 use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_install_wheel::LinkMode;
@@ -77,6 +78,7 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(ArchiveCompression);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExportFormat);
 impl_combine_or!(ForkStrategy);
@@ -95,8 +97,11 @@ impl_combine_or!(PrereleaseMode);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
+impl_combine_or!(RateLimit);
+impl_combine_or!(RefreshInterval);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
+impl_combine_or!(RetryJitter);
 impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
@@ -105,6 +110,10 @@ impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
 impl_combine_or!(bool);
+impl_combine_or!(f64);
+impl_combine_or!(u32);
+impl_combine_or!(u64);
+impl_combine_or!(usize);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
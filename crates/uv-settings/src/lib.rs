@@ -206,6 +206,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         install_mirrors: _,
         publish: _,
         add: _,
+        run: _,
         pip: _,
         cache_keys: _,
         override_dependencies: _,
@@ -214,6 +215,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         environments,
         required_environments,
         conflicts,
+        constraint_files,
         workspace,
         sources,
         dev_dependencies,
@@ -221,6 +223,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         dependency_groups,
         managed,
         package,
+        tasks,
         build_backend,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -229,6 +232,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if conflicts.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "conflicts"));
     }
+    if constraint_files.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "constraint-files",
+        ));
+    }
     if workspace.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "workspace"));
     }
@@ -259,6 +268,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if tasks.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "tasks"));
+    }
     if build_backend.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -292,12 +304,24 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_compression,
+                cache_environment_max_age,
+                cache_environment_max_size,
+                cache_environment_max_count,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                limit_rate,
+                proxy,
+                http_retries,
+                http_retry_base_delay,
+                http_retry_max_delay,
+                http_retry_jitter,
+                http_retry_status_codes,
+                strict_index_tracking,
                 allow_insecure_host,
             },
         top_level:
@@ -313,6 +337,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 prerelease,
                 fork_strategy,
                 dependency_metadata,
+                build_system_overrides,
                 config_settings,
                 config_settings_package,
                 no_build_isolation,
@@ -335,14 +360,22 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 python_install_mirror,
                 pypy_install_mirror,
                 python_downloads_json_url,
+                python_install_require_attestations,
             },
         publish:
             PublishOptions {
                 publish_url,
                 trusted_publishing,
                 check_url,
+                concurrent_uploads,
             },
         add: AddOptions { add_bounds },
+        run:
+            RunOptions {
+                env_file,
+                lock_scripts,
+                tool_run_refresh,
+            },
         pip,
         cache_keys,
         override_dependencies,
@@ -351,6 +384,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         environments: _,
         required_environments: _,
         conflicts: _,
+        constraint_files: _,
         workspace: _,
         sources: _,
         dev_dependencies: _,
@@ -358,6 +392,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         dependency_groups: _,
         managed: _,
         package: _,
+        tasks: _,
         build_backend: _,
     } = options;
 
@@ -378,6 +413,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if cache_compression.is_some() {
+        masked_fields.push("cache-compression");
+    }
+    if cache_environment_max_age.is_some() {
+        masked_fields.push("cache-environment-max-age");
+    }
+    if cache_environment_max_size.is_some() {
+        masked_fields.push("cache-environment-max-size");
+    }
+    if cache_environment_max_count.is_some() {
+        masked_fields.push("cache-environment-max-count");
+    }
     if preview.is_some() {
         masked_fields.push("preview");
     }
@@ -396,6 +443,30 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if concurrent_installs.is_some() {
         masked_fields.push("concurrent-installs");
     }
+    if limit_rate.is_some() {
+        masked_fields.push("limit-rate");
+    }
+    if proxy.is_some() {
+        masked_fields.push("proxy");
+    }
+    if http_retries.is_some() {
+        masked_fields.push("http-retries");
+    }
+    if http_retry_base_delay.is_some() {
+        masked_fields.push("http-retry-base-delay");
+    }
+    if http_retry_max_delay.is_some() {
+        masked_fields.push("http-retry-max-delay");
+    }
+    if http_retry_jitter.is_some() {
+        masked_fields.push("http-retry-jitter");
+    }
+    if http_retry_status_codes.is_some() {
+        masked_fields.push("http-retry-status-codes");
+    }
+    if strict_index_tracking.is_some() {
+        masked_fields.push("strict-index-tracking");
+    }
     if allow_insecure_host.is_some() {
         masked_fields.push("allow-insecure-host");
     }
@@ -432,6 +503,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
+    if build_system_overrides.is_some() {
+        masked_fields.push("build-system-overrides");
+    }
     if config_settings.is_some() {
         masked_fields.push("config-settings");
     }
@@ -489,6 +563,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if python_downloads_json_url.is_some() {
         masked_fields.push("python-downloads-json-url");
     }
+    if python_install_require_attestations.is_some() {
+        masked_fields.push("python-install-require-attestations");
+    }
     if publish_url.is_some() {
         masked_fields.push("publish-url");
     }
@@ -498,9 +575,21 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if check_url.is_some() {
         masked_fields.push("check-url");
     }
+    if concurrent_uploads.is_some() {
+        masked_fields.push("concurrent-uploads");
+    }
     if add_bounds.is_some() {
         masked_fields.push("add-bounds");
     }
+    if env_file.is_some() {
+        masked_fields.push("env-file");
+    }
+    if lock_scripts.is_some() {
+        masked_fields.push("lock-scripts");
+    }
+    if tool_run_refresh.is_some() {
+        masked_fields.push("tool-run-refresh");
+    }
     if pip.is_some() {
         masked_fields.push("pip");
     }
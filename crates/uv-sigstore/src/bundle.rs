@@ -0,0 +1,449 @@
+//! Sigstore bundles, which carry a signature together with the material to verify it.
+//!
+//! See: <https://docs.sigstore.dev/about/bundle/>
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use ring::digest::{SHA256, digest};
+use ring::signature::{ECDSA_P256_SHA256_ASN1, UnparsedPublicKey};
+use serde_json::json;
+
+use crate::certificate::{
+    SOURCE_REPOSITORY_URI_OID, certificate_extension, verify_certificate_signature,
+};
+use crate::instance::SigstoreInstance;
+use crate::rekor::verify_bundle_log_entry;
+use crate::{IN_TOTO_PAYLOAD_TYPE, SigstoreError, pem_to_der};
+
+/// The media type of Sigstore bundles.
+const BUNDLE_MEDIA_TYPE: &str = "application/vnd.dev.sigstore.bundle.v0.3+json";
+
+/// How the key of a signature is bound to an identity.
+#[derive(Debug, Clone, Copy)]
+pub enum BundleIdentity<'a> {
+    /// A PEM-encoded Fulcio certificate.
+    Certificate(&'a str),
+    /// A public key that consumers are expected to know.
+    PublicKey,
+}
+
+/// Assemble the bundle for a signature of a message and its transparency log entry.
+pub fn signature_bundle(
+    identity: BundleIdentity,
+    digest: &[u8],
+    signature: &[u8],
+    transparency_entry: &serde_json::Value,
+) -> Result<serde_json::Value, SigstoreError> {
+    let verification_material = match identity {
+        BundleIdentity::Certificate(certificate) => json!({
+            "certificate": { "rawBytes": BASE64_STANDARD.encode(pem_to_der(certificate)?) },
+            "tlogEntries": [transparency_entry],
+        }),
+        // Consumers are expected to know the public key, there's no key identifier to provide as
+        // hint.
+        BundleIdentity::PublicKey => json!({
+            "publicKey": { "hint": "" },
+            "tlogEntries": [transparency_entry],
+        }),
+    };
+
+    Ok(json!({
+        "mediaType": BUNDLE_MEDIA_TYPE,
+        "verificationMaterial": verification_material,
+        "messageSignature": {
+            "messageDigest": {
+                "algorithm": "SHA2_256",
+                "digest": BASE64_STANDARD.encode(digest),
+            },
+            "signature": BASE64_STANDARD.encode(signature),
+        },
+    }))
+}
+
+/// Verify that a bundle is a signature of the file contents, recorded in the transparency log.
+///
+/// With a public key, the signature must be made with that key, otherwise with the key of a
+/// certificate from the trusted root.
+pub fn verify_signature_bundle(
+    bundle: &serde_json::Value,
+    contents: &[u8],
+    public_key: Option<&[u8]>,
+    instance: &SigstoreInstance,
+) -> Result<(), SigstoreError> {
+    let decode = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .and_then(|value| BASE64_STANDARD.decode(value).ok())
+    };
+
+    if bundle["mediaType"] != BUNDLE_MEDIA_TYPE {
+        return Err(SigstoreError::InvalidBundle("unsupported media type"));
+    }
+    let message_signature = &bundle["messageSignature"];
+    if message_signature["messageDigest"]["algorithm"] != "SHA2_256"
+        || decode(&message_signature["messageDigest"]["digest"]).as_deref()
+            != Some(digest(&SHA256, contents).as_ref())
+    {
+        return Err(SigstoreError::InvalidBundle(
+            "the digest doesn't match the file",
+        ));
+    }
+    let signature = decode(&message_signature["signature"])
+        .ok_or(SigstoreError::InvalidBundle("missing signature"))?;
+
+    let verification_material = &bundle["verificationMaterial"];
+    let [entry] = verification_material["tlogEntries"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+    else {
+        return Err(SigstoreError::InvalidBundle(
+            "expected a single transparency log entry",
+        ));
+    };
+    let integrated_time = verify_bundle_log_entry(entry, "hashedrekord", &signature, instance)?;
+
+    match (
+        public_key,
+        decode(&verification_material["certificate"]["rawBytes"]),
+    ) {
+        (Some(public_key), None) if verification_material["publicKey"].is_object() => {
+            UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public_key)
+                .verify(contents, &signature)
+                .map_err(|_| {
+                    SigstoreError::InvalidBundle("the signature wasn't made with the signing key")
+                })
+        }
+        (None, Some(certificate)) => verify_certificate_signature(
+            &certificate,
+            integrated_time,
+            contents,
+            &signature,
+            instance,
+        ),
+        (Some(_), _) => Err(SigstoreError::InvalidBundle(
+            "the file was signed without the signing key",
+        )),
+        (None, None) => Err(SigstoreError::InvalidBundle(
+            "the file was signed with a key instead of a certificate",
+        )),
+    }
+}
+
+/// Verify a Sigstore bundle that attests to an artifact, such as a GitHub artifact attestation.
+///
+/// The in-toto statement of the bundle must have the SHA-256 digest of the artifact as a subject,
+/// the statement must be signed with the key of a certificate from the trusted root that was issued
+/// to a workflow of `repository`, and the signature must be recorded in the transparency log.
+pub fn verify_attestation(
+    bundle: &serde_json::Value,
+    sha256: &str,
+    repository: &str,
+    instance: &SigstoreInstance,
+) -> Result<(), SigstoreError> {
+    let decode = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .and_then(|value| BASE64_STANDARD.decode(value).ok())
+    };
+
+    if bundle["mediaType"] != BUNDLE_MEDIA_TYPE {
+        return Err(SigstoreError::InvalidBundle("unsupported media type"));
+    }
+    let envelope = &bundle["dsseEnvelope"];
+    if envelope["payloadType"] != IN_TOTO_PAYLOAD_TYPE {
+        return Err(SigstoreError::InvalidBundle("unsupported payload type"));
+    }
+    let statement =
+        decode(&envelope["payload"]).ok_or(SigstoreError::InvalidBundle("missing statement"))?;
+    let [signature] = envelope["signatures"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+    else {
+        return Err(SigstoreError::InvalidBundle("expected a single signature"));
+    };
+    let signature =
+        decode(&signature["sig"]).ok_or(SigstoreError::InvalidBundle("missing signature"))?;
+
+    let subjects = serde_json::from_slice::<serde_json::Value>(&statement)?;
+    let attests_artifact = subjects["subject"].as_array().is_some_and(|subjects| {
+        subjects.iter().any(|subject| {
+            subject["digest"]["sha256"]
+                .as_str()
+                .is_some_and(|digest| digest.eq_ignore_ascii_case(sha256))
+        })
+    });
+    if !attests_artifact {
+        return Err(SigstoreError::InvalidBundle(
+            "the attestation is for a different artifact",
+        ));
+    }
+
+    let verification_material = &bundle["verificationMaterial"];
+    let [entry] = verification_material["tlogEntries"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+    else {
+        return Err(SigstoreError::InvalidBundle(
+            "expected a single transparency log entry",
+        ));
+    };
+    let integrated_time = verify_bundle_log_entry(entry, "dsse", &signature, instance)?;
+
+    let certificate = decode(&verification_material["certificate"]["rawBytes"])
+        .ok_or(SigstoreError::InvalidBundle("missing certificate"))?;
+    verify_certificate_signature(
+        &certificate,
+        integrated_time,
+        &pre_authentication_encoding(&statement),
+        &signature,
+        instance,
+    )?;
+    if certificate_extension(&certificate, &SOURCE_REPOSITORY_URI_OID) != Some(repository) {
+        return Err(SigstoreError::UntrustedIdentity(repository.to_string()));
+    }
+    Ok(())
+}
+
+/// The DSSE pre-authentication encoding of an in-toto statement, which is what's signed.
+///
+/// See: <https://github.com/secure-systems-lab/dsse/blob/v1.0.0/protocol.md>
+pub fn pre_authentication_encoding(statement: &[u8]) -> Vec<u8> {
+    let mut pae = format!(
+        "DSSEv1 {} {} {} ",
+        IN_TOTO_PAYLOAD_TYPE.len(),
+        IN_TOTO_PAYLOAD_TYPE,
+        statement.len()
+    )
+    .into_bytes();
+    pae.extend_from_slice(statement);
+    pae
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair};
+
+    use super::*;
+    use crate::rekor::tests::TestLog;
+    use crate::trusted_root::TrustedRoot;
+
+    /// A certificate authority and a code signing certificate issued by it, with the key of the
+    /// certificate, from `test-data/sigstore/generate.sh`.
+    const TEST_CA: &str = include_str!("../test-data/sigstore/ca.pem");
+    const TEST_LEAF: &str = include_str!("../test-data/sigstore/leaf.pem");
+    const TEST_LEAF_KEY: &str = include_str!("../test-data/sigstore/leaf.key");
+
+    /// The source repository recorded in the test certificate.
+    const TEST_REPOSITORY: &str = "https://github.com/astral-sh/uv";
+
+    /// A time at which the test certificate is valid.
+    const INTEGRATED_TIME: i64 = 1_800_000_000;
+
+    fn leaf_key() -> (EcdsaKeyPair, SystemRandom) {
+        let rng = SystemRandom::new();
+        let key_pair = EcdsaKeyPair::from_pkcs8(
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &pem_to_der(TEST_LEAF_KEY).unwrap(),
+            &rng,
+        )
+        .unwrap();
+        (key_pair, rng)
+    }
+
+    fn ephemeral_key() -> (EcdsaKeyPair, SystemRandom) {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                .unwrap();
+        (key_pair, rng)
+    }
+
+    /// Sign the contents and record the signature in the log, without any network requests.
+    fn sign(
+        (key_pair, rng): &(EcdsaKeyPair, SystemRandom),
+        identity: BundleIdentity,
+        contents: &[u8],
+        log: &TestLog,
+    ) -> serde_json::Value {
+        let signature = key_pair.sign(rng, contents).unwrap();
+        let entry = log.entry("hashedrekord", signature.as_ref(), INTEGRATED_TIME);
+        signature_bundle(
+            identity,
+            digest(&SHA256, contents).as_ref(),
+            signature.as_ref(),
+            &entry,
+        )
+        .unwrap()
+    }
+
+    /// Attest to an artifact with the test certificate, recording the signature in the log.
+    fn attestation_bundle(statement: &serde_json::Value, log: &TestLog) -> serde_json::Value {
+        let (key_pair, rng) = leaf_key();
+        let statement = serde_json::to_vec(statement).unwrap();
+        let signature = key_pair
+            .sign(&rng, &pre_authentication_encoding(&statement))
+            .unwrap();
+        json!({
+            "mediaType": BUNDLE_MEDIA_TYPE,
+            "verificationMaterial": {
+                "certificate": {
+                    "rawBytes": BASE64_STANDARD.encode(pem_to_der(TEST_LEAF).unwrap()),
+                },
+                "tlogEntries": [log.entry("dsse", signature.as_ref(), INTEGRATED_TIME)],
+            },
+            "dsseEnvelope": {
+                "payload": BASE64_STANDARD.encode(&statement),
+                "payloadType": IN_TOTO_PAYLOAD_TYPE,
+                "signatures": [{ "sig": BASE64_STANDARD.encode(signature.as_ref()) }],
+            },
+        })
+    }
+
+    #[test]
+    fn key_bundle() {
+        let key = ephemeral_key();
+        let public_key = key.0.public_key().as_ref().to_vec();
+        let log = TestLog::new();
+        let instance = log.instance(TEST_CA);
+        let bundle = sign(&key, BundleIdentity::PublicKey, b"contents", &log);
+
+        verify_signature_bundle(&bundle, b"contents", Some(&public_key), &instance).unwrap();
+
+        // The bundle is stale if the file changed, the key changed, or keyless signing is used.
+        let err =
+            verify_signature_bundle(&bundle, b"rebuilt", Some(&public_key), &instance).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid Sigstore bundle: the digest doesn't match the file"
+        );
+        let other = ephemeral_key().0.public_key().as_ref().to_vec();
+        let err =
+            verify_signature_bundle(&bundle, b"contents", Some(&other), &instance).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid Sigstore bundle: the signature wasn't made with the signing key"
+        );
+        let err = verify_signature_bundle(&bundle, b"contents", None, &instance).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid Sigstore bundle: the file was signed with a key instead of a certificate"
+        );
+
+        // The signature must be recorded in a trusted log.
+        let err = verify_signature_bundle(
+            &bundle,
+            b"contents",
+            Some(&public_key),
+            &TestLog::new().instance(TEST_CA),
+        )
+        .unwrap_err();
+        assert!(matches!(err, SigstoreError::UnknownLog(_)), "{err:?}");
+    }
+
+    #[test]
+    fn certificate_bundle() {
+        let log = TestLog::new();
+        let bundle = sign(
+            &leaf_key(),
+            BundleIdentity::Certificate(TEST_LEAF),
+            b"contents",
+            &log,
+        );
+
+        verify_signature_bundle(&bundle, b"contents", None, &log.instance(TEST_CA)).unwrap();
+
+        // A keyless signature isn't reused when signing with a key.
+        let public_key = ephemeral_key().0.public_key().as_ref().to_vec();
+        let err = verify_signature_bundle(
+            &bundle,
+            b"contents",
+            Some(&public_key),
+            &log.instance(TEST_CA),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid Sigstore bundle: the file was signed without the signing key"
+        );
+
+        // The certificate must chain to the trusted root.
+        let err = verify_signature_bundle(&bundle, b"contents", None, &log.instance(TEST_LEAF))
+            .unwrap_err();
+        assert!(
+            matches!(err, SigstoreError::UntrustedCertificate(_)),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn attestation() {
+        let log = TestLog::new();
+        let instance = log.instance(TEST_CA);
+        let statement = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicateType": "https://slsa.dev/provenance/v1",
+            "subject": [{ "name": "python.tar.gz", "digest": { "sha256": "abc123" } }],
+        });
+        let bundle = attestation_bundle(&statement, &log);
+        verify_attestation(&bundle, "ABC123", TEST_REPOSITORY, &instance).unwrap();
+
+        // The attestation must be for the artifact, and signed by a workflow of the repository.
+        let err = verify_attestation(&bundle, "def456", TEST_REPOSITORY, &instance).unwrap_err();
+        assert!(matches!(err, SigstoreError::InvalidBundle(_)), "{err:?}");
+        let err = verify_attestation(
+            &bundle,
+            "abc123",
+            "https://github.com/astral-sh/python-build-standalone",
+            &instance,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, SigstoreError::UntrustedIdentity(_)),
+            "{err:?}"
+        );
+
+        // The certificate must chain to the trusted root.
+        let mut untrusted = instance.clone();
+        untrusted.trusted_root.roots = TrustedRoot::default_root().unwrap().roots;
+        let err = verify_attestation(&bundle, "abc123", TEST_REPOSITORY, &untrusted).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                SigstoreError::UntrustedCertificate(webpki::Error::UnknownIssuer)
+            ),
+            "{err:?}"
+        );
+
+        // A statement that was changed after signing is rejected.
+        let mut tampered = bundle.clone();
+        let other = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicateType": "https://slsa.dev/provenance/v1",
+            "subject": [{ "name": "python.tar.gz", "digest": { "sha256": "def456" } }],
+        });
+        tampered["dsseEnvelope"]["payload"] =
+            json!(BASE64_STANDARD.encode(serde_json::to_vec(&other).unwrap()));
+        let err = verify_attestation(&tampered, "def456", TEST_REPOSITORY, &instance).unwrap_err();
+        assert!(matches!(err, SigstoreError::InvalidBundle(_)), "{err:?}");
+
+        // The signature must be recorded in the transparency log.
+        let mut unlogged = bundle.clone();
+        unlogged["verificationMaterial"]["tlogEntries"] =
+            attestation_bundle(&other, &log)["verificationMaterial"]["tlogEntries"].clone();
+        let err = verify_attestation(&unlogged, "abc123", TEST_REPOSITORY, &instance).unwrap_err();
+        assert!(matches!(err, SigstoreError::LogEntryMismatch), "{err:?}");
+    }
+
+    #[test]
+    fn pae() {
+        assert_eq!(
+            pre_authentication_encoding(b"{}"),
+            b"DSSEv1 28 application/vnd.in-toto+json 2 {}"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use rustls_pki_types::{CertificateDer, UnixTime};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::der_parser::der::parse_der_utf8string;
+use x509_parser::der_parser::oid;
+use x509_parser::oid_registry::Oid;
+use x509_parser::prelude::FromDer;
+
+use crate::SigstoreError;
+use crate::instance::SigstoreInstance;
+use crate::trusted_root::TrustedRoot;
+
+/// The `id-kp-codeSigning` extended key usage of Fulcio certificates.
+const ID_KP_CODE_SIGNING: &[u8] = &oid!(raw 1.3.6.1.5.5.7.3.3);
+
+/// The Fulcio certificate extension with the source repository URI of the signing workflow.
+pub(crate) const SOURCE_REPOSITORY_URI_OID: Oid<'static> = oid!(1.3.6.1.4.1.57264.1.12);
+
+/// Verify that a code signing certificate chains to the trusted root at the given time.
+pub(crate) fn verify_chain<'a>(
+    leaf: &'a CertificateDer<'a>,
+    intermediates: &[CertificateDer<'static>],
+    time: UnixTime,
+    trusted_root: &TrustedRoot,
+) -> Result<webpki::EndEntityCert<'a>, SigstoreError> {
+    let intermediates: Vec<CertificateDer> = intermediates
+        .iter()
+        .chain(&trusted_root.intermediates)
+        .cloned()
+        .collect();
+    let anchors = trusted_root
+        .roots
+        .iter()
+        .map(webpki::anchor_from_trusted_cert)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(SigstoreError::UntrustedCertificate)?;
+
+    let end_entity =
+        webpki::EndEntityCert::try_from(leaf).map_err(SigstoreError::UntrustedCertificate)?;
+    end_entity
+        .verify_for_usage(
+            webpki::ALL_VERIFICATION_ALGS,
+            &anchors,
+            &intermediates,
+            time,
+            webpki::KeyUsage::required(ID_KP_CODE_SIGNING),
+            None,
+            None,
+        )
+        .map_err(SigstoreError::UntrustedCertificate)?;
+    Ok(end_entity)
+}
+
+/// Verify a signature made with the key of a DER-encoded Fulcio certificate.
+///
+/// Fulcio certificates are only valid for a few minutes, so the certificate must have chained to
+/// the trusted root at `integrated_time`, when the signature was recorded in the transparency log.
+pub(crate) fn verify_certificate_signature(
+    certificate: &[u8],
+    integrated_time: i64,
+    message: &[u8],
+    signature: &[u8],
+    instance: &SigstoreInstance,
+) -> Result<(), SigstoreError> {
+    let certificate = CertificateDer::from(certificate);
+    let time = u64::try_from(integrated_time)
+        .map(|seconds| UnixTime::since_unix_epoch(Duration::from_secs(seconds)))
+        .map_err(|_| SigstoreError::InvalidLogEntry)?;
+    let end_entity = verify_chain(&certificate, &[], time, &instance.trusted_root)?;
+    end_entity
+        .verify_signature(webpki::ring::ECDSA_P256_SHA256, message, signature)
+        .map_err(|_| SigstoreError::InvalidBundle("the signature doesn't match the file"))
+}
+
+/// Return the value of a Fulcio certificate extension that contains a UTF-8 string.
+pub(crate) fn certificate_extension<'a>(certificate: &'a [u8], oid: &Oid) -> Option<&'a str> {
+    let (_, certificate) = X509Certificate::from_der(certificate).ok()?;
+    let extension = certificate.get_extension_unique(oid).ok()??;
+    let (_, value) = parse_der_utf8string(extension.value).ok()?;
+    value.as_str().ok()
+}
@@ -0,0 +1,177 @@
+use base64::Engine;
+use base64::prelude::{BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD};
+use reqwest_middleware::ClientWithMiddleware;
+use ring::rand::SystemRandom;
+use ring::signature::{EcdsaKeyPair, KeyPair};
+use rustls_pki_types::{CertificateDer, UnixTime};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::certificate::verify_chain;
+use crate::instance::SigstoreInstance;
+use crate::trusted_root::TrustedRoot;
+use crate::{P256_SPKI_PREFIX, SigstoreError, der_to_pem, pem_to_der, post_json};
+
+/// The response from Fulcio's `signingCert` endpoint.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SigningCertificate {
+    signed_certificate_embedded_sct: Option<CertificateChain>,
+    signed_certificate_detached_sct: Option<CertificateChain>,
+}
+
+#[derive(Deserialize)]
+struct CertificateChain {
+    chain: Certificates,
+}
+
+#[derive(Deserialize)]
+struct Certificates {
+    certificates: Vec<String>,
+}
+
+/// The subject of the OIDC token, which is signed as proof of possession of the key.
+#[derive(Deserialize)]
+struct OidcSubject {
+    sub: String,
+}
+
+/// Request a signing certificate for the public key from Fulcio, returning the PEM-encoded
+/// certificate.
+///
+/// The subject of the OIDC token is signed to prove possession of the private key. The
+/// certificate is verified to chain to the trusted root and to be issued for the key.
+pub async fn signing_certificate(
+    key_pair: &EcdsaKeyPair,
+    rng: &SystemRandom,
+    oidc_token: &str,
+    instance: &SigstoreInstance,
+    client: &ClientWithMiddleware,
+) -> Result<String, SigstoreError> {
+    let subject = oidc_token
+        .split('.')
+        .nth(1)
+        .and_then(|payload| BASE64_URL_SAFE_NO_PAD.decode(payload).ok())
+        .and_then(|payload| serde_json::from_slice::<OidcSubject>(&payload).ok())
+        .ok_or(SigstoreError::InvalidOidcToken)?
+        .sub;
+    let proof_of_possession = key_pair
+        .sign(rng, subject.as_bytes())
+        .map_err(|_| SigstoreError::ProofOfPossession)?;
+
+    let mut public_key = P256_SPKI_PREFIX.to_vec();
+    public_key.extend_from_slice(key_pair.public_key().as_ref());
+
+    let request = json!({
+        "credentials": { "oidcIdentityToken": oidc_token },
+        "publicKeyRequest": {
+            "publicKey": {
+                "algorithm": "ECDSA",
+                "content": der_to_pem("PUBLIC KEY", &public_key),
+            },
+            "proofOfPossession": BASE64_STANDARD.encode(proof_of_possession.as_ref()),
+        },
+    });
+
+    let fulcio_url = SigstoreInstance::endpoint(&instance.fulcio_url, "api/v2/signingCert");
+    debug!("Requesting signing certificate from {fulcio_url}");
+    let response: SigningCertificate =
+        post_json(client, &fulcio_url, &request, Some(oidc_token)).await?;
+    let chain = response
+        .signed_certificate_embedded_sct
+        .or(response.signed_certificate_detached_sct)
+        .map(|chain| chain.chain.certificates)
+        .filter(|certificates| !certificates.is_empty())
+        .ok_or(SigstoreError::MissingCertificate)?;
+    verify_certificate(&chain, key_pair, rng, &instance.trusted_root)?;
+
+    chain
+        .into_iter()
+        .next()
+        .ok_or(SigstoreError::MissingCertificate)
+}
+
+/// Verify that the leaf of a PEM-encoded certificate chain from Fulcio is a code signing
+/// certificate that chains to the trusted root, and that it was issued for the signing key.
+fn verify_certificate(
+    chain: &[String],
+    key_pair: &EcdsaKeyPair,
+    rng: &SystemRandom,
+    trusted_root: &TrustedRoot,
+) -> Result<(), SigstoreError> {
+    let chain = chain
+        .iter()
+        .map(|pem| pem_to_der(pem).map(CertificateDer::from))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (leaf, intermediates) = chain
+        .split_first()
+        .ok_or(SigstoreError::MissingCertificate)?;
+    let end_entity = verify_chain(leaf, intermediates, UnixTime::now(), trusted_root)?;
+
+    // Check that the certificate binds our key, by verifying a signature made with it.
+    let challenge = b"uv publish signing certificate";
+    let signature = key_pair
+        .sign(rng, challenge)
+        .map_err(|_| SigstoreError::ProofOfPossession)?;
+    end_entity
+        .verify_signature(
+            webpki::ring::ECDSA_P256_SHA256,
+            challenge,
+            signature.as_ref(),
+        )
+        .map_err(|_| SigstoreError::CertificateKeyMismatch)
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+
+    use super::*;
+
+    /// A certificate authority and a code signing certificate issued by it, from
+    /// `test-data/sigstore/generate.sh`.
+    const TEST_CA: &str = include_str!("../test-data/sigstore/ca.pem");
+    const TEST_LEAF: &str = include_str!("../test-data/sigstore/leaf.pem");
+
+    fn ephemeral_key() -> (EcdsaKeyPair, SystemRandom) {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                .unwrap();
+        (key_pair, rng)
+    }
+
+    #[test]
+    fn untrusted_certificate() {
+        let (key_pair, rng) = ephemeral_key();
+        let trusted_root = TrustedRoot::default_root().unwrap();
+        let err = verify_certificate(&[TEST_LEAF.to_string()], &key_pair, &rng, &trusted_root)
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                SigstoreError::UntrustedCertificate(webpki::Error::UnknownIssuer)
+            ),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn certificate_key_mismatch() {
+        let (key_pair, rng) = ephemeral_key();
+        let trusted_root = TrustedRoot {
+            roots: vec![CertificateDer::from(pem_to_der(TEST_CA).unwrap())],
+            intermediates: Vec::new(),
+            logs: Vec::new(),
+        };
+        // The certificate chains to the root, but it was issued for a different key.
+        let err = verify_certificate(&[TEST_LEAF.to_string()], &key_pair, &rng, &trusted_root)
+            .unwrap_err();
+        assert!(
+            matches!(err, SigstoreError::CertificateKeyMismatch),
+            "{err:?}"
+        );
+    }
+}
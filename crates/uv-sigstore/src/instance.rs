@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use reqwest_middleware::ClientWithMiddleware;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::SigstoreError;
+use crate::trusted_root::TrustedRoot;
+
+/// The certificate authority of the public-good Sigstore instance.
+const DEFAULT_FULCIO_URL: &str = "https://fulcio.sigstore.dev";
+
+/// The transparency log of the public-good Sigstore instance.
+const DEFAULT_REKOR_URL: &str = "https://rekor.sigstore.dev";
+
+/// The user configuration of the Sigstore instance, defaulting to the public-good instance.
+#[derive(Debug, Clone, Default)]
+pub struct SigstoreConfig {
+    /// The base URL of the Fulcio certificate authority.
+    pub fulcio_url: Option<DisplaySafeUrl>,
+    /// The base URL of the Rekor transparency log.
+    pub rekor_url: Option<DisplaySafeUrl>,
+    /// A trusted root in the Sigstore `trusted_root.json` format, which must contain the
+    /// certificate authority and the transparency log.
+    pub trusted_root: Option<PathBuf>,
+}
+
+/// The Sigstore services that sign and record attestations and signatures, and the trusted root
+/// that their responses are verified against.
+#[derive(Debug, Clone)]
+pub struct SigstoreInstance {
+    pub(crate) fulcio_url: DisplaySafeUrl,
+    pub(crate) rekor_url: DisplaySafeUrl,
+    pub(crate) trusted_root: TrustedRoot,
+}
+
+impl SigstoreInstance {
+    /// Resolve the Sigstore instance from the user configuration, with the embedded trusted root
+    /// of the public-good instance.
+    pub fn from_config(config: &SigstoreConfig) -> Result<Self, SigstoreError> {
+        let trusted_root = if let Some(path) = &config.trusted_root {
+            TrustedRoot::read(path)?
+        } else {
+            TrustedRoot::default_root()?
+        };
+        Self::new(config, trusted_root)
+    }
+
+    /// Resolve the Sigstore instance from the user configuration, updating the trusted root of
+    /// the public-good instance from its TUF repository.
+    pub async fn resolve(
+        config: &SigstoreConfig,
+        client: &ClientWithMiddleware,
+    ) -> Result<Self, SigstoreError> {
+        let trusted_root = if let Some(path) = &config.trusted_root {
+            TrustedRoot::read(path)?
+        } else {
+            TrustedRoot::update(client).await?
+        };
+        Self::new(config, trusted_root)
+    }
+
+    fn new(config: &SigstoreConfig, trusted_root: TrustedRoot) -> Result<Self, SigstoreError> {
+        let fulcio_url = config.fulcio_url.clone().unwrap_or_else(|| {
+            DisplaySafeUrl::parse(DEFAULT_FULCIO_URL).expect("Fulcio URL is valid")
+        });
+        let rekor_url = config.rekor_url.clone().unwrap_or_else(|| {
+            DisplaySafeUrl::parse(DEFAULT_REKOR_URL).expect("Rekor URL is valid")
+        });
+        for url in [&fulcio_url, &rekor_url] {
+            if url.cannot_be_a_base() {
+                return Err(SigstoreError::InvalidServiceUrl(url.clone()));
+            }
+        }
+        Ok(Self {
+            fulcio_url,
+            rekor_url,
+            trusted_root,
+        })
+    }
+
+    /// The base URL of the Fulcio certificate authority.
+    pub fn fulcio_url(&self) -> &DisplaySafeUrl {
+        &self.fulcio_url
+    }
+
+    /// Return the URL of an API endpoint relative to a base URL.
+    pub(crate) fn endpoint(base: &DisplaySafeUrl, path: &str) -> DisplaySafeUrl {
+        let mut url = base.clone();
+        url.path_segments_mut()
+            .expect("Sigstore URLs were checked to be base URLs")
+            .pop_if_empty()
+            .extend(path.split('/'));
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint() {
+        let base = DisplaySafeUrl::parse("https://rekor.example.com").unwrap();
+        assert_eq!(
+            SigstoreInstance::endpoint(&base, "api/v1/log/entries").as_str(),
+            "https://rekor.example.com/api/v1/log/entries"
+        );
+        let base = DisplaySafeUrl::parse("https://sigstore.example.com/rekor/").unwrap();
+        assert_eq!(
+            SigstoreInstance::endpoint(&base, "api/v1/log/entries").as_str(),
+            "https://sigstore.example.com/rekor/api/v1/log/entries"
+        );
+
+        let err = SigstoreInstance::from_config(&SigstoreConfig {
+            rekor_url: Some(DisplaySafeUrl::parse("mailto:rekor@example.com").unwrap()),
+            ..SigstoreConfig::default()
+        })
+        .unwrap_err();
+        assert!(matches!(err, SigstoreError::InvalidServiceUrl(_)));
+    }
+}
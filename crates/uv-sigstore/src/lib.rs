@@ -0,0 +1,166 @@
+//! Signing with and verification against a [Sigstore](https://www.sigstore.dev/) instance.
+//!
+//! Signing keys are bound to an identity with a short-lived certificate from the Fulcio
+//! certificate authority, and signatures are recorded in the Rekor transparency log. Certificates
+//! and transparency log entries are verified against the trusted root of the instance: a
+//! certificate must chain to a trusted certificate authority, and a log entry must be signed by a
+//! trusted log and be included in the log's Merkle tree.
+
+use std::path::PathBuf;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use itertools::Itertools;
+use reqwest::header;
+use reqwest_middleware::ClientWithMiddleware;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_redacted::DisplaySafeUrl;
+
+pub use bundle::{
+    BundleIdentity, pre_authentication_encoding, signature_bundle, verify_attestation,
+    verify_signature_bundle,
+};
+pub use fulcio::signing_certificate;
+pub use instance::{SigstoreConfig, SigstoreInstance};
+pub use rekor::transparency_log_entry;
+pub use tuf::tuf_url;
+
+mod bundle;
+mod certificate;
+mod fulcio;
+mod instance;
+mod rekor;
+mod trusted_root;
+mod tuf;
+
+/// The DSSE payload type of in-toto statements.
+pub const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// The DER prefix of a `SubjectPublicKeyInfo` for an uncompressed P-256 public key.
+pub const P256_SPKI_PREFIX: &[u8] = &[
+    0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a,
+    0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+#[derive(Debug, Error)]
+pub enum SigstoreError {
+    #[error("The OIDC token for Sigstore has an unexpected format")]
+    InvalidOidcToken,
+    #[error("Failed to sign the proof of possession for the signing certificate")]
+    ProofOfPossession,
+    #[error("Failed to fetch: `{0}`")]
+    Reqwest(DisplaySafeUrl, #[source] reqwest::Error),
+    #[error("Failed to fetch: `{0}`")]
+    ReqwestMiddleware(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+    #[error("`{0}` returned error code {1}: {2}")]
+    Status(DisplaySafeUrl, reqwest::StatusCode, String),
+    #[error("Fulcio didn't return a signing certificate")]
+    MissingCertificate,
+    #[error("The signing certificate from Fulcio doesn't chain to the Sigstore trusted root")]
+    UntrustedCertificate(#[source] webpki::Error),
+    #[error("The signing certificate from Fulcio was issued for a different key")]
+    CertificateKeyMismatch,
+    #[error("Failed to read Sigstore trusted root: `{}`", _0.user_display())]
+    ReadTrustedRoot(PathBuf, #[source] std::io::Error),
+    #[error("Invalid Sigstore trusted root: {0}")]
+    InvalidTrustedRoot(String),
+    #[error("Failed to determine the directory for the Sigstore trusted root")]
+    StateDirectory(#[source] std::io::Error),
+    #[error("Not a valid base URL for a Sigstore service: `{0}`")]
+    InvalidServiceUrl(DisplaySafeUrl),
+    #[error("Rekor returned an invalid transparency log entry")]
+    InvalidLogEntry,
+    #[error(
+        "The transparency log entry was signed by a log that is not in the trusted root: `{0}`"
+    )]
+    UnknownLog(String),
+    #[error("The transparency log entry has an invalid signed entry timestamp")]
+    InvalidSignedEntryTimestamp,
+    #[error("The transparency log entry has an invalid inclusion proof")]
+    InvalidInclusionProof,
+    #[error("The transparency log entry has an invalid checkpoint")]
+    InvalidCheckpoint,
+    #[error("The transparency log entry doesn't record the signature")]
+    LogEntryMismatch,
+    #[error("Invalid Sigstore bundle: {0}")]
+    InvalidBundle(&'static str),
+    #[error("The attestation was not signed by a workflow of `{0}`")]
+    UntrustedIdentity(String),
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+}
+
+/// Encode DER as PEM, with the given label.
+pub fn der_to_pem(label: &str, der: &[u8]) -> String {
+    let encoded = BASE64_STANDARD.encode(der);
+    let lines = encoded
+        .as_bytes()
+        .chunks(64)
+        .map(|chunk| std::str::from_utf8(chunk).expect("base64 is ASCII"))
+        .join("\n");
+    format!("-----BEGIN {label}-----\n{lines}\n-----END {label}-----\n")
+}
+
+/// Decode the first PEM block to DER.
+pub fn pem_to_der(pem: &str) -> Result<Vec<u8>, SigstoreError> {
+    let encoded: String = pem
+        .lines()
+        .skip_while(|line| !line.starts_with("-----BEGIN"))
+        .skip(1)
+        .take_while(|line| !line.starts_with("-----END"))
+        .collect();
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|_| SigstoreError::MissingCertificate)
+}
+
+/// Send a JSON request to a Sigstore service, returning the deserialized response.
+async fn post_json<T: DeserializeOwned>(
+    client: &ClientWithMiddleware,
+    url: &DisplaySafeUrl,
+    body: &serde_json::Value,
+    bearer: Option<&str>,
+) -> Result<T, SigstoreError> {
+    let mut request = client
+        .post(Url::from(url.clone()))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(body)?);
+    if let Some(bearer) = bearer {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {bearer}"));
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|err| SigstoreError::ReqwestMiddleware(url.clone(), err))?;
+
+    let status = response.status();
+    let body = response
+        .bytes()
+        .await
+        .map_err(|err| SigstoreError::Reqwest(url.clone(), err))?;
+    if !status.is_success() {
+        return Err(SigstoreError::Status(
+            url.clone(),
+            status,
+            String::from_utf8_lossy(&body).to_string(),
+        ));
+    }
+    Ok(serde_json::from_slice(&body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pem_roundtrip() {
+        let der = (0..=255).collect::<Vec<u8>>();
+        let pem = der_to_pem("CERTIFICATE", &der);
+        assert!(pem.lines().all(|line| line.len() <= 64));
+        assert_eq!(pem_to_der(&pem).unwrap(), der);
+    }
+}
@@ -0,0 +1,635 @@
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use reqwest_middleware::ClientWithMiddleware;
+use ring::digest::{SHA256, digest};
+use ring::signature::{ECDSA_P256_SHA256_ASN1, UnparsedPublicKey};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::instance::SigstoreInstance;
+use crate::trusted_root::{TransparencyLog, TrustedRoot};
+use crate::{SigstoreError, post_json};
+
+/// A transparency log entry, as returned by Rekor.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogEntry {
+    body: String,
+    integrated_time: i64,
+    #[serde(rename = "logID")]
+    log_id: String,
+    log_index: i64,
+    verification: LogEntryVerification,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogEntryVerification {
+    inclusion_proof: InclusionProof,
+    signed_entry_timestamp: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InclusionProof {
+    checkpoint: String,
+    hashes: Vec<String>,
+    log_index: i64,
+    root_hash: String,
+    tree_size: i64,
+}
+
+/// Record an entry in Rekor, returning the transparency log entry in the JSON encoding of the
+/// Sigstore protobuf specs.
+///
+/// The entry is verified to record the signature, to be signed by a log in the trusted root, and
+/// to be included in the log.
+pub async fn transparency_log_entry(
+    request: &serde_json::Value,
+    kind: &str,
+    signature: &[u8],
+    instance: &SigstoreInstance,
+    client: &ClientWithMiddleware,
+) -> Result<serde_json::Value, SigstoreError> {
+    let rekor_url = SigstoreInstance::endpoint(&instance.rekor_url, "api/v1/log/entries");
+    debug!("Uploading {kind} entry to the transparency log at {rekor_url}");
+    let entries: FxHashMap<String, LogEntry> = post_json(client, &rekor_url, request, None).await?;
+    let entry = entries
+        .into_values()
+        .next()
+        .ok_or(SigstoreError::InvalidLogEntry)?;
+    verify_log_entry(&entry, kind, signature, &instance.trusted_root)?;
+    entry.to_bundle(kind)
+}
+
+/// Verify a transparency log entry from a Sigstore bundle, e.g., from a previous run, returning
+/// the time the entry was integrated into the log.
+pub(crate) fn verify_bundle_log_entry(
+    entry: &serde_json::Value,
+    kind: &str,
+    signature: &[u8],
+    instance: &SigstoreInstance,
+) -> Result<i64, SigstoreError> {
+    let entry = LogEntry::from_bundle(entry)?;
+    verify_log_entry(&entry, kind, signature, &instance.trusted_root)?;
+    Ok(entry.integrated_time)
+}
+
+impl LogEntry {
+    /// Convert the entry to the JSON encoding of the Sigstore protobuf specs used in bundles.
+    fn to_bundle(&self, kind: &str) -> Result<serde_json::Value, SigstoreError> {
+        let decode_hex = |value: &str| {
+            hex::decode(value)
+                .map(|bytes| BASE64_STANDARD.encode(bytes))
+                .map_err(|_| SigstoreError::InvalidLogEntry)
+        };
+        let proof = &self.verification.inclusion_proof;
+        Ok(json!({
+                "logIndex": self.log_index.to_string(),
+                "logId": { "keyId": decode_hex(&self.log_id)? },
+                "kindVersion": { "kind": kind, "version": "0.0.1" },
+                "integratedTime": self.integrated_time.to_string(),
+                "inclusionPromise": {
+                    "signedEntryTimestamp": self.verification.signed_entry_timestamp,
+                },
+                "inclusionProof": {
+                    "logIndex": proof.log_index.to_string(),
+                    "rootHash": decode_hex(&proof.root_hash)?,
+                    "treeSize": proof.tree_size.to_string(),
+                    "hashes": proof
+                        .hashes
+                        .iter()
+                        .map(|hash| decode_hex(hash))
+                        .collect::<Result<Vec<_>, _>>()?,
+                    "checkpoint": { "envelope": proof.checkpoint },
+                },
+                "canonicalizedBody": self.body,
+        }))
+    }
+
+    /// Read an entry from the JSON encoding of the Sigstore protobuf specs used in bundles.
+    fn from_bundle(entry: &serde_json::Value) -> Result<Self, SigstoreError> {
+        let string = |value: &serde_json::Value| {
+            value
+                .as_str()
+                .map(ToString::to_string)
+                .ok_or(SigstoreError::InvalidLogEntry)
+        };
+        let number = |value: &serde_json::Value| {
+            value
+                .as_str()
+                .and_then(|value| value.parse::<i64>().ok())
+                .ok_or(SigstoreError::InvalidLogEntry)
+        };
+        let encode_hex = |value: &serde_json::Value| {
+            value
+                .as_str()
+                .and_then(|value| BASE64_STANDARD.decode(value).ok())
+                .map(hex::encode)
+                .ok_or(SigstoreError::InvalidLogEntry)
+        };
+        let proof = &entry["inclusionProof"];
+        Ok(Self {
+            body: string(&entry["canonicalizedBody"])?,
+            integrated_time: number(&entry["integratedTime"])?,
+            log_id: encode_hex(&entry["logId"]["keyId"])?,
+            log_index: number(&entry["logIndex"])?,
+            verification: LogEntryVerification {
+                inclusion_proof: InclusionProof {
+                    checkpoint: string(&proof["checkpoint"]["envelope"])?,
+                    hashes: proof["hashes"]
+                        .as_array()
+                        .ok_or(SigstoreError::InvalidLogEntry)?
+                        .iter()
+                        .map(encode_hex)
+                        .collect::<Result<_, _>>()?,
+                    log_index: number(&proof["logIndex"])?,
+                    root_hash: encode_hex(&proof["rootHash"])?,
+                    tree_size: number(&proof["treeSize"])?,
+                },
+                signed_entry_timestamp: string(&entry["inclusionPromise"]["signedEntryTimestamp"])?,
+            },
+        })
+    }
+}
+
+/// Verify a transparency log entry returned by Rekor.
+///
+/// Checks that the entry records the signature, that the signed entry timestamp is signed by a
+/// log in the trusted root, and that the entry is included in the log, using the inclusion proof
+/// and the signed checkpoint.
+fn verify_log_entry(
+    entry: &LogEntry,
+    kind: &str,
+    signature: &[u8],
+    trusted_root: &TrustedRoot,
+) -> Result<(), SigstoreError> {
+    let body = BASE64_STANDARD
+        .decode(&entry.body)
+        .map_err(|_| SigstoreError::InvalidLogEntry)?;
+    if !records_signature(&body, kind, &BASE64_STANDARD.encode(signature)) {
+        return Err(SigstoreError::LogEntryMismatch);
+    }
+
+    let log_id = hex::decode(&entry.log_id).map_err(|_| SigstoreError::InvalidLogEntry)?;
+    let log = trusted_root
+        .logs
+        .iter()
+        .find(|log| log.key_id == log_id)
+        .ok_or_else(|| SigstoreError::UnknownLog(entry.log_id.clone()))?;
+    let public_key = UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, &log.public_key);
+
+    // The signed entry timestamp is a signature over the canonical JSON of the entry, with the
+    // keys in lexicographic order.
+    let payload = json!({
+        "body": entry.body,
+        "integratedTime": entry.integrated_time,
+        "logID": entry.log_id,
+        "logIndex": entry.log_index,
+    });
+    let signed_entry_timestamp = BASE64_STANDARD
+        .decode(&entry.verification.signed_entry_timestamp)
+        .map_err(|_| SigstoreError::InvalidSignedEntryTimestamp)?;
+    public_key
+        .verify(&serde_json::to_vec(&payload)?, &signed_entry_timestamp)
+        .map_err(|_| SigstoreError::InvalidSignedEntryTimestamp)?;
+
+    let proof = &entry.verification.inclusion_proof;
+    let decode_hash = |value: &str| {
+        hex::decode(value)
+            .ok()
+            .filter(|hash| hash.len() == SHA256.output_len())
+            .ok_or(SigstoreError::InvalidInclusionProof)
+    };
+    let root_hash = decode_hash(&proof.root_hash)?;
+    let hashes = proof
+        .hashes
+        .iter()
+        .map(|hash| decode_hash(hash))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (Ok(index), Ok(tree_size)) = (
+        u64::try_from(proof.log_index),
+        u64::try_from(proof.tree_size),
+    ) else {
+        return Err(SigstoreError::InvalidInclusionProof);
+    };
+    if !verify_inclusion(index, tree_size, &leaf_hash(&body), &hashes, &root_hash) {
+        return Err(SigstoreError::InvalidInclusionProof);
+    }
+    if !verify_checkpoint(&proof.checkpoint, tree_size, &root_hash, log, &public_key) {
+        return Err(SigstoreError::InvalidCheckpoint);
+    }
+    Ok(())
+}
+
+/// Whether the canonicalized body of a log entry records the base64-encoded signature.
+fn records_signature(body: &[u8], kind: &str, signature: &str) -> bool {
+    let Ok(body) = serde_json::from_slice::<serde_json::Value>(body) else {
+        return false;
+    };
+    if body["kind"] != kind {
+        return false;
+    }
+    match kind {
+        "dsse" => body["spec"]["signatures"]
+            .as_array()
+            .is_some_and(|signatures| {
+                signatures
+                    .iter()
+                    .any(|entry| entry["signature"] == signature)
+            }),
+        "hashedrekord" => body["spec"]["signature"]["content"] == signature,
+        _ => false,
+    }
+}
+
+/// The RFC 6962 hash of a Merkle tree leaf.
+fn leaf_hash(data: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(data.len() + 1);
+    input.push(0x00);
+    input.extend_from_slice(data);
+    digest(&SHA256, &input).as_ref().to_vec()
+}
+
+/// The RFC 6962 hash of an interior Merkle tree node.
+fn node_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(left.len() + right.len() + 1);
+    input.push(0x01);
+    input.extend_from_slice(left);
+    input.extend_from_slice(right);
+    digest(&SHA256, &input).as_ref().to_vec()
+}
+
+/// Verify a Merkle inclusion proof for the leaf at `index` in a tree of `tree_size` leaves.
+///
+/// See: <https://www.rfc-editor.org/rfc/rfc9162#section-2.1.3.2>
+fn verify_inclusion(
+    index: u64,
+    tree_size: u64,
+    leaf_hash: &[u8],
+    proof: &[Vec<u8>],
+    root_hash: &[u8],
+) -> bool {
+    if index >= tree_size {
+        return false;
+    }
+    let mut node = index;
+    let mut last = tree_size - 1;
+    let mut hash = leaf_hash.to_vec();
+    for sibling in proof {
+        if last == 0 {
+            return false;
+        }
+        if node & 1 == 1 || node == last {
+            hash = node_hash(sibling, &hash);
+            while node & 1 == 0 && node != 0 {
+                node >>= 1;
+                last >>= 1;
+            }
+        } else {
+            hash = node_hash(&hash, sibling);
+        }
+        node >>= 1;
+        last >>= 1;
+    }
+    last == 0 && hash == root_hash
+}
+
+/// Verify that a checkpoint, a signed note committing the log to a tree size and root hash,
+/// matches the inclusion proof and is signed by the log.
+///
+/// See: <https://github.com/transparency-dev/formats/blob/main/log/README.md>
+fn verify_checkpoint(
+    checkpoint: &str,
+    tree_size: u64,
+    root_hash: &[u8],
+    log: &TransparencyLog,
+    public_key: &UnparsedPublicKey<&Vec<u8>>,
+) -> bool {
+    let Some((note, signatures)) = checkpoint.split_once("\n\n") else {
+        return false;
+    };
+    // The note consists of the origin, the tree size, the root hash, and optional extensions.
+    let mut lines = note.lines().skip(1);
+    if lines.next().and_then(|size| size.parse::<u64>().ok()) != Some(tree_size) {
+        return false;
+    }
+    if lines
+        .next()
+        .and_then(|hash| BASE64_STANDARD.decode(hash).ok())
+        .as_deref()
+        != Some(root_hash)
+    {
+        return false;
+    }
+
+    // Each signature line is `— <name> <base64(key hint || signature)>`, where the key hint is
+    // the first four bytes of the log ID.
+    let message = format!("{note}\n");
+    signatures
+        .lines()
+        .filter_map(|line| line.strip_prefix("\u{2014} "))
+        .filter_map(|line| line.rsplit_once(' '))
+        .filter_map(|(_, signature)| BASE64_STANDARD.decode(signature).ok())
+        .any(|signature| {
+            signature.len() > 4
+                && log.key_id.starts_with(&signature[..4])
+                && public_key
+                    .verify(message.as_bytes(), &signature[4..])
+                    .is_ok()
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair};
+    use rustls_pki_types::CertificateDer;
+
+    use super::*;
+    use crate::{P256_SPKI_PREFIX, SigstoreConfig, pem_to_der};
+
+    /// A certificate authority, from `test-data/sigstore/generate.sh`.
+    const TEST_CA: &str = include_str!("../test-data/sigstore/ca.pem");
+
+    /// A transparency log for tests, which signs the entries and checkpoints with its own key.
+    pub(crate) struct TestLog {
+        key_pair: EcdsaKeyPair,
+        rng: SystemRandom,
+        log: TransparencyLog,
+    }
+
+    impl TestLog {
+        pub(crate) fn new() -> Self {
+            let rng = SystemRandom::new();
+            let pkcs8 =
+                EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+            let key_pair =
+                EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                    .unwrap();
+            let mut spki = P256_SPKI_PREFIX.to_vec();
+            spki.extend_from_slice(key_pair.public_key().as_ref());
+            let log = TransparencyLog {
+                key_id: digest(&SHA256, &spki).as_ref().to_vec(),
+                public_key: key_pair.public_key().as_ref().to_vec(),
+            };
+            Self { key_pair, rng, log }
+        }
+
+        /// A Sigstore instance that trusts this log and the given PEM-encoded root certificate.
+        pub(crate) fn instance(&self, root: &str) -> SigstoreInstance {
+            let mut instance = SigstoreInstance::from_config(&SigstoreConfig::default()).unwrap();
+            instance.trusted_root = TrustedRoot {
+                roots: vec![CertificateDer::from(pem_to_der(root).unwrap())],
+                intermediates: Vec::new(),
+                logs: vec![self.log.clone()],
+            };
+            instance
+        }
+
+        /// Record a `hashedrekord` or `dsse` entry for the signature as the only entry of the log,
+        /// returning the transparency log entry as encoded in bundles.
+        pub(crate) fn entry(
+            &self,
+            kind: &str,
+            signature: &[u8],
+            integrated_time: i64,
+        ) -> serde_json::Value {
+            let spec = if kind == "dsse" {
+                json!({ "signatures": [{ "signature": BASE64_STANDARD.encode(signature) }] })
+            } else {
+                json!({ "signature": { "content": BASE64_STANDARD.encode(signature) } })
+            };
+            let body = json!({
+                "apiVersion": "0.0.1",
+                "kind": kind,
+                "spec": spec,
+            })
+            .to_string();
+            let root_hash = leaf_hash(body.as_bytes());
+            let body = BASE64_STANDARD.encode(body);
+            let log_id = hex::encode(&self.log.key_id);
+
+            let payload = json!({
+                "body": body,
+                "integratedTime": integrated_time,
+                "logID": log_id,
+                "logIndex": 0,
+            });
+            let signed_entry_timestamp = self
+                .key_pair
+                .sign(&self.rng, &serde_json::to_vec(&payload).unwrap())
+                .unwrap();
+
+            let note = format!(
+                "rekor.example.com - 1\n1\n{}\n",
+                BASE64_STANDARD.encode(&root_hash)
+            );
+            let mut note_signature = self.log.key_id[..4].to_vec();
+            note_signature.extend_from_slice(
+                self.key_pair
+                    .sign(&self.rng, note.as_bytes())
+                    .unwrap()
+                    .as_ref(),
+            );
+            let checkpoint = format!(
+                "{note}\n\u{2014} rekor.example.com {}\n",
+                BASE64_STANDARD.encode(&note_signature)
+            );
+
+            LogEntry {
+                body,
+                integrated_time,
+                log_id,
+                log_index: 0,
+                verification: LogEntryVerification {
+                    inclusion_proof: InclusionProof {
+                        checkpoint,
+                        hashes: Vec::new(),
+                        log_index: 0,
+                        root_hash: hex::encode(root_hash),
+                        tree_size: 1,
+                    },
+                    signed_entry_timestamp: BASE64_STANDARD.encode(signed_entry_timestamp.as_ref()),
+                },
+            }
+            .to_bundle(kind)
+            .unwrap()
+        }
+    }
+
+    fn ephemeral_key() -> (EcdsaKeyPair, SystemRandom) {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                .unwrap();
+        (key_pair, rng)
+    }
+
+    /// The RFC 6962 Merkle tree hash of the leaves.
+    fn tree_hash(leaves: &[Vec<u8>]) -> Vec<u8> {
+        if let [leaf] = leaves {
+            return leaf.clone();
+        }
+        let split = leaves.len().next_power_of_two() / 2;
+        node_hash(&tree_hash(&leaves[..split]), &tree_hash(&leaves[split..]))
+    }
+
+    /// The RFC 6962 inclusion proof for the leaf at `index`.
+    fn inclusion_proof(index: usize, leaves: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        if leaves.len() == 1 {
+            return Vec::new();
+        }
+        let split = leaves.len().next_power_of_two() / 2;
+        let (mut proof, sibling) = if index < split {
+            (
+                inclusion_proof(index, &leaves[..split]),
+                tree_hash(&leaves[split..]),
+            )
+        } else {
+            (
+                inclusion_proof(index - split, &leaves[split..]),
+                tree_hash(&leaves[..split]),
+            )
+        };
+        proof.push(sibling);
+        proof
+    }
+
+    #[test]
+    fn inclusion() {
+        let leaves: Vec<Vec<u8>> = (0..7u8).map(|leaf| leaf_hash(&[leaf])).collect();
+        let root_hash = tree_hash(&leaves);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = inclusion_proof(index, &leaves);
+            assert!(verify_inclusion(index as u64, 7, leaf, &proof, &root_hash));
+            // A proof for a different leaf, index or root hash is rejected.
+            assert!(!verify_inclusion(
+                index as u64,
+                7,
+                &leaf_hash(b"other"),
+                &proof,
+                &root_hash
+            ));
+            assert!(!verify_inclusion(
+                (index ^ 1) as u64,
+                7,
+                leaf,
+                &proof,
+                &root_hash
+            ));
+            assert!(!verify_inclusion(index as u64, 7, leaf, &proof, &leaves[0]));
+        }
+        assert!(!verify_inclusion(7, 7, &leaves[0], &[], &root_hash));
+    }
+
+    #[test]
+    fn checkpoint() {
+        let (key_pair, rng) = ephemeral_key();
+        let mut spki = P256_SPKI_PREFIX.to_vec();
+        spki.extend_from_slice(key_pair.public_key().as_ref());
+        let log = TransparencyLog {
+            key_id: digest(&SHA256, &spki).as_ref().to_vec(),
+            public_key: key_pair.public_key().as_ref().to_vec(),
+        };
+        let public_key = UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, &log.public_key);
+
+        let root_hash = [7; 32];
+        let note = format!(
+            "rekor.example.com - 1\n5\n{}\n",
+            BASE64_STANDARD.encode(root_hash)
+        );
+        let mut signature = log.key_id[..4].to_vec();
+        signature.extend_from_slice(key_pair.sign(&rng, note.as_bytes()).unwrap().as_ref());
+        let checkpoint = format!(
+            "{note}\n\u{2014} rekor.example.com {}\n",
+            BASE64_STANDARD.encode(&signature)
+        );
+
+        assert!(verify_checkpoint(
+            &checkpoint,
+            5,
+            &root_hash,
+            &log,
+            &public_key
+        ));
+        assert!(!verify_checkpoint(
+            &checkpoint,
+            6,
+            &root_hash,
+            &log,
+            &public_key
+        ));
+        assert!(!verify_checkpoint(
+            &checkpoint.replace("\n5\n", "\n6\n"),
+            6,
+            &root_hash,
+            &log,
+            &public_key
+        ));
+    }
+
+    #[test]
+    fn bundle_log_entry() {
+        let log = TestLog::new();
+        let instance = log.instance(TEST_CA);
+        let entry = log.entry("hashedrekord", b"signature", 1_700_000_000);
+        assert_eq!(
+            verify_bundle_log_entry(&entry, "hashedrekord", b"signature", &instance).unwrap(),
+            1_700_000_000
+        );
+
+        // The entry must record the signature, and be signed by a log in the trusted root.
+        let err = verify_bundle_log_entry(&entry, "hashedrekord", b"other", &instance).unwrap_err();
+        assert!(matches!(err, SigstoreError::LogEntryMismatch), "{err:?}");
+        let err = verify_bundle_log_entry(
+            &entry,
+            "hashedrekord",
+            b"signature",
+            &TestLog::new().instance(TEST_CA),
+        )
+        .unwrap_err();
+        assert!(matches!(err, SigstoreError::UnknownLog(_)), "{err:?}");
+
+        // Changing the integrated time invalidates the signed entry timestamp.
+        let mut tampered = entry.clone();
+        tampered["integratedTime"] = json!("1700000001");
+        let err = verify_bundle_log_entry(&tampered, "hashedrekord", b"signature", &instance)
+            .unwrap_err();
+        assert!(
+            matches!(err, SigstoreError::InvalidSignedEntryTimestamp),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn log_entry_records_signature() {
+        let dsse = json!({
+            "kind": "dsse",
+            "spec": { "signatures": [{ "signature": "c2ln", "verifier": "Y2VydA==" }] },
+        })
+        .to_string();
+        assert!(records_signature(dsse.as_bytes(), "dsse", "c2ln"));
+        assert!(!records_signature(dsse.as_bytes(), "dsse", "b3RoZXI="));
+        assert!(!records_signature(dsse.as_bytes(), "hashedrekord", "c2ln"));
+
+        let hashedrekord = json!({
+            "kind": "hashedrekord",
+            "spec": { "signature": { "content": "c2ln" } },
+        })
+        .to_string();
+        assert!(records_signature(
+            hashedrekord.as_bytes(),
+            "hashedrekord",
+            "c2ln"
+        ));
+        assert!(!records_signature(
+            hashedrekord.as_bytes(),
+            "hashedrekord",
+            "b3RoZXI="
+        ));
+    }
+}
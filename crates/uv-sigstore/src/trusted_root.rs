@@ -0,0 +1,245 @@
+use std::path::Path;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use jiff::Timestamp;
+use reqwest_middleware::ClientWithMiddleware;
+use rustls_pki_types::CertificateDer;
+use serde::Deserialize;
+
+use uv_redacted::DisplaySafeUrl;
+use uv_state::{StateBucket, StateStore};
+use uv_warnings::warn_user_once;
+
+use crate::tuf::{TufRepository, tuf_url};
+use crate::{P256_SPKI_PREFIX, SigstoreError};
+
+/// The trusted root of the public-good Sigstore instance, as distributed through its TUF
+/// repository when uv was built.
+const DEFAULT_TRUSTED_ROOT: &[u8] = include_bytes!("trusted_root.json");
+
+/// The TUF target with the trusted root.
+const TRUSTED_ROOT_TARGET: &str = "trusted_root.json";
+
+/// The certificate authorities and transparency logs of a Sigstore instance.
+#[derive(Debug, Clone)]
+pub(crate) struct TrustedRoot {
+    /// The root certificates of the certificate authorities.
+    pub(crate) roots: Vec<CertificateDer<'static>>,
+    /// The intermediate certificates of the certificate authorities.
+    pub(crate) intermediates: Vec<CertificateDer<'static>>,
+    /// The transparency logs.
+    pub(crate) logs: Vec<TransparencyLog>,
+}
+
+/// A transparency log in the trusted root.
+#[derive(Debug, Clone)]
+pub(crate) struct TransparencyLog {
+    /// The log ID, the SHA-256 of the DER-encoded public key.
+    pub(crate) key_id: Vec<u8>,
+    /// The uncompressed P-256 public key of the log.
+    pub(crate) public_key: Vec<u8>,
+}
+
+/// The subset of the Sigstore `trusted_root.json` format that we use.
+///
+/// See: <https://github.com/sigstore/protobuf-specs/blob/main/protos/sigstore_trustroot.proto>
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrustedRootJson {
+    tlogs: Vec<TransparencyLogJson>,
+    certificate_authorities: Vec<CertificateAuthorityJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransparencyLogJson {
+    public_key: PublicKeyJson,
+    log_id: LogIdJson,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublicKeyJson {
+    raw_bytes: String,
+    key_details: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogIdJson {
+    key_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateAuthorityJson {
+    cert_chain: CertificateChainJson,
+}
+
+#[derive(Deserialize)]
+struct CertificateChainJson {
+    certificates: Vec<RawBytesJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawBytesJson {
+    raw_bytes: String,
+}
+
+impl TrustedRoot {
+    /// The trusted root of the public-good Sigstore instance.
+    pub(crate) fn default_root() -> Result<Self, SigstoreError> {
+        Self::parse(DEFAULT_TRUSTED_ROOT)
+    }
+
+    /// The trusted root of the public-good Sigstore instance, updated from its TUF repository.
+    ///
+    /// If the update fails, the trusted root from the last update is used, or the embedded
+    /// trusted root if there was none.
+    pub(crate) async fn update(client: &ClientWithMiddleware) -> Result<Self, SigstoreError> {
+        let dir = StateStore::from_settings(None)
+            .map_err(SigstoreError::StateDirectory)?
+            .bucket(StateBucket::Sigstore);
+        Self::update_from(&tuf_url(), client, &dir).await
+    }
+
+    async fn update_from(
+        url: &DisplaySafeUrl,
+        client: &ClientWithMiddleware,
+        dir: &Path,
+    ) -> Result<Self, SigstoreError> {
+        let repository = TufRepository::new(url.clone(), client, dir);
+        match repository
+            .fetch_target(TRUSTED_ROOT_TARGET, Timestamp::now())
+            .await
+        {
+            Ok(contents) => Self::parse(&contents),
+            Err(err) => {
+                let (source, trusted_root) = match fs_err::read(dir.join(TRUSTED_ROOT_TARGET)) {
+                    Ok(contents) => ("last", Self::parse(&contents)?),
+                    Err(_) => ("embedded", Self::default_root()?),
+                };
+                warn_user_once!(
+                    "Failed to update the Sigstore trusted root from `{url}`, using the {source} trusted root instead: {err}"
+                );
+                Ok(trusted_root)
+            }
+        }
+    }
+
+    /// Read a trusted root in the Sigstore `trusted_root.json` format.
+    pub(crate) fn read(path: &Path) -> Result<Self, SigstoreError> {
+        let contents = fs_err::read(path)
+            .map_err(|err| SigstoreError::ReadTrustedRoot(path.to_path_buf(), err))?;
+        Self::parse(&contents)
+    }
+
+    pub(crate) fn parse(contents: &[u8]) -> Result<Self, SigstoreError> {
+        let json: TrustedRootJson = serde_json::from_slice(contents)
+            .map_err(|err| SigstoreError::InvalidTrustedRoot(err.to_string()))?;
+        let decode = |value: &str| {
+            BASE64_STANDARD
+                .decode(value)
+                .map_err(|err| SigstoreError::InvalidTrustedRoot(err.to_string()))
+        };
+
+        let mut roots = Vec::new();
+        let mut intermediates = Vec::new();
+        for authority in json.certificate_authorities {
+            // The chain is ordered from the intermediates to the root.
+            let mut chain = authority
+                .cert_chain
+                .certificates
+                .iter()
+                .map(|certificate| decode(&certificate.raw_bytes).map(CertificateDer::from))
+                .collect::<Result<Vec<_>, _>>()?;
+            let Some(root) = chain.pop() else {
+                continue;
+            };
+            roots.push(root);
+            intermediates.extend(chain);
+        }
+
+        let mut logs = Vec::new();
+        for log in json.tlogs {
+            // Rekor signs with ECDSA P-256, other key types are for other services.
+            if log.public_key.key_details != "PKIX_ECDSA_P256_SHA_256" {
+                continue;
+            }
+            let Some(public_key) = decode(&log.public_key.raw_bytes)?
+                .strip_prefix(P256_SPKI_PREFIX)
+                .map(<[u8]>::to_vec)
+            else {
+                return Err(SigstoreError::InvalidTrustedRoot(
+                    "transparency log key is not a P-256 public key".to_string(),
+                ));
+            };
+            logs.push(TransparencyLog {
+                key_id: decode(&log.log_id.key_id)?,
+                public_key,
+            });
+        }
+
+        if roots.is_empty() || logs.is_empty() {
+            return Err(SigstoreError::InvalidTrustedRoot(
+                "a certificate authority and a transparency log are required".to_string(),
+            ));
+        }
+        Ok(Self {
+            roots,
+            intermediates,
+            logs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_trusted_root() {
+        let trusted_root = TrustedRoot::default_root().unwrap();
+        assert!(!trusted_root.roots.is_empty());
+        assert!(!trusted_root.logs.is_empty());
+    }
+
+    /// If the TUF repository is unavailable, the trusted root from the last update is used, or
+    /// the embedded trusted root.
+    #[tokio::test]
+    async fn update_fallback() {
+        let server = wiremock::MockServer::start().await;
+        let url = DisplaySafeUrl::parse(&server.uri()).unwrap();
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+        let dir = tempfile::tempdir().unwrap();
+
+        let trusted_root = TrustedRoot::update_from(&url, &client, dir.path())
+            .await
+            .unwrap();
+        assert_eq!(
+            trusted_root.roots,
+            TrustedRoot::default_root().unwrap().roots
+        );
+
+        fs_err::write(dir.path().join(TRUSTED_ROOT_TARGET), b"{}").unwrap();
+        let err = TrustedRoot::update_from(&url, &client, dir.path())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, SigstoreError::InvalidTrustedRoot(_)),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn invalid_trusted_root() {
+        let err =
+            TrustedRoot::parse(br#"{"tlogs": [], "certificateAuthorities": []}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid Sigstore trusted root: a certificate authority and a transparency log are required"
+        );
+    }
+}
@@ -0,0 +1,724 @@
+//! A client for the [TUF](https://theupdateframework.io/) repository that distributes the trusted
+//! root of the public-good Sigstore instance.
+//!
+//! The embedded root metadata is the initial root of trust. It's updated through the chain of
+//! root metadata versions in the repository, which then vouches for the timestamp, snapshot and
+//! targets metadata, and, finally, the trusted root target. The trusted metadata is persisted, so
+//! that root rotations and rollbacks are tracked across runs. Delegated targets are not
+//! supported, as the Sigstore repository doesn't use them.
+//!
+//! See: <https://theupdateframework.github.io/specification/latest/#detailed-client-workflow>
+
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+use jiff::Timestamp;
+use olpc_cjson::CanonicalFormatter;
+use reqwest::StatusCode;
+use reqwest_middleware::ClientWithMiddleware;
+use ring::digest::{SHA256, digest};
+use ring::signature::{ECDSA_P256_SHA256_ASN1, UnparsedPublicKey};
+use rustc_hash::FxHashMap;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+use url::Url;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::instance::SigstoreInstance;
+use crate::{P256_SPKI_PREFIX, pem_to_der};
+
+/// The TUF repository of the public-good Sigstore instance.
+const DEFAULT_TUF_URL: &str = "https://tuf-repo-cdn.sigstore.dev";
+
+/// The initial root metadata of the Sigstore TUF repository, version 9 from
+/// <https://github.com/sigstore/root-signing>.
+const SEED_ROOT: &[u8] = include_bytes!("tuf_root.json");
+
+/// The maximum number of root metadata versions to update through in a single refresh.
+const MAX_ROOT_ROTATIONS: u64 = 32;
+
+/// The URL of the TUF repository of the public-good Sigstore instance.
+pub fn tuf_url() -> DisplaySafeUrl {
+    DisplaySafeUrl::parse(DEFAULT_TUF_URL).expect("TUF URL is valid")
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum TufError {
+    #[error("Failed to fetch: `{0}`")]
+    Reqwest(DisplaySafeUrl, #[source] reqwest::Error),
+    #[error("Failed to fetch: `{0}`")]
+    ReqwestMiddleware(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+    #[error("`{0}` returned error code {1}")]
+    Status(DisplaySafeUrl, StatusCode),
+    #[error("Invalid TUF metadata `{0}`")]
+    InvalidMetadata(String, #[source] serde_json::Error),
+    #[error("TUF metadata `{0}` is not `{1}` metadata")]
+    WrongType(String, &'static str),
+    #[error("TUF metadata `{0}` is not signed by a threshold of the `{1}` keys")]
+    Threshold(String, &'static str),
+    #[error("TUF metadata `{0}` has version {1}, expected version {2}")]
+    Version(String, u64, u64),
+    #[error("TUF metadata `{0}` was rolled back from version {1} to version {2}")]
+    Rollback(String, u64, u64),
+    #[error("TUF metadata `{0}` expired at {1}")]
+    Expired(String, Timestamp),
+    #[error("`{0}` doesn't match its length and hash in the TUF metadata")]
+    Mismatch(String),
+    #[error("The TUF metadata doesn't list `{0}`")]
+    Missing(String),
+    #[error("Failed to persist TUF metadata: `{0}`")]
+    Persist(PathBuf, #[source] std::io::Error),
+}
+
+/// Signed TUF metadata, as fetched from the repository.
+#[derive(Deserialize)]
+struct Metadata {
+    signed: serde_json::Value,
+    signatures: Vec<Signature>,
+}
+
+#[derive(Deserialize)]
+struct Signature {
+    keyid: String,
+    sig: String,
+}
+
+/// The signed part of TUF metadata, with the fields that are common to all roles.
+#[derive(Deserialize)]
+struct Signed<T> {
+    #[serde(rename = "_type")]
+    kind: String,
+    version: u64,
+    expires: Timestamp,
+    #[serde(flatten)]
+    role: T,
+}
+
+#[derive(Deserialize)]
+struct Root {
+    keys: FxHashMap<String, Key>,
+    roles: FxHashMap<String, RoleKeys>,
+    consistent_snapshot: bool,
+}
+
+#[derive(Deserialize)]
+struct Key {
+    scheme: String,
+    keyval: KeyValue,
+}
+
+#[derive(Deserialize)]
+struct KeyValue {
+    public: String,
+}
+
+#[derive(Deserialize)]
+struct RoleKeys {
+    keyids: Vec<String>,
+    threshold: usize,
+}
+
+#[derive(Deserialize)]
+struct TimestampRole {
+    meta: FxHashMap<String, MetaFile>,
+}
+
+#[derive(Deserialize)]
+struct SnapshotRole {
+    meta: FxHashMap<String, MetaFile>,
+}
+
+#[derive(Deserialize)]
+struct TargetsRole {
+    targets: FxHashMap<String, TargetFile>,
+}
+
+/// A metadata file listed in the timestamp or snapshot metadata.
+#[derive(Deserialize)]
+struct MetaFile {
+    version: u64,
+    length: Option<u64>,
+    #[serde(default)]
+    hashes: FxHashMap<String, String>,
+}
+
+/// A target file listed in the targets metadata.
+#[derive(Deserialize)]
+struct TargetFile {
+    length: u64,
+    hashes: FxHashMap<String, String>,
+}
+
+impl Metadata {
+    fn parse(name: &str, contents: &[u8]) -> Result<Self, TufError> {
+        serde_json::from_slice(contents)
+            .map_err(|err| TufError::InvalidMetadata(name.to_string(), err))
+    }
+
+    /// Verify that the metadata is signed by a threshold of the keys of a role in the root
+    /// metadata, and return its signed part.
+    fn verify<T: DeserializeOwned>(
+        &self,
+        name: &str,
+        role: &'static str,
+        root: &Signed<Root>,
+    ) -> Result<Signed<T>, TufError> {
+        self.verify_signatures(name, role, root)?;
+        self.signed(name, role)
+    }
+
+    /// Return the signed part of the metadata, without verifying its signatures.
+    fn signed<T: DeserializeOwned>(
+        &self,
+        name: &str,
+        role: &'static str,
+    ) -> Result<Signed<T>, TufError> {
+        let signed: Signed<T> = serde_json::from_value(self.signed.clone())
+            .map_err(|err| TufError::InvalidMetadata(name.to_string(), err))?;
+        if signed.kind != role {
+            return Err(TufError::WrongType(name.to_string(), role));
+        }
+        Ok(signed)
+    }
+
+    fn verify_signatures(
+        &self,
+        name: &str,
+        role: &'static str,
+        root: &Signed<Root>,
+    ) -> Result<(), TufError> {
+        let Some(role_keys) = root.role.roles.get(role) else {
+            return Err(TufError::Threshold(name.to_string(), role));
+        };
+
+        // The signatures are over the canonical JSON of the signed part.
+        let mut message = Vec::new();
+        self.signed
+            .serialize(&mut serde_json::Serializer::with_formatter(
+                &mut message,
+                CanonicalFormatter::new(),
+            ))
+            .map_err(|err| TufError::InvalidMetadata(name.to_string(), err))?;
+
+        let valid = role_keys
+            .keyids
+            .iter()
+            .unique()
+            .filter(|keyid| {
+                root.role.keys.get(*keyid).is_some_and(|key| {
+                    self.signatures
+                        .iter()
+                        .filter(|signature| signature.keyid == **keyid)
+                        .any(|signature| key.verify(&message, &signature.sig))
+                })
+            })
+            .count();
+        if role_keys.threshold == 0 || valid < role_keys.threshold {
+            return Err(TufError::Threshold(name.to_string(), role));
+        }
+        Ok(())
+    }
+}
+
+impl Key {
+    /// Verify a hex-encoded signature with the key.
+    ///
+    /// Only ECDSA P-256 keys are supported, which are the keys of the Sigstore repository.
+    fn verify(&self, message: &[u8], signature: &str) -> bool {
+        if self.scheme != "ecdsa-sha2-nistp256" {
+            return false;
+        }
+        let Ok(public_key) = pem_to_der(&self.keyval.public) else {
+            return false;
+        };
+        let Some(public_key) = public_key.strip_prefix(P256_SPKI_PREFIX) else {
+            return false;
+        };
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public_key)
+            .verify(message, &signature)
+            .is_ok()
+    }
+}
+
+impl<T> Signed<T> {
+    fn check_expiry(&self, name: &str, now: Timestamp) -> Result<(), TufError> {
+        if self.expires <= now {
+            return Err(TufError::Expired(name.to_string(), self.expires));
+        }
+        Ok(())
+    }
+}
+
+/// Check that a file matches its length and SHA-256 hash, where they are known.
+fn check_file(
+    name: &str,
+    contents: &[u8],
+    length: Option<u64>,
+    hashes: &FxHashMap<String, String>,
+) -> Result<(), TufError> {
+    let length_matches = length
+        .is_none_or(|length| usize::try_from(length).is_ok_and(|length| length == contents.len()));
+    let hash_matches = hashes
+        .get("sha256")
+        .is_none_or(|sha256| *sha256 == hex::encode(digest(&SHA256, contents)));
+    if !length_matches || !hash_matches {
+        return Err(TufError::Mismatch(name.to_string()));
+    }
+    Ok(())
+}
+
+/// A TUF repository, with the trusted metadata persisted in a local directory.
+pub(crate) struct TufRepository<'a> {
+    url: DisplaySafeUrl,
+    client: &'a ClientWithMiddleware,
+    dir: &'a Path,
+}
+
+impl<'a> TufRepository<'a> {
+    pub(crate) fn new(
+        url: DisplaySafeUrl,
+        client: &'a ClientWithMiddleware,
+        dir: &'a Path,
+    ) -> Self {
+        Self { url, client, dir }
+    }
+
+    /// Update the trusted metadata from the repository, and return the contents of a target.
+    ///
+    /// The target is also persisted, once it's verified.
+    pub(crate) async fn fetch_target(
+        &self,
+        target: &str,
+        now: Timestamp,
+    ) -> Result<Vec<u8>, TufError> {
+        let root = self.update_root().await?;
+        root.check_expiry("root.json", now)?;
+        let timestamp = self.update_timestamp(&root, now).await?;
+
+        let snapshot_meta = timestamp
+            .role
+            .meta
+            .get("snapshot.json")
+            .ok_or_else(|| TufError::Missing("snapshot.json".to_string()))?;
+        let snapshot: Signed<SnapshotRole> = self
+            .fetch_metadata(&root, "snapshot", snapshot_meta, now)
+            .await?;
+
+        let targets_meta = snapshot
+            .role
+            .meta
+            .get("targets.json")
+            .ok_or_else(|| TufError::Missing("targets.json".to_string()))?;
+        let targets: Signed<TargetsRole> = self
+            .fetch_metadata(&root, "targets", targets_meta, now)
+            .await?;
+
+        let file = targets
+            .role
+            .targets
+            .get(target)
+            .ok_or_else(|| TufError::Missing(target.to_string()))?;
+        let path = match (root.role.consistent_snapshot, file.hashes.get("sha256")) {
+            (true, Some(sha256)) => format!("targets/{sha256}.{target}"),
+            (true, None) => return Err(TufError::Mismatch(target.to_string())),
+            (false, _) => format!("targets/{target}"),
+        };
+        let contents = self.fetch(&path).await?;
+        check_file(target, &contents, Some(file.length), &file.hashes)?;
+        self.persist(target, &contents)?;
+        Ok(contents)
+    }
+
+    /// Update the trusted root metadata through the chain of root metadata versions.
+    async fn update_root(&self) -> Result<Signed<Root>, TufError> {
+        let mut root = self.trusted_root()?;
+        let first = root.version + 1;
+        for version in first..first + MAX_ROOT_ROTATIONS {
+            let name = format!("{version}.root.json");
+            let contents = match self.fetch(&name).await {
+                Ok(contents) => contents,
+                Err(TufError::Status(_, StatusCode::NOT_FOUND | StatusCode::FORBIDDEN)) => break,
+                Err(err) => return Err(err),
+            };
+            // A new root must be signed by the keys of the trusted root, and its own keys.
+            let metadata = Metadata::parse(&name, &contents)?;
+            metadata.verify_signatures(&name, "root", &root)?;
+            let new_root: Signed<Root> = metadata.signed(&name, "root")?;
+            metadata.verify_signatures(&name, "root", &new_root)?;
+            if new_root.version != version {
+                return Err(TufError::Version(name, new_root.version, version));
+            }
+            debug!("Updated the Sigstore TUF root to version {version}");
+            self.persist("root.json", &contents)?;
+            root = new_root;
+        }
+        Ok(root)
+    }
+
+    /// Return the trusted root metadata: the persisted root metadata, if it's newer than the
+    /// embedded root metadata.
+    fn trusted_root(&self) -> Result<Signed<Root>, TufError> {
+        let seed = Metadata::parse("root.json", SEED_ROOT)?;
+        let seed_root: Signed<Root> = seed.signed("root.json", "root")?;
+        let Some(persisted) = self.read_persisted("root.json") else {
+            return Ok(seed_root);
+        };
+        // The persisted root was verified before it was persisted, but the embedded root may be
+        // newer after an upgrade.
+        match persisted
+            .signed::<Root>("root.json", "root")
+            .and_then(|root| {
+                persisted
+                    .verify_signatures("root.json", "root", &root)
+                    .map(|()| root)
+            }) {
+            Ok(root) if root.version > seed_root.version => Ok(root),
+            Ok(_) => Ok(seed_root),
+            Err(err) => {
+                debug!("Ignoring the persisted Sigstore TUF root: {err}");
+                Ok(seed_root)
+            }
+        }
+    }
+
+    /// Update the trusted timestamp metadata.
+    async fn update_timestamp(
+        &self,
+        root: &Signed<Root>,
+        now: Timestamp,
+    ) -> Result<Signed<TimestampRole>, TufError> {
+        let name = "timestamp.json";
+        let contents = self.fetch(name).await?;
+        let timestamp: Signed<TimestampRole> =
+            Metadata::parse(name, &contents)?.verify(name, "timestamp", root)?;
+
+        // Reject a rollback of the timestamp or the snapshot it lists. The persisted timestamp
+        // is ignored if its keys were rotated.
+        if let Some(trusted) = self.read_persisted(name).and_then(|metadata| {
+            metadata
+                .verify::<TimestampRole>(name, "timestamp", root)
+                .ok()
+        }) {
+            if timestamp.version < trusted.version {
+                return Err(TufError::Rollback(
+                    name.to_string(),
+                    trusted.version,
+                    timestamp.version,
+                ));
+            }
+            if let (Some(trusted), Some(snapshot)) = (
+                trusted.role.meta.get("snapshot.json"),
+                timestamp.role.meta.get("snapshot.json"),
+            ) {
+                if snapshot.version < trusted.version {
+                    return Err(TufError::Rollback(
+                        "snapshot.json".to_string(),
+                        trusted.version,
+                        snapshot.version,
+                    ));
+                }
+            }
+        }
+        timestamp.check_expiry(name, now)?;
+        self.persist(name, &contents)?;
+        Ok(timestamp)
+    }
+
+    /// Fetch the snapshot or targets metadata, as listed in the timestamp or snapshot metadata.
+    async fn fetch_metadata<T: DeserializeOwned>(
+        &self,
+        root: &Signed<Root>,
+        role: &'static str,
+        meta: &MetaFile,
+        now: Timestamp,
+    ) -> Result<Signed<T>, TufError> {
+        let name = if root.role.consistent_snapshot {
+            format!("{}.{role}.json", meta.version)
+        } else {
+            format!("{role}.json")
+        };
+        let contents = self.fetch(&name).await?;
+        check_file(&name, &contents, meta.length, &meta.hashes)?;
+        let signed: Signed<T> = Metadata::parse(&name, &contents)?.verify(&name, role, root)?;
+        if signed.version != meta.version {
+            return Err(TufError::Version(name, signed.version, meta.version));
+        }
+        signed.check_expiry(&name, now)?;
+        Ok(signed)
+    }
+
+    async fn fetch(&self, path: &str) -> Result<Vec<u8>, TufError> {
+        let url = SigstoreInstance::endpoint(&self.url, path);
+        debug!("Fetching {url}");
+        let response = self
+            .client
+            .get(Url::from(url.clone()))
+            .send()
+            .await
+            .map_err(|err| TufError::ReqwestMiddleware(url.clone(), err))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(TufError::Status(url, status));
+        }
+        let contents = response
+            .bytes()
+            .await
+            .map_err(|err| TufError::Reqwest(url.clone(), err))?;
+        Ok(contents.to_vec())
+    }
+
+    fn read_persisted(&self, name: &str) -> Option<Metadata> {
+        let contents = fs_err::read(self.dir.join(name)).ok()?;
+        Metadata::parse(name, &contents).ok()
+    }
+
+    fn persist(&self, name: &str, contents: &[u8]) -> Result<(), TufError> {
+        let path = self.dir.join(name);
+        fs_err::create_dir_all(self.dir)
+            .and_then(|()| uv_fs::write_atomic_sync(&path, contents))
+            .map_err(|err| TufError::Persist(path, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair};
+    use serde_json::{Value, json};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::der_to_pem;
+
+    const TRUSTED_ROOT: &[u8] = include_bytes!("trusted_root.json");
+
+    /// A key of the test repository.
+    struct TestKey {
+        id: &'static str,
+        key_pair: EcdsaKeyPair,
+        rng: SystemRandom,
+    }
+
+    impl TestKey {
+        fn new(id: &'static str) -> Self {
+            let rng = SystemRandom::new();
+            let pkcs8 =
+                EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+            let key_pair =
+                EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                    .unwrap();
+            Self { id, key_pair, rng }
+        }
+
+        fn json(&self) -> Value {
+            let mut public_key = P256_SPKI_PREFIX.to_vec();
+            public_key.extend_from_slice(self.key_pair.public_key().as_ref());
+            json!({
+                "keytype": "ecdsa",
+                "scheme": "ecdsa-sha2-nistp256",
+                "keyval": { "public": der_to_pem("PUBLIC KEY", &public_key) },
+            })
+        }
+    }
+
+    /// Sign metadata with the given keys.
+    fn sign(signed: &Value, keys: &[&TestKey]) -> Vec<u8> {
+        let mut message = Vec::new();
+        signed
+            .serialize(&mut serde_json::Serializer::with_formatter(
+                &mut message,
+                CanonicalFormatter::new(),
+            ))
+            .unwrap();
+        let signatures = keys
+            .iter()
+            .map(|key| {
+                let signature = key.key_pair.sign(&key.rng, &message).unwrap();
+                json!({ "keyid": key.id, "sig": hex::encode(signature.as_ref()) })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_vec_pretty(&json!({ "signed": signed, "signatures": signatures })).unwrap()
+    }
+
+    /// Root metadata with a single key for all roles.
+    fn root(version: u64, key: &TestKey) -> Value {
+        let role = json!({ "keyids": [key.id], "threshold": 1 });
+        json!({
+            "_type": "root",
+            "spec_version": "1.0",
+            "version": version,
+            "expires": "2100-01-01T00:00:00Z",
+            "consistent_snapshot": true,
+            "keys": { key.id: key.json() },
+            "roles": {
+                "root": role,
+                "timestamp": role,
+                "snapshot": role,
+                "targets": role,
+            },
+        })
+    }
+
+    /// A test repository with a rotated root, serving the trusted root as a target.
+    async fn repository(trusted_root: &[u8]) -> (MockServer, tempfile::TempDir) {
+        let server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+        let old_key = TestKey::new("old");
+        let key = TestKey::new("new");
+
+        // The persisted root, which the rotated root must be signed by.
+        fs_err::write(
+            dir.path().join("root.json"),
+            sign(&root(100, &old_key), &[&old_key]),
+        )
+        .unwrap();
+        let mount = |name: String, contents: Vec<u8>| {
+            Mock::given(method("GET"))
+                .and(path(format!("/{name}")))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(contents))
+        };
+        mount(
+            "101.root.json".to_string(),
+            sign(&root(101, &key), &[&old_key, &key]),
+        )
+        .mount(&server)
+        .await;
+
+        let sha256 = hex::encode(digest(&SHA256, TRUSTED_ROOT));
+        let targets = sign(
+            &json!({
+                "_type": "targets",
+                "version": 7,
+                "expires": "2100-01-01T00:00:00Z",
+                "targets": {
+                    "trusted_root.json": {
+                        "length": TRUSTED_ROOT.len(),
+                        "hashes": { "sha256": sha256 },
+                    },
+                },
+            }),
+            &[&key],
+        );
+        let snapshot = sign(
+            &json!({
+                "_type": "snapshot",
+                "version": 5,
+                "expires": "2100-01-01T00:00:00Z",
+                "meta": { "targets.json": { "version": 7 } },
+            }),
+            &[&key],
+        );
+        let timestamp = sign(
+            &json!({
+                "_type": "timestamp",
+                "version": 3,
+                "expires": "2100-01-01T00:00:00Z",
+                "meta": {
+                    "snapshot.json": {
+                        "version": 5,
+                        "length": snapshot.len(),
+                        "hashes": { "sha256": hex::encode(digest(&SHA256, &snapshot)) },
+                    },
+                },
+            }),
+            &[&key],
+        );
+        mount("timestamp.json".to_string(), timestamp)
+            .mount(&server)
+            .await;
+        mount("5.snapshot.json".to_string(), snapshot)
+            .mount(&server)
+            .await;
+        mount("7.targets.json".to_string(), targets)
+            .mount(&server)
+            .await;
+        mount(
+            format!("targets/{sha256}.trusted_root.json"),
+            trusted_root.to_vec(),
+        )
+        .mount(&server)
+        .await;
+
+        (server, dir)
+    }
+
+    fn client() -> ClientWithMiddleware {
+        reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build()
+    }
+
+    #[test]
+    fn seed_root() {
+        let metadata = Metadata::parse("root.json", SEED_ROOT).unwrap();
+        let root: Signed<Root> = metadata.signed("root.json", "root").unwrap();
+        assert_eq!(root.version, 9);
+        metadata
+            .verify_signatures("root.json", "root", &root)
+            .unwrap();
+
+        // Any change to the signed part invalidates the signatures.
+        let mut tampered = Metadata::parse("root.json", SEED_ROOT).unwrap();
+        tampered.signed["version"] = json!(10);
+        let err = tampered
+            .verify_signatures("root.json", "root", &root)
+            .unwrap_err();
+        assert!(matches!(err, TufError::Threshold(..)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn update() {
+        let (server, dir) = repository(TRUSTED_ROOT).await;
+        let client = client();
+        let repository = TufRepository::new(
+            DisplaySafeUrl::parse(&server.uri()).unwrap(),
+            &client,
+            dir.path(),
+        );
+
+        let contents = repository
+            .fetch_target("trusted_root.json", Timestamp::now())
+            .await
+            .unwrap();
+        assert_eq!(contents, TRUSTED_ROOT);
+
+        // The rotated root, the timestamp and the target are persisted.
+        let root = repository.trusted_root().unwrap();
+        assert_eq!(root.version, 101);
+        assert!(dir.path().join("timestamp.json").is_file());
+        assert_eq!(
+            fs_err::read(dir.path().join("trusted_root.json")).unwrap(),
+            TRUSTED_ROOT
+        );
+
+        // The metadata expires.
+        let err = repository
+            .fetch_target("trusted_root.json", "2100-01-02T00:00:00Z".parse().unwrap())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TufError::Expired(..)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn target_mismatch() {
+        let (server, dir) = repository(b"{}").await;
+        let client = client();
+        let repository = TufRepository::new(
+            DisplaySafeUrl::parse(&server.uri()).unwrap(),
+            &client,
+            dir.path(),
+        );
+
+        let err = repository
+            .fetch_target("trusted_root.json", Timestamp::now())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TufError::Mismatch(..)), "{err:?}");
+        assert!(!dir.path().join("trusted_root.json").exists());
+    }
+}
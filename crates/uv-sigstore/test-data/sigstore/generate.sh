@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+# Generate a certificate authority and a code signing certificate issued by it, to test the
+# verification of Fulcio signing certificates and of signatures made with them. The key of the
+# code signing certificate is kept to sign test files. Like a Fulcio certificate for a GitHub
+# Actions workflow, the code signing certificate records the source repository of the workflow.
+set -euo pipefail
+
+openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -keyout ca.key -out ca.pem \
+  -days 36500 -subj "/O=uv test/CN=uv test CA" \
+  -addext "basicConstraints=critical,CA:TRUE" -addext "keyUsage=critical,keyCertSign"
+openssl req -new -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -keyout leaf.key -out leaf.csr \
+  -subj "/O=uv test"
+openssl x509 -req -in leaf.csr -CA ca.pem -CAkey ca.key -CAcreateserial -out leaf.pem -days 36500 \
+  -extfile <(printf "basicConstraints=critical,CA:FALSE\nkeyUsage=critical,digitalSignature\nextendedKeyUsage=codeSigning\nsubjectAltName=email:test@example.com\n1.3.6.1.4.1.57264.1.12=ASN1:UTF8String:https://github.com/astral-sh/uv\n")
+rm ca.key leaf.csr ca.srl
@@ -45,6 +45,33 @@ impl EnvVars {
     /// directory for caching instead of the default cache directory.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// The compression to apply to unpacked wheels and source distributions in the cache. One of
+    /// `none` (the default) or `zstd`. Compressed wheels reduce the cache's disk footprint, but are
+    /// always decompressed (copied) into the environment, regardless of the link mode.
+    pub const UV_CACHE_COMPRESSION: &'static str = "UV_CACHE_COMPRESSION";
+
+    /// The number of days after which an unused ephemeral environment (e.g., from `uv run --with`
+    /// or `uvx`) is removed from the cache. Defaults to `30`; set to `0` to keep environments
+    /// regardless of their age.
+    pub const UV_CACHE_ENVIRONMENT_MAX_AGE: &'static str = "UV_CACHE_ENVIRONMENT_MAX_AGE";
+
+    /// The maximum total size, in MiB, of the ephemeral environments (e.g., from `uv run --with`
+    /// or `uvx`) in the cache. When exceeded, the least recently used environments are removed.
+    /// Unlimited by default.
+    pub const UV_CACHE_ENVIRONMENT_MAX_SIZE: &'static str = "UV_CACHE_ENVIRONMENT_MAX_SIZE";
+
+    /// The maximum number of ephemeral environments (e.g., from `uv run --with` or `uvx`) in the
+    /// cache. When exceeded, the least recently used environments are removed. Unlimited by
+    /// default.
+    pub const UV_CACHE_ENVIRONMENT_MAX_COUNT: &'static str = "UV_CACHE_ENVIRONMENT_MAX_COUNT";
+
+    /// The protocol uv uses for cross-process locks, e.g., on cache entries. One of `auto`
+    /// (the default), `os`, or `lease`. In `auto` mode, uv uses lease files on network
+    /// filesystems (like NFS and SMB), and OS-level advisory locks otherwise. Network filesystems
+    /// are detected on Linux, macOS, the BSDs, and Windows; on other platforms, set `lease`
+    /// explicitly.
+    pub const UV_LOCK_MODE: &'static str = "UV_LOCK_MODE";
+
     /// Equivalent to the `--no-cache` command-line argument. If set, uv will not use the
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
@@ -104,6 +131,11 @@ impl EnvVars {
     /// as constraints for any source distribution builds. Uses space-separated list of files.
     pub const UV_BUILD_CONSTRAINT: &'static str = "UV_BUILD_CONSTRAINT";
 
+    /// Equivalent to the `--log-dir` argument of `uv build`. If set, uv will append the output of
+    /// the build backend for each source distribution build to a file in this directory, for all
+    /// commands that build packages.
+    pub const UV_BUILD_LOG_DIR: &'static str = "UV_BUILD_LOG_DIR";
+
     /// Equivalent to the `--override` command-line argument. If set, uv will use this file
     /// as the overrides file. Uses space-separated list of files.
     pub const UV_OVERRIDE: &'static str = "UV_OVERRIDE";
@@ -131,6 +163,10 @@ impl EnvVars {
     /// local `uv.toml` file to use as the configuration file.
     pub const UV_CONFIG_FILE: &'static str = "UV_CONFIG_FILE";
 
+    /// The container engine to use for `uv run --container`, e.g., `docker` or `podman`. By
+    /// default, `docker` is used if it's available, and `podman` otherwise.
+    pub const UV_CONTAINER_ENGINE: &'static str = "UV_CONTAINER_ENGINE";
+
     /// Equivalent to the `--no-config` command-line argument. If set, uv will not read
     /// any configuration files from the current directory, parent directories, or user configuration
     /// directories.
@@ -210,6 +246,22 @@ impl EnvVars {
     /// Don't upload a file if it already exists on the index. The value is the URL of the index.
     pub const UV_PUBLISH_CHECK_URL: &'static str = "UV_PUBLISH_CHECK_URL";
 
+    /// An OIDC ID token to exchange for an upload token with trusted publishing in `uv publish`,
+    /// for CI providers other than GitHub Actions and GitLab CI.
+    pub const UV_PUBLISH_OIDC_TOKEN: &'static str = "UV_PUBLISH_OIDC_TOKEN";
+
+    /// Equivalent to the `--fulcio-url` command-line argument. The URL of the Sigstore
+    /// certificate authority for `uv publish --attest` and `--sign`.
+    pub const UV_PUBLISH_FULCIO_URL: &'static str = "UV_PUBLISH_FULCIO_URL";
+
+    /// Equivalent to the `--rekor-url` command-line argument. The URL of the Sigstore
+    /// transparency log for `uv publish --attest` and `--sign`.
+    pub const UV_PUBLISH_REKOR_URL: &'static str = "UV_PUBLISH_REKOR_URL";
+
+    /// Equivalent to the `--sigstore-trusted-root` command-line argument. The path to the
+    /// Sigstore trusted root for `uv publish --attest` and `--sign`.
+    pub const UV_PUBLISH_SIGSTORE_TRUSTED_ROOT: &'static str = "UV_PUBLISH_SIGSTORE_TRUSTED_ROOT";
+
     /// Equivalent to the `--no-sync` command-line argument. If set, uv will skip updating
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
@@ -238,6 +290,22 @@ impl EnvVars {
     /// Equivalent to the `--allow-insecure-host` argument.
     pub const UV_INSECURE_HOST: &'static str = "UV_INSECURE_HOST";
 
+    /// Equivalent to the `--limit-rate` argument.
+    pub const UV_LIMIT_RATE: &'static str = "UV_LIMIT_RATE";
+
+    /// Equivalent to the `--trace-http` command-line argument. Records all HTTP requests to the
+    /// given file, as an HTTP Archive if the path ends in `.har`, or as JSON lines otherwise.
+    pub const UV_TRACE_HTTP: &'static str = "UV_TRACE_HTTP";
+
+    /// Equivalent to the `--debug-auth` command-line argument. If set to `true`, uv will report
+    /// the credentials used for each request, and where they came from.
+    pub const UV_DEBUG_AUTH: &'static str = "UV_DEBUG_AUTH";
+
+    /// Equivalent to the `--strict-index-tracking` command-line argument. If set to `true`, uv will
+    /// refuse to combine versions of a package across indexes that don't track each other, per
+    /// PEP 708.
+    pub const UV_STRICT_INDEX_TRACKING: &'static str = "UV_STRICT_INDEX_TRACKING";
+
     /// Sets the maximum number of in-flight concurrent downloads that uv will
     /// perform at any given time.
     pub const UV_CONCURRENT_DOWNLOADS: &'static str = "UV_CONCURRENT_DOWNLOADS";
@@ -250,6 +318,10 @@ impl EnvVars {
     /// packages.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Equivalent to the `--concurrent-uploads` command-line argument. Sets the maximum number of
+    /// files that `uv publish` uploads concurrently.
+    pub const UV_CONCURRENT_UPLOADS: &'static str = "UV_CONCURRENT_UPLOADS";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
@@ -260,6 +332,13 @@ impl EnvVars {
     /// Specifies the "bin" directory for installing tool executables.
     pub const UV_TOOL_BIN_DIR: &'static str = "UV_TOOL_BIN_DIR";
 
+    /// Specifies the data directory into which `uv tool install --data-files` installs shell
+    /// completions and man pages.
+    pub const UV_TOOL_DATA_DIR: &'static str = "UV_TOOL_DATA_DIR";
+
+    /// Specifies the path to the tools manifest used by `uv tool sync`.
+    pub const UV_TOOL_MANIFEST: &'static str = "UV_TOOL_MANIFEST";
+
     /// Equivalent to the `--build-backend` argument for `uv init`. Determines the default backend
     /// to use when creating a new project.
     pub const UV_INIT_BUILD_BACKEND: &'static str = "UV_INIT_BUILD_BACKEND";
@@ -276,12 +355,26 @@ impl EnvVars {
     /// Specifies the directory for storing managed Python installations.
     pub const UV_PYTHON_INSTALL_DIR: &'static str = "UV_PYTHON_INSTALL_DIR";
 
+    /// Specifies a shared directory of managed Python installations, e.g., `/opt/uv/python`, to
+    /// search after the `UV_PYTHON_INSTALL_DIR` directory when discovering Python versions.
+    pub const UV_PYTHON_SYSTEM_INSTALL_DIR: &'static str = "UV_PYTHON_SYSTEM_INSTALL_DIR";
+
     /// Whether to install the Python executable into the `UV_PYTHON_BIN_DIR` directory.
     pub const UV_PYTHON_INSTALL_BIN: &'static str = "UV_PYTHON_INSTALL_BIN";
 
     /// Whether to install the Python executable into the Windows registry.
     pub const UV_PYTHON_INSTALL_REGISTRY: &'static str = "UV_PYTHON_INSTALL_REGISTRY";
 
+    /// Whether to refuse to install managed Python distributions that can't be verified with an
+    /// attestation from python-build-standalone.
+    ///
+    /// The GitHub artifact attestations of each archive are verified against the Sigstore trusted
+    /// root, in addition to the SHA-256 checksum in the download metadata. Distributions without an
+    /// attestation, such as PyPy and GraalPy, are rejected. Attestations are not verified for
+    /// downloads from a mirror or in offline mode.
+    pub const UV_PYTHON_INSTALL_REQUIRE_ATTESTATIONS: &'static str =
+        "UV_PYTHON_INSTALL_REQUIRE_ATTESTATIONS";
+
     /// Managed Python installations information is hardcoded in the `uv` binary.
     ///
     /// This variable can be set to a URL pointing to JSON to use as a list for Python installations.
@@ -300,7 +393,9 @@ impl EnvVars {
     /// This variable can be set to a mirror URL to use a different source for Python installations.
     /// The provided URL will replace `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g.,
     /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
-    /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    /// Distributions can be read from a local directory by using the `file://` URL scheme or an
+    /// absolute path. A local directory may also contain the archives directly, without the release
+    /// subdirectories.
     pub const UV_PYTHON_INSTALL_MIRROR: &'static str = "UV_PYTHON_INSTALL_MIRROR";
 
     /// Managed PyPy installations are downloaded from [python.org](https://downloads.python.org/).
@@ -375,10 +470,30 @@ impl EnvVars {
     #[attr_hidden]
     pub const UV_LAST_TAG_DISTANCE: &'static str = "UV_LAST_TAG_DISTANCE";
 
+    /// The Python interpreter of the build environment, passed from the `uv_build` Python shims to
+    /// the build backend to run build hooks.
+    #[attr_hidden]
+    pub const UV_INTERNAL__BUILD_BACKEND_PYTHON: &'static str = "UV_INTERNAL__BUILD_BACKEND_PYTHON";
+
     /// Used to set the spawning/parent interpreter when using --system in the test suite.
     #[attr_hidden]
     pub const UV_INTERNAL__PARENT_INTERPRETER: &'static str = "UV_INTERNAL__PARENT_INTERPRETER";
 
+    /// Used to mark a `uv run` invocation that's supervised by `uv run --watch`, which shouldn't
+    /// watch the project itself.
+    #[attr_hidden]
+    pub const UV_INTERNAL__WATCHED: &'static str = "UV_INTERNAL__WATCHED";
+
+    /// Used to mark a `uv run` invocation inside the container of `uv run --container`, which
+    /// shouldn't start another container.
+    #[attr_hidden]
+    pub const UV_INTERNAL__CONTAINERIZED: &'static str = "UV_INTERNAL__CONTAINERIZED";
+
+    /// Used to mark a `uv run` invocation that's spawned by `uv run --all-packages --parallel` for
+    /// a single workspace member, containing the name of the member.
+    #[attr_hidden]
+    pub const UV_INTERNAL__RUN_PACKAGE: &'static str = "UV_INTERNAL__RUN_PACKAGE";
+
     /// Used to force showing the derivation tree during resolver error reporting.
     #[attr_hidden]
     pub const UV_INTERNAL__SHOW_DERIVATION_TREE: &'static str = "UV_INTERNAL__SHOW_DERIVATION_TREE";
@@ -422,6 +537,9 @@ impl EnvVars {
     /// This should be a single file containing both the certificate and the private key in PEM format.
     pub const SSL_CLIENT_CERT: &'static str = "SSL_CLIENT_CERT";
 
+    /// The password used to decrypt PKCS#12 client certificates configured via `client-cert`.
+    pub const UV_CLIENT_CERT_PASSWORD: &'static str = "UV_CLIENT_CERT_PASSWORD";
+
     /// Proxy for HTTP requests.
     pub const HTTP_PROXY: &'static str = "HTTP_PROXY";
 
@@ -429,14 +547,56 @@ impl EnvVars {
     pub const HTTPS_PROXY: &'static str = "HTTPS_PROXY";
 
     /// General proxy for all network requests.
+    ///
+    /// Supports `http://`, `https://`, `socks5://`, and `socks5h://` proxy URLs.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// The URL (or path) of a proxy auto-config (PAC) file, used to select a proxy for each
+    /// request.
+    ///
+    /// Set to `wpad` to discover the PAC file from the system proxy settings (on macOS and
+    /// Windows) or, failing that, via the Web Proxy Auto-Discovery (WPAD) protocol, using the DNS
+    /// domain in `/etc/resolv.conf`.
+    ///
+    /// Takes precedence over `HTTP_PROXY`, `HTTPS_PROXY`, and `ALL_PROXY`, but is ignored if a
+    /// proxy is configured via the `proxy` setting.
+    pub const AUTO_PROXY: &'static str = "AUTO_PROXY";
+
+    /// Timeout (in seconds) for credential helpers. (default: 60 s)
+    pub const UV_CREDENTIAL_HELPER_TIMEOUT: &'static str = "UV_CREDENTIAL_HELPER_TIMEOUT";
+
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
     /// The number of retries for HTTP requests. (default: 3)
+    ///
+    /// Takes precedence over the `http-retries` setting.
     pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
 
+    /// The delay (in seconds) before the first retry of a failed HTTP request, which doubles with
+    /// each subsequent retry. (default: 1 s)
+    pub const UV_HTTP_RETRY_BASE_DELAY: &'static str = "UV_HTTP_RETRY_BASE_DELAY";
+
+    /// The maximum delay (in seconds) between retries of a failed HTTP request.
+    pub const UV_HTTP_RETRY_MAX_DELAY: &'static str = "UV_HTTP_RETRY_MAX_DELAY";
+
+    /// The random jitter to apply to the delay between retries of a failed HTTP request. One of
+    /// `none`, `full` (the default), or `bounded`.
+    pub const UV_HTTP_RETRY_JITTER: &'static str = "UV_HTTP_RETRY_JITTER";
+
+    /// A comma-separated list of additional HTTP status codes to retry (e.g., `403,404`), beyond
+    /// the server errors (5xx), timeouts (408), and rate limits (429) that are always retried.
+    pub const UV_HTTP_RETRY_STATUS_CODES: &'static str = "UV_HTTP_RETRY_STATUS_CODES";
+
+    /// The minimum size (in bytes) of a wheel for which uv reads the wheel's metadata via HTTP
+    /// range requests, when the index doesn't serve the metadata separately. Smaller wheels are
+    /// downloaded in full. Defaults to `0`, i.e., range requests are used for all wheels.
+    pub const UV_LAZY_WHEEL_MIN_SIZE: &'static str = "UV_LAZY_WHEEL_MIN_SIZE";
+
+    /// The number of bytes (default: `16384`) to fetch from the end of a wheel in the initial range
+    /// request, when reading the wheel's metadata via HTTP range requests.
+    pub const UV_LAZY_WHEEL_PREFETCH: &'static str = "UV_LAZY_WHEEL_PREFETCH";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
 
@@ -457,6 +617,15 @@ impl EnvVars {
     /// Used to determine if an active Conda environment is the base environment or not.
     pub const CONDA_DEFAULT_ENV: &'static str = "CONDA_DEFAULT_ENV";
 
+    /// Used to detect Python executables that are pyenv shims.
+    pub const PYENV_ROOT: &'static str = "PYENV_ROOT";
+
+    /// Used to detect Python executables that are asdf shims.
+    pub const ASDF_DATA_DIR: &'static str = "ASDF_DATA_DIR";
+
+    /// Used to detect Python executables that are mise shims.
+    pub const MISE_DATA_DIR: &'static str = "MISE_DATA_DIR";
+
     /// If set to `1` before a virtual environment is activated, then the
     /// virtual environment name will not be prepended to the terminal prompt.
     pub const VIRTUAL_ENV_DISABLE_PROMPT: &'static str = "VIRTUAL_ENV_DISABLE_PROMPT";
@@ -488,6 +657,17 @@ impl EnvVars {
     /// Defaults to `13.0`, the least-recent non-EOL macOS version at time of writing.
     pub const MACOSX_DEPLOYMENT_TARGET: &'static str = "MACOSX_DEPLOYMENT_TARGET";
 
+    /// Set by `uv build --python-platform` to the target platform, to tag wheels built by the
+    /// build backend for a platform other than the current one (e.g., `manylinux_2_28-x86_64`).
+    pub const _PYTHON_HOST_PLATFORM: &'static str = "_PYTHON_HOST_PLATFORM";
+
+    /// The timestamp, in seconds since the Unix epoch, used for the files in source
+    /// distributions and wheels built by the uv build backend, for reproducible builds. Invalid
+    /// values are ignored with a warning.
+    ///
+    /// See [reproducible-builds.org](https://reproducible-builds.org/specs/source-date-epoch/).
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// Disables colored output (takes precedence over `FORCE_COLOR`).
     ///
     /// See [no-color.org](https://no-color.org).
@@ -571,6 +751,13 @@ impl EnvVars {
     /// Used for trusted publishing via `uv publish`. Contains the oidc request token.
     pub const ACTIONS_ID_TOKEN_REQUEST_TOKEN: &'static str = "ACTIONS_ID_TOKEN_REQUEST_TOKEN";
 
+    /// Used for trusted publishing via `uv publish` on GitLab CI.
+    pub const GITLAB_CI: &'static str = "GITLAB_CI";
+
+    /// Used for publish attestations via `uv publish --attest` on GitLab CI. Contains the OIDC
+    /// token with the `sigstore` audience.
+    pub const SIGSTORE_ID_TOKEN: &'static str = "SIGSTORE_ID_TOKEN";
+
     /// Sets the encoding for standard I/O streams (e.g., PYTHONIOENCODING=utf-8).
     #[attr_hidden]
     pub const PYTHONIOENCODING: &'static str = "PYTHONIOENCODING";
@@ -779,4 +966,31 @@ impl EnvVars {
 
     /// Disable Hugging Face authentication, even if `HF_TOKEN` is set.
     pub const UV_NO_HF_TOKEN: &'static str = "UV_NO_HF_TOKEN";
+
+    /// The AWS access key ID, used to sign requests to `s3://` indexes and AWS CodeArtifact.
+    pub const AWS_ACCESS_KEY_ID: &'static str = "AWS_ACCESS_KEY_ID";
+
+    /// The AWS secret access key, used to sign requests to `s3://` indexes and AWS CodeArtifact.
+    pub const AWS_SECRET_ACCESS_KEY: &'static str = "AWS_SECRET_ACCESS_KEY";
+
+    /// The AWS session token, used alongside temporary AWS credentials.
+    pub const AWS_SESSION_TOKEN: &'static str = "AWS_SESSION_TOKEN";
+
+    /// The profile to use from the AWS shared config and credentials files. (default: `default`)
+    pub const AWS_PROFILE: &'static str = "AWS_PROFILE";
+
+    /// The path to the AWS shared credentials file. (default: `~/.aws/credentials`)
+    pub const AWS_SHARED_CREDENTIALS_FILE: &'static str = "AWS_SHARED_CREDENTIALS_FILE";
+
+    /// The AWS region of the buckets that host `s3://` indexes.
+    pub const AWS_REGION: &'static str = "AWS_REGION";
+
+    /// The AWS region of the buckets that host `s3://` indexes, if `AWS_REGION` is not set.
+    pub const AWS_DEFAULT_REGION: &'static str = "AWS_DEFAULT_REGION";
+
+    /// A custom endpoint for S3-compatible storage (e.g., MinIO), used for `s3://` indexes.
+    pub const AWS_ENDPOINT_URL: &'static str = "AWS_ENDPOINT_URL";
+
+    /// A custom endpoint for S3-compatible storage, taking precedence over `AWS_ENDPOINT_URL`.
+    pub const AWS_ENDPOINT_URL_S3: &'static str = "AWS_ENDPOINT_URL_S3";
 }
@@ -2,7 +2,7 @@ use core::fmt;
 use fs_err as fs;
 
 use uv_configuration::Preview;
-use uv_dirs::user_executable_directory;
+use uv_dirs::{user_data_directory, user_executable_directory};
 use uv_pep440::Version;
 use uv_pep508::{InvalidNameError, PackageName};
 
@@ -42,6 +42,8 @@ pub enum Error {
     EntrypointRead(#[from] uv_install_wheel::Error),
     #[error("Failed to find a directory to install executables into")]
     NoExecutableDirectory,
+    #[error("Failed to find a directory to install data files into")]
+    NoDataDirectory,
     #[error(transparent)]
     ToolName(#[from] InvalidNameError),
     #[error(transparent)]
@@ -89,6 +91,11 @@ impl InstalledTools {
         self.root.join(name.to_string())
     }
 
+    /// Return the directory for the dependency layers shared between tool environments.
+    pub fn layers_dir(&self) -> PathBuf {
+        self.root.join(".layers")
+    }
+
     /// Return the metadata for all installed tools.
     ///
     /// If a tool is present, but is missing a receipt or the receipt is invalid, the tool will be
@@ -105,6 +112,10 @@ impl InstalledTools {
             else {
                 continue;
             };
+            // Skip hidden directories, e.g., the shared dependency layers.
+            if name.starts_with('.') {
+                continue;
+            }
             let name = PackageName::from_str(name)?;
             let path = directory.join("uv-receipt.toml");
             let contents = match fs_err::read_to_string(&path) {
@@ -379,6 +390,20 @@ pub fn tool_executable_dir() -> Result<PathBuf, Error> {
     user_executable_directory(Some(EnvVars::UV_TOOL_BIN_DIR)).ok_or(Error::NoExecutableDirectory)
 }
 
+/// Find the tool data directory, into which shell completions and man pages are installed.
+pub fn tool_data_dir() -> Result<PathBuf, Error> {
+    user_data_directory(Some(EnvVars::UV_TOOL_DATA_DIR)).ok_or(Error::NoDataDirectory)
+}
+
+/// The directories within `share` that contain shell completions and man pages, which are picked
+/// up from the user's data directory (e.g., `~/.local/share`) by the respective tools.
+const DATA_FILE_DIRECTORIES: &[&str] = &[
+    "man",
+    "bash-completion/completions",
+    "zsh/site-functions",
+    "fish/vendor_completions.d",
+];
+
 /// Find the `.dist-info` directory for a package in an environment.
 fn find_dist_info<'a>(
     site_packages: &'a SitePackages,
@@ -443,3 +468,48 @@ pub fn entrypoint_paths(
 
     Ok(entrypoints)
 }
+
+/// Find the paths to the shell completions and man pages provided by a package in an environment,
+/// i.e., the files it installs into `share/man`, `share/bash-completion/completions`,
+/// `share/zsh/site-functions`, or `share/fish/vendor_completions.d` via the `.data` directory.
+///
+/// Returns a list of `(relative_path, path)` tuples, where the relative path is relative to the
+/// `share` directory.
+pub fn data_file_paths(
+    site_packages: &SitePackages,
+    package_name: &PackageName,
+    package_version: &Version,
+) -> Result<Vec<(PathBuf, PathBuf)>, Error> {
+    let dist_info_path = find_dist_info(site_packages, package_name, package_version)?;
+
+    // Read the RECORD file.
+    let record = read_record_file(&mut File::open(dist_info_path.join("RECORD"))?)?;
+
+    // The RECORD file uses relative paths, so we're looking for the relative path to be a prefix.
+    let layout = site_packages.interpreter().layout();
+    let share = layout.scheme.data.join("share");
+    let share_relative = pathdiff::diff_paths(&share, &layout.scheme.purelib).ok_or_else(|| {
+        io::Error::other(format!(
+            "Could not find relative path for: {}",
+            share.simplified_display()
+        ))
+    })?;
+
+    let mut data_files = vec![];
+    for entry in record {
+        let relative_path = PathBuf::from(&entry.path);
+        let Ok(path_in_share) = relative_path.strip_prefix(&share_relative) else {
+            continue;
+        };
+        if !DATA_FILE_DIRECTORIES
+            .iter()
+            .any(|directory| path_in_share.starts_with(directory))
+        {
+            continue;
+        }
+        data_files.push((path_in_share.to_path_buf(), share.join(path_in_share)));
+    }
+    data_files.sort();
+
+    Ok(data_files)
+}
@@ -26,6 +26,8 @@ pub struct Tool {
     python: Option<PythonRequest>,
     /// A mapping of entry point names to their metadata.
     entrypoints: Vec<ToolEntrypoint>,
+    /// The paths to the shell completions and man pages installed for this tool.
+    data_files: Vec<PathBuf>,
     /// The [`ToolOptions`] used to install this tool.
     options: ToolOptions,
 }
@@ -44,6 +46,8 @@ struct ToolWire {
     python: Option<PythonRequest>,
     entrypoints: Vec<ToolEntrypoint>,
     #[serde(default)]
+    data_files: Vec<PathBuf>,
+    #[serde(default)]
     options: ToolOptions,
 }
 
@@ -70,6 +74,7 @@ impl From<Tool> for ToolWire {
             build_constraint_dependencies: tool.build_constraints,
             python: tool.python,
             entrypoints: tool.entrypoints,
+            data_files: tool.data_files,
             options: tool.options,
         }
     }
@@ -93,6 +98,7 @@ impl TryFrom<ToolWire> for Tool {
             build_constraints: tool.build_constraint_dependencies,
             python: tool.python,
             entrypoints: tool.entrypoints,
+            data_files: tool.data_files,
             options: tool.options,
         })
     }
@@ -178,6 +184,7 @@ impl Tool {
             build_constraints,
             python,
             entrypoints,
+            data_files: Vec::new(),
             options,
         }
     }
@@ -188,6 +195,14 @@ impl Tool {
         Self { options, ..self }
     }
 
+    /// Create a new [`Tool`] with the given data files, i.e., shell completions and man pages.
+    #[must_use]
+    pub fn with_data_files(self, data_files: impl Iterator<Item = PathBuf>) -> Self {
+        let mut data_files: Vec<_> = data_files.collect();
+        data_files.sort();
+        Self { data_files, ..self }
+    }
+
     /// Returns the TOML table for this tool.
     pub(crate) fn to_toml(&self) -> Result<Table, toml_edit::ser::Error> {
         let mut table = Table::new();
@@ -300,6 +315,18 @@ impl Tool {
             value(entrypoints)
         });
 
+        if !self.data_files.is_empty() {
+            table.insert("data-files", {
+                let data_files = each_element_on_its_line_array(
+                    self.data_files
+                        .iter()
+                        // Use cross-platform slashes so the toml string type does not change
+                        .map(|path| PortablePath::from(path).to_string()),
+                );
+                value(data_files)
+            });
+        }
+
         if self.options != ToolOptions::default() {
             let serialized =
                 serde::Serialize::serialize(&self.options, toml_edit::ser::ValueSerializer::new())?;
@@ -318,6 +345,10 @@ impl Tool {
         &self.entrypoints
     }
 
+    pub fn data_files(&self) -> &[PathBuf] {
+        &self.data_files
+    }
+
     pub fn requirements(&self) -> &[Requirement] {
         &self.requirements
     }
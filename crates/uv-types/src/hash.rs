@@ -10,7 +10,7 @@ use uv_distribution_types::{
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{HashDigest, HashDigests, HashError, ResolverMarkerEnvironment};
+use uv_pypi_types::{HashDigest, HashDigests, HashError, ParsedUrl, ResolverMarkerEnvironment};
 use uv_redacted::DisplaySafeUrl;
 
 #[derive(Debug, Default, Clone)]
@@ -26,7 +26,9 @@ pub enum HashStrategy {
     Verify(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
     /// Hashes should be validated against a pre-defined list of hashes.
     ///
-    /// If necessary, hashes should be generated to ensure that the archive is valid.
+    /// If necessary, hashes should be generated to ensure that the archive is valid. Distributions
+    /// that are pinned by other means (i.e., Git dependencies pinned to a full commit SHA) map to
+    /// an empty list, and are exempt from hash-checking.
     Require(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
 }
 
@@ -43,12 +45,7 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&distribution.version_id())
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes) => Self::require(hashes, &distribution.version_id()),
         }
     }
 
@@ -66,11 +63,9 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&VersionId::from_registry(name.clone(), version.clone()))
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
+            Self::Require(hashes) => Self::require(
+                hashes,
+                &VersionId::from_registry(name.clone(), version.clone()),
             ),
         }
     }
@@ -87,12 +82,20 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&VersionId::from_url(url))
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes) => Self::require(hashes, &VersionId::from_url(url)),
+        }
+    }
+
+    /// Return the [`HashPolicy`] for the given distribution in `--require-hashes` mode.
+    fn require<'a>(
+        hashes: &'a FxHashMap<VersionId, Vec<HashDigest>>,
+        id: &VersionId,
+    ) -> HashPolicy<'a> {
+        match hashes.get(id) {
+            // The distribution is pinned to a Git commit, which stands in for a hash.
+            Some(digests) if digests.is_empty() => HashPolicy::None,
+            Some(digests) => HashPolicy::Validate(digests),
+            None => HashPolicy::Validate(&[]),
         }
     }
 
@@ -246,6 +249,35 @@ impl HashStrategy {
             // Under `--require-hashes`, every requirement must include a hash.
             if digests.is_empty() {
                 if mode.is_require() {
+                    // Git requirements may instead be pinned to a full commit SHA, which
+                    // identifies the contents of the repository.
+                    let git = match &requirement {
+                        UnresolvedRequirement::Named(requirement) => match &requirement.source {
+                            RequirementSource::Git { git, .. } => Some(git),
+                            _ => None,
+                        },
+                        UnresolvedRequirement::Unnamed(requirement) => {
+                            match &requirement.url.parsed_url {
+                                ParsedUrl::Git(parsed) => Some(&parsed.url),
+                                _ => None,
+                            }
+                        }
+                    };
+                    if let Some(git) = git {
+                        let pinned = git.precise().is_some()
+                            || git.reference().as_str().is_some_and(|rev| {
+                                rev.len() == 40 && rev.chars().all(|ch| ch.is_ascii_hexdigit())
+                            });
+                        if !pinned {
+                            return Err(HashStrategyError::UnpinnedGitRequirement(
+                                requirement.to_string(),
+                                mode,
+                            ));
+                        }
+                        requirement_hashes.insert(id, Vec::new());
+                        continue;
+                    }
+
                     return Err(HashStrategyError::MissingHashes(
                         requirement.to_string(),
                         mode,
@@ -333,6 +365,10 @@ pub enum HashStrategyError {
     UnpinnedRequirement(String, HashCheckingMode),
     #[error("In `{1}` mode, all requirements must have a hash, but none were provided for: {0}")]
     MissingHashes(String, HashCheckingMode),
+    #[error(
+        "In `{1}` mode, Git requirements must either have a hash or be pinned to a full commit SHA, but found: {0}"
+    )]
+    UnpinnedGitRequirement(String, HashCheckingMode),
     #[error(
         "In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}"
     )]
@@ -12,8 +12,8 @@ use uv_configuration::{
 };
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    CachedDist, DependencyMetadata, DistributionId, IndexCapabilities, IndexLocations,
-    InstalledDist, IsBuildBackendError, Requirement, Resolution, SourceDist,
+    BuildSystemOverrides, CachedDist, DependencyMetadata, DistributionId, IndexCapabilities,
+    IndexLocations, InstalledDist, IsBuildBackendError, Requirement, Resolution, SourceDist,
 };
 use uv_git::GitResolver;
 use uv_pep508::PackageName;
@@ -80,6 +80,9 @@ pub trait BuildContext {
     /// Return a reference to any pre-defined static metadata.
     fn dependency_metadata(&self) -> &DependencyMetadata;
 
+    /// Return a reference to any user-defined overrides of the `[build-system]` table.
+    fn build_system_overrides(&self) -> &BuildSystemOverrides;
+
     /// Whether source distribution building or pre-built wheels is disabled.
     ///
     /// This [`BuildContext::setup_build`] calls will fail if builds are disabled.
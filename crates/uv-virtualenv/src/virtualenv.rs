@@ -184,8 +184,12 @@ pub(crate) fn create(
     // Create a `.gitignore` file to ignore all files in the venv.
     fs::write(location.join(".gitignore"), "*")?;
 
+    // Transparent upgrades are supported for managed CPython (i.e., `python-build-standalone`),
+    // PyPy, and GraalPy installations.
+    let upgradeable = upgradeable && (interpreter.is_standalone() || interpreter.is_managed());
+
     let mut using_minor_version_link = false;
-    let executable_target = if upgradeable && interpreter.is_standalone() {
+    let executable_target = if upgradeable {
         if let Some(minor_version_link) = PythonMinorVersionLink::from_executable(
             base_python.as_path(),
             &interpreter.key(),
@@ -292,6 +296,26 @@ pub(crate) fn create(
                 create_link_to_executable(targetwt.as_path(), &executable_target)
                     .map_err(Error::Python)?;
             }
+            if interpreter.markers().implementation_name() == "pypy" {
+                for executable in [
+                    WindowsExecutable::PyPy,
+                    WindowsExecutable::PyPyMajor,
+                    WindowsExecutable::PyPyMajorMinor,
+                    WindowsExecutable::PyPyw,
+                    WindowsExecutable::PyPyMajorMinorw,
+                ] {
+                    let target = scripts.join(executable.exe(interpreter));
+                    create_link_to_executable(target.as_path(), &executable_target)
+                        .map_err(Error::Python)?;
+                }
+            }
+            if interpreter.markers().implementation_name() == "graalpy" {
+                for executable in [WindowsExecutable::GraalPy, WindowsExecutable::PythonMajor] {
+                    let target = scripts.join(executable.exe(interpreter));
+                    create_link_to_executable(target.as_path(), &executable_target)
+                        .map_err(Error::Python)?;
+                }
+            }
         } else {
             // Always copy `python.exe`.
             copy_launcher_windows(
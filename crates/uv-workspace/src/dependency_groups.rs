@@ -7,11 +7,12 @@ use tracing::error;
 
 use uv_distribution_types::RequiresPython;
 use uv_fs::Simplified;
-use uv_normalize::{DEV_DEPENDENCIES, GroupName};
+use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::Pep508Error;
 use uv_pypi_types::{DependencyGroupSpecifier, VerbatimParsedUrl};
 
+use crate::Workspace;
 use crate::pyproject::{DependencyGroupSettings, PyProjectToml, ToolUvDependencyGroups};
 
 /// PEP 735 dependency groups, with any `include-group` entries resolved.
@@ -27,10 +28,14 @@ pub struct FlatDependencyGroup {
 impl FlatDependencyGroups {
     /// Gather and flatten all the dependency-groups defined in the given pyproject.toml
     ///
-    /// The path is only used in diagnostics.
+    /// Any `include-group` entries that reference groups in other workspace members (or groups that
+    /// are only defined in the workspace root) are resolved against the given [`Workspace`].
+    ///
+    /// The path is only used in diagnostics, and to identify the project within the workspace.
     pub fn from_pyproject_toml(
         path: &Path,
         pyproject_toml: &PyProjectToml,
+        workspace: &Workspace,
     ) -> Result<Self, DependencyGroupError> {
         // First, collect `tool.uv.dev_dependencies`
         let dev_dependencies = pyproject_toml
@@ -39,28 +44,28 @@ impl FlatDependencyGroups {
             .and_then(|tool| tool.uv.as_ref())
             .and_then(|uv| uv.dev_dependencies.as_ref());
 
-        // Then, collect `dependency-groups`
-        let dependency_groups = pyproject_toml
-            .dependency_groups
-            .iter()
-            .flatten()
-            .collect::<BTreeMap<_, _>>();
-
-        // Get additional settings
-        let empty_settings = ToolUvDependencyGroups::default();
-        let group_settings = pyproject_toml
-            .tool
-            .as_ref()
-            .and_then(|tool| tool.uv.as_ref())
-            .and_then(|uv| uv.dependency_groups.as_ref())
-            .unwrap_or(&empty_settings);
+        // Then, collect the `dependency-groups` for the project, followed by those of the workspace
+        // root and the remaining workspace members, which may be included from the project.
+        let mut projects = vec![ProjectGroups::from_pyproject_toml(pyproject_toml)];
+        let root = if path == workspace.install_path() {
+            None
+        } else {
+            projects.push(ProjectGroups::from_pyproject_toml(
+                workspace.pyproject_toml(),
+            ));
+            Some(projects.len() - 1)
+        };
+        projects.extend(
+            workspace
+                .packages()
+                .values()
+                .filter(|member| member.root() != path && member.root() != workspace.install_path())
+                .map(|member| ProjectGroups::from_pyproject_toml(member.pyproject_toml())),
+        );
 
         // Flatten the dependency groups.
-        let mut dependency_groups = FlatDependencyGroups::from_dependency_groups(
-            &dependency_groups,
-            group_settings.inner(),
-        )
-        .map_err(|err| DependencyGroupError {
+        let mut dependency_groups = FlatDependencyGroups::from_dependency_groups(&projects, root)
+            .map_err(|err| DependencyGroupError {
             package: pyproject_toml
                 .project
                 .as_ref()
@@ -88,25 +93,29 @@ impl FlatDependencyGroups {
         Ok(dependency_groups)
     }
 
-    /// Resolve the dependency groups (which may contain references to other groups) into concrete
-    /// lists of requirements.
+    /// Resolve the dependency groups of the first project (which may contain references to other
+    /// groups, in the same project or in others) into concrete lists of requirements.
+    ///
+    /// If a group isn't defined in the including project, it's resolved against the workspace
+    /// root, if any.
     fn from_dependency_groups(
-        groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
-        settings: &BTreeMap<GroupName, DependencyGroupSettings>,
+        projects: &[ProjectGroups],
+        root: Option<usize>,
     ) -> Result<Self, DependencyGroupErrorInner> {
         fn resolve_group<'data>(
-            resolved: &mut BTreeMap<GroupName, FlatDependencyGroup>,
-            groups: &'data BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
-            settings: &BTreeMap<GroupName, DependencyGroupSettings>,
+            resolved: &mut BTreeMap<(usize, GroupName), FlatDependencyGroup>,
+            projects: &'data [ProjectGroups<'data>],
+            root: Option<usize>,
+            project: usize,
             name: &'data GroupName,
-            parents: &mut Vec<&'data GroupName>,
+            parents: &mut Vec<(usize, &'data GroupName)>,
         ) -> Result<(), DependencyGroupErrorInner> {
-            let Some(specifiers) = groups.get(name) else {
+            let Some(specifiers) = projects[project].groups.get(name) else {
                 // Missing group
                 let parent_name = parents
                     .iter()
                     .last()
-                    .copied()
+                    .map(|(_, name)| *name)
                     .expect("parent when group is missing");
                 return Err(DependencyGroupErrorInner::GroupNotFound(
                     name.clone(),
@@ -115,18 +124,18 @@ impl FlatDependencyGroups {
             };
 
             // "Dependency Group Includes MUST NOT include cycles, and tools SHOULD report an error if they detect a cycle."
-            if parents.contains(&name) {
+            if parents.contains(&(project, name)) {
                 return Err(DependencyGroupErrorInner::DependencyGroupCycle(Cycle(
-                    parents.iter().copied().cloned().collect(),
+                    parents.iter().map(|(_, name)| (*name).clone()).collect(),
                 )));
             }
 
             // If we already resolved this group, short-circuit.
-            if resolved.contains_key(name) {
+            if resolved.contains_key(&(project, name.clone())) {
                 return Ok(());
             }
 
-            parents.push(name);
+            parents.push((project, name));
             let mut requirements = Vec::with_capacity(specifiers.len());
             let mut requires_python_intersection = VersionSpecifiers::empty();
             for specifier in *specifiers {
@@ -143,9 +152,38 @@ impl FlatDependencyGroups {
                             }
                         }
                     }
-                    DependencyGroupSpecifier::IncludeGroup { include_group } => {
-                        resolve_group(resolved, groups, settings, include_group, parents)?;
-                        if let Some(included) = resolved.get(include_group) {
+                    DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        package,
+                    } => {
+                        // Determine the project that defines the included group.
+                        let target = if let Some(package) = package {
+                            let target = projects
+                                .iter()
+                                .position(|project| project.name == Some(package))
+                                .ok_or_else(|| {
+                                    DependencyGroupErrorInner::PackageNotFound(
+                                        package.clone(),
+                                        name.clone(),
+                                    )
+                                })?;
+                            if !projects[target].groups.contains_key(include_group) {
+                                return Err(DependencyGroupErrorInner::PackageGroupNotFound(
+                                    include_group.clone(),
+                                    package.clone(),
+                                    name.clone(),
+                                ));
+                            }
+                            target
+                        } else if projects[project].groups.contains_key(include_group) {
+                            project
+                        } else {
+                            root.filter(|root| projects[*root].groups.contains_key(include_group))
+                                .unwrap_or(project)
+                        };
+
+                        resolve_group(resolved, projects, root, target, include_group, parents)?;
+                        if let Some(included) = resolved.get(&(target, include_group.clone())) {
                             requirements.extend(included.requirements.iter().cloned());
 
                             // Intersect the requires-python for this group with the included group's
@@ -167,8 +205,10 @@ impl FlatDependencyGroups {
             }
 
             let empty_settings = DependencyGroupSettings::default();
-            let DependencyGroupSettings { requires_python } =
-                settings.get(name).unwrap_or(&empty_settings);
+            let DependencyGroupSettings { requires_python } = projects[project]
+                .settings
+                .get(name)
+                .unwrap_or(&empty_settings);
             if let Some(requires_python) = requires_python {
                 // Intersect the requires-python for this group to get the final requires-python
                 // that will be used by interpreter discovery and checking.
@@ -190,7 +230,7 @@ impl FlatDependencyGroups {
             parents.pop();
 
             resolved.insert(
-                name.clone(),
+                (project, name.clone()),
                 FlatDependencyGroup {
                     requirements,
                     requires_python: if requires_python_intersection.is_empty() {
@@ -203,9 +243,13 @@ impl FlatDependencyGroups {
             Ok(())
         }
 
+        let Some(current) = projects.first() else {
+            return Ok(Self::default());
+        };
+
         // Validate the settings
-        for (group_name, ..) in settings {
-            if !groups.contains_key(group_name) {
+        for (group_name, ..) in current.settings {
+            if !current.groups.contains_key(group_name) {
                 return Err(DependencyGroupErrorInner::SettingsGroupNotFound(
                     group_name.clone(),
                 ));
@@ -213,11 +257,17 @@ impl FlatDependencyGroups {
         }
 
         let mut resolved = BTreeMap::new();
-        for name in groups.keys() {
+        for name in current.groups.keys() {
             let mut parents = Vec::new();
-            resolve_group(&mut resolved, groups, settings, name, &mut parents)?;
+            resolve_group(&mut resolved, projects, root, 0, name, &mut parents)?;
         }
-        Ok(Self(resolved))
+        Ok(Self(
+            resolved
+                .into_iter()
+                .filter(|((project, _), _)| *project == 0)
+                .map(|((_, name), group)| (name, group))
+                .collect(),
+        ))
     }
 
     /// Return the requirements for a given group, if any.
@@ -236,6 +286,37 @@ impl FlatDependencyGroups {
     }
 }
 
+/// The (unflattened) dependency groups defined in a `pyproject.toml`.
+struct ProjectGroups<'data> {
+    /// The name of the project, if the `pyproject.toml` defines a `[project]` table.
+    name: Option<&'data PackageName>,
+    /// The `dependency-groups` table.
+    groups: BTreeMap<&'data GroupName, &'data Vec<DependencyGroupSpecifier>>,
+    /// The `tool.uv.dependency-groups` table.
+    settings: &'data BTreeMap<GroupName, DependencyGroupSettings>,
+}
+
+impl<'data> ProjectGroups<'data> {
+    fn from_pyproject_toml(pyproject_toml: &'data PyProjectToml) -> Self {
+        static EMPTY: BTreeMap<GroupName, DependencyGroupSettings> = BTreeMap::new();
+        Self {
+            name: pyproject_toml.project.as_ref().map(|project| &project.name),
+            groups: pyproject_toml
+                .dependency_groups
+                .iter()
+                .flatten()
+                .collect::<BTreeMap<_, _>>(),
+            settings: pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dependency_groups.as_ref())
+                .map(ToolUvDependencyGroups::inner)
+                .unwrap_or(&EMPTY),
+        }
+    }
+}
+
 impl FromIterator<(GroupName, FlatDependencyGroup)> for FlatDependencyGroups {
     fn from_iter<T: IntoIterator<Item = (GroupName, FlatDependencyGroup)>>(iter: T) -> Self {
         Self(iter.into_iter().collect())
@@ -278,6 +359,10 @@ pub enum DependencyGroupErrorInner {
     ),
     #[error("Failed to find group `{0}` included by `{1}`")]
     GroupNotFound(GroupName, GroupName),
+    #[error("Failed to find workspace member `{0}` for a group included by `{1}`")]
+    PackageNotFound(PackageName, GroupName),
+    #[error("Failed to find group `{0}` in workspace member `{1}` included by `{2}`")]
+    PackageGroupNotFound(GroupName, PackageName, GroupName),
     #[error(
         "Group `{0}` includes the `dev` group (`include = \"dev\"`), but only `tool.uv.dev-dependencies` was found. To reference the `dev` group via an `include`, remove the `tool.uv.dev-dependencies` section and add any development dependencies to the `dev` entry in the `[dependency-groups]` table instead."
     )]
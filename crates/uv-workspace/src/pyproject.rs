@@ -116,6 +116,24 @@ impl PyProjectToml {
         }
     }
 
+    /// Returns the names of the entry points in the `[project.scripts]` table.
+    pub fn scripts(&self) -> impl Iterator<Item = &str> {
+        self.project
+            .iter()
+            .filter_map(|project| project.scripts.as_ref())
+            .flat_map(BTreeMap::keys)
+            .map(String::as_str)
+    }
+
+    /// Returns the names of the entry points in the `[project.gui-scripts]` table.
+    pub fn gui_scripts(&self) -> impl Iterator<Item = &str> {
+        self.project
+            .iter()
+            .filter_map(|project| project.gui_scripts.as_ref())
+            .flat_map(BTreeMap::keys)
+            .map(String::as_str)
+    }
+
     /// Returns the set of conflicts for the project.
     pub fn conflicts(&self) -> Conflicts {
         let empty = Conflicts::empty();
@@ -168,12 +186,14 @@ pub struct Project {
     /// The optional dependencies of the project.
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
 
-    /// Used to determine whether a `gui-scripts` section is present.
+    /// Used to determine whether a `gui-scripts` section is present, and the names of the GUI
+    /// entry points.
     #[serde(default, skip_serializing)]
-    pub(crate) gui_scripts: Option<serde::de::IgnoredAny>,
-    /// Used to determine whether a `scripts` section is present.
+    pub(crate) gui_scripts: Option<BTreeMap<String, toml::Value>>,
+    /// Used to determine whether a `scripts` section is present, and the names of the entry
+    /// points.
     #[serde(default, skip_serializing)]
-    pub(crate) scripts: Option<serde::de::IgnoredAny>,
+    pub(crate) scripts: Option<BTreeMap<String, toml::Value>>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -185,8 +205,8 @@ struct ProjectWire {
     requires_python: Option<VersionSpecifiers>,
     dependencies: Option<Vec<String>>,
     optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
-    gui_scripts: Option<serde::de::IgnoredAny>,
-    scripts: Option<serde::de::IgnoredAny>,
+    gui_scripts: Option<BTreeMap<String, toml::Value>>,
+    scripts: Option<BTreeMap<String, toml::Value>>,
 }
 
 impl TryFrom<ProjectWire> for Project {
@@ -470,6 +490,28 @@ pub struct ToolUv {
     )]
     pub constraint_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
 
+    /// Constraints files to apply when resolving the project's dependencies, in addition to any
+    /// `constraint-dependencies`.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. Each entry can be a path, which is resolved relative to the
+    /// workspace root, or an `http://` or `https://` URL, such that a centrally managed set of
+    /// constraints can be shared across projects. Remote files are cached, and revalidated
+    /// according to the server's HTTP caching headers.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `constraint-files` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            constraint-files = ["https://example.com/constraints.txt"]
+        "#
+    )]
+    pub constraint_files: Option<Vec<PathBuf>>,
+
     /// Constraints to apply when solving build dependencies.
     ///
     /// Build constraints are used to restrict the versions of build dependencies that are selected
@@ -609,6 +651,38 @@ pub struct ToolUv {
     )]
     pub conflicts: Option<SchemaConflicts>,
 
+    /// Tasks that can be run with `uv run <name>`.
+    ///
+    /// A task is either a command, given as a string or as a list of arguments, or a table with
+    /// the following keys:
+    ///
+    /// - `cmd`: The command to run.
+    /// - `env`: Environment variables to set for the command.
+    /// - `cwd`: The working directory for the command, relative to the project directory.
+    /// - `depends-on`: Tasks to run before the command.
+    /// - `help`: A description of the task.
+    ///
+    /// Commands given as a string are split into arguments like a POSIX shell would, but are not
+    /// run in a shell, so shell features such as pipes are unsupported. Arguments passed to
+    /// `uv run <name>` are appended to the command. Commands run in the project environment, from
+    /// the project directory by default.
+    ///
+    /// In a workspace, the tasks of the workspace root are available in all members, e.g., with
+    /// `uv run --package <member> <name>`, and run from the member's directory. A member can
+    /// override a workspace task by defining a task with the same name.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.tasks]
+            test = "pytest -x"
+            lint = { cmd = ["ruff", "check", "."], help = "Lint the project" }
+            check = { depends-on = ["lint", "test"] }
+            serve = { cmd = "python -m http.server", cwd = "docs", env = { PYTHONUNBUFFERED = "1" } }
+        "#
+    )]
+    pub tasks: Option<BTreeMap<String, Task>>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -619,6 +693,92 @@ pub struct ToolUv {
     pub build_backend: Option<BuildBackendSettingsSchema>,
 }
 
+/// A task defined in `tool.uv.tasks`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Task {
+    /// A command without further settings, e.g., `test = "pytest"`.
+    Command(TaskCommand),
+    /// A task with settings, e.g., `test = { cmd = "pytest", env = { CI = "1" } }`.
+    Settings(TaskSettings),
+}
+
+impl Task {
+    /// The command to run, if any.
+    ///
+    /// Tasks without a command only run the tasks they depend on.
+    pub fn cmd(&self) -> Option<&TaskCommand> {
+        match self {
+            Self::Command(cmd) => Some(cmd),
+            Self::Settings(settings) => settings.cmd.as_ref(),
+        }
+    }
+
+    /// The environment variables to set for the command.
+    pub fn env(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            Self::Command(_) => None,
+            Self::Settings(settings) => settings.env.as_ref(),
+        }
+    }
+
+    /// The working directory of the command, relative to the project directory.
+    pub fn cwd(&self) -> Option<&Path> {
+        match self {
+            Self::Command(_) => None,
+            Self::Settings(settings) => settings.cwd.as_deref(),
+        }
+    }
+
+    /// The tasks to run before this task.
+    pub fn depends_on(&self) -> &[String] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Settings(settings) => settings.depends_on.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// The description of the task.
+    pub fn help(&self) -> Option<&str> {
+        match self {
+            Self::Command(_) => None,
+            Self::Settings(settings) => settings.help.as_deref(),
+        }
+    }
+}
+
+/// The command of a task.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TaskCommand {
+    /// A command line, split into arguments like a POSIX shell would, e.g., `"pytest -x"`.
+    String(String),
+    /// A list of arguments, e.g., `["pytest", "-x"]`.
+    Args(Vec<String>),
+}
+
+/// The settings of a task in `tool.uv.tasks`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TaskSettings {
+    /// The command to run.
+    pub cmd: Option<TaskCommand>,
+    /// Environment variables to set for the command.
+    pub env: Option<BTreeMap<String, String>>,
+    /// The working directory of the command, relative to the project directory.
+    pub cwd: Option<PathBuf>,
+    /// The tasks to run before this task.
+    pub depends_on: Option<Vec<String>>,
+    /// A description of the task.
+    pub help: Option<String>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -756,7 +916,8 @@ pub struct DependencyGroupSettings {
 pub struct ToolUvWorkspace {
     /// Packages to include as workspace members.
     ///
-    /// Supports both globs and explicit paths.
+    /// Supports both globs and explicit paths. If a member is itself a workspace root, its members
+    /// are included too.
     ///
     /// For more information on the glob syntax, refer to the [`glob` documentation](https://docs.rs/glob/latest/glob/struct.Pattern.html).
     #[option(
@@ -781,6 +942,41 @@ pub struct ToolUvWorkspace {
         "#
     )]
     pub exclude: Option<Vec<SerdePattern>>,
+    /// Tolerate workspace members that are missing from the checkout, as in a sparse or partial
+    /// checkout of a large monorepo.
+    ///
+    /// When enabled, member directories that don't contain a `pyproject.toml` are skipped rather
+    /// than rejected, and any members recorded in the lockfile whose source tree is missing are
+    /// retained in the lockfile, but omitted when installing the workspace. Operations that require
+    /// a missing member (e.g., syncing a member that depends on it) will continue to fail.
+    ///
+    /// If the lockfile needs to be updated while members are missing, uv will error rather than
+    /// dropping the missing members from the lockfile.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            sparse = true
+        "#
+    )]
+    pub sparse: Option<bool>,
+    /// Use a separate virtual environment for each workspace member.
+    ///
+    /// When enabled, the environment of a member is created in the `.venv` directory of the
+    /// member, rather than the workspace root, and contains only the member and its transitive
+    /// dependencies, e.g., `uv sync --package foo` and `uv run --package foo` use
+    /// `<foo>/.venv`. All members continue to share a single lockfile.
+    ///
+    /// Commands that don't target a specific member, e.g., in a virtual workspace root, use the
+    /// `.venv` directory of the workspace root.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            member-environments = true
+        "#
+    )]
+    pub member_environments: Option<bool>,
 }
 
 /// (De)serialize globs as strings.
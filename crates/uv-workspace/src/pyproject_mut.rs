@@ -13,7 +13,7 @@ use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
 use uv_normalize::GroupName;
-use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{Operator, Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{ExtraName, MarkerTree, PackageName, Requirement, VersionOrUrl};
 use uv_redacted::DisplaySafeUrl;
 
@@ -70,6 +70,8 @@ enum CommentType {
     OwnLine,
     /// A comment that appears at the end of a line.
     EndOfLine,
+    /// A blank line, e.g., separating groups of entries.
+    Blank,
 }
 
 #[derive(Debug, Clone)]
@@ -833,9 +835,6 @@ impl PyProjectTomlMut {
             .as_table_mut()
             .ok_or(Error::MalformedSources)?;
 
-        if let Some(key) = find_source(name, sources) {
-            sources.remove(&key);
-        }
         add_source(name, source, sources)?;
 
         Ok(())
@@ -1108,6 +1107,70 @@ impl PyProjectTomlMut {
         types
     }
 
+    /// Replace the version of a package in the specifiers of every requirement on that package,
+    /// e.g., to update `child==0.1.0` to `child==0.2.0` after bumping the version of `child`.
+    ///
+    /// Only specifiers that pin the old version (`==`, `===`, `>=`, and `~=`) are updated; other
+    /// specifiers, like upper bounds, are left as-is.
+    ///
+    /// Returns the updated requirements.
+    pub fn replace_dependency_version(
+        &mut self,
+        name: &PackageName,
+        old: &Version,
+        new: &Version,
+    ) -> Vec<Requirement> {
+        let mut updated = Vec::new();
+
+        if let Some(project) = self.doc.get_mut("project").and_then(Item::as_table_mut) {
+            // Update `project.dependencies`.
+            if let Some(dependencies) = project.get_mut("dependencies").and_then(Item::as_array_mut)
+            {
+                updated.extend(replace_dependency_version(name, old, new, dependencies));
+            }
+
+            // Update `project.optional-dependencies`.
+            if let Some(extras) = project
+                .get_mut("optional-dependencies")
+                .and_then(Item::as_table_like_mut)
+            {
+                for (_, dependencies) in extras.iter_mut() {
+                    if let Some(dependencies) = dependencies.as_array_mut() {
+                        updated.extend(replace_dependency_version(name, old, new, dependencies));
+                    }
+                }
+            }
+        }
+
+        // Update `dependency-groups`.
+        if let Some(groups) = self
+            .doc
+            .get_mut("dependency-groups")
+            .and_then(Item::as_table_like_mut)
+        {
+            for (_, dependencies) in groups.iter_mut() {
+                if let Some(dependencies) = dependencies.as_array_mut() {
+                    updated.extend(replace_dependency_version(name, old, new, dependencies));
+                }
+            }
+        }
+
+        // Update `tool.uv.dev-dependencies`.
+        if let Some(dev_dependencies) = self
+            .doc
+            .get_mut("tool")
+            .and_then(Item::as_table_mut)
+            .and_then(|tool| tool.get_mut("uv"))
+            .and_then(Item::as_table_mut)
+            .and_then(|uv| uv.get_mut("dev-dependencies"))
+            .and_then(Item::as_array_mut)
+        {
+            updated.extend(replace_dependency_version(name, old, new, dev_dependencies));
+        }
+
+        updated
+    }
+
     pub fn version(&mut self) -> Result<Version, Error> {
         let version = self
             .doc
@@ -1140,10 +1203,13 @@ impl PyProjectTomlMut {
             .get_mut("project")
             .and_then(Item::as_table_mut)
             .ok_or(Error::MalformedWorkspace)?;
-        project.insert(
-            "version",
-            Item::Value(Value::String(Formatted::new(version.to_string()))),
-        );
+        let mut value = Value::String(Formatted::new(version.to_string()));
+
+        // Retain any comments around the existing version.
+        if let Some(existing) = project.get("version").and_then(Item::as_value) {
+            *value.decor_mut() = existing.decor().clone();
+        }
+        project.insert("version", Item::Value(value));
 
         Ok(())
     }
@@ -1167,6 +1233,23 @@ pub fn add_dependency(
 ) -> Result<ArrayEdit, Error> {
     let mut to_replace = find_dependencies(&req.name, Some(&req.marker), deps);
 
+    // If the requirement is conditional, but the package is already required unconditionally (and
+    // the requirement doesn't change its version), restrict the existing entry to the marker rather
+    // than adding a redundant conditional entry alongside it.
+    if to_replace.is_empty() && !req.marker.is_true() {
+        let existing = find_dependencies(&req.name, None, deps);
+        if let [(_, existing_req)] = existing.as_slice() {
+            let same_version = match &req.version_or_url {
+                None => true,
+                Some(VersionOrUrl::VersionSpecifier(specifier)) if specifier.is_empty() => true,
+                version_or_url => *version_or_url == existing_req.version_or_url,
+            };
+            if existing_req.marker.is_true() && same_version {
+                to_replace = existing;
+            }
+        }
+    }
+
     match to_replace.as_slice() {
         [] => {
             #[derive(Debug, Copy, Clone)]
@@ -1451,6 +1534,53 @@ fn update_requirement(old: &mut Requirement, new: &Requirement, has_source: bool
     }
 }
 
+/// Replace the version of a package in the specifiers of every requirement on that package in the
+/// given `deps` array.
+fn replace_dependency_version(
+    name: &PackageName,
+    old: &Version,
+    new: &Version,
+    deps: &mut Array,
+) -> Vec<Requirement> {
+    // Returns the updated specifier, if the specifier pins the old version.
+    let replace = |specifier: &VersionSpecifier| {
+        if specifier.version() != old {
+            return None;
+        }
+        match specifier.operator() {
+            Operator::Equal
+            | Operator::ExactEqual
+            | Operator::GreaterThanEqual
+            | Operator::TildeEqual => {
+                VersionSpecifier::from_version(*specifier.operator(), new.clone()).ok()
+            }
+            _ => None,
+        }
+    };
+
+    let mut updated = Vec::new();
+    for (i, mut req) in find_dependencies(name, None, deps) {
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &req.version_or_url else {
+            continue;
+        };
+        if !specifiers
+            .iter()
+            .any(|specifier| replace(specifier).is_some())
+        {
+            continue;
+        }
+
+        let specifiers = specifiers
+            .iter()
+            .map(|specifier| replace(specifier).unwrap_or_else(|| specifier.clone()))
+            .collect::<VersionSpecifiers>();
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+        deps.replace(i, req.to_string());
+        updated.push(req);
+    }
+    updated
+}
+
 /// Removes all occurrences of dependencies with the given name from the given `deps` array.
 fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
     // Remove matching dependencies.
@@ -1465,12 +1595,28 @@ fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
         .collect::<Vec<_>>();
 
     if !removed.is_empty() {
-        reformat_array_multiline(deps);
+        if is_multiline(deps) {
+            reformat_array_multiline(deps);
+        } else {
+            deps.fmt();
+        }
     }
 
     removed
 }
 
+/// Returns `true` if the TOML array spans multiple lines.
+fn is_multiline(deps: &Array) -> bool {
+    let has_newline = |raw: Option<&RawString>| {
+        raw.and_then(RawString::as_str)
+            .is_some_and(|raw| raw.contains(['\r', '\n']))
+    };
+    has_newline(Some(deps.trailing()))
+        || deps
+            .iter()
+            .any(|item| has_newline(item.decor().prefix()) || has_newline(item.decor().suffix()))
+}
+
 /// Returns a `Vec` containing the all dependencies with the given name, along with their positions
 /// in the array.
 fn find_dependencies(
@@ -1499,7 +1645,7 @@ fn find_source(name: &PackageName, sources: &Table) -> Option<String> {
     None
 }
 
-// Add a source to `tool.uv.sources`.
+// Add a source to `tool.uv.sources`, replacing any existing source for the package in place.
 fn add_source(req: &PackageName, source: &Source, sources: &mut Table) -> Result<(), Error> {
     // Serialize as an inline table.
     let mut doc = toml::to_string(&source)
@@ -1508,7 +1654,30 @@ fn add_source(req: &PackageName, source: &Source, sources: &mut Table) -> Result
         .unwrap();
     let table = mem::take(doc.as_table_mut()).into_inline_table();
 
-    sources.insert(req.as_ref(), Item::Value(Value::InlineTable(table)));
+    let Some(key) = find_source(req, sources) else {
+        sources.insert(req.as_ref(), Item::Value(Value::InlineTable(table)));
+        return Ok(());
+    };
+
+    // Retain the position, style, and comments of the existing source, e.g., a
+    // `[tool.uv.sources.foo]` table, or an inline table with a trailing comment.
+    let existing = sources.get_mut(&key).ok_or(Error::MalformedSources)?;
+    match existing {
+        Item::Table(existing) => {
+            existing.clear();
+            for (key, value) in table.into_table() {
+                existing.insert(&key, value);
+            }
+        }
+        Item::Value(existing) => {
+            let decor = existing.decor().clone();
+            *existing = Value::InlineTable(table);
+            *existing.decor_mut() = decor;
+        }
+        existing => {
+            *existing = Item::Value(Value::InlineTable(table));
+        }
+    }
 
     Ok(())
 }
@@ -1527,13 +1696,18 @@ fn try_parse_requirement(req: &str) -> Option<Requirement> {
 /// and move them around. This also formats the array to have a trailing comma.
 fn reformat_array_multiline(deps: &mut Array) {
     fn find_comments(s: Option<&RawString>) -> Box<dyn Iterator<Item = Comment> + '_> {
-        let iter = s
+        let lines = s
             .and_then(|x| x.as_str())
             .unwrap_or("")
             .lines()
+            .collect::<Vec<_>>();
+        let last = lines.len().saturating_sub(1);
+        let iter = lines
+            .into_iter()
+            .enumerate()
             .scan(
                 (false, false),
-                |(prev_line_was_empty, prev_line_was_comment), line| {
+                move |(prev_line_was_empty, prev_line_was_comment), (i, line)| {
                     let trimmed_line = line.trim();
                     if let Some(index) = trimmed_line.find('#') {
                         let comment_text = trimmed_line[index..].trim().to_string();
@@ -1549,9 +1723,15 @@ fn reformat_array_multiline(deps: &mut Array) {
                             comment_type,
                         }))
                     } else {
+                        // Retain blank lines, but not the remainder of the previous line or the
+                        // indentation of the next entry.
+                        let blank_line = trimmed_line.is_empty() && i > 0 && i < last;
                         *prev_line_was_empty = trimmed_line.is_empty();
                         *prev_line_was_comment = false;
-                        Some(None)
+                        Some(blank_line.then(|| Comment {
+                            text: String::new(),
+                            comment_type: CommentType::Blank,
+                        }))
                     }
                 },
             )
@@ -1593,6 +1773,9 @@ fn reformat_array_multiline(deps: &mut Array) {
                 CommentType::EndOfLine => {
                     prefix.push(' ');
                 }
+                CommentType::Blank => {
+                    prefix.push('\n');
+                }
             }
             prefix.push_str(&comment.text);
         }
@@ -1615,6 +1798,9 @@ fn reformat_array_multiline(deps: &mut Array) {
                     CommentType::EndOfLine => {
                         rv.push(' ');
                     }
+                    CommentType::Blank => {
+                        rv.push('\n');
+                    }
                 }
                 rv.push_str(&comment.text);
             }
@@ -1,6 +1,6 @@
 //! Resolve the current [`ProjectWorkspace`] or [`Workspace`].
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
@@ -54,8 +54,6 @@ pub enum WorkspaceError {
     MissingWorkspace(PathBuf),
     #[error("The project is marked as unmanaged: `{}`", _0.simplified_display())]
     NonWorkspace(PathBuf),
-    #[error("Nested workspaces are not supported, but workspace member (`{}`) has a `uv.workspace` table", _0.simplified_display())]
-    NestedWorkspace(PathBuf),
     #[error("Two workspace members are both named: `{name}`: `{}` and `{}`", first.simplified_display(), second.simplified_display())]
     DuplicatePackage {
         name: PackageName,
@@ -121,6 +119,9 @@ pub struct Workspace {
     indexes: Vec<Index>,
     /// The `pyproject.toml` of the workspace root.
     pyproject_toml: PyProjectToml,
+    /// The root of the current member, if each member uses its own environment.
+    #[cfg_attr(test, serde(skip))]
+    member_environment: Option<PathBuf>,
 }
 
 impl Workspace {
@@ -130,7 +131,8 @@ impl Workspace {
     /// always uses absolute path, i.e., this method only supports discovering the main workspace.
     ///
     /// Steps of workspace discovery: Start by looking at the closest `pyproject.toml`:
-    /// * If it's an explicit workspace root: Collect workspace from this root, we're done.
+    /// * If it's an explicit workspace root: Collect workspace from this root, or from the root of
+    ///   the workspace it's nested in, we're done.
     /// * If it's also not a project: Error, must be either a workspace root or a project.
     /// * Otherwise, try to find an explicit workspace root above:
     ///   * If an explicit workspace root exists: Collect workspace from this root, we're done.
@@ -196,8 +198,12 @@ impl Workspace {
 
         let (workspace_root, workspace_definition, workspace_pyproject_toml) =
             if let Some(workspace) = explicit_root {
-                // We have found the explicit root immediately.
-                workspace
+                // We have found the explicit root immediately, unless it's nested in another
+                // workspace.
+                match find_workspace(&project_path, options).await? {
+                    Some(outer) => outer,
+                    None => workspace,
+                }
             } else if pyproject_toml.project.is_none() {
                 // Without a project, it can't be an implicit root
                 return Err(WorkspaceError::MissingProject(pyproject_path));
@@ -245,10 +251,15 @@ impl Workspace {
     /// Returns `None` if the package is not part of the workspace.
     pub fn with_current_project(self, package_name: PackageName) -> Option<ProjectWorkspace> {
         let member = self.packages.get(&package_name)?;
+        let project_root = member.root().clone();
+        let member_environment = self.has_member_environments().then(|| project_root.clone());
         Some(ProjectWorkspace {
-            project_root: member.root().clone(),
+            project_root,
             project_name: package_name,
-            workspace: self,
+            workspace: Self {
+                member_environment,
+                ..self
+            },
         })
     }
 
@@ -312,6 +323,17 @@ impl Workspace {
         }
     }
 
+    /// Returns `true` if each workspace member uses its own virtual environment.
+    pub fn has_member_environments(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+            .and_then(|workspace| workspace.member_environments)
+            .unwrap_or(false)
+    }
+
     /// Returns `true` if the workspace has a (legacy) non-project root.
     pub fn is_non_project(&self) -> bool {
         !self
@@ -320,6 +342,18 @@ impl Workspace {
             .any(|member| *member.root() == self.install_path)
     }
 
+    /// Returns `true` if the workspace tolerates members that are missing from the checkout, i.e.,
+    /// `tool.uv.workspace.sparse` is enabled.
+    pub fn is_sparse(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+            .and_then(|workspace| workspace.sparse)
+            .unwrap_or(false)
+    }
+
     /// Returns the set of all workspace members.
     pub fn members_requirements(&self) -> impl Iterator<Item = Requirement> + '_ {
         self.packages.iter().filter_map(|(name, member)| {
@@ -518,8 +552,11 @@ impl Workspace {
 
             // Get the requires-python for each enabled group on this package
             // We need to do full flattening here because include-group can transfer requires-python
-            let dependency_groups =
-                FlatDependencyGroups::from_pyproject_toml(member.root(), &member.pyproject_toml)?;
+            let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
+                member.root(),
+                &member.pyproject_toml,
+                self,
+            )?;
             let group_requires =
                 dependency_groups
                     .into_iter()
@@ -568,6 +605,7 @@ impl Workspace {
             let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
                 &self.install_path,
                 &self.pyproject_toml,
+                self,
             )?;
             Ok(dependency_groups.into_inner())
         }
@@ -601,6 +639,33 @@ impl Workspace {
         constraints.clone()
     }
 
+    /// Returns the constraints files for the workspace, with any paths resolved relative to the
+    /// workspace root.
+    pub fn constraint_files(&self) -> Vec<PathBuf> {
+        let Some(constraint_files) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.constraint_files.as_ref())
+        else {
+            return vec![];
+        };
+        constraint_files
+            .iter()
+            .map(|file| {
+                if file
+                    .to_str()
+                    .is_some_and(|file| file.starts_with("http://") || file.starts_with("https://"))
+                {
+                    file.clone()
+                } else {
+                    self.install_path.join(file)
+                }
+            })
+            .collect()
+    }
+
     /// Returns the set of build constraints for the workspace.
     pub fn build_constraints(&self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
         let Some(build_constraints) = self
@@ -623,7 +688,8 @@ impl Workspace {
 
     /// The path to the workspace virtual environment.
     ///
-    /// Uses `.venv` in the install path directory by default.
+    /// Uses `.venv` in the install path directory by default, or in the directory of the current
+    /// member if the workspace uses per-member environments.
     ///
     /// If `UV_PROJECT_ENVIRONMENT` is set, it will take precedence. If a relative path is provided,
     /// it is resolved relative to the install path.
@@ -668,8 +734,12 @@ impl Workspace {
         }
 
         // Determine the default value
-        let project_env = from_project_environment_variable(self)
-            .unwrap_or_else(|| self.install_path.join(".venv"));
+        let project_env = from_project_environment_variable(self).unwrap_or_else(|| {
+            self.member_environment
+                .as_ref()
+                .unwrap_or(&self.install_path)
+                .join(".venv")
+        });
 
         // Warn if it conflicts with `VIRTUAL_ENV`
         if let Some(from_virtual_env) = from_virtual_env_variable() {
@@ -799,6 +869,12 @@ impl Workspace {
             Arc::new(workspace_members)
         };
 
+        // If each member uses its own environment, use the environment of the current project.
+        let member_environment = current_project
+            .as_ref()
+            .filter(|_| workspace_definition.member_environments == Some(true))
+            .map(|member| member.root.clone());
+
         // For the cases such as `MemberDiscovery::None`, add the current project if missing.
         if let Some(root_member) = current_project {
             if !workspace_members.contains_key(&root_member.project.name) {
@@ -840,6 +916,7 @@ impl Workspace {
             sources: workspace_sources,
             indexes: workspace_indexes,
             pyproject_toml: workspace_pyproject_toml,
+            member_environment,
         })
     }
 
@@ -877,150 +954,180 @@ impl Workspace {
             );
         }
 
-        // Add all other workspace members.
-        for member_glob in workspace_definition.clone().members.unwrap_or_default() {
-            let absolute_glob = PathBuf::from(glob::Pattern::escape(
-                workspace_root.simplified().to_string_lossy().as_ref(),
-            ))
-            .join(member_glob.as_str())
-            .to_string_lossy()
-            .to_string();
-            for member_root in glob(&absolute_glob)
-                .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?
-            {
-                let member_root = member_root
-                    .map_err(|err| WorkspaceError::GlobWalk(absolute_glob.to_string(), err))?;
-                if !seen.insert(member_root.clone()) {
-                    continue;
-                }
-                let member_root = std::path::absolute(&member_root)
-                    .map_err(WorkspaceError::Normalize)?
-                    .clone();
-
-                // If the directory is explicitly ignored, skip it.
-                let skip = match &options.members {
-                    MemberDiscovery::All => false,
-                    MemberDiscovery::None => true,
-                    MemberDiscovery::Ignore(ignore) => ignore.contains(member_root.as_path()),
-                };
-                if skip {
-                    debug!(
-                        "Ignoring workspace member: `{}`",
-                        member_root.simplified_display()
-                    );
-                    continue;
-                }
-
-                // If the member is excluded, ignore it.
-                if is_excluded_from_workspace(&member_root, workspace_root, workspace_definition)? {
-                    debug!(
-                        "Ignoring workspace member: `{}`",
-                        member_root.simplified_display()
-                    );
-                    continue;
-                }
+        // Add all other workspace members. If a member is itself a workspace root, e.g., a vendored
+        // repository, its members are added to the (outermost) workspace too.
+        let mut workspaces =
+            VecDeque::from([(workspace_root.clone(), workspace_definition.clone())]);
+        while let Some((nested_root, nested_definition)) = workspaces.pop_front() {
+            for member_glob in nested_definition.members.clone().unwrap_or_default() {
+                let absolute_glob = PathBuf::from(glob::Pattern::escape(
+                    nested_root.simplified().to_string_lossy().as_ref(),
+                ))
+                .join(member_glob.as_str())
+                .to_string_lossy()
+                .to_string();
+                for member_root in glob(&absolute_glob)
+                    .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?
+                {
+                    let member_root = member_root
+                        .map_err(|err| WorkspaceError::GlobWalk(absolute_glob.to_string(), err))?;
+                    if !seen.insert(member_root.clone()) {
+                        continue;
+                    }
+                    let member_root = std::path::absolute(&member_root)
+                        .map_err(WorkspaceError::Normalize)?
+                        .clone();
+
+                    // If the directory is explicitly ignored, skip it.
+                    let skip = match &options.members {
+                        MemberDiscovery::All => false,
+                        MemberDiscovery::None => true,
+                        MemberDiscovery::Ignore(ignore) => ignore.contains(member_root.as_path()),
+                    };
+                    if skip {
+                        debug!(
+                            "Ignoring workspace member: `{}`",
+                            member_root.simplified_display()
+                        );
+                        continue;
+                    }
 
-                trace!(
-                    "Processing workspace member: `{}`",
-                    member_root.user_display()
-                );
+                    // If the member is excluded, by the outermost workspace or by the workspace that
+                    // declares it, ignore it.
+                    if is_excluded_from_workspace(
+                        &member_root,
+                        workspace_root,
+                        workspace_definition,
+                    )? || is_excluded_from_workspace(
+                        &member_root,
+                        &nested_root,
+                        &nested_definition,
+                    )? {
+                        debug!(
+                            "Ignoring workspace member: `{}`",
+                            member_root.simplified_display()
+                        );
+                        continue;
+                    }
 
-                // Read the member `pyproject.toml`.
-                let pyproject_path = member_root.join("pyproject.toml");
-                let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
-                    Ok(contents) => contents,
-                    Err(err) => {
-                        if !fs_err::metadata(&member_root)?.is_dir() {
-                            warn!(
-                                "Ignoring non-directory workspace member: `{}`",
-                                member_root.simplified_display()
-                            );
-                            continue;
-                        }
+                    trace!(
+                        "Processing workspace member: `{}`",
+                        member_root.user_display()
+                    );
 
-                        // A directory exists, but it doesn't contain a `pyproject.toml`.
-                        if err.kind() == std::io::ErrorKind::NotFound {
-                            // If the directory is hidden, skip it.
-                            if member_root
-                                .file_name()
-                                .map(|name| name.as_encoded_bytes().starts_with(b"."))
-                                .unwrap_or(false)
-                            {
-                                debug!(
-                                    "Ignoring hidden workspace member: `{}`",
+                    // Read the member `pyproject.toml`.
+                    let pyproject_path = member_root.join("pyproject.toml");
+                    let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
+                        Ok(contents) => contents,
+                        Err(err) => {
+                            if !fs_err::metadata(&member_root)?.is_dir() {
+                                warn!(
+                                    "Ignoring non-directory workspace member: `{}`",
                                     member_root.simplified_display()
                                 );
                                 continue;
                             }
 
-                            return Err(WorkspaceError::MissingPyprojectTomlMember(
-                                member_root,
-                                member_glob.to_string(),
-                            ));
+                            // A directory exists, but it doesn't contain a `pyproject.toml`.
+                            if err.kind() == std::io::ErrorKind::NotFound {
+                                // If the directory is hidden, skip it.
+                                if member_root
+                                    .file_name()
+                                    .map(|name| name.as_encoded_bytes().starts_with(b"."))
+                                    .unwrap_or(false)
+                                {
+                                    debug!(
+                                        "Ignoring hidden workspace member: `{}`",
+                                        member_root.simplified_display()
+                                    );
+                                    continue;
+                                }
+
+                                // If the workspace tolerates a partial checkout, skip it.
+                                if workspace_definition.sparse == Some(true)
+                                    || nested_definition.sparse == Some(true)
+                                {
+                                    debug!(
+                                        "Ignoring workspace member missing from sparse checkout: `{}`",
+                                        member_root.simplified_display()
+                                    );
+                                    continue;
+                                }
+
+                                return Err(WorkspaceError::MissingPyprojectTomlMember(
+                                    member_root,
+                                    member_glob.to_string(),
+                                ));
+                            }
+
+                            return Err(err.into());
                         }
+                    };
+                    let pyproject_toml = PyProjectToml::from_string(contents).map_err(|err| {
+                        WorkspaceError::Toml(pyproject_path.clone(), Box::new(err))
+                    })?;
+
+                    // Check if the current project is explicitly marked as unmanaged.
+                    if pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.managed)
+                        == Some(false)
+                    {
+                        debug!(
+                            "Project `{}` is marked as unmanaged; omitting from workspace members",
+                            pyproject_toml.project.as_ref().unwrap().name
+                        );
+                        continue;
+                    }
 
-                        return Err(err.into());
+                    // If the member is a workspace root, collect its members too.
+                    if let Some(definition) = pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.workspace.as_ref())
+                    {
+                        debug!(
+                            "Adding nested workspace: `{}`",
+                            member_root.simplified_display()
+                        );
+                        workspaces.push_back((member_root.clone(), definition.clone()));
+
+                        // A nested virtual workspace root isn't a member itself.
+                        if pyproject_toml.project.is_none() {
+                            continue;
+                        }
                     }
-                };
-                let pyproject_toml = PyProjectToml::from_string(contents)
-                    .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
-                // Check if the current project is explicitly marked as unmanaged.
-                if pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.managed)
-                    == Some(false)
-                {
+                    // Extract the package name.
+                    let Some(project) = pyproject_toml.project.clone() else {
+                        return Err(WorkspaceError::MissingProject(pyproject_path));
+                    };
+
                     debug!(
-                        "Project `{}` is marked as unmanaged; omitting from workspace members",
-                        pyproject_toml.project.as_ref().unwrap().name
+                        "Adding discovered workspace member: `{}`",
+                        member_root.simplified_display()
                     );
-                    continue;
-                }
 
-                // Extract the package name.
-                let Some(project) = pyproject_toml.project.clone() else {
-                    return Err(WorkspaceError::MissingProject(pyproject_path));
-                };
-
-                debug!(
-                    "Adding discovered workspace member: `{}`",
-                    member_root.simplified_display()
-                );
-
-                if let Some(existing) = workspace_members.insert(
-                    project.name.clone(),
-                    WorkspaceMember {
-                        root: member_root.clone(),
-                        project,
-                        pyproject_toml,
-                    },
-                ) {
-                    return Err(WorkspaceError::DuplicatePackage {
-                        name: existing.project.name,
-                        first: existing.root.clone(),
-                        second: member_root,
-                    });
+                    if let Some(existing) = workspace_members.insert(
+                        project.name.clone(),
+                        WorkspaceMember {
+                            root: member_root.clone(),
+                            project,
+                            pyproject_toml,
+                        },
+                    ) {
+                        return Err(WorkspaceError::DuplicatePackage {
+                            name: existing.project.name,
+                            first: existing.root.clone(),
+                            second: member_root,
+                        });
+                    }
                 }
             }
         }
-
-        // Test for nested workspaces.
-        for member in workspace_members.values() {
-            if member.root() != workspace_root
-                && member
-                    .pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.workspace.as_ref())
-                    .is_some()
-            {
-                return Err(WorkspaceError::NestedWorkspace(member.root.clone()));
-            }
-        }
         Ok(workspace_members)
     }
 }
@@ -1298,10 +1405,10 @@ impl ProjectWorkspace {
                 )
             });
 
-        if workspace.is_none() {
-            // The project isn't an explicit workspace root, check if we're a regular workspace
-            // member by looking for an explicit workspace root above.
-            workspace = find_workspace(&project_path, options).await?;
+        // Check if we're a regular workspace member, or the root of a nested workspace, by
+        // looking for an explicit workspace root above.
+        if let Some(outer) = find_workspace(&project_path, options).await? {
+            workspace = Some(outer);
         }
 
         let current_project = WorkspaceMember {
@@ -1339,6 +1446,7 @@ impl ProjectWorkspace {
                     sources: workspace_sources,
                     indexes: Vec::default(),
                     pyproject_toml: project_pyproject_toml.clone(),
+                    member_environment: None,
                 },
             });
         };
@@ -1421,6 +1529,17 @@ async fn find_workspace(
                 return Ok(None);
             }
 
+            // If the workspace root is a member of a workspace above it, the outermost workspace
+            // owns the project.
+            if let Some(outer) = Box::pin(find_workspace(workspace_root, options)).await? {
+                debug!(
+                    "Workspace root `{}` is nested in: `{}`",
+                    workspace_root.simplified_display(),
+                    outer.0.simplified_display()
+                );
+                return Ok(Some(outer));
+            }
+
             // We found a workspace root.
             Ok(Some((
                 workspace_root.to_path_buf(),
@@ -1605,15 +1724,21 @@ impl VirtualProject {
             .and_then(|uv| uv.workspace.as_ref())
         {
             // Otherwise, if it contains a `tool.uv.workspace` table, it's a non-project workspace
-            // root.
+            // root, unless it's nested in another workspace.
             let project_path = std::path::absolute(project_root)
                 .map_err(WorkspaceError::Normalize)?
                 .clone();
 
+            let (workspace_root, workspace_definition, workspace_pyproject_toml) =
+                match find_workspace(&project_path, options).await? {
+                    Some(outer) => outer,
+                    None => (project_path, workspace.clone(), pyproject_toml),
+                };
+
             let workspace = Workspace::collect_members(
-                project_path,
-                workspace.clone(),
-                pyproject_toml,
+                workspace_root,
+                workspace_definition,
+                workspace_pyproject_toml,
                 None,
                 options,
                 cache,
@@ -1964,7 +2089,9 @@ mod tests {
                         "members": [
                           "packages/*"
                         ],
-                        "exclude": null
+                        "exclude": null,
+                        "sparse": null,
+                        "member-environments": null
                       },
                       "managed": null,
                       "package": null,
@@ -1973,10 +2100,12 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
+                      "constraint-files": null,
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2064,7 +2193,9 @@ mod tests {
                         "members": [
                           "packages/*"
                         ],
-                        "exclude": null
+                        "exclude": null,
+                        "sparse": null,
+                        "member-environments": null
                       },
                       "managed": null,
                       "package": null,
@@ -2073,10 +2204,12 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
+                      "constraint-files": null,
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2277,7 +2410,9 @@ mod tests {
                         ],
                         "exclude": [
                           "packages/bird-feeder"
-                        ]
+                        ],
+                        "sparse": null,
+                        "member-environments": null
                       },
                       "managed": null,
                       "package": null,
@@ -2286,10 +2421,12 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
+                      "constraint-files": null,
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2386,7 +2523,9 @@ mod tests {
                         ],
                         "exclude": [
                           "packages/bird-feeder"
-                        ]
+                        ],
+                        "sparse": null,
+                        "member-environments": null
                       },
                       "managed": null,
                       "package": null,
@@ -2395,10 +2534,12 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
+                      "constraint-files": null,
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2508,7 +2649,9 @@ mod tests {
                         ],
                         "exclude": [
                           "packages"
-                        ]
+                        ],
+                        "sparse": null,
+                        "member-environments": null
                       },
                       "managed": null,
                       "package": null,
@@ -2517,10 +2660,12 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
+                      "constraint-files": null,
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2604,7 +2749,9 @@ mod tests {
                         ],
                         "exclude": [
                           "packages/*"
-                        ]
+                        ],
+                        "sparse": null,
+                        "member-environments": null
                       },
                       "managed": null,
                       "package": null,
@@ -2613,10 +2760,12 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
+                      "constraint-files": null,
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2653,6 +2802,7 @@ bar = ["b"]
                 DependencyGroupSpecifier::Requirement("a".to_string()),
                 DependencyGroupSpecifier::IncludeGroup {
                     include_group: GroupName::from_str("bar").unwrap(),
+                    package: None,
                 }
             ]
         );
@@ -2702,13 +2852,42 @@ bar = ["b"]
             "#,
             )?;
 
-        let (error, root_escaped) = temporary_test(root.as_ref()).await.unwrap_err();
-        let filters = vec![(root_escaped.as_str(), "[ROOT]")];
-        insta::with_settings!({filters => filters}, {
-            assert_snapshot!(
-                error,
-            @"Nested workspaces are not supported, but workspace member (`[ROOT]/packages/seeds`) has a `uv.workspace` table");
-        });
+        // Create a member of the nested workspace (`sprout`).
+        root.child("packages")
+            .child("seeds")
+            .child("nested_packages")
+            .child("sprout")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "sprout"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#,
+            )?;
+
+        // The members of the nested workspace are members of the outer workspace.
+        let (project, _) = temporary_test(root.as_ref()).await.unwrap();
+        assert_eq!(project.workspace().install_path(), root.path());
+        assert_eq!(
+            project
+                .workspace()
+                .packages()
+                .keys()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["albatross", "seeds", "sprout"]
+        );
+
+        // The outermost workspace root owns the members of the nested workspace, including its
+        // root.
+        for member in ["packages/seeds", "packages/seeds/nested_packages/sprout"] {
+            let (project, _) = temporary_test(&root.path().join(member)).await.unwrap();
+            assert_eq!(project.workspace().install_path(), root.path());
+            assert_eq!(project.workspace().packages().len(), 3);
+        }
 
         Ok(())
     }
@@ -3,41 +3,52 @@ use std::fmt::Write as _;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Instant;
 use std::{fmt, io};
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 use thiserror::Error;
 use tracing::instrument;
 
-use uv_build_backend::check_direct_build;
+use uv_build_backend::{check_direct_build, check_direct_list, project_name_and_version};
+use uv_build_frontend::SourceBuild;
 use uv_cache::{Cache, CacheBucket};
+use uv_cli::BuildFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings, Constraints,
     DependencyGroupsWithDefaults, HashCheckingMode, IndexStrategy, KeyringProviderType,
-    PackageConfigSettings, Preview, SourceStrategy,
+    PackageConfigSettings, Preview, PreviewFeatures, SourceStrategy, TargetTriple,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_filename::{
     DistFilename, SourceDistExtension, SourceDistFilename, WheelFilename,
 };
 use uv_distribution_types::{
-    DependencyMetadata, Index, IndexLocations, RequiresPython, SourceDist,
+    BuildSystemOverrides, DependencyMetadata, Index, IndexLocations, RequiresPython, SourceDist,
 };
-use uv_fs::{Simplified, relative_to};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{PortablePathBuf, Simplified, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
     VersionRequest,
 };
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy};
+use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::ExitStatus;
@@ -90,8 +101,16 @@ enum Error {
     InvalidBuiltWheelFilename(#[source] uv_distribution_filename::WheelFilenameError),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error("The built wheel `{0}` is not compatible with the target platform")]
+    IncompatibleWheel(WheelFilename),
+    #[error(transparent)]
+    Tags(#[from] uv_platform_tags::TagsError),
 }
 
+/// The `SOURCE_DATE_EPOCH` for `--reproducible` builds, 1980-01-01, the earliest timestamp that can
+/// be represented in a zip archive.
+const REPRODUCIBLE_SOURCE_DATE_EPOCH: u64 = 315_532_800;
+
 /// Build source distributions and wheels.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn build_frontend(
@@ -103,11 +122,15 @@ pub(crate) async fn build_frontend(
     sdist: bool,
     wheel: bool,
     list: bool,
+    output_format: BuildFormat,
     build_logs: bool,
+    log_dir: Option<PathBuf>,
     force_pep517: bool,
+    reproducible: bool,
     build_constraints: Vec<RequirementsSource>,
     hash_checking: Option<HashCheckingMode>,
     python: Option<String>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
@@ -119,6 +142,15 @@ pub(crate) async fn build_frontend(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::JSON_OUTPUT)
+        && matches!(output_format, BuildFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::JSON_OUTPUT
+        );
+    }
+
     let build_result = build_impl(
         project_dir,
         src.as_deref(),
@@ -128,11 +160,15 @@ pub(crate) async fn build_frontend(
         sdist,
         wheel,
         list,
+        output_format,
         build_logs,
+        log_dir.as_deref(),
         force_pep517,
+        reproducible,
         &build_constraints,
         hash_checking,
         python.as_deref(),
+        python_platform,
         install_mirrors,
         settings,
         network_settings,
@@ -171,11 +207,15 @@ async fn build_impl(
     sdist: bool,
     wheel: bool,
     list: bool,
+    output_format: BuildFormat,
     build_logs: bool,
+    log_dir: Option<&Path>,
     force_pep517: bool,
+    reproducible: bool,
     build_constraints: &[RequirementsSource],
     hash_checking: Option<HashCheckingMode>,
     python_request: Option<&str>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
@@ -196,6 +236,7 @@ async fn build_impl(
         prerelease: _,
         fork_strategy: _,
         dependency_metadata,
+        build_system_overrides,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -208,9 +249,11 @@ async fn build_impl(
     } = settings;
 
     let client_builder = BaseClientBuilder::default()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     // Determine the source to build.
@@ -326,11 +369,21 @@ async fn build_impl(
         vec![AnnotatedSource::from(src)]
     };
 
+    // Create the directory for the build logs.
+    let build_log = if let Some(log_dir) = log_dir {
+        let log_dir = std::path::absolute(log_dir)?;
+        fs_err::tokio::create_dir_all(&log_dir).await?;
+        Some(BuildLog::new(log_dir))
+    } else {
+        None
+    };
+
     let results: Vec<_> = futures::future::join_all(packages.into_iter().map(|source| {
         let future = build_package(
             source.clone(),
             output_dir,
             python_request,
+            python_platform,
             install_mirrors.clone(),
             no_config,
             workspace.as_ref(),
@@ -342,7 +395,9 @@ async fn build_impl(
             client_builder.clone(),
             hash_checking,
             build_logs,
+            build_log.as_ref(),
             force_pep517,
+            reproducible,
             build_constraints,
             *no_build_isolation,
             no_build_isolation_package,
@@ -356,6 +411,7 @@ async fn build_impl(
             wheel,
             list,
             dependency_metadata,
+            build_system_overrides,
             *link_mode,
             config_setting,
             config_settings_package,
@@ -369,11 +425,17 @@ async fn build_impl(
     .await;
 
     let mut success = true;
+    let mut artifacts = Vec::new();
     for (source, result) in results {
         match result {
             Ok(messages) => {
                 for message in messages {
                     message.print(printer)?;
+                    if matches!(output_format, BuildFormat::Json) {
+                        if let Some(artifact) = ArtifactReport::from_message(&message).await? {
+                            artifacts.push(artifact);
+                        }
+                    }
                 }
             }
             Err(err) => {
@@ -397,6 +459,19 @@ async fn build_impl(
         }
     }
 
+    if let Some(build_log) = build_log {
+        build_log.write_index().await?;
+    }
+
+    if matches!(output_format, BuildFormat::Json) {
+        let report = BuildReport { artifacts };
+        writeln!(
+            printer.stdout_important(),
+            "{}",
+            serde_json::to_string_pretty(&report)?
+        )?;
+    }
+
     if success {
         Ok(BuildResult::Success)
     } else {
@@ -409,6 +484,7 @@ async fn build_package(
     source: AnnotatedSource<'_>,
     output_dir: Option<&Path>,
     python_request: Option<&str>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     no_config: bool,
     workspace: Result<&Workspace, &WorkspaceError>,
@@ -420,7 +496,9 @@ async fn build_package(
     client_builder: BaseClientBuilder<'_>,
     hash_checking: Option<HashCheckingMode>,
     build_logs: bool,
+    build_log: Option<&BuildLog>,
     force_pep517: bool,
+    reproducible: bool,
     build_constraints: &[RequirementsSource],
     no_build_isolation: bool,
     no_build_isolation_package: &[PackageName],
@@ -434,6 +512,7 @@ async fn build_package(
     wheel: bool,
     list: bool,
     dependency_metadata: &DependencyMetadata,
+    build_system_overrides: &BuildSystemOverrides,
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
@@ -469,7 +548,7 @@ async fn build_package(
     if interpreter_request.is_none() {
         if let Ok(workspace) = workspace {
             let groups = DependencyGroupsWithDefaults::none();
-            interpreter_request = find_requires_python(workspace, &groups)?
+            interpreter_request = find_requires_python(workspace, &groups, preview)?
                 .as_ref()
                 .map(RequiresPython::specifiers)
                 .map(|specifiers| {
@@ -493,16 +572,34 @@ async fn build_package(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors
+            .python_install_require_attestations
+            .unwrap_or_default(),
         preview,
     )
     .await?
     .into_interpreter();
 
+    // Determine the tags of the target platform, to check the built wheels against.
+    let target_tags = python_platform
+        .map(|python_platform| {
+            Tags::from_env(
+                &python_platform.platform(),
+                interpreter.python_tuple(),
+                interpreter.implementation_name(),
+                interpreter.implementation_tuple(),
+                python_platform.manylinux_compatible(),
+                interpreter.gil_disabled(),
+                interpreter.debug_enabled(),
+            )
+        })
+        .transpose()?;
+
     index_locations.cache_index_credentials();
 
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, cache).await?;
 
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
@@ -559,6 +656,23 @@ async fn build_package(
     let state = SharedState::default();
     let workspace_cache = WorkspaceCache::default();
 
+    // Pass the target platform and the archive timestamp to the build backend.
+    let mut build_extra_env_vars = Vec::new();
+    if let Some(python_platform) = python_platform {
+        // Build backends determine the platform tag through `sysconfig.get_platform()`, which
+        // respects `_PYTHON_HOST_PLATFORM` for cross-compilation.
+        build_extra_env_vars.push((
+            EnvVars::_PYTHON_HOST_PLATFORM,
+            python_platform.sysconfig_platform(),
+        ));
+    }
+    if reproducible && std::env::var_os(EnvVars::SOURCE_DATE_EPOCH).is_none() {
+        build_extra_env_vars.push((
+            EnvVars::SOURCE_DATE_EPOCH,
+            REPRODUCIBLE_SOURCE_DATE_EPOCH.to_string(),
+        ));
+    }
+
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
         &client,
@@ -568,6 +682,7 @@ async fn build_package(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.clone(),
         index_strategy,
         config_setting,
@@ -581,7 +696,9 @@ async fn build_package(
         workspace_cache,
         concurrency,
         preview,
-    );
+    )
+    .with_build_extra_env_vars(build_extra_env_vars)
+    .with_build_log_dir(build_log.map(|build_log| build_log.dir.clone()));
 
     prepare_output_directory(&output_dir).await?;
 
@@ -595,13 +712,16 @@ async fn build_package(
             return Err(Error::ListForcePep517);
         }
 
-        if !check_direct_build(source.path(), source.path().user_display()) {
+        if !check_direct_list(source.path(), source.path().user_display()) {
             // TODO(konsti): Provide more context on what mismatched
             return Err(Error::ListNonUv);
         }
 
         BuildAction::List
-    } else if !force_pep517 && check_direct_build(source.path(), source.path().user_display()) {
+    } else if !force_pep517
+        && check_direct_build(source.path(), source.path().user_display())
+        && !has_build_system_override(source.path(), build_system_overrides)
+    {
         BuildAction::DirectBuild
     } else {
         BuildAction::Pep517
@@ -642,6 +762,7 @@ async fn build_package(
                     subdirectory,
                     version_id,
                     build_output,
+                    build_log,
                 )
                 .await?;
                 build_results.push(sdist_list);
@@ -659,6 +780,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
             )
             .await?;
             build_results.push(sdist_build.clone());
@@ -691,7 +813,9 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
                 Some(sdist_build.normalized_filename().version()),
+                target_tags.as_ref(),
             )
             .await?;
             build_results.push(wheel_build);
@@ -710,6 +834,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
             )
             .await?;
             build_results.push(sdist_build);
@@ -728,7 +853,9 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
                 None,
+                target_tags.as_ref(),
             )
             .await?;
             build_results.push(wheel_build);
@@ -747,6 +874,7 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
             )
             .await?;
 
@@ -763,7 +891,9 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
                 Some(sdist_build.normalized_filename().version()),
+                target_tags.as_ref(),
             )
             .await?;
             build_results.push(sdist_build);
@@ -806,7 +936,9 @@ async fn build_package(
                 subdirectory,
                 version_id,
                 build_output,
+                build_log,
                 version.as_ref(),
+                target_tags.as_ref(),
             )
             .await?;
             build_results.push(wheel_build);
@@ -854,6 +986,7 @@ async fn build_sdist(
     subdirectory: Option<&Path>,
     version_id: Option<&str>,
     build_output: BuildOutput,
+    build_log: Option<&BuildLog>,
 ) -> Result<BuildMessage, Error> {
     let build_result = match action {
         BuildAction::List => {
@@ -881,17 +1014,26 @@ async fn build_sdist(
                 )
                 .bold()
             )?;
+            let start = Instant::now();
             let source_tree = source_tree.to_path_buf();
             let output_dir_ = output_dir.to_path_buf();
-            let filename = tokio::task::spawn_blocking(move || {
+            let result = tokio::task::spawn_blocking(move || {
                 uv_build_backend::build_source_dist(
                     &source_tree,
                     &output_dir_,
                     uv_version::version(),
                 )
             })
-            .await??
-            .to_string();
+            .await?;
+            if let Some(build_log) = build_log {
+                build_log.record(BuildLogEntry::new(
+                    source.path(),
+                    ArtifactKind::Sdist,
+                    start,
+                    result.as_ref().map(ToString::to_string),
+                ));
+            }
+            let filename = result?.to_string();
 
             BuildMessage::Build {
                 normalized_filename: DistFilename::SourceDistFilename(
@@ -913,7 +1055,8 @@ async fn build_sdist(
                 )
                 .bold()
             )?;
-            let builder = build_dispatch
+            let start = Instant::now();
+            let builder = match build_dispatch
                 .setup_build(
                     source_tree,
                     subdirectory,
@@ -926,8 +1069,37 @@ async fn build_sdist(
                     BuildStack::default(),
                 )
                 .await
-                .map_err(|err| Error::BuildDispatch(err.into()))?;
-            let filename = builder.build(output_dir).await?;
+            {
+                Ok(builder) => builder,
+                Err(err) => {
+                    let err = Error::BuildDispatch(err.into());
+                    if let Some(build_log) = build_log {
+                        build_log.record(
+                            BuildLogEntry::new(
+                                source.path(),
+                                ArtifactKind::Sdist,
+                                start,
+                                Err(&err),
+                            )
+                            .with_pep517(build_dispatch.interpreter(), None),
+                        );
+                    }
+                    return Err(err);
+                }
+            };
+            let result = builder.build(output_dir).await;
+            if let Some(build_log) = build_log {
+                build_log.record(
+                    BuildLogEntry::new(
+                        source.path(),
+                        ArtifactKind::Sdist,
+                        start,
+                        result.as_ref().cloned(),
+                    )
+                    .with_pep517(build_dispatch.interpreter(), Some(&builder)),
+                );
+            }
+            let filename = result?;
             BuildMessage::Build {
                 normalized_filename: DistFilename::SourceDistFilename(
                     SourceDistFilename::parsed_normalized_filename(&filename)
@@ -957,14 +1129,57 @@ async fn build_wheel(
     subdirectory: Option<&Path>,
     version_id: Option<&str>,
     build_output: BuildOutput,
+    build_log: Option<&BuildLog>,
     // Used for checking version consistency
     version: Option<&Version>,
+    // Used for checking platform compatibility
+    target_tags: Option<&Tags>,
 ) -> Result<BuildMessage, Error> {
+    // When building for a target platform, build into a temporary directory first, such that a
+    // wheel that isn't compatible with the target platform is never written to the output
+    // directory.
+    let wheel_dir = if target_tags.is_some() && action != BuildAction::List {
+        Some(tempfile::tempdir_in(output_dir)?)
+    } else {
+        None
+    };
+    let build_dir = wheel_dir
+        .as_ref()
+        .map_or(output_dir, tempfile::TempDir::path);
+
     let build_message = match action {
         BuildAction::List => {
+            // Listing the files built by a build hook requires running it in a build environment.
+            let builder = if uv_build_backend::has_build_hook(source_tree) {
+                Some(
+                    build_dispatch
+                        .setup_build(
+                            source_tree,
+                            subdirectory,
+                            source.path(),
+                            version_id,
+                            dist,
+                            sources,
+                            BuildKind::Wheel,
+                            build_output,
+                            BuildStack::default(),
+                        )
+                        .await
+                        .map_err(|err| Error::BuildDispatch(err.into()))?,
+                )
+            } else {
+                None
+            };
             let source_tree_ = source_tree.to_path_buf();
+            let python = builder
+                .as_ref()
+                .map(|builder| builder.python_executable().to_path_buf());
             let (filename, file_list) = tokio::task::spawn_blocking(move || {
-                uv_build_backend::list_wheel(&source_tree_, uv_version::version())
+                uv_build_backend::list_wheel(
+                    &source_tree_,
+                    uv_version::version(),
+                    python.as_deref(),
+                )
             })
             .await??;
             let raw_filename = filename.to_string();
@@ -986,17 +1201,22 @@ async fn build_wheel(
                 )
                 .bold()
             )?;
+            let start = Instant::now();
             let source_tree = source_tree.to_path_buf();
-            let output_dir_ = output_dir.to_path_buf();
-            let filename = tokio::task::spawn_blocking(move || {
-                uv_build_backend::build_wheel(
-                    &source_tree,
-                    &output_dir_,
-                    None,
-                    uv_version::version(),
-                )
+            let build_dir = build_dir.to_path_buf();
+            let result = tokio::task::spawn_blocking(move || {
+                uv_build_backend::build_wheel(&source_tree, &build_dir, None, uv_version::version())
             })
-            .await??;
+            .await?;
+            if let Some(build_log) = build_log {
+                build_log.record(BuildLogEntry::new(
+                    source.path(),
+                    ArtifactKind::Wheel,
+                    start,
+                    result.as_ref().map(ToString::to_string),
+                ));
+            }
+            let filename = result?;
 
             let raw_filename = filename.to_string();
             BuildMessage::Build {
@@ -1016,7 +1236,8 @@ async fn build_wheel(
                 )
                 .bold()
             )?;
-            let builder = build_dispatch
+            let start = Instant::now();
+            let builder = match build_dispatch
                 .setup_build(
                     source_tree,
                     subdirectory,
@@ -1029,8 +1250,37 @@ async fn build_wheel(
                     BuildStack::default(),
                 )
                 .await
-                .map_err(|err| Error::BuildDispatch(err.into()))?;
-            let filename = builder.build(output_dir).await?;
+            {
+                Ok(builder) => builder,
+                Err(err) => {
+                    let err = Error::BuildDispatch(err.into());
+                    if let Some(build_log) = build_log {
+                        build_log.record(
+                            BuildLogEntry::new(
+                                source.path(),
+                                ArtifactKind::Wheel,
+                                start,
+                                Err(&err),
+                            )
+                            .with_pep517(build_dispatch.interpreter(), None),
+                        );
+                    }
+                    return Err(err);
+                }
+            };
+            let result = builder.build(build_dir).await;
+            if let Some(build_log) = build_log {
+                build_log.record(
+                    BuildLogEntry::new(
+                        source.path(),
+                        ArtifactKind::Wheel,
+                        start,
+                        result.as_ref().cloned(),
+                    )
+                    .with_pep517(build_dispatch.interpreter(), Some(&builder)),
+                );
+            }
+            let filename = result?;
             BuildMessage::Build {
                 normalized_filename: DistFilename::WheelFilename(
                     WheelFilename::from_str(&filename).map_err(Error::InvalidBuiltWheelFilename)?,
@@ -1046,9 +1296,34 @@ async fn build_wheel(
             return Err(Error::VersionMismatch(expected.clone(), actual.clone()));
         }
     }
+    if let Some(target_tags) = target_tags {
+        if let DistFilename::WheelFilename(filename) = build_message.normalized_filename() {
+            if !filename.is_compatible(target_tags) {
+                return Err(Error::IncompatibleWheel(filename.clone()));
+            }
+        }
+    }
+    if let Some(wheel_dir) = wheel_dir {
+        let raw_filename = build_message.raw_filename();
+        fs_err::tokio::rename(
+            wheel_dir.path().join(raw_filename),
+            output_dir.join(raw_filename),
+        )
+        .await?;
+    }
     Ok(build_message)
 }
 
+/// Whether `build-system-overrides` replaces the build system of the project in the source tree,
+/// in which case it needs to be built through PEP 517.
+fn has_build_system_override(
+    source_tree: &Path,
+    build_system_overrides: &BuildSystemOverrides,
+) -> bool {
+    project_name_and_version(source_tree)
+        .is_some_and(|(name, version)| build_system_overrides.get(&name, Some(&version)).is_some())
+}
+
 /// Create the output directory and add a `.gitignore`.
 async fn prepare_output_directory(output_dir: &Path) -> Result<(), Error> {
     // Create the output directory.
@@ -1162,6 +1437,195 @@ enum BuildMessage {
     },
 }
 
+/// The report for `uv build --output-format json`.
+#[derive(Debug, Serialize)]
+struct BuildReport {
+    /// The built source distributions and wheels.
+    artifacts: Vec<ArtifactReport>,
+}
+
+/// A built source distribution or wheel.
+#[derive(Debug, Serialize)]
+struct ArtifactReport {
+    /// The absolute path to the distribution.
+    path: PortablePathBuf,
+    /// The filename of the distribution.
+    filename: String,
+    /// Whether the distribution is a source distribution or a wheel.
+    kind: ArtifactKind,
+    /// The name of the package.
+    name: PackageName,
+    /// The version of the package.
+    version: Version,
+    /// The wheel tags, e.g., `py3-none-any`, or an empty list for source distributions.
+    tags: Vec<String>,
+    /// The size of the distribution in bytes.
+    size: u64,
+    /// The hex-encoded SHA-256 digest of the distribution.
+    sha256: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ArtifactKind {
+    Sdist,
+    Wheel,
+}
+
+/// The builds recorded for `uv build --log-dir`.
+#[derive(Debug)]
+struct BuildLog {
+    /// The directory containing the build logs and the index.
+    dir: PathBuf,
+    /// The builds, in the order they finished.
+    entries: Mutex<Vec<BuildLogEntry>>,
+}
+
+impl BuildLog {
+    fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Record the outcome of a build.
+    fn record(&self, entry: BuildLogEntry) {
+        self.entries.lock().unwrap().push(entry);
+    }
+
+    /// Write the recorded builds to `index.json` in the log directory.
+    async fn write_index(self) -> Result<(), Error> {
+        let index = BuildLogIndex {
+            builds: self.entries.into_inner().unwrap(),
+        };
+        let contents = serde_json::to_string_pretty(&index).map_err(io::Error::from)?;
+        fs_err::tokio::write(self.dir.join("index.json"), contents + "\n").await?;
+        Ok(())
+    }
+}
+
+/// The `index.json` for `uv build --log-dir`.
+#[derive(Debug, Serialize)]
+struct BuildLogIndex {
+    builds: Vec<BuildLogEntry>,
+}
+
+/// The outcome of a single source distribution or wheel build.
+#[derive(Debug, Serialize)]
+struct BuildLogEntry {
+    /// The source tree or source distribution that was built.
+    source: PortablePathBuf,
+    /// Whether a source distribution or a wheel was built.
+    kind: ArtifactKind,
+    /// Whether the build succeeded.
+    outcome: BuildOutcome,
+    /// The filename of the built distribution, if the build succeeded.
+    filename: Option<String>,
+    /// The error, if the build failed.
+    error: Option<String>,
+    /// The wall time of the build, in seconds.
+    duration_secs: f64,
+    /// The Python interpreter used for the build, or `None` for builds that called into the uv
+    /// build backend directly.
+    python: Option<PortablePathBuf>,
+    /// The environment the build backend ran in, if it was created.
+    environment: Option<PortablePathBuf>,
+    /// The file containing the output of the build backend, if it was created.
+    log: Option<PortablePathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BuildOutcome {
+    Success,
+    Failure,
+}
+
+impl BuildLogEntry {
+    fn new(
+        source: &Path,
+        kind: ArtifactKind,
+        start: Instant,
+        result: Result<String, &impl std::fmt::Display>,
+    ) -> Self {
+        let (outcome, filename, error) = match result {
+            Ok(filename) => (BuildOutcome::Success, Some(filename), None),
+            Err(err) => (BuildOutcome::Failure, None, Some(err.to_string())),
+        };
+        Self {
+            source: source.into(),
+            kind,
+            outcome,
+            filename,
+            error,
+            duration_secs: start.elapsed().as_secs_f64(),
+            python: None,
+            environment: None,
+            log: None,
+        }
+    }
+
+    /// Add the interpreter, environment, and log file of a PEP 517 build.
+    #[must_use]
+    fn with_pep517(mut self, interpreter: &Interpreter, builder: Option<&SourceBuild>) -> Self {
+        self.python = Some(interpreter.sys_executable().into());
+        if let Some(builder) = builder {
+            self.environment = Some(builder.environment().into());
+            self.log = builder.log_file().map(PortablePathBuf::from);
+        }
+        self
+    }
+}
+
+impl ArtifactReport {
+    /// Read the size and digest of a built distribution, or `None` if the files were only listed.
+    async fn from_message(message: &BuildMessage) -> Result<Option<Self>, Error> {
+        let BuildMessage::Build {
+            normalized_filename,
+            raw_filename,
+            output_dir,
+        } = message
+        else {
+            return Ok(None);
+        };
+
+        let path = output_dir.join(raw_filename);
+        let size = fs_err::tokio::metadata(&path).await?.len();
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let file = tokio::io::BufReader::new(fs_err::tokio::File::open(&path).await?);
+        HashReader::new(file, &mut hashers).finish().await?;
+        let sha256 = HashDigest::from(hashers.remove(0)).digest.to_string();
+
+        let (kind, tags) = match normalized_filename {
+            DistFilename::SourceDistFilename(_) => (ArtifactKind::Sdist, Vec::new()),
+            DistFilename::WheelFilename(filename) => {
+                let tags = filename
+                    .python_tags()
+                    .iter()
+                    .cartesian_product(filename.abi_tags())
+                    .cartesian_product(filename.platform_tags())
+                    .map(|((python_tag, abi_tag), platform_tag)| {
+                        format!("{python_tag}-{abi_tag}-{platform_tag}")
+                    })
+                    .collect();
+                (ArtifactKind::Wheel, tags)
+            }
+        };
+
+        Ok(Some(Self {
+            path: path.as_path().into(),
+            filename: raw_filename.clone(),
+            kind,
+            name: normalized_filename.name().clone(),
+            version: normalized_filename.version().clone(),
+            tags,
+            size,
+            sha256,
+        }))
+    }
+}
+
 impl BuildMessage {
     /// The normalized filename of the wheel or source distribution.
     fn normalized_filename(&self) -> &DistFilename {
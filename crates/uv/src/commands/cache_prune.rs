@@ -10,7 +10,14 @@ use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+///
+/// With `ephemeral`, only prune the ephemeral environments that exceed the configured limits.
+pub(crate) fn cache_prune(
+    ci: bool,
+    ephemeral: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -28,14 +35,23 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
 
     let mut summary = Removal::default();
 
-    // Prune the source distribution cache, which is tightly coupled to the builder crate.
-    summary += uv_distribution::prune(cache)
-        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+    if ephemeral {
+        summary += cache
+            .prune_environments(cache.environment_limits())
+            .with_context(|| {
+                format!("Failed to prune cache at: {}", cache.root().user_display())
+            })?;
+    } else {
+        // Prune the source distribution cache, which is tightly coupled to the builder crate.
+        summary += uv_distribution::prune(cache).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
 
-    // Prune the remaining cache buckets.
-    summary += cache
-        .prune(ci)
-        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+        // Prune the remaining cache buckets.
+        summary += cache.prune(ci).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    }
 
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
@@ -0,0 +1,357 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, DependencyGroupsWithDefaults, ExtrasSpecification,
+    ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions, Preview, TargetTriple,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Dist, Index, ResolvedDist, SourceDist};
+use uv_installer::Preparer;
+use uv_normalize::{DefaultExtras, PackageName};
+use uv_python::{Interpreter, PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
+
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::store_credentials_from_target;
+use crate::commands::project::{
+    PlatformState, ProjectError, ProjectInterpreter, default_dependency_groups, detect_conflicts,
+};
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::{InstallerSettingsRef, NetworkSettings, ResolverInstallerSettings};
+
+/// Populate the cache with the distributions required by the project's lockfile.
+pub(crate) async fn cache_warm(
+    project_dir: &Path,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    all_packages: bool,
+    package: Option<PackageName>,
+    python: Option<String>,
+    python_platform: Option<TargetTriple>,
+    install_mirrors: PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project = if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?
+    };
+
+    // Read the lockfile, without attempting to update it.
+    let Some(lock) = LockTarget::from(project.workspace()).read().await? else {
+        return Err(anyhow::anyhow!(
+            "`uv cache warm` requires a lockfile; run `{}` to generate one",
+            "uv lock".green(),
+        ));
+    };
+
+    // Determine the groups and extras to include.
+    let groups = groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    // Find an interpreter to determine the target environment and to build any source
+    // distributions, without creating a virtual environment.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &network_settings,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Identify the installation target.
+    let target = match &project {
+        VirtualProject::Project(project) => {
+            if all_packages {
+                InstallTarget::Workspace {
+                    workspace: project.workspace(),
+                    lock: &lock,
+                }
+            } else {
+                InstallTarget::Project {
+                    workspace: project.workspace(),
+                    name: project.project_name(),
+                    lock: &lock,
+                }
+            }
+        }
+        VirtualProject::NonProject(workspace) => {
+            if let Some(package) = package.as_ref().filter(|_| !all_packages) {
+                InstallTarget::Project {
+                    workspace,
+                    name: package,
+                    lock: &lock,
+                }
+            } else {
+                InstallTarget::NonProjectWorkspace {
+                    workspace,
+                    lock: &lock,
+                }
+            }
+        }
+    };
+
+    match do_warm(
+        target,
+        &interpreter,
+        &extras,
+        &groups,
+        python_platform.as_ref(),
+        (&settings).into(),
+        &network_settings,
+        concurrency,
+        cache,
+        workspace_cache,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(()) => Ok(ExitStatus::Success),
+        Err(ProjectError::Operation(err)) => {
+            diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Download and build every remote distribution in the [`InstallTarget`].
+async fn do_warm(
+    target: InstallTarget<'_>,
+    interpreter: &Interpreter,
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+    python_platform: Option<&TargetTriple>,
+    settings: InstallerSettingsRef<'_>,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+    workspace_cache: WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<(), ProjectError> {
+    let InstallerSettingsRef {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        dependency_metadata,
+        build_system_overrides,
+        config_setting,
+        config_settings_package,
+        no_build_isolation,
+        no_build_isolation_package,
+        exclude_newer,
+        link_mode,
+        compile_bytecode: _,
+        reinstall: _,
+        build_options,
+        sources,
+    } = settings;
+
+    let start = std::time::Instant::now();
+
+    // Validate that the set of requested extras and development groups are compatible.
+    detect_conflicts(target.lock(), extras, groups)?;
+
+    // Validate that the set of requested extras and development groups are defined in the lockfile.
+    target.validate_extras(extras)?;
+    target.validate_groups(groups)?;
+
+    // Determine the markers and tags for the target environment.
+    let marker_env = resolution_markers(None, python_platform, interpreter);
+    let tags = resolution_tags(None, python_platform, interpreter)?;
+
+    // Read the lockfile into a resolution for the target environment.
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        extras,
+        groups,
+        build_options,
+        &InstallOptions::default(),
+    )?;
+
+    // Collect the remote distributions. Local directories (like workspace members) are built on
+    // every sync, so there's nothing to be gained by warming them.
+    let distributions = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist.clone()),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .filter(|dist| !matches!(dist.as_ref(), Dist::Source(SourceDist::Directory(_))))
+        .collect::<Vec<_>>();
+
+    if distributions.is_empty() {
+        writeln!(printer.stderr(), "No distributions to warm")?;
+        return Ok(());
+    }
+
+    if no_build_isolation || !no_build_isolation_package.is_empty() {
+        warn_user!(
+            "Ignoring `--no-build-isolation`; `uv cache warm` always builds in isolated environments"
+        );
+    }
+
+    index_locations.cache_index_credentials();
+
+    // Populate credentials from the target.
+    store_credentials_from_target(target);
+
+    // Initialize the registry client.
+    let client_builder = BaseClientBuilder::new()
+        .retry_settings(&network_settings.retry)
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .keyring(keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_locations(index_locations)
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Extract the hashes from the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+    let build_hasher = HashStrategy::default();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
+    };
+
+    let state = PlatformState::default();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        target.build_constraints(),
+        interpreter,
+        index_locations,
+        &flat_index,
+        dependency_metadata,
+        build_system_overrides,
+        state.clone().into_inner(),
+        index_strategy,
+        config_setting,
+        config_settings_package,
+        BuildIsolation::Isolated,
+        link_mode,
+        build_options,
+        &build_hasher,
+        exclude_newer,
+        sources,
+        workspace_cache,
+        concurrency,
+        preview,
+    );
+
+    // Download, build, and unzip the distributions into the cache.
+    let preparer = Preparer::new(
+        cache,
+        &tags,
+        &hasher,
+        build_options,
+        DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+    )
+    .with_reporter(Arc::new(
+        PrepareReporter::from(printer).with_length(distributions.len() as u64),
+    ));
+
+    let wheels = preparer
+        .prepare(distributions, state.in_flight(), &resolution)
+        .await
+        .map_err(operations::Error::from)?;
+
+    operations::report_cache_summary(cache);
+
+    let s = if wheels.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Warmed {} {}",
+            format!("{} package{}", wheels.len(), s).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    // Report how much of the work was already cached.
+    let summary = cache.stats().summary();
+    if !summary.is_empty() {
+        let wheels = if summary.wheels_downloaded == 1 {
+            ""
+        } else {
+            "s"
+        };
+        let builds = if summary.builds == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Downloaded {} wheel{wheels}, built {} source distribution{builds}, and reused {} from the cache",
+                summary.wheels_downloaded,
+                summary.builds,
+                summary.wheels_from_cache + summary.builds_avoided,
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(())
+}
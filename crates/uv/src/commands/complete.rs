@@ -0,0 +1,196 @@
+//! Completion of project-specific values, e.g., the entry points and tasks for `uv run <TAB>`.
+//!
+//! The static completion scripts generated by `clap` can't know about the current project, so
+//! for the shells that support it, we extend them with functions that call back into
+//! `uv complete <target>` at completion time.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use clap_complete_command::Shell;
+use tracing::debug;
+
+use uv_cli::CompletionTarget;
+use uv_normalize::DEV_DEPENDENCIES;
+use uv_pypi_types::DependencyGroups;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::task::Tasks;
+use crate::printer::Printer;
+
+/// Print the completion candidates for the given target, one per line.
+///
+/// Completion is best-effort: outside of a project (or if the project is invalid), nothing is
+/// printed.
+pub(crate) async fn complete(
+    project_dir: &Path,
+    target: CompletionTarget,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let project = match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(project) => project,
+        Err(err) => {
+            debug!("Unable to discover project for completion: {err}");
+            return Ok(ExitStatus::Success);
+        }
+    };
+
+    let candidates = match target {
+        CompletionTarget::Run => {
+            let tasks = Tasks::from_project(&project);
+            project
+                .workspace()
+                .packages()
+                .values()
+                .flat_map(|member| {
+                    let pyproject_toml = member.pyproject_toml();
+                    pyproject_toml.scripts().chain(pyproject_toml.gui_scripts())
+                })
+                .chain(tasks.iter().map(|(name, _)| name))
+                .map(ToString::to_string)
+                .collect::<BTreeSet<_>>()
+        }
+        CompletionTarget::Extra => project
+            .pyproject_toml()
+            .project
+            .as_ref()
+            .and_then(|project| project.optional_dependencies.as_ref())
+            .into_iter()
+            .flat_map(|extras| extras.keys())
+            .map(ToString::to_string)
+            .collect(),
+        CompletionTarget::Group => {
+            let pyproject_toml = project.pyproject_toml();
+            let mut groups = pyproject_toml
+                .dependency_groups
+                .iter()
+                .flat_map(DependencyGroups::keys)
+                .map(ToString::to_string)
+                .collect::<BTreeSet<_>>();
+            if pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .is_some_and(|uv| uv.dev_dependencies.is_some())
+            {
+                groups.insert(DEV_DEPENDENCIES.to_string());
+            }
+            groups
+        }
+    };
+
+    for candidate in candidates {
+        writeln!(printer.stdout(), "{candidate}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns the completion functions for project-specific values, to be appended to the
+/// `clap`-generated completion script for the given shell, if supported.
+pub(crate) fn project_completion(shell: Shell) -> Option<&'static str> {
+    match shell {
+        Shell::Bash => Some(BASH),
+        Shell::Zsh => Some(ZSH),
+        Shell::Fish => Some(FISH),
+        _ => None,
+    }
+}
+
+const BASH: &str = r#"
+_uv_project() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local prev="${COMP_WORDS[COMP_CWORD-1]}"
+    local target=""
+    case "${prev}" in
+        --extra|--no-extra)
+            target="extra"
+            ;;
+        --group|--no-group|--only-group)
+            target="group"
+            ;;
+        *)
+            # Complete the command of `uv run` if all the preceding arguments are flags.
+            if [[ "${COMP_WORDS[1]}" == "run" && "${cur}" != -* ]]; then
+                target="run"
+                local i
+                for ((i = 2; i < COMP_CWORD; i++)); do
+                    if [[ "${COMP_WORDS[i]}" != -* ]]; then
+                        target=""
+                        break
+                    fi
+                done
+            fi
+            ;;
+    esac
+    _uv "$@"
+    if [[ -n "${target}" ]]; then
+        local IFS=$'\n'
+        COMPREPLY+=($(compgen -W "$(uv complete "${target}" 2>/dev/null)" -- "${cur}"))
+    fi
+}
+
+complete -F _uv_project -o bashdefault -o default uv
+"#;
+
+const ZSH: &str = r#"
+_uv_project() {
+    local target
+    case "${words[CURRENT-1]}" in
+        --extra|--no-extra)
+            target=extra
+            ;;
+        --group|--no-group|--only-group)
+            target=group
+            ;;
+        *)
+            # Complete the command of `uv run` if all the preceding arguments are flags.
+            if [[ "${words[2]}" == run && "${words[CURRENT]}" != -* ]]; then
+                target=run
+                local word
+                for word in "${(@)words[3,CURRENT-1]}"; do
+                    if [[ "${word}" != -* ]]; then
+                        target=
+                        break
+                    fi
+                done
+            fi
+            ;;
+    esac
+    if [[ -n "${target}" ]]; then
+        local -a candidates
+        candidates=(${(f)"$(uv complete "${target}" 2>/dev/null)"})
+        compadd -a candidates
+    fi
+    _uv "$@"
+}
+
+compdef _uv_project uv
+"#;
+
+const FISH: &str = r#"
+# Complete the command of `uv run` if all the preceding arguments are flags.
+function __uv_complete_run_command
+    set -l tokens (commandline -opc)
+    test "$tokens[2]" = run; or return 1
+    for token in $tokens[3..-1]
+        string match -q -- '-*' $token; or return 1
+    end
+end
+
+complete -c uv -n __uv_complete_run_command -a "(uv complete run 2>/dev/null)"
+complete -c uv -l extra -x -a "(uv complete extra 2>/dev/null)"
+complete -c uv -l no-extra -x -a "(uv complete extra 2>/dev/null)"
+complete -c uv -l group -x -a "(uv complete group 2>/dev/null)"
+complete -c uv -l no-group -x -a "(uv complete group 2>/dev/null)"
+complete -c uv -l only-group -x -a "(uv complete group 2>/dev/null)"
+"#;
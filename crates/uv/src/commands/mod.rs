@@ -11,9 +11,12 @@ pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_warm::cache_warm;
+pub(crate) use complete::{complete, project_completion};
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::download::{DownloadKind, pip_download};
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
@@ -22,15 +25,19 @@ pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::container::run as run_container;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
+pub(crate) use project::migrate::migrate;
+pub(crate) use project::parallel::run as run_parallel;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
 pub(crate) use project::version::{project_version, self_version};
-pub(crate) use publish::publish;
+pub(crate) use project::watch::watch as run_watch;
+pub(crate) use publish::{PublishTarget, publish, publish_workspace};
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::find::find_script as python_find_script;
@@ -42,10 +49,12 @@ pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
+pub(crate) use tool::export::export as tool_export;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::ToolRunCommand;
 pub(crate) use tool::run::run as tool_run;
+pub(crate) use tool::sync::sync as tool_sync;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
@@ -58,6 +67,7 @@ use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
+pub(crate) use yank::yank;
 
 use crate::printer::Printer;
 
@@ -66,6 +76,8 @@ mod build_frontend;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod cache_warm;
+mod complete;
 mod diagnostics;
 mod help;
 pub(crate) mod pip;
@@ -77,6 +89,7 @@ pub(crate) mod reporters;
 mod self_update;
 mod tool;
 mod venv;
+mod yank;
 
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
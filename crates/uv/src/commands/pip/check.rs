@@ -1,21 +1,61 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::time::Instant;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
 
 use uv_cache::Cache;
-use uv_configuration::Preview;
-use uv_distribution_types::{Diagnostic, InstalledDist};
+use uv_cli::CheckFormat;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, IndexStrategy,
+    KeyringProviderType, PackageConfigSettings, Preview, Reinstall, SourceStrategy, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution_types::{
+    BuildSystemOverrides, DependencyMetadata, Diagnostic, Index, IndexLocations, InstalledDist,
+    Name, Requirement, Resolution, UnresolvedRequirementSpecification,
+};
+use uv_install_wheel::LinkMode;
 use uv_installer::{SitePackages, SitePackagesDiagnostic};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, ResolverEnvironment,
+};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
+use uv_workspace::WorkspaceCache;
 
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::loggers::SummaryResolveLogger;
+use crate::commands::pip::operations::{self, report_target_environment};
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
+use crate::settings::NetworkSettings;
 
 /// Check for incompatibilities in installed packages.
-pub(crate) fn pip_check(
+pub(crate) async fn pip_check(
+    output_format: CheckFormat,
+    suggest: bool,
+    prerelease: PrereleaseMode,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    dependency_metadata: DependencyMetadata,
+    build_system_overrides: BuildSystemOverrides,
+    config_settings: ConfigSettings,
+    config_settings_package: PackageConfigSettings,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    link_mode: LinkMode,
+    concurrency: Concurrency,
     python: Option<&str>,
     system: bool,
     cache: &Cache,
@@ -57,34 +97,365 @@ pub(crate) fn pip_check(
     let diagnostics: Vec<SitePackagesDiagnostic> =
         site_packages.diagnostics(&markers)?.into_iter().collect();
 
-    if diagnostics.is_empty() {
-        writeln!(
-            printer.stderr(),
-            "{}",
-            "All installed packages are compatible".to_string().dimmed()
-        )?;
+    // For each missing or incompatible dependency, determine a version that satisfies all
+    // installed requirements.
+    let suggestions = if suggest && !diagnostics.is_empty() {
+        let client_builder = BaseClientBuilder::new()
+            .retry_settings(&network_settings.retry)
+            .connectivity(network_settings.connectivity)
+            .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
+            .keyring(keyring_provider)
+            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+        Suggester {
+            site_packages: &site_packages,
+            markers: &markers,
+            environment: &environment,
+            client_builder,
+            prerelease,
+            index_locations: &index_locations,
+            index_strategy,
+            dependency_metadata: &dependency_metadata,
+            build_system_overrides: &build_system_overrides,
+            config_settings: &config_settings,
+            config_settings_package: &config_settings_package,
+            build_options: &build_options,
+            exclude_newer,
+            sources,
+            link_mode,
+            concurrency,
+            cache,
+            preview,
+        }
+        .suggest(&diagnostics)
+        .await?
+    } else {
+        BTreeMap::default()
+    };
+
+    match output_format {
+        CheckFormat::Text => {
+            if diagnostics.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    "All installed packages are compatible".to_string().dimmed()
+                )?;
+            } else {
+                let incompats = if diagnostics.len() == 1 {
+                    "incompatibility"
+                } else {
+                    "incompatibilities"
+                };
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!(
+                        "Found {}",
+                        format!("{} {}", diagnostics.len(), incompats).bold()
+                    )
+                    .dimmed()
+                )?;
 
+                for diagnostic in &diagnostics {
+                    writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+                    if let Some(name) = dependency_name(diagnostic) {
+                        match suggestions.get(name) {
+                            Some(Some(version)) => writeln!(
+                                printer.stderr(),
+                                "{}{} Install `{}` to satisfy all installed requirements on `{name}`",
+                                "hint".bold().cyan(),
+                                ":".bold(),
+                                format!("{name}=={version}").green(),
+                            )?,
+                            Some(None) => writeln!(
+                                printer.stderr(),
+                                "{}{} No version of `{name}` satisfies all installed requirements",
+                                "hint".bold().cyan(),
+                                ":".bold(),
+                            )?,
+                            None => {}
+                        }
+                    }
+                }
+            }
+        }
+        CheckFormat::Json => {
+            let report = CheckReport {
+                checked: packages.len(),
+                compatible: diagnostics.is_empty(),
+                diagnostics: diagnostics
+                    .iter()
+                    .map(|diagnostic| DiagnosticEntry::new(diagnostic, &suggestions))
+                    .collect(),
+            };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&report)?)?;
+        }
+    }
+
+    if diagnostics.is_empty() {
         Ok(ExitStatus::Success)
     } else {
-        let incompats = if diagnostics.len() == 1 {
-            "incompatibility"
-        } else {
-            "incompatibilities"
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Return the name of the dependency that is missing or incompatible, if any.
+fn dependency_name(diagnostic: &SitePackagesDiagnostic) -> Option<&PackageName> {
+    match diagnostic {
+        SitePackagesDiagnostic::MissingDependency { requirement, .. }
+        | SitePackagesDiagnostic::IncompatibleDependency { requirement, .. } => {
+            Some(&requirement.name)
+        }
+        _ => None,
+    }
+}
+
+/// Computes suggested versions for missing and incompatible dependencies.
+struct Suggester<'a> {
+    site_packages: &'a SitePackages,
+    markers: &'a ResolverMarkerEnvironment,
+    environment: &'a PythonEnvironment,
+    client_builder: BaseClientBuilder<'a>,
+    prerelease: PrereleaseMode,
+    index_locations: &'a IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: &'a DependencyMetadata,
+    build_system_overrides: &'a BuildSystemOverrides,
+    config_settings: &'a ConfigSettings,
+    config_settings_package: &'a PackageConfigSettings,
+    build_options: &'a BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    link_mode: LinkMode,
+    concurrency: Concurrency,
+    cache: &'a Cache,
+    preview: Preview,
+}
+
+impl Suggester<'_> {
+    /// Resolve a suggested version for each dependency referenced by the given diagnostics.
+    ///
+    /// Maps each dependency to the version that satisfies the requirements of every installed
+    /// package, or to `None` if no such version exists.
+    async fn suggest(
+        &self,
+        diagnostics: &[SitePackagesDiagnostic],
+    ) -> Result<BTreeMap<PackageName, Option<Version>>> {
+        let names = diagnostics
+            .iter()
+            .filter_map(dependency_name)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
+        let interpreter = self.environment.interpreter();
+        let tags = interpreter.tags()?;
+
+        // Initialize the registry client.
+        let client = RegistryClientBuilder::try_from(self.client_builder.clone())?
+            .cache(self.cache.clone())
+            .index_locations(self.index_locations)
+            .index_strategy(self.index_strategy)
+            .markers(interpreter.markers())
+            .platform(interpreter.platform())
+            .build();
+
+        // Resolve the flat indexes from `--find-links`.
+        let hasher = HashStrategy::None;
+        let flat_index = {
+            let client =
+                FlatIndexClient::new(client.cached_client(), client.connectivity(), self.cache);
+            let entries = client
+                .fetch_all(self.index_locations.flat_indexes().map(Index::url))
+                .await?;
+            FlatIndex::from_entries(entries, Some(tags), &hasher, self.build_options)
         };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Found {}",
-                format!("{} {}", diagnostics.len(), incompats).bold()
+
+        let state = SharedState::default();
+        let build_dispatch = BuildDispatch::new(
+            &client,
+            self.cache,
+            Constraints::default(),
+            interpreter,
+            self.index_locations,
+            &flat_index,
+            self.dependency_metadata,
+            self.build_system_overrides,
+            state.clone(),
+            self.index_strategy,
+            self.config_settings,
+            self.config_settings_package,
+            BuildIsolation::Isolated,
+            self.link_mode,
+            self.build_options,
+            &hasher,
+            self.exclude_newer,
+            self.sources,
+            WorkspaceCache::default(),
+            self.concurrency,
+            self.preview,
+        );
+
+        let mut suggestions = BTreeMap::default();
+        for name in names {
+            // Collect every installed requirement on the dependency.
+            let requirements = self
+                .site_packages
+                .iter()
+                .filter_map(|dist| dist.metadata().ok())
+                .flat_map(|metadata| metadata.requires_dist.into_iter())
+                .filter(|requirement| requirement.name == name)
+                .filter(|requirement| requirement.evaluate_markers(self.markers, &[]))
+                .map(|requirement| {
+                    UnresolvedRequirementSpecification::from(Requirement::from(requirement))
+                })
+                .collect::<Vec<_>>();
+
+            // Resolve the dependency alone, such that it satisfies all of the requirements.
+            let options = OptionsBuilder::new()
+                .prerelease_mode(self.prerelease)
+                .dependency_mode(DependencyMode::Direct)
+                .exclude_newer(self.exclude_newer)
+                .index_strategy(self.index_strategy)
+                .build_options(self.build_options.clone())
+                .build();
+
+            let resolution = operations::resolve(
+                requirements,
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                None,
+                BTreeSet::default(),
+                &ExtrasSpecification::default(),
+                &BTreeMap::default(),
+                Vec::new(),
+                EmptyInstalledPackages,
+                &hasher,
+                &Reinstall::None,
+                &Upgrade::None,
+                Some(tags),
+                ResolverEnvironment::specific(self.markers.clone()),
+                PythonRequirement::from_interpreter(interpreter),
+                interpreter.markers(),
+                Conflicts::empty(),
+                &client,
+                &flat_index,
+                &InMemoryIndex::default(),
+                &build_dispatch,
+                self.concurrency,
+                options,
+                Box::new(SummaryResolveLogger),
+                Printer::Silent,
             )
-            .dimmed()
-        )?;
+            .await;
 
-        for diagnostic in &diagnostics {
-            writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+            let version = match resolution {
+                Ok(resolution) => Resolution::from(resolution)
+                    .distributions()
+                    .find(|dist| *dist.name() == name)
+                    .and_then(|dist| dist.version().cloned()),
+                Err(err) => {
+                    debug!("Failed to resolve a suggestion for `{name}`: {err}");
+                    None
+                }
+            };
+            suggestions.insert(name, version);
         }
 
-        Ok(ExitStatus::Failure)
+        Ok(suggestions)
+    }
+}
+
+/// The result of a `pip check` invocation, in JSON format.
+#[derive(Debug, Serialize)]
+struct CheckReport {
+    /// The number of installed packages that were checked.
+    checked: usize,
+    /// Whether all installed packages are compatible.
+    compatible: bool,
+    /// The incompatibilities that were found.
+    diagnostics: Vec<DiagnosticEntry>,
+}
+
+/// A single incompatibility, in JSON format.
+#[derive(Debug, Serialize)]
+struct DiagnosticEntry {
+    /// The kind of incompatibility, e.g., `missing-dependency`.
+    kind: &'static str,
+    /// The package that the diagnostic refers to.
+    package: PackageName,
+    /// The human-readable message.
+    message: String,
+    /// The unsatisfied requirement, for missing and incompatible dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirement: Option<String>,
+    /// The installed version of the dependency, for incompatible dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installed_version: Option<Version>,
+    /// The suggested requirement that satisfies all installed packages, if one was computed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggestion: Option<Suggestion>,
+}
+
+/// A suggested version for a missing or incompatible dependency.
+#[derive(Debug, Serialize)]
+struct Suggestion {
+    name: PackageName,
+    /// The suggested version, or `null` if no version satisfies all installed requirements.
+    version: Option<Version>,
+}
+
+impl DiagnosticEntry {
+    fn new(
+        diagnostic: &SitePackagesDiagnostic,
+        suggestions: &BTreeMap<PackageName, Option<Version>>,
+    ) -> Self {
+        let (kind, package, requirement, installed_version) = match diagnostic {
+            SitePackagesDiagnostic::MetadataUnavailable { package, .. } => {
+                ("metadata-unavailable", package, None, None)
+            }
+            SitePackagesDiagnostic::IncompatiblePythonVersion { package, .. } => {
+                ("incompatible-python-version", package, None, None)
+            }
+            SitePackagesDiagnostic::MissingDependency {
+                package,
+                requirement,
+            } => (
+                "missing-dependency",
+                package,
+                Some(requirement.to_string()),
+                None,
+            ),
+            SitePackagesDiagnostic::IncompatibleDependency {
+                package,
+                version,
+                requirement,
+            } => (
+                "incompatible-dependency",
+                package,
+                Some(requirement.to_string()),
+                Some(version.clone()),
+            ),
+            SitePackagesDiagnostic::DuplicatePackage { package, .. } => {
+                ("duplicate-package", package, None, None)
+            }
+        };
+        let suggestion = dependency_name(diagnostic).and_then(|name| {
+            suggestions.get(name).map(|version| Suggestion {
+                name: name.clone(),
+                version: version.clone(),
+            })
+        });
+        Self {
+            kind,
+            package: package.clone(),
+            message: diagnostic.message(),
+            requirement,
+            installed_version,
+            suggestion,
+        }
     }
 }
@@ -20,8 +20,9 @@ use uv_configuration::{
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Origin, Requirement, RequiresPython, UnresolvedRequirementSpecification, Verbatim,
+    BuildSystemOverrides, DependencyMetadata, HashGeneration, Index, IndexLocations,
+    NameRequirementSpecification, Origin, Requirement, RequiresPython,
+    UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -89,6 +90,7 @@ pub(crate) async fn pip_compile(
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
     dependency_metadata: DependencyMetadata,
+    build_system_overrides: BuildSystemOverrides,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     config_settings: ConfigSettings,
@@ -182,9 +184,11 @@ pub(crate) async fn pip_compile(
     }
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -210,6 +214,7 @@ pub(crate) async fn pip_compile(
         overrides,
         Some(&groups),
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -242,7 +247,7 @@ pub(crate) async fn pip_compile(
 
     // Read build constraints.
     let build_constraints: Vec<NameRequirementSpecification> =
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, &cache)
             .await?
             .into_iter()
             .chain(
@@ -477,6 +482,7 @@ pub(crate) async fn pip_compile(
         &index_locations,
         &flat_index,
         &dependency_metadata,
+        &build_system_overrides,
         state,
         index_strategy,
         &config_settings,
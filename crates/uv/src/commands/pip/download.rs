@@ -0,0 +1,640 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
+    IndexStrategy, KeyringProviderType, PackageConfigSettings, Preview, Reinstall, SourceStrategy,
+    TargetTriple, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{
+    BuildSystemOverrides, BuiltDist, DependencyMetadata, Dist, HashPolicy, Index, IndexLocations,
+    IndexUrl, Name, NameRequirementSpecification, Origin, Requirement, Resolution, ResolvedDist,
+    SourceDist, UnresolvedRequirementSpecification,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_normalize::PackageName;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{Conflicts, HashDigest};
+use uv_python::{
+    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVersion, VersionRequest,
+};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, FlatIndex, ForkStrategy, InMemoryIndex, OptionsBuilder,
+    PrereleaseMode, PythonRequirement, ResolutionMode, ResolverEnvironment,
+};
+use uv_torch::{TorchMode, TorchStrategy};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::WorkspaceCache;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{operations, resolution_environment};
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// The kind of files to write to the destination directory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DownloadKind {
+    /// Write the distributions selected by the resolver, be they wheels or source distributions.
+    Distributions,
+    /// Write wheels, building any source distributions selected by the resolver.
+    Wheels,
+}
+
+/// Resolve a set of requirements and download the resulting distributions (or wheels built from
+/// them) into a directory.
+pub(crate) async fn pip_download(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    constraints_from_workspace: Vec<Requirement>,
+    overrides_from_workspace: Vec<Requirement>,
+    build_constraints_from_workspace: Vec<Requirement>,
+    dest: &Path,
+    kind: DownloadKind,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PrereleaseMode,
+    fork_strategy: ForkStrategy,
+    dependency_mode: DependencyMode,
+    upgrade: Upgrade,
+    hash_checking: Option<HashCheckingMode>,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    torch_backend: Option<TorchMode>,
+    dependency_metadata: DependencyMetadata,
+    build_system_overrides: BuildSystemOverrides,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    config_settings: ConfigSettings,
+    config_settings_package: PackageConfigSettings,
+    no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
+    build_options: BuildOptions,
+    mut python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    macos_deployment_target: Option<(u16, u16)>,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    link_mode: LinkMode,
+    mut python: Option<String>,
+    system: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    // As in `pip compile`, a simple Python version request is treated as `--python-version`.
+    if python_version.is_none() {
+        if let Some(request) = python.as_ref() {
+            if let Ok(version) = PythonVersion::from_str(request) {
+                python_version = Some(version);
+                python = None;
+            }
+        }
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .retry_settings(&network_settings.retry)
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .keyring(keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        pylock,
+        source_trees,
+        groups,
+        extras: _,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+    } = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        None,
+        &client_builder,
+        &cache,
+    )
+    .await?;
+
+    if pylock.is_some() {
+        return Err(anyhow!(
+            "`pylock.toml` is not a supported input format for `uv pip download`"
+        ));
+    }
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
+        .iter()
+        .cloned()
+        .chain(
+            constraints_from_workspace
+                .into_iter()
+                .map(NameRequirementSpecification::from),
+        )
+        .collect();
+
+    let overrides: Vec<UnresolvedRequirementSpecification> = overrides
+        .iter()
+        .cloned()
+        .chain(
+            overrides_from_workspace
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from),
+        )
+        .collect();
+
+    // Read build constraints.
+    let build_constraints: Vec<NameRequirementSpecification> =
+        operations::read_constraints(build_constraints, &client_builder, &cache)
+            .await?
+            .into_iter()
+            .chain(
+                build_constraints_from_workspace
+                    .into_iter()
+                    .map(NameRequirementSpecification::from),
+            )
+            .collect();
+
+    // Find an interpreter to use for building distributions.
+    let environment_preference = EnvironmentPreference::from_system_flag(system, false);
+    let interpreter = if let Some(python) = python.as_ref() {
+        let request = PythonRequest::parse(python);
+        PythonInstallation::find(
+            &request,
+            environment_preference,
+            python_preference,
+            &cache,
+            preview,
+        )
+    } else {
+        let request = if let Some(version) = python_version.as_ref() {
+            PythonRequest::Version(VersionRequest::from(version))
+        } else {
+            PythonRequest::default()
+        };
+        PythonInstallation::find_best(
+            &request,
+            environment_preference,
+            python_preference,
+            &cache,
+            preview,
+        )
+    }?
+    .into_interpreter();
+
+    debug!(
+        "Using Python {} interpreter at {} for builds",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    );
+
+    // Create the shared state.
+    let state = SharedState::default();
+
+    // If we're resolving against a different Python version, use a separate index, as in
+    // `pip compile`.
+    let top_level_index = if python_version.is_some() {
+        InMemoryIndex::default()
+    } else {
+        state.index().clone()
+    };
+
+    // Determine the Python requirement and the environment for the resolution.
+    let python_requirement = if let Some(python_version) = python_version.as_ref() {
+        PythonRequirement::from_python_version(&interpreter, python_version)
+    } else {
+        PythonRequirement::from_interpreter(&interpreter)
+    };
+    let (tags, marker_env) =
+        resolution_environment(python_version.clone(), python_platform, &interpreter)?;
+
+    // A pip-style macOS platform tag (e.g., `macosx_11_0_arm64`) determines the deployment target.
+    let tags = match (python_platform, macos_deployment_target) {
+        (Some(python_platform), Some((major, minor))) => Cow::Owned(Tags::from_env(
+            &python_platform.platform_with_macos_deployment_target(major, minor),
+            python_version.as_ref().map_or_else(
+                || interpreter.python_tuple(),
+                |python_version| (python_version.major(), python_version.minor()),
+            ),
+            interpreter.implementation_name(),
+            interpreter.implementation_tuple(),
+            python_platform.manylinux_compatible(),
+            interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+        )?),
+        _ => tags,
+    };
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    // Enforce (but never require) the build constraints, if `--require-hashes` or `--verify-hashes`
+    // is provided, as in `pip install`.
+    let build_hasher = if hash_checking.is_some() {
+        HashStrategy::from_requirements(
+            std::iter::empty(),
+            build_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            HashCheckingMode::Verify,
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    let resolver_env = ResolverEnvironment::specific(marker_env);
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    index_locations.cache_index_credentials();
+
+    // Determine the PyTorch backend.
+    let torch_backend = torch_backend
+        .map(|mode| {
+            TorchStrategy::from_mode(
+                mode,
+                python_platform
+                    .map(TargetTriple::platform)
+                    .as_ref()
+                    .unwrap_or(interpreter.platform())
+                    .os(),
+            )
+        })
+        .transpose()?;
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_locations(&index_locations)
+        .index_strategy(index_strategy)
+        .torch_backend(torch_backend.clone())
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options = build_options.combine(no_binary, no_build);
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&*tags), &hasher, &build_options)
+    };
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = if no_build_isolation {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        environment = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&environment, &no_build_isolation_package)
+    };
+
+    let build_constraints = Constraints::from_requirements(
+        build_constraints
+            .iter()
+            .map(|constraint| constraint.requirement.clone()),
+    );
+
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        &build_system_overrides,
+        state,
+        index_strategy,
+        &config_settings,
+        &config_settings_package,
+        build_isolation,
+        link_mode,
+        &build_options,
+        &build_hasher,
+        exclude_newer,
+        sources,
+        WorkspaceCache::default(),
+        concurrency,
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .fork_strategy(fork_strategy)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .torch_backend(torch_backend)
+        .build_options(build_options.clone())
+        .build();
+
+    // Resolve the requirements.
+    let resolution = match operations::resolve(
+        requirements,
+        constraints,
+        overrides,
+        source_trees,
+        project,
+        BTreeSet::default(),
+        &ExtrasSpecification::default(),
+        &groups,
+        Vec::new(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::None,
+        &upgrade,
+        Some(&*tags),
+        resolver_env,
+        python_requirement,
+        interpreter.markers(),
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        &top_level_index,
+        &build_dispatch,
+        concurrency,
+        options,
+        Box::new(DefaultResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok(resolution) => Resolution::from(resolution),
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    // Determine the files to download.
+    let mut artifacts = Vec::new();
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        if let Some(artifact) = Artifact::from_dist(dist, kind)? {
+            artifacts.push(artifact);
+        } else {
+            warn_user!(
+                "`{}` is a local directory or Git source and cannot be downloaded; skipping",
+                dist.name()
+            );
+        }
+    }
+
+    fs_err::tokio::create_dir_all(dest).await?;
+
+    // Download (or build) the distributions.
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+    let start = std::time::Instant::now();
+    let mut downloaded = futures::stream::iter(artifacts)
+        .map(|artifact| artifact.save(&database, &hasher, &tags, dest))
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+    downloaded.sort_unstable();
+
+    for filename in &downloaded {
+        debug!("Saved: {}", dest.join(filename).user_display());
+    }
+
+    let s = if downloaded.len() == 1 { "" } else { "s" };
+    let (verb, noun) = match kind {
+        DownloadKind::Distributions => ("Downloaded", "file"),
+        DownloadKind::Wheels => ("Saved", "wheel"),
+    };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "{verb} {} to {} {}",
+            format!("{} {noun}{s}", downloaded.len()).bold(),
+            dest.user_display().cyan(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// A distribution file that can be written to the destination directory.
+#[derive(Debug)]
+enum Artifact {
+    /// A wheel or source distribution archive, which is written as-is.
+    Archive {
+        dist: Dist,
+        url: DisplaySafeUrl,
+        index: Option<IndexUrl>,
+        filename: String,
+        /// The hashes reported by the index, if any.
+        hashes: Vec<HashDigest>,
+    },
+    /// A source distribution from which a wheel must be built.
+    Build(SourceDist),
+}
+
+impl Artifact {
+    /// Determine the file backing a resolved [`Dist`], if any.
+    ///
+    /// When downloading distributions, returns `None` for Git and local directory sources, which
+    /// have no single file to download.
+    fn from_dist(dist: &Dist, kind: DownloadKind) -> Result<Option<Self>> {
+        if let (DownloadKind::Wheels, Dist::Source(sdist)) = (kind, dist) {
+            return Ok(Some(Self::Build(sdist.clone())));
+        }
+
+        let (url, index, filename, hashes) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (
+                    wheel.file.url.to_url()?,
+                    Some(wheel.index.clone()),
+                    wheel.file.filename.to_string(),
+                    wheel.file.hashes.to_vec(),
+                )
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => (
+                (*wheel.location).clone(),
+                None,
+                wheel.filename.to_string(),
+                Vec::new(),
+            ),
+            Dist::Built(BuiltDist::Path(wheel)) => (
+                DisplaySafeUrl::from_file_path(&wheel.install_path)
+                    .map_err(|()| anyhow!("Invalid path: {}", wheel.install_path.user_display()))?,
+                None,
+                wheel.filename.to_string(),
+                Vec::new(),
+            ),
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                sdist.file.url.to_url()?,
+                Some(sdist.index.clone()),
+                sdist.file.filename.to_string(),
+                sdist.file.hashes.to_vec(),
+            ),
+            Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                let filename = sdist
+                    .location
+                    .path_segments()
+                    .and_then(|mut segments| segments.next_back())
+                    .filter(|segment| !segment.is_empty())
+                    .map(ToString::to_string)
+                    .ok_or_else(|| anyhow!("Unable to determine filename for: {}", sdist.url))?;
+                ((*sdist.location).clone(), None, filename, Vec::new())
+            }
+            Dist::Source(SourceDist::Path(sdist)) => {
+                let filename = sdist
+                    .install_path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .map(ToString::to_string)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Unable to determine filename for: {}",
+                            sdist.install_path.user_display()
+                        )
+                    })?;
+                (
+                    DisplaySafeUrl::from_file_path(&sdist.install_path).map_err(|()| {
+                        anyhow!("Invalid path: {}", sdist.install_path.user_display())
+                    })?,
+                    None,
+                    filename,
+                    Vec::new(),
+                )
+            }
+            Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_)) => return Ok(None),
+        };
+        Ok(Some(Self::Archive {
+            dist: dist.clone(),
+            url,
+            index,
+            filename,
+            hashes,
+        }))
+    }
+
+    /// Write the artifact to the destination directory, returning its filename.
+    ///
+    /// Files that already exist in the destination directory are left untouched.
+    async fn save(
+        self,
+        database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+        hasher: &HashStrategy,
+        tags: &Tags,
+        dest: &Path,
+    ) -> Result<String> {
+        let (path, filename) = match self {
+            Self::Archive {
+                dist,
+                url,
+                index,
+                filename,
+                hashes,
+            } => {
+                let target = dest.join(&filename);
+                if target.exists() {
+                    debug!("File already exists: {}", target.user_display());
+                    return Ok(filename);
+                }
+
+                // Unless hash-checking is enabled, validate any hashes provided by the index.
+                let policy = match hasher.get(&dist) {
+                    HashPolicy::None if !hashes.is_empty() => HashPolicy::Validate(&hashes),
+                    policy => policy,
+                };
+                let (path, _) = database
+                    .download_archive(url, index.as_ref(), dist.name(), &filename, policy)
+                    .await
+                    .with_context(|| format!("Failed to download `{dist}`"))?;
+                (path, filename)
+            }
+            Self::Build(sdist) => {
+                let (filename, path) = database
+                    .build_wheel_archive(&sdist, tags, hasher.get(&sdist))
+                    .await
+                    .with_context(|| format!("Failed to build `{sdist}`"))?;
+                (path, filename.to_string())
+            }
+        };
+
+        let target = dest.join(&filename);
+        if target.exists() {
+            debug!("File already exists: {}", target.user_display());
+            return Ok(filename);
+        }
+        fs_err::tokio::copy(&path, &target).await?;
+
+        Ok(filename)
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Context;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
 use tracing::{Level, debug, enabled, warn};
 
 use uv_cache::Cache;
@@ -16,8 +18,8 @@ use uv_configuration::{
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    DependencyMetadata, Index, IndexLocations, NameRequirementSpecification, Origin, Requirement,
-    Resolution, UnresolvedRequirementSpecification,
+    BuildSystemOverrides, DependencyMetadata, Index, IndexLocations, NameRequirementSpecification,
+    Origin, Requirement, Resolution, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
@@ -39,6 +41,7 @@ use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 
+use crate::commands::pip::install_report::{self, InstallReport};
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
@@ -67,6 +70,7 @@ pub(crate) async fn pip_install(
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
     dependency_metadata: DependencyMetadata,
+    build_system_overrides: BuildSystemOverrides,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     reinstall: Reinstall,
@@ -94,15 +98,18 @@ pub(crate) async fn pip_install(
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    report: Option<&Path>,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -129,6 +136,7 @@ pub(crate) async fn pip_install(
         extras,
         Some(groups),
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -163,7 +171,7 @@ pub(crate) async fn pip_install(
 
     // Read build constraints.
     let build_constraints: Vec<NameRequirementSpecification> =
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, &cache)
             .await?
             .into_iter()
             .chain(
@@ -287,6 +295,13 @@ pub(crate) async fn pip_install(
                     writeln!(printer.stderr(), "Would make no changes")?;
                 }
 
+                // Write an empty report, as there's nothing to install.
+                if let Some(report) = report {
+                    InstallReport::empty(&marker_env)
+                        .write(report, printer)
+                        .await?;
+                }
+
                 return Ok(ExitStatus::Success);
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -421,6 +436,7 @@ pub(crate) async fn pip_install(
         &index_locations,
         &flat_index,
         &dependency_metadata,
+        &build_system_overrides,
         state.clone(),
         index_strategy,
         config_settings,
@@ -436,6 +452,7 @@ pub(crate) async fn pip_install(
         preview,
     );
 
+    let mut requested_extras = FxHashMap::default();
     let (resolution, hasher) = if let Some(pylock) = pylock {
         // Read the `pylock.toml` from disk, and deserialize it from TOML.
         let install_path = std::path::absolute(&pylock)?;
@@ -488,6 +505,11 @@ pub(crate) async fn pip_install(
         // When resolving, don't take any external preferences into account.
         let preferences = Vec::default();
 
+        // Record the extras requested for each package, for the installation report.
+        if report.is_some() {
+            requested_extras = install_report::requested_extras(&requirements);
+        }
+
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
@@ -541,7 +563,7 @@ pub(crate) async fn pip_install(
     };
 
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         modifications,
@@ -567,12 +589,25 @@ pub(crate) async fn pip_install(
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => changelog,
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
+    };
+
+    // Write the installation report.
+    if let Some(report) = report {
+        InstallReport::from_resolution(
+            &resolution,
+            state.index(),
+            &requested_extras,
+            &changelog,
+            &marker_env,
+        )?
+        .write(report, printer)
+        .await?;
     }
 
     // Notify the user of any resolution diagnostics.
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use petgraph::Direction;
+use petgraph::visit::EdgeRef;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_distribution_types::{
+    BuiltDist, Dist, DistributionMetadata, Name, Node, Resolution, ResolvedDist, SourceDist,
+    UnresolvedRequirement, UnresolvedRequirementSpecification,
+};
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::{MarkerEnvironment, VerbatimUrl};
+use uv_pypi_types::{ArchiveInfo, DirectUrl, HashDigest};
+use uv_resolver::{InMemoryIndex, MetadataResponse};
+
+use crate::commands::pip::operations::Changelog;
+use crate::printer::Printer;
+
+/// A report of the distributions selected for installation, in the format used by
+/// `pip install --report`.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/installation-report/>
+#[derive(Debug, Serialize)]
+pub(crate) struct InstallReport {
+    /// The version of the report format.
+    version: &'static str,
+    /// The distributions that were (or, in a dry run, would be) installed.
+    install: Vec<InstallReportItem>,
+    /// The marker environment against which the requirements were resolved.
+    environment: MarkerEnvironment,
+}
+
+#[derive(Debug, Serialize)]
+struct InstallReportItem {
+    /// The location the distribution was fetched from, in the direct URL data format.
+    download_info: DirectUrl,
+    /// Whether the distribution was requested via a direct URL or path.
+    is_direct: bool,
+    is_yanked: bool,
+    /// Whether the distribution was requested directly, rather than as a dependency.
+    requested: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested_extras: Vec<ExtraName>,
+    metadata: InstallReportMetadata,
+    /// Whether the distribution was installed from the cache, without downloading or building.
+    ///
+    /// Not part of pip's format.
+    cached: bool,
+    /// Whether the distribution was built from source, rather than installed from a wheel or a
+    /// previously built wheel in the cache.
+    ///
+    /// Not part of pip's format.
+    built: bool,
+}
+
+/// The core metadata of a distribution, in the JSON-compatible form described by PEP 566.
+#[derive(Debug, Serialize)]
+struct InstallReportMetadata {
+    name: PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<Version>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<VersionSpecifiers>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    provides_extra: Vec<ExtraName>,
+}
+
+impl InstallReport {
+    /// Create an [`InstallReport`] for the distributions in a [`Resolution`] that were (or, in a
+    /// dry run, would be) installed, as recorded in the [`Changelog`].
+    ///
+    /// Distributions that are already installed in the environment are omitted.
+    pub(crate) fn from_resolution(
+        resolution: &Resolution,
+        index: &InMemoryIndex,
+        requested_extras: &FxHashMap<PackageName, Vec<ExtraName>>,
+        changelog: &Changelog,
+        markers: &MarkerEnvironment,
+    ) -> Result<Self> {
+        let graph = resolution.graph();
+
+        let mut install = Vec::new();
+        for node in graph.node_indices() {
+            let Node::Dist {
+                dist: ResolvedDist::Installable { dist, .. },
+                hashes,
+                install: true,
+            } = &graph[node]
+            else {
+                continue;
+            };
+
+            // Skip any distributions that are already installed.
+            let cached = changelog.cached.contains(dist.name());
+            if !cached && !changelog.fetched.contains(dist.name()) {
+                continue;
+            }
+
+            // A distribution is requested if it's a direct dependency of the root.
+            let requested = graph
+                .edges_directed(node, Direction::Incoming)
+                .any(|edge| matches!(graph[edge.source()], Node::Root));
+
+            // Use the hashes from the resolution, falling back to those reported by the registry.
+            let hashes = if hashes.is_empty() {
+                dist.file()
+                    .map(|file| file.hashes.as_slice())
+                    .unwrap_or_default()
+            } else {
+                hashes.as_slice()
+            };
+
+            let metadata = match index.distributions().get(&dist.version_id()).as_deref() {
+                Some(MetadataResponse::Found(archive)) => InstallReportMetadata {
+                    name: archive.metadata.name.clone(),
+                    version: Some(archive.metadata.version.clone()),
+                    requires_dist: archive
+                        .metadata
+                        .requires_dist
+                        .iter()
+                        .cloned()
+                        .map(|requirement| {
+                            uv_pep508::Requirement::<VerbatimUrl>::from(requirement).to_string()
+                        })
+                        .collect(),
+                    requires_python: archive.metadata.requires_python.clone(),
+                    provides_extra: archive.metadata.provides_extras.to_vec(),
+                },
+                _ => InstallReportMetadata {
+                    name: dist.name().clone(),
+                    version: dist.version().cloned(),
+                    requires_dist: Vec::new(),
+                    requires_python: None,
+                    provides_extra: Vec::new(),
+                },
+            };
+
+            install.push(InstallReportItem {
+                download_info: download_info(dist, hashes)?,
+                is_direct: !matches!(
+                    dist.as_ref(),
+                    Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+                ),
+                is_yanked: dist
+                    .file()
+                    .and_then(|file| file.yanked.as_ref())
+                    .is_some_and(|yanked| yanked.is_yanked()),
+                requested,
+                requested_extras: requested_extras
+                    .get(dist.name())
+                    .cloned()
+                    .unwrap_or_default(),
+                metadata,
+                cached,
+                built: changelog.built.contains(dist.name()),
+            });
+        }
+
+        install.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+        Ok(Self {
+            version: "1",
+            install,
+            environment: markers.clone(),
+        })
+    }
+
+    /// Create an empty [`InstallReport`], for when no distributions need to be installed.
+    pub(crate) fn empty(markers: &MarkerEnvironment) -> Self {
+        Self {
+            version: "1",
+            install: Vec::new(),
+            environment: markers.clone(),
+        }
+    }
+
+    /// Write the report to the given path, or to stdout if the path is `-`.
+    pub(crate) async fn write(&self, path: &Path, printer: Printer) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        if path == Path::new("-") {
+            writeln!(printer.stdout(), "{contents}")?;
+        } else {
+            fs_err::tokio::write(path, format!("{contents}\n")).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Collect the extras requested for each named requirement.
+pub(crate) fn requested_extras(
+    requirements: &[UnresolvedRequirementSpecification],
+) -> FxHashMap<PackageName, Vec<ExtraName>> {
+    let mut extras: FxHashMap<PackageName, Vec<ExtraName>> = FxHashMap::default();
+    for spec in requirements {
+        let UnresolvedRequirement::Named(requirement) = &spec.requirement else {
+            continue;
+        };
+        let entry = extras.entry(requirement.name.clone()).or_default();
+        for extra in &requirement.extras {
+            if !entry.contains(extra) {
+                entry.push(extra.clone());
+            }
+        }
+    }
+    extras
+}
+
+/// Return the [`DirectUrl`] describing where the given distribution was fetched from.
+fn download_info(dist: &Dist, hashes: &[HashDigest]) -> Result<DirectUrl> {
+    let mut direct_url = match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => DirectUrl::ArchiveUrl {
+            url: wheels.best_wheel().file.url.to_url()?.to_string(),
+            archive_info: ArchiveInfo {
+                hash: None,
+                hashes: None,
+            },
+            subdirectory: None,
+        },
+        Dist::Source(SourceDist::Registry(sdist)) => DirectUrl::ArchiveUrl {
+            url: sdist.file.url.to_url()?.to_string(),
+            archive_info: ArchiveInfo {
+                hash: None,
+                hashes: None,
+            },
+            subdirectory: None,
+        },
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => DirectUrl::from(&wheel.parsed_url()),
+        Dist::Built(BuiltDist::Path(wheel)) => DirectUrl::from(&wheel.parsed_url()),
+        Dist::Source(SourceDist::DirectUrl(sdist)) => DirectUrl::from(&sdist.parsed_url()),
+        Dist::Source(SourceDist::Git(sdist)) => DirectUrl::from(&sdist.parsed_url()),
+        Dist::Source(SourceDist::Path(sdist)) => DirectUrl::from(&sdist.parsed_url()),
+        Dist::Source(SourceDist::Directory(sdist)) => DirectUrl::from(&sdist.parsed_url()),
+    };
+
+    // Attach the archive hashes, if known.
+    if let DirectUrl::ArchiveUrl { archive_info, .. } = &mut direct_url {
+        if !hashes.is_empty() {
+            let hashes = hashes
+                .iter()
+                .map(|digest| (digest.algorithm.to_string(), digest.digest.to_string()))
+                .collect::<BTreeMap<_, _>>();
+            archive_info.hash = hashes
+                .get("sha256")
+                .map(|digest| format!("sha256={digest}"));
+            archive_info.hashes = Some(hashes);
+        }
+    }
+
+    Ok(direct_url)
+}
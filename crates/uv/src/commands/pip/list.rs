@@ -87,9 +87,11 @@ pub(crate) async fn pip_list(
         let capabilities = IndexCapabilities::default();
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .keyring(keyring_provider)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -340,11 +342,11 @@ struct Entry {
 
 /// A column in a table.
 #[derive(Debug)]
-struct Column {
+pub(crate) struct Column {
     /// The header of the column.
-    header: String,
+    pub(crate) header: String,
     /// The rows of the column.
-    rows: Vec<String>,
+    pub(crate) rows: Vec<String>,
 }
 
 impl<'a> Column {
@@ -357,7 +359,7 @@ impl<'a> Column {
     }
 
     /// Return an iterator of the column, with the header and rows formatted to the maximum width.
-    fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
+    pub(crate) fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
         let max_width = self.max_width();
         let header = vec![
             format!("{0:width$}", self.header, width = max_width),
@@ -374,7 +376,7 @@ impl<'a> Column {
 ///
 /// A combination of [`itertools::multizip`] and [`itertools::izip`].
 #[derive(Debug)]
-struct MultiZip<T>(Vec<T>);
+pub(crate) struct MultiZip<T>(pub(crate) Vec<T>);
 
 impl<T> Iterator for MultiZip<T>
 where
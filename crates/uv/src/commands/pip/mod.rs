@@ -7,8 +7,10 @@ use uv_python::{Interpreter, PythonVersion};
 
 pub(crate) mod check;
 pub(crate) mod compile;
+pub(crate) mod download;
 pub(crate) mod freeze;
 pub(crate) mod install;
+pub(crate) mod install_report;
 pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
@@ -50,6 +52,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -58,6 +61,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -66,6 +70,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     })
@@ -85,6 +90,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -93,6 +99,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -101,6 +108,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     };
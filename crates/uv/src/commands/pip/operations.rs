@@ -18,8 +18,9 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    CachedDist, Diagnostic, Dist, InstalledDist, LocalDist, NameRequirementSpecification,
+    RemoteSource, Requirement, ResolutionDiagnostic, ResolvedDist, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
 };
 use uv_distribution_types::{
     DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
@@ -46,7 +47,7 @@ use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
-use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode};
+use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Consolidate the requirements for an installation.
@@ -57,6 +58,7 @@ pub(crate) async fn read_requirements(
     extras: &ExtrasSpecification,
     groups: Option<&GroupsSpecification>,
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<RequirementsSpecification, Error> {
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
@@ -82,6 +84,7 @@ pub(crate) async fn read_requirements(
         overrides,
         groups,
         client_builder,
+        cache,
     )
     .await?)
 }
@@ -90,9 +93,10 @@ pub(crate) async fn read_requirements(
 pub(crate) async fn read_constraints(
     constraints: &[RequirementsSource],
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<Vec<NameRequirementSpecification>, Error> {
     Ok(
-        RequirementsSpecification::from_sources(&[], constraints, &[], None, client_builder)
+        RequirementsSpecification::from_sources(&[], constraints, &[], None, client_builder, cache)
             .await?
             .constraints,
     )
@@ -385,6 +389,12 @@ pub(crate) struct Changelog {
     pub(crate) uninstalled: HashSet<LocalDist>,
     /// The distributions that were reinstalled.
     pub(crate) reinstalled: HashSet<LocalDist>,
+    /// The packages that were (or, in a dry run, would be) installed directly from the cache.
+    pub(crate) cached: HashSet<PackageName>,
+    /// The packages that were (or, in a dry run, would be) downloaded or built.
+    pub(crate) fetched: HashSet<PackageName>,
+    /// The packages that were (or, in a dry run, would be) built from source.
+    pub(crate) built: HashSet<PackageName>,
 }
 
 impl Changelog {
@@ -403,6 +413,9 @@ impl Changelog {
             installed,
             uninstalled,
             reinstalled,
+            cached: HashSet::default(),
+            fetched: HashSet::default(),
+            built: HashSet::default(),
         }
     }
 
@@ -412,6 +425,9 @@ impl Changelog {
             installed: installed.into_iter().map(LocalDist::from).collect(),
             uninstalled: HashSet::default(),
             reinstalled: HashSet::default(),
+            cached: HashSet::default(),
+            fetched: HashSet::default(),
+            built: HashSet::default(),
         }
     }
 
@@ -474,9 +490,35 @@ pub(crate) async fn install(
         )
         .context("Failed to determine installation plan")?;
 
+    // Track the distributions that can be installed directly from the cache.
+    let cached_packages = plan
+        .cached
+        .iter()
+        .map(|dist| dist.name().clone())
+        .collect::<HashSet<_>>();
+
+    // Track the distributions that need to be downloaded or built.
+    let fetched_packages = plan
+        .remote
+        .iter()
+        .map(|dist| dist.name().clone())
+        .collect::<HashSet<_>>();
+
     if dry_run.enabled() {
+        // In a dry run, assume that any remote source distributions would need to be built.
+        let built = plan
+            .remote
+            .iter()
+            .filter(|dist| matches!(dist.as_ref(), Dist::Source(_)))
+            .map(|dist| dist.name().clone())
+            .collect();
         report_dry_run(dry_run, resolution, plan, modifications, start, printer)?;
-        return Ok(Changelog::default());
+        return Ok(Changelog {
+            cached: cached_packages,
+            fetched: fetched_packages,
+            built,
+            ..Changelog::default()
+        });
     }
 
     let Plan {
@@ -486,6 +528,24 @@ pub(crate) async fn install(
         extraneous,
     } = plan;
 
+    // Record any distributions that will be installed directly from the cache.
+    if !cached.is_empty() {
+        let dists = resolution
+            .distributions()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable { dist, .. } => Some((dist.name(), dist.as_ref())),
+                ResolvedDist::Installed { .. } => None,
+            })
+            .collect::<BTreeMap<_, _>>();
+        for dist in &cached {
+            match dists.get(dist.name()) {
+                Some(Dist::Source(_)) => cache.stats().record_cached_build(),
+                Some(Dist::Built(built)) => cache.stats().record_cached_wheel(built.size()),
+                None => cache.stats().record_cached_wheel(None),
+            }
+        }
+    }
+
     // If we're in `install` mode, ignore any extraneous distributions.
     let extraneous = match modifications {
         Modifications::Sufficient => vec![],
@@ -504,11 +564,12 @@ pub(crate) async fn install(
     }
 
     // Download, build, and unzip any missing distributions.
-    let wheels = if remote.is_empty() {
-        vec![]
+    let (wheels, built_packages) = if remote.is_empty() {
+        (vec![], HashSet::default())
     } else {
         let start = std::time::Instant::now();
 
+        let reporter = Arc::new(PrepareReporter::from(printer).with_length(remote.len() as u64));
         let preparer = Preparer::new(
             cache,
             tags,
@@ -516,9 +577,7 @@ pub(crate) async fn install(
             build_options,
             DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
         )
-        .with_reporter(Arc::new(
-            PrepareReporter::from(printer).with_length(remote.len() as u64),
-        ));
+        .with_reporter(reporter.clone());
 
         let wheels = preparer
             .prepare(remote.clone(), in_flight, resolution)
@@ -526,7 +585,7 @@ pub(crate) async fn install(
 
         logger.on_prepare(wheels.len(), start, printer)?;
 
-        wheels
+        (wheels, reporter.built().into_iter().collect())
     };
 
     // Remove any upgraded or extraneous installations.
@@ -592,8 +651,13 @@ pub(crate) async fn install(
         compile_bytecode(venv, &concurrency, cache, printer).await?;
     }
 
+    report_cache_summary(cache);
+
     // Construct a summary of the changes made to the environment.
-    let changelog = Changelog::new(installs, uninstalls);
+    let mut changelog = Changelog::new(installs, uninstalls);
+    changelog.cached = cached_packages;
+    changelog.fetched = fetched_packages;
+    changelog.built = built_packages;
 
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer)?;
@@ -601,6 +665,23 @@ pub(crate) async fn install(
     Ok(changelog)
 }
 
+/// Report the effectiveness of the cache over the course of the invocation, in verbose output.
+pub(crate) fn report_cache_summary(cache: &Cache) {
+    let summary = cache.stats().summary();
+    if summary.is_empty() {
+        return;
+    }
+    let (downloaded, downloaded_unit) = human_readable_bytes(summary.bytes_downloaded);
+    let (saved, saved_unit) = human_readable_bytes(summary.bytes_saved);
+    debug!(
+        "Cache summary: {} served from cache, {} downloaded ({downloaded:.1}{downloaded_unit}), {} built, {} avoided ({saved:.1}{saved_unit} saved)",
+        summary.wheels_from_cache,
+        summary.wheels_downloaded,
+        summary.builds,
+        summary.builds_avoided,
+    );
+}
+
 /// Display a message about the interpreter that was selected for the operation.
 #[allow(clippy::result_large_err)]
 pub(crate) fn report_interpreter(
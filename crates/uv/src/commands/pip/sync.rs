@@ -14,7 +14,9 @@ use uv_configuration::{
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, Origin, Resolution};
+use uv_distribution_types::{
+    BuildSystemOverrides, DependencyMetadata, Index, IndexLocations, Origin, Resolution,
+};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::SitePackages;
@@ -59,6 +61,7 @@ pub(crate) async fn pip_sync(
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
     dependency_metadata: DependencyMetadata,
+    build_system_overrides: BuildSystemOverrides,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     allow_empty_requirements: bool,
@@ -86,9 +89,11 @@ pub(crate) async fn pip_sync(
     preview: Preview,
 ) -> Result<ExitStatus> {
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -122,6 +127,7 @@ pub(crate) async fn pip_sync(
         extras,
         Some(groups),
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -136,7 +142,7 @@ pub(crate) async fn pip_sync(
 
     // Read build constraints.
     let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+        operations::read_constraints(build_constraints, &client_builder, &cache).await?;
 
     // Validate that the requirements are non-empty.
     if !allow_empty_requirements {
@@ -356,6 +362,7 @@ pub(crate) async fn pip_sync(
         &index_locations,
         &flat_index,
         &dependency_metadata,
+        &build_system_overrides,
         state.clone(),
         index_strategy,
         config_settings,
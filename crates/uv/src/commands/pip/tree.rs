@@ -86,9 +86,11 @@ pub(crate) async fn pip_tree(
         let capabilities = IndexCapabilities::default();
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .keyring(keyring_provider)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
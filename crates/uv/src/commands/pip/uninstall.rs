@@ -3,15 +3,18 @@ use std::fmt::Write;
 use anyhow::Result;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DryRun, KeyringProviderType, Preview};
 use uv_distribution_types::Requirement;
-use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
+use uv_distribution_types::{InstalledDist, InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
-use uv_pep508::UnnamedRequirement;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep508::{MarkerEnvironment, UnnamedRequirement};
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::EnvironmentPreference;
 use uv_python::PythonRequest;
@@ -35,6 +38,7 @@ pub(crate) async fn pip_uninstall(
     cache: Cache,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
+    cascade: bool,
     dry_run: DryRun,
     printer: Printer,
     preview: Preview,
@@ -42,14 +46,17 @@ pub(crate) async fn pip_uninstall(
     let start = std::time::Instant::now();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     // Read all requirements from the provided sources.
-    let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
+    let spec =
+        RequirementsSpecification::from_simple_sources(sources, &client_builder, &cache).await?;
 
     // Detect the current Python interpreter.
     let environment = PythonEnvironment::find(
@@ -110,7 +117,7 @@ pub(crate) async fn pip_uninstall(
         .ok();
 
     // Index the current `site-packages` directory.
-    let site_packages = uv_installer::SitePackages::from_environment(&environment)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
 
     // Partition the requirements into named and unnamed requirements.
     let (named, unnamed): (Vec<Requirement>, Vec<UnnamedRequirement<VerbatimParsedUrl>>) = spec
@@ -204,6 +211,19 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    // If requested, include any dependencies that would be orphaned by the uninstall.
+    let distributions = if cascade {
+        let markers = environment.interpreter().resolver_marker_environment();
+        let orphans = orphaned_dependencies(&site_packages, &distributions, &markers);
+        let mut distributions = distributions;
+        distributions.extend(orphans);
+        distributions.sort_unstable_by_key(|dist| dist.install_path());
+        distributions.dedup_by_key(|dist| dist.install_path());
+        distributions
+    } else {
+        distributions
+    };
+
     // Uninstall each package.
     if !dry_run.enabled() {
         for distribution in &distributions {
@@ -256,3 +276,94 @@ pub(crate) async fn pip_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Packages that are never removed as orphaned dependencies, as in `pip-autoremove`.
+const CASCADE_EXCLUDES: &[&str] = &["pip", "setuptools"];
+
+/// Identify the installed distributions that would be orphaned by uninstalling the given
+/// distributions, i.e., those that are (transitively) required by an uninstalled distribution,
+/// but not by any distribution that would remain installed.
+fn orphaned_dependencies<'a>(
+    site_packages: &'a SitePackages,
+    distributions: &[&InstalledDist],
+    markers: &MarkerEnvironment,
+) -> Vec<&'a InstalledDist> {
+    // For each installed package, collect its unconditional dependencies, along with all of the
+    // dependencies it could require, including those behind any of its extras.
+    let mut requires: FxHashMap<&PackageName, (Vec<PackageName>, Vec<PackageName>)> =
+        FxHashMap::default();
+    for dist in site_packages.iter() {
+        let metadata = match dist.metadata() {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                debug!("Failed to read metadata for {dist}: {err}");
+                continue;
+            }
+        };
+        let (base, all) = requires.entry(dist.name()).or_default();
+        for requirement in &metadata.requires_dist {
+            if requirement.evaluate_markers(markers, &[]) {
+                base.push(requirement.name.clone());
+            }
+            if requirement.evaluate_markers(markers, &metadata.provides_extras) {
+                all.push(requirement.name.clone());
+            }
+        }
+    }
+
+    // Collect the transitive dependencies of the uninstalled packages as candidates for removal.
+    let uninstalled = distributions
+        .iter()
+        .map(|dist| dist.name())
+        .collect::<FxHashSet<_>>();
+    let mut candidates = FxHashSet::default();
+    let mut queue = uninstalled.iter().copied().collect::<Vec<_>>();
+    while let Some(name) = queue.pop() {
+        let Some((base, _)) = requires.get(name) else {
+            continue;
+        };
+        for dependency in base {
+            if uninstalled.contains(dependency)
+                || CASCADE_EXCLUDES
+                    .iter()
+                    .any(|exclude| dependency.as_ref() == *exclude)
+            {
+                continue;
+            }
+            let Some((dependency, _)) = requires.get_key_value(dependency) else {
+                continue;
+            };
+            if candidates.insert(*dependency) {
+                queue.push(*dependency);
+            }
+        }
+    }
+
+    // Retain any candidate that's required by a package that will remain installed. Since
+    // retaining a candidate can cause its own dependencies to be retained, iterate until the set
+    // of candidates is stable.
+    loop {
+        let retained = candidates
+            .iter()
+            .copied()
+            .filter(|candidate| {
+                requires.iter().any(|(name, (_, all))| {
+                    !uninstalled.contains(name)
+                        && !candidates.contains(name)
+                        && all.contains(*candidate)
+                })
+            })
+            .collect::<Vec<_>>();
+        if retained.is_empty() {
+            break;
+        }
+        for name in retained {
+            candidates.remove(name);
+        }
+    }
+
+    site_packages
+        .iter()
+        .filter(|dist| candidates.contains(dist.name()))
+        .collect()
+}
@@ -179,9 +179,11 @@ pub(crate) async fn add(
         }
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
@@ -333,9 +335,11 @@ pub(crate) async fn add(
         .ok();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(settings.resolver.keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -350,6 +354,7 @@ pub(crate) async fn add(
         &[],
         None,
         &client_builder,
+        cache,
     )
     .await?;
 
@@ -436,6 +441,7 @@ pub(crate) async fn add(
                 &settings.resolver.index_locations,
                 &flat_index,
                 &settings.resolver.dependency_metadata,
+                &settings.resolver.build_system_overrides,
                 state.clone().into_inner(),
                 settings.resolver.index_strategy,
                 &settings.resolver.config_setting,
@@ -467,6 +473,35 @@ pub(crate) async fn add(
         requirements
     };
 
+    // Deduplicate the requirements, e.g., if a package is listed in multiple requirements files.
+    // If the same package is listed with different specifications, the last one wins, as it would
+    // when adding the requirements one at a time.
+    let requirements = {
+        let mut seen = FxHashMap::<(PackageName, MarkerTree), usize>::default();
+        let mut deduplicated: Vec<Requirement> = Vec::with_capacity(requirements.len());
+        for requirement in requirements {
+            match seen.entry((requirement.name.clone(), requirement.marker)) {
+                Entry::Occupied(entry) => {
+                    let existing = &mut deduplicated[*entry.get()];
+                    if *existing != requirement {
+                        warn_user_once!(
+                            "Requirement `{}` was provided multiple times; using `{}` instead of `{}`",
+                            requirement.name.cyan(),
+                            requirement.cyan(),
+                            existing.cyan(),
+                        );
+                        *existing = requirement;
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(deduplicated.len());
+                    deduplicated.push(requirement);
+                }
+            }
+        }
+        deduplicated
+    };
+
     // If any of the requirements are self-dependencies, bail.
     if matches!(dependency_type, DependencyType::Production) {
         if let AddTarget::Project(project, _) = &target {
@@ -506,21 +541,26 @@ pub(crate) async fn add(
         Some(workspace) => workspace,
         None => {
             // Check if we're in a project (not a script), and if any requirements are path
-            // dependencies within the workspace.
+            // dependencies within the workspace (and not excluded from it).
             if let AddTarget::Project(ref project, _) = target {
                 let workspace_root = project.workspace().install_path();
-                requirements.iter().any(|req| {
+                let mut within_workspace = false;
+                for req in &requirements {
                     if let RequirementSource::Directory { install_path, .. } = &req.source {
                         let absolute_path = if install_path.is_absolute() {
                             install_path.to_path_buf()
                         } else {
                             project.root().join(install_path)
                         };
-                        absolute_path.starts_with(workspace_root)
-                    } else {
-                        false
+                        if absolute_path.starts_with(workspace_root)
+                            && !project.workspace().excludes(&absolute_path)?
+                        {
+                            within_workspace = true;
+                            break;
+                        }
                     }
-                })
+                }
+                within_workspace
             } else {
                 false
             }
@@ -557,8 +597,31 @@ pub(crate) async fn add(
                     continue;
                 }
 
-                // If the project is already a member of the workspace, skip it.
-                if project.workspace().includes(&absolute_path)? {
+                // If the path is excluded from the workspace, add it as a path dependency instead,
+                // unless `--workspace` was provided explicitly.
+                if project.workspace().excludes(&absolute_path)? {
+                    if workspace == Some(true) {
+                        bail!(
+                            "Cannot add `{}` to the workspace, since it's excluded by `tool.uv.workspace.exclude`",
+                            absolute_path.user_display()
+                        );
+                    }
+                    debug!(
+                        "Path is excluded from the workspace: `{}`",
+                        absolute_path.user_display()
+                    );
+                    continue;
+                }
+
+                // If the project is already a member of the workspace, e.g., as a member of a
+                // nested workspace, skip it.
+                if project.workspace().includes(&absolute_path)?
+                    || project
+                        .workspace()
+                        .packages()
+                        .values()
+                        .any(|member| *member.root() == absolute_path)
+                {
                     continue;
                 }
 
@@ -760,6 +823,16 @@ fn edits(
         ex.dedup();
         requirement.extras = ex.into_boxed_slice();
 
+        // Respect editable requirements (e.g., `-e ./path` in a `requirements.txt`), unless
+        // overridden on the command-line.
+        let editable = editable.or(match &requirement.source {
+            RequirementSource::Directory {
+                editable: Some(true),
+                ..
+            } => Some(true),
+            _ => None,
+        });
+
         let (requirement, source) = match target {
             AddTarget::Script(_, _) | AddTarget::Project(_, _) if raw => {
                 (uv_pep508::Requirement::from(requirement), None)
@@ -1281,6 +1354,7 @@ impl PythonTarget {
 }
 
 /// Represents the destination where dependencies are added, either to a project or a script.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub(super) enum AddTarget {
     /// A PEP 723 script, with inline metadata.
@@ -1381,6 +1455,7 @@ impl AddTarget {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 enum AddTargetSnapshot {
     Script(Pep723Script, Option<Vec<u8>>),
@@ -0,0 +1,384 @@
+//! Run `uv run` inside a container, i.e., `uv run --container`.
+//!
+//! We start a container from the image with a container engine (`docker` or `podman`), mount the
+//! project at the same path, and run `uv run` with the same arguments inside it, such that the
+//! locked environment is synced against the image's platform. The environment, the cache, and any
+//! managed Python installations are stored in a cache directory that's specific to the project and
+//! image, and mounted into the container, so subsequent runs only need to re-sync what changed.
+//!
+//! The container runs as the current user, such that files written to the project (e.g., the
+//! lockfile) aren't owned by `root`.
+
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_fs::{CWD, Simplified};
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::child::run_to_completion;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The container engines to try, in order of preference.
+const ENGINES: &[&str] = &["docker", "podman"];
+
+/// The path at which the environment, cache, and Python installations are mounted.
+const STATE_PATH: &str = "/uv";
+
+/// The path at which the host's uv executable is mounted, if it can run in the container.
+///
+/// This is deliberately not on the `PATH`, such that we can fall back to the image's `uv`.
+const HOST_UV_PATH: &str = "/opt/uv-host/uv";
+
+/// The path at which the host's netrc file is mounted, if any.
+const NETRC_PATH: &str = "/opt/uv-host/netrc";
+
+/// The environment variables that are set for the container, and so aren't forwarded from the
+/// host.
+const OVERRIDDEN_ENV_VARS: &[&str] = &[
+    EnvVars::UV_PROJECT_ENVIRONMENT,
+    EnvVars::UV_CACHE_DIR,
+    EnvVars::UV_PYTHON_INSTALL_DIR,
+];
+
+/// Run `uv run` with the current arguments inside a container created from the given image.
+pub(crate) async fn run(
+    image: &str,
+    project_dir: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if cfg!(windows) {
+        bail!("`--container` is not supported on Windows");
+    }
+
+    let engine = find_engine()?;
+
+    // Mount the workspace root, such that all members (and the lockfile) are available.
+    let root = match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(project) => project.workspace().install_path().clone(),
+        Err(err) => {
+            debug!("No project found, mounting the project directory: {err}");
+            std::path::absolute(project_dir)?
+        }
+    };
+    let workdir = if CWD.starts_with(&root) {
+        CWD.to_path_buf()
+    } else {
+        root.clone()
+    };
+
+    // Store the state in the cache, rather than in a volume, such that it's owned by the current
+    // user.
+    let state = cache
+        .bucket(CacheBucket::Containers)
+        .join(cache_digest(&(&root, image)));
+    fs_err::create_dir_all(&state)?;
+
+    debug!(
+        "Running in container from `{image}` with `{}`, mounting: `{}`",
+        engine.user_display(),
+        root.user_display()
+    );
+
+    let mut command = Command::new(&engine);
+    command.arg("run").arg("--rm").arg("--interactive");
+    if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
+        command.arg("--tty");
+    }
+    command.args(user_args(&engine));
+    command
+        .arg("--volume")
+        .arg(bind_mount(&root, &root))
+        .arg("--volume")
+        .arg(bind_mount(&state, Path::new(STATE_PATH)))
+        .arg("--workdir")
+        .arg(&workdir)
+        .arg("--env")
+        .arg(format!("{}=1", EnvVars::UV_INTERNAL__CONTAINERIZED))
+        .arg("--env")
+        .arg(format!(
+            "{}={STATE_PATH}/environment",
+            EnvVars::UV_PROJECT_ENVIRONMENT
+        ))
+        .arg("--env")
+        .arg(format!("{}={STATE_PATH}/cache", EnvVars::UV_CACHE_DIR))
+        .arg("--env")
+        .arg(format!(
+            "{}={STATE_PATH}/python",
+            EnvVars::UV_PYTHON_INSTALL_DIR
+        ))
+        // The current user may not have a home directory in the image.
+        .arg("--env")
+        .arg(format!("{}={STATE_PATH}/home", EnvVars::HOME));
+
+    // Forward uv's environment variables, including any index credentials. The values are read by
+    // the container engine, such that they don't show up in its arguments.
+    for name in forwarded_env_vars(std::env::vars_os().map(|(name, _)| name)) {
+        command.arg("--env").arg(name);
+    }
+
+    // Forward the netrc file, if any.
+    if let Some(netrc) = find_netrc() {
+        debug!("Mounting netrc file: `{}`", netrc.user_display());
+        let mut mount = bind_mount(&netrc, Path::new(NETRC_PATH));
+        mount.push(":ro");
+        command
+            .arg("--volume")
+            .arg(mount)
+            .arg("--env")
+            .arg(format!("{}={NETRC_PATH}", EnvVars::NETRC));
+    }
+
+    // Use the running uv executable if it can run in the container, rather than requiring the image
+    // to provide it.
+    let host_uv = if cfg!(target_os = "linux") {
+        match image_platform(&engine, image).await {
+            Some(platform) if platform == host_platform() => true,
+            Some(platform) => {
+                debug!(
+                    "The image platform (`{platform}`) doesn't match the host platform (`{}`), using the image's `uv`",
+                    host_platform()
+                );
+                false
+            }
+            None => {
+                debug!("Failed to determine the platform of `{image}`, using the image's `uv`");
+                false
+            }
+        }
+    } else {
+        false
+    };
+    if host_uv {
+        let executable = std::env::current_exe()?;
+        let mut mount = bind_mount(&executable, Path::new(HOST_UV_PATH));
+        mount.push(":ro");
+        command.arg("--volume").arg(mount).arg(image);
+        if cfg!(target_env = "musl") {
+            // Static executables run on any Linux image.
+            command.arg(HOST_UV_PATH);
+        } else {
+            // Dynamically linked executables fail to run on images with another libc (e.g., musl
+            // on Alpine), in which case we fall back to the image's `uv`.
+            command
+                .arg("/bin/sh")
+                .arg("-c")
+                .arg(r#"if "$0" --version >/dev/null 2>&1; then exec "$0" "$@"; fi; exec uv "$@""#)
+                .arg(HOST_UV_PATH);
+        }
+    } else {
+        command.arg(image).arg("uv");
+    }
+    command.args(std::env::args_os().skip(1));
+
+    let handle = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn `{}`", engine.user_display()))?;
+    let status = run_to_completion(handle).await?;
+
+    // Container engines exit with 127 if the command wasn't found, e.g., if the image doesn't
+    // provide `uv`.
+    if matches!(status, ExitStatus::External(127)) && !host_uv {
+        writeln!(
+            printer.stderr(),
+            "{}{} The image `{image}` must provide `uv` to be used with `--container`",
+            "hint".bold().cyan(),
+            ":".bold(),
+        )?;
+    }
+    Ok(status)
+}
+
+/// Find the container engine to use.
+fn find_engine() -> Result<PathBuf> {
+    if let Some(engine) = std::env::var_os(EnvVars::UV_CONTAINER_ENGINE) {
+        return which::which(&engine).with_context(|| {
+            format!(
+                "Failed to find container engine `{}` (from `{}`)",
+                engine.to_string_lossy(),
+                EnvVars::UV_CONTAINER_ENGINE
+            )
+        });
+    }
+    ENGINES
+        .iter()
+        .find_map(|engine| which::which(engine).ok())
+        .with_context(|| {
+            format!(
+                "`--container` requires a container engine, but neither `docker` nor `podman` was found (set `{}` to use another engine)",
+                EnvVars::UV_CONTAINER_ENGINE
+            )
+        })
+}
+
+/// Returns the arguments to run the container as the current user.
+///
+/// Rootless Podman maps the container's users to subordinate IDs on the host, so the current user
+/// is kept via its user namespace instead.
+fn user_args(engine: &Path) -> Vec<OsString> {
+    if engine.file_stem() == Some(OsStr::new("podman")) {
+        return vec![OsString::from("--userns=keep-id")];
+    }
+    #[cfg(unix)]
+    {
+        vec![
+            OsString::from("--user"),
+            OsString::from(format!(
+                "{}:{}",
+                nix::unistd::getuid(),
+                nix::unistd::getgid()
+            )),
+        ]
+    }
+    #[cfg(not(unix))]
+    {
+        Vec::new()
+    }
+}
+
+/// Returns the names of the environment variables to forward into the container, i.e., uv's
+/// environment variables, except for those that are set for the container.
+fn forwarded_env_vars(names: impl Iterator<Item = OsString>) -> Vec<OsString> {
+    let mut names = names
+        .filter(|name| {
+            name.to_str().is_some_and(|name| {
+                name.starts_with("UV_")
+                    && !name.starts_with("UV_INTERNAL__")
+                    && !OVERRIDDEN_ENV_VARS.contains(&name)
+            })
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Returns the path to the netrc file, if it exists.
+fn find_netrc() -> Option<PathBuf> {
+    let path = std::env::var_os(EnvVars::NETRC)
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os(EnvVars::HOME).map(|home| PathBuf::from(home).join(".netrc"))
+        })?;
+    path.is_file().then_some(path)
+}
+
+/// Returns the platform of the image, as `<os>/<architecture>`, pulling the image if necessary.
+async fn image_platform(engine: &Path, image: &str) -> Option<String> {
+    async fn inspect(engine: &Path, image: &str) -> Option<String> {
+        let output = Command::new(engine)
+            .args([
+                "image",
+                "inspect",
+                "--format",
+                "{{.Os}}/{{.Architecture}}",
+                image,
+            ])
+            .output()
+            .await
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    if let Some(platform) = inspect(engine, image).await {
+        return Some(platform);
+    }
+    debug!("Pulling `{image}`");
+    let output = Command::new(engine)
+        .args(["pull", "--quiet", image])
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    inspect(engine, image).await
+}
+
+/// Returns the platform of the host, in the format used by container engines.
+fn host_platform() -> String {
+    format!("linux/{}", engine_arch(std::env::consts::ARCH))
+}
+
+/// Returns the name that container engines use for the given architecture.
+fn engine_arch(arch: &str) -> &str {
+    match arch {
+        "x86_64" => "amd64",
+        "x86" => "386",
+        "aarch64" => "arm64",
+        "powerpc64" => "ppc64le",
+        arch => arch,
+    }
+}
+
+/// Format a bind mount of `source` on the host at `target` in the container.
+fn bind_mount(source: &Path, target: &Path) -> OsString {
+    let mut mount = OsString::from(source);
+    mount.push(":");
+    mount.push(target);
+    mount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwarded() {
+        let names = [
+            "UV_INDEX_INTERNAL_PASSWORD",
+            "PATH",
+            "UV_CACHE_DIR",
+            "UV_INTERNAL__CONTAINERIZED",
+            "UV_EXTRA_INDEX_URL",
+            "UVICORN_PORT",
+        ]
+        .into_iter()
+        .map(OsString::from);
+        assert_eq!(
+            forwarded_env_vars(names),
+            vec![
+                OsString::from("UV_EXTRA_INDEX_URL"),
+                OsString::from("UV_INDEX_INTERNAL_PASSWORD"),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn user() {
+        assert_eq!(
+            user_args(Path::new("/usr/bin/podman")),
+            vec![OsString::from("--userns=keep-id")]
+        );
+        let args = user_args(Path::new("/usr/bin/docker"));
+        assert_eq!(args[0], "--user");
+        assert!(args[1].to_str().unwrap().contains(':'));
+    }
+
+    #[test]
+    fn arch() {
+        assert_eq!(engine_arch("x86_64"), "amd64");
+        assert_eq!(engine_arch("aarch64"), "arm64");
+        assert_eq!(engine_arch("s390x"), "s390x");
+    }
+}
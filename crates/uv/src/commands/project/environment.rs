@@ -1,6 +1,7 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::Modifications;
@@ -96,11 +97,15 @@ impl EphemeralEnvironment {
 
 /// A [`PythonEnvironment`] stored in the cache.
 #[derive(Debug)]
-pub(crate) struct CachedEnvironment(PythonEnvironment);
+pub(crate) struct CachedEnvironment {
+    environment: PythonEnvironment,
+    /// The content-addressed link to the environment in the cache.
+    link: PathBuf,
+}
 
 impl From<CachedEnvironment> for PythonEnvironment {
     fn from(environment: CachedEnvironment) -> Self {
-        environment.0
+        environment.environment
     }
 }
 
@@ -173,7 +178,12 @@ impl CachedEnvironment {
         if cache.refresh().is_none() {
             if let Ok(root) = cache.resolve_link(cache_entry.path()) {
                 if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
-                    return Ok(Self(environment));
+                    if cache.use_environment(cache_entry.path()) {
+                        return Ok(Self {
+                            environment,
+                            link: cache_entry.into_path_buf(),
+                        });
+                    }
                 }
             }
         }
@@ -213,7 +223,60 @@ impl CachedEnvironment {
         let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
         let root = cache.archive(&id);
 
-        Ok(Self(PythonEnvironment::from_root(root, cache)?))
+        // Remove any environments that exceed the limits, now that we've added one.
+        cache.use_environment(cache_entry.path());
+        if !cache.is_temporary() {
+            if let Err(err) = cache.prune_environments(cache.environment_limits()) {
+                warn!("Failed to prune cached environments: {err}");
+            }
+        }
+
+        Ok(Self {
+            environment: PythonEnvironment::from_root(root, cache)?,
+            link: cache_entry.into_path_buf(),
+        })
+    }
+
+    /// Get the [`CachedEnvironment`] last resolved for the request with the given digest, if it
+    /// was resolved within the given interval.
+    ///
+    /// Unlike [`CachedEnvironment::from_spec`], the requirements aren't resolved, so the
+    /// environment may not include the latest versions of any unpinned requirements.
+    pub(crate) fn from_request(digest: &str, interval: Duration, cache: &Cache) -> Option<Self> {
+        let entry = cache.environment_request(digest);
+        let resolved = fs_err::metadata(entry.path()).ok()?.modified().ok()?;
+        let age = SystemTime::now()
+            .duration_since(resolved)
+            .unwrap_or_default();
+        if age >= interval {
+            debug!(
+                "Request was last resolved {}s ago; resolving again",
+                age.as_secs()
+            );
+            return None;
+        }
+        let link = cache
+            .bucket(CacheBucket::Environments)
+            .join(fs_err::read_to_string(entry.path()).ok()?.trim());
+        let root = cache.resolve_link(&link).ok()?;
+        let environment = PythonEnvironment::from_root(root, cache).ok()?;
+        if !cache.use_environment(&link) {
+            return None;
+        }
+        Some(Self { environment, link })
+    }
+
+    /// Record the [`CachedEnvironment`] as the resolution of the request with the given digest.
+    pub(crate) fn record_request(&self, digest: &str, cache: &Cache) -> std::io::Result<()> {
+        let entry = cache.environment_request(digest);
+        let Ok(link) = self
+            .link
+            .strip_prefix(cache.bucket(CacheBucket::Environments))
+        else {
+            return Ok(());
+        };
+        fs_err::create_dir_all(entry.dir())?;
+        fs_err::write(entry.path(), link.to_string_lossy().as_bytes())
     }
 
     /// Return the [`Interpreter`] to use for the cached environment, based on a given
@@ -1,6 +1,8 @@
 use std::env;
 use std::ffi::OsStr;
+use std::ops::Bound;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Context, Result, anyhow};
 use itertools::Itertools;
@@ -9,17 +11,19 @@ use owo_colors::OwoColorize;
 use uv_cache::Cache;
 use uv_configuration::{
     Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
-    Preview,
+    Preview, TargetTriple,
 };
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport};
+use uv_resolver::{Lock, PylockToml, RequirementsTxtExport};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::resolution_markers;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
@@ -31,6 +35,7 @@ use crate::commands::{ExitStatus, OutputWriter, diagnostics};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverSettings};
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 enum ExportTarget {
     /// A PEP 723 script, with inline metadata.
@@ -68,6 +73,8 @@ pub(crate) async fn export(
     include_annotations: bool,
     include_header: bool,
     script: Option<Pep723Script>,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -212,6 +219,22 @@ pub(crate) async fn export(
     // Validate that the set of requested extras and development groups are compatible.
     detect_conflicts(&lock, &extras, &groups)?;
 
+    // Determine the environment to export for, if any.
+    let markers = if python_platform.is_some() || python_version.is_some() {
+        Some(match interpreter.as_ref() {
+            Some(interpreter) => resolution_markers(
+                python_version.as_ref(),
+                python_platform.as_ref(),
+                interpreter,
+            )
+            .markers()
+            .clone(),
+            None => target_markers(python_version, python_platform, &lock)?,
+        })
+    } else {
+        None
+    };
+
     // Identify the installation target.
     let target = match &target {
         ExportTarget::Project(VirtualProject::Project(project)) => {
@@ -315,6 +338,7 @@ pub(crate) async fn export(
                 editable,
                 hashes,
                 &install_options,
+                markers.as_ref(),
             )?;
 
             if include_header {
@@ -336,6 +360,7 @@ pub(crate) async fn export(
                 include_annotations,
                 editable,
                 &install_options,
+                markers.as_ref(),
             )?;
 
             if include_header {
@@ -355,6 +380,53 @@ pub(crate) async fn export(
     Ok(ExitStatus::Success)
 }
 
+/// Synthesize the [`MarkerEnvironment`] for the given target, without a Python interpreter.
+///
+/// The lockfile is universal, so exporting for a specific platform only requires the markers for
+/// that platform. If no Python version is provided, the minimum version supported by the lockfile
+/// is used.
+fn target_markers(
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    lock: &Lock,
+) -> Result<MarkerEnvironment> {
+    let Some(python_platform) = python_platform else {
+        return Err(anyhow!(
+            "`--python-version` requires a Python interpreter to determine the target platform; provide `--python-platform` to export with `--frozen`"
+        ));
+    };
+
+    let python_version = if let Some(python_version) = python_version {
+        python_version
+    } else {
+        let (Bound::Included(version) | Bound::Excluded(version)) =
+            &**lock.requires_python().range().lower()
+        else {
+            return Err(anyhow!(
+                "The lockfile does not specify a minimum Python version; provide `--python-version` to export for a specific platform"
+            ));
+        };
+        PythonVersion::from_str(&version.only_release().to_string()).map_err(|err| anyhow!(err))?
+    };
+
+    // Start from a CPython environment, then apply the platform and Python version markers.
+    let python_minor_version = format!("{}.{}", python_version.major(), python_version.minor());
+    let base = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+        implementation_name: "cpython",
+        implementation_version: &python_version.to_string(),
+        os_name: "",
+        platform_machine: "",
+        platform_python_implementation: "CPython",
+        platform_release: "",
+        platform_system: "",
+        platform_version: "",
+        python_full_version: &python_version.to_string(),
+        python_version: &python_minor_version,
+        sys_platform: "",
+    })?;
+    Ok(python_version.markers(&python_platform.markers(&base)))
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd() -> String {
     let args = env::args_os()
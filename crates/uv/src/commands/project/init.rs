@@ -216,9 +216,11 @@ async fn init_script(
         warn_user_once!("`--package` is a no-op for Python scripts, which are standalone");
     }
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
@@ -347,9 +349,11 @@ async fn init_project(
 
     let reporter = PythonDownloadReporter::single(printer);
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     // First, determine if there is an request for Python
@@ -437,6 +441,9 @@ async fn init_project(
                         install_mirrors.python_install_mirror.as_deref(),
                         install_mirrors.pypy_install_mirror.as_deref(),
                         install_mirrors.python_downloads_json_url.as_deref(),
+                        install_mirrors
+                            .python_install_require_attestations
+                            .unwrap_or_default(),
                         preview,
                     )
                     .await?
@@ -465,6 +472,9 @@ async fn init_project(
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors
+                        .python_install_require_attestations
+                        .unwrap_or_default(),
                     preview,
                 )
                 .await?
@@ -508,7 +518,9 @@ async fn init_project(
         (requires_python, python_request)
     } else if let Some(requires_python) = workspace
         .as_ref()
-        .map(|workspace| find_requires_python(workspace, &DependencyGroupsWithDefaults::none()))
+        .map(|workspace| {
+            find_requires_python(workspace, &DependencyGroupsWithDefaults::none(), preview)
+        })
         .transpose()?
         .flatten()
     {
@@ -532,6 +544,9 @@ async fn init_project(
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
                 install_mirrors.python_downloads_json_url.as_deref(),
+                install_mirrors
+                    .python_install_require_attestations
+                    .unwrap_or_default(),
                 preview,
             )
             .await?
@@ -560,6 +575,9 @@ async fn init_project(
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors
+                .python_install_require_attestations
+                .unwrap_or_default(),
             preview,
         )
         .await?
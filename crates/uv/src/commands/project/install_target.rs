@@ -6,8 +6,9 @@ use itertools::Either;
 use rustc_hash::FxHashSet;
 
 use uv_configuration::{Constraints, DependencyGroupsWithDefaults, ExtrasSpecification};
-use uv_distribution_types::Index;
+use uv_distribution_types::{Index, RequiresPython};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::{DependencyGroupSpecifier, LenientRequirement, VerbatimParsedUrl};
 use uv_resolver::{Installable, Lock, Package};
 use uv_scripts::Pep723Script;
@@ -64,18 +65,19 @@ impl<'lock> Installable<'lock> for InstallTarget<'lock> {
     fn roots(&self) -> impl Iterator<Item = &PackageName> {
         match self {
             Self::Project { name, .. } => Either::Left(Either::Left(std::iter::once(*name))),
-            Self::NonProjectWorkspace { lock, .. } => {
-                Either::Left(Either::Right(lock.members().iter()))
-            }
-            Self::Workspace { lock, .. } => {
+            Self::NonProjectWorkspace { workspace, lock } | Self::Workspace { workspace, lock } => {
                 // Identify the workspace members.
                 //
                 // The members are encoded directly in the lockfile, unless the workspace contains a
                 // single member at the root, in which case, we identify it by its source.
-                if lock.members().is_empty() {
+                if matches!(self, Self::Workspace { .. }) && lock.members().is_empty() {
                     Either::Right(Either::Left(lock.root().into_iter().map(Package::name)))
                 } else {
-                    Either::Left(Either::Right(lock.members().iter()))
+                    // In a sparse workspace, omit any members that are missing from the checkout.
+                    let workspace: &Workspace = workspace;
+                    Either::Left(Either::Right(lock.members().iter().filter(move |name| {
+                        !workspace.is_sparse() || workspace.packages().contains_key(*name)
+                    })))
                 }
             }
             Self::Script { .. } => Either::Right(Either::Right(std::iter::empty())),
@@ -299,6 +301,38 @@ impl<'lock> InstallTarget<'lock> {
         Ok(())
     }
 
+    /// Validate that the Python version is compatible with the `requires-python` of each member
+    /// that will be installed, as members may declare divergent `requires-python` specifiers.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn validate_requires_python(self, version: &Version) -> Result<(), ProjectError> {
+        let workspace = match self {
+            Self::Project { workspace, .. }
+            | Self::Workspace { workspace, .. }
+            | Self::NonProjectWorkspace { workspace, .. } => workspace,
+            Self::Script { .. } => return Ok(()),
+        };
+
+        for name in self.roots() {
+            let Some(specifiers) = workspace
+                .packages()
+                .get(name)
+                .and_then(|member| member.pyproject_toml().project.as_ref())
+                .and_then(|project| project.requires_python.as_ref())
+            else {
+                continue;
+            };
+            if !RequiresPython::from_specifiers(specifiers).contains(version) {
+                return Err(ProjectError::MemberPythonIncompatibility(
+                    version.clone(),
+                    name.clone(),
+                    specifiers.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate the dependency groups requested by the [`DependencyGroupSpecifier`].
     #[allow(clippy::result_large_err)]
     pub(crate) fn validate_groups(
@@ -2,9 +2,11 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::ops::Bound;
 use std::path::Path;
 use std::sync::Arc;
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
@@ -13,7 +15,7 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Preview,
-    Reinstall, Upgrade,
+    PreviewFeatures, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -23,11 +25,12 @@ use uv_distribution_types::{
 };
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{GroupName, PackageName};
-use uv_pep440::Version;
+use uv_pep440::{LowerBound, Version};
+use uv_pep508::MarkerTree;
 use uv_pypi_types::{Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_requirements::ExtrasResolver;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
+use uv_requirements::{ExtrasResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, PythonRequirement,
     ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
@@ -99,9 +102,11 @@ pub(crate) async fn lock(
     let script = match script {
         Some(ScriptPath::Path(path)) => {
             let client_builder = BaseClientBuilder::new()
-                .retries_from_env()?
+                .retry_settings(&network_settings.retry)
                 .connectivity(network_settings.connectivity)
                 .native_tls(network_settings.native_tls)
+                .proxy_config(network_settings.proxy.clone())
+                .strict_index_tracking(network_settings.strict_index_tracking)
                 .allow_insecure_host(network_settings.allow_insecure_host.clone());
             let reporter = PythonDownloadReporter::single(printer);
             let requires_python = init_script_python_requirement(
@@ -431,6 +436,7 @@ async fn do_lock(
         prerelease,
         fork_strategy,
         dependency_metadata,
+        build_system_overrides,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -442,6 +448,16 @@ async fn do_lock(
         sources,
     } = settings;
 
+    // Initialize the client.
+    let client_builder = BaseClientBuilder::new()
+        .retry_settings(&network_settings.retry)
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .keyring(*keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
     // Collect the requirements, etc.
     let members = target.members();
     let packages = target.packages();
@@ -458,6 +474,35 @@ async fn do_lock(
     let overrides = target.lower(overrides, index_locations, *sources)?;
     let constraints = target.lower(constraints, index_locations, *sources)?;
     let build_constraints = target.lower(build_constraints, index_locations, *sources)?;
+
+    // Read any constraints files (e.g., a centrally managed set of constraints at a remote URL),
+    // which are applied in addition to the `constraint-dependencies`.
+    let constraint_files = target
+        .constraint_files()
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let constraints = if constraint_files.is_empty() {
+        constraints
+    } else {
+        let spec = RequirementsSpecification::from_sources(
+            &[],
+            &constraint_files,
+            &[],
+            None,
+            &client_builder,
+            cache,
+        )
+        .await?;
+        constraints
+            .into_iter()
+            .chain(
+                spec.constraints
+                    .into_iter()
+                    .map(|constraint| constraint.requirement),
+            )
+            .collect()
+    };
     let dependency_groups = dependency_groups
         .into_iter()
         .map(|(name, group)| {
@@ -549,7 +594,7 @@ async fn do_lock(
 
     // Determine the supported Python range. If no range is defined, and warn and default to the
     // current minor version.
-    let requires_python = target.requires_python()?;
+    let requires_python = target.requires_python(preview)?;
 
     let requires_python = if let Some(requires_python) = requires_python {
         if requires_python.is_unbounded() {
@@ -573,6 +618,44 @@ async fn do_lock(
         default
     };
 
+    // In a sparse workspace, retain any members that are missing from the checkout, such that
+    // the lockfile isn't invalidated by their absence.
+    let missing_members = match (target, existing_lock.as_ref()) {
+        (LockTarget::Workspace(workspace), Some(lock)) if workspace.is_sparse() => lock
+            .missing_members(workspace.install_path())
+            .into_iter()
+            .filter(|name| !packages.contains_key(name))
+            .collect::<Vec<_>>(),
+        _ => Vec::new(),
+    };
+    let members = if missing_members.is_empty() {
+        members
+    } else {
+        debug!(
+            "Retaining workspace members missing from sparse checkout: {}",
+            missing_members.iter().join(", ")
+        );
+        packages
+            .keys()
+            .chain(&missing_members)
+            .cloned()
+            .sorted()
+            .collect()
+    };
+
+    // The missing members may narrow the Python requirement, in which case, defer to the lockfile.
+    let requires_python = match existing_lock.as_ref() {
+        Some(lock)
+            if !missing_members.is_empty()
+                && lock
+                    .requires_python()
+                    .is_contained_by(requires_python.specifiers()) =>
+        {
+            lock.requires_python().clone()
+        }
+        _ => requires_python,
+    };
+
     // If any of the forks are incompatible with the Python requirement, error.
     for environment in environments
         .map(SupportedEnvironments::as_markers)
@@ -592,18 +675,18 @@ async fn do_lock(
         }
     }
 
+    // If members can declare divergent `requires-python` specifiers, each member is only included
+    // on the Python versions that it supports.
+    let member_requires_python = if preview.is_enabled(PreviewFeatures::MEMBER_REQUIRES_PYTHON) {
+        target.member_requires_python()
+    } else {
+        BTreeMap::new()
+    };
+
     // Determine the Python requirement.
     let python_requirement =
         PythonRequirement::from_requires_python(interpreter, requires_python.clone());
 
-    // Initialize the client.
-    let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
-        .connectivity(network_settings.connectivity)
-        .native_tls(network_settings.native_tls)
-        .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
-
     index_locations.cache_index_credentials();
 
     for index in target.indexes() {
@@ -672,6 +755,7 @@ async fn do_lock(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.fork().into_inner(),
         *index_strategy,
         config_setting,
@@ -742,6 +826,10 @@ async fn do_lock(
             Ok(LockResult::Unchanged(lock))
         }
 
+        // The lockfile can't be updated without dropping the members that are missing from the
+        // checkout.
+        _ if !missing_members.is_empty() => Err(ProjectError::SparseLockMismatch(missing_members)),
+
         // The lockfile did not contain enough information to obtain a resolution, fallback
         // to a fresh resolve.
         _ => {
@@ -788,10 +876,14 @@ async fn do_lock(
                             .collect()
                     })
                     .unwrap_or_else(|| {
-                        environments
-                            .cloned()
-                            .map(SupportedEnvironments::into_markers)
-                            .unwrap_or_default()
+                        member_forks(
+                            &requires_python,
+                            member_requires_python.values(),
+                            environments
+                                .cloned()
+                                .map(SupportedEnvironments::into_markers)
+                                .unwrap_or_default(),
+                        )
                     }),
             );
 
@@ -799,7 +891,12 @@ async fn do_lock(
             let resolution = pip::operations::resolve(
                 ExtrasResolver::new(&hasher, state.index(), database)
                     .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                    .resolve(target.members_requirements())
+                    .resolve(target.members_requirements().map(|mut requirement| {
+                        if let Some(member) = member_requires_python.get(&requirement.name) {
+                            requirement.marker.and(member.to_marker_tree());
+                        }
+                        requirement
+                    }))
                     .await
                     .map_err(|err| ProjectError::Operation(err.into()))?
                     .into_iter()
@@ -889,6 +986,56 @@ async fn do_lock(
     }
 }
 
+/// Split the workspace `requires-python` at the bounds of each member's `requires-python`, such
+/// that every member is either included or excluded in its entirety within each fork.
+///
+/// The resulting forks are crossed with the supported environments, if any.
+fn member_forks<'a>(
+    requires_python: &RequiresPython,
+    members: impl Iterator<Item = &'a RequiresPython>,
+    environments: Vec<MarkerTree>,
+) -> Vec<MarkerTree> {
+    // Each fork is identified by its lower bound, e.g., a member that requires `>=3.10,<3.12`
+    // introduces forks starting at `>=3.10` and `>=3.12`.
+    let mut bounds = BTreeSet::new();
+    for member in members {
+        bounds.insert(member.range().lower().clone());
+        let bound = match Bound::from(member.range().upper().clone()) {
+            Bound::Included(version) => Bound::Excluded(version),
+            Bound::Excluded(version) => Bound::Included(version),
+            Bound::Unbounded => continue,
+        };
+        bounds.insert(LowerBound::new(bound));
+    }
+
+    let mut forks = Vec::new();
+    let mut remaining = requires_python.clone();
+    for bound in bounds {
+        if let Some((lower, upper)) = remaining.split(bound.into()) {
+            forks.push(lower.to_marker_tree());
+            remaining = upper;
+        }
+    }
+    if forks.is_empty() {
+        return environments;
+    }
+    forks.push(remaining.to_marker_tree());
+
+    if environments.is_empty() {
+        return forks;
+    }
+    environments
+        .iter()
+        .flat_map(|environment| {
+            forks.iter().filter_map(move |fork| {
+                let mut marker = *environment;
+                marker.and(*fork);
+                (!marker.is_false()).then_some(marker)
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 enum ValidatedLock {
     /// An existing lockfile was provided, and it satisfies the workspace requirements.
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 
 use itertools::Either;
 
-use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy};
+use uv_configuration::{DependencyGroupsWithDefaults, Preview, SourceStrategy};
 use uv_distribution::LoweredRequirement;
 use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
 use uv_normalize::{GroupName, PackageName};
@@ -79,6 +79,14 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the constraints files for the [`LockTarget`].
+    pub(crate) fn constraint_files(self) -> Vec<PathBuf> {
+        match self {
+            Self::Workspace(workspace) => workspace.constraint_files(),
+            Self::Script(_) => vec![],
+        }
+    }
+
     /// Returns the set of build constraints for the [`LockTarget`].
     pub(crate) fn build_constraints(self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
         match self {
@@ -115,6 +123,26 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the `requires-python` declared by each member within the target, if any.
+    pub(crate) fn member_requires_python(self) -> BTreeMap<PackageName, RequiresPython> {
+        match self {
+            Self::Workspace(workspace) => workspace
+                .packages()
+                .iter()
+                .filter_map(|(name, member)| {
+                    let specifiers = member
+                        .pyproject_toml()
+                        .project
+                        .as_ref()?
+                        .requires_python
+                        .as_ref()?;
+                    Some((name.clone(), RequiresPython::from_specifiers(specifiers)))
+                })
+                .collect(),
+            Self::Script(_) => BTreeMap::new(),
+        }
+    }
+
     /// Returns the set of all dependency groups within the target.
     pub(crate) fn group_requirements(self) -> impl Iterator<Item = Requirement> + 'lock {
         match self {
@@ -226,12 +254,15 @@ impl<'lock> LockTarget<'lock> {
 
     /// Return the `Requires-Python` bound for the [`LockTarget`].
     #[allow(clippy::result_large_err)]
-    pub(crate) fn requires_python(self) -> Result<Option<RequiresPython>, ProjectError> {
+    pub(crate) fn requires_python(
+        self,
+        preview: Preview,
+    ) -> Result<Option<RequiresPython>, ProjectError> {
         match self {
             Self::Workspace(workspace) => {
                 // When locking, don't try to enforce requires-python bounds that appear on groups
                 let groups = DependencyGroupsWithDefaults::none();
-                find_requires_python(workspace, &groups)
+                find_requires_python(workspace, &groups, preview)
             }
             Self::Script(script) => Ok(script
                 .metadata
@@ -0,0 +1,1646 @@
+//! Migrate a Poetry, Pipenv, or setuptools project to standard `[project]` metadata.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow, bail};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table, Value};
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::MigrateFrom;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DryRun, Preview};
+use uv_distribution_types::Index;
+use uv_fs::Simplified;
+use uv_normalize::{DEV_DEPENDENCIES, ExtraName, GroupName, PackageName};
+use uv_pep440::{Operator, Version, VersionSpecifiers};
+use uv_pep508::Requirement;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::Source;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::lock::lock;
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// Migrate a project from Poetry, Pipenv, or setuptools to standard `[project]` metadata.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn migrate(
+    project_dir: &Path,
+    explicit_path: Option<PathBuf>,
+    from: Option<MigrateFrom>,
+    no_lock: bool,
+    dry_run: bool,
+    run_setup_py: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    // Default to the current directory if a path was not provided.
+    let root = match explicit_path {
+        None => project_dir.to_path_buf(),
+        Some(ref path) => std::path::absolute(path)?,
+    };
+    let pyproject_path = root.join("pyproject.toml");
+
+    // Read the existing `pyproject.toml`, if any.
+    let content = match fs_err::read_to_string(&pyproject_path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+    let mut doc = content
+        .parse::<DocumentMut>()
+        .with_context(|| format!("Failed to parse `{}`", pyproject_path.user_display()))?;
+
+    let from = match from {
+        Some(from) => from,
+        None => detect(&root, &doc).ok_or_else(|| {
+            anyhow!(
+                "No Poetry, Pipenv, or setuptools project found in `{}`",
+                root.simplified_display().cyan()
+            )
+        })?,
+    };
+    debug!(
+        "Migrating project at `{}` from {from:?}",
+        root.user_display()
+    );
+
+    // Migrating from Pipenv or setuptools creates the `[project]` table from scratch, while Poetry
+    // (as of Poetry 2.0) supports defining some metadata in the `[project]` table already.
+    if from != MigrateFrom::Poetry && doc.contains_key("project") {
+        bail!(
+            "Project is already migrated (`{}` contains a `[project]` table)",
+            pyproject_path.user_display().cyan()
+        );
+    }
+
+    let migration = match from {
+        MigrateFrom::Poetry => from_poetry(&root, &content, &mut doc)?,
+        MigrateFrom::Pipenv => from_pipenv(&root)?,
+        MigrateFrom::Setuptools => {
+            // Running a `setup.py` requires a Python interpreter.
+            let setup_py = if !root.join("setup.py").is_file() {
+                None
+            } else if !run_setup_py {
+                // A `setup.py` can run arbitrary code, so it's only executed on request.
+                if !root.join("setup.cfg").is_file() {
+                    bail!(
+                        "Reading the metadata from `setup.py` requires executing it; pass `{}` to allow it",
+                        "--run-setup-py".green()
+                    );
+                }
+                warn_user!(
+                    "Ignoring the metadata in `setup.py`, which requires executing it; pass `{}` to include it",
+                    "--run-setup-py".green()
+                );
+                None
+            } else {
+                let client_builder = BaseClientBuilder::new()
+                    .retry_settings(&network_settings.retry)
+                    .connectivity(network_settings.connectivity)
+                    .native_tls(network_settings.native_tls)
+                    .proxy_config(network_settings.proxy.clone())
+                    .strict_index_tracking(network_settings.strict_index_tracking)
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                let reporter = PythonDownloadReporter::single(printer);
+                let request = python.as_deref().map(PythonRequest::parse);
+                let interpreter = PythonInstallation::find_or_download(
+                    request.as_ref(),
+                    EnvironmentPreference::OnlySystem,
+                    python_preference,
+                    python_downloads,
+                    &client_builder,
+                    cache,
+                    Some(&reporter),
+                    install_mirrors.python_install_mirror.as_deref(),
+                    install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors
+                        .python_install_require_attestations
+                        .unwrap_or_default(),
+                    preview,
+                )
+                .await?
+                .into_interpreter();
+                Some(read_setup_py(&root, interpreter.sys_executable())?)
+            };
+            from_setuptools(&root, setup_py, &mut doc)?
+        }
+    };
+
+    let migrated = migration.write(doc)?;
+
+    if dry_run {
+        write!(printer.stdout(), "{migrated}")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    fs_err::write(&pyproject_path, &migrated)?;
+    writeln!(
+        printer.stderr(),
+        "Migrated `{}` from {}",
+        pyproject_path.user_display().cyan(),
+        match from {
+            MigrateFrom::Poetry => "Poetry",
+            MigrateFrom::Pipenv => "Pipenv",
+            MigrateFrom::Setuptools => "setuptools",
+        }
+    )?;
+
+    if no_lock {
+        return Ok(ExitStatus::Success);
+    }
+
+    Box::pin(lock(
+        &root,
+        false,
+        false,
+        DryRun::Disabled,
+        python,
+        install_mirrors,
+        settings,
+        network_settings,
+        None,
+        python_preference,
+        python_downloads,
+        concurrency,
+        no_config,
+        cache,
+        printer,
+        preview,
+    ))
+    .await
+}
+
+/// Detect the tool used to manage the project at the given root, preferring Poetry, then Pipenv,
+/// then setuptools.
+fn detect(root: &Path, doc: &DocumentMut) -> Option<MigrateFrom> {
+    if doc
+        .get("tool")
+        .and_then(|tool| tool.get("poetry"))
+        .is_some()
+    {
+        Some(MigrateFrom::Poetry)
+    } else if root.join("Pipfile").is_file() {
+        Some(MigrateFrom::Pipenv)
+    } else if root.join("setup.cfg").is_file() || root.join("setup.py").is_file() {
+        Some(MigrateFrom::Setuptools)
+    } else {
+        None
+    }
+}
+
+/// A dependency, along with its `tool.uv.sources` entry, if any.
+#[derive(Debug)]
+struct Dependency {
+    requirement: Requirement,
+    source: Option<Source>,
+}
+
+/// An author or maintainer of the project.
+#[derive(Debug)]
+struct Author {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+impl Author {
+    /// Parse an author from the `Name <email>` format used by Poetry.
+    fn parse(author: &str) -> Self {
+        match author.split_once('<') {
+            Some((name, email)) => Self {
+                name: Some(name.trim().to_string()).filter(|name| !name.is_empty()),
+                email: Some(email.trim().trim_end_matches('>').trim().to_string()),
+            },
+            None => Self {
+                name: Some(author.trim().to_string()),
+                email: None,
+            },
+        }
+    }
+}
+
+/// The project metadata and configuration read from another tool.
+#[derive(Debug, Default)]
+struct Migration {
+    name: Option<PackageName>,
+    version: Option<String>,
+    dynamic: Vec<String>,
+    description: Option<String>,
+    readme: Option<String>,
+    license: Option<String>,
+    requires_python: Option<String>,
+    authors: Vec<Author>,
+    maintainers: Vec<Author>,
+    keywords: Vec<String>,
+    classifiers: Vec<String>,
+    urls: Vec<(String, String)>,
+    scripts: Vec<(String, String)>,
+    dependencies: Vec<Dependency>,
+    optional_dependencies: BTreeMap<ExtraName, Vec<Dependency>>,
+    dependency_groups: BTreeMap<GroupName, Vec<Dependency>>,
+    default_groups: Option<Vec<GroupName>>,
+    indexes: Vec<Index>,
+    tasks: Vec<(String, String)>,
+}
+
+impl Migration {
+    /// Write the migrated metadata into the `pyproject.toml`, returning its new contents.
+    ///
+    /// Any fields that are already present in the `[project]` table are retained as-is.
+    fn write(self, mut doc: DocumentMut) -> Result<String> {
+        let mut project = match doc.remove("project") {
+            Some(Item::Table(table)) => table,
+            Some(_) => bail!("Expected `project` to be a table in `pyproject.toml`"),
+            None => Table::new(),
+        };
+
+        let mut set = |key: &str, item: Option<Item>| {
+            if let Some(item) = item {
+                if !project.contains_key(key) {
+                    project.insert(key, item);
+                }
+            }
+        };
+        set(
+            "name",
+            self.name
+                .as_ref()
+                .map(|name| toml_edit::value(name.to_string())),
+        );
+        set("version", self.version.map(toml_edit::value));
+        set("description", self.description.map(toml_edit::value));
+        set("readme", self.readme.map(toml_edit::value));
+        set("license", self.license.map(license));
+        set(
+            "requires-python",
+            self.requires_python.map(toml_edit::value),
+        );
+        set("authors", authors(&self.authors));
+        set("maintainers", authors(&self.maintainers));
+        set("keywords", strings(&self.keywords));
+        set("classifiers", strings(&self.classifiers));
+        set("dynamic", strings(&self.dynamic));
+        set("dependencies", Some(toml_edit::value(Array::new())));
+        set("urls", table(&self.urls));
+        set("scripts", table(&self.scripts));
+
+        // Retain any existing dependencies, such that Poetry entries only refine them.
+        let existing = project
+            .get("dependencies")
+            .and_then(Item::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(|requirement| Requirement::from_str(requirement).ok())
+            .map(|requirement| (requirement.name.clone(), requirement))
+            .collect::<BTreeMap<_, _>>();
+
+        if let Some(default_groups) = &self.default_groups {
+            tool_uv(&mut doc)?.insert(
+                "default-groups",
+                toml_edit::value(
+                    default_groups
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Array>(),
+                ),
+            );
+        }
+        if let Some(tasks) = table(&self.tasks) {
+            tool_uv(&mut doc)?.insert("tasks", tasks);
+        }
+
+        // Place the `[project]` table at the top of the file.
+        let mut head = DocumentMut::new();
+        head.insert("project", Item::Table(project));
+        let mut content = head.to_string();
+        let rest = doc.to_string();
+        if !rest.trim().is_empty() {
+            content.push('\n');
+            content.push_str(rest.trim_start());
+        }
+
+        let mut toml = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)?;
+        for index in &self.indexes {
+            toml.add_index(index)?;
+        }
+        for dependency in self.dependencies {
+            match existing.get(&dependency.requirement.name) {
+                Some(requirement) => {
+                    if let Some(source) = &dependency.source {
+                        toml.add_dependency(requirement, Some(source), false)?;
+                    }
+                }
+                None => {
+                    toml.add_dependency(
+                        &dependency.requirement,
+                        dependency.source.as_ref(),
+                        false,
+                    )?;
+                }
+            }
+        }
+        for (extra, dependencies) in &self.optional_dependencies {
+            for dependency in dependencies {
+                toml.add_optional_dependency(
+                    extra,
+                    &dependency.requirement,
+                    dependency.source.as_ref(),
+                    false,
+                )?;
+            }
+        }
+        for (group, dependencies) in &self.dependency_groups {
+            for dependency in dependencies {
+                toml.add_dependency_group_requirement(
+                    group,
+                    &dependency.requirement,
+                    dependency.source.as_ref(),
+                    false,
+                )?;
+            }
+        }
+
+        Ok(toml.to_string())
+    }
+}
+
+/// Returns the `tool.uv` table in the document, creating it if necessary.
+fn tool_uv(doc: &mut DocumentMut) -> Result<&mut Table> {
+    implicit_table(implicit_table(doc.as_table_mut(), "tool")?, "uv")
+}
+
+/// Returns the table at the given key, creating an implicit table (i.e., one that is omitted
+/// unless it contains any values) if necessary.
+fn implicit_table<'a>(table: &'a mut Table, key: &str) -> Result<&'a mut Table> {
+    table
+        .entry(key)
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .with_context(|| format!("Expected `{key}` to be a table in `pyproject.toml`"))
+}
+
+/// Returns the `project.license` for a license: an SPDX license expression as-is, or a table with
+/// the license text for any other value (e.g., `Proprietary`).
+fn license(license: String) -> Item {
+    if spdx::Expression::parse(&license).is_ok() {
+        toml_edit::value(license)
+    } else {
+        let mut table = InlineTable::new();
+        table.insert("text", license.into());
+        toml_edit::value(table)
+    }
+}
+
+/// Returns an array of strings, or `None` if empty.
+fn strings(values: &[String]) -> Option<Item> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(toml_edit::value(
+        values.iter().map(String::as_str).collect::<Array>(),
+    ))
+}
+
+/// Returns a table of key-value pairs, or `None` if empty.
+fn table(entries: &[(String, String)]) -> Option<Item> {
+    if entries.is_empty() {
+        return None;
+    }
+    let mut table = Table::new();
+    for (key, value) in entries {
+        table.insert(key, toml_edit::value(value.as_str()));
+    }
+    Some(Item::Table(table))
+}
+
+/// Returns an array of `{ name = "...", email = "..." }` tables, or `None` if empty.
+fn authors(authors: &[Author]) -> Option<Item> {
+    if authors.is_empty() {
+        return None;
+    }
+    let authors = authors
+        .iter()
+        .map(|author| {
+            let mut table = InlineTable::new();
+            if let Some(name) = &author.name {
+                table.insert("name", name.as_str().into());
+            }
+            if let Some(email) = &author.email {
+                table.insert("email", email.as_str().into());
+            }
+            table
+        })
+        .collect::<Array>();
+    Some(toml_edit::value(authors))
+}
+
+/// Build a [`Requirement`] from its parts.
+fn requirement(
+    name: &str,
+    extras: &[String],
+    specifiers: &str,
+    markers: &[String],
+) -> Result<Requirement> {
+    let mut requirement = name.to_string();
+    if !extras.is_empty() {
+        write!(requirement, "[{}]", extras.iter().join(", "))?;
+    }
+    requirement.push_str(specifiers);
+    match markers {
+        [] => {}
+        [marker] => write!(requirement, " ; {marker}")?,
+        markers => write!(
+            requirement,
+            " ; {}",
+            markers
+                .iter()
+                .map(|marker| format!("({marker})"))
+                .join(" and ")
+        )?,
+    }
+    Requirement::from_str(&requirement)
+        .with_context(|| format!("Failed to convert the requirement for `{name}`"))
+}
+
+/// Build a [`Source`] from the fields of a `tool.uv.sources` entry.
+fn source(fields: toml::Table) -> Result<Source> {
+    Ok(toml::Value::Table(fields).try_into::<Source>()?)
+}
+
+/// Normalize a Git URL, converting SCP-like URLs (e.g., `git@github.com:user/repo.git`) to SSH
+/// URLs.
+fn git_url(url: &str) -> String {
+    if url.contains("://") {
+        return url.to_string();
+    }
+    match url.split_once(':') {
+        Some((host, path)) => format!("ssh://{host}/{path}"),
+        None => url.to_string(),
+    }
+}
+
+/// Convert a Poetry version constraint (e.g., `^1.2`) to PEP 440 version specifiers (e.g.,
+/// `>=1.2,<2`).
+///
+/// Returns an empty string for unconstrained versions (i.e., `*`), and `None` for constraints with
+/// alternatives (i.e., `||`), which can't be represented as version specifiers.
+fn poetry_specifiers(constraint: &str) -> Result<Option<String>> {
+    if constraint.contains("||") {
+        return Ok(None);
+    }
+
+    let mut specifiers = Vec::new();
+    for part in constraint.split(',').flat_map(|part| {
+        // Poetry allows separating constraints with whitespace, e.g., `>=1.2 <2`.
+        part.split_whitespace()
+            .fold(Vec::<String>::new(), |mut parts, token| {
+                match parts.last_mut() {
+                    // Re-attach versions to a preceding bare operator, e.g., `>= 1.2`.
+                    Some(last) if last.chars().all(|c| "<>=!~^".contains(c)) => {
+                        last.push_str(token);
+                    }
+                    _ => parts.push(token.to_string()),
+                }
+                parts
+            })
+    }) {
+        let part = part.trim();
+        if part.is_empty() || part == "*" {
+            continue;
+        }
+        if let Some(version) = part.strip_prefix('^') {
+            let parsed = Version::from_str(version.trim())?;
+            let release = parsed.release();
+            // Bump the first non-zero component, or the last component if all are zero.
+            let index = release
+                .iter()
+                .position(|part| *part != 0)
+                .unwrap_or(release.len() - 1);
+            let mut upper = release[..=index].to_vec();
+            upper[index] += 1;
+            specifiers.push(format!(">={}", version.trim()));
+            specifiers.push(format!("<{}", upper.iter().join(".")));
+        } else if let Some(version) = part.strip_prefix('~').filter(|_| !part.starts_with("~=")) {
+            let parsed = Version::from_str(version.trim())?;
+            let release = parsed.release();
+            // Bump the minor version if provided, and the major version otherwise.
+            let index = usize::from(release.len() > 1);
+            let mut upper = release[..=index].to_vec();
+            upper[index] += 1;
+            specifiers.push(format!(">={}", version.trim()));
+            specifiers.push(format!("<{}", upper.iter().join(".")));
+        } else if part.starts_with(['<', '>', '!', '~']) || part.starts_with("==") {
+            specifiers.push(part.to_string());
+        } else if let Some(version) = part.strip_prefix('=') {
+            specifiers.push(format!("=={}", version.trim()));
+        } else {
+            specifiers.push(format!("=={part}"));
+        }
+    }
+
+    let specifiers = specifiers.join(",");
+    VersionSpecifiers::from_str(&specifiers)
+        .with_context(|| format!("Failed to convert version constraint: `{constraint}`"))?;
+    Ok(Some(specifiers))
+}
+
+/// Convert a Poetry Python constraint (e.g., `^3.8`) to a marker expression (e.g.,
+/// `python_full_version >= '3.8' and python_full_version < '4'`).
+fn poetry_python_marker(constraint: &str) -> Result<Option<String>> {
+    let mut alternatives = Vec::new();
+    for alternative in constraint.split("||") {
+        let Some(specifiers) = poetry_specifiers(alternative)? else {
+            unreachable!("alternatives are split on `||`")
+        };
+        if specifiers.is_empty() {
+            return Ok(None);
+        }
+        let specifiers = VersionSpecifiers::from_str(&specifiers)?;
+        let marker = specifiers
+            .iter()
+            .map(|specifier| {
+                let star = if matches!(
+                    specifier.operator(),
+                    Operator::EqualStar | Operator::NotEqualStar
+                ) {
+                    ".*"
+                } else {
+                    ""
+                };
+                format!(
+                    "python_full_version {} '{}{star}'",
+                    specifier.operator(),
+                    specifier.version()
+                )
+            })
+            .join(" and ");
+        alternatives.push(marker);
+    }
+    match alternatives.as_slice() {
+        [marker] => Ok(Some(marker.clone())),
+        alternatives => Ok(Some(
+            alternatives
+                .iter()
+                .map(|marker| format!("({marker})"))
+                .join(" or "),
+        )),
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PoetryPyProjectToml {
+    #[serde(default)]
+    tool: PoetryTools,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PoetryTools {
+    #[serde(default)]
+    poetry: PoetryTool,
+}
+
+/// The `[tool.poetry]` table.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+struct PoetryTool {
+    name: Option<String>,
+    version: Option<String>,
+    description: Option<String>,
+    license: Option<String>,
+    authors: Vec<String>,
+    maintainers: Vec<String>,
+    readme: Option<PoetryReadme>,
+    homepage: Option<String>,
+    repository: Option<String>,
+    documentation: Option<String>,
+    keywords: Vec<String>,
+    classifiers: Vec<String>,
+    urls: BTreeMap<String, String>,
+    scripts: BTreeMap<String, PoetryScript>,
+    dependencies: BTreeMap<String, PoetryDependency>,
+    dev_dependencies: BTreeMap<String, PoetryDependency>,
+    group: BTreeMap<GroupName, PoetryGroup>,
+    extras: BTreeMap<ExtraName, Vec<String>>,
+    source: Vec<PoetrySource>,
+    packages: Vec<PoetryPackage>,
+    package_mode: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PoetryReadme {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PoetryScript {
+    Reference(String),
+    Table {
+        callable: Option<String>,
+        reference: Option<String>,
+        #[serde(rename = "type")]
+        kind: Option<String>,
+    },
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PoetryGroup {
+    optional: bool,
+    dependencies: BTreeMap<String, PoetryDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PoetrySource {
+    name: String,
+    url: Option<String>,
+    priority: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PoetryPackage {
+    include: String,
+    from: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+enum PoetryDependency {
+    Constraint(String),
+    Table(PoetryDependencyTable),
+    Multiple(Vec<PoetryDependencyTable>),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+struct PoetryDependencyTable {
+    version: Option<String>,
+    extras: Vec<String>,
+    optional: bool,
+    markers: Option<String>,
+    python: Option<String>,
+    platform: Option<String>,
+    git: Option<String>,
+    branch: Option<String>,
+    tag: Option<String>,
+    rev: Option<String>,
+    subdirectory: Option<String>,
+    path: Option<String>,
+    develop: Option<bool>,
+    url: Option<String>,
+    source: Option<String>,
+}
+
+impl PoetryDependency {
+    /// Convert the Poetry dependency to a list of dependencies, along with whether each is
+    /// optional.
+    fn convert(self, name: &str) -> Result<Vec<(Dependency, bool)>> {
+        let tables = match self {
+            Self::Constraint(version) => vec![PoetryDependencyTable {
+                version: Some(version),
+                ..PoetryDependencyTable::default()
+            }],
+            Self::Table(table) => vec![table],
+            Self::Multiple(tables) => tables,
+        };
+        tables
+            .into_iter()
+            .map(|table| {
+                let optional = table.optional;
+                Ok((table.convert(name)?, optional))
+            })
+            .collect()
+    }
+}
+
+impl PoetryDependencyTable {
+    fn convert(self, name: &str) -> Result<Dependency> {
+        let mut markers = Vec::new();
+        if let Some(python) = &self.python {
+            markers.extend(poetry_python_marker(python)?);
+        }
+        if let Some(platform) = &self.platform {
+            markers.push(format!("sys_platform == '{platform}'"));
+        }
+        if let Some(marker) = self.markers {
+            markers.push(marker);
+        }
+
+        let mut fields = toml::Table::new();
+        if let Some(git) = self.git {
+            fields.insert("git".to_string(), git_url(&git).into());
+            for (key, value) in [
+                ("branch", self.branch),
+                ("tag", self.tag),
+                ("rev", self.rev),
+                ("subdirectory", self.subdirectory),
+            ] {
+                if let Some(value) = value {
+                    fields.insert(key.to_string(), value.into());
+                }
+            }
+        } else if let Some(path) = self.path {
+            fields.insert("path".to_string(), path.into());
+            if let Some(develop) = self.develop {
+                fields.insert("editable".to_string(), develop.into());
+            }
+        } else if let Some(url) = self.url {
+            fields.insert("url".to_string(), url.into());
+            if let Some(subdirectory) = self.subdirectory {
+                fields.insert("subdirectory".to_string(), subdirectory.into());
+            }
+        } else if let Some(index) = self.source {
+            fields.insert("index".to_string(), index.into());
+        }
+
+        // Version constraints only apply to registry dependencies.
+        let specifiers = if fields.is_empty() || fields.contains_key("index") {
+            match self.version.as_deref().map(poetry_specifiers).transpose()? {
+                Some(Some(specifiers)) => specifiers,
+                Some(None) => {
+                    warn_user!(
+                        "Dropping the version constraint for `{name}`; alternatives (`||`) are not supported: `{}`",
+                        self.version.as_deref().unwrap_or_default()
+                    );
+                    String::new()
+                }
+                None => String::new(),
+            }
+        } else {
+            String::new()
+        };
+
+        Ok(Dependency {
+            requirement: requirement(name, &self.extras, &specifiers, &markers)?,
+            source: if fields.is_empty() {
+                None
+            } else {
+                Some(source(fields)?)
+            },
+        })
+    }
+}
+
+/// Convert the `[tool.poetry]` table, removing it from the document.
+fn from_poetry(root: &Path, content: &str, doc: &mut DocumentMut) -> Result<Migration> {
+    let PoetryPyProjectToml {
+        tool: PoetryTools { poetry },
+    } = toml::from_str(content).context("Failed to parse the `[tool.poetry]` table")?;
+
+    let mut migration = Migration {
+        name: poetry
+            .name
+            .as_deref()
+            .map(PackageName::from_str)
+            .transpose()?,
+        version: poetry.version,
+        description: poetry.description,
+        license: poetry.license,
+        authors: poetry
+            .authors
+            .iter()
+            .map(|author| Author::parse(author))
+            .collect(),
+        maintainers: poetry
+            .maintainers
+            .iter()
+            .map(|maintainer| Author::parse(maintainer))
+            .collect(),
+        keywords: poetry.keywords,
+        classifiers: poetry.classifiers,
+        ..Migration::default()
+    };
+
+    if migration.name.is_none()
+        && doc
+            .get("project")
+            .and_then(|project| project.get("name"))
+            .is_none()
+    {
+        migration.name = Some(directory_name(root)?);
+    }
+    if migration.version.is_none()
+        && doc
+            .get("project")
+            .and_then(|project| project.get("version"))
+            .is_none()
+    {
+        migration.version = Some("0.1.0".to_string());
+    }
+
+    migration.readme = match poetry.readme {
+        Some(PoetryReadme::Single(readme)) => Some(readme),
+        Some(PoetryReadme::Multiple(readmes)) => {
+            if readmes.len() > 1 {
+                warn_user!("Only a single readme is supported; using `{}`", readmes[0]);
+            }
+            readmes.into_iter().next()
+        }
+        None => None,
+    };
+
+    for (label, url) in [
+        ("Homepage", poetry.homepage),
+        ("Repository", poetry.repository),
+        ("Documentation", poetry.documentation),
+    ] {
+        if let Some(url) = url {
+            migration.urls.push((label.to_string(), url));
+        }
+    }
+    migration.urls.extend(poetry.urls);
+
+    for (name, script) in poetry.scripts {
+        match script {
+            PoetryScript::Reference(reference)
+            | PoetryScript::Table {
+                callable: Some(reference),
+                ..
+            } => migration.scripts.push((name, reference)),
+            PoetryScript::Table {
+                reference: Some(reference),
+                kind,
+                ..
+            } if kind.as_deref().is_none_or(|kind| kind == "console") => {
+                migration.scripts.push((name, reference));
+            }
+            PoetryScript::Table { .. } => {
+                warn_user!("Skipping script `{name}`; only console scripts are supported");
+            }
+        }
+    }
+
+    // Determine the optional dependencies for each extra.
+    let mut extras = BTreeMap::<PackageName, Vec<ExtraName>>::new();
+    for (extra, packages) in poetry.extras {
+        for package in packages {
+            // Extras may refer to dependencies with their own extras, e.g., `black[jupyter]`.
+            let package = package.split('[').next().unwrap_or_default().trim();
+            extras
+                .entry(PackageName::from_str(package)?)
+                .or_default()
+                .push(extra.clone());
+        }
+    }
+
+    for (name, dependency) in poetry.dependencies {
+        if name == "python" {
+            if let PoetryDependency::Constraint(constraint) = dependency {
+                match poetry_specifiers(&constraint)? {
+                    Some(specifiers) if !specifiers.is_empty() => {
+                        migration.requires_python = Some(specifiers);
+                    }
+                    Some(_) => {}
+                    None => warn_user!(
+                        "Dropping the Python version constraint; alternatives (`||`) are not supported: `{constraint}`"
+                    ),
+                }
+            }
+            continue;
+        }
+        for (dependency, optional) in dependency.convert(&name)? {
+            if !optional {
+                migration.dependencies.push(dependency);
+                continue;
+            }
+            let Some(extras) = extras.get(&dependency.requirement.name) else {
+                warn_user!(
+                    "Skipping optional dependency `{name}`, which isn't included in any extra"
+                );
+                continue;
+            };
+            for extra in extras {
+                migration
+                    .optional_dependencies
+                    .entry(extra.clone())
+                    .or_default()
+                    .push(Dependency {
+                        requirement: dependency.requirement.clone(),
+                        source: dependency.source.clone(),
+                    });
+            }
+        }
+    }
+
+    // Convert the development dependencies to dependency groups.
+    let mut default_groups = Vec::new();
+    let mut groups = poetry
+        .group
+        .into_iter()
+        .map(|(name, group)| (name, group.optional, group.dependencies))
+        .collect::<Vec<_>>();
+    if !poetry.dev_dependencies.is_empty() {
+        groups.push((DEV_DEPENDENCIES.clone(), false, poetry.dev_dependencies));
+    }
+    for (group, optional, dependencies) in groups {
+        if !optional {
+            default_groups.push(group.clone());
+        }
+        for (name, dependency) in dependencies {
+            for (dependency, _) in dependency.convert(&name)? {
+                migration
+                    .dependency_groups
+                    .entry(group.clone())
+                    .or_default()
+                    .push(dependency);
+            }
+        }
+    }
+    // Poetry installs all non-optional groups by default, while uv only installs `dev`.
+    default_groups.sort();
+    default_groups.dedup();
+    if !migration.dependency_groups.is_empty() && default_groups != [DEV_DEPENDENCIES.clone()] {
+        migration.default_groups = Some(default_groups);
+    }
+
+    for source in poetry.source {
+        let Some(url) = source.url else {
+            // Sources without a URL refer to PyPI, which is used by default.
+            continue;
+        };
+        let mut index = Index::from_str(&format!("{}={url}", source.name))
+            .with_context(|| format!("Failed to convert source `{}`", source.name))?;
+        index.explicit = source.priority.as_deref() == Some("explicit");
+        migration.indexes.push(index);
+    }
+
+    // Remove the `[tool.poetry]` table.
+    if let Some(tool) = doc.get_mut("tool").and_then(Item::as_table_like_mut) {
+        tool.remove("poetry");
+        if tool.is_empty() {
+            doc.remove("tool");
+        }
+    }
+
+    // Replace the Poetry build backend, which requires the `[tool.poetry]` table for anything
+    // beyond the standard metadata.
+    let poetry_core = doc
+        .get("build-system")
+        .and_then(|build_system| build_system.get("build-backend"))
+        .and_then(Item::as_str)
+        .is_some_and(|backend| backend.starts_with("poetry.core"));
+    if poetry.package_mode == Some(false) {
+        doc.remove("build-system");
+    } else if poetry_core {
+        let mut build_system = Table::new();
+        build_system.insert(
+            "requires",
+            toml_edit::value(["hatchling"].into_iter().collect::<Array>()),
+        );
+        build_system.insert("build-backend", toml_edit::value("hatchling.build"));
+        doc.insert("build-system", Item::Table(build_system));
+
+        // Retain any explicit package locations, e.g.,
+        // `packages = [{ include = "foo", from = "src" }]`.
+        let packages = poetry
+            .packages
+            .iter()
+            .map(|package| match &package.from {
+                Some(from) => format!("{from}/{}", package.include),
+                None => package.include.clone(),
+            })
+            .collect::<Array>();
+        if !packages.is_empty() {
+            let mut table = doc.as_table_mut();
+            for key in ["tool", "hatch", "build", "targets", "wheel"] {
+                table = implicit_table(table, key)?;
+            }
+            table.insert("packages", toml_edit::value(packages));
+        }
+    }
+
+    Ok(migration)
+}
+
+/// Returns the project name, inferred from the name of its directory.
+fn directory_name(root: &Path) -> Result<PackageName> {
+    let name = root
+        .file_name()
+        .and_then(|name| name.to_str())
+        .context("Missing directory name")?;
+    Ok(PackageName::from_owned(name.trim().replace(' ', "-"))?)
+}
+
+/// A `Pipfile`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+struct Pipfile {
+    source: Vec<PipfileSource>,
+    packages: BTreeMap<String, PipfileDependency>,
+    dev_packages: BTreeMap<String, PipfileDependency>,
+    requires: PipfileRequires,
+    scripts: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PipfileSource {
+    name: String,
+    url: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PipfileRequires {
+    python_version: Option<String>,
+    python_full_version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+enum PipfileDependency {
+    Specifiers(String),
+    Table(PipfileDependencyTable),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PipfileDependencyTable {
+    version: Option<String>,
+    extras: Vec<String>,
+    markers: Option<String>,
+    git: Option<String>,
+    #[serde(rename = "ref")]
+    rev: Option<String>,
+    subdirectory: Option<String>,
+    path: Option<String>,
+    file: Option<String>,
+    editable: Option<bool>,
+    index: Option<String>,
+    /// Marker keys, e.g., `sys_platform = "== 'linux'"`.
+    #[serde(flatten)]
+    environment: BTreeMap<String, toml::Value>,
+}
+
+/// The marker keys that may be used in a `Pipfile` dependency.
+const PIPFILE_MARKERS: &[&str] = &[
+    "os_name",
+    "sys_platform",
+    "platform_machine",
+    "platform_python_implementation",
+    "platform_release",
+    "platform_system",
+    "platform_version",
+    "python_version",
+    "python_full_version",
+    "implementation_name",
+    "implementation_version",
+];
+
+/// Convert pip-style version specifiers, as used in a `Pipfile` (e.g., `*` or `>=2.0`).
+fn pipfile_specifiers(name: &str, specifiers: &str) -> Result<String> {
+    let specifiers = specifiers.trim();
+    if specifiers.is_empty() || specifiers == "*" {
+        return Ok(String::new());
+    }
+    let specifiers = if specifiers.starts_with(char::is_numeric) {
+        format!("=={specifiers}")
+    } else {
+        specifiers.to_string()
+    };
+    VersionSpecifiers::from_str(&specifiers)
+        .with_context(|| format!("Failed to convert the version specifiers for `{name}`"))?;
+    Ok(specifiers)
+}
+
+impl PipfileDependency {
+    /// Convert the `Pipfile` dependency, returning `None` if it refers to the project itself.
+    fn convert(self, name: &str) -> Result<Option<Dependency>> {
+        let table = match self {
+            Self::Specifiers(specifiers) => {
+                return Ok(Some(Dependency {
+                    requirement: requirement(
+                        name,
+                        &[],
+                        &pipfile_specifiers(name, &specifiers)?,
+                        &[],
+                    )?,
+                    source: None,
+                }));
+            }
+            Self::Table(table) => table,
+        };
+
+        let mut markers = Vec::new();
+        for (key, value) in &table.environment {
+            if !PIPFILE_MARKERS.contains(&key.as_str()) {
+                debug!("Ignoring unsupported key `{key}` for `{name}` in `Pipfile`");
+                continue;
+            }
+            if let Some(value) = value.as_str() {
+                markers.push(format!("{key} {value}"));
+            }
+        }
+        if let Some(marker) = table.markers {
+            markers.push(marker);
+        }
+
+        let mut fields = toml::Table::new();
+        if let Some(git) = table.git {
+            fields.insert("git".to_string(), git_url(&git).into());
+            if let Some(rev) = table.rev {
+                fields.insert("rev".to_string(), rev.into());
+            }
+            if let Some(subdirectory) = table.subdirectory {
+                fields.insert("subdirectory".to_string(), subdirectory.into());
+            }
+        } else if let Some(path) = table.path {
+            // The project itself is often installed with `-e .`.
+            if Path::new(&path)
+                .components()
+                .all(|component| matches!(component, std::path::Component::CurDir))
+            {
+                return Ok(None);
+            }
+            fields.insert("path".to_string(), path.into());
+            if let Some(editable) = table.editable {
+                fields.insert("editable".to_string(), editable.into());
+            }
+        } else if let Some(url) = table.file {
+            fields.insert("url".to_string(), url.into());
+        } else if let Some(index) = table.index {
+            fields.insert("index".to_string(), index.into());
+        }
+
+        let specifiers = if fields.is_empty() || fields.contains_key("index") {
+            pipfile_specifiers(name, table.version.as_deref().unwrap_or_default())?
+        } else {
+            String::new()
+        };
+
+        Ok(Some(Dependency {
+            requirement: requirement(name, &table.extras, &specifiers, &markers)?,
+            source: if fields.is_empty() {
+                None
+            } else {
+                Some(source(fields)?)
+            },
+        }))
+    }
+}
+
+/// Convert a `Pipfile`.
+fn from_pipenv(root: &Path) -> Result<Migration> {
+    let path = root.join("Pipfile");
+    let pipfile: Pipfile = toml::from_str(&fs_err::read_to_string(&path)?)
+        .with_context(|| format!("Failed to parse `{}`", path.user_display()))?;
+
+    let mut migration = Migration {
+        name: Some(directory_name(root)?),
+        version: Some("0.1.0".to_string()),
+        requires_python: pipfile
+            .requires
+            .python_full_version
+            .or(pipfile.requires.python_version)
+            .map(|version| format!(">={version}")),
+        tasks: pipfile.scripts.into_iter().collect(),
+        ..Migration::default()
+    };
+
+    for (name, dependency) in pipfile.packages {
+        migration.dependencies.extend(dependency.convert(&name)?);
+    }
+    for (name, dependency) in pipfile.dev_packages {
+        if let Some(dependency) = dependency.convert(&name)? {
+            migration
+                .dependency_groups
+                .entry(DEV_DEPENDENCIES.clone())
+                .or_default()
+                .push(dependency);
+        }
+    }
+
+    for source in pipfile.source {
+        // PyPI is used by default.
+        if source.url.trim_end_matches('/') == "https://pypi.org/simple" {
+            continue;
+        }
+        let index = Index::from_str(&format!("{}={}", source.name, source.url))
+            .with_context(|| format!("Failed to convert source `{}`", source.name))?;
+        migration.indexes.push(index);
+    }
+
+    Ok(migration)
+}
+
+/// The arguments to `setuptools.setup()`, as read from a `setup.cfg` or `setup.py`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SetupArgs {
+    name: Option<String>,
+    version: Option<String>,
+    description: Option<String>,
+    long_description: Option<String>,
+    author: Option<String>,
+    author_email: Option<String>,
+    maintainer: Option<String>,
+    maintainer_email: Option<String>,
+    url: Option<String>,
+    license: Option<String>,
+    keywords: Option<SetupList>,
+    classifiers: Option<SetupList>,
+    project_urls: BTreeMap<String, String>,
+    python_requires: Option<String>,
+    install_requires: Option<SetupList>,
+    extras_require: BTreeMap<String, SetupList>,
+    entry_points: BTreeMap<String, SetupList>,
+}
+
+/// A list of values, which `setuptools` accepts as a list or as a newline-separated string.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum SetupList {
+    String(String),
+    List(Vec<String>),
+}
+
+impl SetupList {
+    /// Returns the values in the list, splitting strings on newlines and the given separators.
+    fn values(&self, separators: &[char]) -> Vec<String> {
+        let values = match self {
+            Self::String(value) => vec![value.as_str()],
+            Self::List(values) => values.iter().map(String::as_str).collect(),
+        };
+        values
+            .into_iter()
+            .flat_map(|value| value.split(|c| c == '\n' || separators.contains(&c)))
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && !value.starts_with('#'))
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+impl SetupArgs {
+    /// Read the `setuptools` metadata from a `setup.cfg` file.
+    fn from_setup_cfg(content: &str) -> Self {
+        let sections = parse_ini(content);
+        let get = |section: &str, key: &str| {
+            sections
+                .get(section)
+                .and_then(|section| section.get(key))
+                .cloned()
+        };
+        let list = |section: &str, key: &str| get(section, key).map(SetupList::String);
+        let map = |section: &str| {
+            sections
+                .get(section)
+                .into_iter()
+                .flatten()
+                .map(|(key, value)| (key.clone(), SetupList::String(value.clone())))
+                .collect::<BTreeMap<_, _>>()
+        };
+        Self {
+            name: get("metadata", "name"),
+            version: get("metadata", "version"),
+            description: get("metadata", "description"),
+            long_description: get("metadata", "long_description"),
+            author: get("metadata", "author"),
+            author_email: get("metadata", "author_email"),
+            maintainer: get("metadata", "maintainer"),
+            maintainer_email: get("metadata", "maintainer_email"),
+            url: get("metadata", "url").or_else(|| get("metadata", "home_page")),
+            license: get("metadata", "license"),
+            keywords: list("metadata", "keywords"),
+            classifiers: list("metadata", "classifiers"),
+            project_urls: get("metadata", "project_urls")
+                .into_iter()
+                .flat_map(|urls| {
+                    urls.lines()
+                        .filter_map(|line| {
+                            let (key, value) = line.split_once('=')?;
+                            Some((key.trim().to_string(), value.trim().to_string()))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            python_requires: get("options", "python_requires"),
+            install_requires: list("options", "install_requires"),
+            extras_require: map("options.extras_require"),
+            entry_points: map("options.entry_points"),
+        }
+    }
+
+    /// Merge the arguments with another set of arguments, which take precedence.
+    fn merge(self, other: Self) -> Self {
+        let mut project_urls = self.project_urls;
+        project_urls.extend(other.project_urls);
+        let mut extras_require = self.extras_require;
+        extras_require.extend(other.extras_require);
+        let mut entry_points = self.entry_points;
+        entry_points.extend(other.entry_points);
+        Self {
+            name: other.name.or(self.name),
+            version: other.version.or(self.version),
+            description: other.description.or(self.description),
+            long_description: other.long_description.or(self.long_description),
+            author: other.author.or(self.author),
+            author_email: other.author_email.or(self.author_email),
+            maintainer: other.maintainer.or(self.maintainer),
+            maintainer_email: other.maintainer_email.or(self.maintainer_email),
+            url: other.url.or(self.url),
+            license: other.license.or(self.license),
+            keywords: other.keywords.or(self.keywords),
+            classifiers: other.classifiers.or(self.classifiers),
+            project_urls,
+            python_requires: other.python_requires.or(self.python_requires),
+            install_requires: other.install_requires.or(self.install_requires),
+            extras_require,
+            entry_points,
+        }
+    }
+}
+
+/// Parse an INI file (e.g., `setup.cfg`) into a map from section to key-value pairs.
+///
+/// Values may span multiple lines, as long as the continuation lines are indented.
+fn parse_ini(content: &str) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut sections = BTreeMap::<String, BTreeMap<String, String>>::new();
+    let mut section = String::new();
+    let mut key: Option<String> = None;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            // A continuation of the previous value.
+            if let Some(key) = &key {
+                if let Some(value) = sections
+                    .get_mut(&section)
+                    .and_then(|section| section.get_mut(key))
+                {
+                    if !trimmed.is_empty() {
+                        value.push('\n');
+                        value.push_str(trimmed);
+                    }
+                }
+            }
+        } else if let Some(name) = trimmed
+            .strip_prefix('[')
+            .and_then(|name| name.strip_suffix(']'))
+        {
+            section = name.trim().to_string();
+            key = None;
+        } else if let Some((name, value)) = trimmed.split_once(['=', ':']) {
+            let name = name.trim().replace('-', "_");
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(name.clone(), value.trim().to_string());
+            key = Some(name);
+        }
+    }
+    sections
+}
+
+/// A Python script that captures the arguments passed to `setuptools.setup()` by a `setup.py`.
+const SETUP_PY_SCRIPT: &str = r#"
+import json, os, runpy, sys, types
+
+captured = {}
+
+def setup(**kwargs):
+    captured.update(kwargs)
+
+try:
+    import setuptools
+except ImportError:
+    setuptools = types.ModuleType("setuptools")
+    setuptools.find_packages = lambda *args, **kwargs: []
+    setuptools.find_namespace_packages = lambda *args, **kwargs: []
+    setuptools.Extension = lambda *args, **kwargs: None
+    sys.modules["setuptools"] = setuptools
+setuptools.setup = setup
+
+try:
+    import distutils.core
+    distutils.core.setup = setup
+except ImportError:
+    pass
+
+sys.path.insert(0, os.getcwd())
+sys.argv = ["setup.py"]
+runpy.run_path("setup.py", run_name="__main__")
+print(json.dumps(captured, default=str))
+"#;
+
+/// Read the arguments to `setuptools.setup()` by running the `setup.py` with the given interpreter.
+fn read_setup_py(root: &Path, interpreter: &Path) -> Result<SetupArgs> {
+    debug!(
+        "Reading `setup.py` metadata with: `{}`",
+        interpreter.user_display()
+    );
+    let output = Command::new(interpreter)
+        .arg("-c")
+        .arg(SETUP_PY_SCRIPT)
+        .current_dir(root)
+        .output()
+        .context("Failed to run `setup.py`")?;
+    if !output.status.success() {
+        bail!(
+            "Failed to read the metadata from `setup.py`:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json = stdout.lines().last().unwrap_or_default();
+    serde_json::from_str(json).context("Failed to read the metadata from `setup.py`")
+}
+
+/// Convert the metadata in a `setup.cfg` and, if present, the arguments read from a `setup.py`.
+fn from_setuptools(
+    root: &Path,
+    setup_py: Option<SetupArgs>,
+    doc: &mut DocumentMut,
+) -> Result<Migration> {
+    let setup_cfg = root.join("setup.cfg");
+    let mut args = if setup_cfg.is_file() {
+        SetupArgs::from_setup_cfg(&fs_err::read_to_string(&setup_cfg)?)
+    } else {
+        SetupArgs::default()
+    };
+    if let Some(setup_py) = setup_py {
+        args = args.merge(setup_py);
+    }
+
+    // Continue to build the project with `setuptools`, which supports SPDX license expressions in
+    // the `[project]` table as of v77. Projects without a `[build-system]` table use `setuptools`
+    // implicitly.
+    if !doc.contains_key("build-system") {
+        let mut build_system = Table::new();
+        build_system.insert(
+            "requires",
+            toml_edit::value(["setuptools>=77"].into_iter().collect::<Array>()),
+        );
+        build_system.insert("build-backend", toml_edit::value("setuptools.build_meta"));
+        doc.insert("build-system", Item::Table(build_system));
+    }
+
+    let mut migration = Migration {
+        name: match args.name.as_deref() {
+            Some(name) => Some(PackageName::from_str(name)?),
+            None => Some(directory_name(root)?),
+        },
+        description: args.description,
+        license: args.license,
+        requires_python: args.python_requires,
+        keywords: args
+            .keywords
+            .map(|keywords| keywords.values(&[',']))
+            .unwrap_or_default(),
+        classifiers: args
+            .classifiers
+            .map(|classifiers| classifiers.values(&[]))
+            .unwrap_or_default(),
+        ..Migration::default()
+    };
+
+    // Versions read from an attribute or file (e.g., `attr: package.__version__`) are dynamic.
+    match args.version {
+        Some(version) => {
+            if let Some((kind, value)) = version
+                .split_once(':')
+                .filter(|(kind, _)| matches!(kind.trim(), "attr" | "file"))
+            {
+                migration.dynamic.push("version".to_string());
+                let mut dynamic = InlineTable::new();
+                dynamic.insert(kind.trim(), value.trim().into());
+                let tool = implicit_table(doc.as_table_mut(), "tool")?;
+                implicit_table(implicit_table(tool, "setuptools")?, "dynamic")?
+                    .insert("version", toml_edit::value(dynamic));
+            } else {
+                migration.version = Some(version);
+            }
+        }
+        None => migration.version = Some("0.1.0".to_string()),
+    }
+
+    // Prefer a readme file, if the long description is read from one.
+    migration.readme = match args
+        .long_description
+        .as_deref()
+        .and_then(|description| description.strip_prefix("file:"))
+    {
+        Some(files) => files.split(',').next().map(|file| file.trim().to_string()),
+        None => ["README.md", "README.rst", "README.txt"]
+            .into_iter()
+            .find(|readme| root.join(readme).is_file())
+            .map(ToString::to_string),
+    };
+
+    if args.author.is_some() || args.author_email.is_some() {
+        migration.authors.push(Author {
+            name: args.author,
+            email: args.author_email,
+        });
+    }
+    if args.maintainer.is_some() || args.maintainer_email.is_some() {
+        migration.maintainers.push(Author {
+            name: args.maintainer,
+            email: args.maintainer_email,
+        });
+    }
+
+    if let Some(url) = args.url {
+        migration.urls.push(("Homepage".to_string(), url));
+    }
+    migration.urls.extend(args.project_urls);
+
+    for entry_point in args
+        .entry_points
+        .get("console_scripts")
+        .map(|scripts| scripts.values(&[]))
+        .unwrap_or_default()
+    {
+        if let Some((name, reference)) = entry_point.split_once('=') {
+            migration
+                .scripts
+                .push((name.trim().to_string(), reference.trim().to_string()));
+        }
+    }
+
+    for requirement in args
+        .install_requires
+        .map(|requirements| requirements.values(&[]))
+        .unwrap_or_default()
+    {
+        migration.dependencies.push(Dependency {
+            requirement: Requirement::from_str(&requirement)
+                .with_context(|| format!("Failed to parse requirement: `{requirement}`"))?,
+            source: None,
+        });
+    }
+
+    for (extra, requirements) in args.extras_require {
+        // Extras may include a marker, e.g., `test:python_version < "3.11"`.
+        let (extra, marker) = match extra.split_once(':') {
+            Some((extra, marker)) => (extra, Some(marker.trim())),
+            None => (extra.as_str(), None),
+        };
+        let extra = ExtraName::from_str(extra.trim())?;
+        for requirement in requirements.values(&[]) {
+            let mut requirement = Requirement::from_str(&requirement)
+                .with_context(|| format!("Failed to parse requirement: `{requirement}`"))?;
+            if let Some(marker) = marker {
+                requirement
+                    .marker
+                    .and(uv_pep508::MarkerTree::from_str(marker)?);
+            }
+            migration
+                .optional_dependencies
+                .entry(extra.clone())
+                .or_default()
+                .push(Dependency {
+                    requirement,
+                    source: None,
+                });
+        }
+    }
+
+    let files = ["setup.cfg", "setup.py"]
+        .into_iter()
+        .filter(|file| root.join(file).is_file())
+        .map(|file| format!("`{file}`"))
+        .join(" and ");
+    warn_user!(
+        "The project metadata is now defined in `pyproject.toml`; remove the duplicate metadata from {files}"
+    );
+
+    Ok(migration)
+}
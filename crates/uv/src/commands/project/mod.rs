@@ -59,17 +59,23 @@ use crate::settings::{
 };
 
 pub(crate) mod add;
+pub(crate) mod container;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod init;
-mod install_target;
+pub(crate) mod install_target;
 pub(crate) mod lock;
-mod lock_target;
+pub(crate) mod lock_target;
+pub(crate) mod migrate;
+pub(crate) mod parallel;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
+pub(crate) mod task;
 pub(crate) mod tree;
 pub(crate) mod version;
+pub(crate) mod watch;
+pub(crate) mod zipapp;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
@@ -83,6 +89,12 @@ pub(crate) enum ProjectError {
     )]
     MissingLockfile,
 
+    #[error(
+        "The lockfile at `uv.lock` needs to be updated, but the following workspace members are missing from the checkout: {}. To update the lockfile, run `uv lock` from a checkout that includes every member.",
+        _0.iter().map(|name| format!("`{name}`")).join(", ")
+    )]
+    SparseLockMismatch(Vec<PackageName>),
+
     #[error(
         "The lockfile at `uv.lock` uses an unsupported schema version (v{1}, but only v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`."
     )]
@@ -101,6 +113,11 @@ pub(crate) enum ProjectError {
     )]
     LockedPythonIncompatibility(Version, RequiresPython),
 
+    #[error(
+        "The current Python version ({0}) is not compatible with the `requires-python` of workspace member `{1}`: `{2}`"
+    )]
+    MemberPythonIncompatibility(Version, PackageName, VersionSpecifiers),
+
     #[error(
         "The current Python platform is not compatible with the lockfile's supported environments: {0}"
     )]
@@ -413,6 +430,7 @@ impl PlatformState {
 pub(crate) fn find_requires_python(
     workspace: &Workspace,
     groups: &DependencyGroupsWithDefaults,
+    preview: Preview,
 ) -> Result<Option<RequiresPython>, ProjectError> {
     let requires_python = workspace.requires_python(groups)?;
     // If there are no `Requires-Python` specifiers in the workspace, return `None`.
@@ -443,6 +461,13 @@ pub(crate) fn find_requires_python(
             }
         }
     }
+    // If members are allowed to declare divergent `requires-python` specifiers, the workspace
+    // spans all of them; otherwise, it's bound by the intersection.
+    if preview.is_enabled(PreviewFeatures::MEMBER_REQUIRES_PYTHON) {
+        return Ok(RequiresPython::union(
+            requires_python.iter().map(|(.., specifiers)| specifiers),
+        ));
+    }
     match RequiresPython::intersection(requires_python.iter().map(|(.., specifiers)| specifiers)) {
         Some(requires_python) => Ok(Some(requires_python)),
         None => Err(ProjectError::DisjointRequiresPython(requires_python)),
@@ -656,7 +681,8 @@ impl ScriptInterpreter {
             source,
             python_request,
             requires_python,
-        } = ScriptPython::from_request(python_request, workspace, script, no_config).await?;
+        } = ScriptPython::from_request(python_request, workspace, script, no_config, preview)
+            .await?;
 
         let root = Self::root(script, active, cache);
         match PythonEnvironment::from_root(&root, cache) {
@@ -689,9 +715,11 @@ impl ScriptInterpreter {
         }
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
         let reporter = PythonDownloadReporter::single(printer);
@@ -707,6 +735,9 @@ impl ScriptInterpreter {
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors
+                .python_install_require_attestations
+                .unwrap_or_default(),
             preview,
         )
         .await?
@@ -900,6 +931,7 @@ impl ProjectInterpreter {
             groups,
             project_dir,
             no_config,
+            preview,
         )
         .await?;
 
@@ -973,9 +1005,11 @@ impl ProjectInterpreter {
         }
 
         let client_builder = BaseClientBuilder::default()
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
         let reporter = PythonDownloadReporter::single(printer);
@@ -992,6 +1026,9 @@ impl ProjectInterpreter {
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors
+                .python_install_require_attestations
+                .unwrap_or_default(),
             preview,
         )
         .await?;
@@ -1092,7 +1129,8 @@ pub(crate) struct WorkspacePython {
     /// `Requires-Python` specifier in the `pyproject.toml`.
     pub(crate) python_request: Option<PythonRequest>,
     /// The resolved Python requirement for the project, computed by taking the intersection of all
-    /// `Requires-Python` specifiers in the workspace.
+    /// `Requires-Python` specifiers in the workspace (or the union, with
+    /// `--preview-features member-requires-python`).
     pub(crate) requires_python: Option<RequiresPython>,
 }
 
@@ -1104,9 +1142,10 @@ impl WorkspacePython {
         groups: &DependencyGroupsWithDefaults,
         project_dir: &Path,
         no_config: bool,
+        preview: Preview,
     ) -> Result<Self, ProjectError> {
         let requires_python = workspace
-            .map(|workspace| find_requires_python(workspace, groups))
+            .map(|workspace| find_requires_python(workspace, groups, preview))
             .transpose()?
             .flatten();
 
@@ -1180,6 +1219,7 @@ impl ScriptPython {
         workspace: Option<&Workspace>,
         script: Pep723ItemRef<'_>,
         no_config: bool,
+        preview: Preview,
     ) -> Result<Self, ProjectError> {
         // First, discover a requirement from the workspace
         let WorkspacePython {
@@ -1193,6 +1233,7 @@ impl ScriptPython {
             &DependencyGroupsWithDefaults::none(),
             script.path().and_then(Path::parent).unwrap_or(&**CWD),
             no_config,
+            preview,
         )
         .await?;
 
@@ -1681,6 +1722,7 @@ pub(crate) async fn resolve_names(
         resolver:
             ResolverSettings {
                 build_options,
+                build_system_overrides,
                 config_setting,
                 config_settings_package,
                 dependency_metadata,
@@ -1702,10 +1744,11 @@ pub(crate) async fn resolve_names(
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()
-        .map_err(uv_requirements::Error::ClientError)?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(*keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -1748,6 +1791,7 @@ pub(crate) async fn resolve_names(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.clone(),
         *index_strategy,
         config_setting,
@@ -1841,6 +1885,7 @@ pub(crate) async fn resolve_environment(
         prerelease,
         fork_strategy,
         dependency_metadata,
+        build_system_overrides,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -1863,9 +1908,11 @@ pub(crate) async fn resolve_environment(
     } = spec.requirements;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(*keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -1956,6 +2003,7 @@ pub(crate) async fn resolve_environment(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.clone().into_inner(),
         *index_strategy,
         config_setting,
@@ -2024,6 +2072,7 @@ pub(crate) async fn sync_environment(
         index_strategy,
         keyring_provider,
         dependency_metadata,
+        build_system_overrides,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -2037,9 +2086,11 @@ pub(crate) async fn sync_environment(
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -2094,6 +2145,7 @@ pub(crate) async fn sync_environment(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.clone().into_inner(),
         index_strategy,
         config_setting,
@@ -2183,6 +2235,7 @@ pub(crate) async fn update_environment(
         resolver:
             ResolverSettings {
                 build_options,
+                build_system_overrides,
                 config_setting,
                 config_settings_package,
                 dependency_metadata,
@@ -2204,9 +2257,11 @@ pub(crate) async fn update_environment(
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(*keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -2318,6 +2373,7 @@ pub(crate) async fn update_environment(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.clone(),
         *index_strategy,
         config_setting,
@@ -2448,6 +2504,9 @@ pub(crate) async fn init_script_python_requirement(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors
+            .python_install_require_attestations
+            .unwrap_or_default(),
         preview,
     )
     .await?
@@ -0,0 +1,160 @@
+//! Run a command in every workspace member concurrently, i.e., `uv run --all-packages --parallel`.
+//!
+//! We spawn a `uv run` child process with the same arguments for each member, with the member as
+//! the current package, such that each process syncs and runs the command as `uv run --package`
+//! would. The output of each process is forwarded line-by-line, prefixed with the member's name.
+//!
+//! The number of processes running at once is bounded by the build concurrency (i.e.,
+//! `UV_CONCURRENT_BUILDS`), as each process may sync the environment and run a CPU-bound command.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Stdio;
+
+use anyhow::{Context, Result};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use uv_configuration::Concurrency;
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The stream a line of output was written to.
+#[derive(Debug, Clone, Copy)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Run `uv run` with the current arguments in every member of the workspace.
+pub(crate) async fn run(
+    project_dir: &Path,
+    concurrency: Concurrency,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let members = workspace.packages();
+    let width = members
+        .keys()
+        .map(|name| name.as_str().len())
+        .max()
+        .unwrap_or_default();
+
+    let executable = std::env::current_exe()?;
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+
+    // Run the commands, starting the next one whenever one exits.
+    let statuses = async move {
+        futures::stream::iter(members.iter().enumerate())
+            .map(|(index, (name, member))| {
+                let sender = sender.clone();
+                let executable = &executable;
+                async move {
+                    debug!(
+                        "Running command in `{name}` at: `{}`",
+                        member.root().user_display()
+                    );
+                    let mut child = Command::new(executable)
+                        .args(std::env::args_os().skip(1))
+                        .current_dir(member.root())
+                        .env(EnvVars::UV_INTERNAL__RUN_PACKAGE, name.as_str())
+                        .stdin(Stdio::null())
+                        .stdout(Stdio::piped())
+                        .stderr(Stdio::piped())
+                        .spawn()
+                        .with_context(|| format!("Failed to spawn `uv run` for `{name}`"))?;
+                    if let Some(stdout) = child.stdout.take() {
+                        tokio::spawn(forward(stdout, index, Stream::Stdout, sender.clone()));
+                    }
+                    if let Some(stderr) = child.stderr.take() {
+                        tokio::spawn(forward(stderr, index, Stream::Stderr, sender));
+                    }
+                    let status = child.wait().await?;
+                    Ok::<_, anyhow::Error>((index, name, status))
+                }
+            })
+            .buffer_unordered(concurrency.builds)
+            .try_collect::<Vec<_>>()
+            .await
+    };
+
+    // Print the output as it arrives, until all the processes have closed their output.
+    let names = members.keys().collect::<Vec<_>>();
+    let output = async {
+        while let Some((index, stream, line)) = receiver.recv().await {
+            let prefix = format!("{:width$}", names[index].as_str());
+            let line = format!("{} {} {line}", prefix.cyan(), "|".dimmed());
+            match stream {
+                Stream::Stdout => writeln!(printer.stdout(), "{line}")?,
+                Stream::Stderr => writeln!(printer.stderr(), "{line}")?,
+            }
+        }
+        Ok::<_, anyhow::Error>(())
+    };
+
+    let (statuses, ()) = tokio::try_join!(statuses, output)?;
+
+    let mut failures = statuses
+        .into_iter()
+        .filter(|(.., status)| !status.success())
+        .collect::<Vec<_>>();
+    failures.sort_by_key(|(index, ..)| *index);
+
+    if failures.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{}{} Command failed in {} of {} packages:",
+        "error".red().bold(),
+        ":".bold(),
+        failures.len(),
+        members.len()
+    )?;
+    for (_, name, status) in failures {
+        writeln!(printer.stderr(), "- {} ({status})", name.cyan())?;
+    }
+    Ok(ExitStatus::Failure)
+}
+
+/// Forward the lines written to a stream of a child process.
+async fn forward(
+    stream: impl AsyncRead + Unpin,
+    index: usize,
+    kind: Stream,
+    sender: mpsc::UnboundedSender<(usize, Stream, String)>,
+) {
+    let mut segments = BufReader::new(stream).split(b'\n');
+    loop {
+        match segments.next_segment().await {
+            Ok(Some(segment)) => {
+                let line = String::from_utf8_lossy(&segment);
+                if sender
+                    .send((index, kind, line.trim_end_matches('\r').to_string()))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(err) => {
+                debug!("Failed to read output of command: {err}");
+                break;
+            }
+        }
+    }
+}
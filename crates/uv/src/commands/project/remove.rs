@@ -27,7 +27,7 @@ use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::lock::{LockEvent, LockMode, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
@@ -45,6 +45,8 @@ pub(crate) async fn remove(
     frozen: bool,
     active: Option<bool>,
     no_sync: bool,
+    prune_unused: bool,
+    dry_run: DryRun,
     packages: Vec<PackageName>,
     dependency_type: DependencyType,
     package: Option<PackageName>,
@@ -178,8 +180,10 @@ pub(crate) async fn remove(
 
     let content = toml.to_string();
 
-    // Save the modified `pyproject.toml` or script.
-    target.write(&content)?;
+    // Save the modified `pyproject.toml` or script, unless performing a dry run.
+    if !dry_run.enabled() {
+        target.write(&content)?;
+    }
 
     // If `--frozen`, exit early. There's no reason to lock and sync, since we don't need a `uv.lock`
     // to exist at all.
@@ -192,7 +196,12 @@ pub(crate) async fn remove(
         if !LockTarget::from(script).lock_path().is_file() {
             writeln!(
                 printer.stderr(),
-                "Updated `{}`",
+                "{} `{}`",
+                if dry_run.enabled() {
+                    "Would update"
+                } else {
+                    "Updated"
+                },
                 script.path.user_display().cyan()
             )?;
             return Ok(ExitStatus::Success);
@@ -213,7 +222,7 @@ pub(crate) async fn remove(
     // Convert to an `AddTarget` by attaching the appropriate interpreter or environment.
     let target = match target {
         RemoveTarget::Project(project) => {
-            if no_sync {
+            if no_sync || dry_run.enabled() {
                 // Discover the interpreter.
                 let interpreter = ProjectInterpreter::discover(
                     project.workspace(),
@@ -292,6 +301,8 @@ pub(crate) async fn remove(
     // Determine the lock mode.
     let mode = if locked {
         LockMode::Locked(target.interpreter())
+    } else if dry_run.enabled() {
+        LockMode::DryRun(target.interpreter())
     } else {
         LockMode::Write(target.interpreter())
     };
@@ -315,7 +326,7 @@ pub(crate) async fn remove(
     .execute((&target).into())
     .await
     {
-        Ok(result) => result.into_lock(),
+        Ok(result) => result,
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -324,6 +335,18 @@ pub(crate) async fn remove(
         Err(err) => return Err(err.into()),
     };
 
+    // Report the packages that are no longer required.
+    if prune_unused || dry_run.enabled() {
+        report_unused(&lock, dry_run, printer)?;
+    }
+
+    // If we're performing a dry run, exit before syncing.
+    if dry_run.enabled() {
+        return Ok(ExitStatus::Success);
+    }
+
+    let lock = lock.into_lock();
+
     let AddTarget::Project(project, environment) = target else {
         // If we're not adding to a project, exit early.
         return Ok(ExitStatus::Success);
@@ -385,6 +408,7 @@ pub(crate) async fn remove(
 }
 
 /// Represents the destination where dependencies are added, either to a project or a script.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 enum RemoveTarget {
     /// A PEP 723 script, with inline metadata.
@@ -442,6 +466,29 @@ impl RemoveTarget {
     }
 }
 
+/// Report the packages that were removed from the lockfile, i.e., those that are no longer
+/// reachable from the project requirements.
+fn report_unused(lock: &LockResult, dry_run: DryRun, printer: Printer) -> Result<()> {
+    let LockResult::Changed(previous, lock) = lock else {
+        writeln!(printer.stderr(), "{}", "No unused packages".bold())?;
+        return Ok(());
+    };
+
+    let mut unused = 0usize;
+    for event in LockEvent::detect_changes(previous.as_ref(), lock, dry_run) {
+        if matches!(event, LockEvent::Remove(..)) {
+            unused += 1;
+            writeln!(printer.stderr(), "{event}")?;
+        }
+    }
+
+    if unused == 0 {
+        writeln!(printer.stderr(), "{}", "No unused packages".bold())?;
+    }
+
+    Ok(())
+}
+
 /// Show a hint if a dependency with the given name is present as any dependency type.
 ///
 /// This is useful when a dependency of the user-specified type was not found, but it may be present
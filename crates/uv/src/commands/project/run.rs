@@ -9,12 +9,13 @@ use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use thiserror::Error;
 use tokio::process::Command;
 use tracing::{debug, trace, warn};
 use url::Url;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
 use uv_cli::ExternalCommand;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
@@ -22,10 +23,12 @@ use uv_configuration::{
     InstallOptions, Preview,
 };
 use uv_distribution_types::Requirement;
+use uv_extract::hash::Hasher;
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pypi_types::HashDigest;
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -50,6 +53,7 @@ use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironm
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::task::Tasks;
 use crate::commands::project::{
     EnvironmentSpecification, PreferenceLocation, ProjectEnvironment, ProjectError,
     ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
@@ -93,7 +97,11 @@ pub(crate) async fn run(
     cache: &Cache,
     printer: Printer,
     env_file: Vec<PathBuf>,
+    default_env_file: Vec<PathBuf>,
     no_env_file: bool,
+    env: Vec<(String, String)>,
+    unset: Vec<String>,
+    lock_scripts: bool,
     preview: Preview,
     max_recursion_depth: u32,
 ) -> anyhow::Result<ExitStatus> {
@@ -150,9 +158,22 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     let workspace_cache = WorkspaceCache::default();
 
     // Read from the `.env` file, if necessary.
+    //
+    // The files from the configuration are only used if none are provided on the command line, and
+    // are allowed to be missing (e.g., a `.env` that's only present in development).
     if !no_env_file {
+        let required = !env_file.is_empty();
+        let env_file = if required { env_file } else { default_env_file };
         for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
             match dotenvy::from_path(env_file_path) {
+                Err(dotenvy::Error::Io(err))
+                    if !required && err.kind() == std::io::ErrorKind::NotFound =>
+                {
+                    debug!(
+                        "Skipping missing environment file at: `{}`",
+                        env_file_path.simplified_display()
+                    );
+                }
                 Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
                     bail!(
                         "No environment file found at: `{}`",
@@ -214,13 +235,18 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             }
         }
 
-        // If a lockfile already exists, lock the script.
+        // If a lockfile already exists, lock the script. If `lock-scripts` is enabled, create the
+        // lockfile, unless it's expected to exist already (i.e., with `--locked` or `--frozen`).
         if let Some(target) = script
             .as_script()
             .map(LockTarget::from)
-            .filter(|target| target.lock_path().is_file())
+            .filter(|target| target.lock_path().is_file() || (lock_scripts && !locked && !frozen))
         {
-            debug!("Found existing lockfile for script");
+            if target.lock_path().is_file() {
+                debug!("Found existing lockfile for script");
+            } else {
+                debug!("Locking script, since `lock-scripts` is enabled");
+            }
 
             // Discover the interpreter for the script.
             let environment = ScriptEnvironment::get_or_init(
@@ -480,6 +506,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         None
     };
 
+    // The tasks defined in the project, if any.
+    let mut tasks = Tasks::default();
+
+    // The names of the GUI entry points defined in the workspace, if any.
+    let mut gui_scripts = FxHashSet::default();
+
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
@@ -607,6 +639,15 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     project.workspace().install_path().display()
                 );
             }
+            tasks = Tasks::from_project(&project);
+            gui_scripts = project
+                .workspace()
+                .packages()
+                .values()
+                .flat_map(|member| member.pyproject_toml().gui_scripts())
+                .map(ToString::to_string)
+                .collect();
+
             // Determine the groups and extras to include.
             let default_groups = default_dependency_groups(project.pyproject_toml())?;
             let default_extras = DefaultExtras::default();
@@ -619,9 +660,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 // If we're isolating the environment, use an ephemeral virtual environment as the
                 // base environment for the project.
                 let client_builder = BaseClientBuilder::new()
-                    .retries_from_env()?
+                    .retry_settings(&network_settings.retry)
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
+                    .proxy_config(network_settings.proxy.clone())
+                    .strict_index_tracking(network_settings.strict_index_tracking)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
                 // Resolve the Python request and requirement for the workspace.
@@ -635,6 +678,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     &groups,
                     project_dir,
                     no_config,
+                    preview,
                 )
                 .await?;
 
@@ -649,6 +693,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors
+                        .python_install_require_attestations
+                        .unwrap_or_default(),
                     preview,
                 )
                 .await?
@@ -861,9 +908,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             let interpreter = {
                 let client_builder = BaseClientBuilder::new()
-                    .retries_from_env()?
+                    .retry_settings(&network_settings.retry)
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
+                    .proxy_config(network_settings.proxy.clone())
+                    .strict_index_tracking(network_settings.strict_index_tracking)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
                 // (1) Explicit request from user
@@ -891,6 +940,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors
+                        .python_install_require_attestations
+                        .unwrap_or_default(),
                     preview,
                 )
                 .await?;
@@ -932,13 +984,16 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         None
     } else {
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
         let spec =
-            RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
+            RequirementsSpecification::from_simple_sources(&requirements, &client_builder, cache)
+                .await?;
 
         Some(spec)
     };
@@ -1220,6 +1275,21 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             .sorted()
             .collect_vec();
 
+        if !tasks.is_empty() {
+            writeln!(
+                printer.stdout(),
+                "The following tasks are available in the project:\n"
+            )?;
+            for (name, help) in tasks.iter() {
+                if let Some(help) = help {
+                    writeln!(printer.stdout(), "- {name}: {help}")?;
+                } else {
+                    writeln!(printer.stdout(), "- {name}")?;
+                }
+            }
+            writeln!(printer.stdout())?;
+        }
+
         if !commands.is_empty() {
             writeln!(
                 printer.stdout(),
@@ -1234,9 +1304,6 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         return Ok(ExitStatus::Error);
     };
 
-    debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
-
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -1267,19 +1334,72 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env(EnvVars::PATH, new_path);
+    let configure = |process: &mut Command| {
+        process.env(EnvVars::PATH, &new_path);
 
-    // Increment recursion depth counter.
-    process.env(
-        EnvVars::UV_RUN_RECURSION_DEPTH,
-        (recursion_depth + 1).to_string(),
-    );
+        // Allow the command to use `uv run --watch` and `uv run --parallel` itself.
+        process.env_remove(EnvVars::UV_INTERNAL__WATCHED);
+        process.env_remove(EnvVars::UV_INTERNAL__RUN_PACKAGE);
+
+        // Increment recursion depth counter.
+        process.env(
+            EnvVars::UV_RUN_RECURSION_DEPTH,
+            (recursion_depth + 1).to_string(),
+        );
+
+        // Ensure `VIRTUAL_ENV` is set.
+        if interpreter.is_virtualenv() {
+            process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        }
+    };
+
+    // Apply the environment variables from `--env` and `--unset`, which take precedence over the
+    // environment, any `.env` files, and the environment of tasks.
+    let override_env = |process: &mut Command| {
+        for key in &unset {
+            process.env_remove(key);
+        }
+        process.envs(env.iter().map(|(key, value)| (key, value)));
+    };
 
-    // Ensure `VIRTUAL_ENV` is set.
-    if interpreter.is_virtualenv() {
-        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+    // If the command is a task, run it after the tasks it depends on. Tasks take precedence over
+    // executables with the same name.
+    if let RunCommand::External(executable, args) = &command {
+        if let Some(name) = executable.to_str().filter(|name| tasks.contains(name)) {
+            for step in tasks.steps(name, args)? {
+                debug!("Running task `{}`: `{}`", step.name, step.command);
+                writeln!(
+                    printer.stderr(),
+                    "{} {}",
+                    format!("[{}]", step.name).dimmed(),
+                    step.command
+                )?;
+                let mut process = step.command.as_command(interpreter);
+                configure(&mut process);
+                process.envs(&step.env);
+                override_env(&mut process);
+                process.current_dir(&step.cwd);
+                let handle = process.spawn().with_context(|| {
+                    format!(
+                        "Failed to spawn `{}` for task `{}`",
+                        step.command.display_executable(),
+                        step.name
+                    )
+                })?;
+                let status = run_to_completion(handle).await?;
+                if !matches!(status, ExitStatus::Success | ExitStatus::External(0)) {
+                    return Ok(status);
+                }
+            }
+            return Ok(ExitStatus::Success);
+        }
     }
 
+    debug!("Running `{command}`");
+    let mut process = command.as_command(interpreter);
+    configure(&mut process);
+    override_env(&mut process);
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
@@ -1287,9 +1407,62 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         .spawn()
         .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
 
+    // If the command runs with `pythonw`, release the console, such that a console window that was
+    // only created for uv (e.g., when launched from a shortcut) doesn't linger. GUI entry points
+    // run with `pythonw` via their launcher. If `pythonw` doesn't exist, the command runs with
+    // `python`, which needs the console.
+    let is_gui = match &command {
+        RunCommand::PythonGui(..)
+        | RunCommand::PythonGuiScript(..)
+        | RunCommand::PythonGuiStdin(..) => true,
+        RunCommand::External(executable, _) => executable
+            .to_str()
+            .is_some_and(|name| gui_scripts.contains(name)),
+        _ => false,
+    } && find_pythonw(interpreter).is_some();
+    if is_gui {
+        debug!("Detaching from the console to run GUI application");
+        detach_console();
+    }
+
     run_to_completion(handle).await
 }
 
+/// Detach the current process from its console, if any.
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn detach_console() {
+    // SAFETY: `FreeConsole` has no preconditions; if the process has no console, it fails.
+    if let Err(err) = unsafe { windows::Win32::System::Console::FreeConsole() } {
+        debug!("Failed to detach from the console: {err}");
+    }
+}
+
+/// Detach the current process from its console, if any.
+///
+/// On non-Windows platforms, GUI applications aren't tied to a console window, so this is a no-op.
+#[cfg(not(windows))]
+fn detach_console() {}
+
+/// Returns the path to `pythonw` for the interpreter, if it exists.
+///
+/// See `install-wheel-rs::get_script_executable`.
+fn find_pythonw(interpreter: &Interpreter) -> Option<PathBuf> {
+    let python_executable = interpreter.sys_executable();
+    python_executable
+        .file_name()
+        .map(|name| {
+            let new_name = name.to_string_lossy().replace("python", "pythonw");
+            python_executable.with_file_name(new_name)
+        })
+        .filter(|path| path.is_file())
+}
+
+/// Returns the path to `pythonw` for the interpreter, if it exists, or `python` otherwise.
+fn pythonw_executable(interpreter: &Interpreter) -> PathBuf {
+    find_pythonw(interpreter).unwrap_or_else(|| interpreter.sys_executable().to_path_buf())
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: &RequirementsSpecification,
@@ -1338,6 +1511,8 @@ fn can_skip_ephemeral(
 pub(crate) enum RunCommand {
     /// Execute `python`.
     Python(Vec<OsString>),
+    /// Execute `pythonw`.
+    PythonGui(Vec<OsString>),
     /// Execute a `python` script.
     PythonScript(PathBuf, Vec<OsString>),
     /// Search `sys.path` for the named module and execute its contents as the `__main__` module.
@@ -1376,7 +1551,7 @@ impl RunCommand {
             // the available scripts in the interpreter — we could improve this message
             Self::PythonPackage(target, ..) => target.to_string_lossy(),
             Self::PythonModule(..) => Cow::Borrowed("python -m"),
-            Self::PythonGuiScript(..) => {
+            Self::PythonGui(..) | Self::PythonGuiScript(..) => {
                 if cfg!(windows) {
                     Cow::Borrowed("pythonw")
                 } else {
@@ -1403,6 +1578,11 @@ impl RunCommand {
                 process.args(args);
                 process
             }
+            Self::PythonGui(args) => {
+                let mut process = Command::new(pythonw_executable(interpreter));
+                process.args(args);
+                process
+            }
             Self::PythonPackage(target, path, args) => {
                 let name = PathBuf::from(target).with_extension(std::env::consts::EXE_EXTENSION);
                 let entrypoint = interpreter.scripts().join(name);
@@ -1440,20 +1620,7 @@ impl RunCommand {
                 process
             }
             Self::PythonGuiScript(target, args) => {
-                let python_executable = interpreter.sys_executable();
-
-                // Use `pythonw.exe` if it exists, otherwise fall back to `python.exe`.
-                // See `install-wheel-rs::get_script_executable`.gd
-                let pythonw_executable = python_executable
-                    .file_name()
-                    .map(|name| {
-                        let new_name = name.to_string_lossy().replace("python", "pythonw");
-                        python_executable.with_file_name(new_name)
-                    })
-                    .filter(|path| path.is_file())
-                    .unwrap_or_else(|| python_executable.to_path_buf());
-
-                let mut process = Command::new(&pythonw_executable);
+                let mut process = Command::new(pythonw_executable(interpreter));
                 process.arg(target);
                 process.args(args);
                 process
@@ -1478,20 +1645,7 @@ impl RunCommand {
                 process
             }
             Self::PythonGuiStdin(script, args) => {
-                let python_executable = interpreter.sys_executable();
-
-                // Use `pythonw.exe` if it exists, otherwise fall back to `python.exe`.
-                // See `install-wheel-rs::get_script_executable`.gd
-                let pythonw_executable = python_executable
-                    .file_name()
-                    .map(|name| {
-                        let new_name = name.to_string_lossy().replace("python", "pythonw");
-                        python_executable.with_file_name(new_name)
-                    })
-                    .filter(|path| path.is_file())
-                    .unwrap_or_else(|| python_executable.to_path_buf());
-
-                let mut process = Command::new(&pythonw_executable);
+                let mut process = Command::new(pythonw_executable(interpreter));
                 process.arg("-c");
 
                 #[cfg(unix)]
@@ -1547,6 +1701,13 @@ impl std::fmt::Display for RunCommand {
                 }
                 Ok(())
             }
+            Self::PythonGui(args) => {
+                write!(f, "pythonw")?;
+                for arg in args {
+                    write!(f, " {}", arg.to_string_lossy())?;
+                }
+                Ok(())
+            }
             Self::PythonModule(module, args) => {
                 write!(f, "python -m")?;
                 write!(f, " {}", module.to_string_lossy())?;
@@ -1591,9 +1752,12 @@ impl RunCommand {
     pub(crate) async fn from_args(
         command: &ExternalCommand,
         network_settings: NetworkSettings,
+        hash: Option<&HashDigest>,
+        cache: &Cache,
         module: bool,
         script: bool,
         gui_script: bool,
+        gui: bool,
     ) -> anyhow::Result<Self> {
         let (target, args) = command.split();
         let Some(target) = target else {
@@ -1606,7 +1770,7 @@ impl RunCommand {
 
             return if module {
                 Err(anyhow!("Cannot run a Python module from stdin"))
-            } else if gui_script {
+            } else if gui_script || gui {
                 Ok(Self::PythonGuiStdin(buf, args.to_vec()))
             } else {
                 Ok(Self::PythonStdin(buf, args.to_vec()))
@@ -1634,10 +1798,32 @@ impl RunCommand {
                     .suffix(".py")
                     .tempfile()?;
 
+                // If the script is pinned to a hash, reuse the cached copy, if any.
+                let cache_entry = if let Some(hash) = hash {
+                    let cache = cache.clone().init()?;
+                    Some(cache.entry(
+                        CacheBucket::Scripts,
+                        hash.algorithm.to_string(),
+                        format!("{}.py", hash.digest.to_ascii_lowercase()),
+                    ))
+                } else {
+                    None
+                };
+                if let Some(cache_entry) = cache_entry
+                    .as_ref()
+                    .filter(|cache_entry| cache_entry.path().is_file())
+                {
+                    debug!("Using cached script at: `{}`", cache_entry.path().display());
+                    fs_err::copy(cache_entry.path(), file.path())?;
+                    return Ok(Self::PythonRemote(url, file, args.to_vec()));
+                }
+
                 let client = BaseClientBuilder::new()
-                    .retries_from_env()?
+                    .retry_settings(&network_settings.retry)
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
+                    .proxy_config(network_settings.proxy.clone())
+                    .strict_index_tracking(network_settings.strict_index_tracking)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone())
                     .build();
                 let response = client
@@ -1646,21 +1832,47 @@ impl RunCommand {
                     .send()
                     .await?;
 
-                // Stream the response to the file.
+                // Stream the response to the file, hashing it along the way.
+                let mut hasher = hash.map(|hash| Hasher::from(hash.algorithm));
                 let mut writer = file.as_file();
                 let mut reader = response.bytes_stream();
                 while let Some(chunk) = reader.next().await {
                     use std::io::Write;
-                    writer.write_all(&chunk?)?;
+                    let chunk = chunk?;
+                    if let Some(hasher) = hasher.as_mut() {
+                        hasher.update(&chunk);
+                    }
+                    writer.write_all(&chunk)?;
+                }
+
+                if let (Some(expected), Some(hasher), Some(cache_entry)) =
+                    (hash, hasher, cache_entry)
+                {
+                    let actual = HashDigest::from(hasher);
+                    if !actual.digest.eq_ignore_ascii_case(&expected.digest) {
+                        bail!(
+                            "Hash mismatch for remote script `{url}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}"
+                        );
+                    }
+
+                    // Persist the verified script to the cache.
+                    fs_err::create_dir_all(cache_entry.dir())?;
+                    let temp_file = tempfile::NamedTempFile::new_in(cache_entry.dir())?;
+                    fs_err::copy(file.path(), temp_file.path())?;
+                    temp_file.persist(cache_entry.path())?;
                 }
 
                 return Ok(Self::PythonRemote(url, file, args.to_vec()));
             }
         }
 
+        if hash.is_some() {
+            bail!("`--hash` can only be used with remote scripts");
+        }
+
         if module {
             return Ok(Self::PythonModule(target.clone(), args.to_vec()));
-        } else if gui_script {
+        } else if gui_script || (script && gui) {
             return Ok(Self::PythonGuiScript(target.clone().into(), args.to_vec()));
         } else if script {
             return Ok(Self::PythonScript(target.clone().into(), args.to_vec()));
@@ -1671,13 +1883,21 @@ impl RunCommand {
         let is_dir = metadata.as_ref().is_ok_and(std::fs::Metadata::is_dir);
 
         if target.eq_ignore_ascii_case("python") {
-            Ok(Self::Python(args.to_vec()))
+            if gui {
+                Ok(Self::PythonGui(args.to_vec()))
+            } else {
+                Ok(Self::Python(args.to_vec()))
+            }
         } else if target_path
             .extension()
             .is_some_and(|ext| ext.eq_ignore_ascii_case("py") || ext.eq_ignore_ascii_case("pyc"))
             && is_file
         {
-            Ok(Self::PythonScript(target_path, args.to_vec()))
+            if gui {
+                Ok(Self::PythonGuiScript(target_path, args.to_vec()))
+            } else {
+                Ok(Self::PythonScript(target_path, args.to_vec()))
+            }
         } else if target_path
             .extension()
             .is_some_and(|ext| ext.eq_ignore_ascii_case("pyw"))
@@ -1,14 +1,14 @@
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use serde::Serialize;
 use tracing::warn;
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheSummary};
 use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
@@ -79,6 +79,11 @@ pub(crate) async fn sync(
     printer: Printer,
     preview: Preview,
     output_format: SyncFormat,
+    lock_scripts: bool,
+    zipapp: Option<PathBuf>,
+    zipapp_entry_point: Option<String>,
+    python_shebang: Option<String>,
+    allow_extension_modules: bool,
 ) -> Result<ExitStatus> {
     if preview.is_enabled(PreviewFeatures::JSON_OUTPUT) && matches!(output_format, SyncFormat::Json)
     {
@@ -203,10 +208,12 @@ pub(crate) async fn sync(
     }
 
     // Special-case: we're syncing a script that doesn't have an associated lockfile. In that case,
-    // we don't create a lockfile, so the resolve-and-install semantics are different.
+    // we don't create a lockfile (unless `lock-scripts` is enabled), so the resolve-and-install
+    // semantics are different.
     if let SyncTarget::Script(script) = &target {
         let lockfile = LockTarget::from(script).lock_path();
-        if !lockfile.is_file() {
+        let lock_script = lock_scripts && !frozen && !locked;
+        if !lockfile.is_file() && !lock_script {
             if frozen {
                 return Err(anyhow::anyhow!(
                     "`uv sync --frozen` requires a script lockfile; run `{}` to lock the script",
@@ -272,6 +279,7 @@ pub(crate) async fn sync(
                         script: Some(ScriptReport::from(script)),
                         sync: sync_report,
                         lock: None,
+                        cache: cache.stats().summary(),
                         dry_run: dry_run.enabled(),
                     };
                     if let Some(output) = report.format(output_format) {
@@ -354,20 +362,6 @@ pub(crate) async fn sync(
         writeln!(printer.stderr(), "{message}")?;
     }
 
-    let report = Report {
-        schema: SchemaReport::default(),
-        target: TargetName::from(&target),
-        project: target.project().map(ProjectReport::from),
-        script: target.script().map(ScriptReport::from),
-        sync: sync_report,
-        lock: Some(lock_report),
-        dry_run: dry_run.enabled(),
-    };
-
-    if let Some(output) = report.format(output_format) {
-        writeln!(printer.stdout_important(), "{output}")?;
-    }
-
     // Identify the installation target.
     let sync_target =
         identify_installation_target(&target, outcome.lock(), all_packages, package.as_ref());
@@ -407,6 +401,51 @@ pub(crate) async fn sync(
         Err(err) => return Err(err.into()),
     }
 
+    let report = Report {
+        schema: SchemaReport::default(),
+        target: TargetName::from(&target),
+        project: target.project().map(ProjectReport::from),
+        script: target.script().map(ScriptReport::from),
+        sync: sync_report,
+        lock: Some(lock_report),
+        cache: cache.stats().summary(),
+        dry_run: dry_run.enabled(),
+    };
+
+    if let Some(output) = report.format(output_format) {
+        writeln!(printer.stdout_important(), "{output}")?;
+    }
+
+    // Snapshot the environment into a zipapp, if requested.
+    if let (Some(zipapp), Outcome::Success(..)) = (zipapp.as_deref(), &outcome) {
+        let entry_point = if let Some(entry_point) = zipapp_entry_point {
+            entry_point
+        } else {
+            let scripts = target
+                .project()
+                .map(|project| project.pyproject_toml().scripts().collect::<Vec<_>>())
+                .unwrap_or_default();
+            match scripts.as_slice() {
+                [entry_point] => (*entry_point).to_string(),
+                [] => bail!(
+                    "The project doesn't define an entry point in `[project.scripts]` to run in the zipapp"
+                ),
+                _ => bail!(
+                    "The project defines multiple entry points; use `--zipapp-entry-point` to select the entry point to run in the zipapp"
+                ),
+            }
+        };
+        crate::commands::project::zipapp::create(
+            &environment,
+            zipapp,
+            &entry_point,
+            python_shebang.as_deref(),
+            allow_extension_modules,
+            printer,
+        )
+        .await?;
+    }
+
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
         Outcome::LockMismatch(prev, cur) => {
@@ -493,6 +532,7 @@ fn identify_installation_target<'a>(
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 enum SyncTarget {
     /// Sync a project environment.
@@ -574,6 +614,7 @@ pub(super) async fn do_sync(
         index_strategy,
         keyring_provider,
         dependency_metadata,
+        build_system_overrides,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -587,9 +628,11 @@ pub(super) async fn do_sync(
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
@@ -605,6 +648,12 @@ pub(super) async fn do_sync(
         ));
     }
 
+    // Validate that the Python version is supported by the members to install, which may be
+    // narrower than the lockfile's Python requirement.
+    if preview.is_enabled(PreviewFeatures::MEMBER_REQUIRES_PYTHON) {
+        target.validate_requires_python(venv.interpreter().python_version())?;
+    }
+
     // Validate that the set of requested extras and development groups are compatible.
     detect_conflicts(target.lock(), extras, groups)?;
 
@@ -709,6 +758,7 @@ pub(super) async fn do_sync(
         index_locations,
         &flat_index,
         dependency_metadata,
+        build_system_overrides,
         state.clone().into_inner(),
         index_strategy,
         config_setting,
@@ -817,7 +867,7 @@ fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolu
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_target(target: InstallTarget<'_>) {
+pub(crate) fn store_credentials_from_target(target: InstallTarget<'_>) {
     // Iterate over any indexes in the target.
     for index in target.indexes() {
         if let Some(credentials) = index.credentials() {
@@ -945,6 +995,8 @@ struct Report {
     sync: SyncReport,
     /// The report for the lock operation.
     lock: Option<LockReport>,
+    /// How much of the operation was served from the cache.
+    cache: CacheSummary,
     /// Whether this is a dry run.
     dry_run: bool,
 }
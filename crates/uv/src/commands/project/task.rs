@@ -0,0 +1,246 @@
+//! Tasks defined in `tool.uv.tasks`, run with `uv run <name>`.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::iter;
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow, bail};
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+use uv_workspace::VirtualProject;
+use uv_workspace::pyproject::{Task, TaskCommand};
+
+use crate::commands::project::run::RunCommand;
+
+/// The tasks available in a project.
+///
+/// The tasks of the workspace root are available in all members, and a member can override them
+/// by defining a task with the same name. Inherited tasks run in the directory of the member, such
+/// that a single definition (e.g., `test = "pytest"`) can be shared across the workspace.
+#[derive(Debug, Default)]
+pub(crate) struct Tasks(BTreeMap<String, ProjectTask>);
+
+/// A task, with the directory to run it in.
+#[derive(Debug)]
+struct ProjectTask {
+    task: Task,
+    root: PathBuf,
+}
+
+/// A command to run as part of a task.
+#[derive(Debug)]
+pub(crate) struct TaskStep {
+    /// The name of the task the command belongs to.
+    pub(crate) name: String,
+    pub(crate) command: RunCommand,
+    pub(crate) env: BTreeMap<String, String>,
+    pub(crate) cwd: PathBuf,
+}
+
+impl Tasks {
+    /// Collect the tasks of the project and its workspace root.
+    pub(crate) fn from_project(project: &VirtualProject) -> Self {
+        let workspace = project.workspace();
+        let mut tasks = BTreeMap::new();
+        // Insert the tasks of the project last, so they take precedence.
+        for pyproject_toml in [workspace.pyproject_toml(), project.pyproject_toml()] {
+            let Some(project_tasks) = pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.tasks.as_ref())
+            else {
+                continue;
+            };
+            for (name, task) in project_tasks {
+                tasks.insert(
+                    name.clone(),
+                    ProjectTask {
+                        task: task.clone(),
+                        root: project.root().to_path_buf(),
+                    },
+                );
+            }
+        }
+        Self(tasks)
+    }
+
+    /// Returns `true` if a task with the given name exists.
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Returns `true` if there are no tasks.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the names of the tasks and their descriptions.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.0
+            .iter()
+            .map(|(name, task)| (name.as_str(), task.task.help()))
+    }
+
+    /// Resolve the commands to run for a task, preceded by the commands of the tasks it depends on.
+    ///
+    /// Each task runs at most once, even if multiple tasks depend on it. The arguments are
+    /// appended to the command of the requested task.
+    pub(crate) fn steps(&self, name: &str, args: &[OsString]) -> Result<Vec<TaskStep>> {
+        let mut order = Vec::new();
+        self.visit(name, &mut Vec::new(), &mut FxHashSet::default(), &mut order)?;
+
+        let mut steps = Vec::with_capacity(order.len());
+        for task_name in order {
+            let task = &self.0[task_name];
+            let Some(cmd) = task.task.cmd() else {
+                if task.task.depends_on().is_empty() {
+                    bail!("Task `{task_name}` has neither a command nor dependencies");
+                }
+                if task_name == name && !args.is_empty() {
+                    bail!("Task `{task_name}` has no command, so it doesn't accept arguments");
+                }
+                continue;
+            };
+
+            let arguments = match cmd {
+                TaskCommand::String(command) => shlex::split(command).ok_or_else(|| {
+                    anyhow!("Failed to parse the command of task `{task_name}`: `{command}`")
+                })?,
+                TaskCommand::Args(arguments) => arguments.clone(),
+            };
+            let mut arguments = arguments.into_iter().map(OsString::from);
+            let Some(executable) = arguments.next() else {
+                bail!("Task `{task_name}` has an empty command");
+            };
+            let mut arguments = arguments.collect::<Vec<_>>();
+            if task_name == name {
+                arguments.extend(args.iter().cloned());
+            }
+
+            steps.push(TaskStep {
+                name: task_name.to_string(),
+                command: RunCommand::External(executable, arguments),
+                env: task.task.env().cloned().unwrap_or_default(),
+                cwd: task
+                    .task
+                    .cwd()
+                    .map_or_else(|| task.root.clone(), |cwd| task.root.join(cwd)),
+            });
+        }
+        Ok(steps)
+    }
+
+    /// Add the task and its dependencies to `order` in post-order.
+    fn visit<'a>(
+        &'a self,
+        name: &'a str,
+        stack: &mut Vec<&'a str>,
+        visited: &mut FxHashSet<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) -> Result<()> {
+        if visited.contains(name) {
+            return Ok(());
+        }
+        if let Some(position) = stack.iter().position(|task| *task == name) {
+            let cycle = stack[position..]
+                .iter()
+                .chain(iter::once(&name))
+                .map(|task| format!("`{task}`"))
+                .join(" -> ");
+            bail!("Tasks have a cyclic dependency: {cycle}");
+        }
+        let Some(task) = self.0.get(name) else {
+            if let Some(parent) = stack.last() {
+                bail!("Task `{parent}` depends on unknown task `{name}`");
+            }
+            bail!("Unknown task `{name}`");
+        };
+
+        stack.push(name);
+        for dependency in task.task.depends_on() {
+            self.visit(dependency, stack, visited, order)?;
+        }
+        stack.pop();
+
+        visited.insert(name);
+        order.push(name);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    use uv_workspace::pyproject::Task;
+
+    use super::{ProjectTask, Tasks};
+
+    fn tasks(toml: &str) -> Tasks {
+        let tasks: BTreeMap<String, Task> = toml::from_str(toml).unwrap();
+        Tasks(
+            tasks
+                .into_iter()
+                .map(|(name, task)| {
+                    (
+                        name,
+                        ProjectTask {
+                            task,
+                            root: PathBuf::from("/project"),
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn steps() {
+        let tasks = tasks(
+            r#"
+            lint = ["ruff", "check"]
+            test = { cmd = "pytest -x 'tests/a b'", cwd = "tests", depends-on = ["lint"] }
+            check = { depends-on = ["lint", "test"] }
+            "#,
+        );
+
+        let steps = tasks.steps("check", &[]).unwrap();
+        let names: Vec<_> = steps.iter().map(|step| step.name.as_str()).collect();
+        assert_eq!(names, ["lint", "test"]);
+
+        let steps = tasks.steps("test", &[OsString::from("-v")]).unwrap();
+        assert_eq!(steps[1].command.to_string(), "pytest -x tests/a b -v");
+        assert_eq!(steps[1].cwd, PathBuf::from("/project/tests"));
+        assert_eq!(steps[0].cwd, PathBuf::from("/project"));
+
+        assert!(tasks.steps("check", &[OsString::from("-v")]).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let tasks = tasks(
+            r#"
+            a = { cmd = "a", depends-on = ["b"] }
+            b = { cmd = "b", depends-on = ["a"] }
+            c = { depends-on = ["d"] }
+            "#,
+        );
+        assert_eq!(
+            tasks.steps("a", &[]).unwrap_err().to_string(),
+            "Tasks have a cyclic dependency: `a` -> `b` -> `a`"
+        );
+        assert_eq!(
+            tasks.steps("c", &[]).unwrap_err().to_string(),
+            "Task `c` depends on unknown task `d`"
+        );
+        assert_eq!(
+            tasks.steps("e", &[]).unwrap_err().to_string(),
+            "Unknown task `e`"
+        );
+    }
+}
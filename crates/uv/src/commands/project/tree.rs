@@ -1,20 +1,28 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
 use std::path::Path;
+use std::str::FromStr;
 
 use anstream::print;
 use anyhow::{Error, Result};
 use futures::StreamExt;
+use serde::Serialize;
 use tokio::sync::Semaphore;
+use tracing::debug;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::TreeFormat;
 use uv_client::RegistryClientBuilder;
 use uv_configuration::{Concurrency, DependencyGroups, Preview, TargetTriple};
 use uv_distribution_types::IndexCapabilities;
-use uv_normalize::DefaultGroups;
-use uv_pep508::PackageName;
+use uv_normalize::{DefaultGroups, ExtraName, GroupName};
+use uv_pep440::Version;
+use uv_pep508::{PackageName, VerbatimUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_resolver::{PackageMap, TreeDisplay};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
+use uv_workspace::dependency_groups::FlatDependencyGroups;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::pip::latest::LatestClient;
@@ -38,6 +46,8 @@ pub(crate) async fn tree(
     locked: bool,
     frozen: bool,
     universal: bool,
+    workspace_graph: bool,
+    format: TreeFormat,
     depth: u8,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
@@ -61,6 +71,16 @@ pub(crate) async fn tree(
 ) -> Result<ExitStatus> {
     // Find the project requirements.
     let workspace_cache = WorkspaceCache::default();
+
+    if workspace_graph {
+        let workspace =
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?;
+        let graph = WorkspaceGraph::from_workspace(&workspace)?;
+        write!(printer.stdout(), "{}", graph.render(format)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
     let workspace;
     let target = if let Some(script) = script.as_ref() {
         LockTarget::Script(script)
@@ -199,6 +219,7 @@ pub(crate) async fn tree(
                 prerelease: _,
                 fork_strategy: _,
                 dependency_metadata: _,
+                build_system_overrides: _,
                 config_setting: _,
                 config_settings_package: _,
                 no_build_isolation: _,
@@ -216,8 +237,10 @@ pub(crate) async fn tree(
             let client = RegistryClientBuilder::new(
                 cache.clone().with_refresh(Refresh::All(Timestamp::now())),
             )
-            .retries_from_env()?
+            .retry_settings(&network_settings.retry)
             .native_tls(network_settings.native_tls)
+            .proxy_config(network_settings.proxy.clone())
+            .strict_index_tracking(network_settings.strict_index_tracking)
             .connectivity(network_settings.connectivity)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
             .index_locations(index_locations)
@@ -287,3 +310,214 @@ pub(crate) async fn tree(
 
     Ok(ExitStatus::Success)
 }
+
+/// The graph of the members of a workspace and the dependencies between them.
+#[derive(Debug, Serialize)]
+struct WorkspaceGraph {
+    members: Vec<WorkspaceNode>,
+}
+
+/// A workspace member in the [`WorkspaceGraph`].
+#[derive(Debug, Serialize)]
+struct WorkspaceNode {
+    name: PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<Version>,
+    dependencies: Vec<WorkspaceEdge>,
+}
+
+/// A dependency of a workspace member on another member.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct WorkspaceEdge {
+    name: PackageName,
+    #[serde(flatten)]
+    kind: EdgeKind,
+}
+
+/// Where a dependency between workspace members is declared.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum EdgeKind {
+    /// A dependency in `project.dependencies`.
+    Dependency,
+    /// A dependency in `project.optional-dependencies`.
+    Optional { extra: ExtraName },
+    /// A dependency in a dependency group, including `tool.uv.dev-dependencies`.
+    Group { group: GroupName },
+}
+
+impl std::fmt::Display for EdgeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dependency => Ok(()),
+            Self::Optional { extra } => write!(f, "extra: {extra}"),
+            Self::Group { group } => write!(f, "group: {group}"),
+        }
+    }
+}
+
+impl WorkspaceGraph {
+    /// Build the [`WorkspaceGraph`] from the `pyproject.toml` of each workspace member.
+    fn from_workspace(workspace: &Workspace) -> Result<Self> {
+        let names = workspace.packages().keys().collect::<BTreeSet<_>>();
+
+        let mut members = Vec::with_capacity(names.len());
+        for (name, member) in workspace.packages() {
+            let pyproject_toml = member.pyproject_toml();
+            let project = pyproject_toml.project.as_ref();
+
+            let mut requirements = Vec::new();
+            if let Some(project) = project {
+                requirements.extend(
+                    project
+                        .dependencies
+                        .iter()
+                        .flatten()
+                        .map(|requirement| (requirement.as_str(), EdgeKind::Dependency)),
+                );
+                requirements.extend(project.optional_dependencies.iter().flatten().flat_map(
+                    |(extra, requirements)| {
+                        requirements.iter().map(|requirement| {
+                            (
+                                requirement.as_str(),
+                                EdgeKind::Optional {
+                                    extra: extra.clone(),
+                                },
+                            )
+                        })
+                    },
+                ));
+            }
+
+            let mut dependencies = BTreeSet::new();
+            for (requirement, kind) in requirements {
+                let requirement = match uv_pep508::Requirement::<VerbatimUrl>::from_str(requirement)
+                {
+                    Ok(requirement) => requirement,
+                    Err(err) => {
+                        debug!("Ignoring invalid requirement of `{name}`: {err}");
+                        continue;
+                    }
+                };
+                if names.contains(&requirement.name) && requirement.name != *name {
+                    dependencies.insert(WorkspaceEdge {
+                        name: requirement.name,
+                        kind,
+                    });
+                }
+            }
+
+            let groups = FlatDependencyGroups::from_pyproject_toml(
+                member.root(),
+                pyproject_toml,
+                workspace,
+            )?;
+            for (group, flat_group) in groups {
+                for requirement in flat_group.requirements {
+                    if names.contains(&requirement.name) && requirement.name != *name {
+                        dependencies.insert(WorkspaceEdge {
+                            name: requirement.name,
+                            kind: EdgeKind::Group {
+                                group: group.clone(),
+                            },
+                        });
+                    }
+                }
+            }
+
+            members.push(WorkspaceNode {
+                name: name.clone(),
+                version: project.and_then(|project| project.version.clone()),
+                dependencies: dependencies.into_iter().collect(),
+            });
+        }
+
+        Ok(Self { members })
+    }
+
+    /// Render the [`WorkspaceGraph`] in the given format.
+    fn render(&self, format: TreeFormat) -> Result<String> {
+        let mut output = String::new();
+        match format {
+            TreeFormat::Text => {
+                let labels = self
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let label = match &member.version {
+                            Some(version) => format!("{} v{version}", member.name),
+                            None => member.name.to_string(),
+                        };
+                        (&member.name, label)
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                for member in &self.members {
+                    writeln!(output, "{}", labels[&member.name])?;
+                    for (index, edge) in member.dependencies.iter().enumerate() {
+                        let prefix = if index + 1 == member.dependencies.len() {
+                            "└── "
+                        } else {
+                            "├── "
+                        };
+                        if matches!(edge.kind, EdgeKind::Dependency) {
+                            writeln!(output, "{prefix}{}", labels[&edge.name])?;
+                        } else {
+                            writeln!(output, "{prefix}{} ({})", labels[&edge.name], edge.kind)?;
+                        }
+                    }
+                }
+            }
+            TreeFormat::Dot => {
+                writeln!(output, "digraph workspace {{")?;
+                for member in &self.members {
+                    writeln!(output, "    \"{}\";", member.name)?;
+                }
+                for member in &self.members {
+                    for edge in &member.dependencies {
+                        if matches!(edge.kind, EdgeKind::Dependency) {
+                            writeln!(output, "    \"{}\" -> \"{}\";", member.name, edge.name)?;
+                        } else {
+                            writeln!(
+                                output,
+                                "    \"{}\" -> \"{}\" [label=\"{}\", style=dashed];",
+                                member.name, edge.name, edge.kind
+                            )?;
+                        }
+                    }
+                }
+                writeln!(output, "}}")?;
+            }
+            TreeFormat::Mermaid => {
+                // Mermaid node identifiers can't contain dashes, so we use the index of each member
+                // as its identifier, and its name as the label.
+                let ids = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .map(|(index, member)| (&member.name, format!("n{index}")))
+                    .collect::<BTreeMap<_, _>>();
+                writeln!(output, "flowchart TD")?;
+                for member in &self.members {
+                    writeln!(output, "    {}[\"{}\"]", ids[&member.name], member.name)?;
+                }
+                for member in &self.members {
+                    for edge in &member.dependencies {
+                        if matches!(edge.kind, EdgeKind::Dependency) {
+                            writeln!(output, "    {} --> {}", ids[&member.name], ids[&edge.name])?;
+                        } else {
+                            writeln!(
+                                output,
+                                "    {} -.->|{}| {}",
+                                ids[&member.name], edge.kind, ids[&edge.name]
+                            )?;
+                        }
+                    }
+                }
+            }
+            TreeFormat::Json => {
+                writeln!(output, "{}", serde_json::to_string_pretty(self)?)?;
+            }
+        }
+        Ok(output)
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
 
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Context, Result, anyhow, bail};
 use owo_colors::OwoColorize;
 
 use tracing::debug;
@@ -54,12 +54,13 @@ pub(crate) fn self_version(
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn project_version(
     value: Option<String>,
-    mut bump: Vec<VersionBump>,
+    bump: Vec<VersionBump>,
     short: bool,
     output_format: VersionFormat,
     project_dir: &Path,
-    package: Option<PackageName>,
+    package: Vec<PackageName>,
     explicit_project: bool,
+    workspace: bool,
     dry_run: bool,
     locked: bool,
     frozen: bool,
@@ -78,6 +79,42 @@ pub(crate) async fn project_version(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if workspace {
+        return Box::pin(workspace_version(
+            value,
+            bump,
+            short,
+            output_format,
+            project_dir,
+            &package,
+            explicit_project,
+            dry_run,
+            locked,
+            frozen,
+            active,
+            no_sync,
+            python,
+            install_mirrors,
+            settings,
+            network_settings,
+            python_preference,
+            python_downloads,
+            installer_metadata,
+            concurrency,
+            no_config,
+            cache,
+            printer,
+            preview,
+        ))
+        .await;
+    }
+
+    let package = match package.as_slice() {
+        [] => None,
+        [package] => Some(package.clone()),
+        _ => bail!("`--package` can only be provided multiple times with `--workspace`"),
+    };
+
     // Read the metadata
     let project = find_target(project_dir, package.as_ref(), explicit_project).await?;
 
@@ -139,10 +176,240 @@ pub(crate) async fn project_version(
     })?;
 
     // Figure out new metadata
+    let new_version = resolve_new_version(&old_version, value.as_deref(), &bump)?;
+
+    // Update the toml and lock
+    let status = if dry_run {
+        ExitStatus::Success
+    } else if let Some(new_version) = &new_version {
+        let project = update_project(project, new_version, &mut toml, &pyproject_path)?;
+        Box::pin(lock_and_sync(
+            project,
+            project_dir,
+            locked,
+            frozen,
+            active,
+            no_sync,
+            python,
+            install_mirrors,
+            &settings,
+            network_settings,
+            python_preference,
+            python_downloads,
+            installer_metadata,
+            concurrency,
+            no_config,
+            cache,
+            printer,
+            preview,
+        ))
+        .await?
+    } else {
+        debug!("No changes to version; skipping update");
+        ExitStatus::Success
+    };
+
+    // Report the results
+    let old_version = VersionInfo::new(Some(&name), &old_version);
+    let new_version = new_version.map(|version| VersionInfo::new(Some(&name), &version));
+    print_version(old_version, new_version, short, output_format, printer)?;
+
+    Ok(status)
+}
+
+/// Read or update the version of every member in the workspace (`uv version --workspace`).
+///
+/// If any packages are provided, only those members are read or updated.
+///
+/// When updating, any requirements on the updated members that pin their previous versions are
+/// updated to match, and the workspace is re-locked once.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn workspace_version(
+    value: Option<String>,
+    bump: Vec<VersionBump>,
+    short: bool,
+    output_format: VersionFormat,
+    project_dir: &Path,
+    packages: &[PackageName],
+    explicit_project: bool,
+    dry_run: bool,
+    locked: bool,
+    frozen: bool,
+    active: Option<bool>,
+    no_sync: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let project = find_target(project_dir, None, explicit_project).await?;
+    let workspace = project.workspace();
+
+    if let Some(package) = packages
+        .iter()
+        .find(|package| !workspace.packages().contains_key(*package))
+    {
+        bail!("Package `{package}` not found in workspace");
+    }
+
+    // Determine the new version of each member. Members without a static version (e.g., with a
+    // dynamic version) are skipped, unless they were selected explicitly.
+    let mut versions = Vec::new();
+    for (name, member) in workspace.packages() {
+        if !packages.is_empty() && !packages.contains(name) {
+            continue;
+        }
+        let pyproject_path = member.root().join("pyproject.toml");
+        let mut toml = PyProjectTomlMut::from_toml(
+            member.pyproject_toml().raw.as_ref(),
+            DependencyTarget::PyProjectToml,
+        )?;
+        let old_version = match toml.version() {
+            Ok(version) => version,
+            Err(Error::MalformedWorkspace) => {
+                if !packages.is_empty() {
+                    bail!("Workspace member `{name}` does not have a static `project.version`");
+                }
+                debug!("Skipping workspace member without a static version: `{name}`");
+                continue;
+            }
+            Err(err) => return Err(anyhow!("{err}: {}", pyproject_path.user_display())),
+        };
+        let new_version = resolve_new_version(&old_version, value.as_deref(), &bump)?;
+        versions.push((name, old_version, new_version));
+    }
+
+    if versions.is_empty() {
+        return Err(anyhow!(
+            "No workspace members with a static `project.version` found in: {}",
+            workspace.install_path().user_display()
+        ));
+    }
+
+    let updates = versions
+        .iter()
+        .filter_map(|(name, old_version, new_version)| {
+            Some((*name, old_version, new_version.as_ref()?))
+        })
+        .collect::<Vec<_>>();
+
+    // Update the toml and lock
+    let status = if dry_run {
+        ExitStatus::Success
+    } else if !updates.is_empty() {
+        // Collect the `pyproject.toml` of each member, along with that of the workspace root,
+        // which may not be a member itself.
+        let mut pyprojects = workspace
+            .packages()
+            .iter()
+            .map(|(name, member)| (Some(name), member.root(), member.pyproject_toml()))
+            .collect::<Vec<_>>();
+        if !pyprojects
+            .iter()
+            .any(|(.., root, _)| *root == workspace.install_path())
+        {
+            pyprojects.push((None, workspace.install_path(), workspace.pyproject_toml()));
+        }
+
+        // Update the version of each member, and any requirements that pin a previous version.
+        for (name, root, pyproject_toml) in pyprojects {
+            let raw = &pyproject_toml.raw;
+            let mut toml = PyProjectTomlMut::from_toml(raw, DependencyTarget::PyProjectToml)?;
+            if let Some((.., new_version)) =
+                updates.iter().find(|(member, ..)| Some(*member) == name)
+            {
+                toml.set_version(new_version)?;
+            }
+            for (member, old_version, new_version) in &updates {
+                for requirement in toml.replace_dependency_version(member, old_version, new_version)
+                {
+                    debug!(
+                        "Updated requirement `{requirement}` in: `{}`",
+                        root.user_display()
+                    );
+                }
+            }
+
+            let content = toml.to_string();
+            if content != *raw {
+                fs_err::write(root.join("pyproject.toml"), &content)?;
+            }
+        }
+
+        // Re-discover the project to incorporate the updates.
+        let project = find_target(project_dir, None, explicit_project).await?;
+        Box::pin(lock_and_sync(
+            project,
+            project_dir,
+            locked,
+            frozen,
+            active,
+            no_sync,
+            python,
+            install_mirrors,
+            &settings,
+            network_settings,
+            python_preference,
+            python_downloads,
+            installer_metadata,
+            concurrency,
+            no_config,
+            cache,
+            printer,
+            preview,
+        ))
+        .await?
+    } else {
+        debug!("No changes to version; skipping update");
+        ExitStatus::Success
+    };
+
+    // Report the results
+    match output_format {
+        VersionFormat::Text => {
+            for (name, old_version, new_version) in &versions {
+                let old_version = VersionInfo::new(Some(*name), old_version);
+                let new_version = new_version
+                    .as_ref()
+                    .map(|version| VersionInfo::new(Some(*name), version));
+                print_version(old_version, new_version, short, output_format, printer)?;
+            }
+        }
+        VersionFormat::Json => {
+            let versions = versions
+                .iter()
+                .map(|(name, old_version, new_version)| {
+                    VersionInfo::new(Some(*name), new_version.as_ref().unwrap_or(old_version))
+                })
+                .collect::<Vec<_>>();
+            let string = serde_json::to_string_pretty(&versions)?;
+            writeln!(printer.stdout(), "{string}")?;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Determine the new version for a project, given an explicit version or a series of bumps.
+///
+/// Returns `None` if the version should remain unchanged.
+fn resolve_new_version(
+    old_version: &Version,
+    value: Option<&str>,
+    bump: &[VersionBump],
+) -> Result<Option<Version>> {
     let new_version = if let Some(value) = value {
-        match Version::from_str(&value) {
+        match Version::from_str(value) {
             Ok(version) => Some(version),
-            Err(err) => match &*value {
+            Err(err) => match value {
                 "major" | "minor" | "patch" | "alpha" | "beta" | "rc" | "dev" | "post"
                 | "stable" => {
                     return Err(anyhow!(
@@ -246,6 +513,7 @@ pub(crate) async fn project_version(
 
         // Sort the given commands so the user doesn't have to care about
         // the ordering of `--bump minor --bump beta` (only one ordering is ever useful)
+        let mut bump = bump.to_vec();
         bump.sort();
 
         // Apply all the bumps
@@ -271,7 +539,7 @@ pub(crate) async fn project_version(
             new_version.bump(command);
         }
 
-        if new_version <= old_version {
+        if new_version <= *old_version {
             if old_version.is_stable() && new_version.is_pre() {
                 return Err(anyhow!(
                     "{old_version} => {new_version} didn't increase the version; when bumping to a pre-release version you also need to increase a release version component, e.g., with `--bump <major|minor|patch>`"
@@ -287,43 +555,7 @@ pub(crate) async fn project_version(
         None
     };
 
-    // Update the toml and lock
-    let status = if dry_run {
-        ExitStatus::Success
-    } else if let Some(new_version) = &new_version {
-        let project = update_project(project, new_version, &mut toml, &pyproject_path)?;
-        Box::pin(lock_and_sync(
-            project,
-            project_dir,
-            locked,
-            frozen,
-            active,
-            no_sync,
-            python,
-            install_mirrors,
-            &settings,
-            network_settings,
-            python_preference,
-            python_downloads,
-            installer_metadata,
-            concurrency,
-            no_config,
-            cache,
-            printer,
-            preview,
-        ))
-        .await?
-    } else {
-        debug!("No changes to version; skipping update");
-        ExitStatus::Success
-    };
-
-    // Report the results
-    let old_version = VersionInfo::new(Some(&name), &old_version);
-    let new_version = new_version.map(|version| VersionInfo::new(Some(&name), &version));
-    print_version(old_version, new_version, short, output_format, printer)?;
-
-    Ok(status)
+    Ok(new_version)
 }
 
 /// Add hint to use `uv self version` when workspace discovery fails due to missing pyproject.toml
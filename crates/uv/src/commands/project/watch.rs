@@ -0,0 +1,317 @@
+//! Re-run `uv run` whenever the project changes, i.e., `uv run --watch`.
+//!
+//! Rather than re-running the command in-process, we supervise a `uv run` child process with the
+//! same arguments, such that each run re-reads the project configuration and re-syncs the
+//! environment as needed. Changes are detected via the platform's file system notifications
+//! (e.g., `inotify` on Linux, `FSEvents` on macOS, and `ReadDirectoryChangesW` on Windows).
+
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{EventKind, RecursiveMode, Watcher};
+use owo_colors::OwoColorize;
+use tokio::process::{Child, Command};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The files that are watched by default.
+const DEFAULT_GLOBS: &[&str] = &["**/*.py"];
+
+/// The files that are always watched, as they affect the environment.
+const PROJECT_GLOBS: &[&str] = &["**/pyproject.toml", "**/uv.lock"];
+
+/// How long to wait for further changes once a change is detected, such that a burst of changes
+/// (e.g., from a `git checkout`) only restarts the command once.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// How long to wait for the command to exit after asking it to terminate.
+const TERMINATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Run `uv run` with the current arguments, re-running it whenever a watched file changes.
+pub(crate) async fn watch(root: &Path, globs: &[String], printer: Printer) -> Result<ExitStatus> {
+    let globs = {
+        let mut builder = GlobSetBuilder::new();
+        let globs = if globs.is_empty() {
+            DEFAULT_GLOBS.iter().map(ToString::to_string).collect()
+        } else {
+            globs.to_vec()
+        };
+        for glob in globs
+            .iter()
+            .map(String::as_str)
+            .chain(PROJECT_GLOBS.iter().copied())
+        {
+            builder.add(Glob::new(glob).with_context(|| format!("Invalid watch glob: `{glob}`"))?);
+        }
+        builder.build()?
+    };
+
+    // Events are reported for the canonical path (e.g., on macOS, where temporary directories are
+    // symlinked), so watch the canonical path.
+    let root = root.simple_canonicalize()?;
+
+    // Forward the file system events to the runtime.
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = sender.send(event);
+    })
+    .context("Failed to initialize the file watcher")?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch `{}`", root.user_display()))?;
+
+    let executable = std::env::current_exe()?;
+    loop {
+        let mut child = Command::new(&executable)
+            .args(std::env::args_os().skip(1))
+            .env(EnvVars::UV_INTERNAL__WATCHED, "1")
+            .spawn()
+            .context("Failed to spawn `uv run`")?;
+
+        // On Windows, terminating `uv run` doesn't terminate the command it's running, so track
+        // the process tree in a job object.
+        #[cfg(windows)]
+        let job = {
+            let job = job::Job::new()?;
+            job.assign(&child)?;
+            job
+        };
+
+        // Wait for the command to exit, or for a change, whichever comes first.
+        let mut exited = false;
+        let changed = loop {
+            let event = if exited {
+                receiver.recv().await
+            } else {
+                tokio::select! {
+                    status = child.wait() => {
+                        let status = status?;
+                        debug!("Command exited with {status}");
+                        writeln!(
+                            printer.stderr(),
+                            "{}",
+                            "Waiting for changes...".dimmed()
+                        )?;
+                        exited = true;
+                        continue;
+                    }
+                    event = receiver.recv() => event,
+                }
+            };
+            let Some(event) = event else {
+                bail!("The file watcher stopped unexpectedly");
+            };
+            if let Some(path) = changed_path(&root, &globs, event) {
+                break path;
+            }
+        };
+
+        // Let the burst of changes settle, then discard the remaining events.
+        tokio::time::sleep(DEBOUNCE).await;
+        while receiver.try_recv().is_ok() {}
+
+        if !exited {
+            terminate(&mut child).await?;
+        }
+        #[cfg(windows)]
+        job.terminate()?;
+
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Detected change in `{}`, restarting...",
+                changed.user_display()
+            )
+            .dimmed()
+        )?;
+    }
+}
+
+/// Returns the watched file that was added, modified, or removed in a file system event, if any.
+fn changed_path(
+    root: &Path,
+    globs: &GlobSet,
+    event: notify::Result<notify::Event>,
+) -> Option<PathBuf> {
+    let event = match event {
+        Ok(event) => event,
+        Err(err) => {
+            warn!("Failed to watch for changes: {err}");
+            return None;
+        }
+    };
+    if !matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        return None;
+    }
+    event
+        .paths
+        .into_iter()
+        .find(|path| is_watched(root, globs, path))
+}
+
+/// Returns `true` if the file matches the globs.
+///
+/// Files in hidden directories (e.g., `.venv` and `.git`) and `__pycache__` directories are
+/// ignored.
+fn is_watched(root: &Path, globs: &GlobSet, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let ignored = relative
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .any(|component| {
+            let Component::Normal(name) = component else {
+                return false;
+            };
+            name.to_str()
+                .is_some_and(|name| name.starts_with('.') || name == "__pycache__")
+        });
+    !ignored && globs.is_match(relative)
+}
+
+/// Ask the `uv run` child process to terminate, killing it if it doesn't exit in time.
+///
+/// On Unix, `uv run` forwards `SIGTERM` to the command it's running.
+async fn terminate(child: &mut Child) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{Signal, kill};
+        use nix::unistd::Pid;
+
+        if let Some(pid) = child.id().and_then(|pid| i32::try_from(pid).ok()) {
+            let _ = kill(Pid::from_raw(pid), Signal::SIGTERM);
+            if tokio::time::timeout(TERMINATE_TIMEOUT, child.wait())
+                .await
+                .is_ok()
+            {
+                return Ok(());
+            }
+            debug!("Command didn't exit after `SIGTERM`, killing it");
+        }
+    }
+
+    child.kill().await?;
+    Ok(())
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)]
+mod job {
+    use std::ffi::c_void;
+
+    use anyhow::{Context, Result};
+    use tokio::process::Child;
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, TerminateJobObject,
+    };
+    use windows::core::PCWSTR;
+
+    /// A job object containing the `uv run` child process, along with any processes it spawns.
+    ///
+    /// The processes in the job are killed when the job is closed, e.g., if `uv run --watch`
+    /// itself is terminated.
+    pub(super) struct Job(HANDLE);
+
+    impl Job {
+        pub(super) fn new() -> Result<Self> {
+            // SAFETY: There are no preconditions; the job is unnamed and has default security.
+            let job = Self(
+                unsafe { CreateJobObjectW(None, PCWSTR::null()) }
+                    .context("Failed to create a job object")?,
+            );
+            let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+            // SAFETY: The information matches the information class, and outlives the call.
+            unsafe {
+                SetInformationJobObject(
+                    job.0,
+                    JobObjectExtendedLimitInformation,
+                    std::ptr::from_ref(&info).cast::<c_void>(),
+                    u32::try_from(size_of_val(&info))?,
+                )
+            }
+            .context("Failed to configure the job object")?;
+            Ok(job)
+        }
+
+        /// Add the child process (and, in turn, any processes it spawns) to the job.
+        pub(super) fn assign(&self, child: &Child) -> Result<()> {
+            let Some(handle) = child.raw_handle() else {
+                // The process already exited.
+                return Ok(());
+            };
+            // SAFETY: The process handle is valid for as long as `child` is alive.
+            unsafe { AssignProcessToJobObject(self.0, HANDLE(handle)) }
+                .context("Failed to assign `uv run` to the job object")
+        }
+
+        /// Terminate any processes remaining in the job.
+        pub(super) fn terminate(&self) -> Result<()> {
+            // SAFETY: The job handle is valid until the job is dropped.
+            unsafe { TerminateJobObject(self.0, 1) }.context("Failed to terminate the job object")
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            // SAFETY: The job handle is owned, and isn't used after it's closed.
+            let _ = unsafe { CloseHandle(self.0) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watched() {
+        let root = Path::new("/project");
+        let mut builder = GlobSetBuilder::new();
+        builder.add(Glob::new("**/*.py").unwrap());
+        builder.add(Glob::new("**/pyproject.toml").unwrap());
+        let globs = builder.build().unwrap();
+
+        assert!(is_watched(root, &globs, Path::new("/project/main.py")));
+        assert!(is_watched(
+            root,
+            &globs,
+            Path::new("/project/src/foo/bar.py")
+        ));
+        assert!(is_watched(
+            root,
+            &globs,
+            Path::new("/project/packages/foo/pyproject.toml")
+        ));
+        assert!(!is_watched(root, &globs, Path::new("/project/README.md")));
+        assert!(!is_watched(
+            root,
+            &globs,
+            Path::new("/project/.venv/lib/site.py")
+        ));
+        assert!(!is_watched(
+            root,
+            &globs,
+            Path::new("/project/src/__pycache__/bar.py")
+        ));
+        assert!(!is_watched(root, &globs, Path::new("/elsewhere/main.py")));
+    }
+}
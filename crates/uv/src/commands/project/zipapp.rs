@@ -0,0 +1,178 @@
+//! Snapshot a synced environment into a Python zipapp, i.e., `uv sync --zipapp`.
+//!
+//! The zipapp contains the packages of the environment, precompiled to bytecode, and a
+//! `__main__.py` that runs an entry point, such that it can be executed directly without
+//! discovering the project or checking that the environment is up-to-date.
+
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_python::PythonEnvironment;
+use uv_warnings::warn_user;
+
+use crate::printer::Printer;
+
+/// The files created by uv in every virtual environment, which aren't needed in the zipapp.
+const VIRTUALENV_FILES: &[&str] = &["_virtualenv.pth", "_virtualenv.py"];
+
+/// The interpreter written to the zipapp's shebang line, if none is provided.
+const DEFAULT_SHEBANG: &str = "/usr/bin/env python3";
+
+/// Create a zipapp at `output` from the packages in the environment, running the console script
+/// with the given name.
+///
+/// Unless `allow_extension_modules` is set, warns if the environment contains compiled extension
+/// modules, which can't be imported from the zipapp.
+pub(crate) async fn create(
+    environment: &PythonEnvironment,
+    output: &Path,
+    entry_point: &str,
+    python_shebang: Option<&str>,
+    allow_extension_modules: bool,
+    printer: Printer,
+) -> Result<()> {
+    let python = environment.interpreter().sys_executable();
+    let staging = tempfile::tempdir()?;
+    let app = staging.path().join("app");
+    fs_err::create_dir_all(&app)?;
+
+    // Copy the packages into the staging directory.
+    let mut extension_modules = Vec::new();
+    for site_packages in environment.site_packages() {
+        copy_site_packages(&site_packages, &app, &mut extension_modules)?;
+    }
+    if !allow_extension_modules && !extension_modules.is_empty() {
+        extension_modules.sort();
+        warn_user!(
+            "The environment contains compiled extension modules, which can't be imported from a zipapp (pass `--allow-extension-modules` to silence this warning):\n{}",
+            extension_modules
+                .iter()
+                .map(|path| format!("  {}", path.user_display().cyan()))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+    fs_err::write(
+        app.join("__main__.py"),
+        format!(
+            "import sys\n\
+             from importlib.metadata import entry_points\n\
+             \n\
+             (entry_point,) = entry_points(group=\"console_scripts\", name={entry_point:?})\n\
+             sys.exit(entry_point.load()())\n"
+        ),
+    )?;
+
+    // Compile the sources to bytecode. `zipimport` doesn't read from `__pycache__`, so the bytecode
+    // is written next to the sources.
+    debug!("Compiling zipapp sources to bytecode");
+    run_python(
+        python,
+        [
+            OsStr::new("-m"),
+            OsStr::new("compileall"),
+            OsStr::new("-q"),
+            OsStr::new("-b"),
+            app.as_os_str(),
+        ],
+    )
+    .await
+    .context("Failed to compile the zipapp to bytecode")?;
+
+    // Create the zipapp itself.
+    if let Some(parent) = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs_err::create_dir_all(parent)?;
+    }
+    run_python(
+        python,
+        [
+            OsStr::new("-m"),
+            OsStr::new("zipapp"),
+            app.as_os_str(),
+            OsStr::new("--output"),
+            output.as_os_str(),
+            OsStr::new("--python"),
+            OsStr::new(python_shebang.unwrap_or(DEFAULT_SHEBANG)),
+        ],
+    )
+    .await
+    .context("Failed to create the zipapp")?;
+
+    writeln!(
+        printer.stderr(),
+        "Created zipapp at: {}",
+        output.user_display().bold()
+    )?;
+
+    Ok(())
+}
+
+/// Copy the contents of a `site-packages` directory into the zipapp, collecting the paths of any
+/// compiled extension modules.
+fn copy_site_packages(
+    site_packages: &Path,
+    app: &Path,
+    extension_modules: &mut Vec<PathBuf>,
+) -> Result<()> {
+    for entry in walkdir::WalkDir::new(site_packages)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != "__pycache__")
+    {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(site_packages)
+            .expect("walkdir starts with root");
+        let target = app.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs_err::create_dir_all(&target)?;
+            continue;
+        }
+
+        if entry.depth() == 1
+            && VIRTUALENV_FILES
+                .iter()
+                .any(|name| entry.file_name() == OsStr::new(name))
+        {
+            continue;
+        }
+        let extension = relative.extension().and_then(OsStr::to_str);
+        match extension {
+            Some("so" | "pyd") => extension_modules.push(relative.to_path_buf()),
+            Some("pth") => bail!(
+                "The environment contains a `.pth` file, which isn't supported in a zipapp: `{}`; use `--no-editable` to sync the project without an editable install",
+                relative.user_display()
+            ),
+            Some("pyc") => continue,
+            _ => {}
+        }
+        fs_err::copy(entry.path(), &target)?;
+    }
+    Ok(())
+}
+
+/// Run the Python interpreter with the given arguments, returning an error if it fails.
+async fn run_python<'a>(python: &Path, args: impl IntoIterator<Item = &'a OsStr>) -> Result<()> {
+    let output = Command::new(python).args(args).output().await?;
+    if !output.status.success() {
+        bail!(
+            "`{}` exited with {}:\n{}",
+            python.user_display(),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
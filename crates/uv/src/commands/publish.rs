@@ -1,38 +1,127 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::iter;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, bail};
 use console::Term;
+use futures::StreamExt;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tokio::sync::Semaphore;
 use tracing::{debug, info};
 use uv_auth::Credentials;
 use uv_cache::Cache;
+use uv_cli::BuildFormat;
 use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBuilder};
-use uv_configuration::{KeyringProviderType, TrustedPublishing};
+use uv_configuration::{Concurrency, KeyringProviderType, Preview, TrustedPublishing};
+use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep508::{Requirement, VerbatimUrl};
 use uv_publish::{
-    CheckUrlClient, TrustedPublishResult, check_trusted_publishing, files_for_publishing, upload,
+    Attester, CheckUrlClient, PublishError, Signer, TrustedPublishResult, UploadState,
+    ValidationIssue, check_trusted_publishing, files_for_publishing, prepare_upload, read_bundle,
+    supports_attestations, upload, validate_metadata,
 };
+use uv_python::{PythonDownloads, PythonPreference};
 use uv_redacted::DisplaySafeUrl;
-use uv_warnings::warn_user_once;
+use uv_settings::PythonInstallMirrors;
+use uv_sigstore::{SigstoreConfig, SigstoreInstance, tuf_url};
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::reporters::PublishReporter;
-use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::commands::{ExitStatus, build_frontend, human_readable_bytes};
 use crate::printer::Printer;
-use crate::settings::NetworkSettings;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// How long to wait for a published package to become available on the index.
+const INDEX_AVAILABILITY_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// How often to check whether a published package is available on the index.
+const INDEX_AVAILABILITY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// An index to publish the files to.
+#[derive(Debug, Clone)]
+pub(crate) struct PublishTarget {
+    /// The URL of the upload endpoint.
+    pub(crate) publish_url: DisplaySafeUrl,
+    /// The index URL to check for existing files to skip duplicate uploads.
+    pub(crate) check_url: Option<IndexUrl>,
+    /// The credentials configured for the index, e.g., through `UV_INDEX_<NAME>_USERNAME` and
+    /// `UV_INDEX_<NAME>_PASSWORD`, which take precedence over `--username` and `--password`.
+    pub(crate) credentials: Option<Credentials>,
+    /// The number of times to retry uploads that fail with a transient error, if configured for
+    /// the index.
+    pub(crate) retries: Option<u32>,
+}
+
+impl PublishTarget {
+    /// Resolve the target for an index in the configuration by its name.
+    pub(crate) fn from_index(index_name: &str, index_locations: &IndexLocations) -> Result<Self> {
+        debug!("Publishing with index {index_name}");
+        let index = index_locations
+            .simple_indexes()
+            .find(|index| {
+                index
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.as_ref() == index_name)
+            })
+            .with_context(|| {
+                let mut index_names: Vec<String> = index_locations
+                    .simple_indexes()
+                    .filter_map(|index| index.name.as_ref())
+                    .map(ToString::to_string)
+                    .collect();
+                index_names.sort();
+                if index_names.is_empty() {
+                    format!("No indexes were found, can't use index: `{index_name}`")
+                } else {
+                    let index_names = index_names.join("`, `");
+                    format!("Index not found: `{index_name}`. Found indexes: `{index_names}`")
+                }
+            })?;
+        let publish_url = index
+            .publish_url
+            .clone()
+            .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
+        // Only consult the environment, the index URL is used for checking for existing files,
+        // not for uploading.
+        let credentials = index
+            .name
+            .as_ref()
+            .and_then(|name| Credentials::from_env(name.to_env_var()));
+        Ok(Self {
+            publish_url,
+            check_url: Some(index.url.clone()),
+            credentials,
+            retries: index.retries,
+        })
+    }
+}
 
 pub(crate) async fn publish(
     paths: Vec<String>,
-    publish_url: DisplaySafeUrl,
+    targets: Vec<PublishTarget>,
     trusted_publishing: TrustedPublishing,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     username: Option<String>,
     password: Option<String>,
-    check_url: Option<IndexUrl>,
+    attest: bool,
+    sign: bool,
+    sign_key: Option<PathBuf>,
+    sigstore: &SigstoreConfig,
+    check: bool,
+    concurrent_uploads: usize,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -41,10 +130,8 @@ pub(crate) async fn publish(
     }
 
     let files = files_for_publishing(paths)?;
-    match files.len() {
-        0 => bail!("No files found to publish"),
-        1 => writeln!(printer.stderr(), "Publishing 1 file to {publish_url}")?,
-        n => writeln!(printer.stderr(), "Publishing {n} files {publish_url}")?,
+    if files.is_empty() {
+        bail!("No files found to publish");
     }
 
     // * For the uploads themselves, we roll our own retries due to
@@ -61,6 +148,8 @@ pub(crate) async fn publish(
         .retries(0)
         .keyring(keyring_provider)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
@@ -71,95 +160,723 @@ pub(crate) async fn publish(
     let oidc_client = BaseClientBuilder::new()
         .auth_integration(AuthIntegration::NoAuthMiddleware)
         .wrap_existing(&upload_client);
+    // The configured retry policy, which is applied in the upload loop.
+    let upload_retries = BaseClientBuilder::new().retry_settings(&network_settings.retry);
+    let retryable_strategy = upload_retries.retryable_strategy();
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
 
-    let (publish_url, credentials) = gather_credentials(
-        publish_url,
-        username,
-        password,
-        trusted_publishing,
-        keyring_provider,
-        &oidc_client,
-        check_url.as_ref(),
-        Prompt::Enabled,
-        printer,
+    if check {
+        match files.len() {
+            1 => writeln!(printer.stderr(), "Checking 1 file")?,
+            n => writeln!(printer.stderr(), "Checking {n} files")?,
+        }
+        let check_url_clients = targets
+            .iter()
+            .filter_map(|target| target.check_url.as_ref())
+            .map(|index_url| {
+                check_url_client(
+                    index_url,
+                    &upload_client,
+                    keyring_provider,
+                    network_settings,
+                    cache,
+                )
+            })
+            .collect::<Vec<_>>();
+        return check_files(files, &check_url_clients, &download_concurrency, printer).await;
+    }
+
+    // Only indexes that implement PEP 740 accept attestations.
+    if attest {
+        for target in targets
+            .iter()
+            .filter(|target| !supports_attestations(&target.publish_url))
+        {
+            warn_user!(
+                "{} does not support publish attestations (PEP 740), uploading without attestations",
+                target.publish_url
+            );
+        }
+    }
+    let attest = attest
+        && targets
+            .iter()
+            .any(|target| supports_attestations(&target.publish_url));
+
+    let sigstore = if attest || sign {
+        Some(
+            SigstoreInstance::resolve(sigstore, oidc_client.for_host(&tuf_url()).raw_client())
+                .await
+                .context("Failed to load the Sigstore configuration")?,
+        )
+    } else {
+        None
+    };
+
+    // Request a single signing certificate for the attestations of all files.
+    let attester = match &sigstore {
+        Some(instance) if attest => Some(
+            Attester::new(
+                oidc_client.for_host(instance.fulcio_url()).raw_client(),
+                instance,
+            )
+            .await
+            .context("Failed to generate publish attestations")?,
+        ),
+        _ => None,
+    };
+
+    // A signing key is loaded upfront, while for keyless signing, we only request a signing
+    // certificate once a file needs to be signed.
+    let mut signer = match (&sigstore, &sign_key) {
+        (Some(instance), Some(sign_key)) if sign => {
+            Some(Signer::from_key(sign_key, instance).context("Failed to load signing key")?)
+        }
+        _ => None,
+    };
+
+    // Read the metadata and hash each file once, even when publishing to multiple indexes.
+    let mut uploads = Vec::with_capacity(files.len());
+    for (file, raw_filename, filename) in files {
+        let signature = match &sigstore {
+            Some(instance) if sign => {
+                let bundle_path = file.with_file_name(format!("{raw_filename}.sigstore.json"));
+                Some(
+                    sign_file(
+                        &file,
+                        &filename,
+                        &bundle_path,
+                        &mut signer,
+                        instance,
+                        &oidc_client,
+                        printer,
+                    )
+                    .await?,
+                )
+            }
+            _ => None,
+        };
+
+        let attestation = if let (Some(attester), Some(instance)) = (&attester, &sigstore) {
+            let attestation = attester
+                .attest(
+                    &file,
+                    &filename,
+                    oidc_client.for_host(instance.fulcio_url()).raw_client(),
+                )
+                .await
+                .map_err(|err| PublishError::Attestation(file.clone(), err))?;
+            writeln!(printer.stderr(), "{} {filename}", "Attested".bold().green())?;
+            Some(attestation)
+        } else {
+            None
+        };
+        let mut form_metadata = prepare_upload(&file, &filename).await?;
+        if let Some(bundle) = &signature {
+            form_metadata = form_metadata
+                .with_signature(bundle)
+                .map_err(|err| PublishError::PublishPrepare(file.clone(), Box::new(err)))?;
+        }
+        uploads.push((file, raw_filename, filename, form_metadata, attestation));
+    }
+
+    for target in targets {
+        match uploads.len() {
+            1 => writeln!(
+                printer.stderr(),
+                "Publishing 1 file to {}",
+                target.publish_url
+            )?,
+            n => writeln!(
+                printer.stderr(),
+                "Publishing {n} files {}",
+                target.publish_url
+            )?,
+        }
+
+        // Credentials configured for the index take precedence over the global credentials.
+        let (username, password) = if let Some(credentials) = &target.credentials {
+            (
+                credentials.username().map(ToString::to_string),
+                credentials.password().map(ToString::to_string),
+            )
+        } else {
+            (username.clone(), password.clone())
+        };
+
+        let attach_attestations = supports_attestations(&target.publish_url);
+        let (publish_url, credentials) = gather_credentials(
+            target.publish_url,
+            username,
+            password,
+            trusted_publishing,
+            keyring_provider,
+            &oidc_client,
+            target.check_url.as_ref(),
+            Prompt::Enabled,
+            printer,
+        )
+        .await?;
+
+        // Initialize the registry client.
+        let check_url_client = target.check_url.as_ref().map(|index_url| {
+            check_url_client(
+                index_url,
+                &upload_client,
+                keyring_provider,
+                network_settings,
+                cache,
+            )
+        });
+
+        // Respect any retry count configured for the index.
+        let retry_policy = if let Some(retries) = target.retries {
+            upload_retries.clone().retries(retries).retry_policy()
+        } else {
+            upload_retries.retry_policy()
+        };
+
+        // Skip the files that the registry accepted in a previous, interrupted run.
+        let state = UploadState::read(cache, &publish_url);
+        // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
+        let reporter = Arc::new(PublishReporter::new(printer, uploads.len() as u64));
+
+        // A failed upload doesn't cancel the other uploads, so that all files the registry
+        // accepted are recorded and skipped when retrying.
+        let results: Vec<Result<()>> = futures::stream::iter(&uploads)
+            .map(
+                |(file, raw_filename, filename, form_metadata, attestation)| {
+                    let state = &state;
+                    let reporter = reporter.clone();
+                    let publish_url = &publish_url;
+                    let credentials = &credentials;
+                    let check_url_client = check_url_client.as_ref();
+                    let download_concurrency = &download_concurrency;
+                    let upload_client = &upload_client;
+                    let retry_policy = &retry_policy;
+                    let retryable_strategy = &retryable_strategy;
+                    async move {
+                        let result: Result<()> = async {
+                            if state.is_accepted(raw_filename, form_metadata.sha256_digest()) {
+                                writeln!(
+                                    printer.stderr(),
+                                    "File {filename} was already uploaded, skipping"
+                                )?;
+                                return Ok(());
+                            }
+
+                            if let Some(check_url_client) = check_url_client {
+                                if uv_publish::check_url(
+                                    check_url_client,
+                                    file,
+                                    filename,
+                                    download_concurrency,
+                                )
+                                .await?
+                                {
+                                    writeln!(
+                                        printer.stderr(),
+                                        "File {filename} already exists, skipping"
+                                    )?;
+                                    return Ok(());
+                                }
+                            }
+
+                            let form_metadata = match attestation {
+                                Some(attestation) if attach_attestations => Cow::Owned(
+                                    form_metadata.with_attestation(attestation).map_err(|err| {
+                                        PublishError::PublishPrepare(file.clone(), Box::new(err))
+                                    })?,
+                                ),
+                                _ => Cow::Borrowed(form_metadata),
+                            };
+
+                            let size = fs_err::metadata(file)?.len();
+                            let (bytes, unit) = human_readable_bytes(size);
+                            writeln!(
+                                printer.stderr(),
+                                "{} {filename} {}",
+                                "Uploading".bold().green(),
+                                format!("({bytes:.1}{unit})").dimmed()
+                            )?;
+                            let uploaded = upload(
+                                file,
+                                raw_filename,
+                                filename,
+                                &form_metadata,
+                                publish_url,
+                                upload_client,
+                                retry_policy,
+                                retryable_strategy,
+                                credentials,
+                                check_url_client,
+                                download_concurrency,
+                                reporter.clone(),
+                            )
+                            .await?; // Filename and/or URL are already attached, if applicable.
+                            info!("Upload succeeded");
+                            if !uploaded {
+                                writeln!(
+                                    printer.stderr(),
+                                    "{}",
+                                    "File already exists, skipping".dimmed()
+                                )?;
+                            }
+
+                            if let Err(err) =
+                                state.accept(raw_filename, form_metadata.sha256_digest())
+                            {
+                                warn_user_once!("Failed to record upload of {filename}: {err}");
+                            }
+                            Ok(())
+                        }
+                        .await;
+                        reporter.on_file_complete();
+                        result
+                    }
+                },
+            )
+            .buffer_unordered(concurrent_uploads)
+            .collect()
+            .await;
+        reporter.on_complete();
+
+        // Report every failed upload, returning the last failure.
+        let mut errors = results.into_iter().filter_map(Result::err).peekable();
+        while let Some(err) = errors.next() {
+            if errors.peek().is_none() {
+                return Err(err);
+            }
+            writeln!(printer.stderr(), "{}: {err}", "error".red().bold())?;
+            for err in err.chain().skip(1) {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Sign a file with Sigstore, writing the bundle to `bundle_path`, and return the bundle.
+///
+/// A bundle from a previous run is reused if it's still a valid signature of the file.
+async fn sign_file(
+    file: &Path,
+    filename: &DistFilename,
+    bundle_path: &Path,
+    signer: &mut Option<Signer>,
+    instance: &SigstoreInstance,
+    client: &BaseClient,
+    printer: Printer,
+) -> Result<serde_json::Value> {
+    match read_bundle(file, bundle_path, signer.as_ref(), instance).await {
+        Ok(Some(bundle)) => {
+            writeln!(
+                printer.stderr(),
+                "File {filename} was already signed ({}), skipping",
+                bundle_path.user_display().cyan()
+            )?;
+            return Ok(bundle);
+        }
+        Ok(None) => {}
+        Err(err) => {
+            debug!(
+                "Ignoring existing signature `{}`: {err}",
+                bundle_path.user_display()
+            );
+        }
+    }
+
+    let client = client.for_host(instance.fulcio_url()).raw_client();
+    let signer: &Signer = if let Some(signer) = signer.as_ref() {
+        signer
+    } else {
+        signer.insert(
+            Signer::keyless(client, instance)
+                .await
+                .context("Failed to obtain a signing certificate")?,
+        )
+    };
+    let bundle = signer
+        .sign(file, client)
+        .await
+        .map_err(|err| PublishError::Signature(file.to_path_buf(), err))?;
+    fs_err::write(bundle_path, serde_json::to_vec_pretty(&bundle)?)?;
+    writeln!(
+        printer.stderr(),
+        "{} {filename} ({})",
+        "Signed".bold().green(),
+        bundle_path.user_display().cyan()
+    )?;
+    Ok(bundle)
+}
+
+/// Build and publish all packages in the workspace, in dependency order.
+///
+/// Before publishing a package, we wait until the new versions of the workspace members it depends
+/// on are available on the index, otherwise installing it right after publishing could fail.
+pub(crate) async fn publish_workspace(
+    project_dir: &Path,
+    targets: Vec<PublishTarget>,
+    trusted_publishing: TrustedPublishing,
+    keyring_provider: KeyringProviderType,
+    username: Option<String>,
+    password: Option<String>,
+    attest: bool,
+    sign: bool,
+    sign_key: Option<PathBuf>,
+    sigstore: &SigstoreConfig,
+    concurrent_uploads: usize,
+    install_mirrors: PythonInstallMirrors,
+    settings: &ResolverSettings,
+    network_settings: &NetworkSettings,
+    no_config: bool,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if network_settings.connectivity.is_offline() {
+        bail!("Unable to publish files in offline mode");
+    }
+
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
     )
     .await?;
+    let order = publish_order(&workspace)?;
+    if order.is_empty() {
+        bail!(
+            "No packages found to publish in the workspace at: `{}`",
+            workspace.install_path().user_display()
+        );
+    }
+    writeln!(
+        printer.stderr(),
+        "Publishing {} packages in order: {}",
+        order.len(),
+        order.iter().map(|(name, _)| name.cyan()).join(", ")
+    )?;
 
-    // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = &check_url {
-        let index_locations = IndexLocations::new(
-            vec![Index::from_index_url(index_url.clone())],
+    let check_client = BaseClientBuilder::new()
+        .keyring(keyring_provider)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .build();
+    let download_concurrency = Semaphore::new(1);
+    let temp_dir = tempfile::tempdir_in(cache.root())?;
+
+    for (name, has_dependents) in order {
+        let out_dir = temp_dir.path().join(name.as_str());
+        let status = build_frontend(
+            workspace.install_path(),
+            None,
+            Some(name.clone()),
+            false,
+            Some(out_dir.clone()),
+            false,
+            false,
+            false,
+            BuildFormat::default(),
+            true,
+            None,
+            false,
+            false,
             Vec::new(),
+            None,
+            None,
+            None,
+            install_mirrors.clone(),
+            settings,
+            network_settings,
+            no_config,
+            python_preference,
+            python_downloads,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
+
+        let pattern = out_dir.join("*").to_string_lossy().to_string();
+        let status = publish(
+            vec![pattern.clone()],
+            targets.clone(),
+            trusted_publishing,
+            keyring_provider,
+            network_settings,
+            username.clone(),
+            password.clone(),
+            attest,
+            sign,
+            sign_key.clone(),
+            sigstore,
             false,
-        );
-        let registry_client_builder = RegistryClientBuilder::new(cache.clone())
-            .retries_from_env()?
-            .native_tls(network_settings.native_tls)
-            .connectivity(network_settings.connectivity)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone())
-            .index_locations(&index_locations)
-            .keyring(keyring_provider);
-        Some(CheckUrlClient {
-            index_url: index_url.clone(),
-            registry_client_builder,
-            client: &upload_client,
-            index_capabilities: IndexCapabilities::default(),
+            concurrent_uploads,
             cache,
-        })
-    } else {
-        None
-    };
+            printer,
+        )
+        .await?;
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
 
-    for (file, raw_filename, filename) in files {
-        if let Some(check_url_client) = &check_url_client {
-            if uv_publish::check_url(check_url_client, &file, &filename, &download_concurrency)
-                .await?
-            {
-                writeln!(printer.stderr(), "File {filename} already exists, skipping")?;
+        // Only the packages that depend on this one need it on the index.
+        if !has_dependents {
+            continue;
+        }
+        let files = files_for_publishing(vec![pattern])?;
+        let Some((file, _, filename)) = files.first() else {
+            continue;
+        };
+        for target in &targets {
+            let Some(index_url) = &target.check_url else {
+                warn_user_once!(
+                    "Can't wait for `{name}` to become available on {}, the dependent packages \
+                    may be published before it can be installed. Use `--index` or `--check-url` \
+                    to provide the index URL.",
+                    target.publish_url
+                );
+                continue;
+            };
+            let check_url_client = check_url_client(
+                index_url,
+                &check_client,
+                keyring_provider,
+                network_settings,
+                cache,
+            );
+            wait_for_index(
+                &check_url_client,
+                file,
+                filename,
+                &download_concurrency,
+                printer,
+            )
+            .await?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Determine the workspace members to publish, such that each member comes after the members it
+/// depends on.
+///
+/// Returns the name of each member and whether other members depend on it.
+fn publish_order(workspace: &Workspace) -> Result<Vec<(PackageName, bool)>> {
+    let mut dependencies: BTreeMap<&PackageName, BTreeSet<&PackageName>> = workspace
+        .packages()
+        .iter()
+        .filter(|(_, member)| member.pyproject_toml().is_package(true))
+        .map(|(name, _)| (name, BTreeSet::new()))
+        .collect();
+
+    for (name, member) in workspace.packages() {
+        if !dependencies.contains_key(name) {
+            continue;
+        }
+        let Some(project) = &member.pyproject_toml().project else {
+            continue;
+        };
+        let requirements = project.dependencies.iter().flatten().chain(
+            project
+                .optional_dependencies
+                .iter()
+                .flat_map(|extras| extras.values().flatten()),
+        );
+        for requirement in requirements {
+            let requirement = match Requirement::<VerbatimUrl>::from_str(requirement) {
+                Ok(requirement) => requirement,
+                Err(err) => {
+                    // The build reports invalid requirements.
+                    debug!("Ignoring invalid requirement of `{name}`: {err}");
+                    continue;
+                }
+            };
+            let Some((dependency, _)) = dependencies.get_key_value(&requirement.name) else {
                 continue;
+            };
+            let dependency = *dependency;
+            if dependency != name {
+                dependencies
+                    .get_mut(name)
+                    .expect("Member was inserted")
+                    .insert(dependency);
             }
         }
+    }
 
-        let size = fs_err::metadata(&file)?.len();
-        let (bytes, unit) = human_readable_bytes(size);
-        writeln!(
-            printer.stderr(),
-            "{} {filename} {}",
-            "Uploading".bold().green(),
-            format!("({bytes:.1}{unit})").dimmed()
-        )?;
-        let reporter = PublishReporter::single(printer);
-        let uploaded = upload(
-            &file,
-            &raw_filename,
-            &filename,
-            &publish_url,
-            &upload_client,
-            &credentials,
-            check_url_client.as_ref(),
-            &download_concurrency,
-            // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
-            Arc::new(reporter),
-        )
-        .await?; // Filename and/or URL are already attached, if applicable.
-        info!("Upload succeeded");
-        if !uploaded {
+    let mut remaining = dependencies.clone();
+    let mut order = Vec::with_capacity(dependencies.len());
+    while !remaining.is_empty() {
+        let ready: Vec<&PackageName> = remaining
+            .iter()
+            .filter(|(_, dependencies)| dependencies.is_empty())
+            .map(|(name, _)| *name)
+            .collect();
+        if ready.is_empty() {
+            bail!(
+                "Can't determine the publish order, the workspace members have cyclic dependencies: {}",
+                remaining.keys().map(|name| format!("`{name}`")).join(", ")
+            );
+        }
+        for name in ready {
+            remaining.remove(name);
+            for dependencies in remaining.values_mut() {
+                dependencies.remove(name);
+            }
+            order.push(name);
+        }
+    }
+
+    Ok(order
+        .into_iter()
+        .map(|name| {
+            let has_dependents = dependencies
+                .values()
+                .any(|dependencies| dependencies.contains(name));
+            (name.clone(), has_dependents)
+        })
+        .collect())
+}
+
+/// Wait until a published file is available on the index.
+async fn wait_for_index(
+    check_url_client: &CheckUrlClient<'_>,
+    file: &Path,
+    filename: &DistFilename,
+    download_concurrency: &Semaphore,
+    printer: Printer,
+) -> Result<()> {
+    let start = Instant::now();
+    let mut waiting = false;
+    loop {
+        if uv_publish::check_url(check_url_client, file, filename, download_concurrency).await? {
+            return Ok(());
+        }
+        if start.elapsed() > INDEX_AVAILABILITY_TIMEOUT {
+            bail!(
+                "Timed out waiting for {filename} to become available on {}",
+                check_url_client.index_url
+            );
+        }
+        if !waiting {
             writeln!(
                 printer.stderr(),
-                "{}",
-                "File already exists, skipping".dimmed()
+                "Waiting for {filename} to become available on {}",
+                check_url_client.index_url
             )?;
+            waiting = true;
         }
+        tokio::time::sleep(INDEX_AVAILABILITY_INTERVAL).await;
     }
+}
 
+/// Create the client for checking an index for existing files.
+fn check_url_client<'a>(
+    index_url: &IndexUrl,
+    upload_client: &'a BaseClient,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    cache: &'a Cache,
+) -> CheckUrlClient<'a> {
+    let index_locations = IndexLocations::new(
+        vec![Index::from_index_url(index_url.clone())],
+        Vec::new(),
+        false,
+    );
+    let registry_client_builder = RegistryClientBuilder::new(cache.clone())
+        .retry_settings(&network_settings.retry)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .connectivity(network_settings.connectivity)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .index_locations(&index_locations)
+        .keyring(keyring_provider);
+    CheckUrlClient {
+        index_url: index_url.clone(),
+        registry_client_builder,
+        client: upload_client,
+        index_capabilities: IndexCapabilities::default(),
+        cache,
+    }
+}
+
+/// Validate the files without uploading them, reporting all problems.
+///
+/// The files are also compared against the files in the index of each check URL.
+async fn check_files(
+    files: Vec<(PathBuf, String, DistFilename)>,
+    check_url_clients: &[CheckUrlClient<'_>],
+    download_concurrency: &Semaphore,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut seen = FxHashSet::default();
+    let mut errors = 0;
+    for (file, raw_filename, filename) in files {
+        let mut issues = validate_metadata(&file, &filename)
+            .await
+            .map_err(|err| PublishError::PublishPrepare(file.clone(), Box::new(err)))?;
+
+        if !seen.insert(raw_filename) {
+            issues.push(ValidationIssue::DuplicateFilename);
+        }
+
+        for check_url_client in check_url_clients {
+            match uv_publish::check_url(check_url_client, &file, &filename, download_concurrency)
+                .await
+            {
+                Ok(true) => issues.push(ValidationIssue::AlreadyExists),
+                Ok(false) => {}
+                Err(PublishError::HashMismatch { .. }) => {
+                    issues.push(ValidationIssue::ExistsWithDifferentContents);
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        if issues.iter().any(ValidationIssue::is_error) {
+            writeln!(printer.stderr(), "{} {filename}", "Failed".bold().red())?;
+        } else {
+            writeln!(printer.stderr(), "{} {filename}", "Passed".bold().green())?;
+        }
+        for issue in issues {
+            if issue.is_error() {
+                errors += 1;
+                writeln!(printer.stderr(), "  {}: {issue}", "error".red().bold())?;
+            } else {
+                writeln!(printer.stderr(), "  {}: {issue}", "warning".yellow().bold())?;
+            }
+        }
+    }
+
+    if errors > 0 {
+        return Ok(ExitStatus::Failure);
+    }
     Ok(ExitStatus::Success)
 }
 
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Prompt {
+pub(crate) enum Prompt {
     Enabled,
     #[allow(dead_code)]
     Disabled,
@@ -196,7 +913,7 @@ enum Prompt {
 /// otherwise errors without sending the request.
 ///
 /// Returns the publish URL, the username and the password.
-async fn gather_credentials(
+pub(crate) async fn gather_credentials(
     mut publish_url: DisplaySafeUrl,
     mut username: Option<String>,
     mut password: Option<String>,
@@ -342,10 +1059,122 @@ mod tests {
 
     use std::str::FromStr;
 
+    use indoc::{formatdoc, indoc};
     use insta::assert_snapshot;
 
     use uv_redacted::DisplaySafeUrl;
 
+    /// Create a virtual workspace with the given members, each with its dependencies and optional
+    /// dependencies, and whether it has a build system.
+    async fn workspace(dir: &Path, members: &[(&str, &[&str], &[&str], bool)]) -> Workspace {
+        fs_err::write(
+            dir.join("pyproject.toml"),
+            "[tool.uv.workspace]\nmembers = [\"packages/*\"]\n",
+        )
+        .unwrap();
+        for (name, dependencies, optional_dependencies, package) in members {
+            let member = dir.join("packages").join(name);
+            fs_err::create_dir_all(&member).unwrap();
+            let mut pyproject_toml = formatdoc! {r#"
+                [project]
+                name = "{name}"
+                version = "0.1.0"
+                dependencies = {dependencies:?}
+
+                [project.optional-dependencies]
+                extra = {optional_dependencies:?}
+            "#};
+            if *package {
+                pyproject_toml.push_str(indoc! {r#"
+
+                    [build-system]
+                    requires = ["uv_build>=0.8,<0.9"]
+                    build-backend = "uv_build"
+                "#});
+            }
+            fs_err::write(member.join("pyproject.toml"), pyproject_toml).unwrap();
+        }
+        Workspace::discover(
+            dir,
+            &DiscoveryOptions::default(),
+            &WorkspaceCache::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn names(order: &[(PackageName, bool)]) -> Vec<(String, bool)> {
+        order
+            .iter()
+            .map(|(name, has_dependents)| (name.to_string(), *has_dependents))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn publish_order_dependencies() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let workspace = workspace(
+            temp_dir.path(),
+            &[
+                ("a", &["b>=0.1", "anyio"], &[], true),
+                ("b", &[], &["c[extra]; python_version >= '3.8'"], true),
+                ("c", &["c", "not a requirement"], &[], true),
+                ("d", &["a"], &[], true),
+                ("e", &["a"], &[], false),
+            ],
+        )
+        .await;
+
+        // Members are published after their dependencies, including optional ones, while members
+        // that aren't packages are skipped.
+        assert_eq!(
+            names(&publish_order(&workspace).unwrap()),
+            [
+                ("c".to_string(), true),
+                ("b".to_string(), true),
+                ("a".to_string(), true),
+                ("d".to_string(), false),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_order_independent() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let workspace = workspace(
+            temp_dir.path(),
+            &[("b", &[], &[], true), ("a", &[], &[], true)],
+        )
+        .await;
+
+        // Independent members are published in alphabetical order.
+        assert_eq!(
+            names(&publish_order(&workspace).unwrap()),
+            [("a".to_string(), false), ("b".to_string(), false)]
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_order_cycle() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let workspace = workspace(
+            temp_dir.path(),
+            &[
+                ("a", &["b"], &[], true),
+                ("b", &[], &["c"], true),
+                ("c", &["a"], &[], true),
+                ("d", &[], &[], true),
+            ],
+        )
+        .await;
+
+        let err = publish_order(&workspace).unwrap_err();
+        assert_snapshot!(
+            err.to_string(),
+            @"Can't determine the publish order, the workspace members have cyclic dependencies: `a`, `b`, `c`"
+        );
+    }
+
     async fn get_credentials(
         url: DisplaySafeUrl,
         username: Option<String>,
@@ -1,12 +1,15 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::fmt::Write;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_cli::PythonListFormat;
 use uv_configuration::{DependencyGroupsWithDefaults, Preview};
 use uv_fs::Simplified;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest, PythonSource,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -20,12 +23,38 @@ use crate::commands::{
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
 
+#[derive(Debug, Serialize)]
+struct PrintData {
+    path: String,
+    version: String,
+    implementation: String,
+    variant: String,
+    key: String,
+    source: Option<String>,
+}
+
+impl PrintData {
+    fn new(interpreter: &Interpreter, source: Option<PythonSource>) -> Result<Self> {
+        Ok(Self {
+            path: std::path::absolute(interpreter.sys_executable())?
+                .simplified_display()
+                .to_string(),
+            version: interpreter.python_full_version().to_string(),
+            implementation: interpreter.implementation_name().to_string(),
+            variant: interpreter.variant().to_string(),
+            key: interpreter.key().to_string(),
+            source: source.map(|source| source.to_string()),
+        })
+    }
+}
+
 /// Find a Python interpreter.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     show_version: bool,
+    output_format: PythonListFormat,
     no_project: bool,
     no_config: bool,
     system: bool,
@@ -70,6 +99,7 @@ pub(crate) async fn find(
         &groups,
         project_dir,
         no_config,
+        preview,
     )
     .await?;
 
@@ -97,7 +127,10 @@ pub(crate) async fn find(
         }
     }
 
-    if show_version {
+    if matches!(output_format, PythonListFormat::Json) {
+        let data = PrintData::new(python.interpreter(), Some(*python.source()))?;
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+    } else if show_version {
         writeln!(
             printer.stdout(),
             "{}",
@@ -117,6 +150,7 @@ pub(crate) async fn find(
 pub(crate) async fn find_script(
     script: Pep723ItemRef<'_>,
     show_version: bool,
+    output_format: PythonListFormat,
     network_settings: &NetworkSettings,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -149,7 +183,10 @@ pub(crate) async fn find_script(
         Ok(ScriptInterpreter::Environment(environment)) => environment.into_interpreter(),
     };
 
-    if show_version {
+    if matches!(output_format, PythonListFormat::Json) {
+        let data = PrintData::new(&interpreter, None)?;
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+    } else if show_version {
         writeln!(printer.stdout(), "{}", interpreter.python_version())?;
     } else {
         writeln!(
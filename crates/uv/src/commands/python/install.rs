@@ -14,7 +14,7 @@ use owo_colors::OwoColorize;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
 
-use uv_configuration::{Preview, PreviewFeatures};
+use uv_configuration::{DryRun, Preview, PreviewFeatures};
 use uv_fs::Simplified;
 use uv_python::downloads::{
     self, ArchRequest, DownloadResult, ManagedPythonDownload, PythonDownloadRequest,
@@ -25,8 +25,9 @@ use uv_python::managed::{
 };
 use uv_python::platform::{Arch, Libc};
 use uv_python::{
-    PythonDownloads, PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest,
-    PythonVersionFile, VersionFileDiscoveryOptions, VersionFilePreference, VersionRequest,
+    ImplementationName, PythonDownloads, PythonInstallationKey, PythonInstallationMinorVersionKey,
+    PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions, VersionFilePreference,
+    VersionRequest,
 };
 use uv_shell::Shell;
 use uv_trampoline_builder::{Launcher, LauncherKind};
@@ -151,12 +152,14 @@ pub(crate) async fn install(
     targets: Vec<String>,
     reinstall: bool,
     upgrade: bool,
+    dry_run: DryRun,
     bin: Option<bool>,
     registry: Option<bool>,
     force: bool,
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
     python_downloads_json_url: Option<String>,
+    require_attestations: bool,
     network_settings: NetworkSettings,
     default: bool,
     python_downloads: PythonDownloads,
@@ -207,10 +210,15 @@ pub(crate) async fn install(
             let mut minor_version_requests = IndexSet::<InstallRequest>::default();
             for installation in &existing_installations {
                 let request = VersionRequest::major_minor_request_from_key(installation.key());
-                if let Ok(request) = InstallRequest::new(
-                    PythonRequest::Version(request),
-                    python_downloads_json_url.as_deref(),
-                ) {
+                let request = match installation.implementation() {
+                    ImplementationName::CPython => PythonRequest::Version(request),
+                    implementation => {
+                        PythonRequest::ImplementationVersion(*implementation, request)
+                    }
+                };
+                if let Ok(request) =
+                    InstallRequest::new(request, python_downloads_json_url.as_deref())
+                {
                     minor_version_requests.insert(request);
                 }
             }
@@ -261,8 +269,11 @@ pub(crate) async fn install(
     let requested_minor_versions = requests
         .iter()
         .filter_map(|request| {
-            if let PythonRequest::Version(VersionRequest::MajorMinor(major, minor, ..)) =
-                request.python_request()
+            if let PythonRequest::Version(VersionRequest::MajorMinor(major, minor, ..))
+            | PythonRequest::ImplementationVersion(
+                _,
+                VersionRequest::MajorMinor(major, minor, ..),
+            ) = request.python_request()
             {
                 uv_pep440::Version::from_str(&format!("{major}.{minor}")).ok()
             } else {
@@ -390,11 +401,18 @@ pub(crate) async fn install(
         .unique_by(|download| download.key())
         .collect::<Vec<_>>();
 
+    if dry_run.enabled() {
+        report_dry_run(&downloads, &existing_installations, upgrade, printer)?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Download and unpack the Python versions concurrently
     let client = uv_client::BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
         .build();
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
@@ -412,6 +430,7 @@ pub(crate) async fn install(
                         reinstall,
                         python_install_mirror.as_deref(),
                         pypy_install_mirror.as_deref(),
+                        require_attestations,
                         Some(&reporter),
                     )
                     .await,
@@ -721,6 +740,60 @@ pub(crate) async fn install(
     Ok(ExitStatus::Success)
 }
 
+/// Report the Python versions that would be installed (or upgraded) in a dry run.
+fn report_dry_run(
+    downloads: &[&ManagedPythonDownload],
+    existing_installations: &[ManagedPythonInstallation],
+    upgrade: bool,
+    printer: Printer,
+) -> Result<()> {
+    let verb = if upgrade { "upgrade" } else { "install" };
+    if downloads.is_empty() {
+        writeln!(printer.stderr(), "Would not {verb} any versions")?;
+        return Ok(());
+    }
+
+    let s = if downloads.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Would {verb} {}",
+            format!("{} version{s}", downloads.len()).bold()
+        )
+        .dimmed()
+    )?;
+    for download in downloads {
+        // Find the latest existing patch version for the same minor version, which virtual
+        // environments using the minor version link would be moved off of.
+        let previous = existing_installations
+            .iter()
+            .filter(|installation| {
+                PythonInstallationMinorVersionKey::ref_cast(installation.key())
+                    == PythonInstallationMinorVersionKey::ref_cast(download.key())
+            })
+            .map(ManagedPythonInstallation::key)
+            .max();
+        if let Some(previous) = previous {
+            writeln!(
+                printer.stderr(),
+                " {} {} (from {})",
+                "+".green(),
+                download.key().bold(),
+                previous.version()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                " {} {}",
+                "+".green(),
+                download.key().bold()
+            )?;
+        }
+    }
+    Ok(())
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 ///
 /// This function is fallible, but errors are pushed to `errors` instead of being thrown.
@@ -43,6 +43,7 @@ struct PrintData {
     path: Option<String>,
     symlink: Option<String>,
     url: Option<String>,
+    sha256: Option<String>,
     os: String,
     variant: String,
     implementation: String,
@@ -115,7 +116,7 @@ pub(crate) async fn list(
             output.insert((
                 download.key().clone(),
                 Kind::Download,
-                Either::Right(download.url()),
+                Either::Right((download.url(), download.hash())),
             ));
         }
     }
@@ -231,7 +232,7 @@ pub(crate) async fn list(
                                     Some(path.read_link()?.user_display().to_string());
                             }
                         }
-                        Either::Right(url) => {
+                        Either::Right((url, _)) => {
                             url_or_none = Some((*url).to_string());
                         }
                     }
@@ -250,6 +251,10 @@ pub(crate) async fn list(
                         path: path_or_none,
                         symlink: symlink_or_none,
                         url: url_or_none,
+                        sha256: match uri {
+                            Either::Right((_, Some(hash))) => Some(hash.digest.to_string()),
+                            _ => None,
+                        },
                         arch: key.arch().to_string(),
                         implementation: key.implementation().to_string(),
                         os: key.os().to_string(),
@@ -286,7 +291,7 @@ pub(crate) async fn list(
                             )?;
                         }
                     }
-                    Either::Right(url) => {
+                    Either::Right((url, _)) => {
                         if show_urls {
                             writeln!(printer.stdout(), "{key:width$}    {}", url.dimmed())?;
                         } else {
@@ -116,9 +116,11 @@ pub(crate) async fn pin(
     }
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -133,6 +135,9 @@ pub(crate) async fn pin(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors
+            .python_install_require_attestations
+            .unwrap_or_default(),
         preview,
     )
     .await
@@ -163,6 +168,7 @@ pub(crate) async fn pin(
                     existing: false,
                 },
                 virtual_project,
+                preview,
             )?;
         } else {
             if let Some(python) = &python {
@@ -175,6 +181,7 @@ pub(crate) async fn pin(
                         existing: false,
                     },
                     virtual_project,
+                    preview,
                 ) {
                     if resolved {
                         return Err(err);
@@ -282,6 +289,7 @@ fn warn_if_existing_pin_incompatible_with_project(
                 existing: true,
             },
             virtual_project,
+            preview,
         ) {
             warn_user_once!("{err}");
             return;
@@ -312,6 +320,7 @@ fn warn_if_existing_pin_incompatible_with_project(
                     existing: true,
                 },
                 virtual_project,
+                preview,
             ) {
                 warn_user_once!("{err}");
             }
@@ -334,7 +343,11 @@ struct Pin<'a> {
 }
 
 /// Checks if the pinned Python version is compatible with the workspace/project's `Requires-Python`.
-fn assert_pin_compatible_with_project(pin: &Pin, virtual_project: &VirtualProject) -> Result<()> {
+fn assert_pin_compatible_with_project(
+    pin: &Pin,
+    virtual_project: &VirtualProject,
+    preview: Preview,
+) -> Result<()> {
     // Don't factor in requires-python settings on dependency-groups
     let groups = DependencyGroupsWithDefaults::none();
 
@@ -346,7 +359,8 @@ fn assert_pin_compatible_with_project(pin: &Pin, virtual_project: &VirtualProjec
                 project_workspace.workspace().install_path().display()
             );
 
-            let requires_python = find_requires_python(project_workspace.workspace(), &groups)?;
+            let requires_python =
+                find_requires_python(project_workspace.workspace(), &groups, preview)?;
             (requires_python, "project")
         }
         VirtualProject::NonProject(workspace) => {
@@ -354,7 +368,7 @@ fn assert_pin_compatible_with_project(pin: &Pin, virtual_project: &VirtualProjec
                 "Discovered virtual workspace at: {}",
                 workspace.install_path().display()
             );
-            let requires_python = find_requires_python(workspace, &groups)?;
+            let requires_python = find_requires_python(workspace, &groups, preview)?;
             (requires_python, "workspace")
         }
     };
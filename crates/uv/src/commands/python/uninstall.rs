@@ -12,12 +12,18 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, warn};
 
 use uv_configuration::Preview;
-use uv_fs::Simplified;
+use uv_fs::{CWD, Simplified};
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::managed::{
-    ManagedPythonInstallations, PythonMinorVersionLink, python_executable_dir,
+    ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
+    python_executable_dir,
 };
-use uv_python::{PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest};
+use uv_python::{
+    PyVenvConfiguration, PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest,
+};
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
 
 use crate::commands::python::install::format_executables;
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
@@ -29,6 +35,7 @@ pub(crate) async fn uninstall(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     all: bool,
+    force: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -37,7 +44,7 @@ pub(crate) async fn uninstall(
     let _lock = installations.lock().await?;
 
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer, preview).await?;
+    do_uninstall(&installations, targets, all, force, printer, preview).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -65,6 +72,7 @@ async fn do_uninstall(
     installations: &ManagedPythonInstallations,
     targets: Vec<String>,
     all: bool,
+    force: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -140,6 +148,31 @@ async fn do_uninstall(
         return Ok(ExitStatus::Failure);
     }
 
+    // Refuse to break known environments that depend on the installations, unless forced.
+    let dependents =
+        find_dependent_environments(&matching_installations, &installed_installations, preview);
+    if !dependents.is_empty() {
+        let s = if dependents.len() == 1 { "" } else { "s" };
+        let environments = dependents
+            .iter()
+            .map(|(environment, key)| {
+                format!("- {} ({})", environment.user_display().cyan(), key.green())
+            })
+            .join("\n");
+        if force {
+            warn_user!(
+                "The following environment{s} will be broken by this uninstall and must be recreated:\n{environments}"
+            );
+        } else {
+            anyhow::bail!(
+                "The following environment{s} would be broken by this uninstall:\n{environments}\n\n{}{} Use `{}` to uninstall anyway, then recreate the environment{s}",
+                "hint".bold().cyan(),
+                ":".bold(),
+                "--force".green(),
+            );
+        }
+    }
+
     // Remove registry entries first, so we don't have dangling entries between the file removal
     // and the registry removal.
     let mut errors = vec![];
@@ -344,3 +377,61 @@ async fn do_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Find the known virtual environments that were created with one of the given installations, and
+/// would be broken by removing them.
+///
+/// There's no registry of the environments created by uv, so only tool environments, the active
+/// virtual environment, and the `.venv` in the working directory are considered. Environments
+/// that use a minor version link (e.g., `cpython-3.12-macos-aarch64-none`) are only broken if no
+/// other patch version of the same minor version remains installed.
+fn find_dependent_environments(
+    installations: &BTreeSet<ManagedPythonInstallation>,
+    installed_installations: &[ManagedPythonInstallation],
+    preview: Preview,
+) -> Vec<(PathBuf, PythonInstallationKey)> {
+    let mut environments = Vec::new();
+    if let Ok(tools) = InstalledTools::from_settings() {
+        if let Ok(directories) = uv_fs::directories(tools.root()) {
+            environments.extend(directories);
+        }
+    }
+    if let Some(active) = std::env::var_os(EnvVars::VIRTUAL_ENV).filter(|path| !path.is_empty()) {
+        environments.push(PathBuf::from(active));
+    }
+    environments.push(CWD.join(".venv"));
+
+    let remaining_minor_versions: FxHashSet<_> = installed_installations
+        .iter()
+        .filter(|installation| !installations.contains(*installation))
+        .map(ManagedPythonInstallation::minor_version_key)
+        .collect();
+
+    environments
+        .into_iter()
+        .unique_by(|environment| {
+            fs_err::canonicalize(environment).unwrap_or_else(|_| environment.clone())
+        })
+        .filter_map(|environment| {
+            let cfg = PyVenvConfiguration::parse(environment.join("pyvenv.cfg")).ok()?;
+            let home = cfg.home()?;
+            let installation = installations.iter().find(|installation| {
+                if home.starts_with(installation.path()) {
+                    return true;
+                }
+                if remaining_minor_versions.contains(installation.minor_version_key()) {
+                    return false;
+                }
+                PythonMinorVersionLink::from_installation(installation, preview).is_some_and(
+                    |minor_version_link| home.starts_with(&minor_version_link.symlink_directory),
+                )
+            })?;
+            debug!(
+                "Found environment `{}` using `{}`",
+                environment.user_display(),
+                installation.key()
+            );
+            Some((environment, installation.key().clone()))
+        })
+        .collect()
+}
@@ -7,7 +7,7 @@ use std::time::Duration;
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::commands::human_readable_bytes;
 use crate::printer::Printer;
@@ -419,6 +419,8 @@ impl ProgressReporter {
 #[derive(Debug)]
 pub(crate) struct PrepareReporter {
     reporter: ProgressReporter,
+    /// The packages that were built from source.
+    built: Mutex<FxHashSet<PackageName>>,
 }
 
 impl From<Printer> for PrepareReporter {
@@ -434,7 +436,10 @@ impl From<Printer> for PrepareReporter {
         root.set_message("Preparing packages...");
 
         let reporter = ProgressReporter::new(root, multi_progress, printer);
-        Self { reporter }
+        Self {
+            reporter,
+            built: Mutex::default(),
+        }
     }
 }
 
@@ -444,6 +449,11 @@ impl PrepareReporter {
         self.reporter.root.set_length(length);
         self
     }
+
+    /// Returns the packages that were built from source.
+    pub(crate) fn built(&self) -> FxHashSet<PackageName> {
+        self.built.lock().unwrap().clone()
+    }
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
@@ -463,6 +473,9 @@ impl uv_installer::PrepareReporter for PrepareReporter {
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let Some(name) = source.name() {
+            self.built.lock().unwrap().insert(name.clone());
+        }
         self.reporter.on_build_complete(source, id);
     }
 
@@ -678,21 +691,36 @@ pub(crate) struct PublishReporter {
 }
 
 impl PublishReporter {
-    /// Initialize a [`PublishReporter`] for a single upload.
-    pub(crate) fn single(printer: Printer) -> Self {
-        Self::new(printer, 1)
-    }
-
-    /// Initialize a [`PublishReporter`] for multiple uploads.
+    /// Initialize a [`PublishReporter`] for uploading `length` files.
+    ///
+    /// Each upload gets its own progress bar, above a summary of the files that are done.
     pub(crate) fn new(printer: Printer, length: u64) -> Self {
         let multi_progress = MultiProgress::with_draw_target(printer.target());
         let root = multi_progress.add(ProgressBar::with_draw_target(
             Some(length),
             printer.target(),
         ));
+        root.enable_steady_tick(Duration::from_millis(200));
+        root.set_style(
+            ProgressStyle::with_template("{spinner:.white} {msg:.dim} ({pos}/{len})")
+                .unwrap()
+                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+        );
+        root.set_message("Uploading files...");
         let reporter = ProgressReporter::new(root, multi_progress, printer);
         Self { reporter }
     }
+
+    /// Record that a file was uploaded, skipped or failed to upload.
+    pub(crate) fn on_file_complete(&self) {
+        self.reporter.root.inc(1);
+    }
+
+    /// Clear the progress bars once all files are done.
+    pub(crate) fn on_complete(&self) {
+        self.reporter.root.set_message("");
+        self.reporter.root.finish_and_clear();
+    }
 }
 
 impl uv_publish::Reporter for PublishReporter {
@@ -3,6 +3,7 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use std::collections::Bound;
 use std::fmt::Write;
+use std::path::PathBuf;
 use std::{collections::BTreeSet, ffi::OsString};
 use tracing::{debug, warn};
 use uv_cache::Cache;
@@ -10,19 +11,24 @@ use uv_client::BaseClientBuilder;
 use uv_configuration::Preview;
 use uv_distribution_types::Requirement;
 use uv_distribution_types::{InstalledDist, Name};
-use uv_fs::Simplified;
 #[cfg(unix)]
 use uv_fs::replace_symlink;
+use uv_fs::{CWD, Simplified};
 use uv_installer::SitePackages;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::PackageName;
+use uv_pypi_types::DirectUrl;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVariant, VersionRequest,
+    PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
+    VersionRequest,
 };
 use uv_settings::{PythonInstallMirrors, ToolOptions};
 use uv_shell::Shell;
-use uv_tool::{InstalledTools, Tool, ToolEntrypoint, entrypoint_paths, tool_executable_dir};
+use uv_tool::{
+    InstalledTools, Tool, ToolEntrypoint, data_file_paths, entrypoint_paths, tool_data_dir,
+    tool_executable_dir,
+};
 use uv_warnings::warn_user;
 
 use crate::commands::project::ProjectError;
@@ -52,6 +58,30 @@ pub(super) fn matching_packages(name: &str, site_packages: &SitePackages) -> Vec
         .collect()
 }
 
+/// Return the Python version request from the global Python pin (i.e., the `.python-version` file
+/// in the user configuration directory), if any.
+///
+/// Tools are never tied to a project, so local `.python-version` files are ignored.
+pub(crate) async fn global_python_request(
+    no_config: bool,
+) -> Result<Option<PythonRequest>, std::io::Error> {
+    let request = PythonVersionFile::discover(
+        &*CWD,
+        &VersionFileDiscoveryOptions::default()
+            .with_no_config(no_config)
+            .with_no_local(true),
+    )
+    .await?
+    .and_then(PythonVersionFile::into_version);
+    if let Some(request) = &request {
+        debug!(
+            "Using Python request `{}` from the global Python pin",
+            request.to_canonical_string()
+        );
+    }
+    Ok(request)
+}
+
 /// Remove any entrypoints attached to the [`Tool`].
 pub(crate) fn remove_entrypoints(tool: &Tool) {
     for executable in tool
@@ -69,6 +99,23 @@ pub(crate) fn remove_entrypoints(tool: &Tool) {
     }
 }
 
+/// Remove any data files (i.e., shell completions and man pages) attached to the [`Tool`].
+///
+/// Data files that were already removed are skipped.
+pub(crate) fn remove_data_files(tool: &Tool) -> Result<(), std::io::Error> {
+    for path in tool.data_files() {
+        debug!("Removing data file: `{}`", path.simplified_display());
+        match fs_err::remove_file(path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("Data file not found: `{}`", path.simplified_display());
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
 /// Given a no-solution error and the [`Interpreter`] that was used during the solve, attempt to
 /// discover an alternate [`Interpreter`] that satisfies the `requires-python` constraint.
 pub(crate) async fn refine_interpreter(
@@ -145,6 +192,9 @@ pub(crate) async fn refine_interpreter(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors
+            .python_install_require_attestations
+            .unwrap_or_default(),
         preview,
     )
     .await?
@@ -165,6 +215,9 @@ pub(crate) async fn refine_interpreter(
 ///
 /// Installs tool executables for a given package, handling any conflicts.
 ///
+/// If requested, installs the shell completions and man pages provided by the package into the
+/// tool data directory.
+///
 /// Adds a receipt for the tool.
 pub(crate) fn finalize_tool_install(
     environment: &PythonEnvironment,
@@ -172,6 +225,7 @@ pub(crate) fn finalize_tool_install(
     installed_tools: &InstalledTools,
     options: ToolOptions,
     force: bool,
+    data_files: bool,
     python: Option<PythonRequest>,
     requirements: Vec<Requirement>,
     constraints: Vec<Requirement>,
@@ -293,6 +347,12 @@ pub(crate) fn finalize_tool_install(
             .join(", ")
     )?;
 
+    let data_files = if data_files {
+        install_data_files(&site_packages, installed_dist, force, printer)?
+    } else {
+        Vec::new()
+    };
+
     debug!("Adding receipt for tool `{name}`");
     let tool = Tool::new(
         requirements,
@@ -304,7 +364,8 @@ pub(crate) fn finalize_tool_install(
             .into_iter()
             .map(|(name, _, target_path)| ToolEntrypoint::new(name, target_path)),
         options,
-    );
+    )
+    .with_data_files(data_files.into_iter());
     installed_tools.add_tool_receipt(name, tool)?;
 
     // If the executable directory isn't on the user's PATH, warn.
@@ -341,6 +402,65 @@ pub(crate) fn finalize_tool_install(
     Ok(ExitStatus::Success)
 }
 
+/// Install the shell completions and man pages provided by the given package into the tool data
+/// directory, returning the paths to the installed files.
+///
+/// Existing files are left as-is, unless `force` is set.
+fn install_data_files(
+    site_packages: &SitePackages,
+    dist: &InstalledDist,
+    force: bool,
+    printer: Printer,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let data_files = data_file_paths(site_packages, dist.name(), dist.version())?;
+    if data_files.is_empty() {
+        debug!("No data files are provided by package `{}`", dist.name());
+        return Ok(Vec::new());
+    }
+
+    let data_directory = tool_data_dir()?;
+    debug!(
+        "Installing tool data files into: {}",
+        data_directory.user_display()
+    );
+
+    let mut installed = Vec::with_capacity(data_files.len());
+    for (relative_path, source_path) in data_files {
+        let target_path = data_directory.join(&relative_path);
+        if !force && target_path.exists() {
+            warn_user!(
+                "Data file already exists: `{}` (use `--force` to overwrite)",
+                target_path.simplified_display().cyan()
+            );
+            continue;
+        }
+        debug!("Installing data file: `{}`", relative_path.user_display());
+        if let Some(parent) = target_path.parent() {
+            fs_err::create_dir_all(parent).context("Failed to create data directory")?;
+        }
+        fs_err::copy(&source_path, &target_path).context("Failed to install data file")?;
+        installed.push((relative_path, target_path));
+    }
+
+    if !installed.is_empty() {
+        let s = if installed.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Installed {} data file{s}: {}",
+            installed.len(),
+            installed
+                .iter()
+                .map(|(relative_path, _)| relative_path.portable_display().bold().to_string())
+                .join(", ")
+        )?;
+    }
+
+    Ok(installed
+        .into_iter()
+        .map(|(_, target_path)| target_path)
+        .collect())
+}
+
 /// Displays a hint if an executable matching the package name can be found in a dependency of the package.
 fn hint_executable_from_dependency(
     name: &PackageName,
@@ -383,3 +503,22 @@ fn hint_executable_from_dependency(
 
     Ok(())
 }
+
+/// Returns the commit at which the tool is installed in the environment, if it was installed from
+/// a Git repository.
+pub(crate) fn installed_commit(
+    environment: &PythonEnvironment,
+    name: &PackageName,
+) -> Option<String> {
+    let site_packages = SitePackages::from_environment(environment).ok()?;
+    site_packages
+        .get_packages(name)
+        .into_iter()
+        .find_map(|dist| match dist {
+            InstalledDist::Url(dist) => match dist.direct_url.as_ref() {
+                DirectUrl::VcsUrl { vcs_info, .. } => vcs_info.commit_id.clone(),
+                _ => None,
+            },
+            _ => None,
+        })
+}
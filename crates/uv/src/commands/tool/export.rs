@@ -0,0 +1,233 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table, value};
+
+use uv_cache::Cache;
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_fs::Simplified;
+use uv_git_types::GitOid;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{ParsedGitUrl, VerbatimParsedUrl};
+use uv_redacted::DisplaySafeUrl;
+use uv_tool::{InstalledTools, Tool};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::tool::common::installed_commit;
+use crate::printer::Printer;
+
+/// Export the installed tools to a tools manifest, as read by `uv tool sync` and `uv tool import`.
+pub(crate) async fn export(
+    output_file: Option<&Path>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let lock = match installed_tools.lock().await {
+        Ok(lock) => Some(lock),
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut tools = if lock.is_some() {
+        installed_tools.tools()?
+    } else {
+        Vec::new()
+    };
+    tools.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut table = Table::new();
+    for (name, tool) in tools {
+        // Skip invalid tools
+        let Ok(tool) = tool else {
+            warn_user!(
+                "Ignoring malformed tool `{name}` (run `{}` to remove)",
+                format!("uv tool uninstall {name}").green()
+            );
+            continue;
+        };
+
+        // Pin the installed version, such that the tool can be re-created as-is.
+        let version = match installed_tools.version(&name, cache) {
+            Ok(version) => Some(version),
+            Err(err) => {
+                warn_user!("Failed to determine the installed version of `{name}`: {err}");
+                None
+            }
+        };
+
+        // Lock Git sources to the installed commit, rather than a branch or tag that may move.
+        let commit = if matches!(
+            tool_source(&name, &tool),
+            Some(RequirementSource::Git { .. })
+        ) {
+            let commit = installed_tools
+                .get_environment(&name, cache)
+                .ok()
+                .flatten()
+                .and_then(|environment| installed_commit(&environment, &name))
+                .and_then(|commit| GitOid::from_str(&commit).ok());
+            if commit.is_none() {
+                warn_user!(
+                    "Failed to determine the installed commit of `{name}`; exporting its Git source as-is"
+                );
+            }
+            commit
+        } else {
+            None
+        };
+
+        table.insert(
+            name.as_str(),
+            manifest_entry(&name, &tool, version.as_ref(), commit),
+        );
+    }
+
+    let count = table.len();
+    let mut document = DocumentMut::new();
+    document.insert("tools", Item::Table(table));
+
+    if let Some(output_file) = output_file {
+        fs_err::tokio::write(output_file, document.to_string()).await?;
+        let s = if count == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Exported {count} tool{s} to: {}",
+            output_file.user_display().cyan()
+        )?;
+    } else {
+        write!(printer.stdout(), "{document}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns the source of the tool's own requirement in its receipt.
+fn tool_source<'a>(name: &PackageName, tool: &'a Tool) -> Option<&'a RequirementSource> {
+    tool.requirements()
+        .iter()
+        .find(|requirement| requirement.name == *name)
+        .map(|requirement| &requirement.source)
+}
+
+/// Returns the tools manifest entry for a tool, e.g., `black = "==24.2.0"` or
+/// `black = { version = "==24.2.0", extras = ["jupyter"] }`.
+///
+/// Tools installed from Git are locked to the given commit, if any.
+fn manifest_entry(
+    name: &PackageName,
+    tool: &Tool,
+    version: Option<&Version>,
+    commit: Option<GitOid>,
+) -> Item {
+    let mut entry = InlineTable::new();
+    let mut extras = Array::new();
+
+    match tool
+        .requirements()
+        .iter()
+        .find(|requirement| requirement.name == *name)
+    {
+        Some(requirement) => {
+            match (&requirement.source, commit) {
+                (RequirementSource::Registry { specifier, .. }, _) => {
+                    let version = version
+                        .map(|version| format!("=={version}"))
+                        .unwrap_or_else(|| specifier.to_string());
+                    if !version.is_empty() {
+                        entry.insert("version", version.into());
+                    }
+                }
+                (
+                    RequirementSource::Git {
+                        git, subdirectory, ..
+                    },
+                    Some(commit),
+                ) => {
+                    let url = DisplaySafeUrl::from(ParsedGitUrl {
+                        url: git.clone().with_precise(commit),
+                        subdirectory: subdirectory.clone(),
+                    });
+                    entry.insert(
+                        "source",
+                        url.displayable_with_credentials().to_string().into(),
+                    );
+                }
+                (source, _) => {
+                    if let Some(url) = source.to_verbatim_parsed_url() {
+                        entry.insert("source", url.verbatim.to_string().into());
+                    }
+                }
+            }
+            extras.extend(requirement.extras.iter().map(ToString::to_string));
+        }
+        None => {
+            if let Some(version) = version {
+                entry.insert("version", format!("=={version}").into());
+            }
+        }
+    }
+
+    let with = requirements_array(
+        tool.requirements()
+            .iter()
+            .filter(|requirement| requirement.name != *name),
+    );
+    let constraints = requirements_array(tool.constraints());
+    let overrides = requirements_array(tool.overrides());
+    let build_constraints = requirements_array(tool.build_constraints());
+    let python = tool
+        .python()
+        .as_ref()
+        .map(uv_python::PythonRequest::to_canonical_string);
+
+    // Use the version specifier shorthand where possible.
+    if extras.is_empty()
+        && with.is_empty()
+        && constraints.is_empty()
+        && overrides.is_empty()
+        && build_constraints.is_empty()
+        && python.is_none()
+    {
+        if let Some(version) = entry.get("version").and_then(|version| version.as_str()) {
+            if entry.len() == 1 {
+                return value(version);
+            }
+        }
+    }
+
+    if !extras.is_empty() {
+        entry.insert("extras", extras.into());
+    }
+    if !with.is_empty() {
+        entry.insert("with", with.into());
+    }
+    if !constraints.is_empty() {
+        entry.insert("constraints", constraints.into());
+    }
+    if !overrides.is_empty() {
+        entry.insert("overrides", overrides.into());
+    }
+    if !build_constraints.is_empty() {
+        entry.insert("build-constraints", build_constraints.into());
+    }
+    if let Some(python) = python {
+        entry.insert("python", python.into());
+    }
+    value(entry)
+}
+
+/// Returns the requirements as an array of PEP 508 strings.
+fn requirements_array<'a>(requirements: impl IntoIterator<Item = &'a Requirement>) -> Array {
+    requirements
+        .into_iter()
+        .map(|requirement| {
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from(requirement.clone()).to_string()
+        })
+        .collect()
+}
@@ -8,9 +8,11 @@ use tracing::{debug, trace};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{Concurrency, Constraints, DryRun, Preview, Reinstall, Upgrade};
+use uv_configuration::{
+    Concurrency, Constraints, DryRun, Preview, PreviewFeatures, Reinstall, Upgrade,
+};
 use uv_distribution_types::{
-    NameRequirementSpecification, Requirement, RequirementSource,
+    NameRequirementSpecification, Requirement, RequirementSource, Resolution,
     UnresolvedRequirementSpecification,
 };
 use uv_normalize::PackageName;
@@ -33,9 +35,10 @@ use crate::commands::project::{
     sync_environment, update_environment,
 };
 use crate::commands::tool::common::{
-    finalize_tool_install, refine_interpreter, remove_entrypoints,
+    finalize_tool_install, global_python_request, refine_interpreter, remove_data_files,
+    remove_entrypoints,
 };
-use crate::commands::tool::{Target, ToolRequest};
+use crate::commands::tool::{Target, ToolRequest, layers};
 use crate::commands::{diagnostics, reporters::PythonDownloadReporter};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings, ResolverSettings};
@@ -53,11 +56,13 @@ pub(crate) async fn install(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    data_files: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    no_config: bool,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: Cache,
@@ -65,19 +70,29 @@ pub(crate) async fn install(
     preview: Preview,
 ) -> Result<ExitStatus> {
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
 
     let python_request = python.as_deref().map(PythonRequest::parse);
 
+    // If no Python version was requested, respect the global Python pin. Unlike an explicit
+    // request, the pin isn't persisted to the tool receipt, so `uv tool upgrade` keeps using the
+    // tool's existing interpreter rather than re-reading the pin.
+    let interpreter_request = match &python_request {
+        Some(request) => Some(request.clone()),
+        None => global_python_request(no_config).await?,
+    };
+
     // Pre-emptively identify a Python interpreter. We need an interpreter to resolve any unnamed
     // requirements, even if we end up using a different interpreter for the tool install itself.
     let interpreter = PythonInstallation::find_or_download(
-        python_request.as_ref(),
+        interpreter_request.as_ref(),
         EnvironmentPreference::OnlySystem,
         python_preference,
         python_downloads,
@@ -87,6 +102,9 @@ pub(crate) async fn install(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors
+            .python_install_require_attestations
+            .unwrap_or_default(),
         preview,
     )
     .await?
@@ -97,9 +115,11 @@ pub(crate) async fn install(
     let workspace_cache = WorkspaceCache::default();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     // Parse the input requirement.
@@ -124,9 +144,10 @@ pub(crate) async fn install(
             } else {
                 RequirementsSource::from_package(from)?
             };
-            let requirement = RequirementsSpecification::from_source(&source, &client_builder)
-                .await?
-                .requirements;
+            let requirement =
+                RequirementsSpecification::from_source(&source, &client_builder, &cache)
+                    .await?
+                    .requirements;
 
             // If the user provided an executable name, verify that it matches the `--from` requirement.
             let executable = if let Some(executable) = executable {
@@ -262,6 +283,7 @@ pub(crate) async fn install(
         overrides,
         None,
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -311,7 +333,7 @@ pub(crate) async fn install(
 
     // Resolve the build constraints.
     let build_constraints: Vec<Requirement> =
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, &cache)
             .await?
             .into_iter()
             .map(|constraint| constraint.requirement)
@@ -390,6 +412,8 @@ pub(crate) async fn install(
                 && constraints == tool_receipt.constraints()
                 && overrides == tool_receipt.overrides()
                 && build_constraints == tool_receipt.build_constraints()
+                // ...and the data files were installed, if requested...
+                && (!data_files || !tool_receipt.data_files().is_empty())
             {
                 if *tool_receipt.options() != options {
                     // ...but the options differ, we need to update the receipt.
@@ -433,7 +457,11 @@ pub(crate) async fn install(
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
-    let environment = if let Some(environment) = existing_environment {
+    //
+    // Environments with shared layers are always re-created, rather than updated in-place.
+    let environment = if let Some(environment) = existing_environment.filter(|environment| {
+        !preview.is_enabled(PreviewFeatures::TOOL_LAYERS) && !layers::is_layered(environment)
+    }) {
         let environment = match update_environment(
             environment,
             spec,
@@ -467,6 +495,7 @@ pub(crate) async fn install(
         // existing executables.
         if let Some(existing_receipt) = existing_tool_receipt {
             remove_entrypoints(&existing_receipt);
+            remove_data_files(&existing_receipt)?;
         }
 
         environment
@@ -503,7 +532,7 @@ pub(crate) async fn install(
                     // Python 3.10 interpreter and re-resolve.
                     let Some(interpreter) = refine_interpreter(
                         &interpreter,
-                        python_request.as_ref(),
+                        interpreter_request.as_ref(),
                         &err,
                         &client_builder,
                         &reporter,
@@ -565,26 +594,48 @@ pub(crate) async fn install(
         // executables.
         if let Some(existing_receipt) = existing_tool_receipt {
             remove_entrypoints(&existing_receipt);
+            remove_data_files(&existing_receipt)?;
         }
 
         // Sync the environment with the resolved requirements.
-        match sync_environment(
-            environment,
-            &resolution.into(),
-            Modifications::Exact,
-            Constraints::from_requirements(build_constraints.iter().cloned()),
-            (&settings).into(),
-            &network_settings,
-            &state,
-            Box::new(DefaultInstallLogger),
-            installer_metadata,
-            concurrency,
-            &cache,
-            printer,
-            preview,
-        )
-        .await
-        .inspect_err(|_| {
+        let resolution = Resolution::from(resolution);
+        let result = if preview.is_enabled(PreviewFeatures::TOOL_LAYERS) {
+            layers::sync(
+                environment,
+                &resolution,
+                &from.name,
+                &installed_tools,
+                Constraints::from_requirements(build_constraints.iter().cloned()),
+                (&settings).into(),
+                &network_settings,
+                &state,
+                Box::new(DefaultInstallLogger),
+                installer_metadata,
+                concurrency,
+                &cache,
+                printer,
+                preview,
+            )
+            .await
+        } else {
+            sync_environment(
+                environment,
+                &resolution,
+                Modifications::Exact,
+                Constraints::from_requirements(build_constraints.iter().cloned()),
+                (&settings).into(),
+                &network_settings,
+                &state,
+                Box::new(DefaultInstallLogger),
+                installer_metadata,
+                concurrency,
+                &cache,
+                printer,
+                preview,
+            )
+            .await
+        };
+        match result.inspect_err(|_| {
             // If we failed to sync, remove the newly created environment.
             debug!("Failed to sync environment; removing `{}`", from.name);
             let _ = installed_tools.remove_environment(&from.name);
@@ -599,12 +650,18 @@ pub(crate) async fn install(
         }
     };
 
+    // Remove any shared layers that are no longer used, e.g., by a previous version of the tool.
+    if let Err(err) = layers::prune(&installed_tools) {
+        warn_user!("Failed to remove unused shared layers: {err}");
+    }
+
     finalize_tool_install(
         &environment,
         &from.name,
         &installed_tools,
         options,
         force || invalid_tool_receipt,
+        data_files,
         python_request,
         requirements,
         constraints,
@@ -0,0 +1,274 @@
+//! Share dependencies between tool environments, i.e., `--preview-features tool-layers`.
+//!
+//! Each dependency that comes from a package index is installed into a shared layer in the tools
+//! directory, keyed by the interpreter and index, such that tools that depend on the same version
+//! of a package can reuse it. The layers are linked into a tool environment with a `.pth` file,
+//! while the tool itself, and any dependencies that don't come from an index, are installed into
+//! the tool environment directly.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cache_key::cache_digest;
+use uv_configuration::{Concurrency, Constraints, Preview};
+use uv_distribution_types::{Name, Resolution};
+use uv_fs::{PythonExt, Simplified};
+use uv_normalize::PackageName;
+use uv_python::{PythonEnvironment, Target};
+use uv_tool::InstalledTools;
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::{PlatformState, ProjectError, sync_environment};
+use crate::printer::Printer;
+use crate::settings::{InstallerSettingsRef, NetworkSettings};
+
+/// The `.pth` file that links the shared layers into a tool environment.
+const LAYERS_PTH: &str = "_uv_tool_layers.pth";
+
+/// The file that marks a layer as complete, i.e., fully installed.
+const LAYER_MARKER: &str = ".uv-layer";
+
+/// Returns `true` if the tool environment is linked to any shared layers.
+pub(crate) fn is_layered(environment: &PythonEnvironment) -> bool {
+    links(environment).is_some()
+}
+
+/// Sync a tool environment with the given resolution, installing the dependencies that come from a
+/// package index into shared layers.
+pub(crate) async fn sync(
+    environment: PythonEnvironment,
+    resolution: &Resolution,
+    name: &PackageName,
+    installed_tools: &InstalledTools,
+    build_constraints: Constraints,
+    settings: InstallerSettingsRef<'_>,
+    network_settings: &NetworkSettings,
+    state: &PlatformState,
+    logger: Box<dyn InstallLogger>,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<PythonEnvironment, ProjectError> {
+    let interpreter = environment.interpreter();
+
+    let mut shared = BTreeSet::new();
+    let mut layers = BTreeSet::new();
+    let mut created = Vec::new();
+    for dist in resolution.distributions() {
+        // The tool itself is always installed into the tool environment, to provide its
+        // executables.
+        if dist.name() == name {
+            continue;
+        }
+        let (Some(index), Some(version)) = (dist.index(), dist.version()) else {
+            continue;
+        };
+
+        let layer = installed_tools
+            .layers_dir()
+            .join(cache_digest(&(
+                interpreter.sys_base_prefix(),
+                interpreter.python_full_version().to_string(),
+                interpreter.gil_disabled(),
+                index.url().as_str(),
+            )))
+            .join(format!("{}-{version}", dist.name()));
+
+        if !layer.join(LAYER_MARKER).is_file() {
+            debug!(
+                "Creating shared layer for `{}` at: `{}`",
+                dist.name(),
+                layer.user_display()
+            );
+
+            // Remove any incomplete layer, e.g., from an interrupted installation.
+            match fs_err::remove_dir_all(&layer) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+
+            let target = environment
+                .clone()
+                .with_target(Target::from(layer.clone()))?;
+            let resolution = Resolution::new(resolution.graph().clone())
+                .filter(|candidate| candidate.name() == dist.name());
+            sync_environment(
+                target,
+                &resolution,
+                Modifications::Sufficient,
+                build_constraints.clone(),
+                settings.clone(),
+                network_settings,
+                state,
+                Box::new(DefaultInstallLogger),
+                installer_metadata,
+                concurrency,
+                cache,
+                Printer::Quiet,
+                preview,
+            )
+            .await?;
+
+            fs_err::write(layer.join(LAYER_MARKER), "")?;
+            created.push((dist.name().clone(), version.clone()));
+        } else {
+            debug!(
+                "Using shared layer for `{}` at: `{}`",
+                dist.name(),
+                layer.user_display()
+            );
+        }
+
+        shared.insert(dist.name().clone());
+        layers.insert(layer);
+    }
+
+    if !created.is_empty() {
+        created.sort_unstable();
+        let s = if created.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Installed {} shared layer{s}", created.len()).dimmed()
+        )?;
+        for (name, version) in created {
+            writeln!(
+                printer.stderr(),
+                " {} {}{}",
+                "+".green(),
+                name.bold(),
+                format!("=={version}").dimmed()
+            )?;
+        }
+    }
+
+    // Install the remaining distributions into the tool environment.
+    let resolution = resolution
+        .clone()
+        .filter(|dist| !shared.contains(dist.name()));
+    let environment = sync_environment(
+        environment,
+        &resolution,
+        Modifications::Exact,
+        build_constraints,
+        settings,
+        network_settings,
+        state,
+        logger,
+        installer_metadata,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    // Link the layers into the tool environment.
+    let site_packages = environment
+        .site_packages()
+        .next()
+        .ok_or(ProjectError::NoSitePackages)?;
+    let pth = site_packages.join(LAYERS_PTH);
+    if layers.is_empty() {
+        match fs_err::remove_file(&pth) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    } else {
+        let contents = layers.iter().fold(String::new(), |mut acc, layer| {
+            let _ = writeln!(
+                acc,
+                "import site; site.addsitedir(\"{}\")",
+                layer.escape_for_python()
+            );
+            acc
+        });
+        fs_err::write(&pth, contents)?;
+    }
+
+    Ok(environment)
+}
+
+/// Remove any shared layers that aren't linked into a tool environment.
+pub(crate) fn prune(installed_tools: &InstalledTools) -> Result<()> {
+    let layers_dir = installed_tools.layers_dir();
+    if !layers_dir.is_dir() {
+        return Ok(());
+    }
+
+    // Read the layers linked into each tool environment. The `.pth` file is at the top-level of
+    // the `site-packages` directory, e.g., `<name>/lib/python3.12/site-packages`.
+    let mut linked = String::new();
+    for entry in walkdir::WalkDir::new(installed_tools.root())
+        .min_depth(1)
+        .max_depth(5)
+        .into_iter()
+        .filter_entry(|entry| entry.path() != layers_dir.as_path())
+    {
+        let entry = entry?;
+        if entry.file_name() == LAYERS_PTH {
+            linked.push_str(&fs_err::read_to_string(entry.path())?);
+        }
+    }
+
+    for bucket in uv_fs::directories(&layers_dir)? {
+        for layer in uv_fs::directories(&bucket)? {
+            if !is_linked(&layer, &linked) {
+                debug!("Removing unused shared layer: `{}`", layer.user_display());
+                fs_err::remove_dir_all(&layer)?;
+            }
+        }
+        if uv_fs::directories(&bucket)?.next().is_none() {
+            fs_err::remove_dir_all(&bucket)?;
+        }
+    }
+    if uv_fs::directories(&layers_dir)?.next().is_none() {
+        fs_err::remove_dir_all(&layers_dir)?;
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the layer is referenced in the contents of a `.pth` file.
+fn is_linked(layer: &Path, linked: &str) -> bool {
+    linked.contains(&format!("\"{}\"", layer.escape_for_python()))
+}
+
+/// Returns the shared layers linked into the tool environment.
+pub(crate) fn linked(environment: &PythonEnvironment) -> Vec<PathBuf> {
+    links(environment)
+        .into_iter()
+        .flat_map(|contents| {
+            contents
+                .lines()
+                .filter_map(|line| {
+                    let path = line
+                        .strip_prefix("import site; site.addsitedir(\"")?
+                        .strip_suffix("\")")?;
+                    Some(PathBuf::from(
+                        path.replace("\\\"", "\"").replace("\\\\", "\\"),
+                    ))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Returns the contents of the `.pth` file that links the shared layers into the tool environment,
+/// if any.
+pub(crate) fn links(environment: &PythonEnvironment) -> Option<String> {
+    environment
+        .site_packages()
+        .find_map(|site_packages| fs_err::read_to_string(site_packages.join(LAYERS_PTH)).ok())
+}
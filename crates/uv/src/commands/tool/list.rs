@@ -1,16 +1,35 @@
 use std::fmt::Write;
 
 use anyhow::Result;
+use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
+use tokio::sync::Semaphore;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_cli::ToolListFormat;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_configuration::Concurrency;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{IndexCapabilities, InstalledDist, Name, RequiresPython};
 use uv_fs::Simplified;
-use uv_tool::InstalledTools;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::Target;
+use uv_settings::{Combine, ResolverInstallerOptions};
+use uv_tool::{InstalledTools, Tool};
 use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
+use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::commands::reporters::LatestVersionReporter;
+use crate::commands::tool::layers;
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
 /// List installed tools.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -19,6 +38,12 @@ pub(crate) async fn list(
     show_version_specifiers: bool,
     show_with: bool,
     show_extras: bool,
+    outdated: bool,
+    include_dependencies: bool,
+    output_format: ToolListFormat,
+    filesystem: ResolverInstallerOptions,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -26,6 +51,9 @@ pub(crate) async fn list(
     let _lock = match installed_tools.lock().await {
         Ok(lock) => lock,
         Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            if matches!(output_format, ToolListFormat::Json) {
+                writeln!(printer.stdout(), "[]")?;
+            }
             writeln!(printer.stderr(), "No tools installed")?;
             return Ok(ExitStatus::Success);
         }
@@ -36,10 +64,14 @@ pub(crate) async fn list(
     tools.sort_by_key(|(name, _)| name.clone());
 
     if tools.is_empty() {
+        if matches!(output_format, ToolListFormat::Json) {
+            writeln!(printer.stdout(), "[]")?;
+        }
         writeln!(printer.stderr(), "No tools installed")?;
         return Ok(ExitStatus::Success);
     }
 
+    let mut installed = Vec::with_capacity(tools.len());
     for (name, tool) in tools {
         // Skip invalid tools
         let Ok(tool) = tool else {
@@ -50,7 +82,7 @@ pub(crate) async fn list(
             continue;
         };
 
-        // Output tool name and version
+        // Determine the tool version
         let version = match installed_tools.version(&name, cache) {
             Ok(version) => version,
             Err(e) => {
@@ -66,6 +98,44 @@ pub(crate) async fn list(
             }
         };
 
+        installed.push((name, tool, version));
+    }
+
+    if outdated {
+        return list_outdated(
+            installed,
+            include_dependencies,
+            output_format,
+            &filesystem,
+            &installed_tools,
+            network_settings,
+            concurrency,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
+    if matches!(output_format, ToolListFormat::Json) {
+        let entries = installed
+            .into_iter()
+            .map(|(name, tool, version)| Entry {
+                executables: tool
+                    .entrypoints()
+                    .iter()
+                    .map(|entrypoint| entrypoint.name.clone())
+                    .collect(),
+                name,
+                version,
+                latest_version: None,
+                outdated_dependencies: None,
+            })
+            .collect::<Vec<_>>();
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, tool, version) in installed {
         let version_specifier = show_version_specifiers
             .then(|| {
                 tool.requirements()
@@ -146,3 +216,245 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// List the installed tools (and, optionally, their dependencies) with a newer version available.
+async fn list_outdated(
+    installed: Vec<(PackageName, Tool, Version)>,
+    include_dependencies: bool,
+    output_format: ToolListFormat,
+    filesystem: &ResolverInstallerOptions,
+    installed_tools: &InstalledTools,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut entries = Vec::new();
+    for (name, tool, version) in installed {
+        let packages = find_outdated(
+            &name,
+            &tool,
+            include_dependencies,
+            filesystem,
+            installed_tools,
+            network_settings,
+            concurrency,
+            cache,
+            printer,
+        )
+        .await?;
+        if packages.is_empty() {
+            continue;
+        }
+
+        let (latest_version, dependencies): (Vec<_>, Vec<_>) = packages
+            .into_iter()
+            .partition(|package| package.name == name);
+        entries.push(Entry {
+            executables: tool
+                .entrypoints()
+                .iter()
+                .map(|entrypoint| entrypoint.name.clone())
+                .collect(),
+            latest_version: latest_version
+                .into_iter()
+                .next()
+                .map(|package| package.latest_version),
+            outdated_dependencies: include_dependencies.then_some(dependencies),
+            name,
+            version,
+        });
+    }
+
+    match output_format {
+        ToolListFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+        }
+        ToolListFormat::Text if entries.is_empty() => {
+            writeln!(printer.stderr(), "All tools are up-to-date")?;
+        }
+        ToolListFormat::Text => {
+            // Flatten the tools and their dependencies into rows of the table.
+            let rows = entries
+                .iter()
+                .flat_map(|entry| {
+                    entry
+                        .latest_version
+                        .as_ref()
+                        .map(|latest| (&entry.name, &entry.name, &entry.version, latest))
+                        .into_iter()
+                        .chain(entry.outdated_dependencies.iter().flatten().map(|package| {
+                            (
+                                &entry.name,
+                                &package.name,
+                                &package.version,
+                                &package.latest_version,
+                            )
+                        }))
+                })
+                .collect::<Vec<_>>();
+
+            let mut columns = vec![Column {
+                header: String::from("Tool"),
+                rows: rows.iter().map(|(tool, ..)| tool.to_string()).collect(),
+            }];
+            if include_dependencies {
+                columns.push(Column {
+                    header: String::from("Package"),
+                    rows: rows
+                        .iter()
+                        .map(|(_, package, ..)| package.to_string())
+                        .collect(),
+                });
+            }
+            columns.push(Column {
+                header: String::from("Version"),
+                rows: rows
+                    .iter()
+                    .map(|(_, _, version, _)| version.to_string())
+                    .collect(),
+            });
+            columns.push(Column {
+                header: String::from("Latest"),
+                rows: rows.iter().map(|(.., latest)| latest.to_string()).collect(),
+            });
+
+            for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+                writeln!(printer.stdout(), "{}", elems.join(" ").trim_end())?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the packages in a tool environment with a newer version available on the indexes the tool
+/// was installed from.
+///
+/// Only the tool itself is checked, unless `include_dependencies` is set. Packages that weren't
+/// installed from an index (e.g., from Git or a local directory) are skipped.
+async fn find_outdated(
+    name: &PackageName,
+    tool: &Tool,
+    include_dependencies: bool,
+    filesystem: &ResolverInstallerOptions,
+    installed_tools: &InstalledTools,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Vec<OutdatedPackage>> {
+    let Some(environment) = installed_tools.get_environment(name, cache)? else {
+        return Ok(Vec::new());
+    };
+
+    // Collect the installed packages, including those in any shared layers.
+    let mut site_packages = vec![SitePackages::from_environment(&environment)?];
+    for layer in layers::linked(&environment) {
+        let layer = environment.clone().with_target(Target::from(layer))?;
+        site_packages.push(SitePackages::from_environment(&layer)?);
+    }
+    let packages = site_packages
+        .iter()
+        .flat_map(SitePackages::iter)
+        .filter(|dist| matches!(dist, InstalledDist::Registry(_)))
+        .filter(|dist| include_dependencies || dist.name() == name)
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+        .collect::<Vec<_>>();
+    if packages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Resolve the appropriate settings, preferring: receipt > user.
+    let settings = ResolverInstallerSettings::from(
+        ResolverInstallerOptions::from(tool.options().clone()).combine(filesystem.clone()),
+    );
+
+    let client_builder = BaseClientBuilder::new()
+        .retry_settings(&network_settings.retry)
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .keyring(settings.resolver.keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+    // Initialize the registry client.
+    let interpreter = environment.interpreter();
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
+        .index_locations(&settings.resolver.index_locations)
+        .index_strategy(settings.resolver.index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+    let capabilities = IndexCapabilities::default();
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    // Determine the platform tags.
+    let tags = interpreter.tags()?;
+    let requires_python =
+        RequiresPython::greater_than_equal_version(interpreter.python_full_version());
+
+    // Initialize the client to fetch the latest version of each package.
+    let client = LatestClient {
+        client: &client,
+        capabilities: &capabilities,
+        prerelease: settings.resolver.prerelease,
+        exclude_newer: settings.resolver.exclude_newer,
+        tags: Some(tags),
+        requires_python: &requires_python,
+    };
+
+    let reporter = LatestVersionReporter::from(printer).with_length(packages.len() as u64);
+
+    // Fetch the latest version for each package.
+    let mut fetches = futures::stream::iter(&packages)
+        .map(async |dist| {
+            let latest = client
+                .find_latest(dist.name(), None, &download_concurrency)
+                .await?;
+            Ok::<(&InstalledDist, Option<DistFilename>), uv_client::Error>((*dist, latest))
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut outdated = Vec::new();
+    while let Some((dist, latest)) = fetches.next().await.transpose()? {
+        let Some(latest) = latest else {
+            reporter.on_fetch_progress();
+            continue;
+        };
+        reporter.on_fetch_version(dist.name(), latest.version());
+        if latest.version() > dist.version() {
+            outdated.push(OutdatedPackage {
+                name: dist.name().clone(),
+                version: dist.version().clone(),
+                latest_version: latest.version().clone(),
+            });
+        }
+    }
+    reporter.on_fetch_complete();
+
+    outdated.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(outdated)
+}
+
+/// An entry in a JSON list of installed tools.
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: PackageName,
+    version: Version,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_version: Option<Version>,
+    executables: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outdated_dependencies: Option<Vec<OutdatedPackage>>,
+}
+
+/// A package with a newer version available.
+#[derive(Debug, Serialize)]
+struct OutdatedPackage {
+    name: PackageName,
+    version: Version,
+    latest_version: Version,
+}
@@ -8,9 +8,12 @@ use uv_python::PythonRequest;
 
 mod common;
 pub(crate) mod dir;
+pub(crate) mod export;
 pub(crate) mod install;
+mod layers;
 pub(crate) mod list;
 pub(crate) mod run;
+pub(crate) mod sync;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 pub(crate) mod upgrade;
@@ -14,10 +14,11 @@ use tracing::{debug, warn};
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cache_key::hash_digest;
 use uv_cli::ExternalCommand;
 use uv_client::BaseClientBuilder;
 use uv_configuration::Constraints;
-use uv_configuration::{Concurrency, Preview};
+use uv_configuration::{Concurrency, Preview, RefreshInterval};
 use uv_distribution_types::InstalledDist;
 use uv_distribution_types::{
     IndexUrl, Name, NameRequirementSpecification, Requirement, RequirementSource,
@@ -51,7 +52,7 @@ use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_names,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::tool::common::{matching_packages, refine_interpreter};
+use crate::commands::tool::common::{global_python_request, matching_packages, refine_interpreter};
 use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::{diagnostics, project::environment::CachedEnvironment};
 use crate::printer::Printer;
@@ -93,14 +94,18 @@ pub(crate) async fn run(
     network_settings: NetworkSettings,
     invocation_source: ToolRunCommand,
     isolated: bool,
+    refresh_interval: Option<RefreshInterval>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    no_config: bool,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: Cache,
     printer: Printer,
     env_file: Vec<PathBuf>,
     no_env_file: bool,
+    env: Vec<(String, String)>,
+    unset: Vec<String>,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
     /// Whether or not a path looks like a Python script based on the file extension.
@@ -270,8 +275,10 @@ pub(crate) async fn run(
         &settings,
         &network_settings,
         isolated,
+        refresh_interval,
         python_preference,
         python_downloads,
+        no_config,
         installer_metadata,
         concurrency,
         &cache,
@@ -369,6 +376,13 @@ pub(crate) async fn run(
     .context("Failed to build new PATH variable")?;
     process.env(EnvVars::PATH, new_path);
 
+    // Apply the environment variables from `--env` and `--unset`, which take precedence over the
+    // environment and any `.env` files.
+    for key in &unset {
+        process.env_remove(key);
+    }
+    process.envs(env);
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     let space = if args.is_empty() { "" } else { " " };
@@ -680,8 +694,10 @@ async fn get_or_create_environment(
     settings: &ResolverInstallerSettings,
     network_settings: &NetworkSettings,
     isolated: bool,
+    refresh_interval: Option<RefreshInterval>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    no_config: bool,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: &Cache,
@@ -689,15 +705,17 @@ async fn get_or_create_environment(
     preview: Preview,
 ) -> Result<(ToolRequirement, PythonEnvironment), ProjectError> {
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
 
-    // Figure out what Python we're targeting, either explicitly like `uvx python@3`, or via the
-    // -p/--python flag.
+    // Figure out what Python we're targeting, either explicitly like `uvx python@3`, via the
+    // -p/--python flag, or via the global Python pin.
     let python_request = match request {
         ToolRequest::Python {
             request: tool_python_request,
@@ -732,7 +750,10 @@ async fn get_or_create_environment(
                 }
             }
         }
-        ToolRequest::Package { .. } => python.map(PythonRequest::parse),
+        ToolRequest::Package { .. } => match python {
+            Some(python) => Some(PythonRequest::parse(python)),
+            None => global_python_request(no_config).await?,
+        },
     };
 
     // Discover an interpreter.
@@ -747,6 +768,9 @@ async fn get_or_create_environment(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors
+            .python_install_require_attestations
+            .unwrap_or_default(),
         preview,
     )
     .await?
@@ -872,6 +896,7 @@ async fn get_or_create_environment(
         overrides,
         None,
         &client_builder,
+        cache,
     )
     .await?;
 
@@ -964,6 +989,50 @@ async fn get_or_create_environment(
         }
     }
 
+    // If configured, reuse the environment from a recent resolution of the same request, unless
+    // the user requested a refresh (e.g., via `--refresh` or `ruff@latest`).
+    let request_digest = refresh_interval
+        .filter(|interval| !interval.duration().is_zero())
+        .map(|_| {
+            // Include any settings that affect the resolution, such that changing them invalidates
+            // the environment.
+            let indexes = settings
+                .resolver
+                .index_locations
+                .allowed_indexes()
+                .into_iter()
+                .map(|index| {
+                    (
+                        index.url().to_string(),
+                        index.format,
+                        index.explicit,
+                        index.default,
+                    )
+                })
+                .collect::<Vec<_>>();
+            hash_digest(&(
+                &requirements,
+                &constraints,
+                &overrides,
+                interpreter.sys_executable(),
+                indexes,
+                settings.resolver.index_strategy,
+                settings.resolver.prerelease,
+                settings.resolver.resolution,
+                settings.resolver.exclude_newer,
+            ))
+        });
+    if let (Some(interval), Some(digest)) = (refresh_interval, request_digest.as_deref()) {
+        if cache.refresh().is_none() {
+            if let Some(environment) =
+                CachedEnvironment::from_request(digest, interval.duration(), cache)
+            {
+                debug!("Reusing environment resolved within the last {interval}");
+                return Ok((from, environment.into()));
+            }
+        }
+    }
+
     // Create a `RequirementsSpecification` from the resolved requirements, to avoid re-resolving.
     let spec = EnvironmentSpecification::from(RequirementsSpecification {
         requirements: requirements
@@ -983,7 +1052,7 @@ async fn get_or_create_environment(
 
     // Read the `--build-constraints` requirements.
     let build_constraints = Constraints::from_requirements(
-        operations::read_constraints(build_constraints, &client_builder)
+        operations::read_constraints(build_constraints, &client_builder, cache)
             .await?
             .into_iter()
             .map(|constraint| constraint.requirement),
@@ -1080,5 +1149,11 @@ async fn get_or_create_environment(
         },
     };
 
+    if let Some(digest) = request_digest.as_deref() {
+        if let Err(err) = environment.record_request(digest, cache) {
+            debug!("Failed to record the environment for the request: {err}");
+        }
+    }
+
     Ok((from, environment.into()))
 }
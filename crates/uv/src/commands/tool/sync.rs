@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_configuration::{Concurrency, Preview};
+use uv_dirs::user_uv_config_dir;
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::VersionSpecifiers;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_requirements::RequirementsSource;
+use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions};
+use uv_tool::InstalledTools;
+
+use crate::commands::ExitStatus;
+use crate::commands::tool::install::install;
+use crate::commands::tool::uninstall::uninstall;
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
+
+/// A manifest of the tools to install, e.g., in `~/.config/uv/tools.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct ToolManifest {
+    /// The tools to install, by package name.
+    #[serde(default)]
+    tools: BTreeMap<PackageName, ManifestTool>,
+}
+
+/// A tool in the [`ToolManifest`], either as a version specifier (e.g., `ruff = ">=0.5"`) or as a
+/// table (e.g., `black = { version = ">=24", extras = ["jupyter"] }`).
+///
+/// The format is shared with `uv tool export` and `uv tool import`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ManifestTool {
+    Specifiers(VersionSpecifiers),
+    Table(ManifestToolTable),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct ManifestToolTable {
+    /// The version specifiers for the tool.
+    version: Option<VersionSpecifiers>,
+    /// The direct URL or Git source to install the tool from, e.g.,
+    /// `git+https://github.com/psf/black@main`, in lieu of a version.
+    source: Option<String>,
+    /// The extras to install with the tool.
+    #[serde(default)]
+    extras: Vec<ExtraName>,
+    /// Additional packages to install in the tool environment, as with `--with`.
+    #[serde(default)]
+    with: Vec<String>,
+    /// Constraints to apply when resolving the tool environment, as with `--constraints`.
+    #[serde(default)]
+    constraints: Vec<String>,
+    /// Overrides to apply when resolving the tool environment, as with `--overrides`.
+    #[serde(default)]
+    overrides: Vec<String>,
+    /// Constraints to apply when building source distributions, as with `--build-constraints`.
+    #[serde(default)]
+    build_constraints: Vec<String>,
+    /// The Python interpreter to use to build the tool environment.
+    python: Option<String>,
+}
+
+impl ManifestTool {
+    /// Returns the requirement to install the tool, e.g., `black[jupyter]>=24`.
+    fn requirement(&self, name: &PackageName) -> Result<String> {
+        match self {
+            Self::Specifiers(specifiers) => Ok(format!("{name}{specifiers}")),
+            Self::Table(table) => {
+                let mut requirement = name.to_string();
+                if !table.extras.is_empty() {
+                    write!(requirement, "[{}]", table.extras.iter().join(",")).unwrap();
+                }
+                match (&table.version, &table.source) {
+                    (Some(_), Some(_)) => {
+                        bail!(
+                            "Tool `{name}` in the tools manifest can't specify both `version` and `source`"
+                        );
+                    }
+                    (Some(version), None) => write!(requirement, "{version}").unwrap(),
+                    (None, Some(source)) => write!(requirement, " @ {source}").unwrap(),
+                    (None, None) => {}
+                }
+                Ok(requirement)
+            }
+        }
+    }
+
+    /// Returns the additional packages to install in the tool environment.
+    fn with(&self) -> &[String] {
+        match self {
+            Self::Specifiers(_) => &[],
+            Self::Table(table) => &table.with,
+        }
+    }
+
+    /// Returns the constraints to apply when resolving the tool environment.
+    fn constraints(&self) -> &[String] {
+        match self {
+            Self::Specifiers(_) => &[],
+            Self::Table(table) => &table.constraints,
+        }
+    }
+
+    /// Returns the overrides to apply when resolving the tool environment.
+    fn overrides(&self) -> &[String] {
+        match self {
+            Self::Specifiers(_) => &[],
+            Self::Table(table) => &table.overrides,
+        }
+    }
+
+    /// Returns the constraints to apply when building source distributions.
+    fn build_constraints(&self) -> &[String] {
+        match self {
+            Self::Specifiers(_) => &[],
+            Self::Table(table) => &table.build_constraints,
+        }
+    }
+
+    /// Returns the Python interpreter requested for the tool, if any.
+    fn python(&self) -> Option<&str> {
+        match self {
+            Self::Specifiers(_) => None,
+            Self::Table(table) => table.python.as_deref(),
+        }
+    }
+}
+
+/// Returns the default path to the tools manifest.
+fn default_manifest() -> Option<PathBuf> {
+    user_uv_config_dir().map(|dir| dir.join("tools.toml"))
+}
+
+/// Read the [`ToolManifest`] at the given path.
+fn read_manifest(path: &Path) -> Result<ToolManifest> {
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!("No tools manifest found at: `{}`", path.user_display());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    toml::from_str(&content)
+        .with_context(|| format!("Failed to parse tools manifest: `{}`", path.user_display()))
+}
+
+/// Returns the [`RequirementsSource`] for each requirement in the tools manifest.
+fn requirements_sources(requirements: &[String]) -> Result<Vec<RequirementsSource>> {
+    requirements
+        .iter()
+        .map(|requirement| RequirementsSource::from_with_package_argument(requirement))
+        .collect()
+}
+
+/// Install and uninstall tools to match the tools manifest.
+pub(crate) async fn sync(
+    manifest: Option<PathBuf>,
+    inexact: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    options: ResolverInstallerOptions,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    no_config: bool,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let Some(path) = manifest.or_else(default_manifest) else {
+        bail!(
+            "Failed to determine the user configuration directory; use `--manifest` to provide the tools manifest"
+        );
+    };
+    debug!("Reading tools manifest at: `{}`", path.user_display());
+    let manifest = read_manifest(&path)?;
+
+    // Install the tools in the manifest, leaving any that are already satisfied as-is.
+    for (name, tool) in &manifest.tools {
+        let with = requirements_sources(tool.with())?;
+        let constraints = requirements_sources(tool.constraints())?;
+        let overrides = requirements_sources(tool.overrides())?;
+        let build_constraints = requirements_sources(tool.build_constraints())?;
+        let python = tool.python().map(ToString::to_string).or(python.clone());
+
+        let status = Box::pin(install(
+            tool.requirement(name)?,
+            false,
+            None,
+            &with,
+            &constraints,
+            &overrides,
+            &build_constraints,
+            python,
+            install_mirrors.clone(),
+            false,
+            false,
+            options.clone(),
+            settings.clone(),
+            network_settings.clone(),
+            python_preference,
+            python_downloads,
+            no_config,
+            installer_metadata,
+            concurrency,
+            cache.clone(),
+            printer,
+            preview,
+        ))
+        .await
+        .with_context(|| format!("Failed to install `{name}` from the tools manifest"))?;
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
+    }
+
+    if inexact {
+        return Ok(ExitStatus::Success);
+    }
+
+    // Uninstall any tools that aren't in the manifest.
+    let installed_tools = InstalledTools::from_settings()?;
+    let extraneous = if installed_tools.root().is_dir() {
+        installed_tools
+            .tools()?
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| !manifest.tools.contains_key(name))
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    if extraneous.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Uninstalling {} not listed in the tools manifest: {}",
+        if extraneous.len() == 1 {
+            "tool"
+        } else {
+            "tools"
+        },
+        extraneous.iter().map(|name| name.cyan()).join(", ")
+    )?;
+    uninstall(extraneous, printer).await
+}
@@ -8,8 +8,10 @@ use tracing::debug;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_tool::{InstalledTools, Tool, ToolEntrypoint};
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
+use crate::commands::tool::layers;
 use crate::printer::Printer;
 
 /// Uninstall a tool.
@@ -33,6 +35,11 @@ pub(crate) async fn uninstall(name: Vec<PackageName>, printer: Printer) -> Resul
     // Perform the uninstallation.
     do_uninstall(&installed_tools, name, printer).await?;
 
+    // Remove any shared layers that are no longer used.
+    if let Err(err) = layers::prune(&installed_tools) {
+        warn_user!("Failed to remove unused shared layers: {err}");
+    }
+
     // Clean up any empty directories.
     if uv_fs::directories(installed_tools.root())?.all(|path| uv_fs::is_temporary(&path)) {
         fs_err::tokio::remove_dir_all(&installed_tools.root())
@@ -214,5 +221,19 @@ async fn uninstall_tool(
         }
     }
 
+    // Remove the tool's data files, i.e., shell completions and man pages.
+    for path in receipt.data_files() {
+        debug!("Removing data file: {}", path.user_display());
+        match fs_err::tokio::remove_file(path).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("Data file not found: {}", path.user_display());
+            }
+            Err(err) => {
+                return Err(err.into());
+            }
+        }
+    }
+
     Ok(entrypoints.to_vec())
 }
@@ -7,10 +7,12 @@ use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{Concurrency, Constraints, DryRun, Preview};
-use uv_distribution_types::Requirement;
+use uv_configuration::{Concurrency, Constraints, DryRun, Preview, PreviewFeatures};
+use uv_distribution_types::{Name, Requirement, RequirementSource, Resolution};
 use uv_fs::CWD;
+use uv_git_types::GitReference;
 use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
     PythonRequest,
@@ -18,6 +20,7 @@ use uv_python::{
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 
 use crate::commands::pip::loggers::{
@@ -28,7 +31,8 @@ use crate::commands::project::{
     EnvironmentUpdate, PlatformState, resolve_environment, sync_environment, update_environment,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{installed_commit, remove_data_files, remove_entrypoints};
+use crate::commands::tool::layers;
 use crate::commands::{ExitStatus, conjunction, tool::common::finalize_tool_install};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
@@ -36,6 +40,7 @@ use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 /// Upgrade a tool.
 pub(crate) async fn upgrade(
     names: Vec<String>,
+    dry_run: DryRun,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     args: ResolverInstallerOptions,
@@ -52,6 +57,9 @@ pub(crate) async fn upgrade(
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = installed_tools.lock().await?;
 
+    // When upgrading all tools, report any tools that are held back by their constraints.
+    let all = names.is_empty();
+
     // Collect the tools to upgrade, along with any constraints.
     let names: BTreeMap<PackageName, Vec<Requirement>> = {
         if names.is_empty() {
@@ -80,9 +88,11 @@ pub(crate) async fn upgrade(
 
     let reporter = PythonDownloadReporter::single(printer);
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     let python_request = python.as_deref().map(PythonRequest::parse);
@@ -100,6 +110,9 @@ pub(crate) async fn upgrade(
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
                 install_mirrors.python_downloads_json_url.as_deref(),
+                install_mirrors
+                    .python_install_require_attestations
+                    .unwrap_or_default(),
                 preview,
             )
             .await?
@@ -115,6 +128,9 @@ pub(crate) async fn upgrade(
     // Determine whether we applied any upgrades.
     let mut did_upgrade_environment = vec![];
 
+    // Determine whether any tools were held back by their version constraints.
+    let mut held_back = vec![];
+
     let mut errors = Vec::new();
     for (name, constraints) in &names {
         debug!("Upgrading tool: `{name}`");
@@ -130,10 +146,33 @@ pub(crate) async fn upgrade(
             &filesystem,
             installer_metadata,
             concurrency,
+            dry_run,
             preview,
         )
         .await;
 
+        if all && result.is_ok() {
+            match find_held_back(
+                name,
+                interpreter.as_ref(),
+                printer,
+                &installed_tools,
+                &args,
+                &network_settings,
+                cache,
+                &filesystem,
+                concurrency,
+                dry_run,
+                preview,
+            )
+            .await
+            {
+                Ok(Some(tool)) => held_back.push(tool),
+                Ok(None) => {}
+                Err(err) => debug!("Failed to determine whether `{name}` was held back: {err}"),
+            }
+        }
+
         match result {
             Ok(UpgradeOutcome::UpgradeEnvironment) => {
                 did_upgrade_environment.push(name);
@@ -150,6 +189,13 @@ pub(crate) async fn upgrade(
         }
     }
 
+    // Remove any shared layers that are no longer used.
+    if !dry_run.enabled() {
+        if let Err(err) = layers::prune(&installed_tools) {
+            warn_user!("Failed to remove unused shared layers: {err}");
+        }
+    }
+
     if !errors.is_empty() {
         for (name, err) in errors
             .into_iter()
@@ -186,16 +232,58 @@ pub(crate) async fn upgrade(
             let s = if tools.len() > 1 { "s" } else { "" };
             writeln!(
                 printer.stderr(),
-                "Upgraded tool environment{s} for {} to {}",
+                "{} tool environment{s} for {} to {}",
+                if dry_run.enabled() {
+                    "Would upgrade"
+                } else {
+                    "Upgraded"
+                },
                 conjunction(tools),
                 python_request.cyan(),
             )?;
         }
     }
 
+    if !held_back.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{} held back by version constraints:",
+            if held_back.len() == 1 {
+                "One tool was"
+            } else {
+                "Some tools were"
+            }
+        )?;
+        for tool in held_back {
+            writeln!(
+                printer.stderr(),
+                " {} {} v{} (requires `{}{}`, latest: v{})",
+                "~".yellow(),
+                tool.name.bold(),
+                tool.version,
+                tool.name,
+                tool.specifiers,
+                tool.latest,
+            )?;
+        }
+    }
+
     Ok(ExitStatus::Success)
 }
 
+/// A tool that wasn't upgraded to the latest version due to its version constraints.
+#[derive(Debug)]
+struct HeldBack {
+    /// The name of the tool.
+    name: PackageName,
+    /// The version of the tool after upgrading.
+    version: Version,
+    /// The latest version of the tool, ignoring its version constraints.
+    latest: Version,
+    /// The version constraints from the tool's receipt.
+    specifiers: VersionSpecifiers,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum UpgradeOutcome {
     /// The tool itself was upgraded.
@@ -221,6 +309,7 @@ async fn upgrade_tool(
     filesystem: &ResolverInstallerOptions,
     installer_metadata: bool,
     concurrency: Concurrency,
+    dry_run: DryRun,
     preview: Preview,
 ) -> Result<UpgradeOutcome> {
     // Ensure the tool is installed.
@@ -264,6 +353,33 @@ async fn upgrade_tool(
         }
     };
 
+    // If the tool was installed from Git, track the commit it's installed at. Tools that follow a
+    // branch are updated to its latest commit, while tools pinned to a tag or commit stay put.
+    let git_reference = existing_tool_receipt
+        .requirements()
+        .iter()
+        .filter(|requirement| requirement.name == *name)
+        .find_map(|requirement| match &requirement.source {
+            RequirementSource::Git { git, .. } => Some(git.reference().clone()),
+            _ => None,
+        });
+    let previous_commit = if let Some(reference) = &git_reference {
+        if reference.is_mutable() {
+            debug!(
+                "`{name}` follows the {} `{reference}`",
+                reference.kind_str()
+            );
+        } else {
+            warn_user!(
+                "`{name}` is pinned to {}, so it won't be upgraded to newer commits; reinstall it from a branch to follow updates",
+                describe_reference(reference)
+            );
+        }
+        installed_commit(&environment, name)
+    } else {
+        None
+    };
+
     // Resolve the appropriate settings, preferring: CLI > receipt > user.
     let options = args.clone().combine(
         ResolverInstallerOptions::from(existing_tool_receipt.options().clone())
@@ -290,6 +406,43 @@ async fn upgrade_tool(
     let state = PlatformState::default();
     let workspace_cache = WorkspaceCache::default();
 
+    // In a dry run, resolve the tool's version and report the change, without modifying the
+    // environment.
+    if dry_run.enabled() {
+        let new_interpreter = interpreter.filter(|interpreter| !environment.uses(interpreter));
+        let version = resolve_tool_version(
+            name,
+            spec,
+            new_interpreter.unwrap_or(environment.interpreter()),
+            build_constraints,
+            &settings,
+            network_settings,
+            &state,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+        let installed = installed_tools.version(name, cache).ok();
+
+        let mut outcome = UpgradeOutcome::NoOp;
+        if let (Some(installed), Some(version)) = (installed, version) {
+            if installed != version {
+                writeln!(
+                    printer.stderr(),
+                    "{} {name} v{installed} -> v{version}",
+                    "Would update".green().bold(),
+                )?;
+                outcome = UpgradeOutcome::UpgradeTool;
+            }
+        }
+        if new_interpreter.is_some() {
+            outcome = UpgradeOutcome::UpgradeEnvironment;
+        }
+        return Ok(outcome);
+    }
+
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
     let (environment, outcome) = if let Some(interpreter) =
@@ -313,15 +466,75 @@ async fn upgrade_tool(
 
         let environment = installed_tools.create_environment(name, interpreter.clone(), preview)?;
 
-        let environment = sync_environment(
+        let environment = if preview.is_enabled(PreviewFeatures::TOOL_LAYERS) {
+            layers::sync(
+                environment,
+                &resolution.into(),
+                name,
+                installed_tools,
+                build_constraints,
+                (&settings).into(),
+                network_settings,
+                &state,
+                Box::new(DefaultInstallLogger),
+                installer_metadata,
+                concurrency,
+                cache,
+                printer,
+                preview,
+            )
+            .await?
+        } else {
+            sync_environment(
+                environment,
+                &resolution.into(),
+                Modifications::Exact,
+                build_constraints,
+                (&settings).into(),
+                network_settings,
+                &state,
+                Box::new(DefaultInstallLogger),
+                installer_metadata,
+                concurrency,
+                cache,
+                printer,
+                preview,
+            )
+            .await?
+        };
+
+        (environment, UpgradeOutcome::UpgradeEnvironment)
+    } else if preview.is_enabled(PreviewFeatures::TOOL_LAYERS) || layers::is_layered(&environment) {
+        // Environments with shared layers are re-resolved and linked to the latest layers, rather
+        // than updated in-place.
+        let previous_version = installed_tools.version(name, cache).ok();
+        let previous_links = layers::links(&environment);
+
+        let resolution = resolve_environment(
+            spec.into(),
+            environment.interpreter(),
+            build_constraints.clone(),
+            &settings.resolver,
+            network_settings,
+            &state,
+            Box::new(SummaryResolveLogger),
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+
+        let environment = layers::sync(
             environment,
             &resolution.into(),
-            Modifications::Exact,
+            name,
+            installed_tools,
             build_constraints,
             (&settings).into(),
             network_settings,
             &state,
-            Box::new(DefaultInstallLogger),
+            Box::new(UpgradeInstallLogger::new(name.clone())),
             installer_metadata,
             concurrency,
             cache,
@@ -330,7 +543,17 @@ async fn upgrade_tool(
         )
         .await?;
 
-        (environment, UpgradeOutcome::UpgradeEnvironment)
+        let outcome = if previous_version != installed_tools.version(name, cache).ok()
+            || (git_reference.is_some() && previous_commit != installed_commit(&environment, name))
+        {
+            UpgradeOutcome::UpgradeTool
+        } else if previous_links != layers::links(&environment) {
+            UpgradeOutcome::UpgradeDependencies
+        } else {
+            UpgradeOutcome::NoOp
+        };
+
+        (environment, outcome)
     } else {
         // Otherwise, upgrade the existing environment.
         // TODO(zanieb): Build the environment in the cache directory then copy into the tool
@@ -373,9 +596,28 @@ async fn upgrade_tool(
         outcome,
         UpgradeOutcome::UpgradeEnvironment | UpgradeOutcome::UpgradeTool
     ) {
+        // Report the new commit of tools that follow a Git branch.
+        if let Some(reference) = &git_reference {
+            if let (Some(previous), Some(current)) =
+                (previous_commit, installed_commit(&environment, name))
+            {
+                if previous != current {
+                    writeln!(
+                        printer.stderr(),
+                        "{} {name} to the latest commit on {}: {} -> {}",
+                        "Updated".green().bold(),
+                        describe_reference(reference),
+                        short_commit(&previous),
+                        short_commit(&current),
+                    )?;
+                }
+            }
+        }
+
         // At this point, we updated the existing environment, so we should remove any of its
         // existing executables.
         remove_entrypoints(&existing_tool_receipt);
+        remove_data_files(&existing_tool_receipt)?;
 
         // If we modified the target tool, reinstall the entrypoints.
         finalize_tool_install(
@@ -384,6 +626,7 @@ async fn upgrade_tool(
             installed_tools,
             ToolOptions::from(options),
             true,
+            !existing_tool_receipt.data_files().is_empty(),
             existing_tool_receipt.python().to_owned(),
             existing_tool_receipt.requirements().to_vec(),
             existing_tool_receipt.constraints().to_vec(),
@@ -395,3 +638,176 @@ async fn upgrade_tool(
 
     Ok(outcome)
 }
+
+/// Determine whether a tool was held back from its latest version by the version constraints in
+/// its receipt, i.e., whether dropping the constraints would resolve to a newer version.
+async fn find_held_back(
+    name: &PackageName,
+    interpreter: Option<&Interpreter>,
+    printer: Printer,
+    installed_tools: &InstalledTools,
+    args: &ResolverInstallerOptions,
+    network_settings: &NetworkSettings,
+    cache: &Cache,
+    filesystem: &ResolverInstallerOptions,
+    concurrency: Concurrency,
+    dry_run: DryRun,
+    preview: Preview,
+) -> Result<Option<HeldBack>> {
+    let Some(receipt) = installed_tools.get_tool_receipt(name)? else {
+        return Ok(None);
+    };
+    let Some(environment) = installed_tools.get_environment(name, cache)? else {
+        return Ok(None);
+    };
+
+    // Find the version constraints on the tool itself, if any.
+    let Some(specifiers) = receipt
+        .requirements()
+        .iter()
+        .filter(|requirement| requirement.name == *name)
+        .find_map(|requirement| match &requirement.source {
+            RequirementSource::Registry { specifier, .. } if !specifier.is_empty() => {
+                Some(specifier.clone())
+            }
+            _ => None,
+        })
+    else {
+        return Ok(None);
+    };
+
+    let options = args.clone().combine(
+        ResolverInstallerOptions::from(receipt.options().clone()).combine(filesystem.clone()),
+    );
+    let settings = ResolverInstallerSettings::from(options);
+    let build_constraints =
+        Constraints::from_requirements(receipt.build_constraints().iter().cloned());
+    let interpreter = interpreter.unwrap_or(environment.interpreter());
+    let state = PlatformState::default();
+
+    // Determine the version the tool was upgraded to (or would be, in a dry run).
+    let version = if dry_run.enabled() {
+        let spec = RequirementsSpecification::from_overrides(
+            receipt.requirements().to_vec(),
+            receipt.constraints().to_vec(),
+            receipt.overrides().to_vec(),
+        );
+        resolve_tool_version(
+            name,
+            spec,
+            interpreter,
+            build_constraints.clone(),
+            &settings,
+            network_settings,
+            &state,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?
+    } else {
+        installed_tools.version(name, cache).ok()
+    };
+    let Some(version) = version else {
+        return Ok(None);
+    };
+
+    // Resolve the tool again, without its version constraints.
+    let requirements = receipt
+        .requirements()
+        .iter()
+        .cloned()
+        .map(|mut requirement| {
+            if requirement.name == *name {
+                if let RequirementSource::Registry { specifier, .. } = &mut requirement.source {
+                    *specifier = VersionSpecifiers::empty();
+                }
+            }
+            requirement
+        })
+        .collect();
+    let spec = RequirementsSpecification::from_overrides(
+        requirements,
+        receipt.constraints().to_vec(),
+        receipt.overrides().to_vec(),
+    );
+    let Some(latest) = resolve_tool_version(
+        name,
+        spec,
+        interpreter,
+        build_constraints,
+        &settings,
+        network_settings,
+        &state,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    if latest > version {
+        Ok(Some(HeldBack {
+            name: name.clone(),
+            version,
+            latest,
+            specifiers,
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Resolve the requirements of a tool, returning the version of the tool itself.
+async fn resolve_tool_version(
+    name: &PackageName,
+    spec: RequirementsSpecification,
+    interpreter: &Interpreter,
+    build_constraints: Constraints,
+    settings: &ResolverInstallerSettings,
+    network_settings: &NetworkSettings,
+    state: &PlatformState,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<Option<Version>> {
+    let resolution = resolve_environment(
+        spec.into(),
+        interpreter,
+        build_constraints,
+        &settings.resolver,
+        network_settings,
+        state,
+        Box::new(SummaryResolveLogger),
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+    Ok(Resolution::from(resolution)
+        .distributions()
+        .find(|dist| dist.name() == name)
+        .and_then(|dist| dist.version())
+        .cloned())
+}
+
+/// Describe a [`GitReference`] for display, e.g., ``branch `main` ``.
+fn describe_reference(reference: &GitReference) -> String {
+    match reference {
+        GitReference::DefaultBranch => "the default branch".to_string(),
+        // References that look like a commit are resolved as commits.
+        GitReference::BranchOrTagOrCommit(rev) => format!("commit `{}`", short_commit(rev).cyan()),
+        reference => format!("{} `{}`", reference.kind_str(), reference.cyan()),
+    }
+}
+
+/// Abbreviate a commit hash for display.
+fn short_commit(commit: &str) -> &str {
+    commit.get(..7).unwrap_or(commit)
+}
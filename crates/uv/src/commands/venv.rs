@@ -16,7 +16,7 @@ use uv_configuration::{
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::Requirement;
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
+use uv_distribution_types::{BuildSystemOverrides, DependencyMetadata, Index, IndexLocations};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_normalize::DefaultGroups;
@@ -69,6 +69,7 @@ pub(crate) async fn venv(
     index_locations: &IndexLocations,
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
+    build_system_overrides: BuildSystemOverrides,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     prompt: uv_virtualenv::Prompt,
@@ -124,12 +125,12 @@ pub(crate) async fn venv(
             .unwrap_or(PathBuf::from(".venv")),
     );
 
-    // TODO(zanieb): We don't use [`BaseClientBuilder::retries_from_env`] here because it's a pain
-    // to map into a miette diagnostic. We should just remove miette diagnostics here, we're not
-    // using them elsewhere.
     let client_builder = BaseClientBuilder::default()
+        .retry_settings(&network_settings.retry)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
     let reporter = PythonDownloadReporter::single(printer);
@@ -151,6 +152,7 @@ pub(crate) async fn venv(
         &groups,
         project_dir,
         no_config,
+        preview,
     )
     .await?;
 
@@ -167,6 +169,9 @@ pub(crate) async fn venv(
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors
+                .python_install_require_attestations
+                .unwrap_or_default(),
             preview,
         )
         .await?;
@@ -276,6 +281,7 @@ pub(crate) async fn venv(
             index_locations,
             &flat_index,
             &dependency_metadata,
+            &build_system_overrides,
             state.clone(),
             index_strategy,
             &config_settings,
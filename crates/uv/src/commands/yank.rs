@@ -0,0 +1,93 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use uv_client::{AuthIntegration, BaseClientBuilder};
+use uv_configuration::{KeyringProviderType, TrustedPublishing};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_publish::YankAction;
+
+use crate::commands::ExitStatus;
+use crate::commands::publish::{Prompt, PublishTarget, gather_credentials};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// Yank or un-yank a release on an index.
+pub(crate) async fn yank(
+    package: &PackageName,
+    version: &Version,
+    action: &YankAction,
+    target: PublishTarget,
+    trusted_publishing: TrustedPublishing,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    username: Option<String>,
+    password: Option<String>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if network_settings.connectivity.is_offline() {
+        bail!("Unable to {action} releases in offline mode");
+    }
+
+    // See `publish` for why the client is configured this way.
+    let client = BaseClientBuilder::new()
+        .retries(0)
+        .keyring(keyring_provider)
+        .native_tls(network_settings.native_tls)
+        .proxy_config(network_settings.proxy.clone())
+        .strict_index_tracking(network_settings.strict_index_tracking)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .auth_integration(AuthIntegration::OnlyAuthenticated)
+        .default_timeout(Duration::from_secs(60))
+        .build();
+    let oidc_client = BaseClientBuilder::new()
+        .auth_integration(AuthIntegration::NoAuthMiddleware)
+        .wrap_existing(&client);
+
+    // Credentials configured for the index take precedence over the global credentials.
+    let (username, password) = if let Some(credentials) = &target.credentials {
+        (
+            credentials.username().map(ToString::to_string),
+            credentials.password().map(ToString::to_string),
+        )
+    } else {
+        (username, password)
+    };
+
+    let (publish_url, credentials) = gather_credentials(
+        target.publish_url,
+        username,
+        password,
+        trusted_publishing,
+        keyring_provider,
+        &oidc_client,
+        target.check_url.as_ref(),
+        Prompt::Enabled,
+        printer,
+    )
+    .await?;
+
+    uv_publish::yank(
+        package,
+        version,
+        action,
+        &publish_url,
+        &client,
+        &credentials,
+    )
+    .await?;
+
+    let verb = match action {
+        YankAction::Yank { .. } => "Yanked",
+        YankAction::Unyank => "Un-yanked",
+    };
+    writeln!(
+        printer.stderr(),
+        "{} {package}=={version} on {publish_url}",
+        verb.bold().green()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
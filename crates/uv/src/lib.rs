@@ -19,7 +19,7 @@ use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
 use tracing::{debug, instrument};
 
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, EnvironmentLimits, Refresh};
 use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
@@ -28,8 +28,9 @@ use uv_cli::{
     ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
     ToolNamespace, TopLevelArgs, compat::CompatArgs,
 };
-use uv_configuration::min_stack_size;
+use uv_configuration::{DryRun, min_stack_size};
 use uv_fs::{CWD, Simplified};
+use uv_normalize::PackageName;
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
 use uv_pep508::VersionOrUrl;
@@ -46,9 +47,9 @@ use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipDownloadSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PublishSettings, YankSettings,
 };
 
 pub(crate) mod child;
@@ -59,6 +60,27 @@ pub(crate) mod settings;
 #[cfg(windows)]
 mod windows_exception;
 
+/// Select a proxy for each request via the proxy auto-config file in `AUTO_PROXY`, which is
+/// either a URL, a path to a local file, or `wpad` to discover the file.
+fn proxy_auto_config(auto_proxy: &str) -> Result<Option<uv_client::ProxyConfig>> {
+    let source = if auto_proxy.eq_ignore_ascii_case("wpad") {
+        uv_client::ProxyAutoConfigSource::Discover
+    } else if Path::new(auto_proxy).is_file() {
+        std::path::absolute(auto_proxy)
+            .ok()
+            .and_then(|path| uv_redacted::DisplaySafeUrl::from_file_path(path).ok())
+            .map(uv_client::ProxyAutoConfigSource::Url)
+            .with_context(|| format!("Invalid proxy auto-config path: `{auto_proxy}`"))?
+    } else {
+        uv_redacted::DisplaySafeUrl::parse(auto_proxy)
+            .map(uv_client::ProxyAutoConfigSource::Url)
+            .with_context(|| format!("Invalid proxy auto-config URL: `{auto_proxy}`"))?
+    };
+    Ok(Some(uv_client::ProxyConfig::AutoConfig(
+        uv_client::ProxyAutoConfig::new(source),
+    )))
+}
+
 #[instrument(skip_all)]
 async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Enable flag to pick up warnings generated by workspace loading.
@@ -165,17 +187,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             module,
             script,
             gui_script,
+            gui,
+            hash,
             ..
         }) = &mut **command
         {
             let settings = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+            let cache_settings =
+                CacheSettings::resolve((*cli.top_level.cache_args).clone(), filesystem.as_ref());
+            let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
             Some(
                 RunCommand::from_args(
                     command,
                     settings.network_settings,
+                    hash.as_ref(),
+                    &cache,
                     *module,
                     *script,
                     *gui_script,
+                    *gui,
                 )
                 .await?,
             )
@@ -307,7 +337,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     let environment = EnvironmentOptions::new()?;
 
     // Resolve the global settings.
-    let globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+    let mut globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
 
     // Resolve the cache settings.
     let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
@@ -365,6 +395,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         },
         durations_layer,
         globals.color,
+        globals.network_settings.debug_auth,
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
@@ -409,6 +440,44 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     debug!("uv {}", uv_cli::version::uv_self_version());
 
+    // Throttle all downloads to the configured aggregate bandwidth, if any.
+    if let Some(limit_rate) = globals.network_settings.limit_rate {
+        uv_client::set_download_rate_limit(limit_rate);
+    }
+
+    // Retry transient failures according to the configured policy, if any; the
+    // `UV_HTTP_RETRIES` and `UV_HTTP_RETRY_*` environment variables take precedence.
+    globals.network_settings.retry = {
+        let options = filesystem.as_ref().map(|filesystem| &filesystem.globals);
+        uv_client::RetrySettings::resolve(
+            options.and_then(|options| options.http_retries),
+            options.and_then(|options| options.http_retry_base_delay),
+            options.and_then(|options| options.http_retry_max_delay),
+            options.and_then(|options| options.http_retry_jitter),
+            options.and_then(|options| options.http_retry_status_codes.as_deref()),
+        )?
+    };
+
+    // Record all HTTP requests, if requested.
+    if let Some(path) = globals.network_settings.trace_http.as_ref() {
+        uv_client::set_http_trace(path).with_context(|| {
+            format!("Failed to create HTTP trace at: `{}`", path.user_display())
+        })?;
+    }
+
+    // Route all requests through the configured proxy, if any; otherwise, select a proxy for each
+    // request via the proxy auto-config file, if any.
+    if let Some(proxy) = globals.network_settings.proxy.as_ref() {
+        proxy
+            .validate()
+            .with_context(|| format!("Invalid proxy URL: `{proxy}`"))?;
+    } else if let Some(auto_proxy) = std::env::var(EnvVars::AUTO_PROXY)
+        .ok()
+        .filter(|auto_proxy| !auto_proxy.is_empty())
+    {
+        globals.network_settings.proxy = proxy_auto_config(&auto_proxy)?;
+    }
+
     // Write out any resolved settings.
     macro_rules! show_settings {
         ($arg:expr) => {
@@ -427,7 +496,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_compression(cache_settings.cache_compression)
+        .with_environment_limits({
+            let globals = filesystem.as_ref().map(|filesystem| &filesystem.globals);
+            EnvironmentLimits::from_settings(
+                globals.and_then(|globals| globals.cache_environment_max_age),
+                globals.and_then(|globals| globals.cache_environment_max_size),
+                globals.and_then(|globals| globals.cache_environment_max_count),
+            )
+        });
 
     match *cli.command {
         Commands::Help(args) => commands::help(
@@ -510,6 +588,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_strategy,
                 args.settings.torch_backend,
                 args.settings.dependency_metadata,
+                args.settings.build_system_overrides,
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 args.settings.config_setting,
@@ -585,6 +664,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_strategy,
                 args.settings.torch_backend,
                 args.settings.dependency_metadata,
+                args.settings.build_system_overrides,
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 args.settings.allow_empty_requirements,
@@ -724,6 +804,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_strategy,
                 args.settings.torch_backend,
                 args.settings.dependency_metadata,
+                args.settings.build_system_overrides,
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 args.settings.reinstall,
@@ -751,6 +832,102 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.report.as_deref(),
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: download_variant @ (PipCommand::Download(_) | PipCommand::Wheel(_)),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = match download_variant {
+                PipCommand::Download(args) => {
+                    args.compat_args.validate()?;
+                    PipDownloadSettings::resolve(args, filesystem)
+                }
+                PipCommand::Wheel(args) => {
+                    args.compat_args.validate()?;
+                    PipDownloadSettings::resolve_wheel(args, filesystem)
+                }
+                // OK guarded by the outer match statement
+                _ => unreachable!(),
+            };
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_constraints = args
+                .build_constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.constraints_from_workspace,
+                args.overrides_from_workspace,
+                args.build_constraints_from_workspace,
+                args.dest.as_deref().unwrap_or(Path::new(".")),
+                args.kind,
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.fork_strategy,
+                args.settings.dependency_mode,
+                args.settings.upgrade,
+                args.settings.hash_checking,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.torch_backend,
+                args.settings.dependency_metadata,
+                args.settings.build_system_overrides,
+                args.settings.keyring_provider,
+                &globals.network_settings,
+                args.settings.config_setting,
+                args.settings.config_settings_package,
+                args.settings.no_build_isolation,
+                args.settings.no_build_isolation_package,
+                args.settings.build_options,
+                args.settings.python_version,
+                args.settings.python_platform,
+                args.macos_deployment_target,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.link_mode,
+                args.settings.python,
+                args.settings.system,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
                 printer,
                 globals.preview,
             )
@@ -786,6 +963,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 cache,
                 args.settings.keyring_provider,
                 &globals.network_settings,
+                args.cascade,
                 args.dry_run,
                 printer,
                 globals.preview,
@@ -911,12 +1089,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let cache = cache.init()?;
 
             commands::pip_check(
+                args.output_format,
+                args.suggest,
+                args.settings.prerelease,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.keyring_provider,
+                &globals.network_settings,
+                args.settings.dependency_metadata,
+                args.settings.build_system_overrides,
+                args.settings.config_setting,
+                args.settings.config_settings_package,
+                args.settings.build_options,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.link_mode,
+                globals.concurrency,
                 args.settings.python.as_deref(),
                 args.settings.system,
                 &cache,
                 printer,
                 globals.preview,
             )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
@@ -929,7 +1124,42 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_prune(args.ci, args.ephemeral, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Warm(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheWarmSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::cache_warm(
+                &project_dir,
+                args.extras,
+                args.groups,
+                args.all_packages,
+                args.package,
+                args.python,
+                args.python_platform,
+                args.install_mirrors,
+                globals.python_preference,
+                globals.python_downloads,
+                args.settings,
+                globals.network_settings,
+                globals.concurrency,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -964,11 +1194,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.sdist,
                 args.wheel,
                 args.list,
+                args.output_format,
                 args.build_logs,
+                args.log_dir,
                 args.force_pep517,
+                args.reproducible,
                 build_constraints,
                 args.hash_checking,
                 args.python,
+                args.python_platform,
                 args.install_mirrors,
                 &args.settings,
                 &globals.network_settings,
@@ -1033,6 +1267,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.dependency_metadata,
+                args.settings.build_system_overrides,
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 uv_virtualenv::Prompt::from_args(prompt),
@@ -1102,8 +1337,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }
         Commands::GenerateShellCompletion(args) => {
             args.shell.generate(&mut Cli::command(), &mut stdout());
+            if let Some(script) = commands::project_completion(args.shell) {
+                std::io::Write::write_all(&mut stdout(), script.as_bytes())?;
+            }
             Ok(ExitStatus::Success)
         }
+        Commands::Complete(args) => commands::complete(&project_dir, args.target, printer).await,
         Commands::Tool(ToolNamespace {
             command: run_variant @ (ToolCommand::Uvx(_) | ToolCommand::Run(_)),
         }) => {
@@ -1199,14 +1438,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.network_settings,
                 invocation_source,
                 args.isolated,
+                args.refresh_interval,
                 globals.python_preference,
                 globals.python_downloads,
+                cli.top_level.no_config,
                 globals.installer_metadata,
                 globals.concurrency,
                 cache,
                 printer,
                 args.env_file,
                 args.no_env_file,
+                args.env,
+                args.unset,
                 globals.preview,
             ))
             .await
@@ -1268,11 +1511,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python,
                 args.install_mirrors,
                 args.force,
+                args.data_files,
                 args.options,
                 args.settings,
                 globals.network_settings,
                 globals.python_preference,
                 globals.python_downloads,
+                cli.top_level.no_config,
                 globals.installer_metadata,
                 globals.concurrency,
                 cache,
@@ -1281,6 +1526,84 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             ))
             .await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolSyncSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::tool_sync(
+                args.manifest,
+                args.inexact,
+                args.python,
+                args.install_mirrors,
+                args.options,
+                args.settings,
+                globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                cli.top_level.no_config,
+                globals.installer_metadata,
+                globals.concurrency,
+                cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Import(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolSyncSettings::resolve_import(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::tool_sync(
+                args.manifest,
+                args.inexact,
+                args.python,
+                args.install_mirrors,
+                args.options,
+                args.settings,
+                globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                cli.top_level.no_config,
+                globals.installer_metadata,
+                globals.concurrency,
+                cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Export(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::tool_export(args.output_file.as_deref(), &cache, printer).await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::List(args),
         }) => {
@@ -1296,6 +1619,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.show_version_specifiers,
                 args.show_with,
                 args.show_extras,
+                args.outdated,
+                args.include_dependencies,
+                args.output_format,
+                args.filesystem,
+                &globals.network_settings,
+                globals.concurrency,
                 &cache,
                 printer,
             )
@@ -1313,6 +1642,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             Box::pin(commands::tool_upgrade(
                 args.names,
+                args.dry_run,
                 args.python,
                 args.install_mirrors,
                 args.args,
@@ -1395,12 +1725,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.targets,
                 args.reinstall,
                 upgrade,
+                DryRun::Disabled,
                 args.bin,
                 args.registry,
                 args.force,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
                 args.python_downloads_json_url,
+                args.require_attestations,
                 globals.network_settings,
                 args.default,
                 globals.python_downloads,
@@ -1425,12 +1757,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.targets,
                 reinstall,
                 upgrade,
+                args.dry_run,
                 args.bin,
                 args.registry,
                 args.force,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
                 args.python_downloads_json_url,
+                args.require_attestations,
                 globals.network_settings,
                 args.default,
                 globals.python_downloads,
@@ -1451,6 +1785,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.install_dir,
                 args.targets,
                 args.all,
+                args.force,
                 printer,
                 globals.preview,
             )
@@ -1469,6 +1804,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 commands::python_find_script(
                     Pep723ItemRef::Script(&script),
                     args.show_version,
+                    args.output_format,
                     &globals.network_settings,
                     globals.python_preference,
                     globals.python_downloads,
@@ -1483,6 +1819,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     &project_dir,
                     args.request,
                     args.show_version,
+                    args.output_format,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
@@ -1559,59 +1896,122 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 keyring_provider,
                 check_url,
                 index,
+                attest,
+                sign,
+                sign_key,
+                sigstore,
+                check,
+                workspace,
+                concurrent_uploads,
                 index_locations,
+                settings,
+                install_mirrors,
             } = PublishSettings::resolve(args, filesystem);
 
-            let (publish_url, check_url) = if let Some(index_name) = index {
-                debug!("Publishing with index {index_name}");
-                let index = index_locations
-                    .simple_indexes()
-                    .find(|index| {
-                        index
-                            .name
-                            .as_ref()
-                            .is_some_and(|name| name.as_ref() == index_name)
-                    })
-                    .with_context(|| {
-                        let mut index_names: Vec<String> = index_locations
-                            .simple_indexes()
-                            .filter_map(|index| index.name.as_ref())
-                            .map(ToString::to_string)
-                            .collect();
-                        index_names.sort();
-                        if index_names.is_empty() {
-                            format!("No indexes were found, can't use index: `{index_name}`")
-                        } else {
-                            let index_names = index_names.join("`, `");
-                            format!(
-                                "Index not found: `{index_name}`. Found indexes: `{index_names}`"
-                            )
-                        }
-                    })?;
-                let publish_url = index
-                    .publish_url
-                    .clone()
-                    .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
-                let check_url = index.url.clone();
-                (publish_url, Some(check_url))
+            let targets = if index.is_empty() {
+                vec![commands::PublishTarget {
+                    publish_url,
+                    check_url,
+                    credentials: None,
+                    retries: None,
+                }]
             } else {
-                (publish_url, check_url)
+                index
+                    .iter()
+                    .map(|index_name| {
+                        commands::PublishTarget::from_index(index_name, &index_locations)
+                    })
+                    .collect::<Result<Vec<_>>>()?
             };
 
+            // The global credentials would be sent to every index.
+            if targets.len() > 1 && (username.is_some() || password.is_some()) {
+                bail!(
+                    "`--username`, `--password`, and `--token` can't be used when publishing to multiple indexes, use `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD` to provide credentials for each index"
+                );
+            }
+
+            if workspace {
+                // Initialize the cache.
+                let cache = cache.init()?;
+
+                return commands::publish_workspace(
+                    &project_dir,
+                    targets,
+                    trusted_publishing,
+                    keyring_provider,
+                    username,
+                    password,
+                    attest,
+                    sign,
+                    sign_key,
+                    &sigstore,
+                    concurrent_uploads,
+                    install_mirrors,
+                    &settings,
+                    &globals.network_settings,
+                    cli.top_level.no_config,
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.concurrency,
+                    &cache,
+                    printer,
+                    globals.preview,
+                )
+                .await;
+            }
+
             commands::publish(
                 files,
-                publish_url,
+                targets,
                 trusted_publishing,
                 keyring_provider,
                 &globals.network_settings,
                 username,
                 password,
-                check_url,
+                attest,
+                sign,
+                sign_key,
+                &sigstore,
+                check,
+                concurrent_uploads,
                 &cache,
                 printer,
             )
             .await
         }
+        Commands::Yank(args) => {
+            show_settings!(args);
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let YankSettings {
+                package,
+                version,
+                action,
+                username,
+                password,
+                index,
+                trusted_publishing,
+                keyring_provider,
+                index_locations,
+            } = YankSettings::resolve(args, filesystem);
+
+            let target = commands::PublishTarget::from_index(&index, &index_locations)?;
+
+            commands::yank(
+                &package,
+                &version,
+                &action,
+                target,
+                trusted_publishing,
+                keyring_provider,
+                &globals.network_settings,
+                username,
+                password,
+                printer,
+            )
+            .await
+        }
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1711,11 +2111,90 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::Migrate(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::MigrateSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::migrate(
+                project_dir,
+                args.path,
+                args.from,
+                args.no_lock,
+                args.dry_run,
+                args.run_setup_py,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Run(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let args = settings::RunSettings::resolve(args, filesystem);
+            let mut args = settings::RunSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Supervise a `uv run` process that's restarted on changes, unless we're that process.
+            if args.watch && std::env::var_os(EnvVars::UV_INTERNAL__WATCHED).is_none() {
+                if matches!(
+                    command,
+                    Some(RunCommand::PythonStdin(..) | RunCommand::PythonGuiStdin(..))
+                ) {
+                    bail!("`--watch` can't be used when reading a script from stdin");
+                }
+                return commands::run_watch(project_dir, &args.watch_glob, printer).await;
+            }
+
+            // Run `uv run` inside a container, unless we're already inside it.
+            if let Some(image) = args.container.as_deref() {
+                if std::env::var_os(EnvVars::UV_INTERNAL__CONTAINERIZED).is_none() {
+                    if matches!(
+                        command,
+                        Some(RunCommand::PythonStdin(..) | RunCommand::PythonGuiStdin(..))
+                    ) {
+                        bail!("`--container` can't be used when reading a script from stdin");
+                    }
+                    let cache = cache.init()?;
+                    return commands::run_container(image, project_dir, &cache, printer).await;
+                }
+            }
+
+            // Run the command in every workspace member, unless we're running it for a single
+            // member.
+            if args.parallel {
+                if let Some(package) = std::env::var_os(EnvVars::UV_INTERNAL__RUN_PACKAGE) {
+                    args.all_packages = false;
+                    args.package = Some(PackageName::from_str(&package.to_string_lossy())?);
+                } else {
+                    if matches!(
+                        command,
+                        Some(RunCommand::PythonStdin(..) | RunCommand::PythonGuiStdin(..))
+                    ) {
+                        bail!("`--parallel` can't be used when reading a script from stdin");
+                    }
+                    if script.is_some() {
+                        bail!(
+                            "`--parallel` can't be used with Python scripts with inline metadata"
+                        );
+                    }
+                    return commands::run_parallel(project_dir, globals.concurrency, printer).await;
+                }
+            }
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
                 args.refresh
@@ -1769,7 +2248,11 @@ async fn run_project(
                 &cache,
                 printer,
                 args.env_file,
+                args.default_env_file,
                 args.no_env_file,
+                args.env,
+                args.unset,
+                args.lock_scripts,
                 globals.preview,
                 args.max_recursion_depth,
             ))
@@ -1822,6 +2305,11 @@ async fn run_project(
                 printer,
                 globals.preview,
                 args.output_format,
+                args.lock_scripts,
+                args.zipapp,
+                args.zipapp_entry_point,
+                args.python_shebang,
+                args.allow_extension_modules,
             ))
             .await
         }
@@ -2014,6 +2502,8 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.prune_unused,
+                args.dry_run,
                 args.packages,
                 args.dependency_type,
                 args.package,
@@ -2053,6 +2543,7 @@ async fn run_project(
                 project_dir,
                 args.package,
                 explicit_project,
+                args.workspace,
                 args.dry_run,
                 args.locked,
                 args.frozen,
@@ -2094,6 +2585,8 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.universal,
+                args.workspace,
+                args.format,
                 args.depth,
                 args.prune,
                 args.package,
@@ -2149,6 +2642,8 @@ async fn run_project(
                 args.include_annotations,
                 args.include_header,
                 script,
+                args.python_version,
+                args.python_platform,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -2269,6 +2764,14 @@ where
         // turned out to be unnecessary. Waiting for those to complete can cause
         // the CLI to hang before exiting.
         runtime.shutdown_background();
+        // Write out the HTTP trace, if it's buffered until exit.
+        if let Err(err) = uv_client::flush_http_trace() {
+            warn_user!("Failed to write HTTP trace: {err}");
+        }
+        // Report any time spent waiting on rate-limited indexes.
+        if let Some(summary) = uv_client::retry_after_summary() {
+            warn_user!("{summary}");
+        }
         result
     };
     let result = std::thread::Builder::new()
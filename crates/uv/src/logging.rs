@@ -116,6 +116,7 @@ pub(crate) fn setup_logging(
     level: Level,
     durations_layer: Option<impl Layer<Registry> + Send + Sync>,
     color: ColorChoice,
+    debug_auth: bool,
 ) -> anyhow::Result<()> {
     // We use directives here to ensure `RUST_LOG` can override them
     let default_directive = match level {
@@ -145,11 +146,18 @@ pub(crate) fn setup_logging(
                 .with_target("", tracing::level_filters::LevelFilter::INFO),
         )
     });
-    let filter = EnvFilter::builder()
+    let mut filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
         .context("Invalid RUST_LOG directives")?;
 
+    // Report the credentials used for each request, if requested.
+    if debug_auth {
+        filter = filter.add_directive(
+            Directive::from_str(&format!("{}=info", uv_auth::DEBUG_AUTH_TARGET)).unwrap(),
+        );
+    }
+
     // Determine our final color settings and create an anstream wrapper based on it.
     //
     // The tracing `with_ansi` function on affects color tracing adds *on top of* the
@@ -4,33 +4,39 @@ use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
 
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{ArchiveCompression, CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
+    AddArgs, CheckFormat, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs,
+    Maybe, PipCheckArgs, PipCompileArgs, PipDownloadArgs, PipFreezeArgs, PipInstallArgs,
+    PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PipWheelArgs,
+    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
+    PythonUninstallArgs, PythonUpgradeArgs, PythonUpgradePolicy, RemoveArgs, RunArgs, SyncArgs,
+    SyncFormat, ToolDirArgs, ToolExportArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
     ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBump, VersionFormat,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
-    ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, BuildFormat, CacheWarmArgs, ExportArgs, MigrateArgs, MigrateFrom,
+    PublishArgs, PythonDirArgs, ResolverInstallerArgs, ToolImportArgs, ToolListFormat,
+    ToolSyncArgs, ToolUpgradeArgs, TreeFormat, YankArgs,
     options::{flag, resolver_installer_options, resolver_options},
 };
-use uv_client::Connectivity;
+use uv_client::{Connectivity, ProxyConfig, RetrySettings};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
     ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
     KeyringProviderType, NoBinary, NoBuild, PackageConfigSettings, Preview, ProjectBuildBackend,
-    Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing,
-    Upgrade, VersionControlSystem,
+    RateLimit, RefreshInterval, Reinstall, RequiredVersion, SourceStrategy, TargetTriple,
+    TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+};
+use uv_distribution_types::{
+    BuildSystemOverrides, DependencyMetadata, Index, IndexLocations, IndexUrl, Requirement,
 };
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl, Requirement};
 use uv_install_wheel::LinkMode;
 use uv_normalize::{PackageName, PipGroupName};
+use uv_pep440::Version;
 use uv_pep508::{ExtraName, MarkerTree, RequirementOrigin};
+use uv_publish::YankAction;
 use uv_pypi_types::SupportedEnvironments;
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_redacted::DisplaySafeUrl;
@@ -41,6 +47,7 @@ use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
     PythonInstallMirrors, ResolverInstallerOptions, ResolverOptions,
 };
+use uv_sigstore::SigstoreConfig;
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
 use uv_warnings::warn_user_once;
@@ -48,7 +55,7 @@ use uv_workspace::pyproject::DependencyType;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::commands::ToolRunCommand;
-use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
+use crate::commands::{DownloadKind, InitKind, InitProjectKind, pip::operations::Modifications};
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
@@ -163,6 +170,12 @@ pub(crate) struct NetworkSettings {
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) limit_rate: Option<RateLimit>,
+    pub(crate) proxy: Option<ProxyConfig>,
+    pub(crate) retry: RetrySettings,
+    pub(crate) trace_http: Option<PathBuf>,
+    pub(crate) debug_auth: bool,
+    pub(crate) strict_index_tracking: bool,
 }
 
 impl NetworkSettings {
@@ -195,10 +208,31 @@ impl NetworkSettings {
                     .flatten(),
             )
             .collect();
+        let limit_rate = args
+            .limit_rate
+            .combine(workspace.and_then(|workspace| workspace.globals.limit_rate));
+        let proxy = workspace
+            .and_then(|workspace| workspace.globals.proxy.clone())
+            .map(ProxyConfig::Url);
+        let strict_index_tracking = flag(
+            args.strict_index_tracking,
+            args.no_strict_index_tracking,
+            "strict-index-tracking",
+        )
+        .combine(workspace.and_then(|workspace| workspace.globals.strict_index_tracking))
+        .unwrap_or(false);
         Self {
             connectivity,
             native_tls,
             allow_insecure_host,
+            limit_rate,
+            proxy,
+            // Resolved from the `http-retries` and `http-retry-*` settings once the environment is
+            // validated.
+            retry: RetrySettings::default(),
+            trace_http: args.trace_http.clone(),
+            debug_auth: args.debug_auth,
+            strict_index_tracking,
         }
     }
 }
@@ -208,6 +242,7 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) cache_compression: ArchiveCompression,
 }
 
 impl CacheSettings {
@@ -221,6 +256,10 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_compression: args
+                .cache_compression
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_compression))
+                .unwrap_or_default(),
         }
     }
 }
@@ -313,6 +352,55 @@ impl InitSettings {
     }
 }
 
+/// The resolved settings to use for a `migrate` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct MigrateSettings {
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) from: Option<MigrateFrom>,
+    pub(crate) no_lock: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) run_setup_py: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl MigrateSettings {
+    /// Resolve the [`MigrateSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: MigrateArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let MigrateArgs {
+            path,
+            from,
+            no_lock,
+            dry_run,
+            run_setup_py,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            from,
+            no_lock,
+            dry_run,
+            run_setup_py,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors,
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `run` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct RunSettings {
@@ -328,6 +416,7 @@ pub(crate) struct RunSettings {
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
+    pub(crate) parallel: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
     pub(crate) active: Option<bool>,
@@ -337,7 +426,14 @@ pub(crate) struct RunSettings {
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
+    pub(crate) default_env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) env: Vec<(String, String)>,
+    pub(crate) unset: Vec<String>,
+    pub(crate) lock_scripts: bool,
+    pub(crate) watch: bool,
+    pub(crate) watch_glob: Vec<String>,
+    pub(crate) container: Option<String>,
     pub(crate) max_recursion_depth: u32,
 }
 
@@ -370,6 +466,8 @@ impl RunSettings {
             exact,
             script: _,
             gui_script: _,
+            gui: _,
+            hash: _,
             command: _,
             with,
             with_editable,
@@ -384,12 +482,18 @@ impl RunSettings {
             build,
             refresh,
             all_packages,
+            parallel,
             package,
             no_project,
             python,
             show_resolution,
             env_file,
             no_env_file,
+            env,
+            unset,
+            watch,
+            watch_glob,
+            container,
             max_recursion_depth,
         } = args;
 
@@ -397,6 +501,14 @@ impl RunSettings {
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
+        let default_env_file = filesystem
+            .as_ref()
+            .and_then(|fs| fs.run.env_file.clone())
+            .unwrap_or_default();
+        let lock_scripts = filesystem
+            .as_ref()
+            .and_then(|fs| fs.run.lock_scripts)
+            .unwrap_or_default();
 
         Self {
             locked,
@@ -441,6 +553,7 @@ impl RunSettings {
             isolated,
             show_resolution,
             all_packages,
+            parallel,
             package,
             no_project,
             no_sync,
@@ -452,7 +565,14 @@ impl RunSettings {
                 filesystem,
             ),
             env_file,
+            default_env_file,
             no_env_file,
+            env,
+            unset,
+            lock_scripts,
+            watch,
+            watch_glob,
+            container,
             install_mirrors,
             max_recursion_depth: max_recursion_depth.unwrap_or(Self::DEFAULT_MAX_RECURSION_DEPTH),
         }
@@ -475,10 +595,13 @@ pub(crate) struct ToolRunSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
+    pub(crate) refresh_interval: Option<RefreshInterval>,
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) env: Vec<(String, String)>,
+    pub(crate) unset: Vec<String>,
 }
 
 impl ToolRunSettings {
@@ -501,6 +624,8 @@ impl ToolRunSettings {
             isolated,
             env_file,
             no_env_file,
+            env,
+            unset,
             show_resolution,
             installer,
             build,
@@ -543,6 +668,8 @@ impl ToolRunSettings {
                 .unwrap_or_default(),
         );
 
+        let refresh_interval = filesystem.as_ref().and_then(|fs| fs.run.tool_run_refresh);
+
         let install_mirrors = filesystem
             .map(FilesystemOptions::into_options)
             .map(|options| options.install_mirrors)
@@ -581,11 +708,14 @@ impl ToolRunSettings {
             show_resolution,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
+            refresh_interval,
             settings,
             options,
             install_mirrors,
             env_file,
             no_env_file,
+            env,
+            unset,
         }
     }
 }
@@ -607,6 +737,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
     pub(crate) editable: bool,
+    pub(crate) data_files: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
 
@@ -626,6 +757,7 @@ impl ToolInstallSettings {
             build_constraints,
             installer,
             force,
+            data_files,
             build,
             refresh,
             python,
@@ -676,18 +808,115 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             force,
             editable,
+            data_files,
+            refresh: Refresh::from(refresh),
+            options,
+            settings,
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `tool sync` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolSyncSettings {
+    pub(crate) manifest: Option<PathBuf>,
+    pub(crate) inexact: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) options: ResolverInstallerOptions,
+    pub(crate) settings: ResolverInstallerSettings,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl ToolSyncSettings {
+    /// Resolve the [`ToolSyncSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolSyncArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolSyncArgs {
+            manifest,
+            inexact,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let options = resolver_installer_options(installer, build).combine(
+            filesystem
+                .clone()
+                .map(FilesystemOptions::into_options)
+                .map(|options| options.top_level)
+                .unwrap_or_default(),
+        );
+
+        let install_mirrors = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.install_mirrors)
+            .unwrap_or_default();
+
+        let settings = ResolverInstallerSettings::from(options.clone());
+
+        Self {
+            manifest,
+            inexact,
+            python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             options,
             settings,
             install_mirrors,
         }
     }
+
+    /// Resolve the [`ToolSyncSettings`] for a `tool import` invocation, which installs the tools
+    /// in the given manifest without uninstalling any others.
+    pub(crate) fn resolve_import(
+        args: ToolImportArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let ToolImportArgs {
+            manifest,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self::resolve(
+            ToolSyncArgs {
+                manifest: Some(manifest),
+                inexact: true,
+                installer,
+                build,
+                refresh,
+                python,
+            },
+            filesystem,
+        )
+    }
+}
+
+/// The resolved settings to use for a `tool export` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolExportSettings {
+    pub(crate) output_file: Option<PathBuf>,
+}
+
+impl ToolExportSettings {
+    /// Resolve the [`ToolExportSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolExportArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolExportArgs { output_file } = args;
+
+        Self { output_file }
+    }
 }
 
 /// The resolved settings to use for a `tool upgrade` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct ToolUpgradeSettings {
     pub(crate) names: Vec<String>,
+    pub(crate) dry_run: DryRun,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) args: ResolverInstallerOptions,
@@ -704,6 +933,7 @@ impl ToolUpgradeSettings {
             upgrade_package,
             index_args,
             all,
+            dry_run,
             reinstall,
             no_reinstall,
             reinstall_package,
@@ -772,6 +1002,7 @@ impl ToolUpgradeSettings {
 
         Self {
             names: if all { vec![] } else { name },
+            dry_run: DryRun::from_args(dry_run),
             python: python.and_then(Maybe::into_option),
             args,
             filesystem: top_level,
@@ -787,26 +1018,43 @@ pub(crate) struct ToolListSettings {
     pub(crate) show_version_specifiers: bool,
     pub(crate) show_with: bool,
     pub(crate) show_extras: bool,
+    pub(crate) outdated: bool,
+    pub(crate) include_dependencies: bool,
+    pub(crate) output_format: ToolListFormat,
+    pub(crate) filesystem: ResolverInstallerOptions,
 }
 
 impl ToolListSettings {
     /// Resolve the [`ToolListSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: ToolListArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: ToolListArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let ToolListArgs {
             show_paths,
             show_version_specifiers,
             show_with,
             show_extras,
+            outdated,
+            no_outdated,
+            include_dependencies,
+            output_format,
             python_preference: _,
             no_python_downloads: _,
         } = args;
 
+        let filesystem = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
         Self {
             show_paths,
             show_version_specifiers,
             show_with,
             show_extras,
+            outdated: flag(outdated, no_outdated, "outdated").unwrap_or(false),
+            include_dependencies,
+            output_format,
+            filesystem,
         }
     }
 }
@@ -942,6 +1190,7 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) require_attestations: bool,
     pub(crate) default: bool,
 }
 
@@ -954,18 +1203,22 @@ impl PythonInstallSettings {
         environment: EnvironmentOptions,
     ) -> Self {
         let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
-            Some(options) => (
-                options.install_mirrors.python_install_mirror,
-                options.install_mirrors.pypy_install_mirror,
-                options.install_mirrors.python_downloads_json_url,
-            ),
-            None => (None, None, None),
-        };
+        let (python_mirror, pypy_mirror, python_downloads_json_url, require_attestations) =
+            match options {
+                Some(options) => (
+                    options.install_mirrors.python_install_mirror,
+                    options.install_mirrors.pypy_install_mirror,
+                    options.install_mirrors.python_downloads_json_url,
+                    options.install_mirrors.python_install_require_attestations,
+                ),
+                None => (None, None, None, None),
+            };
         let python_mirror = args.mirror.or(python_mirror);
         let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
         let python_downloads_json_url =
             args.python_downloads_json_url.or(python_downloads_json_url);
+        let require_attestations =
+            args.require_attestations || require_attestations.unwrap_or_default();
 
         let PythonInstallArgs {
             install_dir,
@@ -979,6 +1232,7 @@ impl PythonInstallSettings {
             mirror: _,
             pypy_mirror: _,
             python_downloads_json_url: _,
+            require_attestations: _,
             default,
         } = args;
 
@@ -993,6 +1247,7 @@ impl PythonInstallSettings {
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
             python_downloads_json_url,
+            require_attestations,
             default,
         }
     }
@@ -1009,8 +1264,10 @@ pub(crate) struct PythonUpgradeSettings {
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) require_attestations: bool,
     pub(crate) default: bool,
     pub(crate) bin: Option<bool>,
+    pub(crate) dry_run: DryRun,
 }
 
 impl PythonUpgradeSettings {
@@ -1018,14 +1275,16 @@ impl PythonUpgradeSettings {
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: PythonUpgradeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
-            Some(options) => (
-                options.install_mirrors.python_install_mirror,
-                options.install_mirrors.pypy_install_mirror,
-                options.install_mirrors.python_downloads_json_url,
-            ),
-            None => (None, None, None),
-        };
+        let (python_mirror, pypy_mirror, python_downloads_json_url, require_attestations) =
+            match options {
+                Some(options) => (
+                    options.install_mirrors.python_install_mirror,
+                    options.install_mirrors.pypy_install_mirror,
+                    options.install_mirrors.python_downloads_json_url,
+                    options.install_mirrors.python_install_require_attestations,
+                ),
+                None => (None, None, None, None),
+            };
         let python_mirror = args.mirror.or(python_mirror);
         let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
         let python_downloads_json_url =
@@ -1041,6 +1300,9 @@ impl PythonUpgradeSettings {
             mirror: _,
             pypy_mirror: _,
             python_downloads_json_url: _,
+            // Patch upgrades are the only supported policy.
+            policy: PythonUpgradePolicy::Patch,
+            dry_run,
         } = args;
 
         Self {
@@ -1051,8 +1313,10 @@ impl PythonUpgradeSettings {
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
             python_downloads_json_url,
+            require_attestations: require_attestations.unwrap_or_default(),
             default,
             bin,
+            dry_run: DryRun::from_args(dry_run),
         }
     }
 }
@@ -1063,6 +1327,7 @@ pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) force: bool,
 }
 
 impl PythonUninstallSettings {
@@ -1076,12 +1341,14 @@ impl PythonUninstallSettings {
             install_dir,
             targets,
             all,
+            force,
         } = args;
 
         Self {
             install_dir,
             targets,
             all,
+            force,
         }
     }
 }
@@ -1091,6 +1358,7 @@ impl PythonUninstallSettings {
 pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
+    pub(crate) output_format: PythonListFormat,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
 }
@@ -1106,11 +1374,13 @@ impl PythonFindSettings {
             system,
             no_system,
             script: _,
+            output_format,
         } = args;
 
         Self {
             request,
             show_version,
+            output_format,
             no_project,
             system: flag(system, no_system, "system").unwrap_or_default(),
         }
@@ -1178,6 +1448,11 @@ pub(crate) struct SyncSettings {
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) output_format: SyncFormat,
+    pub(crate) lock_scripts: bool,
+    pub(crate) zipapp: Option<PathBuf>,
+    pub(crate) zipapp_entry_point: Option<String>,
+    pub(crate) python_shebang: Option<String>,
+    pub(crate) allow_extension_modules: bool,
 }
 
 impl SyncSettings {
@@ -1219,11 +1494,19 @@ impl SyncSettings {
             check,
             no_check,
             output_format,
+            zipapp,
+            zipapp_entry_point,
+            python_shebang,
+            allow_extension_modules,
         } = args;
         let install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
+        let lock_scripts = filesystem
+            .as_ref()
+            .and_then(|fs| fs.run.lock_scripts)
+            .unwrap_or_default();
 
         let settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
@@ -1281,6 +1564,84 @@ impl SyncSettings {
             refresh: Refresh::from(refresh),
             settings,
             install_mirrors,
+            lock_scripts,
+            zipapp,
+            zipapp_entry_point,
+            python_shebang,
+            allow_extension_modules,
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache warm` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheWarmSettings {
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) all_packages: bool,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl CacheWarmSettings {
+    /// Resolve the [`CacheWarmSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: Box<CacheWarmArgs>, filesystem: Option<FilesystemOptions>) -> Self {
+        let CacheWarmArgs {
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            no_dev,
+            group,
+            no_group,
+            no_default_groups,
+            all_groups,
+            all_packages,
+            package,
+            installer,
+            build,
+            refresh,
+            python,
+            python_platform,
+        } = *args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                false,
+                no_dev,
+                false,
+                group,
+                no_group,
+                no_default_groups,
+                vec![],
+                all_groups,
+            ),
+            all_packages,
+            package,
+            python: python.and_then(Maybe::into_option),
+            python_platform,
+            install_mirrors,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
         }
     }
 }
@@ -1517,6 +1878,8 @@ pub(crate) struct RemoveSettings {
     pub(crate) frozen: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) prune_unused: bool,
+    pub(crate) dry_run: DryRun,
     pub(crate) packages: Vec<PackageName>,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
@@ -1537,6 +1900,8 @@ impl RemoveSettings {
             packages,
             group,
             no_sync,
+            prune_unused,
+            dry_run,
             locked,
             frozen,
             active,
@@ -1574,6 +1939,8 @@ impl RemoveSettings {
             frozen,
             active: flag(active, no_active, "active"),
             no_sync,
+            prune_unused,
+            dry_run: DryRun::from_args(dry_run),
             packages,
             dependency_type,
             package,
@@ -1602,7 +1969,8 @@ pub(crate) struct VersionSettings {
     pub(crate) frozen: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
-    pub(crate) package: Option<PackageName>,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) workspace: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1628,6 +1996,7 @@ impl VersionSettings {
             build,
             refresh,
             package,
+            workspace,
             python,
         } = args;
 
@@ -1647,6 +2016,7 @@ impl VersionSettings {
             active: flag(active, no_active, "active"),
             no_sync,
             package,
+            workspace,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
@@ -1665,6 +2035,8 @@ pub(crate) struct TreeSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) universal: bool,
+    pub(crate) workspace: bool,
+    pub(crate) format: TreeFormat,
     pub(crate) depth: u8,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
@@ -1686,6 +2058,8 @@ impl TreeSettings {
         let TreeArgs {
             tree,
             universal,
+            workspace,
+            format,
             dev,
             only_dev,
             no_dev,
@@ -1722,6 +2096,8 @@ impl TreeSettings {
             locked,
             frozen,
             universal,
+            workspace,
+            format,
             depth: tree.depth,
             prune: tree.prune,
             package: tree.package,
@@ -1757,6 +2133,8 @@ pub(crate) struct ExportSettings {
     pub(crate) include_annotations: bool,
     pub(crate) include_header: bool,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1801,6 +2179,8 @@ impl ExportSettings {
             build,
             refresh,
             script,
+            python_version,
+            python_platform,
             python,
         } = args;
         let install_mirrors = filesystem
@@ -1845,6 +2225,8 @@ impl ExportSettings {
             include_annotations: flag(annotate, no_annotate, "annotate").unwrap_or(true),
             include_header: flag(header, no_header, "header").unwrap_or(true),
             script,
+            python_version,
+            python_platform,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1853,6 +2235,25 @@ impl ExportSettings {
     }
 }
 
+/// Combine the constraints files provided on the command line with those in the `[tool.uv.pip]`
+/// table.
+fn pip_constraints(
+    constraints: Vec<Maybe<PathBuf>>,
+    filesystem: Option<&FilesystemOptions>,
+) -> Vec<PathBuf> {
+    constraints
+        .into_iter()
+        .filter_map(Maybe::into_option)
+        .chain(
+            filesystem
+                .and_then(|filesystem| filesystem.pip.as_ref())
+                .and_then(|pip| pip.constraints.clone())
+                .into_iter()
+                .flatten(),
+        )
+        .collect()
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCompileSettings {
@@ -1976,10 +2377,7 @@ impl PipCompileSettings {
         Self {
             format,
             src_file,
-            constraints: constraints
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect(),
+            constraints: pip_constraints(constraints, filesystem.as_ref()),
             build_constraints: build_constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -2094,10 +2492,7 @@ impl PipSyncSettings {
 
         Self {
             src_file,
-            constraints: constraints
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect(),
+            constraints: pip_constraints(constraints, filesystem.as_ref()),
             build_constraints: build_constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -2150,6 +2545,7 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
@@ -2198,6 +2594,7 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            report,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2248,10 +2645,7 @@ impl PipInstallSettings {
             package,
             requirements,
             editables: editable,
-            constraints: constraints
-                .into_iter()
-                .filter_map(Maybe::into_option)
-                .collect(),
+            constraints: pip_constraints(constraints, filesystem.as_ref()),
             overrides: overrides
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -2261,6 +2655,7 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             build_constraints_from_workspace,
@@ -2302,11 +2697,224 @@ impl PipInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `pip download` or `pip wheel` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipDownloadSettings {
+    pub(crate) kind: DownloadKind,
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) dest: Option<PathBuf>,
+    pub(crate) macos_deployment_target: Option<(u16, u16)>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) overrides_from_workspace: Vec<Requirement>,
+    pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipDownloadSettings {
+    /// Resolve the [`PipDownloadSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipDownloadArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        Self::resolve_kind(DownloadKind::Distributions, args, filesystem)
+    }
+
+    /// Resolve the [`PipDownloadSettings`] for a `pip wheel` invocation from the CLI and filesystem
+    /// configuration.
+    ///
+    /// `pip wheel` accepts the same options as `pip download`, except that wheels are always built
+    /// for the current interpreter.
+    pub(crate) fn resolve_wheel(args: PipWheelArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipWheelArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            wheel_dir,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            torch_backend,
+            compat_args,
+        } = args;
+
+        let mut settings = Self::resolve_kind(
+            DownloadKind::Wheels,
+            PipDownloadArgs {
+                package,
+                requirements,
+                constraints,
+                overrides,
+                build_constraints,
+                dest: wheel_dir,
+                resolver,
+                refresh,
+                no_deps,
+                deps,
+                require_hashes,
+                no_require_hashes,
+                verify_hashes,
+                no_verify_hashes,
+                python,
+                system,
+                no_system,
+                no_build,
+                build,
+                no_binary,
+                only_binary,
+                python_version: None,
+                python_platform: None,
+                torch_backend,
+                compat_args,
+            },
+            filesystem,
+        );
+
+        // Ignore any configured target, as wheels can only be built for the current interpreter.
+        settings.settings.python_version = None;
+        settings.settings.python_platform = None;
+
+        settings
+    }
+
+    /// Resolve the [`PipDownloadSettings`] for the given kind of download.
+    fn resolve_kind(
+        kind: DownloadKind,
+        args: PipDownloadArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let PipDownloadArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            dest,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            python_version,
+            python_platform,
+            torch_backend,
+            compat_args: _,
+        } = args;
+
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let overrides_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .override_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .build_constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            kind,
+            package,
+            requirements,
+            constraints: pip_constraints(constraints, filesystem.as_ref()),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_constraints: build_constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            dest,
+            macos_deployment_target: python_platform
+                .and_then(|platform| platform.macos_deployment_target),
+            constraints_from_workspace,
+            overrides_from_workspace,
+            build_constraints_from_workspace,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    no_deps: flag(no_deps, deps, "deps"),
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    python_version,
+                    python_platform: python_platform.map(|platform| platform.triple),
+                    torch_backend,
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip uninstall` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) cascade: bool,
     pub(crate) dry_run: DryRun,
     pub(crate) settings: PipSettings,
 }
@@ -2325,6 +2933,7 @@ impl PipUninstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            cascade,
             dry_run,
             compat_args: _,
         } = args;
@@ -2332,6 +2941,7 @@ impl PipUninstallSettings {
         Self {
             package,
             requirements,
+            cascade,
             dry_run: DryRun::from_args(dry_run),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2528,6 +3138,8 @@ impl PipTreeSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCheckSettings {
+    pub(crate) output_format: CheckFormat,
+    pub(crate) suggest: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2535,17 +3147,23 @@ impl PipCheckSettings {
     /// Resolve the [`PipCheckSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: PipCheckArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipCheckArgs {
+            output_format,
+            suggest,
+            no_suggest,
+            fetch,
             python,
             system,
             no_system,
         } = args;
 
         Self {
+            output_format,
+            suggest: flag(suggest, no_suggest, "suggest").unwrap_or(false),
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
                     system: flag(system, no_system, "system"),
-                    ..PipOptions::default()
+                    ..PipOptions::from(fetch)
                 },
                 filesystem,
             ),
@@ -2563,11 +3181,15 @@ pub(crate) struct BuildSettings {
     pub(crate) sdist: bool,
     pub(crate) wheel: bool,
     pub(crate) list: bool,
+    pub(crate) output_format: BuildFormat,
     pub(crate) build_logs: bool,
+    pub(crate) log_dir: Option<PathBuf>,
     pub(crate) force_pep517: bool,
+    pub(crate) reproducible: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -2584,7 +3206,9 @@ impl BuildSettings {
             sdist,
             wheel,
             list,
+            output_format,
             force_pep517,
+            reproducible,
             build_constraints,
             require_hashes,
             no_require_hashes,
@@ -2592,7 +3216,9 @@ impl BuildSettings {
             no_verify_hashes,
             build_logs,
             no_build_logs,
+            log_dir,
             python,
+            python_platform,
             build,
             refresh,
             resolver,
@@ -2611,17 +3237,21 @@ impl BuildSettings {
             sdist,
             wheel,
             list,
+            output_format,
             build_logs: flag(build_logs, no_build_logs, "build-logs").unwrap_or(true),
+            log_dir,
             build_constraints: build_constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
             force_pep517,
+            reproducible,
             hash_checking: HashCheckingMode::from_args(
                 flag(require_hashes, no_require_hashes, "require-hashes"),
                 flag(verify_hashes, no_verify_hashes, "verify-hashes"),
             ),
             python: python.and_then(Maybe::into_option),
+            python_platform,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -2704,6 +3334,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) dependency_metadata: &'a DependencyMetadata,
+    pub(crate) build_system_overrides: &'a BuildSystemOverrides,
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) config_settings_package: &'a PackageConfigSettings,
     pub(crate) no_build_isolation: bool,
@@ -2723,6 +3354,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ResolverSettings {
     pub(crate) build_options: BuildOptions,
+    pub(crate) build_system_overrides: BuildSystemOverrides,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) dependency_metadata: DependencyMetadata,
@@ -2780,6 +3412,9 @@ impl From<ResolverOptions> for ResolverSettings {
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
+            build_system_overrides: BuildSystemOverrides::from_entries(
+                value.build_system_overrides.into_iter().flatten(),
+            ),
             index_strategy: value.index_strategy.unwrap_or_default(),
             keyring_provider: value.keyring_provider.unwrap_or_default(),
             config_setting: value.config_settings.unwrap_or_default(),
@@ -2867,6 +3502,9 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 dependency_metadata: DependencyMetadata::from_entries(
                     value.dependency_metadata.into_iter().flatten(),
                 ),
+                build_system_overrides: BuildSystemOverrides::from_entries(
+                    value.build_system_overrides.into_iter().flatten(),
+                ),
                 exclude_newer: value.exclude_newer,
                 fork_strategy: value.fork_strategy.unwrap_or_default(),
                 index_locations,
@@ -2925,6 +3563,7 @@ pub(crate) struct PipSettings {
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
+    pub(crate) build_system_overrides: BuildSystemOverrides,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
     pub(crate) no_strip_markers: bool,
@@ -2991,10 +3630,13 @@ impl PipSettings {
             group,
             no_deps,
             allow_empty_requirements,
+            // Merged into the command-specific settings, alongside `--constraints`.
+            constraints: _,
             resolution,
             prerelease,
             fork_strategy,
             dependency_metadata,
+            build_system_overrides,
             output_file,
             no_strip_extras,
             no_strip_markers,
@@ -3038,6 +3680,7 @@ impl PipSettings {
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
             dependency_metadata: top_level_dependency_metadata,
+            build_system_overrides: top_level_build_system_overrides,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
             no_build_isolation: top_level_no_build_isolation,
@@ -3071,6 +3714,8 @@ impl PipSettings {
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
+        let build_system_overrides =
+            build_system_overrides.combine(top_level_build_system_overrides);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
             config_settings_package.combine(top_level_config_settings_package);
@@ -3132,6 +3777,11 @@ impl PipSettings {
                     .combine(dependency_metadata)
                     .unwrap_or_default(),
             ),
+            build_system_overrides: BuildSystemOverrides::from_entries(
+                args.build_system_overrides
+                    .combine(build_system_overrides)
+                    .unwrap_or_default(),
+            ),
             output_file: args.output_file.combine(output_file),
             no_strip_extras: args
                 .no_strip_extras
@@ -3272,6 +3922,7 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             index_strategy: settings.resolver.index_strategy,
             keyring_provider: settings.resolver.keyring_provider,
             dependency_metadata: &settings.resolver.dependency_metadata,
+            build_system_overrides: &settings.resolver.build_system_overrides,
             config_setting: &settings.resolver.config_setting,
             config_settings_package: &settings.resolver.config_settings_package,
             no_build_isolation: settings.resolver.no_build_isolation,
@@ -3293,21 +3944,37 @@ pub(crate) struct PublishSettings {
     pub(crate) files: Vec<String>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
-    pub(crate) index: Option<String>,
+    pub(crate) index: Vec<String>,
+    pub(crate) attest: bool,
+    pub(crate) sign: bool,
+    pub(crate) sign_key: Option<PathBuf>,
+    pub(crate) sigstore: SigstoreConfig,
+    pub(crate) check: bool,
+    pub(crate) workspace: bool,
 
     // Both CLI and configuration.
     pub(crate) publish_url: DisplaySafeUrl,
     pub(crate) trusted_publishing: TrustedPublishing,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) check_url: Option<IndexUrl>,
+    pub(crate) concurrent_uploads: usize,
 
     // Configuration only
     pub(crate) index_locations: IndexLocations,
+    /// The settings for building the workspace members with `--workspace`.
+    pub(crate) settings: ResolverSettings,
+    pub(crate) install_mirrors: PythonInstallMirrors,
 }
 
 impl PublishSettings {
     /// Resolve the [`PublishSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: PublishArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let install_mirrors = match &filesystem {
+            Some(fs) => fs.install_mirrors.clone(),
+            None => PythonInstallMirrors::default(),
+        };
+        let settings = ResolverSettings::combine(ResolverOptions::default(), filesystem.clone());
+
         let Options {
             publish, top_level, ..
         } = filesystem
@@ -3318,6 +3985,7 @@ impl PublishSettings {
             publish_url,
             trusted_publishing,
             check_url,
+            concurrent_uploads,
         } = publish;
         let ResolverInstallerOptions {
             keyring_provider,
@@ -3351,6 +4019,102 @@ impl PublishSettings {
                 .unwrap_or_default(),
             check_url: args.check_url.combine(check_url),
             index: args.index,
+            attest: args.attest,
+            sign: args.sign || args.sign_key.is_some(),
+            sign_key: args.sign_key,
+            sigstore: SigstoreConfig {
+                fulcio_url: args.fulcio_url,
+                rekor_url: args.rekor_url,
+                trusted_root: args.sigstore_trusted_root,
+            },
+            check: args.check,
+            workspace: args.workspace,
+            concurrent_uploads: args
+                .concurrent_uploads
+                .combine(concurrent_uploads)
+                .map_or(1, NonZeroUsize::get),
+            index_locations: IndexLocations::new(
+                index
+                    .into_iter()
+                    .flatten()
+                    .chain(extra_index_url.into_iter().flatten().map(Index::from))
+                    .chain(index_url.into_iter().map(Index::from))
+                    .collect(),
+                Vec::new(),
+                false,
+            ),
+            settings,
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv yank` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct YankSettings {
+    // CLI only, see [`YankArgs`] for docs.
+    pub(crate) package: PackageName,
+    pub(crate) version: Version,
+    pub(crate) action: YankAction,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) index: String,
+
+    // Both CLI and configuration.
+    pub(crate) trusted_publishing: TrustedPublishing,
+    pub(crate) keyring_provider: KeyringProviderType,
+
+    // Configuration only
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl YankSettings {
+    /// Resolve the [`YankSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: YankArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options {
+            publish, top_level, ..
+        } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let PublishOptions {
+            trusted_publishing, ..
+        } = publish;
+        let ResolverInstallerOptions {
+            keyring_provider,
+            index,
+            extra_index_url,
+            index_url,
+            ..
+        } = top_level;
+
+        // Tokens are encoded in the same way as username/password
+        let (username, password) = if let Some(token) = args.token {
+            (Some("__token__".to_string()), Some(token))
+        } else {
+            (args.username, args.password)
+        };
+
+        Self {
+            package: args.package,
+            version: args.version,
+            action: if args.undo {
+                YankAction::Unyank
+            } else {
+                YankAction::Yank {
+                    reason: args.reason,
+                }
+            },
+            username,
+            password,
+            index: args.index,
+            trusted_publishing: trusted_publishing
+                .combine(args.trusted_publishing)
+                .unwrap_or_default(),
+            keyring_provider: args
+                .keyring_provider
+                .combine(keyring_provider)
+                .unwrap_or_default(),
             index_locations: IndexLocations::new(
                 index
                     .into_iter()
@@ -7,6 +7,7 @@ use indoc::indoc;
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
 use std::env::current_dir;
+use uv_static::EnvVars;
 use zip::ZipArchive;
 
 #[test]
@@ -893,6 +894,128 @@ fn build_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Replace the unsatisfiable build requirements of a project with `build-system-overrides`.
+#[test]
+fn build_system_overrides() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\\\.", "")])
+        .collect::<Vec<_>>();
+
+    let project = context.temp_dir.child("project");
+
+    let pyproject_toml = project.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling>=1.0", "hatchling<1.0"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project.child("README").touch()?;
+
+    uv_snapshot!(&filters, context.build().arg("--sdist").current_dir(&project), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+      × Failed to build `[TEMP_DIR]/project`
+      ├─▶ Failed to resolve requirements from `build-system.requires`
+      ├─▶ No solution found when resolving: `hatchling>=1.0`, `hatchling<1.0`
+      ╰─▶ Because you require hatchling>=1.0 and hatchling<1.0, we can conclude that your requirements are unsatisfiable.
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling>=1.0", "hatchling<1.0"]
+        build-backend = "hatchling.build"
+
+        [tool.uv]
+        build-system-overrides = [
+            { name = "project", requires = ["hatchling"] },
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(&filters, context.build().arg("--sdist").current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+    Successfully built dist/project-0.1.0.tar.gz
+    "###);
+
+    project
+        .child("dist")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
+/// A build system override for a project using the uv build backend disables the direct build.
+#[test]
+fn build_system_overrides_direct_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+
+        [tool.uv]
+        build-system-overrides = [
+            { name = "project", requires = ["hatchling"], build-backend = "hatchling.build" },
+        ]
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(&context.filters(), context.build().arg("--sdist").current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+    Successfully built dist/project-0.1.0.tar.gz
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn build_sha() -> Result<()> {
     let context = TestContext::new(DEFAULT_PYTHON_VERSION);
@@ -1179,6 +1302,105 @@ fn build_no_build_logs() -> Result<()> {
     Ok(())
 }
 
+/// Write the build backend output and an index of the builds to `--log-dir`.
+#[test]
+fn build_log_dir() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+
+    let pyproject_toml = project.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project.child("README").touch()?;
+
+    uv_snapshot!(&context.filters(), context.build().arg("project").arg("--no-build-logs").arg("--log-dir").arg("logs"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Successfully built project/dist/project-0.1.0.tar.gz
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    "###);
+
+    let logs = context.temp_dir.child("logs");
+    logs.child("project-0.1.0-sdist.log")
+        .assert(predicate::path::is_file());
+    logs.child("project-0.1.0-wheel.log")
+        .assert(predicate::path::is_file());
+
+    let index: serde_json::Value =
+        serde_json::from_str(&fs_err::read_to_string(logs.child("index.json"))?)?;
+    let builds = index["builds"].as_array().unwrap();
+    assert_eq!(builds.len(), 2);
+    assert_eq!(builds[0]["kind"], "sdist");
+    assert_eq!(builds[0]["outcome"], "success");
+    assert_eq!(builds[0]["filename"], "project-0.1.0.tar.gz");
+    assert_eq!(builds[1]["kind"], "wheel");
+    assert_eq!(builds[1]["outcome"], "success");
+    assert_eq!(builds[1]["filename"], "project-0.1.0-py3-none-any.whl");
+    for build in builds {
+        assert!(build["python"].is_string());
+        assert!(build["environment"].is_string());
+        assert!(
+            build["log"]
+                .as_str()
+                .unwrap()
+                .ends_with(&format!("-{}.log", build["kind"].as_str().unwrap()))
+        );
+    }
+
+    // A second build appends to the existing logs.
+    context
+        .build()
+        .arg("project")
+        .arg("--no-build-logs")
+        .arg("--log-dir")
+        .arg("logs")
+        .assert()
+        .success();
+    let sdist_log = fs_err::read_to_string(logs.child("project-0.1.0-sdist.log"))?;
+    assert_eq!(sdist_log.matches("=== sdist build of ").count(), 2);
+
+    // Other commands that build packages write logs with `UV_BUILD_LOG_DIR`.
+    context
+        .pip_install()
+        .arg("./project")
+        .env(EnvVars::UV_BUILD_LOG_DIR, "install-logs")
+        .assert()
+        .success();
+    let install_logs: Vec<String> = fs_err::read_dir(context.temp_dir.join("install-logs"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<_>>()?;
+    assert!(
+        install_logs
+            .iter()
+            .any(|name| name.starts_with("project") && name.ends_with("-wheel.log")),
+        "{install_logs:?}"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn build_tool_uv_sources() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1531,6 +1753,69 @@ fn build_fast_path() -> Result<()> {
     Ok(())
 }
 
+/// Report the built distributions with `--output-format json`.
+#[test]
+fn build_output_format_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r#""size": \d+"#, r#""size": "[SIZE]""#),
+            (r#""sha256": "[0-9a-f]{64}""#, r#""sha256": "[SHA256]""#),
+        ])
+        .collect::<Vec<_>>();
+
+    let built_by_uv = current_dir()?.join("../../scripts/packages/built-by-uv");
+
+    uv_snapshot!(filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output"))
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "artifacts": [
+        {
+          "path": "[TEMP_DIR]/output/built_by_uv-0.1.0.tar.gz",
+          "filename": "built_by_uv-0.1.0.tar.gz",
+          "kind": "sdist",
+          "name": "built-by-uv",
+          "version": "0.1.0",
+          "tags": [],
+          "size": "[SIZE]",
+          "sha256": "[SHA256]"
+        },
+        {
+          "path": "[TEMP_DIR]/output/built_by_uv-0.1.0-py3-none-any.whl",
+          "filename": "built_by_uv-0.1.0-py3-none-any.whl",
+          "kind": "wheel",
+          "name": "built-by-uv",
+          "version": "0.1.0",
+          "tags": [
+            "py3-none-any"
+          ],
+          "size": "[SIZE]",
+          "sha256": "[SHA256]"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output/built_by_uv-0.1.0.tar.gz
+    Successfully built output/built_by_uv-0.1.0-py3-none-any.whl
+    "#);
+
+    Ok(())
+}
+
 /// Test the `--list` option.
 #[test]
 fn build_list_files() -> Result<()> {
@@ -2024,3 +2309,187 @@ fn force_pep517() -> Result<()> {
 
     Ok(())
 }
+
+/// Build a platform-specific wheel for a different platform with `--python-platform`.
+#[cfg(unix)] // `sysconfig` ignores `_PYTHON_HOST_PLATFORM` on Windows.
+#[test]
+fn build_python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    // Mark the distribution as platform-specific, without compiling an extension module.
+    project.child("setup.py").write_str(indoc! {r"
+        from setuptools import Distribution, setup
+
+        class BinaryDistribution(Distribution):
+            def has_ext_modules(self):
+                return True
+
+        setup(distclass=BinaryDistribution)
+    "})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    context
+        .build()
+        .arg("--wheel")
+        .arg("--python-platform")
+        .arg("aarch64-apple-darwin")
+        .env_remove(EnvVars::MACOSX_DEPLOYMENT_TARGET)
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    project
+        .child("dist")
+        .child("project-0.1.0-cp312-cp312-macosx_13_0_arm64.whl")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
+/// Build environments are shared through the cache, so a build backend that modifies its
+/// environment must not leak into subsequent builds.
+#[test]
+fn build_modified_build_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\\\.", "")])
+        .collect::<Vec<_>>();
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["iniconfig"]
+        build-backend = "backend"
+        backend-path = ["backend"]
+    "#})?;
+    // A backend that installs a package into its build environment, if requested.
+    project
+        .child("backend")
+        .child("backend.py")
+        .write_str(indoc! {r#"
+        import importlib.util
+        import os
+        import sysconfig
+        import tarfile
+
+        def build_sdist(sdist_directory, config_settings=None):
+            if importlib.util.find_spec("injected") is not None:
+                raise RuntimeError("The build environment contains `injected`")
+            if os.environ.get("INJECT"):
+                site_packages = sysconfig.get_paths()["purelib"]
+                with open(os.path.join(site_packages, "injected.py"), "w") as f:
+                    f.write("")
+                dist_info = os.path.join(site_packages, "injected-1.0.dist-info")
+                os.makedirs(dist_info)
+                with open(os.path.join(dist_info, "METADATA"), "w") as f:
+                    f.write("Metadata-Version: 2.1\nName: injected\nVersion: 1.0\n")
+                with open(os.path.join(dist_info, "RECORD"), "w") as f:
+                    f.write("")
+            name = "project-0.1.0"
+            with tarfile.open(os.path.join(sdist_directory, name + ".tar.gz"), "w:gz") as tar:
+                tar.add("pyproject.toml", arcname=name + "/pyproject.toml")
+            return name + ".tar.gz"
+    "#})?;
+
+    // The first build modifies the cached build environment.
+    context
+        .build()
+        .arg("--sdist")
+        .env("INJECT", "1")
+        .current_dir(&project)
+        .assert()
+        .success();
+
+    // The modified environment is discarded, rather than reused.
+    uv_snapshot!(&filters, context.build().arg("--sdist").current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+    Successfully built dist/project-0.1.0.tar.gz
+    "###);
+
+    Ok(())
+}
+
+/// A wheel that isn't compatible with the target platform is rejected before it's written to the
+/// output directory.
+#[test]
+fn build_python_platform_incompatible() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["backend"]
+    "#})?;
+    // A backend that always tags its wheels for Windows.
+    project
+        .child("backend")
+        .child("backend.py")
+        .write_str(indoc! {r#"
+        import os
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "project-0.1.0-py3-none-win_amd64.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("project/__init__.py", "")
+            return filename
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .build()
+        .arg("--wheel")
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_28")
+        .current_dir(&project), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ The built wheel `project-0.1.0-py3-none-win_amd64.whl` is not compatible with the target platform
+    "###);
+
+    // The wheel must not be written to the output directory.
+    project
+        .child("dist")
+        .child("project-0.1.0-py3-none-win_amd64.whl")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -884,3 +884,241 @@ fn invalid_build_backend_settings_are_ignored() -> Result<()> {
 
     Ok(())
 }
+
+/// Use `SOURCE_DATE_EPOCH` as timestamp for the archive entries.
+#[test]
+fn source_date_epoch() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let temp_dir = TempDir::new()?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context
+        .temp_dir
+        .child("src/foo/__init__.py")
+        .write_str(r#"print("Hi from foo")"#)?;
+
+    // 2024-02-29T12:34:56Z
+    context
+        .build_backend()
+        .arg("build-sdist")
+        .arg(temp_dir.path())
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1709210096")
+        .assert()
+        .success();
+    context
+        .build_backend()
+        .arg("build-wheel")
+        .arg(temp_dir.path())
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1709210096")
+        .assert()
+        .success();
+
+    let sdist_reader = BufReader::new(File::open(temp_dir.path().join("foo-1.0.0.tar.gz"))?);
+    let mut sdist = tar::Archive::new(GzDecoder::new(sdist_reader));
+    for entry in sdist.entries()? {
+        assert_eq!(entry?.header().mtime()?, 1_709_210_096);
+    }
+
+    let mut wheel = zip::ZipArchive::new(File::open(
+        temp_dir.path().join("foo-1.0.0-py3-none-any.whl"),
+    )?)?;
+    for index in 0..wheel.len() {
+        let file = wheel.by_index(index)?;
+        let last_modified = file.last_modified().unwrap();
+        assert_eq!(
+            (
+                last_modified.year(),
+                last_modified.month(),
+                last_modified.day(),
+                last_modified.hour(),
+                last_modified.minute(),
+                last_modified.second()
+            ),
+            (2024, 2, 29, 12, 34, 56),
+            "{}",
+            file.name()
+        );
+    }
+
+    // Invalid timestamps are ignored with a warning.
+    uv_snapshot!(context
+        .build_backend()
+        .arg("build-wheel")
+        .arg(temp_dir.path())
+        .env(EnvVars::SOURCE_DATE_EPOCH, "yesterday"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo-1.0.0-py3-none-any.whl
+
+    ----- stderr -----
+    warning: Ignoring invalid `SOURCE_DATE_EPOCH`, expected a Unix timestamp: `yesterday`
+    ");
+
+    Ok(())
+}
+
+const BUILD_HOOK_SCRIPT: &str = indoc! {r#"
+    import sys
+    from pathlib import Path
+
+    output = Path(sys.argv[1]) / "foo"
+    output.mkdir(parents=True, exist_ok=True)
+    (output / "_generated.py").write_text("GREETING = 'Hi from the build hook'\n")
+"#};
+
+/// Run a build hook and add its output to the wheel.
+#[test]
+fn build_hook() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let temp_dir = TempDir::new()?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+
+        [tool.uv.build-backend]
+        build-hook = "build.py"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context
+        .temp_dir
+        .child("src/foo/__init__.py")
+        .write_str(r#"print("Hi from foo")"#)?;
+    context
+        .temp_dir
+        .child("build.py")
+        .write_str(BUILD_HOOK_SCRIPT)?;
+
+    let mut filters = context.filters();
+    filters.push((r"cp312-cp312-[a-z0-9_]+\.whl", "cp312-cp312-[PLATFORM].whl"));
+
+    // Without an interpreter, the build hook can't be run.
+    uv_snapshot!(filters, context
+        .build_backend()
+        .arg("build-wheel")
+        .arg(temp_dir.path()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The build hook `build.py` can only be run through the `uv_build` Python package, which provides the Python interpreter
+    ");
+
+    // The `uv_build` Python package passes its interpreter, which is used for the wheel tags.
+    uv_snapshot!(filters, context
+        .build_backend()
+        .arg("build-wheel")
+        .arg(temp_dir.path())
+        .env(EnvVars::UV_INTERNAL__BUILD_BACKEND_PYTHON, context.interpreter()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo-1.0.0-cp312-cp312-[PLATFORM].whl
+
+    ----- stderr -----
+    ");
+
+    let wheel = fs_err::read_dir(temp_dir.path())?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .find(|path| path.extension().is_some_and(|ext| ext == "whl"))
+        .expect("the wheel was built");
+
+    let mut archive = zip::ZipArchive::new(File::open(&wheel)?)?;
+    let mut wheel_info = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("foo-1.0.0.dist-info/WHEEL")?,
+        &mut wheel_info,
+    )?;
+    assert!(
+        wheel_info.contains("Root-Is-Purelib: false"),
+        "{wheel_info}"
+    );
+
+    context.pip_install().arg(&wheel).assert().success();
+
+    // The file written by the build hook is installed next to the module.
+    uv_snapshot!(context.python_command()
+        .arg("-c")
+        .arg("from foo._generated import GREETING; print(GREETING)"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hi from foo
+    Hi from the build hook
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// List the files generated by a build hook, which requires running it in a build environment.
+#[test]
+#[cfg(feature = "pypi")]
+fn build_hook_list() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+
+        [tool.uv.build-backend]
+        build-hook = "build.py"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context
+        .temp_dir
+        .child("src/foo/__init__.py")
+        .write_str(r#"print("Hi from foo")"#)?;
+    context
+        .temp_dir
+        .child("build.py")
+        .write_str(BUILD_HOOK_SCRIPT)?;
+
+    let mut filters = context.filters();
+    filters.push((r"cp312-cp312-[a-z0-9_]+\.whl", "cp312-cp312-[PLATFORM].whl"));
+
+    uv_snapshot!(filters, context.build().arg("--wheel").arg("--list"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Building foo-1.0.0-cp312-cp312-[PLATFORM].whl will include the following files:
+    foo/__init__.py (src/foo/__init__.py)
+    foo/_generated.py (generated)
+    foo-1.0.0.dist-info/WHEEL (generated)
+    foo-1.0.0.dist-info/METADATA (generated)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+use predicates::prelude::predicate;
+
+use uv_fs::Simplified;
+
+use crate::common::TestContext;
+use crate::common::uv_snapshot;
+
+/// `cache warm` should populate the cache such that a subsequent offline sync succeeds.
+#[test]
+fn warm_then_sync_offline() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.cache_warm(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Warmed 1 package in [TIME]
+    Downloaded 1 wheel, built 0 source distributions, and reused 0 from the cache
+    "###);
+
+    // Warming again should be served entirely from the cache.
+    uv_snapshot!(context.filters(), context.cache_warm(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Warmed 1 package in [TIME]
+    Downloaded 0 wheels, built 0 source distributions, and reused 1 from the cache
+    "###);
+
+    // The lockfile should be left untouched.
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Everything required by the lockfile should now be available offline.
+    uv_snapshot!(context.filters(), context.sync().arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// `cache warm` should accept `--platform` as an alias for `--python-platform`.
+#[test]
+fn warm_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "colorama==0.4.6 ; sys_platform == 'win32'"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // When warming for Windows, the Windows-only dependency should be included.
+    uv_snapshot!(context.filters(), context.cache_warm().arg("--platform").arg("windows"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Warmed 2 packages in [TIME]
+    Downloaded 2 wheels, built 0 source distributions, and reused 0 from the cache
+    "###);
+
+    // When warming for Linux, it should be skipped.
+    uv_snapshot!(context.filters(), context.cache_warm().arg("--python-platform").arg("linux"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Warmed 1 package in [TIME]
+    Downloaded 0 wheels, built 0 source distributions, and reused 1 from the cache
+    "###);
+
+    Ok(())
+}
+
+/// `cache warm` should select wheels using the tags for the `--python-platform`, rather than those
+/// of the interpreter.
+#[test]
+fn warm_platform_tags() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The only distribution of `maturin==2.0.0` is a Linux wheel.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/maturin-2.0.0-py3-none-linux_x86_64.whl"),
+        links.join("maturin-2.0.0-py3-none-linux_x86_64.whl"),
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["maturin==2.0.0"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["{}"]
+        "#,
+        links.path().portable_display(),
+    })?;
+
+    context.lock().assert().success();
+
+    // When warming for Linux, the wheel should be selected.
+    uv_snapshot!(context.filters(), context.cache_warm().arg("--python-platform").arg("linux"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Warmed 1 package in [TIME]
+    "###);
+
+    // When warming for Windows, there's no compatible distribution.
+    uv_snapshot!(context.filters(), context.cache_warm().arg("--python-platform").arg("windows"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Distribution `maturin==2.0.0 @ registry+links` can't be installed because it doesn't have a source distribution or wheel for the current platform
+
+    hint: You're on Windows (`win_amd64`), but `maturin` (v2.0.0) only has wheels for the following platform: `linux_x86_64`; consider adding your platform to `tool.uv.required-environments` to ensure uv resolves to a version with compatible wheels
+    "###);
+
+    Ok(())
+}
+
+/// `cache warm` should require an existing lockfile, rather than creating one.
+#[test]
+fn warm_requires_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.cache_warm(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv cache warm` requires a lockfile; run `uv lock` to generate one
+    "###);
+
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -796,6 +796,7 @@ impl TestContext {
             .env(EnvVars::APPDATA, self.home_dir.as_os_str())
             .env(EnvVars::USERPROFILE, self.home_dir.as_os_str())
             .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .env_remove(EnvVars::UV_PYTHON_SYSTEM_INSTALL_DIR)
             // Installations are not allowed by default; see `Self::with_managed_python_dirs`
             .env(EnvVars::UV_PYTHON_DOWNLOADS, "never")
             .env(EnvVars::UV_TEST_PYTHON_PATH, self.python_path())
@@ -888,6 +889,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip download` command with options shared across scenarios.
+    pub fn pip_download(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("download");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `pip wheel` command with options shared across scenarios.
+    pub fn pip_wheel(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("wheel");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip uninstall` command with options shared across scenarios.
     pub fn pip_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -922,6 +939,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv migrate` command with options shared across scenarios.
+    pub fn migrate(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("migrate");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv sync` command with options shared across scenarios.
     pub fn sync(&self) -> Command {
         let mut command = self.new_command();
@@ -1096,6 +1121,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool sync` command with options shared across scenarios.
+    pub fn tool_sync(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("sync");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool export` command with options shared across scenarios.
+    pub fn tool_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool import` command with options shared across scenarios.
+    pub fn tool_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("import");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv tool uninstall` command with options shared across scenarios.
     pub fn tool_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -1144,6 +1193,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache warm` command.
+    pub fn cache_warm(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("warm");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1672,6 +1729,15 @@ pub fn copy_dir_ignore(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> anyhow::
     Ok(())
 }
 
+/// List the files in a directory, sorted by name, one per line.
+pub fn list_dir(path: &Path) -> anyhow::Result<String> {
+    let mut names = fs_err::read_dir(path)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    names.sort();
+    Ok(names.join("\n"))
+}
+
 /// Create a stub package `name` in `dir` with the given `pyproject.toml` body.
 pub fn make_project(dir: &Path, name: &str, body: &str) -> anyhow::Result<()> {
     let pyproject_toml = formatdoc! {r#"
@@ -3311,6 +3311,78 @@ fn add_update_marker() -> Result<()> {
     Ok(())
 }
 
+/// Add a marker to an existing, unconditional requirement.
+#[test]
+fn add_marker_unconditional() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1", "typing-extensions"]
+    "#})?;
+
+    // Restrict `iniconfig` to Windows.
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--marker").arg("sys_platform == 'win32'").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // Should restrict the existing entry, rather than adding a redundant one.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=1 ; sys_platform == 'win32'",
+            "typing-extensions",
+        ]
+        "###
+        );
+    });
+
+    // Require a newer `typing-extensions` on Linux.
+    uv_snapshot!(context.filters(), context.add().arg("typing-extensions>=4.10").arg("--marker").arg("sys_platform == 'linux'").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // Should add a new entry, since the version differs from the unconditional entry.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=1 ; sys_platform == 'win32'",
+            "typing-extensions",
+            "typing-extensions>=4.10 ; sys_platform == 'linux'",
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "git")]
 fn update_source_replace_url() -> Result<()> {
@@ -3909,6 +3981,97 @@ fn remove_registry() -> Result<()> {
     Ok(())
 }
 
+/// Report the packages that become unused when removing a dependency, with `--prune-unused` and
+/// `--dry-run`.
+#[test]
+fn remove_prune_unused() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "idna"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    // With `--dry-run`, `idna` is still required, but `anyio` and `sniffio` would be removed.
+    uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Remove anyio v3.7.0
+    Remove sniffio v1.3.1
+    ");
+
+    // The `pyproject.toml` should be unchanged.
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "idna"]
+        "#
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--prune-unused"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Removed anyio v3.7.0
+    Removed sniffio v1.3.1
+    Uninstalled 2 packages in [TIME]
+     - anyio==3.7.0
+     - sniffio==1.3.1
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["idna"]
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 #[test]
 fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5271,6 +5434,93 @@ fn add_requirements_file_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Add requirements from a file with editable and duplicate entries to a dependency group.
+#[test]
+fn add_requirements_file_editable_duplicates() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        -e ./child
+        iniconfig==2.0.0
+        iniconfig==2.0.0
+        anyio>=4
+        anyio>=4.3
+    "})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("-r").arg("requirements.txt").arg("--group").arg("dev"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Requirement `anyio` was provided multiple times; using `anyio>=4.3` instead of `anyio>=4`
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + anyio==4.3.0
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = [
+            "anyio>=4.3",
+            "child",
+            "iniconfig==2.0.0",
+        ]
+
+        [tool.uv.sources]
+        child = { path = "child", editable = true }
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 /// Add a requirement to a dependency group.
 #[test]
 fn add_group() -> Result<()> {
@@ -13595,3 +13845,204 @@ fn add_path_outside_workspace_no_default() -> Result<()> {
 
     Ok(())
 }
+
+/// Add a path dependency within the workspace directory that's excluded from the workspace. It
+/// should be added as a path dependency, rather than as a workspace member.
+#[test]
+fn add_path_excluded_from_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let workspace_dir = context.temp_dir.child("workspace");
+    workspace_dir.create_dir_all()?;
+
+    let workspace_toml = workspace_dir.child("pyproject.toml");
+    workspace_toml.write_str(indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = []
+        exclude = ["vendor/*"]
+    "#})?;
+
+    let dep_dir = workspace_dir.child("vendor").child("dep");
+    dep_dir.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "dep"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // With `--workspace`, the path can't be added to the workspace.
+    uv_snapshot!(context.filters(), context
+        .add()
+        .current_dir(&workspace_dir)
+        .arg("./vendor/dep")
+        .arg("--workspace"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Cannot add `vendor/dep` to the workspace, since it's excluded by `tool.uv.workspace.exclude`
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .add()
+        .current_dir(&workspace_dir)
+        .arg("./vendor/dep")
+        .arg("--editable"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + dep==0.1.0 (from file://[TEMP_DIR]/workspace/vendor/dep)
+    ");
+
+    let pyproject_toml = fs_err::read_to_string(workspace_toml)?;
+    assert_snapshot!(
+        pyproject_toml, @r#"
+    [project]
+    name = "parent"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "dep",
+    ]
+
+    [tool.uv.workspace]
+    members = []
+    exclude = ["vendor/*"]
+
+    [tool.uv.sources]
+    dep = { path = "vendor/dep", editable = true }
+    "#
+    );
+
+    Ok(())
+}
+
+/// Updating a dependency and its source should retain the comments, blank lines, and ordering of
+/// the `dependencies` array and `tool.uv.sources` table.
+#[test]
+fn add_update_preserves_formatting() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+
+            # Local packages.
+            "child",
+            "other",
+        ]
+
+        [tool.uv.sources]
+        # The child package.
+        child = { path = "child" }  # Not yet editable.
+        other = { path = "other" }
+    "#})?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "child"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("./child").arg("--editable").arg("--no-workspace").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+
+            # Local packages.
+            "child",
+            "other",
+        ]
+
+        [tool.uv.sources]
+        # The child package.
+        child = { path = "child", editable = true }  # Not yet editable.
+        other = { path = "other" }
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Removing a dependency from a single-line array should keep the array on a single line.
+#[test]
+fn remove_preserves_single_line_array() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "child", "iniconfig"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.remove().arg("child").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig"]
+        "#
+        );
+    });
+
+    Ok(())
+}
@@ -18,6 +18,7 @@ fn help() {
     Commands:
       run                        Run a command or script
       init                       Create a new project
+      migrate                    Migrate a project from another tool to uv
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
       version                    Read or update the project's version
@@ -31,15 +32,21 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      yank                       Yank or un-yank a release on an index
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -61,6 +68,17 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -98,6 +116,7 @@ fn help_flag() {
     Commands:
       run      Run a command or script
       init     Create a new project
+      migrate  Migrate a project from another tool to uv
       add      Add dependencies to the project
       remove   Remove dependencies from the project
       version  Read or update the project's version
@@ -111,14 +130,20 @@ fn help_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      yank     Yank or un-yank a release on an index
       cache    Manage uv's cache
       self     Manage the uv executable
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -140,6 +165,17 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -176,6 +212,7 @@ fn help_short_flag() {
     Commands:
       run      Run a command or script
       init     Create a new project
+      migrate  Migrate a project from another tool to uv
       add      Add dependencies to the project
       remove   Remove dependencies from the project
       version  Read or update the project's version
@@ -189,14 +226,20 @@ fn help_short_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      yank     Yank or un-yank a release on an index
       cache    Manage uv's cache
       self     Manage the uv executable
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -218,6 +261,17 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -316,6 +370,21 @@ fn help_subcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache.
+              
+              Compressed archives reduce the cache's disk footprint, but are always copied (and
+              decompressed) into the environment, regardless of the link mode.
+              
+              Defaults to `none`.
+              
+              [env: UV_CACHE_COMPRESSION=]
+
+              Possible values:
+              - none: Store unpacked archives uncompressed, such that they can be linked into
+                environments
+              - zstd: Store the files in unpacked archives zstd-compressed
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -394,6 +463,42 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth.
+              
+              Accepts a rate in bytes per second, with an optional `K`, `M`, or `G` suffix. For example,
+              `--limit-rate 10M` limits the combined throughput of all downloads to 10 MiB/s.
+              
+              [env: UV_LIMIT_RATE=]
+
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues.
+              
+              Each request is recorded with its URL, headers (omitting credentials), status, timing, and
+              cache disposition. If the path has a `.har` extension, the trace is written as an HTTP
+              Archive; otherwise, it's written as JSON lines.
+              
+              [env: UV_TRACE_HTTP=]
+
+          --debug-auth
+              Report the credentials used for each request, and where they came from.
+              
+              For each request, uv reports whether credentials were sent and which source (e.g., the
+              request URL, the keyring, or a netrc file) supplied them. Passwords and tokens are never
+              shown.
+              
+              [env: UV_DEBUG_AUTH=]
+
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other.
+              
+              By default, uv warns if a package is found on multiple indexes that publish PEP 708
+              metadata without linking them. With this flag, uv fails unless one index tracks the other,
+              or both list each other as alternate locations.
+              
+              [env: UV_STRICT_INDEX_TRACKING=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -541,6 +646,15 @@ fn help_subsubcommand() {
               
               [env: UV_PYTHON_DOWNLOADS_JSON_URL=]
 
+          --require-attestations
+              Refuse to install Python distributions that can't be verified with an attestation from
+              python-build-standalone.
+              
+              The GitHub artifact attestations of each archive are verified against the Sigstore trusted
+              root. Attestations are not verified for downloads from a mirror or in offline mode.
+              
+              This can also be set with `UV_PYTHON_INSTALL_REQUIRE_ATTESTATIONS=1`.
+
       -r, --reinstall
               Reinstall the requested Python version, if it's already installed.
               
@@ -583,6 +697,21 @@ fn help_subsubcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache.
+              
+              Compressed archives reduce the cache's disk footprint, but are always copied (and
+              decompressed) into the environment, regardless of the link mode.
+              
+              Defaults to `none`.
+              
+              [env: UV_CACHE_COMPRESSION=]
+
+              Possible values:
+              - none: Store unpacked archives uncompressed, such that they can be linked into
+                environments
+              - zstd: Store the files in unpacked archives zstd-compressed
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -661,6 +790,42 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth.
+              
+              Accepts a rate in bytes per second, with an optional `K`, `M`, or `G` suffix. For example,
+              `--limit-rate 10M` limits the combined throughput of all downloads to 10 MiB/s.
+              
+              [env: UV_LIMIT_RATE=]
+
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues.
+              
+              Each request is recorded with its URL, headers (omitting credentials), status, timing, and
+              cache disposition. If the path has a `.har` extension, the trace is written as an HTTP
+              Archive; otherwise, it's written as JSON lines.
+              
+              [env: UV_TRACE_HTTP=]
+
+          --debug-auth
+              Report the credentials used for each request, and where they came from.
+              
+              For each request, uv reports whether credentials were sent and which source (e.g., the
+              request URL, the keyring, or a netrc file) supplied them. Passwords and tokens are never
+              shown.
+              
+              [env: UV_DEBUG_AUTH=]
+
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other.
+              
+              By default, uv warns if a package is found on multiple indexes that publish PEP 708
+              metadata without linking them. With this flag, uv fails unless one index tracks the other,
+              or both list each other as alternate locations.
+              
+              [env: UV_STRICT_INDEX_TRACKING=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -738,9 +903,14 @@ fn help_flag_subcommand() {
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -762,6 +932,17 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -811,6 +992,9 @@ fn help_flag_subsubcommand() {
               UV_PYPY_INSTALL_MIRROR=]
           --python-downloads-json-url <PYTHON_DOWNLOADS_JSON_URL>
               URL pointing to JSON of custom Python installations [env: UV_PYTHON_DOWNLOADS_JSON_URL=]
+          --require-attestations
+              Refuse to install Python distributions that can't be verified with an attestation from
+              python-build-standalone
       -r, --reinstall
               Reinstall the requested Python version, if it's already installed
       -f, --force
@@ -819,9 +1003,14 @@ fn help_flag_subsubcommand() {
               Use as the default Python version
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -843,6 +1032,17 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -873,6 +1073,7 @@ fn help_unknown_subcommand() {
     error: There is no command `foobar` for `uv`. Did you mean one of:
         run
         init
+        migrate
         add
         remove
         version
@@ -886,6 +1087,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        yank
         cache
         self
         generate-shell-completion
@@ -900,6 +1102,7 @@ fn help_unknown_subcommand() {
     error: There is no command `foo bar` for `uv`. Did you mean one of:
         run
         init
+        migrate
         add
         remove
         version
@@ -913,6 +1116,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        yank
         cache
         self
         generate-shell-completion
@@ -956,6 +1160,7 @@ fn help_with_global_option() {
     Commands:
       run                        Run a command or script
       init                       Create a new project
+      migrate                    Migrate a project from another tool to uv
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
       version                    Read or update the project's version
@@ -969,15 +1174,21 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      yank                       Yank or un-yank a release on an index
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -999,6 +1210,17 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1077,6 +1299,7 @@ fn help_with_no_pager() {
     Commands:
       run                        Run a command or script
       init                       Create a new project
+      migrate                    Migrate a project from another tool to uv
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
       version                    Read or update the project's version
@@ -1090,15 +1313,21 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      yank                       Yank or un-yank a release on an index
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache
+              Avoid reading from or writing to the cache, instead using a temporary directory for the
+              duration of the operation [env: UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]
+              Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-compression <CACHE_COMPRESSION>
+              The compression to apply to unpacked wheels and source distributions in the cache [env:
+              UV_CACHE_COMPRESSION=] [possible values: none, zstd]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -1120,6 +1349,17 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <LIMIT_RATE>
+              Limit the aggregate download bandwidth [env: UV_LIMIT_RATE=]
+          --trace-http <PATH>
+              Record all HTTP requests to the given file, for debugging network issues [env:
+              UV_TRACE_HTTP=]
+          --debug-auth
+              Report the credentials used for each request, and where they came from [env:
+              UV_DEBUG_AUTH=]
+          --strict-index-tracking
+              Refuse to combine versions of a package across indexes that don't declare a relationship
+              to each other [env: UV_STRICT_INDEX_TRACKING=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
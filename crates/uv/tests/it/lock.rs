@@ -5,6 +5,8 @@ use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use std::io::BufReader;
 use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use crate::common::{
     self, TestContext, build_vendor_links_url, decode_token, download_to_disk, packse_index_url,
@@ -1825,6 +1827,87 @@ fn lock_project_with_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with `uv.tool.constraint-files`, reading the constraints from a remote URL.
+#[tokio::test]
+async fn lock_project_with_remote_constraint_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/constraints.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("idna<3.4"))
+        .mount(&server)
+        .await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        constraint-files = ["{url}/constraints.txt"]
+        "#,
+        url = server.uri(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Install the base dependencies from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.3
+     + sniffio==1.3.1
+    "###);
+
+    // If the remote constraints change, the lockfile is outdated.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/constraints.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("idna<3.3"))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    Ok(())
+}
+
 /// Lock a project with `uv.tool.constraint-dependencies` that reference `tool.uv.sources`.
 #[test]
 fn lock_project_with_constraint_sources() -> Result<()> {
@@ -5340,6 +5423,128 @@ fn lock_requires_python_disjoint() -> Result<()> {
     Ok(())
 }
 
+/// Lock a workspace whose members declare divergent `requires-python` specifiers, with
+/// `member-requires-python`.
+#[test]
+fn lock_member_requires_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [tool.uv.workspace]
+        members = ["library", "app"]
+        "#,
+    )?;
+
+    context
+        .temp_dir
+        .child("library")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+        [project]
+        name = "library"
+        version = "0.1.0"
+        requires-python = ">=3.9"
+        dependencies = ["iniconfig"]
+        "#,
+        )?;
+
+    context
+        .temp_dir
+        .child("app")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+        [project]
+        name = "app"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["library", "anyio"]
+
+        [tool.uv.sources]
+        library = { workspace = true }
+        "#,
+        )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("member-requires-python"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    // The lockfile spans the union of the members' `requires-python`, forking at `3.12`, where
+    // `app` is included.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("requires-python = \">=3.9\""), "{lock}");
+    assert!(lock.contains("\"python_full_version >= '3.12'\""), "{lock}");
+    assert!(lock.contains("\"python_full_version < '3.12'\""), "{lock}");
+
+    // Without the preview feature, the workspace is constrained to the intersection.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
+/// Lock a workspace whose members declare disjoint `requires-python` specifiers, with
+/// `member-requires-python`.
+#[test]
+fn lock_member_requires_python_disjoint() -> Result<()> {
+    let context = TestContext::new("3.11");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("child").child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = "==3.10"
+        dependencies = []
+        "#,
+    )?;
+
+    // The gap between the members is filled, such that the lockfile covers `>=3.10`.
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("member-requires-python"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("requires-python = \">=3.10\""), "{lock}");
+
+    Ok(())
+}
+
 #[test]
 fn lock_requires_python_maximum_version() -> Result<()> {
     let context = TestContext::new("3.11");
@@ -17,6 +17,9 @@ mod cache_clean;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_warm;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -39,6 +42,9 @@ mod lock_conflict;
 
 mod lock_scenarios;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod migrate;
+
 mod network;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -49,6 +55,9 @@ mod pip_compile;
 
 mod pip_compile_scenarios;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_download;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod pip_freeze;
 
@@ -67,6 +76,9 @@ mod pip_sync;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_wheel;
+
 #[cfg(feature = "pypi")]
 mod publish;
 
@@ -111,6 +123,9 @@ mod tool_list;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_run;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_sync;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_uninstall;
 
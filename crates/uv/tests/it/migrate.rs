@@ -0,0 +1,425 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Migrate a Poetry project, converting its dependencies, extras, groups, and scripts.
+#[test]
+fn migrate_poetry() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [tool.poetry]
+        name = "foo"
+        version = "1.0.0"
+        description = "A Poetry project"
+        authors = ["Jane Doe <jane@example.com>"]
+        readme = "README.md"
+        license = "MIT"
+
+        [tool.poetry.dependencies]
+        python = "^3.12"
+        anyio = "^3.7.0"
+        iniconfig = { version = "~1.1", python = "<3.13" }
+        typing-extensions = { git = "https://github.com/python/typing_extensions", tag = "4.10.0" }
+        requests = { version = ">=2.31", optional = true }
+
+        [tool.poetry.extras]
+        http = ["requests"]
+
+        [tool.poetry.group.test.dependencies]
+        pytest = "*"
+
+        [tool.poetry.group.docs]
+        optional = true
+
+        [tool.poetry.group.docs.dependencies]
+        sphinx = "^7"
+
+        [tool.poetry.scripts]
+        foo = "foo.cli:main"
+
+        [build-system]
+        requires = ["poetry-core>=1.0.0"]
+        build-backend = "poetry.core.masonry.api"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.migrate().arg("--no-lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated `pyproject.toml` from Poetry
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        description = "A Poetry project"
+        readme = "README.md"
+        license = "MIT"
+        requires-python = ">=3.12,<4"
+        authors = [{ name = "Jane Doe", email = "jane@example.com" }]
+        dependencies = [
+            "anyio>=3.7.0,<4",
+            "iniconfig>=1.1,<1.2 ; python_full_version < '3.13'",
+            "typing-extensions",
+        ]
+
+        [project.scripts]
+        foo = "foo.cli:main"
+
+        [project.optional-dependencies]
+        http = [
+            "requests>=2.31",
+        ]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv]
+        default-groups = ["test"]
+
+        [tool.uv.sources]
+        typing-extensions = { git = "https://github.com/python/typing_extensions", tag = "4.10.0" }
+
+        [dependency-groups]
+        docs = [
+            "sphinx>=7,<8",
+        ]
+        test = [
+            "pytest",
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Migrate a Poetry project, then lock it.
+#[test]
+fn migrate_poetry_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [tool.poetry]
+        name = "foo"
+        version = "0.1.0"
+        package-mode = false
+
+        [tool.poetry.dependencies]
+        python = "^3.12"
+        iniconfig = "^2.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.migrate(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated `pyproject.toml` from Poetry
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    assert_snapshot!(
+        pyproject_toml, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.12,<4"
+    dependencies = [
+        "iniconfig>=2.0,<3",
+    ]
+    "###
+    );
+
+    assert!(context.temp_dir.child("uv.lock").exists());
+
+    Ok(())
+}
+
+/// Migrate a Pipenv project, converting its development packages and scripts.
+#[test]
+fn migrate_pipenv() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let child = context.temp_dir.child("foo");
+    child.child("Pipfile").write_str(indoc! {r#"
+        [[source]]
+        url = "https://pypi.org/simple"
+        verify_ssl = true
+        name = "pypi"
+
+        [packages]
+        anyio = "==3.7.0"
+        iniconfig = { version = "*", sys_platform = "== 'linux'" }
+
+        [dev-packages]
+        pytest = "*"
+
+        [requires]
+        python_version = "3.12"
+
+        [scripts]
+        test = "pytest -x"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.migrate().arg("foo").arg("--no-lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated `foo/pyproject.toml` from Pipenv
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(child.join("pyproject.toml"))?;
+
+    assert_snapshot!(
+        pyproject_toml, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "anyio==3.7.0",
+        "iniconfig ; sys_platform == 'linux'",
+    ]
+
+    [tool.uv.tasks]
+    test = "pytest -x"
+
+    [dependency-groups]
+    dev = [
+        "pytest",
+    ]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Migrate a setuptools project with its metadata in a `setup.cfg`.
+#[test]
+fn migrate_setuptools() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("setup.cfg").write_str(indoc! {r#"
+        [metadata]
+        name = foo
+        version = attr: foo.__version__
+        description = A setuptools project
+        author = Jane Doe
+        author_email = jane@example.com
+        license = MIT
+        classifiers =
+            Programming Language :: Python :: 3
+
+        [options]
+        python_requires = >=3.12
+        install_requires =
+            anyio>=3.7
+            iniconfig; python_version < "3.13"
+
+        [options.extras_require]
+        test = pytest
+
+        [options.entry_points]
+        console_scripts =
+            foo = foo.cli:main
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.migrate().arg("--no-lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The project metadata is now defined in `pyproject.toml`; remove the duplicate metadata from `setup.cfg`
+    Migrated `pyproject.toml` from setuptools
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    assert_snapshot!(
+        pyproject_toml, @r###"
+    [project]
+    name = "foo"
+    description = "A setuptools project"
+    license = "MIT"
+    requires-python = ">=3.12"
+    authors = [{ name = "Jane Doe", email = "jane@example.com" }]
+    classifiers = ["Programming Language :: Python :: 3"]
+    dynamic = ["version"]
+    dependencies = [
+        "anyio>=3.7",
+        "iniconfig ; python_full_version < '3.13'",
+    ]
+
+    [project.scripts]
+    foo = "foo.cli:main"
+
+    [project.optional-dependencies]
+    test = [
+        "pytest",
+    ]
+
+    [build-system]
+    requires = ["setuptools>=77"]
+    build-backend = "setuptools.build_meta"
+
+    [tool.setuptools.dynamic]
+    version = { attr = "foo.__version__" }
+    "###
+    );
+
+    Ok(())
+}
+
+/// Migrate a setuptools project with its metadata in a `setup.py`, which is only executed with
+/// `--run-setup-py`.
+#[test]
+fn migrate_setuptools_setup_py() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("setup.py").write_str(indoc! {r#"
+        from setuptools import setup
+
+        setup(
+            name="foo",
+            version="0.1.0",
+            license="Proprietary",
+            python_requires=">=3.12",
+            install_requires=["anyio"],
+        )
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.migrate().arg("--dry-run"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Reading the metadata from `setup.py` requires executing it; pass `--run-setup-py` to allow it
+    "###);
+
+    // Licenses that aren't SPDX license expressions are written as text.
+    uv_snapshot!(context.filters(), context.migrate().arg("--dry-run").arg("--run-setup-py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    license = { text = "Proprietary" }
+    requires-python = ">=3.12"
+    dependencies = [
+        "anyio",
+    ]
+
+    [build-system]
+    requires = ["setuptools>=77"]
+    build-backend = "setuptools.build_meta"
+
+    ----- stderr -----
+    warning: The project metadata is now defined in `pyproject.toml`; remove the duplicate metadata from `setup.py`
+    "###);
+
+    // With a `setup.cfg`, the `setup.py` is ignored unless requested.
+    context.temp_dir.child("setup.cfg").write_str(indoc! {r"
+        [metadata]
+        name = foo
+        version = 0.1.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.migrate().arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    dependencies = []
+
+    [build-system]
+    requires = ["setuptools>=77"]
+    build-backend = "setuptools.build_meta"
+
+    ----- stderr -----
+    warning: Ignoring the metadata in `setup.py`, which requires executing it; pass `--run-setup-py` to include it
+    warning: The project metadata is now defined in `pyproject.toml`; remove the duplicate metadata from `setup.cfg` and `setup.py`
+    "###);
+
+    Ok(())
+}
+
+/// Print the migrated `pyproject.toml` without writing it.
+#[test]
+fn migrate_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [tool.poetry]
+        name = "foo"
+        version = "0.1.0"
+        package-mode = false
+
+        [tool.poetry.dependencies]
+        python = "^3.12"
+        iniconfig = "2.0.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.migrate().arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.12,<4"
+    dependencies = [
+        "iniconfig==2.0.0",
+    ]
+
+    ----- stderr -----
+    "###);
+
+    // The `pyproject.toml` should be unchanged.
+    assert!(context.read("pyproject.toml").contains("[tool.poetry]"));
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    Ok(())
+}
+
+/// Migrating a directory without a Poetry, Pipenv, or setuptools project should fail.
+#[test]
+fn migrate_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.migrate(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No Poetry, Pipenv, or setuptools project found in `[TEMP_DIR]/`
+    "###);
+}
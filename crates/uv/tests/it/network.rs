@@ -360,3 +360,59 @@ async fn install_http_retries() {
     "
     );
 }
+
+/// Retry an otherwise non-retryable status if it's listed in `http-retry-status-codes`.
+#[tokio::test]
+async fn install_http_retry_status_codes_setting() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(StatusCode::CONFLICT))
+        .mount(&server)
+        .await;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml
+        .write_str("http-retry-status-codes = [409]\n")
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--index")
+        .arg(server.uri())
+        .arg("--config-file")
+        .arg(uv_toml.path())
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Request failed after 3 retries
+      Caused by: Failed to fetch: `http://[LOCALHOST]/anyio/`
+      Caused by: HTTP status client error (409 Conflict) for url (http://[LOCALHOST]/anyio/)
+    "
+    );
+
+    // The environment variable takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--index")
+        .arg(server.uri())
+        .arg("--config-file")
+        .arg(uv_toml.path())
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_HTTP_RETRY_STATUS_CODES, "")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to fetch: `http://[LOCALHOST]/anyio/`
+      Caused by: HTTP status client error (409 Conflict) for url (http://[LOCALHOST]/anyio/)
+    "
+    );
+}
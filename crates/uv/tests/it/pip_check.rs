@@ -1,4 +1,5 @@
 use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -187,3 +188,67 @@ fn check_multiple_incompatible_packages() -> Result<()> {
 
     Ok(())
 }
+
+/// Suggest a version of the incompatible dependency that satisfies all installed packages.
+#[test]
+fn check_incompatible_packages_suggest() {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("requests==2.31.0")
+        .assert()
+        .success();
+    context.pip_install().arg("idna==2.4").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--suggest"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    Found 1 incompatibility
+    The package `requests` requires `idna>=2.5,<4`, but `2.4` is installed
+    hint: Install `idna==3.6` to satisfy all installed requirements on `idna`
+    "###
+    );
+}
+
+/// Report incompatibilities, and the suggested versions, in JSON format.
+#[test]
+fn check_incompatible_packages_json() {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("requests==2.31.0")
+        .assert()
+        .success();
+    context.pip_install().arg("idna==2.4").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--output-format").arg("json").arg("--suggest"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {"checked":5,"compatible":false,"diagnostics":[{"kind":"incompatible-dependency","package":"requests","message":"The package `requests` requires `idna>=2.5,<4`, but `2.4` is installed","requirement":"idna>=2.5,<4","installed_version":"2.4","suggestion":{"name":"idna","version":"3.6"}}]}
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    "###
+    );
+
+    // Without any incompatibilities, the report is empty.
+    context.pip_install().arg("idna==3.6").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"checked":5,"compatible":true,"diagnostics":[]}
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    "###
+    );
+}
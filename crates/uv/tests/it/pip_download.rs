@@ -0,0 +1,310 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, list_dir, uv_snapshot};
+
+/// Download a package and its dependencies into a directory.
+#[test]
+fn download() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("anyio==4.3.0")
+        .arg("-d")
+        .arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 files to wheelhouse in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheelhouse"))?, @r###"
+    anyio-4.3.0-py3-none-any.whl
+    idna-3.6-py3-none-any.whl
+    sniffio-1.3.1-py3-none-any.whl
+    "###);
+
+    Ok(())
+}
+
+/// Download the packages listed in a requirements file, without their dependencies.
+#[test]
+fn download_requirements_no_deps() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0\niniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 files to . in [TIME]
+    "###
+    );
+
+    assert!(
+        context
+            .temp_dir
+            .child("anyio-4.3.0-py3-none-any.whl")
+            .exists()
+    );
+    assert!(
+        context
+            .temp_dir
+            .child("iniconfig-2.0.0-py3-none-any.whl")
+            .exists()
+    );
+
+    Ok(())
+}
+
+/// Download a source distribution with `--no-binary`.
+#[test]
+fn download_no_binary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 file to wheelhouse in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheelhouse"))?, @"iniconfig-2.0.0.tar.gz");
+
+    Ok(())
+}
+
+/// Download wheels for another platform and Python version, using pip's flags.
+#[test]
+fn download_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("markupsafe==2.1.5")
+        .arg("--platform")
+        .arg("win_amd64")
+        .arg("--python-version")
+        .arg("3.8")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 file to wheelhouse in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheelhouse"))?, @"MarkupSafe-2.1.5-cp38-cp38-win_amd64.whl");
+
+    Ok(())
+}
+
+/// Use the macOS version in a pip-style platform tag as the deployment target.
+#[test]
+fn download_platform_macos_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `numpy` publishes wheels for macOS 14.0 and later, in addition to macOS 11.0 and later.
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("numpy==2.0.0")
+        .arg("--platform")
+        .arg("macosx_14_0_arm64")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 file to wheelhouse in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheelhouse"))?, @"numpy-2.0.0-cp312-cp312-macosx_14_0_arm64.whl");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("numpy==2.0.0")
+        .arg("--platform")
+        .arg("macosx_11_0_arm64")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("wheelhouse-11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 file to wheelhouse-11 in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheelhouse-11"))?, @"numpy-2.0.0-cp312-cp312-macosx_11_0_arm64.whl");
+
+    Ok(())
+}
+
+/// Reject platform tags that don't correspond to a supported target.
+#[test]
+fn download_unsupported_platform() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("iniconfig")
+        .arg("--platform")
+        .arg("manylinux1_i686"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'manylinux1_i686' for '--python-platform <PYTHON_PLATFORM>': Unsupported platform: `manylinux1_i686`
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
+/// Download a requirement whose hash matches with `--require-hashes`.
+#[test]
+fn download_require_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        idna==3.6 \
+            --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("-d")
+        .arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 file to wheelhouse in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheelhouse"))?, @"idna-3.6-py3-none-any.whl");
+
+    Ok(())
+}
+
+/// Reject a download whose hash doesn't match with `--require-hashes`, without writing the file.
+#[test]
+fn download_require_hashes_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r"
+        idna==3.6 \
+            --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("-d")
+        .arg("wheelhouse"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to download `idna==3.6`
+      Caused by: Hash mismatch for `idna-3.6-py3-none-any.whl`
+
+    Expected:
+      sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
+
+    Computed:
+      sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "###
+    );
+
+    assert!(
+        !context
+            .temp_dir
+            .child("wheelhouse")
+            .child("idna-3.6-py3-none-any.whl")
+            .exists()
+    );
+
+    Ok(())
+}
+
+/// Install from a directory of downloaded distributions, without network access.
+#[test]
+fn download_install_offline() {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_download()
+        .arg("anyio==4.3.0")
+        .arg("-d")
+        .arg("wheelhouse")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("wheelhouse")
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+}
@@ -1960,6 +1960,93 @@ async fn install_deduplicated_indices() {
     ");
 }
 
+/// Fail over to a mirror of the index when a wheel download from the index fails.
+#[tokio::test]
+async fn install_mirror_failover() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let primary = MockServer::start().await;
+    let mirror = MockServer::start().await;
+
+    // The primary index serves the metadata, but fails to serve the wheel itself.
+    Mock::given(method("GET"))
+        .and(path("/simple/iniconfig/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            format!(
+                r#"<a href="{}/files/iniconfig-2.0.0-py3-none-any.whl" data-core-metadata="true">iniconfig-2.0.0-py3-none-any.whl</a>"#,
+                primary.uri()
+            ),
+            "text/html",
+        ))
+        .mount(&primary)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/iniconfig-2.0.0-py3-none-any.whl.metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(indoc! {"
+            Metadata-Version: 2.1
+            Name: iniconfig
+            Version: 2.0.0
+            Requires-Python: >=3.7
+        "}))
+        .mount(&primary)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/iniconfig-2.0.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&primary)
+        .await;
+
+    // The mirror serves the wheel at the same path, relative to the root of the index.
+    Mock::given(method("GET"))
+        .and(path("/files/iniconfig-2.0.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(302).insert_header(
+            "Location",
+            "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+        ))
+        .expect(1)
+        .mount(&mirror)
+        .await;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(&indoc::formatdoc! {r#"
+        [[index]]
+        name = "primary"
+        url = "{primary}/simple"
+        default = true
+        mirrors = ["{mirror}/simple"]
+        "#,
+        primary = primary.uri(),
+        mirror = mirror.uri(),
+    })?;
+
+    let primary_uri = regex::escape(&primary.uri());
+    let mirror_uri = regex::escape(&mirror.uri());
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (primary_uri.as_str(), "[PRIMARY]"),
+            (mirror_uri.as_str(), "[MIRROR]"),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Index `[PRIMARY]/simple` is unavailable; falling back to `[MIRROR]/simple`
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
 /// Install a package from a public GitHub repository
 #[test]
 #[cfg(feature = "git")]
@@ -3599,6 +3686,142 @@ fn install_constraints_respects_offline_mode() {
     );
 }
 
+/// Remote requirements files are cached, such that they can be reused when offline.
+#[tokio::test]
+async fn install_remote_requirements_txt_cached() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The file should only be fetched once.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/requirements.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("iniconfig==2.0.0"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let requirements_url = format!("{}/requirements.txt", server.uri());
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(&requirements_url), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(&requirements_url)
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Cached remote requirements files are revalidated when online, such that any changes are used.
+#[tokio::test]
+async fn install_remote_requirements_txt_revalidated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The response doesn't allow caching, so the file should be fetched on each invocation.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/requirements.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("iniconfig==1.1.1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/requirements.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("iniconfig==2.0.0"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let requirements_url = format!("{}/requirements.txt", server.uri());
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(&requirements_url), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(&requirements_url), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Read constraints files from the `[pip]` section of a `uv.toml`.
+#[test]
+fn install_constraints_from_settings() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        constraints = ["constraints.txt"]
+    "#})?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "git")]
 fn install_git_source_respects_offline_mode() {
@@ -5183,6 +5406,118 @@ fn dry_run_install() -> std::result::Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Write a pip-compatible installation report, without modifying the environment.
+#[test]
+fn dry_run_install_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio[trio]==4.3.0")
+        .arg("--no-deps")
+        .arg("--dry-run")
+        .arg("--report")
+        .arg("report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + anyio==4.3.0
+    "###
+    );
+
+    let report: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("report.json"),
+    )?)?;
+    assert_eq!(report["version"], "1");
+    assert_eq!(report["environment"]["implementation_name"], "cpython");
+
+    let install = report["install"].as_array().unwrap();
+    assert_eq!(install.len(), 1);
+
+    let anyio = &install[0];
+    assert_eq!(anyio["metadata"]["name"], "anyio");
+    assert_eq!(anyio["metadata"]["version"], "4.3.0");
+    assert_eq!(anyio["requested"], true);
+    assert_eq!(anyio["requested_extras"], serde_json::json!(["trio"]));
+    assert_eq!(anyio["is_direct"], false);
+    assert_eq!(anyio["is_yanked"], false);
+    assert_eq!(anyio["built"], false);
+    assert_eq!(
+        anyio["download_info"]["url"],
+        "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl"
+    );
+    assert_eq!(
+        anyio["download_info"]["archive_info"]["hash"],
+        "sha256=048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8"
+    );
+
+    // Nothing was installed.
+    context.assert_command("import anyio").failure();
+
+    Ok(())
+}
+
+/// Report whether each package was built or installed from the cache, omitting any packages that
+/// are already installed.
+#[test]
+fn install_report_cached_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build `iniconfig` from source.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("anyio==4.3.0")
+        .arg("--no-deps")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("--report")
+        .arg("report.json")
+        .assert()
+        .success();
+
+    let report: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("report.json"),
+    )?)?;
+    let install = report["install"].as_array().unwrap();
+    assert_eq!(install.len(), 2);
+    assert_eq!(install[0]["metadata"]["name"], "anyio");
+    assert_eq!(install[0]["cached"], false);
+    assert_eq!(install[0]["built"], false);
+    assert_eq!(install[1]["metadata"]["name"], "iniconfig");
+    assert_eq!(install[1]["cached"], false);
+    assert_eq!(install[1]["built"], true);
+
+    // Reinstall `iniconfig` from the previously built wheel; `anyio` is already installed.
+    context.pip_uninstall().arg("iniconfig").assert().success();
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("anyio==4.3.0")
+        .arg("--no-deps")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("--report")
+        .arg("report.json")
+        .assert()
+        .success();
+
+    let report: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("report.json"),
+    )?)?;
+    let install = report["install"].as_array().unwrap();
+    assert_eq!(install.len(), 1);
+    assert_eq!(install[0]["metadata"]["name"], "iniconfig");
+    assert_eq!(install[0]["cached"], true);
+    assert_eq!(install[0]["built"], false);
+
+    Ok(())
+}
+
 #[test]
 fn dry_run_install_url_dependency() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let context = TestContext::new("3.12");
@@ -6803,6 +7138,46 @@ fn require_hashes_editable() -> Result<()> {
     Ok(())
 }
 
+/// Allow reading requirements from a source tree with dynamic dependencies under
+/// `--require-hashes`, which requires building its metadata.
+#[test]
+fn require_hashes_source_tree_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dynamic = ["dependencies"]
+
+        [tool.setuptools.dynamic]
+        dependencies = { file = ["requirements.in"] }
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context.temp_dir.child("requirements.in").touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("pyproject.toml")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved in [TIME]
+    Audited in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// If a hash is only included as a constraint, that's good enough for `--require-hashes`.
 #[test]
 fn require_hashes_constraint() -> Result<()> {
@@ -3993,7 +3993,7 @@ fn require_hashes_wheel_url_mismatch() -> Result<()> {
     Ok(())
 }
 
-/// Reject Git dependencies when `--require-hashes` is provided.
+/// Validate the built wheel for a Git dependency when `--require-hashes` is provided.
 #[test]
 #[cfg(feature = "git")]
 fn require_hashes_git() -> Result<()> {
@@ -4001,9 +4001,15 @@ fn require_hashes_git() -> Result<()> {
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
     requirements_txt
-        .write_str("anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+        .write_str("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
 
-    uv_snapshot!(context.pip_sync()
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"(Computed:\n\s+)sha256:[0-9a-f]{64}", "${1}sha256:[HASH]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_sync()
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
@@ -4011,15 +4017,68 @@ fn require_hashes_git() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to download and build `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
-      ╰─▶ Hash-checking is not supported for Git repositories: `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
+    Resolved 1 package in [TIME]
+      × Failed to download and build `uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main`
+      ╰─▶ Hash mismatch for `uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main`
+
+          Expected:
+            sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+          Computed:
+            sha256:[HASH]
     "###
     );
 
     Ok(())
 }
 
-/// Reject local directory dependencies when `--require-hashes` is provided.
+/// Allow Git dependencies pinned to a full commit SHA, without a hash, when `--require-hashes` is
+/// provided.
+#[test]
+#[cfg(feature = "git")]
+fn require_hashes_git_commit() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###
+    );
+
+    // A Git dependency without a hash must be pinned to a full commit SHA.
+    requirements_txt.write_str(
+        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, Git requirements must either have a hash or be pinned to a full commit SHA, but found: uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@main
+    "###
+    );
+
+    Ok(())
+}
+
+/// Validate the built wheel for a local directory dependency when `--require-hashes` is provided.
 #[test]
 fn require_hashes_source_tree() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4033,7 +4092,13 @@ fn require_hashes_source_tree() -> Result<()> {
             .display()
     ))?;
 
-    uv_snapshot!(context.filters(), context.pip_sync()
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"(Computed:\n\s+)sha256:[0-9a-f]{64}", "${1}sha256:[HASH]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_sync()
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
@@ -4041,8 +4106,15 @@ fn require_hashes_source_tree() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
+    Resolved 1 package in [TIME]
       × Failed to build `black @ file://[WORKSPACE]/scripts/packages/black_editable`
-      ╰─▶ Hash-checking is not supported for local directories: `black @ file://[WORKSPACE]/scripts/packages/black_editable`
+      ╰─▶ Hash mismatch for `black @ file://[WORKSPACE]/scripts/packages/black_editable`
+
+          Expected:
+            sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+          Computed:
+            sha256:[HASH]
     "###
     );
 
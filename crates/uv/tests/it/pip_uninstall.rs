@@ -524,3 +524,92 @@ fn dry_run_uninstall_egg_info() -> Result<()> {
 
     Ok(())
 }
+
+/// Uninstall a package along with any dependencies that are no longer required.
+#[test]
+#[cfg(feature = "pypi")]
+fn uninstall_cascade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a project that shares a dependency (`idna`) with `anyio`.
+    let pyproject_toml = context.temp_dir.child("project").child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+requires-python = ">=3.12"
+dependencies = ["idna"]
+
+[build-system]
+requires = ["setuptools>=42"]
+build-backend = "setuptools.build_meta"
+"#,
+    )?;
+
+    context
+        .pip_install()
+        .arg("anyio==4.3.0")
+        .arg("./project")
+        .assert()
+        .success();
+
+    // Without `--cascade`, only the named package is removed.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("--dry-run")
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 1 package
+     - anyio==4.3.0
+    "###);
+
+    // With `--cascade`, `sniffio` is orphaned, but `idna` is still required by the project.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("--dry-run")
+        .arg("--cascade")
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 2 packages
+     - anyio==4.3.0
+     - sniffio==1.3.1
+    "###);
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("--cascade")
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 packages in [TIME]
+     - anyio==4.3.0
+     - sniffio==1.3.1
+    "###);
+
+    context.assert_command("import idna").success();
+    context.assert_command("import sniffio").failure();
+
+    // Uninstalling the project removes `idna`, which is no longer required by anything.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("--cascade")
+        .arg("project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 packages in [TIME]
+     - idna==3.6
+     - project==0.1.0
+    "###);
+
+    Ok(())
+}
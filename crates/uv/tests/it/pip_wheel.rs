@@ -0,0 +1,155 @@
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, list_dir, uv_snapshot};
+
+/// Pre-built wheels are written to the wheel directory as-is.
+#[test]
+fn wheel_prebuilt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("anyio==4.3.0")
+        .arg("-w")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Saved 3 wheels to wheels in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheels"))?, @r###"
+    anyio-4.3.0-py3-none-any.whl
+    idna-3.6-py3-none-any.whl
+    sniffio-1.3.1-py3-none-any.whl
+    "###);
+
+    Ok(())
+}
+
+/// Source distributions are built into wheels.
+#[test]
+fn wheel_from_source_dist() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("-w")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Saved 1 wheel to wheels in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheels"))?, @"iniconfig-2.0.0-py3-none-any.whl");
+
+    Ok(())
+}
+
+/// With `--require-hashes`, source distributions are validated before they're built.
+#[test]
+fn wheel_require_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig==2.0.0 \
+            --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("-w")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Saved 1 wheel to wheels in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheels"))?, @"iniconfig-2.0.0-py3-none-any.whl");
+
+    Ok(())
+}
+
+/// Local projects are built into wheels, along with their dependencies.
+#[test]
+fn wheel_local_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("project").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("./project")
+        .arg("-w")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Saved 2 wheels to wheels in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheels"))?, @r###"
+    iniconfig-2.0.0-py3-none-any.whl
+    project-0.1.0-py3-none-any.whl
+    "###);
+
+    // With `--no-deps`, only the project itself is built.
+    fs_err::remove_dir_all(context.temp_dir.child("wheels"))?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("./project")
+        .arg("--no-deps")
+        .arg("-w")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Saved 1 wheel to wheels in [TIME]
+    "###
+    );
+
+    assert_snapshot!(list_dir(&context.temp_dir.child("wheels"))?, @"project-0.1.0-py3-none-any.whl");
+
+    Ok(())
+}
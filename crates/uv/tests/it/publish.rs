@@ -1,10 +1,15 @@
 use crate::common::{TestContext, uv_snapshot, venv_bin_path};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild};
-use indoc::indoc;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use indoc::{formatdoc, indoc};
+use serde_json::json;
 use std::env;
 use std::env::current_dir;
 use uv_static::EnvVars;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn username_password_no_longer_supported() {
@@ -113,6 +118,34 @@ fn missing_trusted_publishing_permission() {
     );
 }
 
+/// Emulate a GitLab CI job without an `id_tokens` entry for PyPI.
+#[test]
+fn missing_gitlab_id_token() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--trusted-publishing")
+        .arg("always")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        // Emulate CI
+        .env(EnvVars::GITLAB_CI, "true")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        // Just to make sure
+        .env_remove("TESTPYPI_ID_TOKEN"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to https://test.pypi.org/legacy/
+    error: Failed to obtain token for trusted publishing
+      Caused by: Environment variable TESTPYPI_ID_TOKEN not set, is the `id_tokens` entry with `aud: testpypi` missing from the GitLab CI job?
+    "###
+    );
+}
+
 /// Check the error when there are no credentials provided on GitHub Actions. Is it an incorrect
 /// trusted publishing configuration?
 #[test]
@@ -143,6 +176,30 @@ fn no_credentials() {
     );
 }
 
+/// Validate a file without credentials and without uploading it.
+#[test]
+fn check() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--check")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checking 1 file
+    Passed ok-1.0.0-py3-none-any.whl
+      warning: The summary is missing
+      warning: The long description is missing
+    "###
+    );
+}
+
 /// Hint people that it's not `--skip-existing` but `--check-url`.
 #[test]
 fn skip_existing_redirect() {
@@ -386,3 +443,451 @@ fn invalid_index() {
     "###
     );
 }
+
+/// Indexes other than PyPI don't support attestations, so the files are uploaded without them.
+#[tokio::test]
+async fn attest_unsupported_index() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let server_uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(server_uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.publish()
+        .arg("--token")
+        .arg("dummy")
+        .arg("--attest")
+        .arg("--publish-url")
+        .arg(format!("{}/legacy/", server.uri()))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: [SERVER]/legacy/ does not support publish attestations (PEP 740), uploading without attestations
+    Publishing 1 file to [SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(!String::from_utf8_lossy(&requests[0].body).contains(r#"name="attestations""#));
+}
+
+/// A signing certificate from Fulcio that doesn't chain to the Sigstore trusted root is rejected
+/// before anything is signed or uploaded.
+#[tokio::test]
+async fn attest_untrusted_certificate() {
+    let context = TestContext::new("3.12");
+
+    // A code signing certificate issued by a test certificate authority.
+    let certificate = fs_err::read_to_string("../uv-publish/test-data/sigstore/leaf.pem").unwrap();
+    // A GitLab CI ID token with the subject `test`.
+    let id_token = "e30.eyJzdWIiOiJ0ZXN0In0.c2ln";
+
+    let fulcio = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v2/signingCert"))
+        .and(header("Authorization", format!("Bearer {id_token}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "signedCertificateEmbeddedSct": {
+                "chain": { "certificates": [certificate] },
+            },
+        })))
+        .expect(1)
+        .mount(&fulcio)
+        .await;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--token")
+        .arg("dummy")
+        .arg("--attest")
+        .arg("--fulcio-url")
+        .arg(fulcio.uri())
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        // Emulate CI
+        .env(EnvVars::GITLAB_CI, "true")
+        .env(EnvVars::SIGSTORE_ID_TOKEN, id_token)
+        .env_remove(EnvVars::GITHUB_ACTIONS), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to generate publish attestations
+      Caused by: The signing certificate from Fulcio doesn't chain to the Sigstore trusted root
+      Caused by: UnknownIssuer
+    "
+    );
+}
+
+/// When publishing to multiple indexes, each index only receives its own credentials.
+#[tokio::test]
+async fn multiple_indexes_credentials() {
+    let context = TestContext::new("3.12");
+
+    let server_a = MockServer::start().await;
+    let server_b = MockServer::start().await;
+    for server in [&server_a, &server_b] {
+        Mock::given(method("POST"))
+            .and(path("/legacy/"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(server)
+            .await;
+    }
+
+    let pyproject_toml = formatdoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+
+        [[tool.uv.index]]
+        name = "a"
+        url = "{a}/simple/"
+        publish-url = "{a}/legacy/"
+
+        [[tool.uv.index]]
+        name = "b"
+        url = "{b}/simple/"
+        publish-url = "{b}/legacy/"
+        "#,
+        a = server_a.uri(),
+        b = server_b.uri(),
+    };
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject_toml)
+        .unwrap();
+
+    let ok_wheel = current_dir()
+        .unwrap()
+        .join("../../scripts/links/ok-1.0.0-py3-none-any.whl");
+
+    let server_a_uri = server_a.uri();
+    let server_b_uri = server_b.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (server_a_uri.as_str(), "[SERVER_A]"),
+            (server_b_uri.as_str(), "[SERVER_B]"),
+        ])
+        .collect::<Vec<_>>();
+
+    // Global credentials would be sent to both indexes.
+    uv_snapshot!(filters, context.publish()
+        .arg("--token")
+        .arg("dummy")
+        .arg("--index")
+        .arg("a")
+        .arg("--index")
+        .arg("b")
+        .arg(&ok_wheel)
+        .current_dir(context.temp_dir.path()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--username`, `--password`, and `--token` can't be used when publishing to multiple indexes, use `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD` to provide credentials for each index
+    "
+    );
+
+    uv_snapshot!(filters, context.publish()
+        .arg("--index")
+        .arg("a")
+        .arg("--index")
+        .arg("b")
+        .arg(&ok_wheel)
+        .env("UV_INDEX_A_USERNAME", "__token__")
+        .env("UV_INDEX_A_PASSWORD", "token-a")
+        .env("UV_INDEX_B_USERNAME", "__token__")
+        .env("UV_INDEX_B_PASSWORD", "token-b")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI)
+        .current_dir(context.temp_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to [SERVER_A]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    Publishing 1 file to [SERVER_B]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+
+    // Every request, including the checks for existing files, only carries the index's own token.
+    for (server, token, other_token) in [
+        (&server_a, "token-a", "token-b"),
+        (&server_b, "token-b", "token-a"),
+    ] {
+        let requests = server.received_requests().await.unwrap();
+        let authorizations: Vec<String> = requests
+            .iter()
+            .filter_map(|request| request.headers.get("authorization"))
+            .map(|value| {
+                let encoded = value.to_str().unwrap().strip_prefix("Basic ").unwrap();
+                String::from_utf8(BASE64_STANDARD.decode(encoded).unwrap()).unwrap()
+            })
+            .collect();
+        assert!(
+            requests
+                .iter()
+                .any(|request| request.method.as_str() == "POST")
+        );
+        assert!(authorizations.contains(&format!("__token__:{token}")));
+        assert!(
+            !authorizations
+                .iter()
+                .any(|authorization| authorization.contains(other_token))
+        );
+    }
+}
+
+/// A failed upload doesn't stop the other files from being uploaded, and retrying only uploads the
+/// files the registry didn't accept.
+#[tokio::test]
+async fn failed_upload_resume() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .and(|request: &wiremock::Request| {
+            let filename = b"ok-1.0.0-py3-none-any.whl";
+            request
+                .body
+                .windows(filename.len())
+                .any(|window| window == filename)
+        })
+        .respond_with(ResponseTemplate::new(400).set_body_string("Invalid file"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let server_uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(server_uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.publish()
+        .arg("--token")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg(format!("{}/legacy/", server.uri()))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/ok-2.0.0-py3-none-any.whl")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 2 files [SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-2.0.0-py3-none-any.whl ([SIZE])
+    error: Failed to publish `../../scripts/links/ok-1.0.0-py3-none-any.whl` to [SERVER]/legacy/
+      Caused by: Upload failed with status code 400 Bad Request. Server says: Invalid file
+    "
+    );
+
+    uv_snapshot!(filters, context.publish()
+        .arg("--token")
+        .arg("dummy")
+        .arg("--concurrent-uploads")
+        .arg("2")
+        .arg("--publish-url")
+        .arg(format!("{}/legacy/", server.uri()))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/ok-2.0.0-py3-none-any.whl")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 2 files [SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    File ok-2.0.0-py3-none-any.whl was already uploaded, skipping
+    "
+    );
+
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[test]
+fn yank_requires_index() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("yank")
+        .arg("foo")
+        .arg("1.0.0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --index <INDEX>
+
+    Usage: uv yank --index <INDEX> <PACKAGE> <VERSION>
+
+    For more information, try '--help'.
+    "
+    );
+}
+
+/// Yank releases on indexes with and without support for yanking.
+#[tokio::test]
+async fn yank() {
+    let context = TestContext::new("3.12");
+
+    let supported = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&supported)
+        .await;
+    let unsupported = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .respond_with(ResponseTemplate::new(405))
+        .mount(&unsupported)
+        .await;
+
+    let pyproject_toml = formatdoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+
+        [[tool.uv.index]]
+        name = "supported"
+        url = "{supported}/simple/"
+        publish-url = "{supported}/legacy/"
+
+        [[tool.uv.index]]
+        name = "unsupported"
+        url = "{unsupported}/simple/"
+        publish-url = "{unsupported}/legacy/"
+
+        [[tool.uv.index]]
+        name = "pypi"
+        url = "https://pypi.org/simple/"
+        publish-url = "https://upload.pypi.org/legacy/"
+        "#,
+        supported = supported.uri(),
+        unsupported = unsupported.uri(),
+    };
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject_toml)
+        .unwrap();
+
+    let supported_uri = supported.uri();
+    let unsupported_uri = unsupported.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (supported_uri.as_str(), "[SUPPORTED]"),
+            (unsupported_uri.as_str(), "[UNSUPPORTED]"),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.command()
+        .arg("yank")
+        .arg("foo")
+        .arg("1.0.0")
+        .arg("--reason")
+        .arg("Broken on Windows")
+        .arg("--index")
+        .arg("supported")
+        .arg("--token")
+        .arg("dummy")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Yanked foo==1.0.0 on [SUPPORTED]/legacy/
+    "
+    );
+
+    let requests = supported.received_requests().await.unwrap();
+    let body = String::from_utf8_lossy(&requests[0].body);
+    assert!(body.contains("name=\":action\"\r\n\r\nyank\r\n"), "{body}");
+    assert!(
+        body.contains("name=\"yanked_reason\"\r\n\r\nBroken on Windows\r\n"),
+        "{body}"
+    );
+
+    uv_snapshot!(filters, context.command()
+        .arg("yank")
+        .arg("foo")
+        .arg("1.0.0")
+        .arg("--index")
+        .arg("unsupported")
+        .arg("--token")
+        .arg("dummy")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: [UNSUPPORTED]/legacy/ doesn't support yanking releases through its upload API, use the index's web interface instead
+    "
+    );
+
+    // PyPI only supports yanking through its web interface, so we don't send a request.
+    uv_snapshot!(filters, context.command()
+        .arg("yank")
+        .arg("foo")
+        .arg("1.0.0")
+        .arg("--index")
+        .arg("pypi")
+        .arg("--token")
+        .arg("dummy")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        .env_remove(EnvVars::GITLAB_CI), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: https://upload.pypi.org/legacy/ doesn't support yanking releases through its upload API, use the index's web interface instead
+    "
+    );
+}
@@ -1013,6 +1013,27 @@ fn python_find_show_version() {
     ");
 }
 
+#[test]
+fn python_find_output_format_json() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context
+        .python_find()
+        .arg("--output-format")
+        .arg("json")
+        .arg(">=3.12,<3.13")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(data["version"].as_str().unwrap().starts_with("3.12."));
+    assert_eq!(data["implementation"], "cpython");
+    assert_eq!(data["variant"], "default");
+    assert!(data["path"].is_string());
+    assert!(data["source"].is_string());
+}
+
 #[test]
 fn python_find_path() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_not_executable();
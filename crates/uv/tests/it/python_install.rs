@@ -1180,6 +1180,60 @@ fn python_install_freethreaded() {
     ");
 }
 
+#[test]
+fn python_install_graalpy_release() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install GraalPy by its release version, rather than its Python version
+    uv_snapshot!(context.filters(), context.python_install().arg("graalpy@24.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.0 in [TIME]
+     + graalpy-3.10.0-[PLATFORM] (python3.10)
+    ");
+
+    // Should be a no-op when already installed
+    uv_snapshot!(context.filters(), context.python_install().arg("graalpy@24.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut filters = context.filters();
+    filters.push((
+        r"\[TEMP_DIR\]/managed/graalpy-3\.10\.0-\[PLATFORM\]/\S+",
+        "[TEMP_DIR]/managed/graalpy-3.10.0-[PLATFORM]/[GRAALPY]",
+    ));
+
+    // The installation can be found by its release version
+    uv_snapshot!(filters, context.python_find().arg("graalpy@24.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/managed/graalpy-3.10.0-[PLATFORM]/[GRAALPY]
+
+    ----- stderr -----
+    ");
+
+    // But not by a release version it doesn't match
+    uv_snapshot!(filters, context.python_find().arg("graalpy@24.2"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for GraalPy 24.2 in virtual environments, managed installations, or search path
+    ");
+}
+
 #[test]
 fn python_install_invalid_request() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -2810,13 +2864,30 @@ fn uninstall_last_patch() {
     "
     );
 
+    // The virtual environment depends on the last patch version, so uninstalling should fail.
     uv_snapshot!(context.filters(), context.python_uninstall().arg("--preview").arg("3.10.17"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.10.17
+    error: The following environment would be broken by this uninstall:
+    - .venv (cpython-3.10.17-[PLATFORM])
+
+    hint: Use `--force` to uninstall anyway, then recreate the environment
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--preview").arg("--force").arg("3.10.17"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Searching for Python versions matching: Python 3.10.17
+    warning: The following environment will be broken by this uninstall and must be recreated:
+    - .venv (cpython-3.10.17-[PLATFORM])
     Uninstalled Python 3.10.17 in [TIME]
      - cpython-3.10.17-[PLATFORM] (python3.10)
     "
@@ -2850,3 +2921,80 @@ fn uninstall_last_patch() {
     "#
     );
 }
+
+/// Python versions installed into a shared directory should be discoverable by other users via
+/// `UV_PYTHON_SYSTEM_INSTALL_DIR`.
+#[test]
+fn python_install_system_dir() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let system = context.temp_dir.child("system");
+    system.create_dir_all().unwrap();
+
+    // Install into the shared directory.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--install-dir").arg(system.path())
+        .arg("--no-bin")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     + cpython-3.12.11-[PLATFORM]
+    ");
+
+    let context = context
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names();
+
+    // Without opt-in, the shared directory isn't searched.
+    uv_snapshot!(context.filters(), context.python_find().arg("--managed-python").arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12 in virtual environments or managed installations
+    ");
+
+    // With `UV_PYTHON_SYSTEM_INSTALL_DIR`, it's found as a managed installation.
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("--managed-python")
+        .arg("3.12")
+        .env(EnvVars::UV_PYTHON_SYSTEM_INSTALL_DIR, system.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/system/cpython-3.12.11-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
+
+    ----- stderr -----
+    ");
+
+    // Installations in the user directory are preferred.
+    uv_snapshot!(context.filters(), context.python_install().arg("--no-bin").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     + cpython-3.12.11-[PLATFORM]
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("--managed-python")
+        .arg("3.12")
+        .env(EnvVars::UV_PYTHON_SYSTEM_INSTALL_DIR, system.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/managed/cpython-3.12.11-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
+
+    ----- stderr -----
+    ");
+}
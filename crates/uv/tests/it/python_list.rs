@@ -404,6 +404,40 @@ fn python_list_downloads() {
     ");
 }
 
+#[test]
+fn python_list_downloads_graalpy_release() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    // GraalPy can be requested by its release version, rather than its Python version
+    uv_snapshot!(context.filters(), context.python_list().arg("graalpy@24").env_remove("UV_PYTHON_DOWNLOADS"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    graalpy-3.11.0-[PLATFORM]    <download available>
+    graalpy-3.10.0-[PLATFORM]    <download available>
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_list().arg("graalpy24.0").env_remove("UV_PYTHON_DOWNLOADS"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    graalpy-3.10.0-[PLATFORM]    <download available>
+
+    ----- stderr -----
+    ");
+
+    // Release versions only apply to GraalPy requests
+    uv_snapshot!(context.filters(), context.python_list().arg("24.0").env_remove("UV_PYTHON_DOWNLOADS"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 #[cfg(feature = "python-managed")]
 fn python_list_downloads_installed() {
@@ -478,3 +512,60 @@ fn python_list_downloads_installed() {
     ----- stderr -----
     ");
 }
+
+/// The JSON output should include the checksum of each download, if known.
+#[test]
+fn python_list_downloads_json() -> anyhow::Result<()> {
+    use assert_fs::prelude::*;
+
+    let context: TestContext = TestContext::new_with_versions(&[]);
+
+    let downloads = context.temp_dir.child("downloads.json");
+    downloads.write_str(indoc::indoc! {r#"
+        {
+            "cpython-3.13.0-linux-x86_64-gnu": {
+                "name": "cpython",
+                "arch": { "family": "x86_64", "variant": null },
+                "os": "linux",
+                "libc": "gnu",
+                "major": 3,
+                "minor": 13,
+                "patch": 0,
+                "prerelease": null,
+                "url": "https://example.com/cpython-3.13.0-linux-x86_64-gnu.tar.gz",
+                "sha256": "dcfb420af1aa99d91db6e5d318abff5779abe3e2f743da0da4eca921910caeaf",
+                "variant": null
+            },
+            "cpython-3.12.0-linux-x86_64-gnu": {
+                "name": "cpython",
+                "arch": { "family": "x86_64", "variant": null },
+                "os": "linux",
+                "libc": "gnu",
+                "major": 3,
+                "minor": 12,
+                "patch": 0,
+                "prerelease": null,
+                "url": "https://example.com/cpython-3.12.0-linux-x86_64-gnu.tar.gz",
+                "sha256": null,
+                "variant": null
+            }
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.python_list()
+        .arg("--only-downloads")
+        .arg("--all-platforms")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--python-downloads-json-url")
+        .arg(downloads.path()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"key":"cpython-3.13.0-linux-x86_64-gnu","version":"3.13.0","version_parts":{"major":3,"minor":13,"patch":0},"path":null,"symlink":null,"url":"https://example.com/cpython-3.13.0-linux-x86_64-gnu.tar.gz","sha256":"dcfb420af1aa99d91db6e5d318abff5779abe3e2f743da0da4eca921910caeaf","os":"linux","variant":"default","implementation":"cpython","arch":"x86_64","libc":"gnu"},{"key":"cpython-3.12.0-linux-x86_64-gnu","version":"3.12.0","version_parts":{"major":3,"minor":12,"patch":0},"path":null,"symlink":null,"url":"https://example.com/cpython-3.12.0-linux-x86_64-gnu.tar.gz","sha256":null,"os":"linux","variant":"default","implementation":"cpython","arch":"x86_64","libc":"gnu"}]
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
@@ -701,3 +701,182 @@ fn python_upgrade_force_install() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn python_upgrade_transparent_from_venv_pypy() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install an earlier patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("--no-bin").arg("pypy3.10.14"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.14 in [TIME]
+     + pypy-3.10.14-[PLATFORM]
+    ");
+
+    // Create a virtual environment
+    uv_snapshot!(context.filters(), context.venv().arg("-p").arg("pypy3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using PyPy 3.10.14
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    ");
+
+    // Upgrade patch version
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("pypy3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.16 in [TIME]
+     + pypy-3.10.16-[PLATFORM]
+    ");
+
+    // The virtual environment should reflect the upgraded patch
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg("import platform; print(platform.python_version())"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.10.16
+
+    ----- stderr -----
+    "
+    );
+
+    // Should be a no-op when already upgraded
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("pypy3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn python_upgrade_transparent_from_venv_graalpy() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install an earlier patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("--no-bin").arg("graalpy3.8.2"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.8.2 in [TIME]
+     + graalpy-3.8.2-[PLATFORM]
+    ");
+
+    // Create a virtual environment
+    uv_snapshot!(context.filters(), context.venv().arg("-p").arg("graalpy3.8"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using GraalPy 3.8.2
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    ");
+
+    // Upgrading without a version should include GraalPy installations
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.8.5 in [TIME]
+     + graalpy-3.8.5-[PLATFORM]
+    ");
+
+    // The virtual environment should reflect the upgraded patch
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("-c").arg("import platform; print(platform.python_version())"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.8.5
+
+    ----- stderr -----
+    "
+    );
+}
+
+#[test]
+fn python_upgrade_dry_run_and_policy() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install an earlier patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.10.17"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.17 in [TIME]
+     + cpython-3.10.17-[PLATFORM] (python3.10)
+    ");
+
+    // Report the patch version that would be installed
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("--dry-run").arg("3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would upgrade 1 version
+     + cpython-3.10.18-[PLATFORM] (from 3.10.17)
+    ");
+
+    // Only patch upgrades are supported
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("--policy").arg("minor").arg("3.10"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'minor' for '--policy <POLICY>'
+      [possible values: patch]
+
+    For more information, try '--help'.
+    ");
+
+    // The dry run should not have installed anything
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("--policy").arg("patch").arg("3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.18 in [TIME]
+     + cpython-3.10.18-[PLATFORM] (python3.10)
+    ");
+
+    // Nothing is left to upgrade
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("--dry-run").arg("3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would not upgrade any versions
+    ");
+}
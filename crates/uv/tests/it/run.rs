@@ -10,6 +10,8 @@ use std::path::Path;
 use uv_fs::copy_dir_all;
 use uv_python::PYTHON_VERSION_FILENAME;
 use uv_static::EnvVars;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use crate::common::{TestContext, uv_snapshot};
 
@@ -263,6 +265,225 @@ fn run_no_args() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_tasks() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv.tasks]
+        hello = ["python", "-c", "import sys; print('hello', *sys.argv[1:])"]
+        greet = { cmd = "python -c \"print('greet')\"", depends-on = ["hello"] }
+        cycle = { cmd = "python", depends-on = ["cycle"] }
+        "#
+    })?;
+
+    // Dependencies run first, and arguments are only passed to the requested task.
+    uv_snapshot!(context.filters(), context.run().arg("greet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+    greet
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+    [hello] python -c import sys; print('hello', *sys.argv[1:])
+    [greet] python -c print('greet')
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("hello").arg("world"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello world
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    [hello] python -c import sys; print('hello', *sys.argv[1:]) world
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("cycle"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    error: Tasks have a cyclic dependency: `cycle` -> `cycle`
+    "###);
+
+    Ok(())
+}
+
+/// The tasks of the workspace root are inherited by its members, and run in the member directory.
+#[test]
+fn run_workspace_tasks() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.tasks]
+        cwd = ["python", "-c", "import os; print(os.path.basename(os.getcwd()))"]
+        hello = "python -c \"print('hello from the workspace')\""
+        "#
+        })?;
+
+    context
+        .temp_dir
+        .child("packages")
+        .child("foo")
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+            [project]
+            name = "foo"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+        })?;
+
+    // Override the `hello` task in `bar`.
+    context
+        .temp_dir
+        .child("packages")
+        .child("bar")
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+            [project]
+            name = "bar"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [tool.uv.tasks]
+            hello = "python -c \"print('hello from bar')\""
+            "#
+        })?;
+
+    // Inherited tasks run in the directory of the selected member.
+    uv_snapshot!(context.filters(), context.run().arg("--package").arg("foo").arg("cwd"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    [cwd] python -c import os; print(os.path.basename(os.getcwd()))
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--package").arg("foo").arg("hello"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello from the workspace
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    [hello] python -c print('hello from the workspace')
+    "###);
+
+    // A member can override a workspace task.
+    uv_snapshot!(context.filters(), context.run().arg("--package").arg("bar").arg("hello"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello from bar
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    [hello] python -c print('hello from bar')
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn run_complete() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cli = ["click"]
+
+        [project.scripts]
+        foo = "foo:main"
+
+        [project.gui-scripts]
+        foo-gui = "foo:gui"
+
+        [dependency-groups]
+        lint = ["ruff"]
+
+        [tool.uv.tasks]
+        hello = ["python", "-c", "print('hello')"]
+        "#
+    })?;
+
+    // Entry points and tasks are offered as commands for `uv run`.
+    uv_snapshot!(context.filters(), context.command().arg("complete").arg("run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo
+    foo-gui
+    hello
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("complete").arg("extra"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cli
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("complete").arg("group"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lint
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 /// Run a PEP 723-compatible script. The script should take precedence over the workspace
 /// dependencies.
 #[test]
@@ -1043,6 +1264,78 @@ fn run_pep723_script_lock() -> Result<()> {
     Ok(())
 }
 
+/// With `lock-scripts = true`, running a PEP 723-compatible script should create a lockfile.
+#[test]
+fn run_pep723_script_lock_scripts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("lock-scripts = true")?;
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print("Hello, world!")
+       "#
+    })?;
+
+    // With `--locked`, the lockfile should not be created.
+    uv_snapshot!(context.filters(), context.run().arg("--locked").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    warning: No lockfile found for Python script (ignoring `--locked`); run `uv lock --script` to generate a lockfile
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    assert!(!context.temp_dir.child("main.py.lock").exists());
+
+    // Otherwise, the lockfile should be created.
+    uv_snapshot!(context.filters(), context.run().arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    assert!(context.temp_dir.child("main.py.lock").exists());
+
+    // The lockfile should now be respected by `uv sync --script --locked`.
+    uv_snapshot!(context.filters(), context.sync().arg("--script").arg("main.py").arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using script environment at: [CACHE_DIR]/environments-v2/script-[HASH]
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// With `managed = false`, we should avoid installing the project itself.
 #[test]
 fn run_managed_false() -> Result<()> {
@@ -1738,6 +2031,74 @@ fn run_in_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Run a command in every workspace member with `--all-packages --parallel`.
+#[test]
+fn run_all_packages_parallel() -> Result<()> {
+    use indoc::formatdoc;
+
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [tool.uv.workspace]
+        members = ["child1", "child2"]
+        "#
+        })?;
+    for name in ["child1", "child2"] {
+        context
+            .temp_dir
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! { r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+            })?;
+    }
+
+    // The command is run from each member's directory, and fails in `child2`.
+    let output = context
+        .run()
+        .arg("--all-packages")
+        .arg("--parallel")
+        .arg("python")
+        .arg("-c")
+        .arg("import os, sys; name = os.path.basename(os.getcwd()); print(f'Hello from {name}'); sys.exit(name == 'child2')")
+        .output()?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(!output.status.success());
+    assert!(stdout.contains("child1 | Hello from child1"), "{stdout}");
+    assert!(stdout.contains("child2 | Hello from child2"), "{stdout}");
+    assert!(
+        stderr.contains("error: Command failed in 1 of 2 packages:\n- child2"),
+        "{stderr}"
+    );
+
+    // The number of commands running at once is bounded by the build concurrency. Each command
+    // fails if another command holds the marker file.
+    let output = context
+        .run()
+        .env(EnvVars::UV_CONCURRENT_BUILDS, "1")
+        .arg("--all-packages")
+        .arg("--parallel")
+        .arg("python")
+        .arg("-c")
+        .arg("import os, time; fd = os.open('../running', os.O_CREAT | os.O_EXCL); time.sleep(1); os.close(fd); os.remove('../running')")
+        .output()?;
+
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    Ok(())
+}
+
 #[test]
 fn run_with_editable() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3821,6 +4182,41 @@ fn run_gui_script_explicit_unix() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(windows))]
+fn run_gui_unix() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let test_script = context.temp_dir.child("script.py");
+    test_script.write_str(indoc! { r#"
+        import sys
+        import os
+
+        executable = os.path.basename(sys.executable).lower()
+        print(f"Using executable: {executable}", file=sys.stderr)
+    "#})?;
+
+    // Without `pythonw`, GUI applications fall back to `python`.
+    uv_snapshot!(context.filters(), context.run().arg("--gui").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using executable: python
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--gui").arg("python").arg("script.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using executable: python
+    "###);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(unix)]
 fn run_linked_environment_path() -> Result<()> {
@@ -4169,6 +4565,121 @@ fn run_remote_pep723_script() {
     "###);
 }
 
+/// Run a remote script pinned to a hash.
+#[tokio::test]
+async fn run_remote_script_hash() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/main.py"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("print(\"Hello, world!\")\n"))
+        // Once the script has been verified, it's served from the cache.
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let url = format!("{}/main.py", server.uri());
+    let server_uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(server_uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    // The hash doesn't match, so the script isn't run or cached.
+    uv_snapshot!(filters, context.run()
+        .arg("--hash")
+        .arg("sha256:0000000000000000000000000000000000000000000000000000000000000000")
+        .arg(&url), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash mismatch for remote script `[SERVER]/main.py`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673
+    ");
+
+    // The hash matches.
+    uv_snapshot!(filters, context.run()
+        .arg("--hash")
+        .arg("sha256:51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673")
+        .arg(&url), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    // The script is read from the cache, without another request.
+    uv_snapshot!(filters, context.run()
+        .arg("--hash")
+        .arg("sha256:51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673")
+        .arg(&url), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    server.verify().await;
+
+    // The cached script is used even if the remote script changes.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/main.py"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("print(\"Goodbye, world!\")\n"))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(filters, context.run()
+        .arg("--hash")
+        .arg("sha256:51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673")
+        .arg(&url), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn run_hash_local_script() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("main.py")
+        .write_str(r#"print("Hello, world!")"#)?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--hash")
+        .arg("sha256:51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673")
+        .arg("main.py"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--hash` can only be used with remote scripts
+    ");
+
+    Ok(())
+}
+
 #[cfg(unix)] // A URL could be a valid filepath on Unix but not on Windows
 #[test]
 fn run_url_like_with_local_file_priority() -> Result<()> {
@@ -4315,6 +4826,56 @@ fn run_with_env_file() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_with_env_overrides() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("test.py").write_str(indoc! { "
+        import os
+        print(os.environ.get('THE_EMPIRE_VARIABLE'))
+        print(os.environ.get('REBEL_1'))
+        print(os.environ.get('REBEL_2'))
+       "
+    })?;
+
+    context.temp_dir.child(".file").write_str(indoc! { "
+        THE_EMPIRE_VARIABLE=palpatine
+        REBEL_1=leia_organa
+       "
+    })?;
+
+    // `--env` and `--unset` take precedence over the `.env` file and the environment.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--env-file").arg(".file")
+        .arg("--env").arg("THE_EMPIRE_VARIABLE=vader")
+        .arg("--unset").arg("REBEL_1")
+        .arg("--env").arg("REBEL_2=han=solo")
+        .arg("test.py")
+        .env("REBEL_2", "C3PO"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    vader
+    None
+    han=solo
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--env").arg("=palpatine").arg("test.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '=palpatine' for '--env <KEY=VALUE>': Invalid environment variable: `=palpatine` (expected `KEY=VALUE`)
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_with_multiple_env_files() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4448,6 +5009,68 @@ fn run_with_not_existing_env_file() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_with_configured_env_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("test.py").write_str(indoc! { "
+        import os
+        print(os.environ.get('THE_EMPIRE_VARIABLE'))
+        print(os.environ.get('REBEL_1'))
+       "
+    })?;
+
+    context.temp_dir.child(".env").write_str(indoc! { "
+        THE_EMPIRE_VARIABLE=palpatine
+        REBEL_1=leia_organa
+       "
+    })?;
+
+    context.temp_dir.child(".env.local").write_str(indoc! { "
+        REBEL_1=obi_wan_kenobi
+       "
+    })?;
+
+    // Missing files are skipped, and later files take precedence.
+    context.temp_dir.child("uv.toml").write_str(indoc! { r#"
+        env-file = [".env", ".env.missing", ".env.local"]
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("test.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    palpatine
+    obi_wan_kenobi
+
+    ----- stderr -----
+    "###);
+
+    // The files provided on the command line replace the configured files.
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env.local").arg("test.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+    obi_wan_kenobi
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-env-file").arg("test.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+    None
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_with_extra_conflict() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5719,3 +6342,77 @@ fn run_python_preference_no_project() {
     ----- stderr -----
     ");
 }
+
+/// Run a command in a container, with a fake container engine that records its arguments.
+#[test]
+#[cfg(unix)]
+fn run_container() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+        "#
+    })?;
+
+    // The image is for another platform, and doesn't provide `uv`.
+    let engine = context.temp_dir.child("docker");
+    engine.write_str(indoc! { r#"
+        #!/bin/sh
+        if [ "$1" = "image" ]; then
+            echo "linux/unknown"
+            exit 0
+        fi
+        printf '%s\n' "$@" > "$(dirname "$0")/arguments.txt"
+        exit 127
+        "#
+    })?;
+    fs_err::set_permissions(&engine, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .env(EnvVars::UV_CONTAINER_ENGINE, engine.path())
+        .env("UV_INDEX_INTERNAL_PASSWORD", "secret")
+        .arg("--container")
+        .arg("python:3.12-slim")
+        .arg("python")
+        .arg("-c")
+        .arg("pass"), @r"
+    success: false
+    exit_code: 127
+    ----- stdout -----
+
+    ----- stderr -----
+    hint: The image `python:3.12-slim` must provide `uv` to be used with `--container`
+    ");
+
+    let arguments = fs_err::read_to_string(context.temp_dir.child("arguments.txt"))?;
+    let arguments = arguments.lines().collect::<Vec<_>>();
+
+    // The container runs as the current user.
+    let user = arguments.iter().position(|arg| *arg == "--user").unwrap();
+    assert!(arguments[user + 1].contains(':'));
+
+    // uv's environment variables are forwarded by name, without their values.
+    assert!(
+        arguments
+            .windows(2)
+            .any(|args| args == ["--env", "UV_INDEX_INTERNAL_PASSWORD"])
+    );
+    assert!(!arguments.iter().any(|arg| arg.contains("secret")));
+
+    // The host's uv executable isn't used for an image of another platform.
+    assert!(!arguments.iter().any(|arg| arg.contains("/opt/uv-host/uv")));
+    let image = arguments
+        .iter()
+        .position(|arg| *arg == "python:3.12-slim")
+        .unwrap();
+    assert_eq!(arguments[image + 1], "uv");
+
+    Ok(())
+}
@@ -64,6 +64,20 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -86,6 +100,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -144,6 +159,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -154,6 +180,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -197,6 +224,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -253,6 +283,20 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -275,6 +319,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -333,6 +378,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -343,6 +399,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -386,6 +443,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -443,6 +503,20 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -465,6 +539,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -523,6 +598,17 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -533,6 +619,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -576,6 +663,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -665,6 +755,20 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -687,6 +791,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -745,6 +850,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -755,6 +871,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -798,6 +915,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -856,6 +976,20 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -878,6 +1012,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -912,6 +1047,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -955,6 +1091,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1023,6 +1162,20 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1045,6 +1198,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -1103,6 +1257,17 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -1113,6 +1278,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1156,6 +1322,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1239,6 +1408,20 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1261,6 +1444,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -1319,6 +1503,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -1352,6 +1547,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -1362,6 +1568,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1405,6 +1612,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1463,6 +1673,20 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1485,6 +1709,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -1545,6 +1770,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -1578,6 +1814,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -1611,6 +1858,17 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -1621,6 +1879,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1664,6 +1923,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1745,6 +2007,20 @@ fn resolve_find_links() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1767,6 +2043,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -1826,6 +2103,17 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 no_index: true,
@@ -1835,6 +2123,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1878,6 +2167,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -1958,6 +2250,20 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1980,6 +2286,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -2014,6 +2321,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2057,6 +2365,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2130,6 +2441,20 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2152,6 +2477,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -2210,6 +2536,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -2243,6 +2580,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -2253,6 +2601,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2296,6 +2645,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2352,6 +2704,20 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2374,6 +2740,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -2432,6 +2799,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -2465,6 +2843,17 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -2475,6 +2864,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2518,6 +2908,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2597,6 +2990,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2619,6 +3026,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -2653,6 +3061,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2696,6 +3105,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2759,6 +3171,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2781,6 +3207,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -2815,6 +3242,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2858,6 +3286,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -2921,6 +3352,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2943,6 +3388,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -2977,6 +3423,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3020,6 +3467,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3085,6 +3535,20 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3107,6 +3571,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -3141,6 +3606,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3184,6 +3650,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3268,6 +3737,20 @@ fn resolve_tool() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3290,6 +3773,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3323,6 +3807,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             prerelease: None,
             fork_strategy: None,
             dependency_metadata: None,
+            build_system_overrides: None,
             config_settings: None,
             config_settings_package: None,
             no_build_isolation: None,
@@ -3348,6 +3833,9 @@ fn resolve_tool() -> anyhow::Result<()> {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -3379,10 +3867,12 @@ fn resolve_tool() -> anyhow::Result<()> {
         },
         force: false,
         editable: false,
+        data_files: false,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
     }
 
@@ -3441,6 +3931,20 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3463,6 +3967,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -3497,6 +4002,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3540,6 +4046,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3637,6 +4146,20 @@ fn resolve_both() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3659,6 +4182,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -3717,6 +4241,17 @@ fn resolve_both() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -3727,6 +4262,7 @@ fn resolve_both() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3770,6 +4306,9 @@ fn resolve_both() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -3871,6 +4410,20 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3893,6 +4446,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -3951,6 +4505,17 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -3961,6 +4526,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4004,6 +4570,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4184,6 +4753,20 @@ fn resolve_config_file() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4206,6 +4789,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -4264,6 +4848,17 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -4274,6 +4869,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4317,6 +4913,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4384,7 +4983,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `cache-compression`, `cache-environment-max-age`, `cache-environment-max-size`, `cache-environment-max-count`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `limit-rate`, `proxy`, `http-retries`, `http-retry-base-delay`, `http-retry-max-delay`, `http-retry-jitter`, `http-retry-status-codes`, `strict-index-tracking`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `build-system-overrides`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `python-install-require-attestations`, `publish-url`, `trusted-publishing`, `check-url`, `concurrent-uploads`, `add-bounds`, `env-file`, `lock-scripts`, `tool-run-refresh`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `constraint-files`, `workspace`, `sources`, `managed`, `package`, `tasks`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4473,6 +5072,20 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4495,6 +5108,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -4529,6 +5143,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4572,6 +5187,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4638,6 +5256,20 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4660,6 +5292,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -4694,6 +5327,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4737,6 +5371,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4822,6 +5459,20 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4844,6 +5495,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -4878,6 +5530,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4921,6 +5574,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -4998,6 +5654,20 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5020,6 +5690,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -5080,6 +5751,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -5113,6 +5795,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -5123,6 +5816,7 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5166,6 +5860,9 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5222,6 +5919,20 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5244,6 +5955,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -5304,6 +6016,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -5337,6 +6060,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -5347,6 +6081,7 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5390,6 +6125,9 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5452,6 +6190,20 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5474,6 +6226,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -5534,6 +6287,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -5567,6 +6331,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -5577,6 +6352,7 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5620,6 +6396,9 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5677,6 +6456,20 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5699,6 +6492,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -5759,6 +6553,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -5792,6 +6597,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -5802,6 +6618,7 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5845,6 +6662,9 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -5909,6 +6729,20 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5931,6 +6765,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -5991,6 +6826,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -6024,6 +6870,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -6034,6 +6891,7 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6077,6 +6935,9 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6134,6 +6995,20 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6156,6 +7031,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipCompileSettings {
         format: None,
@@ -6216,6 +7092,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                     Index {
                         name: None,
@@ -6249,6 +7136,17 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        retries: None,
+                        concurrent_requests: None,
+                        client_cert: None,
+                        client_key: None,
+                        mirrors: [],
+                        mirror_selection: Ordered,
+                        credential_helper: None,
+                        ca_cert: None,
+                        range_requests: None,
+                        credential_sources: None,
+                        cloud_auth: None,
                     },
                 ],
                 flat_index: [],
@@ -6259,6 +7157,7 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6302,6 +7201,9 @@ fn index_priority() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6372,6 +7274,20 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6394,6 +7310,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipInstallSettings {
         package: [],
@@ -6405,6 +7322,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6428,6 +7346,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6471,6 +7390,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6527,6 +7449,20 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6549,6 +7485,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipInstallSettings {
         package: [],
@@ -6560,6 +7497,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6583,6 +7521,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6626,6 +7565,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6680,6 +7622,20 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6702,6 +7658,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipInstallSettings {
         package: [],
@@ -6713,6 +7670,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6736,6 +7694,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6779,6 +7738,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6835,6 +7797,20 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6857,6 +7833,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipInstallSettings {
         package: [],
@@ -6868,6 +7845,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6891,6 +7869,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6934,6 +7913,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -6988,6 +7970,20 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7010,6 +8006,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipInstallSettings {
         package: [],
@@ -7021,6 +8018,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7044,6 +8042,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -7087,6 +8086,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7142,6 +8144,20 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7164,6 +8180,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     PipInstallSettings {
         package: [],
@@ -7175,6 +8192,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7198,6 +8216,7 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_require_attestations: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -7241,6 +8260,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             dependency_metadata: DependencyMetadata(
                 {},
             ),
+            build_system_overrides: BuildSystemOverrides(
+                {},
+            ),
             output_file: None,
             no_strip_extras: false,
             no_strip_markers: false,
@@ -7311,6 +8333,20 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7320,7 +8356,7 @@ fn preview_features() {
         show_settings: true,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | AUDIT_WHEELS | TOOL_LAYERS | MEMBER_REQUIRES_PYTHON,
             ),
         },
         python_preference: Managed,
@@ -7333,6 +8369,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     VersionSettings {
         value: None,
@@ -7344,12 +8381,14 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
-        package: None,
+        package: [],
+        workspace: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
         refresh: None(
             Timestamp(
@@ -7365,6 +8404,9 @@ fn preview_features() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -7413,6 +8455,20 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7435,6 +8491,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     VersionSettings {
         value: None,
@@ -7446,12 +8503,14 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
-        package: None,
+        package: [],
+        workspace: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
         refresh: None(
             Timestamp(
@@ -7467,6 +8526,9 @@ fn preview_features() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -7515,6 +8577,20 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7524,7 +8600,7 @@ fn preview_features() {
         show_settings: true,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | AUDIT_WHEELS | TOOL_LAYERS | MEMBER_REQUIRES_PYTHON,
             ),
         },
         python_preference: Managed,
@@ -7537,6 +8613,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     VersionSettings {
         value: None,
@@ -7548,12 +8625,14 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
-        package: None,
+        package: [],
+        workspace: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
         refresh: None(
             Timestamp(
@@ -7569,6 +8648,9 @@ fn preview_features() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -7617,6 +8699,20 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7639,6 +8735,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     VersionSettings {
         value: None,
@@ -7650,12 +8747,14 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
-        package: None,
+        package: [],
+        workspace: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
         refresh: None(
             Timestamp(
@@ -7671,6 +8770,9 @@ fn preview_features() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -7719,6 +8821,20 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7741,6 +8857,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     VersionSettings {
         value: None,
@@ -7752,12 +8869,14 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
-        package: None,
+        package: [],
+        workspace: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
         refresh: None(
             Timestamp(
@@ -7773,6 +8892,9 @@ fn preview_features() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -7823,6 +8945,20 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7845,6 +8981,7 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_compression: None,
     }
     VersionSettings {
         value: None,
@@ -7856,12 +8993,14 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
-        package: None,
+        package: [],
+        workspace: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_require_attestations: None,
         },
         refresh: None(
             Timestamp(
@@ -7877,6 +9016,9 @@ fn preview_features() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_system_overrides: BuildSystemOverrides(
+                    {},
+                ),
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -7912,3 +9054,141 @@ fn preview_features() {
     "#
     );
 }
+
+/// Read the cache compression from a `uv.toml` file, with the CLI taking precedence.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_cache_compression() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write a `uv.toml` file to the directory.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        cache-compression = "zstd"
+    "#})?;
+
+    let cmd = || {
+        let mut cmd = context.command();
+        cmd.arg("cache").arg("dir").arg("--show-settings");
+        add_shared_args(cmd, context.temp_dir.path())
+    };
+
+    // The compression should be read from the `uv.toml`.
+    uv_snapshot!(context.filters(), cmd(), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GlobalSettings {
+        required_version: None,
+        quiet: 0,
+        verbose: 0,
+        color: Auto,
+        network_settings: NetworkSettings {
+            connectivity: Online,
+            native_tls: false,
+            allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
+        },
+        concurrency: Concurrency {
+            downloads: 50,
+            builds: 16,
+            installs: 8,
+        },
+        show_settings: true,
+        preview: Preview {
+            flags: PreviewFeatures(
+                0x0,
+            ),
+        },
+        python_preference: Managed,
+        python_downloads: Automatic,
+        no_progress: false,
+        installer_metadata: true,
+    }
+    CacheSettings {
+        no_cache: false,
+        cache_dir: Some(
+            "[CACHE_DIR]/",
+        ),
+        cache_compression: Zstd,
+    }
+    [CACHE_DIR]/
+
+    ----- stderr -----
+    "#);
+
+    // The CLI should take precedence over the `uv.toml`.
+    uv_snapshot!(context.filters(), cmd().arg("--cache-compression").arg("none"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GlobalSettings {
+        required_version: None,
+        quiet: 0,
+        verbose: 0,
+        color: Auto,
+        network_settings: NetworkSettings {
+            connectivity: Online,
+            native_tls: false,
+            allow_insecure_host: [],
+            limit_rate: None,
+            proxy: None,
+            retry: RetrySettings {
+                retries: None,
+                backoff: RetryBackoff {
+                    base_delay: None,
+                    max_delay: None,
+                    jitter: None,
+                },
+                status_codes: [],
+            },
+            trace_http: None,
+            debug_auth: false,
+            strict_index_tracking: false,
+        },
+        concurrency: Concurrency {
+            downloads: 50,
+            builds: 16,
+            installs: 8,
+        },
+        show_settings: true,
+        preview: Preview {
+            flags: PreviewFeatures(
+                0x0,
+            ),
+        },
+        python_preference: Managed,
+        python_downloads: Automatic,
+        no_progress: false,
+        installer_metadata: true,
+    }
+    CacheSettings {
+        no_cache: false,
+        cache_dir: Some(
+            "[CACHE_DIR]/",
+        ),
+        cache_compression: None,
+    }
+    [CACHE_DIR]/
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
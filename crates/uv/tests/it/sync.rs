@@ -268,12 +268,95 @@ fn package() -> Result<()> {
     Ok(())
 }
 
+/// With `member-environments`, syncing a member installs only the member and its dependencies into
+/// an environment in the member directory.
+#[test]
+fn package_member_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "anyio>3"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+        member-environments = true
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("child"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: child/.venv
+    Resolved 6 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + iniconfig==2.0.0
+    ");
+
+    child.child(".venv").assert(predicate::path::is_dir());
+
+    // Syncing from the member directory uses the same environment.
+    uv_snapshot!(context.filters(), context.sync().current_dir(&child), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Audited 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Test json output
 #[test]
 fn sync_json() -> Result<()> {
     let context = TestContext::new("3.12")
         .with_filtered_python_names()
         .with_filtered_virtualenv_bin();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r#""bytes_(downloaded|saved)": \d+"#,
+            r#""bytes_$1": "[SIZE]""#,
+        )])
+        .collect::<Vec<_>>();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -286,7 +369,7 @@ fn sync_json() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--output-format").arg("json"), @r#"
     success: true
     exit_code: 0
@@ -317,6 +400,14 @@ fn sync_json() -> Result<()> {
         "path": "[TEMP_DIR]/uv.lock",
         "action": "create"
       },
+      "cache": {
+        "wheels_from_cache": 0,
+        "wheels_downloaded": 1,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -329,7 +420,7 @@ fn sync_json() -> Result<()> {
 
     assert!(context.temp_dir.child("uv.lock").exists());
 
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--frozen")
         .arg("--output-format").arg("json"), @r#"
     success: true
@@ -361,6 +452,14 @@ fn sync_json() -> Result<()> {
         "path": "[TEMP_DIR]/uv.lock",
         "action": "use"
       },
+      "cache": {
+        "wheels_from_cache": 0,
+        "wheels_downloaded": 0,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -368,7 +467,7 @@ fn sync_json() -> Result<()> {
     Audited 1 package in [TIME]
     "#);
 
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--locked")
         .arg("--output-format").arg("json"), @r#"
     success: true
@@ -400,6 +499,14 @@ fn sync_json() -> Result<()> {
         "path": "[TEMP_DIR]/uv.lock",
         "action": "check"
       },
+      "cache": {
+        "wheels_from_cache": 0,
+        "wheels_downloaded": 0,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -420,7 +527,7 @@ fn sync_json() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--locked")
         .arg("--output-format").arg("json"), @r###"
     success: false
@@ -433,7 +540,7 @@ fn sync_json() -> Result<()> {
     "###);
 
     // Test that JSON output is shown even with --quiet flag
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--quiet")
         .arg("--frozen")
         .arg("--output-format").arg("json"), @r#"
@@ -466,6 +573,14 @@ fn sync_json() -> Result<()> {
         "path": "[TEMP_DIR]/uv.lock",
         "action": "use"
       },
+      "cache": {
+        "wheels_from_cache": 0,
+        "wheels_downloaded": 0,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -481,6 +596,14 @@ fn sync_dry_json() -> Result<()> {
     let context = TestContext::new_with_versions(&["3.12"])
         .with_filtered_python_names()
         .with_filtered_virtualenv_bin();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r#""bytes_(downloaded|saved)": \d+"#,
+            r#""bytes_$1": "[SIZE]""#,
+        )])
+        .collect::<Vec<_>>();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -494,7 +617,7 @@ fn sync_dry_json() -> Result<()> {
     )?;
 
     // Running `uv sync` should report intent to create the environment and lockfile
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--output-format").arg("json")
         .arg("--dry-run"), @r#"
     success: true
@@ -526,6 +649,14 @@ fn sync_dry_json() -> Result<()> {
         "path": "[TEMP_DIR]/uv.lock",
         "action": "create"
       },
+      "cache": {
+        "wheels_from_cache": 0,
+        "wheels_downloaded": 0,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": true
     }
 
@@ -625,6 +756,107 @@ fn mixed_requires_python() -> Result<()> {
     Ok(())
 }
 
+/// Sync members with divergent `requires-python` specifiers, with `member-requires-python`.
+#[test]
+fn sync_member_requires_python() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.9", "3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [tool.uv.workspace]
+        members = ["library", "app"]
+        "#,
+    )?;
+
+    context
+        .temp_dir
+        .child("library")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+        [project]
+        name = "library"
+        version = "0.1.0"
+        requires-python = ">=3.9"
+        dependencies = ["iniconfig"]
+        "#,
+        )?;
+
+    context
+        .temp_dir
+        .child("app")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+        [project]
+        name = "app"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["library", "anyio"]
+
+        [tool.uv.sources]
+        library = { workspace = true }
+        "#,
+        )?;
+
+    // The library can be synced with Python 3.9.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--preview-features").arg("member-requires-python")
+        .arg("--package").arg("library")
+        .arg("-p").arg("3.9"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.9.[X] interpreter at: [PYTHON-3.9]
+    Creating virtual environment at: .venv
+    Resolved 6 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The application can't.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--preview-features").arg("member-requires-python")
+        .arg("--package").arg("app")
+        .arg("-p").arg("3.9"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    error: The current Python version (3.9.[X]) is not compatible with the `requires-python` of workspace member `app`: `>=3.12`
+    ");
+
+    // But can be synced with Python 3.12.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--preview-features").arg("member-requires-python")
+        .arg("--package").arg("app")
+        .arg("-p").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 6 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
 /// Ensure that group requires-python solves an actual problem
 #[test]
 #[cfg(not(windows))]
@@ -4994,6 +5226,14 @@ fn sync_active_script_environment_json() -> Result<()> {
     let context = TestContext::new_with_versions(&["3.11", "3.12"])
         .with_filtered_virtualenv_bin()
         .with_filtered_python_names();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r#""bytes_(downloaded|saved)": \d+"#,
+            r#""bytes_$1": "[SIZE]""#,
+        )])
+        .collect::<Vec<_>>();
 
     let script = context.temp_dir.child("script.py");
     script.write_str(indoc! { r#"
@@ -5009,7 +5249,7 @@ fn sync_active_script_environment_json() -> Result<()> {
     })?;
 
     // Running `uv sync --script` with `VIRTUAL_ENV` should warn
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--script").arg("script.py")
         .arg("--output-format").arg("json")
         .env(EnvVars::VIRTUAL_ENV, "foo"), @r#"
@@ -5036,6 +5276,14 @@ fn sync_active_script_environment_json() -> Result<()> {
         "action": "create"
       },
       "lock": null,
+      "cache": {
+        "wheels_from_cache": 0,
+        "wheels_downloaded": 3,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -5055,7 +5303,7 @@ fn sync_active_script_environment_json() -> Result<()> {
         .assert(predicate::path::missing());
 
     // Using `--active` should create the environment
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--script").arg("script.py")
         .arg("--output-format").arg("json")
         .env(EnvVars::VIRTUAL_ENV, "foo").arg("--active"), @r#"
@@ -5082,6 +5330,14 @@ fn sync_active_script_environment_json() -> Result<()> {
         "action": "create"
       },
       "lock": null,
+      "cache": {
+        "wheels_from_cache": 3,
+        "wheels_downloaded": 0,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -5111,7 +5367,7 @@ fn sync_active_script_environment_json() -> Result<()> {
     ");
 
     // Requesting another Python version will invalidate the environment
-    uv_snapshot!(context.filters(), context.sync()
+    uv_snapshot!(filters, context.sync()
         .arg("--script").arg("script.py")
         .arg("--output-format").arg("json")
         .env(EnvVars::VIRTUAL_ENV, "foo")
@@ -5141,6 +5397,14 @@ fn sync_active_script_environment_json() -> Result<()> {
         "action": "update"
       },
       "lock": null,
+      "cache": {
+        "wheels_from_cache": 3,
+        "wheels_downloaded": 0,
+        "builds": 0,
+        "builds_avoided": 0,
+        "bytes_downloaded": "[SIZE]",
+        "bytes_saved": "[SIZE]"
+      },
       "dry_run": false
     }
 
@@ -6313,6 +6577,127 @@ fn sync_no_editable() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn sync_zipapp() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.scripts]
+        foo = "foo:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let src = context.temp_dir.child("src").child("foo");
+    src.create_dir_all()?;
+    src.child("__init__.py").write_str(indoc! { r#"
+        def main():
+            import iniconfig
+            print("Hello from the zipapp!")
+        "#
+    })?;
+
+    // Editable installs can't be included in a zipapp.
+    uv_snapshot!(context.filters(), context.sync().arg("--zipapp").arg("foo.pyz"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/)
+     + iniconfig==2.0.0
+    error: The environment contains a `.pth` file, which isn't supported in a zipapp: `__editable__.foo-0.1.0.pth`; use `--no-editable` to sync the project without an editable install
+    ");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable").arg("--zipapp").arg("foo.pyz"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ foo==0.1.0 (from file://[TEMP_DIR]/)
+    Created zipapp at: foo.pyz
+    ");
+
+    uv_snapshot!(context.filters(), context.python_command().arg("foo.pyz"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from the zipapp!
+
+    ----- stderr -----
+    ");
+
+    // The shebang can be customized.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable").arg("--zipapp").arg("bar.pyz").arg("--python-shebang").arg("/opt/python/bin/python3"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Created zipapp at: bar.pyz
+    ");
+
+    let contents = fs_err::read(context.temp_dir.child("bar.pyz"))?;
+    assert!(contents.starts_with(b"#!/opt/python/bin/python3\n"));
+
+    // Compiled extension modules can't be imported from a zipapp, so uv warns about them.
+    fs_err::write(
+        context
+            .site_packages()
+            .join("iniconfig")
+            .join("_speedups.so"),
+        "",
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable").arg("--zipapp").arg("foo.pyz"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    warning: The environment contains compiled extension modules, which can't be imported from a zipapp (pass `--allow-extension-modules` to silence this warning):
+      iniconfig/_speedups.so
+    Created zipapp at: foo.pyz
+    ");
+
+    // The warning can be silenced.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable").arg("--zipapp").arg("foo.pyz").arg("--allow-extension-modules"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Created zipapp at: foo.pyz
+    ");
+
+    Ok(())
+}
+
 #[test]
 /// Check warning message for <https://github.com/astral-sh/uv/issues/6998>
 /// if no `build-system` section is defined.
@@ -3832,3 +3832,244 @@ fn tool_install_default_credentials() -> Result<()> {
 
     Ok(())
 }
+
+/// Install tools with shared dependency layers.
+#[test]
+fn tool_install_layers() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`; its dependencies should be installed into shared layers.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--preview-features")
+        .arg("tool-layers")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed 5 shared layers
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+    Installed 2 executables: black, blackd
+    ");
+
+    tool_dir.child(".layers").assert(predicate::path::is_dir());
+
+    uv_snapshot!(context.filters(), Command::new("black").arg("--version").env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    "###);
+
+    // Install `flask`, which should reuse the existing `click` layer.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("flask")
+        .arg("--preview-features")
+        .arg("tool-layers")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed 5 shared layers
+     + blinker==1.7.0
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + flask==3.0.2
+    Installed 1 executable: flask
+    ");
+
+    // Uninstalling both tools should remove the shared layers.
+    uv_snapshot!(context.filters(), context.tool_uninstall()
+        .arg("black")
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 3 executables: black, blackd, flask
+    ");
+
+    tool_dir.child(".layers").assert(predicate::path::missing());
+}
+
+/// `uv tool install` should respect the global Python pin, without recording it in the receipt.
+#[test]
+fn tool_install_global_python_pin() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"])
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .user_config_dir
+        .child("uv")
+        .child(".python-version")
+        .write_str("3.11")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    uv_snapshot!(context.filters(), Command::new("black").arg("--version").env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.11.[X]
+
+    ----- stderr -----
+    "###);
+
+    // The pin is not recorded in the receipt.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+
+    Ok(())
+}
+
+/// Install the shell completions and man pages provided by a tool with `--data-files`.
+#[test]
+fn tool_install_data_files() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let data_dir = context.temp_dir.child("data");
+
+    let hello = context.temp_dir.child("hello");
+    hello.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "hello"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        hello = "hello:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.hatch.build.targets.wheel.shared-data]
+        "completions/hello.bash" = "share/bash-completion/completions/hello"
+        "man/hello.1" = "share/man/man1/hello.1"
+        "#
+    })?;
+    hello
+        .child("src/hello/__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
+    hello
+        .child("completions/hello.bash")
+        .write_str("complete -W 'world' hello\n")?;
+    hello.child("man/hello.1").write_str(".TH HELLO 1\n")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--data-files")
+        .arg(hello.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::UV_TOOL_DATA_DIR, data_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hello==0.1.0 (from file://[TEMP_DIR]/hello)
+    Installed 1 executable: hello
+    Installed 2 data files: bash-completion/completions/hello, man/man1/hello.1
+    ");
+
+    data_dir
+        .child("bash-completion/completions/hello")
+        .assert("complete -W 'world' hello\n");
+    data_dir.child("man/man1/hello.1").assert(".TH HELLO 1\n");
+
+    // Uninstalling the tool removes its data files.
+    uv_snapshot!(context.filters(), context.tool_uninstall()
+        .arg("hello")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::UV_TOOL_DATA_DIR, data_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 executable: hello
+    ");
+
+    data_dir
+        .child("bash-completion/completions/hello")
+        .assert(predicate::path::missing());
+    data_dir
+        .child("man/man1/hello.1")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -0,0 +1,361 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::OutputAssertExt;
+use assert_fs::prelude::*;
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+#[test]
+fn tool_sync() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let manifest = context.temp_dir.child("tools.toml");
+
+    manifest.write_str(indoc::indoc! {r#"
+        [tools]
+        black = "==24.2.0"
+    "#})?;
+
+    // Install the tools in the manifest.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("--manifest")
+        .arg(manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Tools that already satisfy the manifest are left as-is.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_MANIFEST, manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black==24.2.0` is already installed
+    "###);
+
+    // With `--inexact`, tools that aren't in the manifest are retained.
+    manifest.write_str("[tools]")?;
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("--manifest")
+        .arg(manifest.path())
+        .arg("--inexact")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // Otherwise, they're uninstalled.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("--manifest")
+        .arg(manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalling tool not listed in the tools manifest: black
+    Uninstalled 2 executables: black, blackd
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_sync_missing_manifest() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("--manifest")
+        .arg("tools.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No tools manifest found at: `tools.toml`
+    "###);
+}
+
+#[test]
+fn tool_export_import() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let manifest = context.temp_dir.child("tools.toml");
+
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+    context
+        .tool_install()
+        .arg("flask")
+        .arg("--with")
+        .arg("requests")
+        .arg("--python")
+        .arg("3.12")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Export the installed tools, pinned to their installed versions.
+    uv_snapshot!(context.filters(), context.tool_export()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [tools]
+    black = "==24.2.0"
+    flask = { version = "==3.0.2", with = ["requests"], python = "3.12" }
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_export()
+        .arg("--output-file")
+        .arg(manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 2 tools to: tools.toml
+    "###);
+
+    // Re-create the tools in a fresh tools directory.
+    let tool_dir = context.temp_dir.child("imported-tools");
+    let bin_dir = context.temp_dir.child("imported-bin");
+    uv_snapshot!(context.filters(), context.tool_import()
+        .arg(manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + blinker==1.7.0
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + click==8.1.7
+     + flask==3.0.2
+     + idna==3.6
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + requests==2.31.0
+     + urllib3==2.2.1
+     + werkzeug==3.0.1
+    Installed 1 executable: flask
+    "###);
+}
+
+#[test]
+fn tool_export_constraints() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let manifest = context.temp_dir.child("tools.toml");
+
+    let constraints = context.temp_dir.child("constraints.txt");
+    constraints.write_str("click<8.1.7")?;
+    let overrides = context.temp_dir.child("overrides.txt");
+    overrides.write_str("packaging==23.2")?;
+
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .arg("--constraints")
+        .arg(constraints.path())
+        .arg("--overrides")
+        .arg(overrides.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // The constraints and overrides are exported along with the tool.
+    uv_snapshot!(context.filters(), context.tool_export()
+        .arg("--output-file")
+        .arg(manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 1 tool to: tools.toml
+    "###);
+
+    insta::assert_snapshot!(fs_err::read_to_string(&manifest)?, @r###"
+    [tools]
+    black = { version = "==24.2.0", constraints = ["click<8.1.7"], overrides = ["packaging==23.2"] }
+    "###);
+
+    // And are respected when re-creating the tool.
+    let tool_dir = context.temp_dir.child("imported-tools");
+    let bin_dir = context.temp_dir.child("imported-bin");
+    uv_snapshot!(context.filters(), context.tool_import()
+        .arg(manifest.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.6
+     + mypy-extensions==1.0.0
+     + packaging==23.2
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_export_git() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Create a Git repository with a tool.
+    let repo = context.temp_dir.child("hello");
+    repo.child("pyproject.toml").write_str(indoc::indoc! { r#"
+        [project]
+        name = "hello"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        hello = "hello:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    repo.child("src/hello/__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone()
+    };
+    git(&["init", "--initial-branch", "main"]);
+    git(&["config", "--local", "user.name", "Alice"]);
+    git(&["config", "--local", "user.email", "alice@example.com"]);
+    git(&["add", "."]);
+    git(&["commit", "-m", "Initial commit"]);
+    let commit = String::from_utf8(git(&["rev-parse", "HEAD"]))?
+        .trim()
+        .to_string();
+    let url = Url::from_directory_path(repo.path()).unwrap();
+
+    // Install the tool from the `main` branch.
+    context
+        .tool_install()
+        .arg(format!("hello @ git+{url}@main"))
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Move the branch.
+    repo.child("src/hello/__init__.py")
+        .write_str("def main():\n    print('Hello, world!')\n")?;
+    git(&["commit", "-am", "Update greeting"]);
+
+    // The tool is exported at the installed commit, rather than the branch.
+    let output = context
+        .tool_export()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(output.status.success(), "{stdout}");
+    assert!(
+        stdout.contains(&format!(r#"hello = {{ source = "git+{url}@{commit}" }}"#)),
+        "{stdout}"
+    );
+
+    Ok(())
+}
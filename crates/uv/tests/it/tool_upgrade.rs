@@ -1,5 +1,10 @@
+use std::process::Command;
+
+use assert_cmd::prelude::OutputAssertExt;
 use assert_fs::prelude::*;
+use indoc::indoc;
 use insta::assert_snapshot;
+use url::Url;
 
 use uv_static::EnvVars;
 
@@ -289,6 +294,118 @@ fn tool_upgrade_all() {
     "###);
 }
 
+#[test]
+fn tool_upgrade_all_dry_run() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `python-dotenv` from Test PyPI, to get an outdated version.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("python-dotenv")
+        .arg("--index-url")
+        .arg("https://test.pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + python-dotenv==0.10.2.post2
+    Installed 1 executable: dotenv
+    "###);
+
+    // Install `babel` from Test PyPI, with a constraint that holds it back from the latest version.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("babel<2.10")
+        .arg("--index-url")
+        .arg("https://test.pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + babel==2.6.0
+     + pytz==2018.5
+    Installed 1 executable: pybabel
+    "###);
+
+    // Report the upgrades from PyPI, without applying them.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("--all")
+        .arg("--dry-run")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would update babel v2.6.0 -> v2.9.1
+    Would update python-dotenv v0.10.2.post2 -> v1.0.1
+    One tool was held back by version constraints:
+     ~ babel v2.9.1 (requires `babel<2.10`, latest: v2.14.0)
+    "###);
+
+    // The tools should be unchanged.
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    babel v2.6.0
+    - pybabel
+    python-dotenv v0.10.2.post2
+    - dotenv
+
+    ----- stderr -----
+    "###);
+
+    // Apply the upgrades, which should report the same tool as held back.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("--all")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple/")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated babel v2.6.0 -> v2.9.1
+     - babel==2.6.0
+     + babel==2.9.1
+     - pytz==2018.5
+     + pytz==2024.1
+    Installed 1 executable: pybabel
+    Updated python-dotenv v0.10.2.post2 -> v1.0.1
+     - python-dotenv==0.10.2.post2
+     + python-dotenv==1.0.1
+    Installed 1 executable: dotenv
+    One tool was held back by version constraints:
+     ~ babel v2.9.1 (requires `babel<2.10`, latest: v2.14.0)
+    "###);
+}
+
 #[test]
 fn tool_upgrade_non_existing_package() {
     let context = TestContext::new("3.12")
@@ -835,3 +952,120 @@ fn tool_upgrade_python_with_all() {
         assert_snapshot!(lines[lines.len() - 3], @"version_info = 3.12.[X]");
     });
 }
+
+/// Upgrade tools installed from a Git repository, which follow branches and stay pinned to commits.
+#[test]
+fn tool_upgrade_git() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Create a Git repository with a tool.
+    let repo = context.temp_dir.child("hello");
+    repo.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "hello"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        hello = "hello:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    repo.child("src/hello/__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone()
+    };
+    git(&["init", "--initial-branch", "main"]);
+    git(&["config", "--local", "user.name", "Alice"]);
+    git(&["config", "--local", "user.email", "alice@example.com"]);
+    git(&["add", "."]);
+    git(&["commit", "-m", "Initial commit"]);
+    let first = String::from_utf8(git(&["rev-parse", "HEAD"]))?
+        .trim()
+        .to_string();
+    let url = Url::from_directory_path(repo.path()).unwrap();
+
+    // Install the tool from the `main` branch.
+    context
+        .tool_install()
+        .arg(format!("hello @ git+{url}@main"))
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Add a commit to the branch.
+    repo.child("src/hello/__init__.py")
+        .write_str("def main():\n    print('Hello, world!')\n")?;
+    git(&["commit", "-am", "Update greeting"]);
+    let second = String::from_utf8(git(&["rev-parse", "HEAD"]))?
+        .trim()
+        .to_string();
+
+    // Upgrading the tool follows the branch.
+    let output = context
+        .tool_upgrade()
+        .arg("hello")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains(&format!(
+            "Updated hello to the latest commit on branch or tag `main`: {} -> {}",
+            &first[..7],
+            &second[..7]
+        )),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("pinned"), "{stderr}");
+
+    // Reinstall the tool, pinned to the first commit.
+    context
+        .tool_install()
+        .arg(format!("hello @ git+{url}@{first}"))
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Upgrading the tool leaves it at the commit, and warns that it's pinned.
+    let output = context
+        .tool_upgrade()
+        .arg("hello")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains(&format!(
+            "warning: `hello` is pinned to commit `{}`, so it won't be upgraded to newer commits; reinstall it from a branch to follow updates",
+            &first[..7]
+        )),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("Updated hello"), "{stderr}");
+
+    Ok(())
+}
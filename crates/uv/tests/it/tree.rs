@@ -1669,3 +1669,156 @@ fn only_group() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn workspace_graph() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "child"]
+
+        [dependency-groups]
+        dev = ["leaf"]
+
+        [tool.uv.workspace]
+        members = ["child", "leaf"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        leaf = { workspace = true }
+    "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        cli = ["leaf"]
+
+        [tool.uv.sources]
+        leaf = { workspace = true }
+    "#,
+    )?;
+
+    let leaf = context.temp_dir.child("leaf");
+    leaf.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "leaf"
+        version = "0.2.0"
+        requires-python = ">=3.12"
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--workspace"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child v0.1.0
+    └── leaf v0.2.0 (extra: cli)
+    leaf v0.2.0
+    project v0.1.0
+    ├── child v0.1.0
+    └── leaf v0.2.0 (group: dev)
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--workspace").arg("--format").arg("dot"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph workspace {
+        "child";
+        "leaf";
+        "project";
+        "child" -> "leaf" [label="extra: cli", style=dashed];
+        "project" -> "child";
+        "project" -> "leaf" [label="group: dev", style=dashed];
+    }
+
+    ----- stderr -----
+    "#
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--workspace").arg("--format").arg("mermaid"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flowchart TD
+        n0["child"]
+        n1["leaf"]
+        n2["project"]
+        n0 -.->|extra: cli| n1
+        n2 --> n0
+        n2 -.->|group: dev| n1
+
+    ----- stderr -----
+    "#
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--workspace").arg("--format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "members": [
+        {
+          "name": "child",
+          "version": "0.1.0",
+          "dependencies": [
+            {
+              "name": "leaf",
+              "kind": "optional",
+              "extra": "cli"
+            }
+          ]
+        },
+        {
+          "name": "leaf",
+          "version": "0.2.0",
+          "dependencies": []
+        },
+        {
+          "name": "project",
+          "version": "0.1.0",
+          "dependencies": [
+            {
+              "name": "child",
+              "kind": "dependency"
+            },
+            {
+              "name": "leaf",
+              "kind": "group",
+              "group": "dev"
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#
+    );
+
+    // The lockfile isn't created.
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anyhow::{Ok, Result};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 
 use crate::common::TestContext;
@@ -167,6 +167,47 @@ requires-python = ">=3.12"
 }
 
 // Set the version (--short)
+// Set the version, retaining any comments around it
+#[test]
+fn version_set_value_preserves_comments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+[project]
+name = "myproject"
+# Bumped on release.
+version = "1.10.31"  # Keep in sync with `__init__.py`.
+requires-python = ">=3.12"
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("1.1.1").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    myproject 1.10.31 => 1.1.1
+
+    ----- stderr -----
+    ");
+
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    assert_snapshot!(
+        pyproject,
+    @r###"
+    [project]
+    name = "myproject"
+    # Bumped on release.
+    version = "1.1.1"  # Keep in sync with `__init__.py`.
+    requires-python = ">=3.12"
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn version_set_value_short() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2516,3 +2557,202 @@ conflicts = [[{"extra" = "foo"}, {"extra" = "bar"}]]
 
     Ok(())
 }
+
+/// Bump the version of every workspace member, updating the requirements that pin the previous
+/// versions.
+#[test]
+fn version_bump_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child==0.1.0"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--workspace")
+        .arg("--bump").arg("minor")
+        .arg("--no-sync"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child 0.1.0 => 0.2.0
+    root 0.1.0 => 0.2.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    assert_snapshot!(
+        pyproject,
+        @r#"
+    [project]
+    name = "root"
+    version = "0.2.0"
+    requires-python = ">=3.12"
+    dependencies = ["child==0.2.0"]
+
+    [tool.uv.sources]
+    child = { workspace = true }
+
+    [tool.uv.workspace]
+    members = ["child"]
+    "#
+    );
+
+    let pyproject = context.read("child/pyproject.toml");
+    assert_snapshot!(
+        pyproject,
+        @r#"
+    [project]
+    name = "child"
+    version = "0.2.0"
+    requires-python = ">=3.12"
+    dependencies = []
+    "#
+    );
+
+    uv_snapshot!(context.filters(), context.version().arg("--workspace"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child 0.2.0
+    root 0.2.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Bump the version of selected workspace members.
+#[test]
+fn version_bump_workspace_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child==0.1.0"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child", "other"]
+    "#})?;
+
+    for name in ["child", "other"] {
+        context
+            .temp_dir
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+        "#})?;
+    }
+
+    // Only the selected members are bumped, along with the requirements on them.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--workspace")
+        .arg("--package").arg("child")
+        .arg("--package").arg("other")
+        .arg("--bump").arg("patch")
+        .arg("--no-sync"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child 0.1.0 => 0.1.1
+    other 0.1.0 => 0.1.1
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    assert_snapshot!(
+        pyproject,
+        @r#"
+    [project]
+    name = "root"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["child==0.1.1"]
+
+    [tool.uv.sources]
+    child = { workspace = true }
+
+    [tool.uv.workspace]
+    members = ["child", "other"]
+    "#
+    );
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--workspace")
+        .arg("--package").arg("root")
+        .arg("--package").arg("child"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child 0.1.1
+    root 0.1.0
+
+    ----- stderr -----
+    ");
+
+    // The selected members must exist.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--workspace")
+        .arg("--package").arg("missing"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `missing` not found in workspace
+    ");
+
+    // Without `--workspace`, only a single package can be selected.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--package").arg("child")
+        .arg("--package").arg("other"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--package` can only be provided multiple times with `--workspace`
+    ");
+
+    Ok(())
+}
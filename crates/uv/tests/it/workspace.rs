@@ -894,6 +894,194 @@ fn workspace_empty_member() -> Result<()> {
     Ok(())
 }
 
+/// Ensure that a sparse workspace tolerates members that are missing from the checkout.
+#[test]
+fn workspace_sparse_checkout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build the main workspace ...
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        sparse = true
+    "#})?;
+
+    // ... with a ...
+    let deps = indoc! {r#"
+        dependencies = ["iniconfig"]
+    "#};
+    make_project(&workspace.join("packages").join("a"), "a", deps)?;
+
+    // ... and b ...
+    let deps = indoc! {r"
+    "};
+    make_project(&workspace.join("packages").join("b"), "b", deps)?;
+
+    // ... and c.
+    let deps = indoc! {r"
+    "};
+    make_project(&workspace.join("packages").join("c"), "c", deps)?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock"))?;
+
+    // Simulate a sparse checkout, in which `b` is missing entirely, and `c` is only partially
+    // checked out.
+    fs_err::remove_dir_all(workspace.join("packages").join("b"))?;
+    fs_err::remove_file(workspace.join("packages").join("c").join("pyproject.toml"))?;
+
+    // The lockfile should still be considered up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    // Syncing the workspace should omit the missing members.
+    uv_snapshot!(context.filters(), context.sync().arg("--all-packages").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 4 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + a==0.1.0 (from file://[TEMP_DIR]/workspace/packages/a)
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The lockfile should be unchanged.
+    assert_eq!(lock, fs_err::read_to_string(workspace.join("uv.lock"))?);
+
+    // Updating the lockfile would drop the missing members, so it should error.
+    workspace
+        .child("packages")
+        .child("a")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "a"
+        version = "0.1.0"
+        requires-python = ">=3.11,<3.13"
+        dependencies = ["iniconfig", "sniffio"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lockfile at `uv.lock` needs to be updated, but the following workspace members are missing from the checkout: `b`, `c`. To update the lockfile, run `uv lock` from a checkout that includes every member.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include dependency groups that are defined in the workspace root and in other members.
+#[test]
+fn workspace_include_group_across_members() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build the main workspace, with a shared `lint` group ...
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [dependency-groups]
+        lint = ["iniconfig"]
+    "#})?;
+
+    // ... with a, which includes groups from the root and from b ...
+    let deps = indoc! {r#"
+        [dependency-groups]
+        dev = [{include-group = "lint"}, {include-group = "test", package = "b"}]
+    "#};
+    make_project(&workspace.join("packages").join("a"), "a", deps)?;
+
+    // ... and b, which defines a `test` group.
+    let deps = indoc! {r#"
+        [dependency-groups]
+        test = ["sniffio"]
+    "#};
+    make_project(&workspace.join("packages").join("b"), "b", deps)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("a").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + a==0.1.0 (from file://[TEMP_DIR]/workspace/packages/a)
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Including a group from a package that isn't a workspace member should error.
+    workspace
+        .child("packages")
+        .child("a")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "a"
+        version = "0.1.0"
+        requires-python = ">=3.11,<3.13"
+
+        [dependency-groups]
+        dev = [{include-group = "test", package = "c"}]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Project `a @ packages/a` has malformed dependency groups
+      Caused by: Failed to find workspace member `c` for a group included by `dev`
+    "###
+    );
+
+    Ok(())
+}
+
 /// Ensure that workspace discovery ignores hidden directories.
 #[test]
 fn workspace_hidden_files() -> Result<()> {
@@ -1016,6 +1204,89 @@ fn workspace_hidden_member() -> Result<()> {
     Ok(())
 }
 
+/// Ensure that the members of a nested workspace are members of the enclosing workspace, which
+/// owns the lockfile.
+#[test]
+fn workspace_nested() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build the main workspace ...
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    // ... with a ...
+    let deps = indoc! {r#"
+        dependencies = ["c"]
+
+        [tool.uv.sources]
+        c = { workspace = true }
+    "#};
+    make_project(&workspace.join("packages").join("a"), "a", deps)?;
+
+    // ... and a vendored workspace, with b ...
+    let deps = indoc! {r#"
+        dependencies = ["c"]
+
+        [tool.uv.sources]
+        c = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["libs/*"]
+    "#};
+    make_project(&workspace.join("packages").join("b"), "b", deps)?;
+
+    // ... as its root, and c as its member.
+    let deps = indoc! {r"
+        dependencies = []
+    "};
+    make_project(
+        &workspace.join("packages").join("b").join("libs").join("c"),
+        "c",
+        deps,
+    )?;
+
+    // Lock from the nested workspace member.
+    uv_snapshot!(context.filters(), context.lock().current_dir(workspace.join("packages").join("b").join("libs").join("c")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // The lockfile is written to the outermost workspace root.
+    assert!(
+        !workspace
+            .join("packages")
+            .join("b")
+            .join("uv.lock")
+            .exists()
+    );
+    let lock: SourceLock = toml::from_str(&fs_err::read_to_string(workspace.join("uv.lock"))?)?;
+
+    assert_json_snapshot!(lock.sources(), @r###"
+    {
+      "a": {
+        "editable": "packages/a"
+      },
+      "b": {
+        "editable": "packages/b"
+      },
+      "c": {
+        "editable": "packages/b/libs/c"
+      }
+    }
+    "###);
+
+    Ok(())
+}
+
 /// Ensure that workspace discovery accepts valid hidden directories.
 #[test]
 fn workspace_non_included_member() -> Result<()> {